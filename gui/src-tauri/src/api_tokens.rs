@@ -0,0 +1,224 @@
+/*!
+Responsibility:
+- Create, list and revoke HTTP API tokens with scopes (`submit`, `read_status`, `admin`) so the API can be opened on
+  a LAN without handing every caller the shared admin token.
+- Persist only a SHA-256 hash of each token in `<app config>/http_api_tokens.json`; the token itself is shown once.
+*/
+
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+use crate::hex_codec::encode_hex;
+
+pub const HTTP_API_TOKENS_FILENAME: &str = "http_api_tokens.json";
+
+const API_TOKEN_PREFIX: &str = "oca_";
+const API_TOKEN_SECRET_BYTES: usize = 32;
+const API_TOKEN_ID_BYTES: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiTokenScope {
+  // NOTE: `POST /api/v1/jobs`.
+  Submit,
+  // NOTE: `GET /api/v1/status`.
+  ReadStatus,
+  // NOTE: Watcher control (pause/resume/stop/start); implies the other scopes.
+  Admin,
+}
+
+impl ApiTokenScope {
+  pub fn label(self) -> &'static str {
+    match self {
+      ApiTokenScope::Submit => "submit",
+      ApiTokenScope::ReadStatus => "read_status",
+      ApiTokenScope::Admin => "admin",
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiTokenGrant {
+  pub scopes: Vec<ApiTokenScope>,
+  // NOTE: Recorded on jobs submitted with this token, like an `OCR_AGENT_HTTP_API_SUBMITTER_TOKENS` entry.
+  pub submitter: Option<String>,
+}
+
+impl ApiTokenGrant {
+  pub fn admin() -> Self {
+    ApiTokenGrant {
+      scopes: vec![ApiTokenScope::Admin],
+      submitter: None,
+    }
+  }
+
+  pub fn allows(&self, scope: ApiTokenScope) -> bool {
+    self
+      .scopes
+      .iter()
+      .any(|granted_scope| *granted_scope == scope || *granted_scope == ApiTokenScope::Admin)
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ApiTokenRecord {
+  token_id: String,
+  name: String,
+  scopes: Vec<ApiTokenScope>,
+  #[serde(default)]
+  submitter: Option<String>,
+  token_sha256: String,
+  created_unix_timestamp_millis: i64,
+  #[serde(default)]
+  revoked_unix_timestamp_millis: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ApiTokenStore {
+  #[serde(default)]
+  tokens: Vec<ApiTokenRecord>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiTokenSummary {
+  pub token_id: String,
+  pub name: String,
+  pub scopes: Vec<ApiTokenScope>,
+  pub submitter: Option<String>,
+  pub created_unix_timestamp_millis: i64,
+  pub revoked_unix_timestamp_millis: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatedApiToken {
+  #[serde(flatten)]
+  pub summary: ApiTokenSummary,
+  // NOTE: The only time the token is available; the store keeps its hash.
+  pub token: String,
+}
+
+impl ApiTokenRecord {
+  fn summary(&self) -> ApiTokenSummary {
+    ApiTokenSummary {
+      token_id: self.token_id.clone(),
+      name: self.name.clone(),
+      scopes: self.scopes.clone(),
+      submitter: self.submitter.clone(),
+      created_unix_timestamp_millis: self.created_unix_timestamp_millis,
+      revoked_unix_timestamp_millis: self.revoked_unix_timestamp_millis,
+    }
+  }
+}
+
+fn random_hex(byte_count: usize) -> Result<String, String> {
+  let mut bytes = vec![0u8; byte_count];
+  getrandom::getrandom(&mut bytes).map_err(|error| format!("Failed to generate a token: {error}"))?;
+  Ok(encode_hex(&bytes))
+}
+
+fn hash_api_token(token: &str) -> String {
+  encode_hex(&Sha256::digest(token.as_bytes()))
+}
+
+fn read_api_token_store(store_path: &Path) -> Result<ApiTokenStore, String> {
+  if !store_path.exists() {
+    return Ok(ApiTokenStore::default());
+  }
+  let raw = fs::read_to_string(store_path).map_err(|error| error.to_string())?;
+  serde_json::from_str::<ApiTokenStore>(&raw)
+    .map_err(|error| format!("Invalid token store {}: {error}", store_path.display()))
+}
+
+fn write_api_token_store(store_path: &Path, store: &ApiTokenStore) -> Result<(), String> {
+  if let Some(parent_directory_path) = store_path.parent() {
+    fs::create_dir_all(parent_directory_path).map_err(|error| error.to_string())?;
+  }
+  let serialized = serde_json::to_string_pretty(store).map_err(|error| error.to_string())?;
+  fs::write(store_path, serialized).map_err(|error| error.to_string())?;
+  restrict_to_owner(store_path)
+}
+
+// NOTE: Hashes cannot be replayed as tokens, but the file still reveals names and scopes; keep it owner-only.
+#[cfg(unix)]
+fn restrict_to_owner(store_path: &Path) -> Result<(), String> {
+  use std::os::unix::fs::PermissionsExt;
+
+  fs::set_permissions(store_path, fs::Permissions::from_mode(0o600)).map_err(|error| error.to_string())
+}
+
+#[cfg(not(unix))]
+fn restrict_to_owner(_store_path: &Path) -> Result<(), String> {
+  Ok(())
+}
+
+pub fn list_api_tokens(store_path: &Path) -> Result<Vec<ApiTokenSummary>, String> {
+  Ok(read_api_token_store(store_path)?.tokens.iter().map(ApiTokenRecord::summary).collect())
+}
+
+pub fn create_api_token(
+  store_path: &Path,
+  name: &str,
+  scopes: Vec<ApiTokenScope>,
+  submitter: Option<String>,
+  now_unix_timestamp_millis: i64,
+) -> Result<CreatedApiToken, String> {
+  let name = name.trim();
+  if name.is_empty() {
+    return Err("Token name is empty.".to_string());
+  }
+  if scopes.is_empty() {
+    // Guard: a token without scopes would be rejected everywhere; almost certainly a mistake.
+    return Err("Choose at least one scope.".to_string());
+  }
+  let mut store = read_api_token_store(store_path)?;
+  let token = format!("{API_TOKEN_PREFIX}{}", random_hex(API_TOKEN_SECRET_BYTES)?);
+  let record = ApiTokenRecord {
+    token_id: random_hex(API_TOKEN_ID_BYTES)?,
+    name: name.to_string(),
+    scopes,
+    submitter,
+    token_sha256: hash_api_token(&token),
+    created_unix_timestamp_millis: now_unix_timestamp_millis,
+    revoked_unix_timestamp_millis: None,
+  };
+  let summary = record.summary();
+  store.tokens.push(record);
+  write_api_token_store(store_path, &store)?;
+  Ok(CreatedApiToken { summary, token })
+}
+
+// NOTE: Revoked tokens stay listed (with the revocation time) so the audit trail keeps their names.
+pub fn revoke_api_token(
+  store_path: &Path,
+  token_id: &str,
+  now_unix_timestamp_millis: i64,
+) -> Result<ApiTokenSummary, String> {
+  let mut store = read_api_token_store(store_path)?;
+  let record = store
+    .tokens
+    .iter_mut()
+    .find(|record| record.token_id == token_id)
+    .ok_or_else(|| format!("API token not found: {token_id}"))?;
+  record.revoked_unix_timestamp_millis.get_or_insert(now_unix_timestamp_millis);
+  let summary = record.summary();
+  write_api_token_store(store_path, &store)?;
+  Ok(summary)
+}
+
+// NOTE: Read on every request so a revocation applies immediately; an unreadable store grants nothing.
+pub fn authorize_api_token(store_path: &Path, presented_token: &str) -> Option<ApiTokenGrant> {
+  let presented_hash = hash_api_token(presented_token);
+  read_api_token_store(store_path)
+    .ok()?
+    .tokens
+    .into_iter()
+    .filter(|record| record.revoked_unix_timestamp_millis.is_none())
+    .find(|record| bool::from(presented_hash.as_bytes().ct_eq(record.token_sha256.as_bytes())))
+    .map(|record| ApiTokenGrant {
+      scopes: record.scopes,
+      submitter: record.submitter,
+    })
+}
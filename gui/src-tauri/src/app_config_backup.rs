@@ -0,0 +1,392 @@
+/*!
+Responsibility:
+- Bundle the app config directory's settings, presets and templates into one JSON file for a second workstation.
+- Blank secrets (webhook URLs, OAuth client secrets) on export and list them so the importer can re-enter them.
+- Restore a bundle, refusing paths outside the exported scope so a crafted file cannot write elsewhere.
+- Keep this machine's trust settings (updater signing key, host interpreter) on restore and list settings that run code.
+*/
+
+use std::{
+  collections::BTreeMap,
+  fs,
+  path::{Component, Path},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use walkdir::WalkDir;
+
+pub const APP_CONFIG_BACKUP_FORMAT_VERSION: u32 = 1;
+
+// NOTE: Webhook URLs embed their own credentials; the Google "Desktop app" secret is low-risk but still not shared.
+const SECRET_FIELD_NAMES: &[&str] = &["webhook_url", "client_secret"];
+
+// NOTE: Settings that make a job run scripts, plugins or custom environment variables; imported, but listed for review.
+const REVIEW_FIELD_NAMES: &[&str] = &["script_hooks", "enabled_plugin_names", "extra_environment"];
+
+#[derive(Debug, Clone)]
+pub struct AppConfigBackupScope {
+  pub settings_filenames: Vec<&'static str>,
+  // NOTE: Directories of `*.json` files (presets), stored parsed so secrets inside them are blanked too.
+  pub json_directory_names: Vec<&'static str>,
+  // NOTE: Directories of arbitrary text files (templates), stored verbatim.
+  pub text_directory_names: Vec<&'static str>,
+  // NOTE: `(file, top-level field)` pairs that decide which code this machine trusts; restore keeps the local values.
+  pub protected_fields: Vec<(&'static str, &'static str)>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedactedSecret {
+  // NOTE: Relative to the app config directory, with `/` separators.
+  pub file: String,
+  // NOTE: RFC 6901 pointer into the file, e.g. `/0/webhook_url`.
+  pub json_pointer: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigFieldReference {
+  pub file: String,
+  pub json_pointer: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppConfigBackup {
+  pub format_version: u32,
+  pub exported_unix_timestamp_millis: i64,
+  pub json_files: BTreeMap<String, Value>,
+  pub text_files: BTreeMap<String, String>,
+  pub redacted_secrets: Vec<RedactedSecret>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AppConfigExportReport {
+  pub exported_files: Vec<String>,
+  pub redacted_secrets: Vec<RedactedSecret>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AppConfigImportReport {
+  pub imported_files: Vec<String>,
+  // NOTE: Secrets still blank after import; the matching notifications or connectors stay broken until re-entered.
+  pub secrets_to_reenter: Vec<RedactedSecret>,
+  // NOTE: Keychain entries (SMTP passwords, OAuth sign-ins) never leave the exporting machine.
+  pub keychain_reminders: Vec<String>,
+  // NOTE: Protected fields whose bundle value differed and was not applied; set them locally if the change is wanted.
+  pub withheld_trust_settings: Vec<ConfigFieldReference>,
+  // NOTE: Imported script hooks, plugin lists and extra environment variables; confirm them before running a job.
+  pub settings_to_review: Vec<ConfigFieldReference>,
+}
+
+fn relative_file_key(directory_name: &str, file_path: &Path, directory_path: &Path) -> Option<String> {
+  let relative_path = file_path.strip_prefix(directory_path).ok()?;
+  let parts: Vec<&str> = relative_path
+    .components()
+    .map(|component| component.as_os_str().to_str())
+    .collect::<Option<Vec<&str>>>()?;
+  Some(format!("{directory_name}/{}", parts.join("/")))
+}
+
+fn redact_secret_fields(value: &mut Value, json_pointer: &str, file: &str, redacted_secrets: &mut Vec<RedactedSecret>) {
+  match value {
+    Value::Object(map) => {
+      for (key, child) in map.iter_mut() {
+        let child_pointer = format!("{json_pointer}/{}", key.replace('~', "~0").replace('/', "~1"));
+        let is_secret = SECRET_FIELD_NAMES.iter().any(|name| *name == key.as_str())
+          && child.as_str().map(|secret| !secret.trim().is_empty()).unwrap_or(false);
+        if is_secret {
+          *child = Value::String(String::new());
+          redacted_secrets.push(RedactedSecret {
+            file: file.to_string(),
+            json_pointer: child_pointer,
+          });
+        } else {
+          redact_secret_fields(child, &child_pointer, file, redacted_secrets);
+        }
+      }
+    }
+    Value::Array(items) => {
+      for (index, child) in items.iter_mut().enumerate() {
+        redact_secret_fields(child, &format!("{json_pointer}/{index}"), file, redacted_secrets);
+      }
+    }
+    _ => {}
+  }
+}
+
+fn is_unset_value(value: &Value) -> bool {
+  match value {
+    Value::Null => true,
+    Value::String(text) => text.trim().is_empty(),
+    Value::Array(items) => items.is_empty(),
+    Value::Object(map) => map.is_empty(),
+    _ => false,
+  }
+}
+
+fn collect_review_fields(
+  value: &Value,
+  json_pointer: &str,
+  file: &str,
+  settings_to_review: &mut Vec<ConfigFieldReference>,
+) {
+  match value {
+    Value::Object(map) => {
+      for (key, child) in map {
+        let child_pointer = format!("{json_pointer}/{}", key.replace('~', "~0").replace('/', "~1"));
+        if REVIEW_FIELD_NAMES.iter().any(|name| *name == key.as_str()) && !is_unset_value(child) {
+          settings_to_review.push(ConfigFieldReference {
+            file: file.to_string(),
+            json_pointer: child_pointer,
+          });
+        } else {
+          collect_review_fields(child, &child_pointer, file, settings_to_review);
+        }
+      }
+    }
+    Value::Array(items) => {
+      for (index, child) in items.iter().enumerate() {
+        collect_review_fields(child, &format!("{json_pointer}/{index}"), file, settings_to_review);
+      }
+    }
+    _ => {}
+  }
+}
+
+fn withhold_protected_fields(
+  app_config_directory_path: &Path,
+  protected_fields: &[(&'static str, &'static str)],
+  json_files: &mut BTreeMap<String, Value>,
+) -> Vec<ConfigFieldReference> {
+  let mut withheld_trust_settings: Vec<ConfigFieldReference> = vec![];
+  for (file, field_name) in protected_fields {
+    let Some(Value::Object(imported_settings)) = json_files.get_mut(*file) else {
+      continue;
+    };
+    // NOTE: An unreadable local file counts as unset, so the field is dropped rather than taken from the bundle.
+    let local_value = read_json_file(&app_config_directory_path.join(file))
+      .ok()
+      .and_then(|local_settings| local_settings.get(*field_name).cloned());
+    let bundle_value = match &local_value {
+      Some(local_value) => imported_settings.insert(field_name.to_string(), local_value.clone()),
+      None => imported_settings.remove(*field_name),
+    };
+    let is_withheld = bundle_value
+      .filter(|bundle_value| !is_unset_value(bundle_value))
+      .is_some_and(|bundle_value| Some(&bundle_value) != local_value.as_ref());
+    if is_withheld {
+      withheld_trust_settings.push(ConfigFieldReference {
+        file: file.to_string(),
+        json_pointer: format!("/{field_name}"),
+      });
+    }
+  }
+  withheld_trust_settings
+}
+
+fn collect_smtp_accounts(value: &Value, smtp_accounts: &mut Vec<String>) {
+  match value {
+    Value::Object(map) => {
+      let smtp_host = map.get("smtp_host").and_then(Value::as_str);
+      let username = map.get("username").and_then(Value::as_str);
+      if let (Some(smtp_host), Some(username)) = (smtp_host, username) {
+        let smtp_account = format!("{username}@{smtp_host}");
+        if !smtp_accounts.contains(&smtp_account) {
+          smtp_accounts.push(smtp_account);
+        }
+      }
+      map.values().for_each(|child| collect_smtp_accounts(child, smtp_accounts));
+    }
+    Value::Array(items) => items.iter().for_each(|child| collect_smtp_accounts(child, smtp_accounts)),
+    _ => {}
+  }
+}
+
+fn read_json_file(file_path: &Path) -> Result<Value, String> {
+  let raw = fs::read_to_string(file_path).map_err(|error| error.to_string())?;
+  serde_json::from_str::<Value>(&raw).map_err(|error| format!("Invalid settings file {}: {error}", file_path.display()))
+}
+
+fn build_app_config_backup(
+  app_config_directory_path: &Path,
+  scope: &AppConfigBackupScope,
+  exported_unix_timestamp_millis: i64,
+) -> Result<AppConfigBackup, String> {
+  let mut json_files: BTreeMap<String, Value> = BTreeMap::new();
+  let mut text_files: BTreeMap<String, String> = BTreeMap::new();
+  for filename in &scope.settings_filenames {
+    let file_path = app_config_directory_path.join(filename);
+    if file_path.is_file() {
+      json_files.insert(filename.to_string(), read_json_file(&file_path)?);
+    }
+  }
+  for directory_name in &scope.json_directory_names {
+    let directory_path = app_config_directory_path.join(directory_name);
+    if !directory_path.is_dir() {
+      continue;
+    }
+    for entry in fs::read_dir(&directory_path).map_err(|error| error.to_string())? {
+      let file_path = entry.map_err(|error| error.to_string())?.path();
+      let is_json_file = file_path.is_file() && file_path.extension().and_then(|extension| extension.to_str()) == Some("json");
+      if !is_json_file {
+        continue;
+      }
+      if let Some(key) = relative_file_key(directory_name, &file_path, &directory_path) {
+        json_files.insert(key, read_json_file(&file_path)?);
+      }
+    }
+  }
+  for directory_name in &scope.text_directory_names {
+    let directory_path = app_config_directory_path.join(directory_name);
+    if !directory_path.is_dir() {
+      continue;
+    }
+    for entry in WalkDir::new(&directory_path).into_iter().filter_map(Result::ok) {
+      if !entry.file_type().is_file() {
+        continue;
+      }
+      let Some(key) = relative_file_key(directory_name, entry.path(), &directory_path) else {
+        continue;
+      };
+      // NOTE: Binary files (e.g. a logo next to a template) are not part of the portable config.
+      if let Ok(content) = fs::read_to_string(entry.path()) {
+        text_files.insert(key, content);
+      }
+    }
+  }
+
+  let mut redacted_secrets: Vec<RedactedSecret> = vec![];
+  for (file, value) in json_files.iter_mut() {
+    redact_secret_fields(value, "", file, &mut redacted_secrets);
+  }
+  Ok(AppConfigBackup {
+    format_version: APP_CONFIG_BACKUP_FORMAT_VERSION,
+    exported_unix_timestamp_millis,
+    json_files,
+    text_files,
+    redacted_secrets,
+  })
+}
+
+fn is_key_in_scope(key: &str, filenames: &[&'static str], directory_names: &[&'static str]) -> bool {
+  if filenames.iter().any(|filename| *filename == key) {
+    return true;
+  }
+  let Some((directory_name, rest)) = key.split_once('/') else {
+    return false;
+  };
+  let is_plain_relative_path = !rest.is_empty()
+    && Path::new(rest)
+      .components()
+      .all(|component| matches!(component, Component::Normal(_)));
+  directory_names.iter().any(|name| *name == directory_name) && is_plain_relative_path
+}
+
+fn restore_app_config_backup(
+  app_config_directory_path: &Path,
+  scope: &AppConfigBackupScope,
+  mut backup: AppConfigBackup,
+  secret_values: &BTreeMap<String, String>,
+) -> Result<AppConfigImportReport, String> {
+  if backup.format_version != APP_CONFIG_BACKUP_FORMAT_VERSION {
+    return Err(format!(
+      "Unsupported config backup version {} (expected {APP_CONFIG_BACKUP_FORMAT_VERSION}).",
+      backup.format_version
+    ));
+  }
+  // Guard: validate every path before writing anything so a bad bundle leaves the current config untouched.
+  for key in backup.json_files.keys() {
+    if !is_key_in_scope(key, &scope.settings_filenames, &scope.json_directory_names) {
+      return Err(format!("Config backup contains an unexpected file: {key}"));
+    }
+  }
+  for key in backup.text_files.keys() {
+    if !is_key_in_scope(key, &[], &scope.text_directory_names) {
+      return Err(format!("Config backup contains an unexpected file: {key}"));
+    }
+  }
+
+  let mut secrets_to_reenter: Vec<RedactedSecret> = vec![];
+  for redacted_secret in backup.redacted_secrets {
+    let secret_key = format!("{}#{}", redacted_secret.file, redacted_secret.json_pointer);
+    let secret_value = secret_values.get(&secret_key).map(|value| value.trim()).filter(|value| !value.is_empty());
+    let target = backup
+      .json_files
+      .get_mut(&redacted_secret.file)
+      .and_then(|value| value.pointer_mut(&redacted_secret.json_pointer));
+    match (secret_value, target) {
+      (Some(secret_value), Some(target)) => *target = Value::String(secret_value.to_string()),
+      _ => secrets_to_reenter.push(redacted_secret),
+    }
+  }
+  let withheld_trust_settings =
+    withhold_protected_fields(app_config_directory_path, &scope.protected_fields, &mut backup.json_files);
+  let mut settings_to_review: Vec<ConfigFieldReference> = vec![];
+  for (file, value) in &backup.json_files {
+    collect_review_fields(value, "", file, &mut settings_to_review);
+  }
+
+  let mut imported_files: Vec<String> = vec![];
+  for (key, value) in &backup.json_files {
+    let file_path = app_config_directory_path.join(key);
+    if let Some(parent) = file_path.parent() {
+      fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+    }
+    let serialized = serde_json::to_string_pretty(value).map_err(|error| error.to_string())?;
+    fs::write(&file_path, serialized).map_err(|error| error.to_string())?;
+    imported_files.push(key.clone());
+  }
+  for (key, content) in &backup.text_files {
+    let file_path = app_config_directory_path.join(key);
+    if let Some(parent) = file_path.parent() {
+      fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+    }
+    fs::write(&file_path, content).map_err(|error| error.to_string())?;
+    imported_files.push(key.clone());
+  }
+  let mut smtp_accounts: Vec<String> = vec![];
+  backup
+    .json_files
+    .values()
+    .for_each(|value| collect_smtp_accounts(value, &mut smtp_accounts));
+  let keychain_reminders = smtp_accounts
+    .into_iter()
+    .map(|smtp_account| format!("Store the SMTP password for {smtp_account} (store_smtp_password)."))
+    .collect();
+  Ok(AppConfigImportReport {
+    imported_files,
+    secrets_to_reenter,
+    keychain_reminders,
+    withheld_trust_settings,
+    settings_to_review,
+  })
+}
+
+pub fn export_app_config_backup(
+  app_config_directory_path: &Path,
+  scope: &AppConfigBackupScope,
+  backup_file_path: &Path,
+  exported_unix_timestamp_millis: i64,
+) -> Result<AppConfigExportReport, String> {
+  let backup = build_app_config_backup(app_config_directory_path, scope, exported_unix_timestamp_millis)?;
+  if let Some(parent) = backup_file_path.parent() {
+    fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+  }
+  let serialized = serde_json::to_string_pretty(&backup).map_err(|error| error.to_string())?;
+  fs::write(backup_file_path, serialized).map_err(|error| error.to_string())?;
+  Ok(AppConfigExportReport {
+    exported_files: backup.json_files.keys().chain(backup.text_files.keys()).cloned().collect(),
+    redacted_secrets: backup.redacted_secrets,
+  })
+}
+
+pub fn import_app_config_backup(
+  app_config_directory_path: &Path,
+  scope: &AppConfigBackupScope,
+  backup_file_path: &Path,
+  secret_values: &BTreeMap<String, String>,
+) -> Result<AppConfigImportReport, String> {
+  let raw = fs::read_to_string(backup_file_path).map_err(|error| error.to_string())?;
+  let backup = serde_json::from_str::<AppConfigBackup>(&raw)
+    .map_err(|error| format!("Invalid config backup {}: {error}", backup_file_path.display()))?;
+  restore_app_config_backup(app_config_directory_path, scope, backup, secret_values)
+}
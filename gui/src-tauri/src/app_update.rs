@@ -0,0 +1,183 @@
+/*!
+Responsibility:
+- Hold the GUI updater endpoint settings and the record of the last verified engine image (`engine_update.json`).
+- Track a staged engine image update (keep the previous image, build, verify, then switch or roll back) for polling.
+- Explain when the engine image has drifted from the GUI version, so both are updated together.
+*/
+
+use std::{
+  collections::VecDeque,
+  sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize};
+
+pub const GUI_UPDATER_SETTINGS_FILENAME: &str = "gui_updater.json";
+pub const ENGINE_UPDATE_RECORD_FILENAME: &str = "engine_update.json";
+
+// NOTE: The image that was current before the update; restored under the service's tag if verification fails.
+const PREVIOUS_IMAGE_TAG: &str = "previous";
+const MAX_ENGINE_UPDATE_LOG_LINES: usize = 1500;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GuiUpdaterSettings {
+  // NOTE: Tauri updater manifest URLs (e.g. a release's `latest.json`); empty disables the GUI update check.
+  #[serde(default)]
+  pub endpoints: Vec<String>,
+  // NOTE: Public key matching the key the release bundles were signed with.
+  pub pubkey: Option<String>,
+}
+
+impl GuiUpdaterSettings {
+  pub fn resolved_pubkey(&self) -> Option<&str> {
+    self.pubkey.as_deref().map(str::trim).filter(|pubkey| !pubkey.is_empty())
+  }
+
+  pub fn is_configured(&self) -> bool {
+    !self.endpoints.is_empty() && self.resolved_pubkey().is_some()
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngineUpdateRecord {
+  // NOTE: GUI version that built and verified this image; a different running GUI means the two drifted apart.
+  pub gui_version: String,
+  pub image_id: String,
+  pub verified_unix_timestamp_millis: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EngineUpdatePhase {
+  Idle,
+  // NOTE: Maintenance mode is on and running jobs are finishing on the current image.
+  Draining,
+  Building,
+  Verifying,
+  Switched,
+  RolledBack,
+  Failed,
+}
+
+impl EngineUpdatePhase {
+  pub fn is_in_progress(self) -> bool {
+    matches!(self, EngineUpdatePhase::Draining | EngineUpdatePhase::Building | EngineUpdatePhase::Verifying)
+  }
+}
+
+pub(crate) struct EngineUpdateRuntimeState {
+  phase: EngineUpdatePhase,
+  message: Option<String>,
+  log_lines: VecDeque<String>,
+}
+
+impl Default for EngineUpdateRuntimeState {
+  fn default() -> Self {
+    EngineUpdateRuntimeState {
+      phase: EngineUpdatePhase::Idle,
+      message: None,
+      log_lines: VecDeque::new(),
+    }
+  }
+}
+
+pub type SharedEngineUpdateRuntimeState = Arc<Mutex<EngineUpdateRuntimeState>>;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EngineUpdateStatus {
+  pub phase: EngineUpdatePhase,
+  pub is_in_progress: bool,
+  pub message: Option<String>,
+  pub log_lines: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateCheckReport {
+  pub current_gui_version: String,
+  pub is_gui_updater_configured: bool,
+  pub available_gui_version: Option<String>,
+  pub gui_release_notes: Option<String>,
+  pub engine_image_id: Option<String>,
+  pub engine_verified_gui_version: Option<String>,
+  pub is_engine_update_recommended: bool,
+  pub engine_update_reasons: Vec<String>,
+}
+
+pub fn new_shared_engine_update_state() -> SharedEngineUpdateRuntimeState {
+  Arc::new(Mutex::new(EngineUpdateRuntimeState::default()))
+}
+
+pub fn previous_image_name(image_name: &str) -> String {
+  let repository = image_name.rsplit_once(':').map(|(repository, _)| repository).unwrap_or(image_name);
+  format!("{repository}:{PREVIOUS_IMAGE_TAG}")
+}
+
+pub fn describe_engine_update_reasons(
+  current_gui_version: &str,
+  record: Option<&EngineUpdateRecord>,
+  engine_image_id: Option<&str>,
+  is_rebuild_recommended: bool,
+) -> Vec<String> {
+  let mut reasons: Vec<String> = vec![];
+  if engine_image_id.is_none() {
+    reasons.push("The engine image is not built yet.".to_string());
+  }
+  if is_rebuild_recommended {
+    reasons.push("The Dockerfile, requirements or compose.yaml changed since the image was built.".to_string());
+  }
+  // NOTE: No record means the image was built by hand or before the updater existed; that alone is not drift.
+  if let Some(record) = record.filter(|record| record.gui_version != current_gui_version) {
+    reasons.push(format!(
+      "The engine image was last verified with GUI {}; this GUI is {current_gui_version}.",
+      record.gui_version
+    ));
+  }
+  reasons
+}
+
+pub fn begin_engine_update(state: &SharedEngineUpdateRuntimeState) -> Result<(), String> {
+  let mut locked = state.lock().map_err(|_| "Engine update state lock poisoned".to_string())?;
+  if locked.phase.is_in_progress() {
+    // Guard: two updates would fight over the service tag and the previous-image backup.
+    return Err("An engine update is already in progress.".to_string());
+  }
+  locked.phase = EngineUpdatePhase::Draining;
+  locked.message = None;
+  locked.log_lines.clear();
+  Ok(())
+}
+
+pub fn set_engine_update_phase(state: &SharedEngineUpdateRuntimeState, phase: EngineUpdatePhase, message: Option<String>) {
+  let Ok(mut locked) = state.lock() else {
+    return;
+  };
+  locked.phase = phase;
+  locked.message = message;
+}
+
+pub fn append_engine_update_log_line(state: &SharedEngineUpdateRuntimeState, line: String) {
+  let Ok(mut locked) = state.lock() else {
+    return;
+  };
+  locked.log_lines.push_back(line);
+  while locked.log_lines.len() > MAX_ENGINE_UPDATE_LOG_LINES {
+    locked.log_lines.pop_front();
+  }
+}
+
+pub fn get_engine_update_status(state: &SharedEngineUpdateRuntimeState) -> EngineUpdateStatus {
+  match state.lock() {
+    Ok(locked) => EngineUpdateStatus {
+      phase: locked.phase,
+      is_in_progress: locked.phase.is_in_progress(),
+      message: locked.message.clone(),
+      log_lines: locked.log_lines.iter().cloned().collect(),
+    },
+    Err(_) => EngineUpdateStatus {
+      phase: EngineUpdatePhase::Failed,
+      is_in_progress: false,
+      message: Some("Engine update state lock poisoned".to_string()),
+      log_lines: vec![],
+    },
+  }
+}
@@ -0,0 +1,132 @@
+/*!
+Responsibility:
+- Probe which optional host features exist (container runtime, NVIDIA GPU, scanner tooling, clipboard access).
+- Describe them in one report so the frontend can hide unusable controls instead of failing at click time.
+*/
+
+use std::process::Command;
+
+use serde::Serialize;
+
+use crate::{
+  host_engine::ExecutionEngineKind,
+  probe_timeout::{output_with_timeout, ProbeCommandError, PROBE_COMMAND_TIMEOUT},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContainerRuntimeKind {
+  Docker,
+  // NOTE: Jobs still call `docker`; Podman works through its docker CLI shim (`podman-docker`).
+  Podman,
+  None,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ContainerRuntimeProbe {
+  pub kind: ContainerRuntimeKind,
+  // NOTE: False when the CLI exists but its engine is not running (e.g. Docker Desktop not started).
+  pub is_engine_running: bool,
+  pub version: Option<String>,
+  pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BackendCapabilities {
+  pub execution_engine: ExecutionEngineKind,
+  pub container_runtime: ContainerRuntimeProbe,
+  pub is_host_engine_supported: bool,
+  // NOTE: Host `nvidia-smi` only; whether Docker passes the GPU through is checked by `probe_gpu_passthrough`.
+  pub is_gpu_available: bool,
+  pub gpu_name: Option<String>,
+  pub is_apple_silicon: bool,
+  pub is_scanner_available: bool,
+  pub scanner_backend: Option<String>,
+  pub is_clipboard_available: bool,
+  pub notification_channels: Vec<String>,
+  pub is_http_api_enabled: bool,
+  pub http_api_bind_address: Option<String>,
+  pub is_gui_updater_configured: bool,
+}
+
+fn run_probe_command(program: &str, arguments: &[&str]) -> Option<String> {
+  let output = output_with_timeout(Command::new(program).args(arguments), PROBE_COMMAND_TIMEOUT).ok()?;
+  output
+    .status
+    .success()
+    .then(|| String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn parse_version_field(version_output: &str) -> Option<String> {
+  version_output
+    .lines()
+    .find_map(|line| line.trim().strip_prefix("Version:"))
+    .map(|version| version.trim().to_string())
+}
+
+pub fn probe_container_runtime() -> ContainerRuntimeProbe {
+  match output_with_timeout(Command::new("docker").arg("version"), PROBE_COMMAND_TIMEOUT) {
+    Ok(output) => {
+      let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+      // NOTE: The podman shim answers `docker version` with a "Podman Engine" server section.
+      let kind = if stdout.to_lowercase().contains("podman") {
+        ContainerRuntimeKind::Podman
+      } else {
+        ContainerRuntimeKind::Docker
+      };
+      ContainerRuntimeProbe {
+        kind,
+        is_engine_running: output.status.success(),
+        version: parse_version_field(&stdout),
+        note: (!output.status.success()).then(|| "The docker CLI is installed but its engine is not running.".to_string()),
+      }
+    }
+    // NOTE: The CLI is there but hangs talking to its engine (e.g. Docker Desktop stuck starting).
+    Err(unresponsive @ ProbeCommandError::EngineUnresponsive { .. }) => ContainerRuntimeProbe {
+      kind: ContainerRuntimeKind::Docker,
+      is_engine_running: false,
+      version: None,
+      note: Some(unresponsive.to_string()),
+    },
+    Err(ProbeCommandError::Failed(_)) => match run_probe_command("podman", &["--version"]) {
+      Some(podman_version) => ContainerRuntimeProbe {
+        kind: ContainerRuntimeKind::Podman,
+        is_engine_running: false,
+        version: Some(podman_version.trim().to_string()),
+        note: Some("Podman is installed without the docker CLI; install podman-docker so jobs can run.".to_string()),
+      },
+      None => ContainerRuntimeProbe {
+        kind: ContainerRuntimeKind::None,
+        is_engine_running: false,
+        version: None,
+        note: Some("Neither docker nor podman was found.".to_string()),
+      },
+    },
+  }
+}
+
+pub fn probe_host_gpu_name() -> Option<String> {
+  run_probe_command("nvidia-smi", &["--query-gpu=name", "--format=csv,noheader"])?
+    .lines()
+    .map(str::trim)
+    .find(|name| !name.is_empty())
+    .map(str::to_string)
+}
+
+// NOTE: Only reports that scanning tooling exists; scanned files still arrive through the watcher or hotfolders.
+pub fn probe_scanner_backend() -> Option<String> {
+  if cfg!(windows) {
+    let system_root = std::env::var("SystemRoot").unwrap_or_else(|_| "C:\\Windows".to_string());
+    let is_wia_installed = std::path::Path::new(&system_root).join("System32").join("wiaaut.dll").exists();
+    return is_wia_installed.then(|| "wia".to_string());
+  }
+  run_probe_command("scanimage", &["--version"]).map(|_| "sane".to_string())
+}
+
+pub fn is_clipboard_environment_available() -> bool {
+  if cfg!(target_os = "linux") {
+    // Guard: a headless session (SSH, kiosk without a compositor) has no clipboard to read from.
+    return std::env::var_os("DISPLAY").is_some() || std::env::var_os("WAYLAND_DISPLAY").is_some();
+  }
+  true
+}
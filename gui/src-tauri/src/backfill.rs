@@ -0,0 +1,209 @@
+/*!
+Responsibility:
+- Walk an archive of historical scans and hand it to the watcher inbox in fixed-size batches, a few at a time.
+- Persist per-archive progress (last submitted file) so a stopped or interrupted backfill resumes where it left off.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  connector_runtime::{read_connector_runtime_status, SharedConnectorRuntimeState},
+  now_unix_timestamp_millis,
+  watch_folder::list_ready_bundle_directories,
+};
+
+pub const BACKFILL_PROGRESS_FILENAME: &str = "backfill_progress.json";
+pub const BACKFILL_POLL_INTERVAL_MILLIS: u64 = 5000;
+// NOTE: Every document of a batch is held in memory while its bundle is written.
+pub const MAX_BACKFILL_BATCH_SIZE: usize = 500;
+// NOTE: New batches are only written while the inbox holds fewer unfinished bundles than this, so live intake is not starved.
+const MAX_PENDING_INBOX_BUNDLES: usize = 2;
+
+// NOTE: Mirrors the CLI's supported inputs (`input_discovery.py`); anything else in the archive is skipped.
+const BACKFILL_FILE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp", "bmp", "tif", "tiff", "pdf"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackfillProgress {
+  pub source_directory_path: String,
+  pub batch_size: usize,
+  pub total_file_count: usize,
+  pub submitted_file_count: usize,
+  pub submitted_batch_count: usize,
+  // NOTE: Resume key: files are submitted in sorted relative-path order, so everything up to this path is done.
+  pub last_submitted_relative_path: Option<String>,
+  pub is_completed: bool,
+  pub updated_unix_timestamp_millis: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BackfillStatus {
+  pub is_running: bool,
+  pub last_error_message: Option<String>,
+  pub progress_entries: Vec<BackfillProgress>,
+}
+
+pub type BackfillBatchSubmitter<'a> = &'a dyn Fn(usize, &[PathBuf]) -> Result<(), String>;
+
+fn load_backfill_progress_entries(progress_file_path: &Path) -> Result<Vec<BackfillProgress>, String> {
+  if !progress_file_path.exists() {
+    return Ok(vec![]);
+  }
+  let raw = fs::read_to_string(progress_file_path).map_err(|error| error.to_string())?;
+  serde_json::from_str::<Vec<BackfillProgress>>(&raw)
+    .map_err(|error| format!("Invalid backfill progress file {}: {error}", progress_file_path.display()))
+}
+
+fn save_backfill_progress_entries(progress_file_path: &Path, entries: &[BackfillProgress]) -> Result<(), String> {
+  if let Some(parent) = progress_file_path.parent() {
+    fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+  }
+  let serialized = serde_json::to_string_pretty(entries).map_err(|error| error.to_string())?;
+  fs::write(progress_file_path, serialized).map_err(|error| error.to_string())
+}
+
+fn list_backfill_source_files(source_directory_path: &Path) -> Vec<(String, PathBuf)> {
+  let mut source_files: Vec<(String, PathBuf)> = walkdir::WalkDir::new(source_directory_path)
+    .into_iter()
+    .filter_map(|entry| entry.ok())
+    .filter(|entry| entry.file_type().is_file())
+    .filter(|entry| {
+      entry
+        .path()
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| BACKFILL_FILE_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str()))
+        .unwrap_or(false)
+    })
+    .filter_map(|entry| {
+      let relative_path = entry.path().strip_prefix(source_directory_path).ok()?;
+      // Guard: normalize separators so progress saved on one OS still resumes on another.
+      let relative_key = relative_path.to_string_lossy().replace('\\', "/");
+      Some((relative_key, entry.path().to_path_buf()))
+    })
+    .collect();
+  source_files.sort();
+  source_files
+}
+
+pub struct BackfillRun {
+  progress_file_path: PathBuf,
+  source_directory_key: String,
+  batch_size: usize,
+  // NOTE: Listed once per run; files added to the archive afterwards are picked up on the next start.
+  source_files: Vec<(String, PathBuf)>,
+}
+
+impl BackfillRun {
+  pub fn prepare(progress_file_path: PathBuf, source_directory_path: &Path, batch_size: usize) -> Result<Self, String> {
+    if batch_size == 0 || batch_size > MAX_BACKFILL_BATCH_SIZE {
+      return Err(format!("batch_size must be between 1 and {MAX_BACKFILL_BATCH_SIZE} (got {batch_size})."));
+    }
+    if !source_directory_path.is_dir() {
+      // Guard: the archive must be a readable directory.
+      return Err(format!("Backfill source is not a directory: {}", source_directory_path.display()));
+    }
+    let source_directory_key = source_directory_path.to_string_lossy().to_string();
+    let source_files = list_backfill_source_files(source_directory_path);
+    if source_files.is_empty() {
+      return Err(format!("No supported scans found in {}", source_directory_path.display()));
+    }
+
+    let mut entries = load_backfill_progress_entries(&progress_file_path)?;
+    let entry_index = match entries
+      .iter()
+      .position(|entry| entry.source_directory_path == source_directory_key)
+    {
+      Some(index) => index,
+      None => {
+        entries.push(BackfillProgress {
+          source_directory_path: source_directory_key.clone(),
+          batch_size,
+          total_file_count: 0,
+          submitted_file_count: 0,
+          submitted_batch_count: 0,
+          last_submitted_relative_path: None,
+          is_completed: false,
+          updated_unix_timestamp_millis: 0,
+        });
+        entries.len() - 1
+      }
+    };
+    let entry = &mut entries[entry_index];
+    // NOTE: Changing the batch size on resume only affects batches that have not been written yet.
+    entry.batch_size = batch_size;
+    entry.total_file_count = source_files.len();
+    entry.is_completed = count_remaining_files(&source_files, entry.last_submitted_relative_path.as_deref()) == 0;
+    entry.updated_unix_timestamp_millis = now_unix_timestamp_millis();
+    save_backfill_progress_entries(&progress_file_path, &entries)?;
+
+    Ok(Self {
+      progress_file_path,
+      source_directory_key,
+      batch_size,
+      source_files,
+    })
+  }
+
+  // NOTE: Writes at most one batch per poll; a crash between submit and save re-sends that batch, which the bundle ledger skips.
+  pub fn poll_once(&mut self, inbox_directory_path: &Path, submit_batch: BackfillBatchSubmitter) -> Result<(), String> {
+    let mut entries = load_backfill_progress_entries(&self.progress_file_path)?;
+    let Some(entry) = entries
+      .iter_mut()
+      .find(|entry| entry.source_directory_path == self.source_directory_key)
+    else {
+      return Err(format!("Backfill progress was removed for {}", self.source_directory_key));
+    };
+    if entry.is_completed {
+      return Ok(());
+    }
+    if list_ready_bundle_directories(inbox_directory_path)?.len() >= MAX_PENDING_INBOX_BUNDLES {
+      return Ok(());
+    }
+
+    let batch: Vec<&(String, PathBuf)> = self
+      .source_files
+      .iter()
+      .filter(|(relative_key, _)| is_after_resume_key(relative_key, entry.last_submitted_relative_path.as_deref()))
+      .take(self.batch_size)
+      .collect();
+    if let Some((last_relative_key, _)) = batch.last() {
+      let file_paths: Vec<PathBuf> = batch.iter().map(|(_, path)| path.clone()).collect();
+      submit_batch(entry.submitted_batch_count + 1, &file_paths)?;
+      entry.submitted_batch_count += 1;
+      entry.submitted_file_count += file_paths.len();
+      entry.last_submitted_relative_path = Some(last_relative_key.clone());
+    }
+    entry.is_completed =
+      count_remaining_files(&self.source_files, entry.last_submitted_relative_path.as_deref()) == 0;
+    entry.updated_unix_timestamp_millis = now_unix_timestamp_millis();
+    save_backfill_progress_entries(&self.progress_file_path, &entries)
+  }
+}
+
+fn is_after_resume_key(relative_key: &str, last_submitted_relative_path: Option<&str>) -> bool {
+  match last_submitted_relative_path {
+    Some(last_submitted) => relative_key > last_submitted,
+    None => true,
+  }
+}
+
+fn count_remaining_files(source_files: &[(String, PathBuf)], last_submitted_relative_path: Option<&str>) -> usize {
+  source_files
+    .iter()
+    .filter(|(relative_key, _)| is_after_resume_key(relative_key, last_submitted_relative_path))
+    .count()
+}
+
+pub fn get_backfill_status(state: &SharedConnectorRuntimeState, progress_file_path: &Path) -> Result<BackfillStatus, String> {
+  let (is_running, last_error_message) = read_connector_runtime_status(state);
+  Ok(BackfillStatus {
+    is_running,
+    last_error_message,
+    progress_entries: load_backfill_progress_entries(progress_file_path)?,
+  })
+}
@@ -0,0 +1,139 @@
+/*!
+Responsibility:
+- Describe bulk actions over a selection of job roots (retry failed pages, archive completed jobs, export outputs).
+- Decide which roots an archive applies to and move them, and tally per-job results for progress events and the
+  final summary.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::job_runs::{JobRunRecord, RecordedRunOutcome};
+
+pub const BULK_JOB_OPERATION_PROGRESS_EVENT: &str = "bulk-job-operation-progress";
+
+// NOTE: Archiving the same folder name twice gets `_2`, `_3`, ...; past this something is wrong.
+const MAX_ARCHIVED_JOB_ROOT_SUFFIX: u32 = 999;
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BulkJobOperation {
+  // NOTE: Each retry is a job start, so it waits in the job queue when every slot is taken.
+  RetryFailed,
+  // NOTE: Moves job roots whose latest run completed at least `older_than_days` ago; 0 archives every completed job.
+  ArchiveCompleted {
+    archive_directory_path: String,
+    older_than_days: u32,
+  },
+  ExportOutputs {
+    destination_directory_path: String,
+  },
+}
+
+impl BulkJobOperation {
+  pub fn label(&self) -> &'static str {
+    match self {
+      BulkJobOperation::RetryFailed => "retry_failed",
+      BulkJobOperation::ArchiveCompleted { .. } => "archive_completed",
+      BulkJobOperation::ExportOutputs { .. } => "export_outputs",
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum BulkJobItemOutcome {
+  Done { detail: String },
+  // NOTE: The job did not qualify (e.g. still running, nothing failed); not an error.
+  Skipped { reason: String },
+  Failed { error_message: String },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkJobItemResult {
+  pub job_root_directory_path: String,
+  #[serde(flatten)]
+  pub outcome: BulkJobItemOutcome,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkJobOperationProgress {
+  pub operation: &'static str,
+  pub processed_count: usize,
+  pub total_count: usize,
+  pub item: BulkJobItemResult,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BulkJobOperationSummary {
+  pub done_count: usize,
+  pub skipped_count: usize,
+  pub failed_count: usize,
+  pub items: Vec<BulkJobItemResult>,
+}
+
+impl BulkJobOperationSummary {
+  pub fn record(&mut self, item: BulkJobItemResult) {
+    match item.outcome {
+      BulkJobItemOutcome::Done { .. } => self.done_count += 1,
+      BulkJobItemOutcome::Skipped { .. } => self.skipped_count += 1,
+      BulkJobItemOutcome::Failed { .. } => self.failed_count += 1,
+    }
+    self.items.push(item);
+  }
+}
+
+// NOTE: Err carries the skip reason shown for the job.
+pub fn check_archivable_run(
+  latest_run: Option<&JobRunRecord>,
+  older_than_days: u32,
+  now_unix_timestamp_millis: i64,
+) -> Result<(), String> {
+  let Some(latest_run) = latest_run else {
+    return Err("never run".to_string());
+  };
+  let Some(finished_unix_timestamp_millis) = latest_run
+    .finished_unix_timestamp_millis
+    .filter(|_| latest_run.outcome == Some(RecordedRunOutcome::Completed))
+  else {
+    return Err("latest run did not complete".to_string());
+  };
+  let min_age_millis = i64::from(older_than_days) * MILLIS_PER_DAY;
+  if now_unix_timestamp_millis - finished_unix_timestamp_millis < min_age_millis {
+    return Err(format!("completed less than {older_than_days} day(s) ago"));
+  }
+  Ok(())
+}
+
+// NOTE: A plain rename, so the archive folder must be on the same volume as the job roots; nothing is copied.
+pub fn move_job_root_to_archive(job_root_directory_path: &Path, archive_directory_path: &Path) -> Result<PathBuf, String> {
+  let job_root_name = job_root_directory_path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .ok_or_else(|| format!("Invalid job root: {}", job_root_directory_path.display()))?;
+  if archive_directory_path.starts_with(job_root_directory_path) {
+    // Guard: moving a folder into itself fails halfway on some platforms.
+    return Err("The archive folder is inside this job root.".to_string());
+  }
+  let mut destination_path = archive_directory_path.join(job_root_name);
+  let mut suffix = 2;
+  while destination_path.exists() {
+    if suffix > MAX_ARCHIVED_JOB_ROOT_SUFFIX {
+      return Err(format!("Too many archived jobs named {job_root_name}"));
+    }
+    destination_path = archive_directory_path.join(format!("{job_root_name}_{suffix}"));
+    suffix += 1;
+  }
+  fs::rename(job_root_directory_path, &destination_path).map_err(|error| {
+    format!(
+      "Failed to move to {} (the archive folder must be on the same drive): {error}",
+      destination_path.display()
+    )
+  })?;
+  Ok(destination_path)
+}
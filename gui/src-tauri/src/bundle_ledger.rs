@@ -0,0 +1,105 @@
+/*!
+Responsibility:
+- Fingerprint the documents in a watcher inbox bundle (relative paths + file contents, markers excluded).
+- Keep a per-jobs-root ledger of processed fingerprints so identical re-submissions are not OCR'd twice.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::duplicate_detection::sha256_file_hex;
+
+const BUNDLE_LEDGER_FILENAME: &str = ".bundle_ledger.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleLedgerEntry {
+  pub fingerprint: String,
+  pub job_id: String,
+  pub bundle_name: String,
+  pub processed_unix_timestamp_millis: i64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BundleLedger {
+  #[serde(default)]
+  pub entries: Vec<BundleLedgerEntry>,
+}
+
+fn bundle_ledger_file_path(jobs_root_directory_path: &Path) -> PathBuf {
+  jobs_root_directory_path.join(BUNDLE_LEDGER_FILENAME)
+}
+
+// NOTE: Renaming the bundle or adding markers/settings keeps the fingerprint; changing any document changes it.
+pub fn compute_bundle_fingerprint(
+  bundle_directory_path: &Path,
+  excluded_filenames: &[&str],
+  is_cancel_requested: &dyn Fn() -> bool,
+) -> Result<String, String> {
+  let mut document_paths: Vec<(String, PathBuf)> = walkdir::WalkDir::new(bundle_directory_path)
+    .into_iter()
+    .filter_map(|entry| entry.ok())
+    .filter(|entry| entry.file_type().is_file())
+    .filter(|entry| {
+      let file_name = entry.file_name().to_str().unwrap_or("");
+      !excluded_filenames.contains(&file_name)
+    })
+    .filter_map(|entry| {
+      let relative_path = entry.path().strip_prefix(bundle_directory_path).ok()?;
+      // Guard: normalize separators so a bundle hashes the same whichever OS wrote the ledger.
+      let relative_key = relative_path.to_string_lossy().replace('\\', "/");
+      Some((relative_key, entry.path().to_path_buf()))
+    })
+    .collect();
+  if document_paths.is_empty() {
+    // Guard: every empty bundle would otherwise share one fingerprint.
+    return Err(format!("Bundle has no documents: {}", bundle_directory_path.display()));
+  }
+  document_paths.sort();
+
+  let mut hasher = Sha256::new();
+  for (relative_key, document_path) in &document_paths {
+    if is_cancel_requested() {
+      return Err(format!("Fingerprinting cancelled: {}", bundle_directory_path.display()));
+    }
+    hasher.update(relative_key.as_bytes());
+    hasher.update([0u8]);
+    hasher.update(sha256_file_hex(document_path)?.as_bytes());
+    hasher.update([0u8]);
+  }
+  Ok(format!("sha256:{:x}", hasher.finalize()))
+}
+
+impl BundleLedger {
+  pub fn load(jobs_root_directory_path: &Path) -> Result<Self, String> {
+    let ledger_path = bundle_ledger_file_path(jobs_root_directory_path);
+    if !ledger_path.exists() {
+      return Ok(Self::default());
+    }
+    let raw = fs::read_to_string(&ledger_path).map_err(|error| error.to_string())?;
+    serde_json::from_str::<Self>(&raw).map_err(|error| format!("Invalid bundle ledger {}: {error}", ledger_path.display()))
+  }
+
+  pub fn save(&self, jobs_root_directory_path: &Path) -> Result<(), String> {
+    fs::create_dir_all(jobs_root_directory_path).map_err(|error| error.to_string())?;
+    let serialized = serde_json::to_string_pretty(self).map_err(|error| error.to_string())?;
+    fs::write(bundle_ledger_file_path(jobs_root_directory_path), serialized).map_err(|error| error.to_string())
+  }
+
+  // NOTE: Entries whose job root was deleted (e.g. by retention cleanup) no longer count, so the bundle can run again.
+  pub fn find_live_entry(&self, jobs_root_directory_path: &Path, fingerprint: &str) -> Option<&BundleLedgerEntry> {
+    self
+      .entries
+      .iter()
+      .rev()
+      .find(|entry| entry.fingerprint == fingerprint && jobs_root_directory_path.join(&entry.job_id).is_dir())
+  }
+
+  pub fn record(&mut self, entry: BundleLedgerEntry) {
+    self.entries.push(entry);
+  }
+}
@@ -0,0 +1,97 @@
+/*!
+Responsibility:
+- Run a cloud connector's poll function on a background thread until it is stopped.
+- Keep the last poll error so connector status commands can surface it.
+*/
+
+use std::{
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+  },
+  thread,
+  time::{Duration, Instant},
+};
+
+const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Default)]
+pub(crate) struct ConnectorRuntimeState {
+  running_thread: Option<thread::JoinHandle<()>>,
+  stop_requested: Arc<AtomicBool>,
+  last_error_message: Option<String>,
+}
+
+pub type SharedConnectorRuntimeState = Arc<Mutex<ConnectorRuntimeState>>;
+pub type ConnectorPollFunction = Box<dyn FnMut() -> Result<(), String> + Send>;
+
+pub fn new_shared_connector_state() -> SharedConnectorRuntimeState {
+  Arc::new(Mutex::new(ConnectorRuntimeState::default()))
+}
+
+pub fn start_connector_polling(
+  state: &SharedConnectorRuntimeState,
+  connector_name: &str,
+  poll_interval: Duration,
+  mut poll_once: ConnectorPollFunction,
+) -> Result<(), String> {
+  let lock_error = || format!("{connector_name} connector state lock poisoned");
+  let stop_flag = {
+    let mut locked = state.lock().map_err(|_| lock_error())?;
+    if locked.running_thread.is_some() {
+      // Guard: prevent double-start.
+      return Err(format!("{connector_name} connector is already running."));
+    }
+    locked.stop_requested = Arc::new(AtomicBool::new(false));
+    locked.last_error_message = None;
+    locked.stop_requested.clone()
+  };
+
+  let shared_state_for_thread = state.clone();
+  let thread_handle = thread::spawn(move || {
+    while !stop_flag.load(Ordering::SeqCst) {
+      let poll_result = poll_once();
+      if let Ok(mut locked) = shared_state_for_thread.lock() {
+        // NOTE: Keep polling across transient API errors; the last error is surfaced in the status.
+        locked.last_error_message = poll_result.err();
+      }
+
+      let sleep_started_at = Instant::now();
+      while sleep_started_at.elapsed() < poll_interval && !stop_flag.load(Ordering::SeqCst) {
+        thread::sleep(STOP_CHECK_INTERVAL);
+      }
+    }
+  });
+
+  let mut locked = state.lock().map_err(|_| lock_error())?;
+  locked.running_thread = Some(thread_handle);
+  Ok(())
+}
+
+pub fn stop_connector(state: &SharedConnectorRuntimeState) {
+  let join_handle = {
+    let mut locked = match state.lock() {
+      Ok(value) => value,
+      Err(_) => return,
+    };
+    locked.stop_requested.store(true, Ordering::SeqCst);
+    locked.running_thread.take()
+  };
+  // Guard: join outside of lock to avoid deadlocks.
+  if let Some(handle) = join_handle {
+    let _ = handle.join();
+  }
+}
+
+pub fn record_connector_error(state: &SharedConnectorRuntimeState, message: String) {
+  if let Ok(mut locked) = state.lock() {
+    locked.last_error_message = Some(message);
+  }
+}
+
+pub fn read_connector_runtime_status(state: &SharedConnectorRuntimeState) -> (bool, Option<String>) {
+  match state.lock() {
+    Ok(locked) => (locked.running_thread.is_some(), locked.last_error_message.clone()),
+    Err(_) => (false, Some("Connector state lock poisoned".to_string())),
+  }
+}
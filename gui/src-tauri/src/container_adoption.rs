@@ -0,0 +1,90 @@
+/*!
+Responsibility:
+- Detect this compose project's running OCR containers (e.g. started by the CLI or before a GUI restart).
+- Follow an adopted container's output with `docker logs -f` so it can feed the per-root log buffer.
+*/
+
+use std::process::{Child, Command, Stdio};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::docker_cleanup::{parse_json_lines, project_label_filter, run_docker};
+
+const CONTAINER_DATA_MOUNT_DESTINATION: &str = "/data";
+// NOTE: Enough backlog to show what the container did before it was adopted without flooding the buffer.
+const ADOPTED_CONTAINER_LOG_TAIL_LINES: &str = "200";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectContainer {
+  pub container_id: String,
+  pub name: String,
+  pub service_name: Option<String>,
+  pub started_at: Option<String>,
+  // NOTE: Host path of the `/data` bind mount as Docker reports it; on Docker Desktop this is a VM path.
+  pub data_mount_source: Option<String>,
+}
+
+fn inspect_project_container(container_id: &str) -> Result<ProjectContainer, String> {
+  let inspected: Value =
+    serde_json::from_str(&run_docker(&["container", "inspect", container_id])?).map_err(|error| error.to_string())?;
+  let container = inspected
+    .get(0)
+    .ok_or_else(|| format!("docker container inspect returned nothing for {container_id}"))?;
+  let data_mount_source = container
+    .get("Mounts")
+    .and_then(Value::as_array)
+    .and_then(|mounts| {
+      mounts
+        .iter()
+        .find(|mount| mount.get("Destination").and_then(Value::as_str) == Some(CONTAINER_DATA_MOUNT_DESTINATION))
+    })
+    .and_then(|mount| mount.get("Source"))
+    .and_then(Value::as_str)
+    .map(str::to_string);
+  Ok(ProjectContainer {
+    container_id: container_id.to_string(),
+    name: container
+      .get("Name")
+      .and_then(Value::as_str)
+      .unwrap_or_default()
+      .trim_start_matches('/')
+      .to_string(),
+    service_name: container
+      .pointer("/Config/Labels/com.docker.compose.service")
+      .and_then(Value::as_str)
+      .map(str::to_string),
+    started_at: container.pointer("/State/StartedAt").and_then(Value::as_str).map(str::to_string),
+    data_mount_source,
+  })
+}
+
+pub fn list_running_project_containers(project_name: &str) -> Result<Vec<ProjectContainer>, String> {
+  let label_filter = project_label_filter(project_name);
+  let listed = parse_json_lines(&run_docker(&[
+    "container",
+    "ls",
+    "--filter",
+    &label_filter,
+    "--format",
+    "{{json .}}",
+  ])?);
+  listed
+    .iter()
+    .filter_map(|entry| entry.get("ID").and_then(Value::as_str))
+    .map(inspect_project_container)
+    .collect()
+}
+
+pub fn spawn_container_log_follower(container_id: &str) -> Result<Child, String> {
+  Command::new("docker")
+    .arg("logs")
+    .arg("--follow")
+    .arg("--tail")
+    .arg(ADOPTED_CONTAINER_LOG_TAIL_LINES)
+    .arg(container_id)
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .map_err(|error| format!("Failed to run docker logs. {error}"))
+}
@@ -0,0 +1,192 @@
+/*!
+Responsibility:
+- Check the watcher's jobs root against a free-space watermark, an optional size quota and a cap on queued jobs.
+- Remove the oldest finished job roots (retention cleanup) until the jobs root is back under its limits.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+pub const DISK_WATERMARK_SETTINGS_FILENAME: &str = "disk_watermarks.json";
+
+// NOTE: Walking a large jobs root every poll would cost more than it protects; free space itself is cheap to read.
+const JOBS_ROOT_SIZE_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+// NOTE: Counting reads every job's job_state.json; the watcher drops the cached count whenever it creates a job.
+const QUEUED_JOB_COUNT_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DiskWatermarkSettings {
+  // NOTE: Intake pauses when free space on the jobs root's volume drops below this.
+  pub min_free_bytes: Option<u64>,
+  // NOTE: Intake resumes only above this (defaults to min_free_bytes) so it does not flap around the threshold.
+  pub resume_free_bytes: Option<u64>,
+  // NOTE: Quota for the jobs root itself, checked against its total file size.
+  pub max_jobs_root_bytes: Option<u64>,
+  // NOTE: Intake pauses while this many watcher jobs wait in `queued` (e.g. with auto-run off), until some start.
+  #[serde(default)]
+  pub max_queued_jobs: Option<usize>,
+  #[serde(default)]
+  pub is_retention_cleanup_enabled: bool,
+  // NOTE: Finished jobs younger than this are never removed by retention cleanup.
+  pub retention_min_age_hours: Option<u64>,
+}
+
+impl DiskWatermarkSettings {
+  pub fn is_enabled(&self) -> bool {
+    self.min_free_bytes.is_some() || self.max_jobs_root_bytes.is_some() || self.max_queued_jobs.is_some()
+  }
+
+  pub fn validate(&self) -> Result<(), String> {
+    if let (Some(min_free_bytes), Some(resume_free_bytes)) = (self.min_free_bytes, self.resume_free_bytes) {
+      if resume_free_bytes < min_free_bytes {
+        return Err("resume_free_bytes must be >= min_free_bytes.".to_string());
+      }
+    }
+    if self.resume_free_bytes.is_some() && self.min_free_bytes.is_none() {
+      return Err("resume_free_bytes requires min_free_bytes.".to_string());
+    }
+    if self.max_jobs_root_bytes == Some(0) {
+      return Err("max_jobs_root_bytes must be > 0.".to_string());
+    }
+    if self.max_queued_jobs == Some(0) {
+      return Err("max_queued_jobs must be > 0.".to_string());
+    }
+    Ok(())
+  }
+
+  pub fn retention_min_age(&self) -> Duration {
+    Duration::from_secs(self.retention_min_age_hours.unwrap_or(0).saturating_mul(3600))
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DiskUsageSnapshot {
+  pub free_bytes: u64,
+  pub jobs_root_bytes: Option<u64>,
+}
+
+#[derive(Default)]
+pub struct DiskWatermarkMonitor {
+  cached_jobs_root_bytes: Option<(Instant, u64)>,
+  cached_queued_job_count: Option<(Instant, usize)>,
+}
+
+fn measure_directory_bytes(directory_path: &Path) -> u64 {
+  walkdir::WalkDir::new(directory_path)
+    .into_iter()
+    .filter_map(|entry| entry.ok())
+    .filter_map(|entry| entry.metadata().ok())
+    .filter(|metadata| metadata.is_file())
+    .map(|metadata| metadata.len())
+    .sum()
+}
+
+impl DiskWatermarkMonitor {
+  pub fn invalidate(&mut self) {
+    self.cached_jobs_root_bytes = None;
+    self.cached_queued_job_count = None;
+  }
+
+  pub fn count_queued_jobs(&mut self, settings: &DiskWatermarkSettings, count: impl FnOnce() -> usize) -> Option<usize> {
+    settings.max_queued_jobs?;
+    Some(match self.cached_queued_job_count {
+      Some((counted_at, queued_job_count)) if counted_at.elapsed() < QUEUED_JOB_COUNT_REFRESH_INTERVAL => queued_job_count,
+      _ => {
+        let queued_job_count = count();
+        self.cached_queued_job_count = Some((Instant::now(), queued_job_count));
+        queued_job_count
+      }
+    })
+  }
+
+  pub fn measure(&mut self, jobs_root_directory_path: &Path, settings: &DiskWatermarkSettings) -> Result<DiskUsageSnapshot, String> {
+    // Guard: the jobs root may not exist before the first job; its volume is what matters.
+    let existing_path = jobs_root_directory_path
+      .ancestors()
+      .find(|path| path.exists())
+      .unwrap_or(jobs_root_directory_path);
+    let free_bytes = fs4::available_space(existing_path)
+      .map_err(|error| format!("Failed to read free space for {}: {error}", existing_path.display()))?;
+    let jobs_root_bytes = match settings.max_jobs_root_bytes {
+      None => None,
+      Some(_) => Some(match self.cached_jobs_root_bytes {
+        Some((measured_at, bytes)) if measured_at.elapsed() < JOBS_ROOT_SIZE_REFRESH_INTERVAL => bytes,
+        _ => {
+          let bytes = measure_directory_bytes(jobs_root_directory_path);
+          self.cached_jobs_root_bytes = Some((Instant::now(), bytes));
+          bytes
+        }
+      }),
+    };
+    Ok(DiskUsageSnapshot {
+      free_bytes,
+      jobs_root_bytes,
+    })
+  }
+}
+
+// NOTE: While paused, the resume threshold applies instead of the pause threshold (hysteresis).
+pub fn describe_watermark_breach(
+  snapshot: &DiskUsageSnapshot,
+  settings: &DiskWatermarkSettings,
+  is_intake_paused: bool,
+) -> Option<String> {
+  let free_threshold = if is_intake_paused {
+    settings.resume_free_bytes.or(settings.min_free_bytes)
+  } else {
+    settings.min_free_bytes
+  };
+  if let Some(free_threshold) = free_threshold {
+    if snapshot.free_bytes < free_threshold {
+      return Some(format!(
+        "Free disk space is low ({} bytes free, watermark {free_threshold} bytes).",
+        snapshot.free_bytes
+      ));
+    }
+  }
+  if let (Some(jobs_root_bytes), Some(max_jobs_root_bytes)) = (snapshot.jobs_root_bytes, settings.max_jobs_root_bytes) {
+    if jobs_root_bytes > max_jobs_root_bytes {
+      return Some(format!(
+        "Jobs root uses {jobs_root_bytes} bytes, over its quota of {max_jobs_root_bytes} bytes."
+      ));
+    }
+  }
+  None
+}
+
+// NOTE: Kept apart from the disk checks, because removing finished jobs cannot shorten the queue.
+pub fn describe_queued_job_breach(queued_job_count: Option<usize>, settings: &DiskWatermarkSettings) -> Option<String> {
+  let (queued_job_count, max_queued_jobs) = (queued_job_count?, settings.max_queued_jobs?);
+  (queued_job_count >= max_queued_jobs).then(|| {
+    format!("{queued_job_count} watcher job(s) are queued, at the limit of {max_queued_jobs}; start or remove some to resume.")
+  })
+}
+
+// NOTE: `candidates` must be finished job roots ordered oldest first; running jobs must never be passed in.
+pub fn remove_jobs_for_retention(
+  monitor: &mut DiskWatermarkMonitor,
+  jobs_root_directory_path: &Path,
+  settings: &DiskWatermarkSettings,
+  candidates: &[PathBuf],
+) -> Result<Vec<PathBuf>, String> {
+  let mut removed_job_roots: Vec<PathBuf> = vec![];
+  for candidate in candidates {
+    let snapshot = monitor.measure(jobs_root_directory_path, settings)?;
+    if describe_watermark_breach(&snapshot, settings, true).is_none() {
+      break;
+    }
+    if !candidate.starts_with(jobs_root_directory_path) || candidate == jobs_root_directory_path {
+      // Guard: cleanup only ever deletes job roots inside the watcher's jobs root.
+      continue;
+    }
+    fs::remove_dir_all(candidate).map_err(|error| format!("Failed to remove {}: {error}", candidate.display()))?;
+    monitor.invalidate();
+    removed_job_roots.push(candidate.clone());
+  }
+  Ok(removed_job_roots)
+}
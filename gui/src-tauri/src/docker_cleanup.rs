@@ -0,0 +1,196 @@
+/*!
+Responsibility:
+- Report Docker disk usage (`docker system df`) plus what belongs to this compose project.
+- Prune only this project's stopped containers, dangling images and (optionally) volumes.
+*/
+
+use std::{
+  process::{Command, Output, Stdio},
+  time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::probe_timeout::output_with_timeout;
+
+const COMPOSE_PROJECT_LABEL: &str = "com.docker.compose.project";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DockerDiskUsageEntry {
+  pub kind: String,
+  pub total_count: String,
+  pub active_count: String,
+  pub size: String,
+  pub reclaimable: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DockerProjectImage {
+  pub reference: String,
+  pub image_id: String,
+  pub size: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DockerDiskUsage {
+  pub project_name: String,
+  // NOTE: System-wide totals; Docker cannot break these down per project.
+  pub entries: Vec<DockerDiskUsageEntry>,
+  pub project_images: Vec<DockerProjectImage>,
+  pub project_volume_names: Vec<String>,
+  pub project_stopped_container_count: usize,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DockerPruneOptions {
+  // NOTE: Removes the Hugging Face cache volume too, so the next run re-downloads the model.
+  #[serde(default)]
+  pub is_volume_removal_enabled: bool,
+  // NOTE: The build cache is shared by every project on this Docker host; pruning it is opt-in.
+  #[serde(default)]
+  pub is_build_cache_prune_enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DockerPruneReport {
+  pub messages: Vec<String>,
+}
+
+fn docker_stdout(arguments: &[&str], output: Output) -> Result<String, String> {
+  if !output.status.success() {
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    return Err(format!("docker {} failed.\n{stderr}", arguments.join(" ")));
+  }
+  Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+pub(crate) fn run_docker(arguments: &[&str]) -> Result<String, String> {
+  let output = Command::new("docker")
+    .args(arguments)
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .output()
+    .map_err(|error| format!("Failed to run docker {}. {error}", arguments.join(" ")))?;
+  docker_stdout(arguments, output)
+}
+
+// NOTE: For commands a GUI action waits on (`pause`, `stop`, ...), so a wedged engine cannot hold the action forever.
+pub(crate) fn run_docker_with_timeout(arguments: &[&str], timeout: Duration) -> Result<String, String> {
+  let output = output_with_timeout(Command::new("docker").args(arguments), timeout)?;
+  docker_stdout(arguments, output)
+}
+
+// NOTE: `--format "{{json .}}"` prints one JSON object per line rather than a JSON array.
+pub(crate) fn parse_json_lines(raw: &str) -> Vec<Value> {
+  raw
+    .lines()
+    .filter_map(|line| serde_json::from_str::<Value>(line.trim()).ok())
+    .collect()
+}
+
+fn json_string_field(value: &Value, key: &str) -> String {
+  match value.get(key) {
+    Some(Value::String(text)) => text.clone(),
+    Some(Value::Number(number)) => number.to_string(),
+    _ => String::new(),
+  }
+}
+
+pub(crate) fn project_label_filter(project_name: &str) -> String {
+  format!("label={COMPOSE_PROJECT_LABEL}={project_name}")
+}
+
+fn list_project_volume_names(project_name: &str) -> Result<Vec<String>, String> {
+  let label_filter = project_label_filter(project_name);
+  let raw = run_docker(&["volume", "ls", "--quiet", "--filter", &label_filter])?;
+  Ok(raw.lines().map(str::trim).filter(|name| !name.is_empty()).map(str::to_string).collect())
+}
+
+pub fn read_docker_disk_usage(project_name: &str) -> Result<DockerDiskUsage, String> {
+  let label_filter = project_label_filter(project_name);
+  let entries = parse_json_lines(&run_docker(&["system", "df", "--format", "{{json .}}"])?)
+    .iter()
+    .map(|entry| DockerDiskUsageEntry {
+      kind: json_string_field(entry, "Type"),
+      total_count: json_string_field(entry, "TotalCount"),
+      active_count: json_string_field(entry, "Active"),
+      size: json_string_field(entry, "Size"),
+      reclaimable: json_string_field(entry, "Reclaimable"),
+    })
+    .collect();
+  let project_images = parse_json_lines(&run_docker(&[
+    "image",
+    "ls",
+    "--all",
+    "--filter",
+    &label_filter,
+    "--format",
+    "{{json .}}",
+  ])?)
+  .iter()
+  .map(|image| DockerProjectImage {
+    reference: format!("{}:{}", json_string_field(image, "Repository"), json_string_field(image, "Tag")),
+    image_id: json_string_field(image, "ID"),
+    size: json_string_field(image, "Size"),
+  })
+  .collect();
+  let project_stopped_container_count = run_docker(&[
+    "container",
+    "ls",
+    "--all",
+    "--quiet",
+    "--filter",
+    &label_filter,
+    "--filter",
+    "status=exited",
+  ])?
+  .lines()
+  .filter(|line| !line.trim().is_empty())
+  .count();
+
+  Ok(DockerDiskUsage {
+    project_name: project_name.to_string(),
+    entries,
+    project_images,
+    project_volume_names: list_project_volume_names(project_name)?,
+    project_stopped_container_count,
+  })
+}
+
+fn last_non_empty_line(raw: &str) -> Option<String> {
+  raw.lines().map(str::trim).rfind(|line| !line.is_empty()).map(str::to_string)
+}
+
+pub fn prune_project_docker_artifacts(project_name: &str, options: &DockerPruneOptions) -> Result<DockerPruneReport, String> {
+  let label_filter = project_label_filter(project_name);
+  let mut messages: Vec<String> = vec![];
+
+  let container_output = run_docker(&["container", "prune", "--force", "--filter", &label_filter])?;
+  messages.push(format!(
+    "containers: {}",
+    last_non_empty_line(&container_output).unwrap_or_default()
+  ));
+  // NOTE: Without `--all`, only dangling images are removed, i.e. the layers left behind by earlier rebuilds.
+  let image_output = run_docker(&["image", "prune", "--force", "--filter", &label_filter])?;
+  messages.push(format!("images: {}", last_non_empty_line(&image_output).unwrap_or_default()));
+
+  if options.is_volume_removal_enabled {
+    for volume_name in list_project_volume_names(project_name)? {
+      // Guard: a volume still attached to a container fails to remove; report it instead of aborting the rest.
+      match run_docker(&["volume", "rm", &volume_name]) {
+        Ok(_) => messages.push(format!("volume removed: {volume_name}")),
+        Err(error) => messages.push(format!("volume kept: {volume_name} ({})", error.trim())),
+      }
+    }
+  }
+  if options.is_build_cache_prune_enabled {
+    let build_cache_output = run_docker(&["builder", "prune", "--force"])?;
+    messages.push(format!(
+      "build cache: {}",
+      last_non_empty_line(&build_cache_output).unwrap_or_default()
+    ));
+  }
+
+  Ok(DockerPruneReport { messages })
+}
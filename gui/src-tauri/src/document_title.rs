@@ -0,0 +1,154 @@
+/*!
+Responsibility:
+- Detect a document title in OCR markdown (first real H1, else H2, else a short leading line).
+- Turn a title into a safe output filename stem, so timestamped names can be replaced by meaningful ones.
+*/
+
+use serde::{Deserialize, Serialize};
+
+// NOTE: Lines the merge itself writes; they describe the run, not the document.
+const MERGED_MARKDOWN_TITLE_LINE: &str = "# OCR Output";
+const PARTIAL_OUTPUT_BANNER_PREFIX: &str = "> **Partial output (run cancelled)**";
+const MAX_TITLE_CHARACTERS: usize = 120;
+const MAX_TITLE_FILENAME_STEM_CHARACTERS: usize = 80;
+// NOTE: Only the start of the document is searched; a heading on page 12 is a section, not the title.
+const MAX_TITLE_SEARCH_LINES: usize = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentTitleSource {
+  Heading1,
+  Heading2,
+  // NOTE: A short leading plain-text line; least reliable, which is why titles are only applied on request.
+  FirstLine,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetectedDocumentTitle {
+  pub title: String,
+  pub source: DocumentTitleSource,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AppliedDocumentTitle {
+  pub detected_title: DetectedDocumentTitle,
+  pub previous_output_markdown_path: String,
+  pub output_markdown_path: String,
+  // NOTE: False when the output already had the title's name.
+  pub is_renamed: bool,
+}
+
+fn strip_inline_markdown(text: &str) -> String {
+  let mut plain = String::new();
+  let mut characters = text.chars().peekable();
+  while let Some(character) = characters.next() {
+    match character {
+      '*' | '_' | '`' => {}
+      // NOTE: `[label](url)` keeps only the label.
+      ']' if characters.peek() == Some(&'(') => {
+        for skipped in characters.by_ref() {
+          if skipped == ')' {
+            break;
+          }
+        }
+      }
+      '[' | ']' => {}
+      _ => plain.push(character),
+    }
+  }
+  plain.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+fn is_page_source_heading(heading_text: &str) -> bool {
+  // NOTE: The merge's page headings are `## <source path>` or `## <source path> (page n/total)`.
+  heading_text.starts_with('/') || heading_text.contains(":\\") || (heading_text.ends_with(')') && heading_text.contains("(page "))
+}
+
+fn is_usable_title(title: &str) -> bool {
+  let letter_count = title.chars().filter(|character| character.is_alphabetic()).count();
+  letter_count >= 2 && title.chars().count() <= MAX_TITLE_CHARACTERS
+}
+
+pub fn detect_document_title(markdown: &str) -> Option<DetectedDocumentTitle> {
+  let mut heading2_title: Option<String> = None;
+  let mut first_line_title: Option<String> = None;
+  let mut is_inside_fenced_code_block = false;
+  for line in markdown.lines().take(MAX_TITLE_SEARCH_LINES) {
+    let trimmed = line.trim();
+    if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+      is_inside_fenced_code_block = !is_inside_fenced_code_block;
+      continue;
+    }
+    let is_skipped_line = is_inside_fenced_code_block
+      || trimmed.is_empty()
+      || trimmed == MERGED_MARKDOWN_TITLE_LINE
+      || trimmed.starts_with(PARTIAL_OUTPUT_BANNER_PREFIX)
+      || trimmed.starts_with("<!--")
+      || trimmed.starts_with("---");
+    if is_skipped_line {
+      continue;
+    }
+    if let Some(heading_text) = trimmed.strip_prefix("# ") {
+      let title = strip_inline_markdown(heading_text);
+      if is_usable_title(&title) {
+        return Some(DetectedDocumentTitle {
+          title,
+          source: DocumentTitleSource::Heading1,
+        });
+      }
+      continue;
+    }
+    if let Some(heading_text) = trimmed.strip_prefix("## ") {
+      let title = strip_inline_markdown(heading_text);
+      if heading2_title.is_none() && !is_page_source_heading(heading_text.trim()) && is_usable_title(&title) {
+        heading2_title = Some(title);
+      }
+      continue;
+    }
+    let is_structural_line = trimmed.starts_with('#')
+      || trimmed.starts_with('|')
+      || trimmed.starts_with('<')
+      || trimmed.starts_with('>')
+      || trimmed.starts_with('!')
+      || trimmed.starts_with('$')
+      || trimmed.starts_with("- ")
+      || trimmed.starts_with("* ");
+    if first_line_title.is_none() && !is_structural_line {
+      let title = strip_inline_markdown(trimmed);
+      // Guard: a long first line is body text, not a title.
+      if is_usable_title(&title) && !title.ends_with('.') && !title.ends_with('。') {
+        first_line_title = Some(title);
+      }
+    }
+  }
+  heading2_title
+    .map(|title| DetectedDocumentTitle {
+      title,
+      source: DocumentTitleSource::Heading2,
+    })
+    .or_else(|| {
+      first_line_title.map(|title| DetectedDocumentTitle {
+        title,
+        source: DocumentTitleSource::FirstLine,
+      })
+    })
+}
+
+// NOTE: Keeps letters and digits of any script (e.g. Japanese titles); everything else collapses into `_`.
+pub fn title_to_filename_stem(title: &str) -> Option<String> {
+  let mut stem = String::new();
+  for character in title.chars() {
+    if character.is_alphanumeric() || character == '-' {
+      stem.push(character);
+    } else if !stem.ends_with('_') {
+      stem.push('_');
+    }
+  }
+  let stem: String = stem
+    .trim_matches('_')
+    .chars()
+    .take(MAX_TITLE_FILENAME_STEM_CHARACTERS)
+    .collect();
+  let stem = stem.trim_end_matches('_').to_string();
+  (!stem.is_empty()).then_some(stem)
+}
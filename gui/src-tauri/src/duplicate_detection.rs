@@ -0,0 +1,183 @@
+/*!
+Responsibility:
+- Fingerprint job inputs (SHA-256) and OCR text (64-bit simhash) across a jobs root.
+- Group exact input duplicates and near-duplicate documents so re-scans are flagged instead of double-filed.
+*/
+
+use std::{
+  collections::BTreeMap,
+  fs::File,
+  io::Read,
+  path::{Path, PathBuf},
+};
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::output_documents::DocumentMarkdown;
+
+const SIMHASH_SHINGLE_CHARACTERS: usize = 3;
+const FILE_HASH_BUFFER_BYTES: usize = 1024 * 1024;
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+const MIN_SIMHASH_TEXT_CHARACTERS: usize = 40;
+
+pub struct JobFingerprintSource {
+  pub job_root_directory_path: PathBuf,
+  pub input_file_paths: Vec<PathBuf>,
+  pub documents: Vec<DocumentMarkdown>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateMatchKind {
+  IdenticalInput,
+  SimilarText,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateDocumentMember {
+  pub job_root_directory_path: String,
+  pub document_path: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateDocumentGroup {
+  pub match_kind: DuplicateMatchKind,
+  pub fingerprint: String,
+  pub members: Vec<DuplicateDocumentMember>,
+}
+
+pub fn sha256_file_hex(file_path: &Path) -> Result<String, String> {
+  let mut file = File::open(file_path).map_err(|error| format!("Failed to open {}: {error}", file_path.display()))?;
+  let mut hasher = Sha256::new();
+  let mut buffer = vec![0u8; FILE_HASH_BUFFER_BYTES];
+  loop {
+    let read_bytes = file.read(&mut buffer).map_err(|error| error.to_string())?;
+    if read_bytes == 0 {
+      break;
+    }
+    hasher.update(&buffer[..read_bytes]);
+  }
+  Ok(
+    hasher
+      .finalize()
+      .iter()
+      .map(|byte| format!("{byte:02x}"))
+      .collect(),
+  )
+}
+
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+  let mut hash = FNV_OFFSET_BASIS;
+  for byte in bytes {
+    hash ^= u64::from(*byte);
+    hash = hash.wrapping_mul(FNV_PRIME);
+  }
+  hash
+}
+
+fn normalize_text_for_fingerprint(text: &str) -> Vec<char> {
+  // NOTE: Character shingles work for both space-separated and CJK text.
+  text
+    .chars()
+    .filter(|character| character.is_alphanumeric())
+    .flat_map(|character| character.to_lowercase())
+    .collect()
+}
+
+pub fn compute_text_simhash(text: &str) -> Option<u64> {
+  let characters = normalize_text_for_fingerprint(text);
+  if characters.len() < MIN_SIMHASH_TEXT_CHARACTERS {
+    // Guard: near-empty pages (blank scans) would all look identical.
+    return None;
+  }
+
+  let mut weights = [0i64; 64];
+  for shingle in characters.windows(SIMHASH_SHINGLE_CHARACTERS) {
+    let shingle_string: String = shingle.iter().collect();
+    let hash = fnv1a_64(shingle_string.as_bytes());
+    for (bit_index, weight) in weights.iter_mut().enumerate() {
+      if hash & (1u64 << bit_index) != 0 {
+        *weight += 1;
+      } else {
+        *weight -= 1;
+      }
+    }
+  }
+
+  let mut simhash = 0u64;
+  for (bit_index, weight) in weights.iter().enumerate() {
+    if *weight > 0 {
+      simhash |= 1u64 << bit_index;
+    }
+  }
+  Some(simhash)
+}
+
+fn group_identical_inputs(sources: &[JobFingerprintSource]) -> Result<Vec<DuplicateDocumentGroup>, String> {
+  let mut members_by_hash: BTreeMap<String, Vec<DuplicateDocumentMember>> = BTreeMap::new();
+  for source in sources {
+    for input_file_path in &source.input_file_paths {
+      let hash = sha256_file_hex(input_file_path)?;
+      members_by_hash.entry(hash).or_default().push(DuplicateDocumentMember {
+        job_root_directory_path: source.job_root_directory_path.to_string_lossy().to_string(),
+        document_path: input_file_path.to_string_lossy().to_string(),
+      });
+    }
+  }
+
+  Ok(
+    members_by_hash
+      .into_iter()
+      .filter(|(_, members)| members.len() > 1)
+      .map(|(hash, members)| DuplicateDocumentGroup {
+        match_kind: DuplicateMatchKind::IdenticalInput,
+        fingerprint: format!("sha256:{hash}"),
+        members,
+      })
+      .collect(),
+  )
+}
+
+fn group_similar_texts(sources: &[JobFingerprintSource], max_hamming_distance: u32) -> Vec<DuplicateDocumentGroup> {
+  // NOTE: Greedy clustering against each cluster's first member; good enough for hundreds of jobs.
+  let mut clusters: Vec<(u64, Vec<DuplicateDocumentMember>)> = vec![];
+  for source in sources {
+    for document in &source.documents {
+      let Some(simhash) = compute_text_simhash(&document.markdown) else {
+        continue;
+      };
+      let member = DuplicateDocumentMember {
+        job_root_directory_path: source.job_root_directory_path.to_string_lossy().to_string(),
+        document_path: document.source_path.clone(),
+      };
+      let existing_cluster = clusters
+        .iter_mut()
+        .find(|(representative, _)| (representative ^ simhash).count_ones() <= max_hamming_distance);
+      match existing_cluster {
+        Some((_, members)) => members.push(member),
+        None => clusters.push((simhash, vec![member])),
+      }
+    }
+  }
+
+  clusters
+    .into_iter()
+    .filter(|(_, members)| members.len() > 1)
+    .map(|(simhash, members)| DuplicateDocumentGroup {
+      match_kind: DuplicateMatchKind::SimilarText,
+      fingerprint: format!("simhash:{simhash:016x}"),
+      members,
+    })
+    .collect()
+}
+
+pub fn find_duplicate_documents(
+  sources: &[JobFingerprintSource],
+  max_hamming_distance: u32,
+) -> Result<Vec<DuplicateDocumentGroup>, String> {
+  let mut groups = group_identical_inputs(sources)?;
+  groups.extend(group_similar_texts(sources, max_hamming_distance));
+  Ok(groups)
+}
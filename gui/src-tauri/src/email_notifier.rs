@@ -0,0 +1,132 @@
+/*!
+Responsibility:
+- Send job completion emails over SMTP, optionally attaching the output markdown.
+- Keep SMTP passwords in the OS keychain instead of job settings files.
+*/
+
+use std::{fs, path::Path};
+
+use lettre::{
+  message::{header::ContentType, Attachment, Mailbox, MultiPart, SinglePart},
+  transport::smtp::authentication::Credentials,
+  Message, SmtpTransport, Transport,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::notifications::{JobNotification, Notifier};
+
+const SMTP_KEYCHAIN_SERVICE_NAME: &str = "ocr-agent-smtp";
+const DEFAULT_SMTP_PORT: u16 = 587;
+const MAX_EMAIL_ATTACHMENT_BYTES: u64 = 10_000_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailNotificationSettings {
+  pub smtp_host: String,
+  pub smtp_port: Option<u16>,
+  // NOTE: The password is looked up in the keychain under this username and host.
+  pub username: String,
+  pub from_address: String,
+  pub to_addresses: Vec<String>,
+  #[serde(default)]
+  pub is_markdown_attached: bool,
+}
+
+fn keychain_account_name(smtp_host: &str, username: &str) -> String {
+  format!("{username}@{smtp_host}")
+}
+
+fn keychain_entry(smtp_host: &str, username: &str) -> Result<keyring::Entry, String> {
+  keyring::Entry::new(SMTP_KEYCHAIN_SERVICE_NAME, &keychain_account_name(smtp_host, username))
+    .map_err(|error| format!("Keychain is unavailable: {error}"))
+}
+
+pub fn store_smtp_password(smtp_host: &str, username: &str, password: &str) -> Result<(), String> {
+  keychain_entry(smtp_host, username)?
+    .set_password(password)
+    .map_err(|error| format!("Failed to store SMTP password in keychain: {error}"))
+}
+
+fn read_smtp_password(smtp_host: &str, username: &str) -> Result<String, String> {
+  keychain_entry(smtp_host, username)?
+    .get_password()
+    .map_err(|error| format!("SMTP password for {username} is not in the keychain: {error}"))
+}
+
+fn parse_mailbox(address: &str) -> Result<Mailbox, String> {
+  address
+    .trim()
+    .parse::<Mailbox>()
+    .map_err(|error| format!("Invalid email address {address}: {error}"))
+}
+
+fn build_markdown_attachment(markdown_path: &Path) -> Result<SinglePart, String> {
+  let metadata = fs::metadata(markdown_path).map_err(|error| error.to_string())?;
+  if metadata.len() > MAX_EMAIL_ATTACHMENT_BYTES {
+    // Guard: most SMTP relays reject large messages; the body still links to the file path.
+    return Err(format!("Output markdown is too large to attach ({} bytes).", metadata.len()));
+  }
+  let bytes = fs::read(markdown_path).map_err(|error| error.to_string())?;
+  let filename = markdown_path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .unwrap_or("output.md")
+    .to_string();
+  let content_type = ContentType::parse("text/markdown; charset=utf-8").map_err(|error| error.to_string())?;
+  Ok(Attachment::new(filename).body(bytes, content_type))
+}
+
+pub struct EmailNotifier {
+  pub settings: EmailNotificationSettings,
+}
+
+impl EmailNotifier {
+  fn build_message(&self, notification: &JobNotification) -> Result<Message, String> {
+    if self.settings.to_addresses.is_empty() {
+      // Guard: lettre would fail later with a less helpful message.
+      return Err("Email notification has no recipients.".to_string());
+    }
+    let mut builder = Message::builder()
+      .from(parse_mailbox(&self.settings.from_address)?)
+      .subject(notification.title());
+    for to_address in &self.settings.to_addresses {
+      builder = builder.to(parse_mailbox(to_address)?);
+    }
+
+    let body = SinglePart::plain(notification.detail_lines().join("\n"));
+    let attachment_path = notification
+      .output_markdown_path
+      .as_deref()
+      .filter(|_| self.settings.is_markdown_attached)
+      .map(Path::new)
+      .filter(|path| path.exists());
+    let message = match attachment_path {
+      Some(markdown_path) => builder.multipart(
+        MultiPart::mixed()
+          .singlepart(body)
+          .singlepart(build_markdown_attachment(markdown_path)?),
+      ),
+      None => builder.singlepart(body),
+    };
+    message.map_err(|error| format!("Failed to build email: {error}"))
+  }
+}
+
+impl Notifier for EmailNotifier {
+  fn channel_name(&self) -> &'static str {
+    "email"
+  }
+
+  fn send(&self, notification: &JobNotification) -> Result<(), String> {
+    let message = self.build_message(notification)?;
+    let password = read_smtp_password(&self.settings.smtp_host, &self.settings.username)?;
+    let transport = SmtpTransport::starttls_relay(&self.settings.smtp_host)
+      .map_err(|error| format!("Invalid SMTP host {}: {error}", self.settings.smtp_host))?
+      .port(self.settings.smtp_port.unwrap_or(DEFAULT_SMTP_PORT))
+      .credentials(Credentials::new(self.settings.username.clone(), password))
+      .build();
+    transport
+      .send(&message)
+      .map_err(|error| format!("SMTP send failed: {error}"))?;
+    Ok(())
+  }
+}
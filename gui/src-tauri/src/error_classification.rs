@@ -0,0 +1,104 @@
+/*!
+Responsibility:
+- Classify a failed run's container output into typed causes (CUDA out of memory, driver mismatch, model download failure,
+  Docker engine disconnect).
+- Pick the smaller inference image size used when an out-of-memory failure is retried automatically.
+*/
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobErrorCategory {
+  CudaOutOfMemory,
+  CudaDriverMismatch,
+  ModelDownloadFailed,
+  DockerEngineDisconnected,
+}
+
+// NOTE: Matched case-insensitively against stderr lines and the queue's last task error (a Python `repr`).
+const CUDA_OUT_OF_MEMORY_PATTERNS: &[&str] = &["cuda out of memory", "outofmemoryerror", "cublas_status_alloc_failed"];
+const CUDA_DRIVER_MISMATCH_PATTERNS: &[&str] = &[
+  "cuda driver version is insufficient",
+  "driver on your system is too old",
+  "could not select device driver",
+  "no cuda-capable device",
+  "nvidia-container-cli",
+];
+const MODEL_DOWNLOAD_FAILED_PATTERNS: &[&str] = &[
+  "localentrynotfounderror",
+  "repositorynotfounderror",
+  "max retries exceeded with url",
+  "couldn't connect to 'https://huggingface.co'",
+];
+// NOTE: Printed by the compose client when the engine goes away (Docker Desktop restart, WSL shutdown, daemon crash).
+const DOCKER_ENGINE_DISCONNECTED_PATTERNS: &[&str] = &[
+  "cannot connect to the docker daemon",
+  "is the docker daemon running",
+  "error during connect",
+  "//./pipe/docker_engine",
+  "docker desktop is stopping",
+];
+
+// NOTE: Mirrors the CLI's `DEFAULT_INFERENCE_IMAGE_SIZE_PIXELS`, used when the job does not set a size.
+const CLI_DEFAULT_INFERENCE_IMAGE_SIZE_PIXELS: u32 = 768;
+// NOTE: Each step is tried once, so an OOM run is retried at most twice.
+const OOM_RETRY_INFERENCE_IMAGE_SIZES_PIXELS: &[u32] = &[640, 512];
+
+#[derive(Debug, Clone)]
+pub struct ClassifiedJobError {
+  pub category: JobErrorCategory,
+  pub matched_line: String,
+}
+
+impl JobErrorCategory {
+  fn patterns(self) -> &'static [&'static str] {
+    match self {
+      JobErrorCategory::CudaOutOfMemory => CUDA_OUT_OF_MEMORY_PATTERNS,
+      JobErrorCategory::CudaDriverMismatch => CUDA_DRIVER_MISMATCH_PATTERNS,
+      JobErrorCategory::ModelDownloadFailed => MODEL_DOWNLOAD_FAILED_PATTERNS,
+      JobErrorCategory::DockerEngineDisconnected => DOCKER_ENGINE_DISCONNECTED_PATTERNS,
+    }
+  }
+
+  pub fn summary(self) -> &'static str {
+    match self {
+      JobErrorCategory::CudaOutOfMemory => "GPU ran out of memory",
+      JobErrorCategory::CudaDriverMismatch => "NVIDIA driver/CUDA runtime mismatch or GPU not visible to the container",
+      JobErrorCategory::ModelDownloadFailed => "Model download from Hugging Face failed",
+      JobErrorCategory::DockerEngineDisconnected => "Docker engine disconnected (e.g. Docker Desktop restarted)",
+    }
+  }
+}
+
+// NOTE: Driver and download failures stop the run before any page is processed, so they win over a stray OOM line.
+// NOTE: An engine disconnect kills every process at once, so it explains whatever else the log shows.
+pub fn classify_job_error(lines: &[String]) -> Option<ClassifiedJobError> {
+  let categories = [
+    JobErrorCategory::DockerEngineDisconnected,
+    JobErrorCategory::CudaDriverMismatch,
+    JobErrorCategory::ModelDownloadFailed,
+    JobErrorCategory::CudaOutOfMemory,
+  ];
+  categories.into_iter().find_map(|category| {
+    lines
+      .iter()
+      .rev()
+      .find(|line| {
+        let lowercase_line = line.to_lowercase();
+        category.patterns().iter().any(|pattern| lowercase_line.contains(pattern))
+      })
+      .map(|line| ClassifiedJobError {
+        category,
+        matched_line: line.trim().to_string(),
+      })
+  })
+}
+
+pub fn next_oom_retry_inference_image_size(configured_size_pixels: Option<u32>) -> Option<u32> {
+  let current_size_pixels = configured_size_pixels.unwrap_or(CLI_DEFAULT_INFERENCE_IMAGE_SIZE_PIXELS);
+  OOM_RETRY_INFERENCE_IMAGE_SIZES_PIXELS
+    .iter()
+    .copied()
+    .find(|size| *size < current_size_pixels)
+}
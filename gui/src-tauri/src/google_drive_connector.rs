@@ -0,0 +1,495 @@
+/*!
+Responsibility:
+- Sign in to Google Drive with the installed-app loopback OAuth flow (PKCE) and keep the refresh token in the OS keychain.
+- Follow the Drive changes feed and hand new documents in watched folders to a callback, each folder mapped to a preset.
+*/
+
+use std::{
+  collections::{hash_map::RandomState, BTreeSet},
+  fs,
+  hash::{BuildHasher, Hasher},
+  io::Read,
+  path::Path,
+  sync::Arc,
+  time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tiny_http::{Header, Response, Server};
+
+use crate::{
+  connector_runtime::{read_connector_runtime_status, SharedConnectorRuntimeState},
+  oauth_tokens::{
+    describe_oauth_error,
+    post_oauth_form,
+    read_refresh_token as read_keychain_refresh_token,
+    store_refresh_token as store_keychain_refresh_token,
+  },
+};
+
+pub const GOOGLE_DRIVE_CONNECTOR_SETTINGS_FILENAME: &str = "google_drive.json";
+pub const GOOGLE_DRIVE_CONNECTOR_STATE_FILENAME: &str = "google_drive_state.json";
+
+const GOOGLE_DRIVE_KEYCHAIN_SERVICE_NAME: &str = "ocr-agent-google-drive";
+const GOOGLE_AUTHORIZATION_URL: &str = "https://accounts.google.com/o/oauth2/v2/auth";
+const GOOGLE_TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+const DRIVE_API_BASE_URL: &str = "https://www.googleapis.com/drive/v3";
+const DRIVE_SCOPE: &str = "https://www.googleapis.com/auth/drive.readonly";
+const CHANGES_FIELDS: &str = "nextPageToken,newStartPageToken,changes(fileId,removed,file(id,name,parents,trashed))";
+const SIGN_IN_TIMEOUT: Duration = Duration::from_secs(300);
+const DEFAULT_POLL_INTERVAL_SECONDS: u64 = 60;
+const MAX_DOWNLOAD_BYTES: u64 = 200_000_000;
+// NOTE: Refresh a little early so a token never expires between listing and downloading.
+const ACCESS_TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(120);
+const SUPPORTED_DOCUMENT_EXTENSIONS: [&str; 8] = ["pdf", "png", "jpg", "jpeg", "webp", "bmp", "tif", "tiff"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoogleDriveWatchedFolder {
+  pub folder_id: String,
+  // NOTE: Name of a job settings preset applied to documents from this folder.
+  pub preset_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoogleDriveConnectorSettings {
+  // NOTE: OAuth client of type "Desktop app"; Google treats its secret as non-confidential.
+  pub client_id: String,
+  pub client_secret: Option<String>,
+  pub folders: Vec<GoogleDriveWatchedFolder>,
+  pub poll_interval_seconds: Option<u64>,
+}
+
+impl GoogleDriveConnectorSettings {
+  pub fn poll_interval(&self) -> Duration {
+    Duration::from_secs(self.poll_interval_seconds.unwrap_or(DEFAULT_POLL_INTERVAL_SECONDS).max(10))
+  }
+
+  fn client_secret(&self) -> &str {
+    self.client_secret.as_deref().map(str::trim).unwrap_or("")
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleDriveSignInPrompt {
+  pub authorization_url: String,
+}
+
+pub struct PendingGoogleDriveSignIn {
+  server: Server,
+  redirect_uri: String,
+  code_verifier: String,
+  state: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoteDriveFile {
+  pub file_id: String,
+  pub name: String,
+  pub folder: GoogleDriveWatchedFolder,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct GoogleDrivePersistentState {
+  page_token: Option<String>,
+  #[serde(default)]
+  processed_file_ids: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleDriveConnectorStatus {
+  pub is_running: bool,
+  pub is_signed_in: bool,
+  pub last_error_message: Option<String>,
+  pub processed_file_count: usize,
+}
+
+pub type GoogleDriveDocumentHandler = Arc<dyn Fn(&RemoteDriveFile, Vec<u8>) -> Result<(), String> + Send + Sync>;
+
+fn keychain_account_name(settings: &GoogleDriveConnectorSettings) -> String {
+  settings.client_id.trim().to_string()
+}
+
+fn read_refresh_token(settings: &GoogleDriveConnectorSettings) -> Result<String, String> {
+  read_keychain_refresh_token(GOOGLE_DRIVE_KEYCHAIN_SERVICE_NAME, &keychain_account_name(settings))
+    .ok_or_else(|| "Google Drive is not signed in.".to_string())
+}
+
+pub fn is_signed_in(settings: &GoogleDriveConnectorSettings) -> bool {
+  read_refresh_token(settings).is_ok()
+}
+
+fn random_token() -> String {
+  // NOTE: `RandomState` keys come from the OS RNG, which is enough for PKCE verifiers without another dependency.
+  let nanos = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_nanos())
+    .unwrap_or(0);
+  (0..4u64)
+    .map(|index| {
+      let mut hasher = RandomState::new().build_hasher();
+      hasher.write_u64(index);
+      hasher.write_u128(nanos);
+      format!("{:016x}", hasher.finish())
+    })
+    .collect()
+}
+
+fn base64_url_encode_without_padding(bytes: &[u8]) -> String {
+  const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+  let mut encoded = String::new();
+  for chunk in bytes.chunks(3) {
+    let padded = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+    let triple = (u32::from(padded[0]) << 16) | (u32::from(padded[1]) << 8) | u32::from(padded[2]);
+    for index in 0..=chunk.len() {
+      encoded.push(ALPHABET[((triple >> (18 - 6 * index)) & 0x3F) as usize] as char);
+    }
+  }
+  encoded
+}
+
+fn percent_encode_component(value: &str) -> String {
+  let mut encoded = String::new();
+  for byte in value.as_bytes() {
+    match byte {
+      b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(*byte as char),
+      _ => encoded.push_str(&format!("%{byte:02X}")),
+    }
+  }
+  encoded
+}
+
+fn percent_decode_component(value: &str) -> String {
+  let bytes = value.as_bytes();
+  let mut decoded: Vec<u8> = vec![];
+  let mut index = 0;
+  while index < bytes.len() {
+    match bytes[index] {
+      b'%' if index + 2 < bytes.len() => {
+        let hex_byte = std::str::from_utf8(&bytes[index + 1..index + 3])
+          .ok()
+          .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match hex_byte {
+          Some(byte) => {
+            decoded.push(byte);
+            index += 3;
+            continue;
+          }
+          None => decoded.push(b'%'),
+        }
+      }
+      b'+' => decoded.push(b' '),
+      other => decoded.push(other),
+    }
+    index += 1;
+  }
+  String::from_utf8_lossy(&decoded).to_string()
+}
+
+fn read_query_parameter(url: &str, name: &str) -> Option<String> {
+  let (_, query) = url.split_once('?')?;
+  query.split('&').find_map(|pair| {
+    let (key, value) = pair.split_once('=')?;
+    (key == name).then(|| percent_decode_component(value))
+  })
+}
+
+pub fn begin_sign_in(
+  settings: &GoogleDriveConnectorSettings,
+) -> Result<(GoogleDriveSignInPrompt, PendingGoogleDriveSignIn), String> {
+  if settings.client_id.trim().is_empty() {
+    return Err("Google Drive client_id is empty.".to_string());
+  }
+  // NOTE: Port 0 lets the OS pick a free port; Google accepts any loopback port for desktop clients.
+  let server = Server::http("127.0.0.1:0").map_err(|error| format!("Failed to start sign-in listener: {error}"))?;
+  let port = server
+    .server_addr()
+    .to_ip()
+    .map(|address| address.port())
+    .ok_or_else(|| "Sign-in listener has no TCP port".to_string())?;
+  let redirect_uri = format!("http://127.0.0.1:{port}");
+  let code_verifier = random_token();
+  let code_challenge = base64_url_encode_without_padding(&Sha256::digest(code_verifier.as_bytes()));
+  let state = random_token();
+
+  let authorization_url = format!(
+    "{GOOGLE_AUTHORIZATION_URL}?response_type=code&access_type=offline&prompt=consent&client_id={}&redirect_uri={}&scope={}&code_challenge={code_challenge}&code_challenge_method=S256&state={state}",
+    percent_encode_component(settings.client_id.trim()),
+    percent_encode_component(&redirect_uri),
+    percent_encode_component(DRIVE_SCOPE),
+  );
+  Ok((
+    GoogleDriveSignInPrompt { authorization_url },
+    PendingGoogleDriveSignIn {
+      server,
+      redirect_uri,
+      code_verifier,
+      state,
+    },
+  ))
+}
+
+fn respond_with_html(request: tiny_http::Request, status_code: u16, message: &str) {
+  let response = Response::from_string(format!("<!doctype html><html><body><p>{message}</p></body></html>"))
+    .with_status_code(status_code);
+  let response = match Header::from_bytes(&b"Content-Type"[..], &b"text/html; charset=utf-8"[..]) {
+    Ok(header) => response.with_header(header),
+    Err(_) => response,
+  };
+  let _ = request.respond(response);
+}
+
+pub fn complete_sign_in(settings: &GoogleDriveConnectorSettings, pending: PendingGoogleDriveSignIn) -> Result<(), String> {
+  let deadline = Instant::now() + SIGN_IN_TIMEOUT;
+  let authorization_code = loop {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    if remaining.is_zero() {
+      return Err("Google Drive sign-in expired before it was completed.".to_string());
+    }
+    let Some(request) = pending.server.recv_timeout(remaining).map_err(|error| error.to_string())? else {
+      continue;
+    };
+    let url = request.url().to_string();
+    let code = read_query_parameter(&url, "code");
+    let error = read_query_parameter(&url, "error");
+    if code.is_none() && error.is_none() {
+      // Guard: browsers also ask the loopback server for favicon.ico and similar.
+      respond_with_html(request, 404, "Not found.");
+      continue;
+    }
+    if read_query_parameter(&url, "state").as_deref() != Some(pending.state.as_str()) {
+      respond_with_html(request, 400, "Sign-in state mismatch. Please try again from ocr-agent.");
+      return Err("Google Drive sign-in state mismatch.".to_string());
+    }
+    if let Some(error) = error {
+      respond_with_html(request, 400, "Sign-in was not completed. You can close this window.");
+      return Err(format!("Google Drive sign-in failed: {error}"));
+    }
+    respond_with_html(request, 200, "ocr-agent is now connected to Google Drive. You can close this window.");
+    break code.unwrap_or_default();
+  };
+
+  let body = post_oauth_form(
+    GOOGLE_TOKEN_URL,
+    &[
+      ("grant_type", "authorization_code"),
+      ("code", &authorization_code),
+      ("client_id", settings.client_id.trim()),
+      ("client_secret", settings.client_secret()),
+      ("redirect_uri", &pending.redirect_uri),
+      ("code_verifier", &pending.code_verifier),
+    ],
+  )
+  .map_err(|error_body| format!("Google Drive token exchange failed: {}", describe_oauth_error(&error_body)))?;
+  let refresh_token = body
+    .get("refresh_token")
+    .and_then(Value::as_str)
+    .ok_or_else(|| "Sign-in succeeded but no refresh token was returned.".to_string())?;
+  store_keychain_refresh_token(GOOGLE_DRIVE_KEYCHAIN_SERVICE_NAME, &keychain_account_name(settings), refresh_token)
+}
+
+fn is_supported_document_name(name: &str) -> bool {
+  let Some((_, extension)) = name.rsplit_once('.') else {
+    return false;
+  };
+  SUPPORTED_DOCUMENT_EXTENSIONS.contains(&extension.to_lowercase().as_str())
+}
+
+pub struct DriveSession {
+  settings: GoogleDriveConnectorSettings,
+  cached_access_token: Option<(String, Instant)>,
+}
+
+impl DriveSession {
+  pub fn new(settings: GoogleDriveConnectorSettings) -> DriveSession {
+    DriveSession {
+      settings,
+      cached_access_token: None,
+    }
+  }
+
+  fn access_token(&mut self) -> Result<String, String> {
+    if let Some((access_token, expires_at)) = self.cached_access_token.as_ref() {
+      if Instant::now() < *expires_at {
+        return Ok(access_token.clone());
+      }
+    }
+    let refresh_token = read_refresh_token(&self.settings)?;
+    let body = post_oauth_form(
+      GOOGLE_TOKEN_URL,
+      &[
+        ("grant_type", "refresh_token"),
+        ("client_id", self.settings.client_id.trim()),
+        ("client_secret", self.settings.client_secret()),
+        ("refresh_token", &refresh_token),
+      ],
+    )
+    .map_err(|error_body| format!("Google Drive token refresh failed: {}", describe_oauth_error(&error_body)))?;
+    let access_token = body
+      .get("access_token")
+      .and_then(Value::as_str)
+      .ok_or_else(|| "Token response is missing access_token".to_string())?
+      .to_string();
+    let expires_in = Duration::from_secs(body.get("expires_in").and_then(Value::as_u64).unwrap_or(3600));
+    let expires_at = Instant::now() + expires_in.saturating_sub(ACCESS_TOKEN_EXPIRY_MARGIN);
+    self.cached_access_token = Some((access_token.clone(), expires_at));
+    Ok(access_token)
+  }
+
+  fn get_json(&mut self, url: &str) -> Result<Value, String> {
+    let access_token = self.access_token()?;
+    ureq::get(url)
+      .set("Authorization", &format!("Bearer {access_token}"))
+      .call()
+      .map_err(|error| format!("Google Drive request failed: {error}"))?
+      .into_json::<Value>()
+      .map_err(|error| error.to_string())
+  }
+
+  fn fetch_start_page_token(&mut self) -> Result<String, String> {
+    let body = self.get_json(&format!("{DRIVE_API_BASE_URL}/changes/startPageToken?supportsAllDrives=true"))?;
+    body
+      .get("startPageToken")
+      .and_then(Value::as_str)
+      .map(str::to_string)
+      .ok_or_else(|| "Drive response is missing startPageToken".to_string())
+  }
+
+  fn list_changed_documents(&mut self, page_token: &str) -> Result<(Vec<RemoteDriveFile>, String), String> {
+    let mut next_page_token = page_token.to_string();
+    let mut documents: Vec<RemoteDriveFile> = vec![];
+    loop {
+      let url = format!(
+        "{DRIVE_API_BASE_URL}/changes?pageToken={}&pageSize=100&spaces=drive&includeItemsFromAllDrives=true&supportsAllDrives=true&fields={}",
+        percent_encode_component(&next_page_token),
+        percent_encode_component(CHANGES_FIELDS),
+      );
+      let body = self.get_json(&url)?;
+      for change in body.get("changes").and_then(Value::as_array).into_iter().flatten() {
+        if change.get("removed").and_then(Value::as_bool).unwrap_or(false) {
+          continue;
+        }
+        let Some(file) = change.get("file") else {
+          continue;
+        };
+        if file.get("trashed").and_then(Value::as_bool).unwrap_or(false) {
+          continue;
+        }
+        let name = file.get("name").and_then(Value::as_str).unwrap_or("").to_string();
+        if !is_supported_document_name(&name) {
+          // Guard: native Google Docs have no extension and cannot be downloaded as-is.
+          continue;
+        }
+        let parent_ids: Vec<&str> = file
+          .get("parents")
+          .and_then(Value::as_array)
+          .into_iter()
+          .flatten()
+          .filter_map(Value::as_str)
+          .collect();
+        let Some(folder) = self
+          .settings
+          .folders
+          .iter()
+          .find(|folder| parent_ids.contains(&folder.folder_id.trim()))
+        else {
+          continue;
+        };
+        documents.push(RemoteDriveFile {
+          file_id: file.get("id").and_then(Value::as_str).unwrap_or("").to_string(),
+          name,
+          folder: folder.clone(),
+        });
+      }
+
+      if let Some(new_start_page_token) = body.get("newStartPageToken").and_then(Value::as_str) {
+        return Ok((documents, new_start_page_token.to_string()));
+      }
+      next_page_token = body
+        .get("nextPageToken")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| "Drive changes response has no page token".to_string())?;
+    }
+  }
+
+  fn download_file(&mut self, file: &RemoteDriveFile) -> Result<Vec<u8>, String> {
+    let access_token = self.access_token()?;
+    let url = format!(
+      "{DRIVE_API_BASE_URL}/files/{}?alt=media&supportsAllDrives=true",
+      percent_encode_component(&file.file_id)
+    );
+    let response = ureq::get(&url)
+      .set("Authorization", &format!("Bearer {access_token}"))
+      .call()
+      .map_err(|error| format!("Failed to download {}: {error}", file.name))?;
+    let mut bytes: Vec<u8> = vec![];
+    response
+      .into_reader()
+      .take(MAX_DOWNLOAD_BYTES + 1)
+      .read_to_end(&mut bytes)
+      .map_err(|error| format!("Failed to download {}: {error}", file.name))?;
+    if bytes.len() as u64 > MAX_DOWNLOAD_BYTES {
+      return Err(format!("{} exceeds {MAX_DOWNLOAD_BYTES} bytes.", file.name));
+    }
+    Ok(bytes)
+  }
+}
+
+fn read_persistent_state(state_file_path: &Path) -> GoogleDrivePersistentState {
+  fs::read_to_string(state_file_path)
+    .ok()
+    .and_then(|raw| serde_json::from_str::<GoogleDrivePersistentState>(&raw).ok())
+    .unwrap_or_default()
+}
+
+fn write_persistent_state(state_file_path: &Path, state: &GoogleDrivePersistentState) -> Result<(), String> {
+  if let Some(parent) = state_file_path.parent() {
+    fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+  }
+  let serialized = serde_json::to_string_pretty(state).map_err(|error| error.to_string())?;
+  fs::write(state_file_path, serialized).map_err(|error| error.to_string())
+}
+
+pub fn poll_google_drive_changes_once(
+  session: &mut DriveSession,
+  state_file_path: &Path,
+  handler: &GoogleDriveDocumentHandler,
+) -> Result<(), String> {
+  let mut persistent_state = read_persistent_state(state_file_path);
+  let Some(page_token) = persistent_state.page_token.clone() else {
+    // NOTE: The first poll only records the current position; files already in the folders are not imported.
+    persistent_state.page_token = Some(session.fetch_start_page_token()?);
+    return write_persistent_state(state_file_path, &persistent_state);
+  };
+
+  let (documents, new_start_page_token) = session.list_changed_documents(&page_token)?;
+  for document in documents {
+    if document.file_id.is_empty() || persistent_state.processed_file_ids.contains(&document.file_id) {
+      // Guard: the changes feed also reports later edits and renames of files we already imported.
+      continue;
+    }
+    let bytes = session.download_file(&document)?;
+    handler(&document, bytes)?;
+    persistent_state.processed_file_ids.insert(document.file_id.clone());
+    write_persistent_state(state_file_path, &persistent_state)?;
+  }
+  // NOTE: Advance the page token only after every change was handled so a failure replays the same page.
+  persistent_state.page_token = Some(new_start_page_token);
+  write_persistent_state(state_file_path, &persistent_state)
+}
+
+pub fn get_google_drive_connector_status(
+  state: &SharedConnectorRuntimeState,
+  settings: Option<&GoogleDriveConnectorSettings>,
+  state_file_path: &Path,
+) -> GoogleDriveConnectorStatus {
+  let (is_running, last_error_message) = read_connector_runtime_status(state);
+  GoogleDriveConnectorStatus {
+    is_running,
+    is_signed_in: settings.map(is_signed_in).unwrap_or(false),
+    last_error_message,
+    processed_file_count: read_persistent_state(state_file_path).processed_file_ids.len(),
+  }
+}
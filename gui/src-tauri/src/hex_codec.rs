@@ -0,0 +1,19 @@
+/*!
+Responsibility:
+- Encode and decode the lowercase hex used for digests, keys and tokens stored as text (keychain, JSON files).
+*/
+
+pub fn encode_hex(bytes: &[u8]) -> String {
+  bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+// NOTE: None for an odd length or a non-hex character.
+pub fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+  if !hex.len().is_multiple_of(2) {
+    return None;
+  }
+  (0..hex.len())
+    .step_by(2)
+    .map(|index| u8::from_str_radix(hex.get(index..index + 2)?, 16).ok())
+    .collect()
+}
@@ -0,0 +1,143 @@
+/*!
+Responsibility:
+- Configure which engine runs the Python OCR pipeline: the Docker Compose service (default) or a local interpreter.
+- Build and probe host processes that run `ocr_agent.cli` from the repo's `src/` without Docker.
+- Detect Apple Silicon hosts, where there is no NVIDIA GPU and inference falls back to MPS/CPU.
+*/
+
+use std::{
+  path::Path,
+  process::Command,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::probe_timeout::{output_with_timeout, SLOW_PROBE_COMMAND_TIMEOUT};
+
+pub const EXECUTION_ENGINE_SETTINGS_FILENAME: &str = "execution_engine.json";
+
+const HOST_PYTHON_PROBE_SCRIPT: &str = "import torch, ocr_agent; mps = getattr(torch.backends, 'mps', None); print('cuda' if torch.cuda.is_available() else 'mps' if mps is not None and mps.is_available() else 'cpu')";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionEngineKind {
+  #[default]
+  Docker,
+  Host,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExecutionEngineSettings {
+  #[serde(default)]
+  pub kind: ExecutionEngineKind,
+  // NOTE: A venv/conda interpreter with the pinned requirements installed, as an absolute path (e.g.
+  // `/home/me/venvs/ocr/bin/python`); it is not run through a shell, so `~` is not expanded.
+  pub python_interpreter_path: Option<String>,
+}
+
+impl ExecutionEngineSettings {
+  pub fn resolved_python_interpreter_path(&self) -> Result<&str, String> {
+    self
+      .python_interpreter_path
+      .as_deref()
+      .map(str::trim)
+      .filter(|path| !path.is_empty())
+      .ok_or_else(|| "Host engine needs python_interpreter_path (a venv/conda python with CUDA or MPS torch).".to_string())
+  }
+}
+
+// NOTE: Compile-time target, which matches the host because the GUI ships as a native arm64 build on Apple Silicon.
+pub fn is_apple_silicon_host() -> bool {
+  cfg!(all(target_os = "macos", target_arch = "aarch64"))
+}
+
+pub fn validate_host_engine_supported() -> Result<(), String> {
+  if cfg!(windows) {
+    // Guard: the pipeline pins Linux CUDA wheels; on Windows, Docker (WSL2) is the supported path.
+    return Err("The host engine supports Linux and macOS only. Use the Docker engine on Windows.".to_string());
+  }
+  Ok(())
+}
+
+// NOTE: POSIX single-quote quoting; the same script is run by `bash -lc` in the container and `sh -c` on the host.
+pub fn shell_quote(argument: &str) -> String {
+  format!("'{}'", argument.replace('\'', "'\\''"))
+}
+
+pub fn render_cli_script(python_program: &str, invocations: &[Vec<String>]) -> String {
+  let last_index = invocations.len().saturating_sub(1);
+  invocations
+    .iter()
+    .enumerate()
+    .map(|(index, arguments)| {
+      let quoted_arguments = arguments.iter().map(|argument| shell_quote(argument)).collect::<Vec<_>>().join(" ");
+      // NOTE: `exec` on the last step lets cancel (which kills the shell's PID) stop Python itself.
+      let exec_prefix = if index == last_index { "exec " } else { "" };
+      format!("{exec_prefix}{python_program} -m ocr_agent.cli {quoted_arguments}")
+    })
+    .collect::<Vec<_>>()
+    .join(" && ")
+}
+
+fn apply_repo_python_path(command: &mut Command, repo_root: &Path) {
+  let source_directory_path = repo_root.join("src");
+  let python_path = match std::env::var_os("PYTHONPATH") {
+    Some(existing) if !existing.is_empty() => {
+      let mut joined = source_directory_path.into_os_string();
+      joined.push(":");
+      joined.push(existing);
+      joined
+    }
+    _ => source_directory_path.into_os_string(),
+  };
+  command.env("PYTHONPATH", python_path);
+}
+
+pub fn build_host_job_command(
+  repo_root: &Path,
+  python_interpreter_path: &str,
+  environment_variables: &[(String, String)],
+  invocations: &[Vec<String>],
+) -> Command {
+  let mut command = Command::new("sh");
+  command.arg("-c");
+  command.arg(render_cli_script(&shell_quote(python_interpreter_path), invocations));
+  command.current_dir(repo_root);
+  apply_repo_python_path(&mut command, repo_root);
+  for (name, value) in environment_variables {
+    command.env(name, value);
+  }
+  // NOTE: Its own process group, so `pause_job` can SIGSTOP the Python pipeline along with this `sh`.
+  #[cfg(unix)]
+  {
+    use std::os::unix::process::CommandExt;
+    command.process_group(0);
+  }
+  command
+}
+
+// NOTE: Returns the device the pipeline will pick on "auto": "cuda", or "mps" on Apple Silicon.
+pub fn probe_host_python(repo_root: &Path, python_interpreter_path: &str) -> Result<String, String> {
+  validate_host_engine_supported()?;
+  let mut command = Command::new(python_interpreter_path);
+  command.arg("-c").arg(HOST_PYTHON_PROBE_SCRIPT);
+  apply_repo_python_path(&mut command, repo_root);
+  // NOTE: Importing torch on a cold start is slow, hence the longer deadline.
+  let output = output_with_timeout(&mut command, SLOW_PROBE_COMMAND_TIMEOUT)
+    .map_err(|error| format!("Failed to run {python_interpreter_path}. {error}"))?;
+  if !output.status.success() {
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    return Err(format!(
+      "Host Python cannot import the OCR pipeline. Install requirements.base.txt into this environment.\n{stderr}"
+    ));
+  }
+  let inference_device = String::from_utf8_lossy(&output.stdout).trim().to_string();
+  match inference_device.as_str() {
+    "cuda" => Ok(inference_device),
+    "mps" if is_apple_silicon_host() => Ok(inference_device),
+    _ if is_apple_silicon_host() => Err(
+      "Host Python has no MPS-enabled torch. Install an arm64 (not Rosetta) Python with torch >= 2.0.".to_string(),
+    ),
+    _ => Err("Host Python has no CUDA-enabled torch. Install the CUDA build of torch or use the Docker engine.".to_string()),
+  }
+}
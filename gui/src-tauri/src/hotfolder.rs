@@ -0,0 +1,146 @@
+/*!
+Responsibility:
+- Watch "hotfolders" where single PDFs are dropped directly (e.g. by a PDF printer driver), without bundles or `.ready`.
+- Claim each PDF once its size has settled and hand it to a callback that wraps it into a bundle with the folder's preset.
+*/
+
+use std::{
+  collections::HashMap,
+  fs,
+  path::{Path, PathBuf},
+  sync::Arc,
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  connector_runtime::{read_connector_runtime_status, SharedConnectorRuntimeState},
+  natural_sort::sort_paths_naturally,
+};
+
+pub const HOTFOLDER_SETTINGS_FILENAME: &str = "hotfolders.json";
+pub const HOTFOLDER_POLL_INTERVAL_MILLIS: u64 = 2000;
+
+const HOTFOLDER_PROCESSING_DIRECTORY_NAME: &str = ".processing";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HotfolderPreset {
+  pub folder_path: String,
+  pub preset_name: String,
+  // NOTE: Hotfolder jobs run immediately by default, regardless of the watch-folder auto-run toggle.
+  #[serde(default = "default_is_auto_run_enabled")]
+  pub is_auto_run_enabled: bool,
+}
+
+fn default_is_auto_run_enabled() -> bool {
+  true
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HotfolderStatus {
+  pub is_running: bool,
+  pub last_error_message: Option<String>,
+}
+
+pub type HotfolderDocumentHandler = Arc<dyn Fn(&HotfolderPreset, &Path) -> Result<(), String> + Send + Sync>;
+
+// NOTE: (size, modified) seen on the previous poll; a PDF is claimed only when both are unchanged.
+pub type HotfolderObservations = HashMap<PathBuf, (u64, Option<SystemTime>)>;
+
+fn is_pdf_file(path: &Path) -> bool {
+  path.is_file()
+    && path
+      .extension()
+      .and_then(|extension| extension.to_str())
+      .map(|extension| extension.eq_ignore_ascii_case("pdf"))
+      .unwrap_or(false)
+}
+
+fn list_settled_pdf_files(folder_path: &Path, observations: &mut HotfolderObservations) -> Result<Vec<PathBuf>, String> {
+  let mut settled: Vec<PathBuf> = vec![];
+  let entries =
+    fs::read_dir(folder_path).map_err(|error| format!("Failed to read hotfolder {}: {error}", folder_path.display()))?;
+  for entry in entries {
+    let path = entry.map_err(|error| error.to_string())?.path();
+    if !is_pdf_file(&path) {
+      continue;
+    }
+    let Ok(metadata) = fs::metadata(&path) else {
+      continue;
+    };
+    let observation = (metadata.len(), metadata.modified().ok());
+    let previous_observation = observations.insert(path.clone(), observation);
+    // Guard: printer drivers write PDFs progressively; wait until two polls agree on a non-empty file.
+    if observation.0 > 0 && previous_observation == Some(observation) {
+      settled.push(path);
+    }
+  }
+  sort_paths_naturally(&mut settled);
+  Ok(settled)
+}
+
+fn claim_pdf_file(folder_path: &Path, pdf_path: &Path) -> Option<PathBuf> {
+  // NOTE: Printer drivers often reuse one filename; a per-claim directory keeps an earlier failed PDF intact.
+  let claimed_at_millis = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_millis();
+  let claim_directory_path = folder_path
+    .join(HOTFOLDER_PROCESSING_DIRECTORY_NAME)
+    .join(claimed_at_millis.to_string());
+  fs::create_dir_all(&claim_directory_path).ok()?;
+  let claimed_path = claim_directory_path.join(pdf_path.file_name()?);
+  // NOTE: Rename fails on Windows while the writer still holds the file open, so it doubles as a lock check.
+  if fs::rename(pdf_path, &claimed_path).is_err() {
+    let _ = fs::remove_dir(&claim_directory_path);
+    return None;
+  }
+  Some(claimed_path)
+}
+
+pub fn poll_hotfolders_once(
+  presets: &[HotfolderPreset],
+  observations: &mut HotfolderObservations,
+  handler: &HotfolderDocumentHandler,
+) -> Result<(), String> {
+  let mut error_messages: Vec<String> = vec![];
+  for preset in presets {
+    let folder_path = PathBuf::from(preset.folder_path.trim());
+    let settled_pdf_paths = match list_settled_pdf_files(&folder_path, observations) {
+      Ok(paths) => paths,
+      Err(error) => {
+        // Guard: one unreachable folder (e.g. a disconnected share) must not stop the others.
+        error_messages.push(error);
+        continue;
+      }
+    };
+    for pdf_path in settled_pdf_paths {
+      observations.remove(&pdf_path);
+      let Some(claimed_path) = claim_pdf_file(&folder_path, &pdf_path) else {
+        continue;
+      };
+      match handler(preset, &claimed_path) {
+        Ok(()) => {
+          if let Some(claim_directory_path) = claimed_path.parent() {
+            let _ = fs::remove_dir_all(claim_directory_path);
+          }
+        }
+        // NOTE: A failed PDF stays in `.processing/` so it is never silently lost.
+        Err(error) => error_messages.push(format!("{}: {error}", claimed_path.display())),
+      }
+    }
+  }
+  // Guard: forget files that disappeared so the map does not grow forever.
+  observations.retain(|path, _| path.exists());
+
+  if error_messages.is_empty() {
+    return Ok(());
+  }
+  Err(error_messages.join("; "))
+}
+
+pub fn get_hotfolder_status(state: &SharedConnectorRuntimeState) -> HotfolderStatus {
+  let (is_running, last_error_message) = read_connector_runtime_status(state);
+  HotfolderStatus {
+    is_running,
+    last_error_message,
+  }
+}
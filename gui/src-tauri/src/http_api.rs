@@ -0,0 +1,705 @@
+/*!
+Responsibility:
+- Serve a small token-authenticated HTTP API so automation tools (Zapier, Make, n8n, curl) can submit documents.
+- Parse a multipart upload or a document URL and hand the files to a submission callback that returns a job id.
+- Let an admin check, pause/resume and stop/start the watcher remotely (e.g. before maintenance on the OCR machine).
+
+Endpoints:
+- Every endpoint takes `Authorization: Bearer <token>` (or `X-Api-Token: <token>`). The shared token may do
+  everything; per-submitter tokens may only submit; managed tokens (`create_http_api_token`) have their own scopes.
+  A token without the endpoint's scope gets `403`.
+- `POST /api/v1/jobs` (scope `submit`):
+  - A per-submitter token (or a managed token with a submitter) also records that submitter on the created job.
+  - `multipart/form-data`: every part with a filename is a document (a leading `.` in the name becomes
+    `_`); a `url` text field is downloaded.
+  - `application/json`: `{ "url": "https://..." }`.
+  - URLs are downloaded under the upload cap with connect/read timeouts, and only from public addresses: a host that
+    resolves to loopback, link-local or private addresses is refused (`502`).
+  - Optional per-submission job settings: a `settings` text field holding a JSON object (multipart), or a
+    `"settings": { ... }` member (JSON). Invalid or disallowed settings are rejected with `400`.
+  - Responds `202 {"job_id": "..."}`.
+- Control endpoints, all responding `200` with the current status:
+  - `GET /api/v1/status` (scope `read_status`): watcher state, running jobs and queue counts.
+  - Scope `admin`: `POST /api/v1/watcher/pause` with an optional `{ "reason": "..." }`, `POST /api/v1/watcher/resume`.
+  - `POST /api/v1/watcher/stop`, `POST /api/v1/watcher/start` (restarts with the settings it was last started with).
+- Limits (`http_api_*` in `runtime_limits.json`, read per request): a submission larger than the upload cap gets
+  `413`; a client address over its requests or submissions per minute gets `429` with `Retry-After`.
+*/
+
+use std::{
+  io::{self, Read},
+  net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs},
+  sync::Arc,
+  thread,
+  time::{Duration, Instant},
+};
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use subtle::ConstantTimeEq;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::{
+  api_tokens::{ApiTokenGrant, ApiTokenScope},
+  http_rate_limit::RateLimiter,
+  runtime_limits::current_runtime_limits,
+};
+
+pub const DEFAULT_HTTP_API_BIND_ADDRESS: &str = "127.0.0.1:8765";
+
+const JOBS_ENDPOINT_PATH: &str = "/api/v1/jobs";
+const STATUS_ENDPOINT_PATH: &str = "/api/v1/status";
+const WATCHER_ENDPOINT_PATH_PREFIX: &str = "/api/v1/watcher/";
+const MAX_CONTROL_BODY_BYTES: u64 = 64_000;
+const DEFAULT_DOWNLOADED_FILENAME: &str = "download";
+const DEFAULT_UPLOADED_FILENAME: &str = "upload";
+// NOTE: Requests are served one at a time, so a slow or stalled URL must not hold the server (and the admin control
+// endpoints) for long.
+const DOWNLOAD_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const DOWNLOAD_READ_TIMEOUT: Duration = Duration::from_secs(30);
+const DOWNLOAD_TOTAL_TIMEOUT: Duration = Duration::from_secs(120);
+
+#[derive(Debug, Clone)]
+pub struct HttpApiConfig {
+  pub bind_address: String,
+  pub token: String,
+  // NOTE: (submitter, token) pairs accepted in addition to the shared token.
+  pub submitter_tokens: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InboundDocument {
+  pub filename: String,
+  pub bytes: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionError {
+  // NOTE: Reported as `400`; the request itself is wrong (e.g. a per-submission setting failed validation).
+  InvalidRequest(String),
+  // NOTE: Reported as `503`; the caller may retry later (e.g. the watcher is stopped or maintenance is on).
+  Unavailable(String),
+}
+
+// NOTE: The second argument is the raw `settings` object, validated by the handler against the job settings
+// schema; the third is the submitter whose token authorized the request.
+pub type InboundSubmissionHandler =
+  Arc<dyn Fn(Vec<InboundDocument>, Option<Value>, Option<String>) -> Result<String, SubmissionError> + Send + Sync>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ControlAction {
+  Status,
+  StartWatcher,
+  StopWatcher,
+  PauseIntake { reason: Option<String> },
+  ResumeIntake,
+}
+
+// NOTE: Looks up a token that is neither the shared nor a per-submitter one; None rejects it.
+pub type ApiTokenAuthorizer = Arc<dyn Fn(&str) -> Option<ApiTokenGrant> + Send + Sync>;
+
+// NOTE: Applies the action and returns the status afterwards; an Err is reported as `409` (e.g. already running).
+pub type ControlHandler = Arc<dyn Fn(ControlAction) -> Result<Value, String> + Send + Sync>;
+
+#[derive(Debug, Default, Deserialize)]
+struct PauseRequestBody {
+  #[serde(default)]
+  reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UrlSubmissionBody {
+  url: String,
+  #[serde(default)]
+  settings: Option<Value>,
+}
+
+struct MultipartPart {
+  name: Option<String>,
+  filename: Option<String>,
+  data: Vec<u8>,
+}
+
+struct ApiError {
+  status_code: u16,
+  message: String,
+  // NOTE: Sent as `Retry-After` (and `retry_after_seconds` in the body) with `429`.
+  retry_after_seconds: Option<u64>,
+}
+
+impl ApiError {
+  fn new(status_code: u16, message: impl Into<String>) -> ApiError {
+    ApiError {
+      status_code,
+      message: message.into(),
+      retry_after_seconds: None,
+    }
+  }
+
+  fn rate_limited(retry_after: Duration, limit_name: &str, max_per_minute: u32) -> ApiError {
+    // NOTE: Rounded up so a client that waits exactly this long is let through.
+    let retry_after_seconds = (retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0)).max(1);
+    ApiError {
+      status_code: 429,
+      message: format!(
+        "Too many requests: at most {max_per_minute} per minute ({limit_name}). Retry in {retry_after_seconds}s."
+      ),
+      retry_after_seconds: Some(retry_after_seconds),
+    }
+  }
+
+  fn upload_too_large(max_upload_bytes: u64) -> ApiError {
+    ApiError::new(
+      413,
+      format!("Submission exceeds the upload limit of {max_upload_bytes} bytes (http_api_max_upload_bytes)."),
+    )
+  }
+}
+
+#[derive(Debug, Default)]
+struct HttpApiRateLimiters {
+  requests: RateLimiter,
+  submissions: RateLimiter,
+}
+
+fn find_header_value<'a>(request: &'a Request, header_name: &'static str) -> Option<&'a str> {
+  request
+    .headers()
+    .iter()
+    .find(|header| header.field.equiv(header_name))
+    .map(|header| header.value.as_str())
+}
+
+// NOTE: None when the token is missing or unknown.
+fn authorize_request(
+  request: &Request,
+  config: &HttpApiConfig,
+  token_authorizer: &ApiTokenAuthorizer,
+) -> Option<ApiTokenGrant> {
+  let presented_token = find_header_value(request, "Authorization")
+    .and_then(|value| value.trim().strip_prefix("Bearer "))
+    .or_else(|| find_header_value(request, "X-Api-Token"))
+    .map(str::trim)
+    .filter(|token| !token.is_empty())?;
+  if bool::from(presented_token.as_bytes().ct_eq(config.token.as_bytes())) {
+    return Some(ApiTokenGrant::admin());
+  }
+  let submitter_token_grant = config
+    .submitter_tokens
+    .iter()
+    .find(|(_, token)| bool::from(presented_token.as_bytes().ct_eq(token.as_bytes())))
+    .map(|(submitter, _)| ApiTokenGrant {
+      scopes: vec![ApiTokenScope::Submit],
+      submitter: Some(submitter.clone()),
+    });
+  submitter_token_grant.or_else(|| token_authorizer(presented_token))
+}
+
+fn authorize_scope(
+  request: &Request,
+  config: &HttpApiConfig,
+  token_authorizer: &ApiTokenAuthorizer,
+  scope: ApiTokenScope,
+) -> Result<ApiTokenGrant, ApiError> {
+  let grant = authorize_request(request, config, token_authorizer)
+    .ok_or_else(|| ApiError::new(401, "Missing or invalid API token."))?;
+  if !grant.allows(scope) {
+    return Err(ApiError::new(403, format!("This API token lacks the {} scope.", scope.label())));
+  }
+  Ok(grant)
+}
+
+// NOTE: `alice=token-a,bob=token-b`; entries without a name or token are skipped.
+pub fn parse_submitter_tokens(raw: &str) -> Vec<(String, String)> {
+  raw
+    .split(',')
+    .filter_map(|entry| {
+      let (submitter, token) = entry.split_once('=')?;
+      let (submitter, token) = (submitter.trim(), token.trim());
+      (!submitter.is_empty() && !token.is_empty()).then(|| (submitter.to_string(), token.to_string()))
+    })
+    .collect()
+}
+
+fn read_request_body(request: &mut Request, max_upload_bytes: u64) -> Result<Vec<u8>, ApiError> {
+  if request.body_length().is_some_and(|body_length| body_length as u64 > max_upload_bytes) {
+    // Guard: refuse from Content-Length before buffering anything.
+    return Err(ApiError::upload_too_large(max_upload_bytes));
+  }
+  let mut body: Vec<u8> = vec![];
+  request
+    .as_reader()
+    .take(max_upload_bytes + 1)
+    .read_to_end(&mut body)
+    .map_err(|error| ApiError::new(400, format!("Failed to read request body: {error}")))?;
+  if body.len() as u64 > max_upload_bytes {
+    return Err(ApiError::upload_too_large(max_upload_bytes));
+  }
+  Ok(body)
+}
+
+// NOTE: Also used for documents from cloud drives and hot folders; their names are just as untrusted.
+pub fn sanitize_submitted_filename(raw_filename: &str, fallback_filename: &str) -> String {
+  // Guard: keep only the last path component so a crafted name cannot escape the bundle directory.
+  let basename = raw_filename
+    .rsplit(['/', '\\'])
+    .next()
+    .unwrap_or("")
+    .trim();
+  let sanitized: String = basename
+    .chars()
+    .map(|character| if character == ':' || character.is_control() { '_' } else { character })
+    .collect();
+  let undotted = sanitized.trim_start_matches('.');
+  if undotted.is_empty() {
+    return fallback_filename.to_string();
+  }
+  if undotted.len() != sanitized.len() {
+    // Guard: bundle markers (`.ready`, `.job_settings.json`, `.submitter`, ...) are dot files; a document must never
+    // land on one and be read as bundle settings.
+    return format!("_{undotted}");
+  }
+  sanitized
+}
+
+fn parse_header_parameters(header_value: &str) -> Vec<(String, String)> {
+  header_value
+    .split(';')
+    .skip(1)
+    .filter_map(|parameter| {
+      let (key, value) = parameter.split_once('=')?;
+      Some((key.trim().to_lowercase(), value.trim().trim_matches('"').to_string()))
+    })
+    .collect()
+}
+
+fn parse_multipart_boundary(content_type: &str) -> Option<String> {
+  parse_header_parameters(content_type)
+    .into_iter()
+    .find(|(key, _)| key == "boundary")
+    .map(|(_, value)| value)
+    .filter(|value| !value.is_empty())
+}
+
+fn find_subsequence(haystack: &[u8], needle: &[u8], start_index: usize) -> Option<usize> {
+  if start_index > haystack.len() {
+    return None;
+  }
+  haystack[start_index..]
+    .windows(needle.len())
+    .position(|window| window == needle)
+    .map(|offset| start_index + offset)
+}
+
+fn parse_multipart_form(body: &[u8], boundary: &str) -> Result<Vec<MultipartPart>, String> {
+  let delimiter = format!("--{boundary}").into_bytes();
+  let part_separator = format!("\r\n--{boundary}").into_bytes();
+  let mut cursor = find_subsequence(body, &delimiter, 0).ok_or_else(|| "Multipart boundary not found".to_string())?;
+  let mut parts: Vec<MultipartPart> = vec![];
+  loop {
+    cursor += delimiter.len();
+    if body[cursor..].starts_with(b"--") {
+      // NOTE: Closing delimiter (`--boundary--`).
+      return Ok(parts);
+    }
+    if body[cursor..].starts_with(b"\r\n") {
+      cursor += 2;
+    }
+    let header_end = find_subsequence(body, b"\r\n\r\n", cursor).ok_or_else(|| "Malformed multipart headers".to_string())?;
+    let header_text = String::from_utf8_lossy(&body[cursor..header_end]).to_string();
+    let data_start = header_end + 4;
+    let data_end =
+      find_subsequence(body, &part_separator, data_start).ok_or_else(|| "Unterminated multipart part".to_string())?;
+
+    let mut name: Option<String> = None;
+    let mut filename: Option<String> = None;
+    for header_line in header_text.lines() {
+      let Some((header_name, header_value)) = header_line.split_once(':') else {
+        continue;
+      };
+      if !header_name.trim().eq_ignore_ascii_case("content-disposition") {
+        continue;
+      }
+      for (key, value) in parse_header_parameters(header_value) {
+        match key.as_str() {
+          "name" => name = Some(value),
+          "filename" => filename = Some(value),
+          _ => {}
+        }
+      }
+    }
+    parts.push(MultipartPart {
+      name,
+      filename,
+      data: body[data_start..data_end].to_vec(),
+    });
+    // NOTE: Skip the CRLF that precedes the next delimiter.
+    cursor = data_end + 2;
+  }
+}
+
+fn derive_filename_from_url(url: &str) -> String {
+  let without_query = url.split(['?', '#']).next().unwrap_or("");
+  let last_segment = without_query
+    .split_once("://")
+    .map(|(_, rest)| rest)
+    .and_then(|rest| rest.split_once('/'))
+    .map(|(_, path)| path.rsplit('/').next().unwrap_or(""))
+    .unwrap_or("");
+  sanitize_submitted_filename(last_segment, DEFAULT_DOWNLOADED_FILENAME)
+}
+
+fn is_ipv4_publicly_routable(address: Ipv4Addr) -> bool {
+  let [first_octet, second_octet, ..] = address.octets();
+  // NOTE: 100.64.0.0/10 is carrier-grade NAT, which is as internal as the RFC 1918 ranges.
+  let is_shared_address_space = first_octet == 100 && (64..128).contains(&second_octet);
+  !(address.is_private()
+    || address.is_loopback()
+    || address.is_link_local()
+    || address.is_unspecified()
+    || address.is_broadcast()
+    || address.is_documentation()
+    || address.is_multicast()
+    || is_shared_address_space
+    || first_octet == 0)
+}
+
+// NOTE: IPv4-mapped (`::ffff:a.b.c.d`), IPv4-compatible (`::a.b.c.d`) and NAT64 (`64:ff9b::a.b.c.d`) addresses reach
+// the IPv4 host in their last 32 bits.
+fn embedded_ipv4_address(address: Ipv6Addr) -> Option<Ipv4Addr> {
+  if let Some(mapped_address) = address.to_ipv4_mapped() {
+    return Some(mapped_address);
+  }
+  let segments = address.segments();
+  let is_ipv4_compatible = segments[..6].iter().all(|segment| *segment == 0);
+  let is_nat64 = segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0];
+  if !is_ipv4_compatible && !is_nat64 {
+    return None;
+  }
+  let [.., a, b, c, d] = address.octets();
+  Some(Ipv4Addr::new(a, b, c, d))
+}
+
+fn is_ipv6_publicly_routable(address: Ipv6Addr) -> bool {
+  if address.is_loopback() || address.is_unspecified() {
+    return false;
+  }
+  if let Some(embedded_address) = embedded_ipv4_address(address) {
+    return is_ipv4_publicly_routable(embedded_address);
+  }
+  let first_segment = address.segments()[0];
+  let is_unique_local = (first_segment & 0xfe00) == 0xfc00;
+  let is_link_local = (first_segment & 0xffc0) == 0xfe80;
+  !(address.is_loopback() || address.is_unspecified() || address.is_multicast() || is_unique_local || is_link_local)
+}
+
+fn is_publicly_routable(address: IpAddr) -> bool {
+  match address {
+    IpAddr::V4(address) => is_ipv4_publicly_routable(address),
+    IpAddr::V6(address) => is_ipv6_publicly_routable(address),
+  }
+}
+
+// NOTE: Used as the download agent's resolver, so the check covers every redirect hop and the address connected to is
+// the one checked (a second DNS lookup could answer differently).
+fn resolve_public_addresses(netloc: &str) -> io::Result<Vec<SocketAddr>> {
+  let addresses: Vec<SocketAddr> = netloc.to_socket_addrs()?.collect();
+  let public_addresses: Vec<SocketAddr> = addresses
+    .iter()
+    .copied()
+    .filter(|address| is_publicly_routable(address.ip()))
+    .collect();
+  if public_addresses.is_empty() {
+    // Guard: a submitted URL must not reach this machine or its network (admin pages, cloud metadata endpoints).
+    return Err(io::Error::new(
+      io::ErrorKind::PermissionDenied,
+      format!("{netloc} resolves to a loopback, link-local or private address"),
+    ));
+  }
+  Ok(public_addresses)
+}
+
+fn download_document(url: &str, max_upload_bytes: u64) -> Result<InboundDocument, ApiError> {
+  let url = url.trim();
+  if !(url.starts_with("https://") || url.starts_with("http://")) {
+    // Guard: refuse file:// and other schemes that would read from this machine.
+    return Err(ApiError::new(400, "Only http(s) URLs can be submitted."));
+  }
+  let agent = ureq::AgentBuilder::new()
+    .timeout_connect(DOWNLOAD_CONNECT_TIMEOUT)
+    .timeout_read(DOWNLOAD_READ_TIMEOUT)
+    .timeout(DOWNLOAD_TOTAL_TIMEOUT)
+    .resolver(resolve_public_addresses)
+    .build();
+  let response = agent
+    .get(url)
+    .call()
+    .map_err(|error| ApiError::new(502, format!("Failed to download {url}: {error}")))?;
+  let declared_length = response
+    .header("Content-Length")
+    .and_then(|value| value.trim().parse::<u64>().ok());
+  if declared_length.is_some_and(|declared_length| declared_length > max_upload_bytes) {
+    // Guard: refuse from Content-Length before reading the body.
+    return Err(ApiError::upload_too_large(max_upload_bytes));
+  }
+  let mut bytes: Vec<u8> = vec![];
+  response
+    .into_reader()
+    .take(max_upload_bytes + 1)
+    .read_to_end(&mut bytes)
+    .map_err(|error| ApiError::new(502, format!("Failed to download {url}: {error}")))?;
+  if bytes.len() as u64 > max_upload_bytes {
+    return Err(ApiError::upload_too_large(max_upload_bytes));
+  }
+  Ok(InboundDocument {
+    filename: derive_filename_from_url(url),
+    bytes,
+  })
+}
+
+fn parse_settings_field(data: &[u8]) -> Result<Option<Value>, ApiError> {
+  if data.iter().all(u8::is_ascii_whitespace) {
+    return Ok(None);
+  }
+  serde_json::from_slice::<Value>(data)
+    .map(Some)
+    .map_err(|error| ApiError::new(400, format!("Invalid JSON in the settings field: {error}")))
+}
+
+// NOTE: Returns the documents and the optional per-submission settings object.
+fn collect_inbound_documents(
+  request: &mut Request,
+  max_upload_bytes: u64,
+) -> Result<(Vec<InboundDocument>, Option<Value>), ApiError> {
+  let content_type = find_header_value(request, "Content-Type").unwrap_or("").to_string();
+  let body = read_request_body(request, max_upload_bytes)?;
+  let lowered_content_type = content_type.to_lowercase();
+
+  if lowered_content_type.starts_with("multipart/form-data") {
+    let boundary = parse_multipart_boundary(&content_type)
+      .ok_or_else(|| ApiError::new(400, "multipart/form-data request has no boundary."))?;
+    let parts = parse_multipart_form(&body, &boundary).map_err(|message| ApiError::new(400, message))?;
+    let mut documents: Vec<InboundDocument> = vec![];
+    let mut settings: Option<Value> = None;
+    let mut downloaded_bytes: u64 = 0;
+    for part in parts {
+      if let Some(filename) = part.filename.as_deref() {
+        if part.data.is_empty() {
+          continue;
+        }
+        documents.push(InboundDocument {
+          filename: sanitize_submitted_filename(filename, DEFAULT_UPLOADED_FILENAME),
+          bytes: part.data,
+        });
+        continue;
+      }
+      match part.name.as_deref() {
+        Some("url") => {
+          let url = String::from_utf8_lossy(&part.data).to_string();
+          if !url.trim().is_empty() {
+            let document = download_document(&url, max_upload_bytes)?;
+            downloaded_bytes += document.bytes.len() as u64;
+            if downloaded_bytes > max_upload_bytes {
+              // Guard: several `url` fields share one cap, like the parts of an upload do.
+              return Err(ApiError::upload_too_large(max_upload_bytes));
+            }
+            documents.push(document);
+          }
+        }
+        Some("settings") => settings = parse_settings_field(&part.data)?,
+        _ => {}
+      }
+    }
+    return Ok((documents, settings));
+  }
+
+  if lowered_content_type.starts_with("application/json") {
+    let submission = serde_json::from_slice::<UrlSubmissionBody>(&body)
+      .map_err(|error| ApiError::new(400, format!("Invalid JSON body: {error}")))?;
+    return Ok((vec![download_document(&submission.url, max_upload_bytes)?], submission.settings));
+  }
+
+  Err(ApiError::new(
+    415,
+    "Use multipart/form-data (file upload) or application/json ({\"url\": ...}).",
+  ))
+}
+
+fn handle_jobs_submission(
+  request: &mut Request,
+  submitter: Option<String>,
+  handler: &InboundSubmissionHandler,
+  max_upload_bytes: u64,
+) -> Result<String, ApiError> {
+  let (documents, settings) = collect_inbound_documents(request, max_upload_bytes)?;
+  if documents.is_empty() {
+    return Err(ApiError::new(400, "No document was submitted."));
+  }
+  handler(documents, settings, submitter).map_err(|error| match error {
+    SubmissionError::InvalidRequest(message) => ApiError::new(400, message),
+    SubmissionError::Unavailable(message) => ApiError::new(503, message),
+  })
+}
+
+fn parse_control_action(request: &mut Request, path: &str) -> Result<ControlAction, ApiError> {
+  if path == STATUS_ENDPOINT_PATH {
+    if *request.method() != Method::Get {
+      return Err(ApiError::new(405, "Use GET."));
+    }
+    return Ok(ControlAction::Status);
+  }
+  let action_name = path.strip_prefix(WATCHER_ENDPOINT_PATH_PREFIX).unwrap_or("");
+  if !matches!(action_name, "start" | "stop" | "pause" | "resume") {
+    return Err(ApiError::new(404, "Not found."));
+  }
+  if *request.method() != Method::Post {
+    return Err(ApiError::new(405, "Use POST."));
+  }
+  Ok(match action_name {
+    "start" => ControlAction::StartWatcher,
+    "stop" => ControlAction::StopWatcher,
+    "resume" => ControlAction::ResumeIntake,
+    _ => {
+      let mut body: Vec<u8> = vec![];
+      request
+        .as_reader()
+        .take(MAX_CONTROL_BODY_BYTES)
+        .read_to_end(&mut body)
+        .map_err(|error| ApiError::new(400, format!("Failed to read request body: {error}")))?;
+      // NOTE: The body is optional; `curl -X POST` without data pauses with a generic reason.
+      let pause_request = if body.iter().all(u8::is_ascii_whitespace) {
+        PauseRequestBody::default()
+      } else {
+        serde_json::from_slice::<PauseRequestBody>(&body)
+          .map_err(|error| ApiError::new(400, format!("Invalid JSON body: {error}")))?
+      };
+      ControlAction::PauseIntake {
+        reason: pause_request.reason.map(|reason| reason.trim().to_string()).filter(|reason| !reason.is_empty()),
+      }
+    }
+  })
+}
+
+fn handle_control_request(
+  request: &mut Request,
+  path: &str,
+  config: &HttpApiConfig,
+  token_authorizer: &ApiTokenAuthorizer,
+  control_handler: &ControlHandler,
+) -> Result<Value, ApiError> {
+  // Guard: tokens handed out for uploads or dashboards must not be able to stop intake.
+  let scope = if path == STATUS_ENDPOINT_PATH {
+    ApiTokenScope::ReadStatus
+  } else {
+    ApiTokenScope::Admin
+  };
+  authorize_scope(request, config, token_authorizer, scope)?;
+  let action = parse_control_action(request, path)?;
+  control_handler(action).map_err(|message| ApiError::new(409, message))
+}
+
+fn json_response(status_code: u16, body: &Value) -> Response<std::io::Cursor<Vec<u8>>> {
+  let response = Response::from_string(body.to_string()).with_status_code(status_code);
+  match Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]) {
+    Ok(header) => response.with_header(header),
+    Err(_) => response,
+  }
+}
+
+fn error_response(error: &ApiError) -> Response<std::io::Cursor<Vec<u8>>> {
+  let Some(retry_after_seconds) = error.retry_after_seconds else {
+    return json_response(error.status_code, &json!({ "error": error.message }));
+  };
+  let response = json_response(
+    error.status_code,
+    &json!({ "error": error.message, "retry_after_seconds": retry_after_seconds }),
+  );
+  match Header::from_bytes(&b"Retry-After"[..], retry_after_seconds.to_string().as_bytes()) {
+    Ok(header) => response.with_header(header),
+    Err(_) => response,
+  }
+}
+
+fn handle_request(
+  mut request: Request,
+  config: &HttpApiConfig,
+  token_authorizer: &ApiTokenAuthorizer,
+  handler: &InboundSubmissionHandler,
+  control_handler: &ControlHandler,
+  rate_limiters: &mut HttpApiRateLimiters,
+) {
+  let limits = current_runtime_limits();
+  let now = Instant::now();
+  // NOTE: Behind a reverse proxy every client shares the proxy's address; the limits then apply to all of them.
+  let client_address = request
+    .remote_addr()
+    .map_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED), |address| address.ip());
+  // Guard: checked before authentication so guessing tokens is throttled too.
+  if let Err(retry_after) = rate_limiters
+    .requests
+    .check(client_address, limits.http_api_max_requests_per_minute, now)
+  {
+    let error = ApiError::rate_limited(
+      retry_after,
+      "http_api_max_requests_per_minute",
+      limits.http_api_max_requests_per_minute,
+    );
+    let _ = request.respond(error_response(&error));
+    return;
+  }
+
+  let path = request.url().split('?').next().unwrap_or("").to_string();
+  let result = if path == STATUS_ENDPOINT_PATH || path.starts_with(WATCHER_ENDPOINT_PATH_PREFIX) {
+    handle_control_request(&mut request, &path, config, token_authorizer, control_handler).map(|status| (200, status))
+  } else if path != JOBS_ENDPOINT_PATH {
+    Err(ApiError::new(404, "Not found."))
+  } else if *request.method() != Method::Post {
+    Err(ApiError::new(405, "Use POST."))
+  } else {
+    authorize_scope(&request, config, token_authorizer, ApiTokenScope::Submit)
+      .and_then(|grant| {
+        rate_limiters
+          .submissions
+          .check(client_address, limits.http_api_max_submissions_per_minute, now)
+          .map_err(|retry_after| {
+            ApiError::rate_limited(
+              retry_after,
+              "http_api_max_submissions_per_minute",
+              limits.http_api_max_submissions_per_minute,
+            )
+          })
+          .map(|_| grant)
+      })
+      .and_then(|grant| {
+        handle_jobs_submission(&mut request, grant.submitter, handler, limits.http_api_max_upload_bytes)
+          .map(|job_id| (202, json!({ "job_id": job_id })))
+      })
+  };
+
+  let response = match result {
+    Ok((status_code, body)) => json_response(status_code, &body),
+    Err(error) => error_response(&error),
+  };
+  let _ = request.respond(response);
+}
+
+pub fn start_http_api_server(
+  config: HttpApiConfig,
+  token_authorizer: ApiTokenAuthorizer,
+  handler: InboundSubmissionHandler,
+  control_handler: ControlHandler,
+) -> Result<thread::JoinHandle<()>, String> {
+  if config.token.trim().is_empty() {
+    // Guard: never expose an unauthenticated submission endpoint.
+    return Err("HTTP API token is empty.".to_string());
+  }
+  let server = Server::http(&config.bind_address)
+    .map_err(|error| format!("Failed to bind HTTP API on {}: {error}", config.bind_address))?;
+  Ok(thread::spawn(move || {
+    let mut rate_limiters = HttpApiRateLimiters::default();
+    // NOTE: Requests are handled one at a time; submissions only write files, the OCR itself runs elsewhere.
+    for request in server.incoming_requests() {
+      handle_request(request, &config, &token_authorizer, &handler, &control_handler, &mut rate_limiters);
+    }
+  }))
+}
@@ -0,0 +1,57 @@
+/*!
+Responsibility:
+- Count HTTP API requests per client address over a sliding one-minute window, so a runaway script gets `429`
+  instead of flooding the GPU queue.
+*/
+
+use std::{
+  collections::{HashMap, VecDeque},
+  net::IpAddr,
+  time::{Duration, Instant},
+};
+
+pub const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+
+// NOTE: Past this many tracked addresses, addresses without a request inside the window are forgotten.
+const MAX_TRACKED_CLIENTS: usize = 4096;
+
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+  request_instants_by_client: HashMap<IpAddr, VecDeque<Instant>>,
+}
+
+impl RateLimiter {
+  // NOTE: `max_requests_per_window` 0 disables the limit. Err carries how long until the client may send again;
+  // rejected requests are not counted, so a client that keeps retrying is not locked out for longer.
+  pub fn check(&mut self, client_address: IpAddr, max_requests_per_window: u32, now: Instant) -> Result<(), Duration> {
+    if max_requests_per_window == 0 {
+      return Ok(());
+    }
+    if self.request_instants_by_client.len() >= MAX_TRACKED_CLIENTS
+      && !self.request_instants_by_client.contains_key(&client_address)
+    {
+      self.forget_idle_clients(now);
+    }
+    let request_instants = self.request_instants_by_client.entry(client_address).or_default();
+    while request_instants
+      .front()
+      .is_some_and(|instant| now.duration_since(*instant) >= RATE_LIMIT_WINDOW)
+    {
+      request_instants.pop_front();
+    }
+    if request_instants.len() >= max_requests_per_window as usize {
+      let oldest_instant = request_instants.front().copied().unwrap_or(now);
+      return Err(RATE_LIMIT_WINDOW.saturating_sub(now.duration_since(oldest_instant)));
+    }
+    request_instants.push_back(now);
+    Ok(())
+  }
+
+  fn forget_idle_clients(&mut self, now: Instant) {
+    self.request_instants_by_client.retain(|_, request_instants| {
+      request_instants
+        .back()
+        .is_some_and(|instant| now.duration_since(*instant) < RATE_LIMIT_WINDOW)
+    });
+  }
+}
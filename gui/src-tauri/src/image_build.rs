@@ -0,0 +1,145 @@
+/*!
+Responsibility:
+- Fingerprint the Docker build inputs (Dockerfile, requirements, compose.yaml) so a stale OCR image can be detected.
+- Run `docker compose build` in the background for the GUI and keep its output for status polling.
+*/
+
+use std::{
+  collections::VecDeque,
+  fs,
+  path::Path,
+  process::{Command, Stdio},
+  sync::{Arc, Mutex},
+  thread,
+};
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::log_capture::for_each_captured_line;
+
+// NOTE: compose.yaml copies this variable into the image label below at build time.
+pub const BUILD_INPUTS_HASH_ENVIRONMENT_VARIABLE_NAME: &str = "OCR_AGENT_BUILD_INPUTS_HASH";
+pub const BUILD_INPUTS_HASH_IMAGE_LABEL: &str = "io.ocr-agent.build-inputs-hash";
+
+// NOTE: `src/` is bind-mounted at runtime, so only files that change the image contents are tracked.
+const BUILD_INPUT_RELATIVE_PATHS: &[&str] = &[
+  "docker/Dockerfile",
+  "docker/Dockerfile.cpu",
+  "requirements.base.txt",
+  "requirements.dev.txt",
+  "compose.yaml",
+];
+const MAX_BUILD_LOG_LINES: usize = 1500;
+
+#[derive(Default)]
+pub(crate) struct ImageBuildRuntimeState {
+  is_running: bool,
+  log_lines: VecDeque<String>,
+  is_last_build_successful: Option<bool>,
+}
+
+pub type SharedImageBuildRuntimeState = Arc<Mutex<ImageBuildRuntimeState>>;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageBuildStatus {
+  pub is_running: bool,
+  pub log_lines: Vec<String>,
+  pub is_last_build_successful: Option<bool>,
+}
+
+pub fn new_shared_image_build_state() -> SharedImageBuildRuntimeState {
+  Arc::new(Mutex::new(ImageBuildRuntimeState::default()))
+}
+
+pub fn compute_build_inputs_hash(repo_root: &Path) -> String {
+  let mut hasher = Sha256::new();
+  for relative_path in BUILD_INPUT_RELATIVE_PATHS {
+    hasher.update(relative_path.as_bytes());
+    hasher.update([0u8]);
+    match fs::read(repo_root.join(relative_path)) {
+      // Guard: normalize line endings so a Windows checkout hashes the same as the one that built the image.
+      Ok(bytes) => hasher.update(String::from_utf8_lossy(&bytes).replace("\r\n", "\n").as_bytes()),
+      Err(_) => hasher.update(b"<missing>"),
+    }
+    hasher.update([0u8]);
+  }
+  format!("sha256:{:x}", hasher.finalize())
+}
+
+fn append_build_log_line(state: &SharedImageBuildRuntimeState, line: String) {
+  let Ok(mut locked) = state.lock() else {
+    return;
+  };
+  locked.log_lines.push_back(line);
+  while locked.log_lines.len() > MAX_BUILD_LOG_LINES {
+    locked.log_lines.pop_front();
+  }
+}
+
+fn spawn_build_log_reader_thread(state: SharedImageBuildRuntimeState, stream: impl std::io::Read + Send + 'static) {
+  thread::spawn(move || {
+    for_each_captured_line(stream, |line| append_build_log_line(&state, line));
+  });
+}
+
+pub fn start_image_build(state: &SharedImageBuildRuntimeState, mut command: Command) -> Result<(), String> {
+  {
+    let mut locked = state.lock().map_err(|_| "Image build state lock poisoned".to_string())?;
+    if locked.is_running {
+      // Guard: two concurrent builds of the same service would race on the image tag.
+      return Err("An image build is already running.".to_string());
+    }
+    locked.is_running = true;
+    locked.log_lines.clear();
+    locked.is_last_build_successful = None;
+  }
+
+  command.stdout(Stdio::piped());
+  command.stderr(Stdio::piped());
+  let mut child = match command.spawn() {
+    Ok(child) => child,
+    Err(error) => {
+      if let Ok(mut locked) = state.lock() {
+        locked.is_running = false;
+        locked.is_last_build_successful = Some(false);
+      }
+      return Err(format!("Failed to start docker compose build. {error}"));
+    }
+  };
+  if let Some(stream) = child.stdout.take() {
+    spawn_build_log_reader_thread(state.clone(), stream);
+  }
+  if let Some(stream) = child.stderr.take() {
+    spawn_build_log_reader_thread(state.clone(), stream);
+  }
+
+  let waiter_state = state.clone();
+  thread::spawn(move || {
+    let is_successful = child.wait().map(|status| status.success()).unwrap_or(false);
+    append_build_log_line(
+      &waiter_state,
+      format!("[backend] build {}", if is_successful { "finished" } else { "failed" }),
+    );
+    if let Ok(mut locked) = waiter_state.lock() {
+      locked.is_running = false;
+      locked.is_last_build_successful = Some(is_successful);
+    }
+  });
+  Ok(())
+}
+
+pub fn get_image_build_status(state: &SharedImageBuildRuntimeState) -> ImageBuildStatus {
+  match state.lock() {
+    Ok(locked) => ImageBuildStatus {
+      is_running: locked.is_running,
+      log_lines: locked.log_lines.iter().cloned().collect(),
+      is_last_build_successful: locked.is_last_build_successful,
+    },
+    Err(_) => ImageBuildStatus {
+      is_running: false,
+      log_lines: vec!["Image build state lock poisoned".to_string()],
+      is_last_build_successful: None,
+    },
+  }
+}
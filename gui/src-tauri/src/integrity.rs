@@ -0,0 +1,190 @@
+/*!
+Responsibility:
+- Sign job state and provenance manifests with a per-machine key (HMAC-SHA256; the key stays in the OS keychain),
+  written as `<file>.sig` sidecars next to the signed file.
+- Verify those signatures and re-check the checksums recorded in a provenance manifest, to detect later tampering
+  with OCR results in shared storage.
+*/
+
+use std::{
+  ffi::OsString,
+  fs,
+  path::{Path, PathBuf},
+};
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+  duplicate_detection::sha256_file_hex,
+  hex_codec::{decode_hex, encode_hex},
+  now_unix_timestamp_millis,
+  provenance::ProvenanceManifest,
+};
+
+const INTEGRITY_KEYCHAIN_SERVICE_NAME: &str = "ocr-agent-integrity";
+const INTEGRITY_KEYCHAIN_ACCOUNT_NAME: &str = "job-signing-key";
+const SIGNATURE_ALGORITHM: &str = "hmac-sha256";
+const SIGNATURE_FILE_SUFFIX: &str = ".sig";
+const SIGNING_KEY_BYTES: usize = 32;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileSignature {
+  pub algorithm: String,
+  // NOTE: Identifies the signing machine's key without revealing it (first bytes of the key's SHA-256).
+  pub key_id: String,
+  pub signed_unix_timestamp_millis: i64,
+  pub signature: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureStatus {
+  Valid,
+  Unsigned,
+  Tampered,
+  // NOTE: Signed on another machine (or before the key was reset); this machine cannot vouch for it.
+  SignedWithOtherKey,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SignedFileCheck {
+  pub relative_path: String,
+  pub status: SignatureStatus,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ChecksumComparison {
+  pub checked_file_count: usize,
+  pub modified_relative_paths: Vec<String>,
+  pub missing_relative_paths: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobIntegrityReport {
+  // NOTE: True only when every checked file carries a valid signature from this machine and no checksum differs.
+  pub is_intact: bool,
+  pub signed_files: Vec<SignedFileCheck>,
+  pub provenance_manifest_path: Option<String>,
+  pub checksums: Option<ChecksumComparison>,
+}
+
+impl JobIntegrityReport {
+  pub fn new(
+    signed_files: Vec<SignedFileCheck>,
+    provenance_manifest_path: Option<String>,
+    checksums: Option<ChecksumComparison>,
+  ) -> Self {
+    let are_signatures_valid =
+      !signed_files.is_empty() && signed_files.iter().all(|check| check.status == SignatureStatus::Valid);
+    let are_checksums_unchanged = checksums
+      .as_ref()
+      .map(|comparison| comparison.modified_relative_paths.is_empty() && comparison.missing_relative_paths.is_empty())
+      .unwrap_or(false);
+    Self {
+      is_intact: are_signatures_valid && are_checksums_unchanged,
+      signed_files,
+      provenance_manifest_path,
+      checksums,
+    }
+  }
+}
+
+fn keychain_entry() -> Result<keyring::Entry, String> {
+  keyring::Entry::new(INTEGRITY_KEYCHAIN_SERVICE_NAME, INTEGRITY_KEYCHAIN_ACCOUNT_NAME)
+    .map_err(|error| format!("Keychain is unavailable: {error}"))
+}
+
+fn read_signing_key() -> Option<Vec<u8>> {
+  let encoded_key = keychain_entry().ok()?.get_password().ok()?;
+  decode_hex(encoded_key.trim())
+}
+
+fn read_or_create_signing_key() -> Result<Vec<u8>, String> {
+  if let Some(signing_key) = read_signing_key() {
+    return Ok(signing_key);
+  }
+  let mut signing_key = vec![0u8; SIGNING_KEY_BYTES];
+  getrandom::getrandom(&mut signing_key).map_err(|error| format!("Failed to generate a signing key: {error}"))?;
+  keychain_entry()?
+    .set_password(&encode_hex(&signing_key))
+    .map_err(|error| format!("Failed to store the signing key in keychain: {error}"))?;
+  Ok(signing_key)
+}
+
+fn derive_key_id(signing_key: &[u8]) -> String {
+  encode_hex(&Sha256::digest(signing_key)[..8])
+}
+
+fn compute_signature(signing_key: &[u8], file_bytes: &[u8]) -> Result<String, String> {
+  let mut mac = Hmac::<Sha256>::new_from_slice(signing_key).map_err(|error| error.to_string())?;
+  mac.update(file_bytes);
+  Ok(encode_hex(&mac.finalize().into_bytes()))
+}
+
+pub fn signature_file_path(file_path: &Path) -> PathBuf {
+  let mut file_name = file_path.file_name().map(OsString::from).unwrap_or_default();
+  file_name.push(SIGNATURE_FILE_SUFFIX);
+  file_path.with_file_name(file_name)
+}
+
+// NOTE: The first signature creates this machine's key; later runs reuse it from the keychain.
+pub fn sign_file(file_path: &Path) -> Result<PathBuf, String> {
+  let signing_key = read_or_create_signing_key()?;
+  let file_bytes = fs::read(file_path).map_err(|error| format!("Failed to read {}: {error}", file_path.display()))?;
+  let signature = FileSignature {
+    algorithm: SIGNATURE_ALGORITHM.to_string(),
+    key_id: derive_key_id(&signing_key),
+    signed_unix_timestamp_millis: now_unix_timestamp_millis(),
+    signature: compute_signature(&signing_key, &file_bytes)?,
+  };
+  let signature_path = signature_file_path(file_path);
+  let serialized = serde_json::to_string_pretty(&signature).map_err(|error| error.to_string())?;
+  fs::write(&signature_path, serialized).map_err(|error| error.to_string())?;
+  Ok(signature_path)
+}
+
+// NOTE: Never creates a key; verifying on a machine that has not signed anything reports `SignedWithOtherKey`.
+pub fn verify_file_signature(file_path: &Path) -> Result<SignatureStatus, String> {
+  let signature_path = signature_file_path(file_path);
+  if !signature_path.exists() {
+    return Ok(SignatureStatus::Unsigned);
+  }
+  let raw_signature = fs::read_to_string(&signature_path).map_err(|error| error.to_string())?;
+  let Ok(signature) = serde_json::from_str::<FileSignature>(&raw_signature) else {
+    // Guard: a sidecar that no longer parses was edited too.
+    return Ok(SignatureStatus::Tampered);
+  };
+  if signature.algorithm != SIGNATURE_ALGORITHM {
+    return Err(format!("Unsupported signature algorithm: {}", signature.algorithm));
+  }
+  let Some(signing_key) = read_signing_key().filter(|signing_key| derive_key_id(signing_key) == signature.key_id) else {
+    return Ok(SignatureStatus::SignedWithOtherKey);
+  };
+  let Ok(file_bytes) = fs::read(file_path) else {
+    return Ok(SignatureStatus::Tampered);
+  };
+  let expected_signature = decode_hex(&signature.signature).unwrap_or_default();
+  let mut mac = Hmac::<Sha256>::new_from_slice(&signing_key).map_err(|error| error.to_string())?;
+  mac.update(&file_bytes);
+  // NOTE: `verify_slice` compares in constant time.
+  Ok(match mac.verify_slice(&expected_signature) {
+    Ok(()) => SignatureStatus::Valid,
+    Err(_) => SignatureStatus::Tampered,
+  })
+}
+
+pub fn compare_provenance_checksums(job_root_directory_path: &Path, manifest: &ProvenanceManifest) -> ChecksumComparison {
+  let mut comparison = ChecksumComparison::default();
+  for entry in manifest.inputs.iter().chain(manifest.outputs.iter()) {
+    comparison.checked_file_count += 1;
+    let file_path = job_root_directory_path.join(&entry.relative_path);
+    match sha256_file_hex(&file_path) {
+      Ok(actual_sha256) if actual_sha256 == entry.sha256 => {}
+      Ok(_) => comparison.modified_relative_paths.push(entry.relative_path.clone()),
+      Err(_) => comparison.missing_relative_paths.push(entry.relative_path.clone()),
+    }
+  }
+  comparison
+}
@@ -0,0 +1,104 @@
+/*!
+Responsibility:
+- Cancel a running job gracefully: write a marker the CLI checks before claiming each task, so the page in
+  progress finishes and nothing is left half written.
+- Stop a run that does not exit in time (SIGTERM to the host process group, `docker stop` for containers), put
+  tasks the stopped run left `running` back to `pending`, and describe the cancellation for the job state file.
+*/
+
+use std::{fs, io, path::Path, time::Duration};
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  docker_cleanup::run_docker_with_timeout,
+  job_pause::{signal_process_group, JobPauseTarget, JobPauseTargetKind},
+};
+
+// NOTE: Must match `CANCEL_MARKER_FILENAME` in `src/ocr_agent/config.py`; written into the job's output directory.
+pub const JOB_CANCEL_MARKER_FILENAME: &str = "cancel_requested";
+// NOTE: Long enough for one slow page plus model loading when the cancel arrives right after the start.
+pub const GRACEFUL_CANCEL_TIMEOUT: Duration = Duration::from_secs(180);
+pub const GRACEFUL_CANCEL_POLL_INTERVAL: Duration = Duration::from_secs(1);
+// NOTE: `docker stop` waits out its 10 s grace period before killing; past this the engine itself is wedged.
+const DOCKER_STOP_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobCancelMode {
+  // NOTE: Stops after the current page; escalates to `Immediate` after `GRACEFUL_CANCEL_TIMEOUT`.
+  #[default]
+  Graceful,
+  Immediate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobCancellationRecord {
+  pub mode: JobCancelMode,
+  pub requested_unix_timestamp_millis: i64,
+  // NOTE: Set when a graceful cancel timed out (or was followed by an immediate one) and the run was stopped.
+  #[serde(default)]
+  pub escalated_unix_timestamp_millis: Option<i64>,
+  // NOTE: Tasks the stopped run left `running`; they are `pending` again, so a retry picks them up.
+  #[serde(default)]
+  pub requeued_task_count: usize,
+}
+
+impl JobCancellationRecord {
+  pub fn new(mode: JobCancelMode, now_unix_timestamp_millis: i64) -> Self {
+    JobCancellationRecord {
+      mode,
+      requested_unix_timestamp_millis: now_unix_timestamp_millis,
+      escalated_unix_timestamp_millis: None,
+      requeued_task_count: 0,
+    }
+  }
+}
+
+pub fn write_cancel_marker(output_directory_path: &Path) -> Result<(), String> {
+  fs::create_dir_all(output_directory_path).map_err(|error| error.to_string())?;
+  fs::write(output_directory_path.join(JOB_CANCEL_MARKER_FILENAME), b"")
+    .map_err(|error| format!("Failed to write the cancel marker: {error}"))
+}
+
+// NOTE: A leftover marker would stop the next run before its first page, so starts clear it too.
+pub fn remove_cancel_marker(output_directory_path: &Path) -> Result<(), String> {
+  match fs::remove_file(output_directory_path.join(JOB_CANCEL_MARKER_FILENAME)) {
+    Err(error) if error.kind() != io::ErrorKind::NotFound => {
+      Err(format!("Failed to remove the cancel marker: {error}"))
+    }
+    _ => Ok(()),
+  }
+}
+
+pub fn requeue_running_tasks(queue_database_path: &Path) -> Result<usize, String> {
+  if !queue_database_path.exists() {
+    return Ok(0);
+  }
+  let connection = Connection::open(queue_database_path).map_err(|error| error.to_string())?;
+  connection
+    .execute("UPDATE tasks SET status = 'pending' WHERE status = 'running'", [])
+    .map_err(|error| error.to_string())
+}
+
+// NOTE: SIGTERM lets the pipeline exit on its own; `docker stop` sends SIGTERM and kills only after its grace
+// period, so the container is removed cleanly instead of being left behind by a killed compose client.
+// Returns one message per target that could not be stopped; workers that already exited are not reported.
+pub fn terminate_job_targets(targets: &[JobPauseTarget]) -> Vec<String> {
+  let mut error_messages: Vec<String> = vec![];
+  for target in targets {
+    let result = match &target.kind {
+      JobPauseTargetKind::Container(container) => {
+        run_docker_with_timeout(&["stop", container], DOCKER_STOP_TIMEOUT).map(|_| ())
+      }
+      JobPauseTargetKind::HostProcessGroup(process_group_id) => signal_process_group(*process_group_id, "TERM"),
+    };
+    if let Err(error) = result {
+      if !target.is_worker {
+        error_messages.push(error);
+      }
+    }
+  }
+  error_messages
+}
@@ -0,0 +1,238 @@
+/*!
+Responsibility:
+- Record which job roots must complete successfully before another job root may start (`job_dependencies.json`).
+- Reject dependency cycles, and propagate finished outcomes: start dependents whose prerequisites all succeeded, block the rest.
+*/
+
+use std::{
+  collections::BTreeMap,
+  fs,
+  path::Path,
+  sync::Mutex,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::now_unix_timestamp_millis;
+
+pub const JOB_DEPENDENCIES_FILENAME: &str = "job_dependencies.json";
+
+// NOTE: Waiter threads of different jobs can finish at once; each load-modify-save cycle runs under this lock.
+static JOB_DEPENDENCY_GRAPH_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobRunOutcome {
+  Succeeded,
+  Failed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependentJobStatus {
+  Waiting,
+  Started,
+  Blocked,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependentJob {
+  pub job_root_directory_path: String,
+  pub prerequisite_job_root_directory_paths: Vec<String>,
+  pub status: DependentJobStatus,
+  // NOTE: The job whose failure blocked this one (possibly several links up the chain); rerunning it unblocks.
+  pub blocked_by_job_root_directory_path: Option<String>,
+  pub blocked_reason: Option<String>,
+  pub declared_unix_timestamp_millis: i64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JobDependencyGraph {
+  #[serde(default)]
+  pub dependent_jobs: Vec<DependentJob>,
+  // NOTE: Outcome of each job's latest finished run; cleared when it starts again so dependents wait for the new run.
+  #[serde(default)]
+  pub outcome_by_job_root: BTreeMap<String, JobRunOutcome>,
+}
+
+fn load_job_dependency_graph(graph_file_path: &Path) -> Result<JobDependencyGraph, String> {
+  if !graph_file_path.exists() {
+    return Ok(JobDependencyGraph::default());
+  }
+  let raw = fs::read_to_string(graph_file_path).map_err(|error| error.to_string())?;
+  serde_json::from_str::<JobDependencyGraph>(&raw)
+    .map_err(|error| format!("Invalid job dependency file {}: {error}", graph_file_path.display()))
+}
+
+pub fn read_job_dependency_graph(graph_file_path: &Path) -> Result<JobDependencyGraph, String> {
+  let _guard = JOB_DEPENDENCY_GRAPH_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+  load_job_dependency_graph(graph_file_path)
+}
+
+pub fn update_job_dependency_graph<T>(
+  graph_file_path: &Path,
+  update: impl FnOnce(&mut JobDependencyGraph) -> Result<T, String>,
+) -> Result<T, String> {
+  let _guard = JOB_DEPENDENCY_GRAPH_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+  let mut graph = load_job_dependency_graph(graph_file_path)?;
+  let result = update(&mut graph)?;
+  if let Some(parent) = graph_file_path.parent() {
+    fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+  }
+  let serialized = serde_json::to_string_pretty(&graph).map_err(|error| error.to_string())?;
+  fs::write(graph_file_path, serialized).map_err(|error| error.to_string())?;
+  Ok(result)
+}
+
+impl JobDependencyGraph {
+  fn prerequisites_of(&self, job_root_directory_path: &str) -> &[String] {
+    self
+      .dependent_jobs
+      .iter()
+      .find(|dependent| dependent.job_root_directory_path == job_root_directory_path)
+      .map(|dependent| dependent.prerequisite_job_root_directory_paths.as_slice())
+      .unwrap_or(&[])
+  }
+
+  // NOTE: Returns the chain `from -> ... -> target` when `target` is reachable through existing declarations.
+  fn find_prerequisite_path(&self, from: &str, target: &str, visited: &mut Vec<String>) -> Option<Vec<String>> {
+    if from == target {
+      return Some(vec![from.to_string()]);
+    }
+    if visited.iter().any(|seen| seen == from) {
+      return None;
+    }
+    visited.push(from.to_string());
+    for prerequisite in self.prerequisites_of(from) {
+      if let Some(mut chain) = self.find_prerequisite_path(prerequisite, target, visited) {
+        chain.insert(0, from.to_string());
+        return Some(chain);
+      }
+    }
+    None
+  }
+
+  // NOTE: An empty prerequisite list removes the declaration.
+  pub fn declare(&mut self, job_root_directory_path: &str, prerequisite_job_root_directory_paths: Vec<String>) -> Result<(), String> {
+    self
+      .dependent_jobs
+      .retain(|dependent| dependent.job_root_directory_path != job_root_directory_path);
+    if prerequisite_job_root_directory_paths.is_empty() {
+      return Ok(());
+    }
+    for prerequisite in &prerequisite_job_root_directory_paths {
+      if let Some(chain) = self.find_prerequisite_path(prerequisite, job_root_directory_path, &mut vec![]) {
+        // Guard: a cycle would leave every job in it waiting forever.
+        return Err(format!(
+          "Dependency cycle: {job_root_directory_path} -> {}",
+          chain.join(" -> ")
+        ));
+      }
+    }
+    self.dependent_jobs.push(DependentJob {
+      job_root_directory_path: job_root_directory_path.to_string(),
+      prerequisite_job_root_directory_paths,
+      status: DependentJobStatus::Waiting,
+      blocked_by_job_root_directory_path: None,
+      blocked_reason: None,
+      declared_unix_timestamp_millis: now_unix_timestamp_millis(),
+    });
+    self.propagate_failures();
+    Ok(())
+  }
+
+  pub fn record_run_started(&mut self, job_root_directory_path: &str) {
+    self.outcome_by_job_root.remove(job_root_directory_path);
+    for dependent in &mut self.dependent_jobs {
+      if dependent.job_root_directory_path == job_root_directory_path {
+        // NOTE: A manual run of a waiting job satisfies its declaration early; it is not started a second time.
+        dependent.status = DependentJobStatus::Started;
+        dependent.blocked_by_job_root_directory_path = None;
+        dependent.blocked_reason = None;
+      } else if dependent.blocked_by_job_root_directory_path.as_deref() == Some(job_root_directory_path) {
+        dependent.status = DependentJobStatus::Waiting;
+        dependent.blocked_by_job_root_directory_path = None;
+        dependent.blocked_reason = None;
+      }
+    }
+  }
+
+  pub fn record_run_finished(&mut self, job_root_directory_path: &str, outcome: JobRunOutcome) {
+    self
+      .outcome_by_job_root
+      .insert(job_root_directory_path.to_string(), outcome);
+    self.propagate_failures();
+  }
+
+  pub fn mark_blocked(&mut self, job_root_directory_path: &str, reason: String) {
+    if let Some(dependent) = self
+      .dependent_jobs
+      .iter_mut()
+      .find(|dependent| dependent.job_root_directory_path == job_root_directory_path)
+    {
+      dependent.status = DependentJobStatus::Blocked;
+      dependent.blocked_by_job_root_directory_path = Some(job_root_directory_path.to_string());
+      dependent.blocked_reason = Some(reason);
+    }
+  }
+
+  // NOTE: Repeats until stable so a failure blocks the whole chain below it, not just direct dependents.
+  fn propagate_failures(&mut self) {
+    loop {
+      let mut has_changed = false;
+      for index in 0..self.dependent_jobs.len() {
+        if self.dependent_jobs[index].status != DependentJobStatus::Waiting {
+          continue;
+        }
+        let blocking = self.dependent_jobs[index]
+          .prerequisite_job_root_directory_paths
+          .iter()
+          .find_map(|prerequisite| self.find_blocking_root(prerequisite));
+        if let Some((blocked_by, reason)) = blocking {
+          let dependent = &mut self.dependent_jobs[index];
+          dependent.status = DependentJobStatus::Blocked;
+          dependent.blocked_by_job_root_directory_path = Some(blocked_by);
+          dependent.blocked_reason = Some(reason);
+          has_changed = true;
+        }
+      }
+      if !has_changed {
+        return;
+      }
+    }
+  }
+
+  fn find_blocking_root(&self, prerequisite: &str) -> Option<(String, String)> {
+    if self.outcome_by_job_root.get(prerequisite) == Some(&JobRunOutcome::Failed) {
+      return Some((prerequisite.to_string(), format!("Prerequisite failed: {prerequisite}")));
+    }
+    let blocked_prerequisite = self.dependent_jobs.iter().find(|dependent| {
+      dependent.job_root_directory_path == prerequisite && dependent.status == DependentJobStatus::Blocked
+    })?;
+    let blocked_by = blocked_prerequisite
+      .blocked_by_job_root_directory_path
+      .clone()
+      .unwrap_or_else(|| prerequisite.to_string());
+    Some((blocked_by, format!("Prerequisite is blocked: {prerequisite}")))
+  }
+
+  // NOTE: The returned jobs are marked started; the caller must start them (or mark them blocked on failure).
+  pub fn take_ready_jobs(&mut self) -> Vec<String> {
+    let mut ready_job_roots: Vec<String> = vec![];
+    for index in 0..self.dependent_jobs.len() {
+      let dependent = &self.dependent_jobs[index];
+      if dependent.status != DependentJobStatus::Waiting {
+        continue;
+      }
+      let is_ready = dependent
+        .prerequisite_job_root_directory_paths
+        .iter()
+        .all(|prerequisite| self.outcome_by_job_root.get(prerequisite) == Some(&JobRunOutcome::Succeeded));
+      if is_ready {
+        self.dependent_jobs[index].status = DependentJobStatus::Started;
+        ready_job_roots.push(self.dependent_jobs[index].job_root_directory_path.clone());
+      }
+    }
+    ready_job_roots
+  }
+}
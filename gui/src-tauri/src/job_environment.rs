@@ -0,0 +1,65 @@
+/*!
+Responsibility:
+- Validate a job's `extra_environment` map (allow-listed name prefixes, no names the GUI already derives from settings).
+- Keep values safe to hand to `docker compose run -e` and `Command::env` (no NUL bytes, bounded size).
+*/
+
+use std::collections::BTreeMap;
+
+// NOTE: Only engine knobs; anything else (PATH, HF_TOKEN, proxy settings) could change what the container runs or sends out.
+pub const ALLOWED_EXTRA_ENVIRONMENT_PREFIXES: &[&str] = &["OCR_AGENT_", "DEEPSEEK_"];
+
+const MAX_EXTRA_ENVIRONMENT_VARIABLE_COUNT: usize = 64;
+const MAX_EXTRA_ENVIRONMENT_VALUE_BYTES: usize = 8192;
+
+fn validate_extra_environment_variable_name(name: &str, managed_variable_names: &[&str]) -> Result<(), String> {
+  let is_well_formed = name
+    .chars()
+    .all(|character| character.is_ascii_uppercase() || character.is_ascii_digit() || character == '_');
+  if !is_well_formed {
+    return Err(format!(
+      "Invalid extra environment variable name {name:?}: use only A-Z, 0-9 and _."
+    ));
+  }
+  let has_allowed_prefix = ALLOWED_EXTRA_ENVIRONMENT_PREFIXES
+    .iter()
+    .any(|prefix| name.len() > prefix.len() && name.starts_with(prefix));
+  if !has_allowed_prefix {
+    return Err(format!(
+      "Extra environment variable {name} is not allowed; names must start with one of: {}.",
+      ALLOWED_EXTRA_ENVIRONMENT_PREFIXES.join(", ")
+    ));
+  }
+  // Guard: two sources for one variable would make the effective value depend on argument order.
+  if managed_variable_names.iter().any(|managed_name| *managed_name == name) {
+    return Err(format!(
+      "Extra environment variable {name} is already set from the job settings; change that setting instead."
+    ));
+  }
+  Ok(())
+}
+
+pub fn validate_extra_environment(
+  extra_environment: &BTreeMap<String, String>,
+  managed_variable_names: &[&str],
+) -> Result<(), String> {
+  if extra_environment.len() > MAX_EXTRA_ENVIRONMENT_VARIABLE_COUNT {
+    return Err(format!(
+      "Too many extra environment variables ({}); at most {MAX_EXTRA_ENVIRONMENT_VARIABLE_COUNT} are allowed.",
+      extra_environment.len()
+    ));
+  }
+  for (name, value) in extra_environment {
+    validate_extra_environment_variable_name(name, managed_variable_names)?;
+    // Guard: `Command::env` rejects NUL bytes at spawn time, long after the settings were saved.
+    if value.contains('\0') {
+      return Err(format!("Extra environment variable {name} contains a NUL byte."));
+    }
+    if value.len() > MAX_EXTRA_ENVIRONMENT_VALUE_BYTES {
+      return Err(format!(
+        "Extra environment variable {name} is longer than {MAX_EXTRA_ENVIRONMENT_VALUE_BYTES} bytes."
+      ));
+    }
+  }
+  Ok(())
+}
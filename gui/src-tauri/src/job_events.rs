@@ -0,0 +1,57 @@
+/*!
+Responsibility:
+- Name and shape the events pushed to the GUI while jobs run (`job://status`, `job://log-line`, `job://task-finished`),
+  so the UI follows progress as it happens instead of polling every job.
+- Recognize the CLI's per-task result lines in the job output.
+*/
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+// NOTE: Payload is the same `JobStatus` that `get_job_status` returns.
+pub const JOB_STATUS_EVENT: &str = "job://status";
+pub const JOB_LOG_LINE_EVENT: &str = "job://log-line";
+pub const JOB_TASK_FINISHED_EVENT: &str = "job://task-finished";
+
+// NOTE: Only diffs the in-memory running jobs; SQLite is read only for the roots that changed.
+pub const JOB_STATUS_EVENT_PUMP_INTERVAL: Duration = Duration::from_millis(250);
+
+// NOTE: Must match the lines printed by `_run_run_command` in `src/ocr_agent/cli.py`.
+const TASK_COMPLETED_LINE_PREFIX: &str = "Task completed (task_id=";
+const TASK_FAILED_LINE_PREFIX: &str = "Task failed (task_id=";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobLogLineEvent {
+  pub job_root_directory_path: String,
+  // NOTE: Formatted like the lines `get_job_logs` returns (`[stdout] ...`, `[backend] ...`); output that is not valid
+  // UTF-8 arrives with U+FFFD in place of the undecodable bytes.
+  pub line: String,
+  // NOTE: The line is a terminal `\r` overwrite of the previous log line (a progress bar), not a new line.
+  pub replaces_previous_line: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FinishedTaskStatus {
+  Completed,
+  Failed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobTaskFinishedEvent {
+  pub job_root_directory_path: String,
+  pub task_id: i64,
+  pub status: FinishedTaskStatus,
+}
+
+pub fn parse_task_finished_line(line: &str) -> Option<(i64, FinishedTaskStatus)> {
+  let line = line.trim_start();
+  let (remainder, status) = if let Some(remainder) = line.strip_prefix(TASK_COMPLETED_LINE_PREFIX) {
+    (remainder, FinishedTaskStatus::Completed)
+  } else {
+    (line.strip_prefix(TASK_FAILED_LINE_PREFIX)?, FinishedTaskStatus::Failed)
+  };
+  let (task_id, _) = remainder.split_once(')')?;
+  Some((task_id.parse().ok()?, status))
+}
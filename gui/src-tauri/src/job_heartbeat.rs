@@ -0,0 +1,99 @@
+/*!
+Responsibility:
+- Periodically write `heartbeat.json` (timestamp, backend pid, container name) into every active job root.
+- Remove the heartbeat once a job stops, so a stale file left behind means the owning process crashed.
+- Tell "actively running elsewhere" from "abandoned" for the dashboard and container adoption.
+*/
+
+use std::{
+  collections::HashSet,
+  fs,
+  path::{Path, PathBuf},
+  sync::Arc,
+  thread,
+  time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+pub const HEARTBEAT_FILENAME: &str = "heartbeat.json";
+
+const HEARTBEAT_WRITE_INTERVAL: Duration = Duration::from_secs(5);
+// NOTE: Several missed writes in a row; a single slow network-share write must not look like a crash.
+pub const HEARTBEAT_STALE_AFTER: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobHeartbeat {
+  pub updated_unix_timestamp_millis: i64,
+  // NOTE: Process id of the backend driving the job, not of the OCR process or container.
+  pub pid: u32,
+  pub container_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobHeartbeatStatus {
+  pub heartbeat: Option<JobHeartbeat>,
+  pub age_seconds: Option<i64>,
+  pub is_stale: bool,
+}
+
+pub type JobHeartbeatProvider = Arc<dyn Fn() -> Vec<(PathBuf, JobHeartbeat)> + Send + Sync>;
+
+pub fn job_heartbeat_file_path(job_root_directory_path: &Path) -> PathBuf {
+  job_root_directory_path.join(HEARTBEAT_FILENAME)
+}
+
+pub fn write_job_heartbeat(job_root_directory_path: &Path, heartbeat: &JobHeartbeat) -> Result<(), String> {
+  let heartbeat_file_path = job_heartbeat_file_path(job_root_directory_path);
+  let temporary_path = heartbeat_file_path.with_extension("json.tmp");
+  let serialized = serde_json::to_string_pretty(heartbeat).map_err(|error| error.to_string())?;
+  fs::write(&temporary_path, serialized).map_err(|error| error.to_string())?;
+  fs::rename(&temporary_path, &heartbeat_file_path).map_err(|error| error.to_string())?;
+  Ok(())
+}
+
+pub fn read_job_heartbeat_best_effort(job_root_directory_path: &Path) -> Option<JobHeartbeat> {
+  let raw = fs::read_to_string(job_heartbeat_file_path(job_root_directory_path)).ok()?;
+  serde_json::from_str::<JobHeartbeat>(&raw).ok()
+}
+
+pub fn remove_job_heartbeat_best_effort(job_root_directory_path: &Path) {
+  let _ = fs::remove_file(job_heartbeat_file_path(job_root_directory_path));
+}
+
+pub fn describe_job_heartbeat(job_root_directory_path: &Path, now_unix_timestamp_millis: i64) -> JobHeartbeatStatus {
+  let Some(heartbeat) = read_job_heartbeat_best_effort(job_root_directory_path) else {
+    return JobHeartbeatStatus {
+      heartbeat: None,
+      age_seconds: None,
+      is_stale: false,
+    };
+  };
+  // NOTE: Clock skew between machines sharing a job root can make the heartbeat look slightly in the future.
+  let age_millis = now_unix_timestamp_millis.saturating_sub(heartbeat.updated_unix_timestamp_millis).max(0);
+  JobHeartbeatStatus {
+    heartbeat: Some(heartbeat),
+    age_seconds: Some(age_millis / 1000),
+    is_stale: age_millis > HEARTBEAT_STALE_AFTER.as_millis() as i64,
+  }
+}
+
+pub fn start_job_heartbeat_writer(heartbeat_provider: JobHeartbeatProvider) {
+  thread::spawn(move || {
+    let mut written_job_root_directory_paths: HashSet<PathBuf> = HashSet::new();
+    loop {
+      let heartbeats = heartbeat_provider();
+      let active_job_root_directory_paths: HashSet<PathBuf> =
+        heartbeats.iter().map(|(job_root_directory_path, _)| job_root_directory_path.clone()).collect();
+      for job_root_directory_path in written_job_root_directory_paths.difference(&active_job_root_directory_paths) {
+        remove_job_heartbeat_best_effort(job_root_directory_path);
+      }
+      for (job_root_directory_path, heartbeat) in &heartbeats {
+        // Guard: a vanished volume or read-only share must not affect the OCR job.
+        let _ = write_job_heartbeat(job_root_directory_path, heartbeat);
+      }
+      written_job_root_directory_paths = active_job_root_directory_paths;
+      thread::sleep(HEARTBEAT_WRITE_INTERVAL);
+    }
+  });
+}
@@ -0,0 +1,194 @@
+/*!
+Responsibility:
+- Keep an app-wide history of every run of every job root in `<app config>/history.sqlite3`, so recent jobs survive
+  an app restart (per-root details stay in each root's `runs.json`).
+- List the most recently run job roots with their latest run for the "recent jobs" panel.
+*/
+
+use std::{fs, path::Path, time::Duration};
+
+use rusqlite::{params, Connection};
+use serde::Serialize;
+
+use crate::job_runs::RecordedRunOutcome;
+
+pub const JOB_HISTORY_DATABASE_FILENAME: &str = "history.sqlite3";
+
+pub const DEFAULT_RECENT_JOB_LIMIT: usize = 20;
+const MAX_RECENT_JOB_LIMIT: usize = 200;
+// NOTE: Several job waiters may finish at once; each opens its own connection.
+const JOB_HISTORY_BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecentJob {
+  pub job_root_directory_path: String,
+  // NOTE: False once the folder was moved or deleted; the entry is kept so the history stays complete.
+  pub is_job_root_present: bool,
+  pub run_count: u32,
+  pub last_run_mode: String,
+  pub last_started_unix_timestamp_millis: i64,
+  pub last_finished_unix_timestamp_millis: Option<i64>,
+  pub last_exit_status: Option<String>,
+  pub last_exit_code: Option<i32>,
+  // NOTE: None while the run is in progress, or when the app exited before the run finished.
+  pub last_outcome: Option<RecordedRunOutcome>,
+  pub last_output_markdown_path: String,
+  pub is_running: bool,
+}
+
+fn open_job_history_database(app_config_directory_path: &Path) -> Result<Connection, String> {
+  fs::create_dir_all(app_config_directory_path).map_err(|error| error.to_string())?;
+  let connection =
+    Connection::open(app_config_directory_path.join(JOB_HISTORY_DATABASE_FILENAME)).map_err(|error| error.to_string())?;
+  connection.busy_timeout(JOB_HISTORY_BUSY_TIMEOUT).map_err(|error| error.to_string())?;
+  connection
+    .execute_batch(
+      "CREATE TABLE IF NOT EXISTS job_runs (
+         history_id INTEGER PRIMARY KEY AUTOINCREMENT,
+         job_root_directory_path TEXT NOT NULL,
+         run_mode TEXT NOT NULL,
+         started_unix_timestamp_millis INTEGER NOT NULL,
+         finished_unix_timestamp_millis INTEGER,
+         exit_status TEXT,
+         exit_code INTEGER,
+         outcome TEXT,
+         output_markdown_path TEXT NOT NULL
+       );
+       CREATE INDEX IF NOT EXISTS job_runs_by_root ON job_runs (job_root_directory_path, history_id);",
+    )
+    .map_err(|error| error.to_string())?;
+  Ok(connection)
+}
+
+fn outcome_to_text(outcome: RecordedRunOutcome) -> Result<String, String> {
+  match serde_json::to_value(outcome).map_err(|error| error.to_string())? {
+    serde_json::Value::String(text) => Ok(text),
+    other => Err(format!("Unexpected run outcome encoding: {other}")),
+  }
+}
+
+fn outcome_from_text(text: Option<String>) -> Option<RecordedRunOutcome> {
+  // Guard: an outcome written by a newer app version is shown as unknown instead of failing the whole list.
+  text.and_then(|text| serde_json::from_value(serde_json::Value::String(text)).ok())
+}
+
+// NOTE: Returns the history id, so the waiter can close the same entry.
+pub fn record_job_history_started(
+  app_config_directory_path: &Path,
+  job_root_directory_path: &Path,
+  run_mode: &str,
+  started_unix_timestamp_millis: i64,
+  output_markdown_path: &str,
+) -> Result<i64, String> {
+  let connection = open_job_history_database(app_config_directory_path)?;
+  connection
+    .execute(
+      "INSERT INTO job_runs (job_root_directory_path, run_mode, started_unix_timestamp_millis, output_markdown_path)
+       VALUES (?1, ?2, ?3, ?4)",
+      params![
+        job_root_directory_path.to_string_lossy().to_string(),
+        run_mode,
+        started_unix_timestamp_millis,
+        output_markdown_path
+      ],
+    )
+    .map_err(|error| error.to_string())?;
+  Ok(connection.last_insert_rowid())
+}
+
+pub fn record_job_history_finished(
+  app_config_directory_path: &Path,
+  history_id: i64,
+  finished_unix_timestamp_millis: i64,
+  exit_status: &str,
+  exit_code: Option<i32>,
+  outcome: RecordedRunOutcome,
+) -> Result<(), String> {
+  let connection = open_job_history_database(app_config_directory_path)?;
+  let updated_row_count = connection
+    .execute(
+      "UPDATE job_runs
+       SET finished_unix_timestamp_millis = ?1, exit_status = ?2, exit_code = ?3, outcome = ?4
+       WHERE history_id = ?5",
+      params![
+        finished_unix_timestamp_millis,
+        exit_status,
+        exit_code,
+        outcome_to_text(outcome)?,
+        history_id
+      ],
+    )
+    .map_err(|error| error.to_string())?;
+  if updated_row_count == 0 {
+    return Err(format!("Job history entry {history_id} is missing"));
+  }
+  Ok(())
+}
+
+// NOTE: Mirrors `rename_job_run_output`, so the recent jobs panel opens the renamed file.
+pub fn rename_job_history_output(
+  app_config_directory_path: &Path,
+  job_root_directory_path: &Path,
+  previous_output_markdown_path: &str,
+  renamed_output_markdown_path: &str,
+) -> Result<(), String> {
+  let connection = open_job_history_database(app_config_directory_path)?;
+  connection
+    .execute(
+      "UPDATE job_runs SET output_markdown_path = ?1 WHERE job_root_directory_path = ?2 AND output_markdown_path = ?3",
+      params![
+        renamed_output_markdown_path,
+        job_root_directory_path.to_string_lossy().to_string(),
+        previous_output_markdown_path
+      ],
+    )
+    .map_err(|error| error.to_string())?;
+  Ok(())
+}
+
+// NOTE: One entry per job root (its latest run), most recently started first.
+pub fn list_recent_job_roots(
+  app_config_directory_path: &Path,
+  limit: usize,
+  is_job_root_running: impl Fn(&Path) -> bool,
+) -> Result<Vec<RecentJob>, String> {
+  let limit = limit.clamp(1, MAX_RECENT_JOB_LIMIT);
+  let connection = open_job_history_database(app_config_directory_path)?;
+  let mut statement = connection
+    .prepare(
+      "SELECT latest.job_root_directory_path, latest.run_mode, latest.started_unix_timestamp_millis,
+              latest.finished_unix_timestamp_millis, latest.exit_status, latest.exit_code, latest.outcome,
+              latest.output_markdown_path,
+              (SELECT COUNT(*) FROM job_runs AS runs WHERE runs.job_root_directory_path = latest.job_root_directory_path)
+       FROM job_runs AS latest
+       WHERE latest.history_id = (
+         SELECT MAX(newer.history_id) FROM job_runs AS newer
+         WHERE newer.job_root_directory_path = latest.job_root_directory_path
+       )
+       ORDER BY latest.history_id DESC
+       LIMIT ?1",
+    )
+    .map_err(|error| error.to_string())?;
+  let mut rows = statement.query(params![limit as i64]).map_err(|error| error.to_string())?;
+  let mut recent_jobs: Vec<RecentJob> = vec![];
+  while let Some(row) = rows.next().map_err(|error| error.to_string())? {
+    let job_root_directory_path: String = row.get(0).map_err(|error| error.to_string())?;
+    let job_root_path = Path::new(&job_root_directory_path);
+    let last_finished_unix_timestamp_millis: Option<i64> = row.get(3).map_err(|error| error.to_string())?;
+    recent_jobs.push(RecentJob {
+      is_job_root_present: job_root_path.is_dir(),
+      // Guard: an unfinished entry is only "running" if this app instance is actually running it.
+      is_running: last_finished_unix_timestamp_millis.is_none() && is_job_root_running(job_root_path),
+      run_count: row.get::<_, i64>(8).map_err(|error| error.to_string())? as u32,
+      last_run_mode: row.get(1).map_err(|error| error.to_string())?,
+      last_started_unix_timestamp_millis: row.get(2).map_err(|error| error.to_string())?,
+      last_finished_unix_timestamp_millis,
+      last_exit_status: row.get(4).map_err(|error| error.to_string())?,
+      last_exit_code: row.get(5).map_err(|error| error.to_string())?,
+      last_outcome: outcome_from_text(row.get(6).map_err(|error| error.to_string())?),
+      last_output_markdown_path: row.get(7).map_err(|error| error.to_string())?,
+      job_root_directory_path,
+    });
+  }
+  Ok(recent_jobs)
+}
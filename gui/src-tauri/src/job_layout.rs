@@ -0,0 +1,91 @@
+/*!
+Responsibility:
+- Name the per-job input directory, output directory and queue database (`input/`, `output/`, `queue.sqlite3` by default).
+- Validate app-level overrides from `job_layout.json`; each job records the layout it was created with.
+*/
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+pub const JOB_DIRECTORY_LAYOUT_SETTINGS_FILENAME: &str = "job_layout.json";
+
+const DEFAULT_INPUT_DIRECTORY_NAME: &str = "input";
+const DEFAULT_OUTPUT_DIRECTORY_NAME: &str = "output";
+const DEFAULT_QUEUE_DATABASE_FILENAME: &str = "queue.sqlite3";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobDirectoryLayout {
+  pub input_directory_name: String,
+  pub output_directory_name: String,
+  pub queue_database_filename: String,
+}
+
+impl Default for JobDirectoryLayout {
+  fn default() -> Self {
+    Self {
+      input_directory_name: DEFAULT_INPUT_DIRECTORY_NAME.to_string(),
+      output_directory_name: DEFAULT_OUTPUT_DIRECTORY_NAME.to_string(),
+      queue_database_filename: DEFAULT_QUEUE_DATABASE_FILENAME.to_string(),
+    }
+  }
+}
+
+fn validate_layout_name(kind: &str, name: &str, reserved_name: &str) -> Result<(), String> {
+  let is_single_component = !name.is_empty()
+    && name != "."
+    && name != ".."
+    && !name.contains(['/', '\\', ':'])
+    && name.trim() == name;
+  if !is_single_component {
+    // Guard: names are joined onto the job root and passed into the container as `/data/<name>`.
+    return Err(format!("Invalid {kind} name: {name:?} (use a single folder/file name)."));
+  }
+  if name == reserved_name {
+    return Err(format!("{kind} name {name:?} is reserved for job settings."));
+  }
+  Ok(())
+}
+
+impl JobDirectoryLayout {
+  // NOTE: `reserved_name` is the job settings directory (`.ocr-agent`), which must not be reused.
+  pub fn validate(&self, reserved_name: &str) -> Result<(), String> {
+    validate_layout_name("input directory", &self.input_directory_name, reserved_name)?;
+    validate_layout_name("output directory", &self.output_directory_name, reserved_name)?;
+    validate_layout_name("queue database", &self.queue_database_filename, reserved_name)?;
+    let is_distinct = self.input_directory_name != self.output_directory_name
+      && self.input_directory_name != self.queue_database_filename
+      && self.output_directory_name != self.queue_database_filename;
+    if !is_distinct {
+      // Guard: reset deletes the output directory and the queue, so neither may alias the inputs.
+      return Err("Input directory, output directory and queue database names must all differ.".to_string());
+    }
+    Ok(())
+  }
+
+  pub fn input_directory_path(&self, job_root_directory_path: &Path) -> PathBuf {
+    job_root_directory_path.join(&self.input_directory_name)
+  }
+
+  pub fn output_directory_path(&self, job_root_directory_path: &Path) -> PathBuf {
+    job_root_directory_path.join(&self.output_directory_name)
+  }
+
+  pub fn queue_database_path(&self, job_root_directory_path: &Path) -> PathBuf {
+    job_root_directory_path.join(&self.queue_database_filename)
+  }
+
+  // NOTE: True when the job root already holds data in this layout (used to keep old jobs on the default names).
+  pub fn has_job_data(&self, job_root_directory_path: &Path) -> bool {
+    let is_non_empty_directory = |path: PathBuf| {
+      path
+        .read_dir()
+        .map(|mut entries| entries.next().is_some())
+        .unwrap_or(false)
+    };
+    self.queue_database_path(job_root_directory_path).exists()
+      || is_non_empty_directory(self.input_directory_path(job_root_directory_path))
+      || is_non_empty_directory(self.output_directory_path(job_root_directory_path))
+  }
+}
@@ -0,0 +1,166 @@
+/*!
+Responsibility:
+- Hold the recent log lines of each job root in memory, but only for roots that are running or were used recently:
+  idle roots are written to `<job root>/job_log.txt` and dropped, so long sessions over many roots stay bounded.
+- Load an evicted root's lines back from that file when they are asked for again.
+*/
+
+use std::{
+  collections::{HashMap, VecDeque},
+  fs,
+  path::{Path, PathBuf},
+  time::{Duration, Instant},
+};
+
+const JOB_LOG_FILENAME: &str = "job_log.txt";
+
+// NOTE: Running roots are never evicted; these bound only the roots whose logs are merely being looked at.
+const MAX_RESIDENT_IDLE_JOB_LOGS: usize = 8;
+const IDLE_JOB_LOG_EVICTION_AGE: Duration = Duration::from_secs(10 * 60);
+
+struct ResidentJobLog {
+  lines: VecDeque<String>,
+  last_used_at: Instant,
+  // NOTE: The last line is a `\r` progress update that the next line from its stream overwrites.
+  is_last_line_progress: bool,
+}
+
+impl ResidentJobLog {
+  fn new(lines: VecDeque<String>) -> Self {
+    ResidentJobLog {
+      lines,
+      last_used_at: Instant::now(),
+      is_last_line_progress: false,
+    }
+  }
+}
+
+#[derive(Default)]
+pub struct JobLogStore {
+  logs_by_root: HashMap<PathBuf, ResidentJobLog>,
+}
+
+pub struct EvictedJobLog {
+  pub job_root_directory_path: PathBuf,
+  pub lines: VecDeque<String>,
+}
+
+impl JobLogStore {
+  pub fn is_resident(&self, job_root_directory_path: &Path) -> bool {
+    self.logs_by_root.contains_key(job_root_directory_path)
+  }
+
+  // NOTE: Does not count as a use; for internal readers (failure classification) that must not keep a root resident.
+  pub fn peek(&self, job_root_directory_path: &Path) -> Option<&VecDeque<String>> {
+    self.logs_by_root.get(job_root_directory_path).map(|log| &log.lines)
+  }
+
+  pub fn lines(&mut self, job_root_directory_path: &Path) -> Option<Vec<String>> {
+    let log = self.logs_by_root.get_mut(job_root_directory_path)?;
+    log.last_used_at = Instant::now();
+    Some(log.lines.iter().cloned().collect())
+  }
+
+  // NOTE: Lines read back from `job_log.txt`; ignored when the root became resident again meanwhile (newer lines).
+  pub fn insert_loaded(&mut self, job_root_directory_path: &Path, lines: VecDeque<String>) {
+    self
+      .logs_by_root
+      .entry(job_root_directory_path.to_path_buf())
+      .or_insert_with(|| ResidentJobLog::new(lines));
+  }
+
+  // NOTE: `overwritable_prefix` is the stream prefix (`[stdout] `) of a captured line: it overwrites the previous line
+  // when that was a progress update from the same stream. Returns whether it did.
+  pub fn append(
+    &mut self,
+    job_root_directory_path: &Path,
+    line: String,
+    overwritable_prefix: Option<&str>,
+    is_progress: bool,
+    max_log_lines: usize,
+  ) -> bool {
+    let log = self
+      .logs_by_root
+      .entry(job_root_directory_path.to_path_buf())
+      .or_insert_with(|| ResidentJobLog::new(VecDeque::new()));
+    log.last_used_at = Instant::now();
+    let overwritten_line = match overwritable_prefix {
+      Some(prefix) if log.is_last_line_progress => {
+        log.lines.back_mut().filter(|last_line| last_line.starts_with(prefix))
+      }
+      _ => None,
+    };
+    let is_overwrite = overwritten_line.is_some();
+    match overwritten_line {
+      Some(last_line) => *last_line = line,
+      None => log.lines.push_back(line),
+    }
+    log.is_last_line_progress = is_progress;
+    while log.lines.len() > max_log_lines {
+      log.lines.pop_front();
+    }
+    is_overwrite
+  }
+
+  pub fn remove(&mut self, job_root_directory_path: &Path) {
+    self.logs_by_root.remove(job_root_directory_path);
+  }
+
+  // NOTE: Removes idle roots unused for a while, then the least recently used idle roots past the resident cap.
+  // The caller writes the returned lines to disk outside the state lock.
+  pub fn take_idle_logs(&mut self, is_running: impl Fn(&Path) -> bool) -> Vec<EvictedJobLog> {
+    let mut idle_roots: Vec<(PathBuf, Instant)> = self
+      .logs_by_root
+      .iter()
+      .filter(|(job_root_directory_path, _)| !is_running(job_root_directory_path))
+      .map(|(job_root_directory_path, log)| (job_root_directory_path.clone(), log.last_used_at))
+      .collect();
+    idle_roots.sort_by_key(|(_, last_used_at)| std::cmp::Reverse(*last_used_at));
+    idle_roots
+      .into_iter()
+      .enumerate()
+      .filter(|(index, (_, last_used_at))| {
+        *index >= MAX_RESIDENT_IDLE_JOB_LOGS || last_used_at.elapsed() >= IDLE_JOB_LOG_EVICTION_AGE
+      })
+      .filter_map(|(_, (job_root_directory_path, _))| {
+        let log = self.logs_by_root.remove(&job_root_directory_path)?;
+        Some(EvictedJobLog {
+          job_root_directory_path,
+          lines: log.lines,
+        })
+      })
+      .collect()
+  }
+}
+
+fn job_log_file_path(job_root_directory_path: &Path) -> PathBuf {
+  job_root_directory_path.join(JOB_LOG_FILENAME)
+}
+
+// NOTE: Missing or unreadable files read as no lines; the log is informational. The file is decoded lossily so one
+// edited or truncated byte does not hide the whole log.
+pub fn read_persisted_job_log(job_root_directory_path: &Path, max_log_lines: usize) -> VecDeque<String> {
+  let Ok(raw_bytes) = fs::read(job_log_file_path(job_root_directory_path)) else {
+    return VecDeque::new();
+  };
+  let raw = String::from_utf8_lossy(&raw_bytes);
+  let lines: Vec<&str> = raw.lines().collect();
+  lines[lines.len().saturating_sub(max_log_lines)..]
+    .iter()
+    .map(|line| line.to_string())
+    .collect()
+}
+
+// NOTE: Overwrites the file; the lines already hold everything kept from earlier loads.
+pub fn write_persisted_job_log(job_root_directory_path: &Path, lines: &VecDeque<String>) -> Result<(), String> {
+  if !job_root_directory_path.is_dir() {
+    // Guard: the root was archived or deleted; do not recreate it just for the log.
+    return Ok(());
+  }
+  if lines.is_empty() {
+    return Ok(());
+  }
+  let mut serialized = lines.iter().map(String::as_str).collect::<Vec<&str>>().join("\n");
+  serialized.push('\n');
+  fs::write(job_log_file_path(job_root_directory_path), serialized).map_err(|error| error.to_string())
+}
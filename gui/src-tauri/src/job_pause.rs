@@ -0,0 +1,87 @@
+/*!
+Responsibility:
+- Freeze and thaw a running job without losing its progress: `docker pause`/`docker unpause` for containers,
+  SIGSTOP/SIGCONT to the process group for host runs.
+*/
+
+use crate::{docker_cleanup::run_docker_with_timeout, probe_timeout::PROBE_COMMAND_TIMEOUT};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobPauseTargetKind {
+  // NOTE: A container name or id; the compose client process stays unfrozen and simply waits.
+  Container(String),
+  // NOTE: Host runs start `sh` in its own process group, so the Python pipeline under it is signalled too.
+  HostProcessGroup(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobPauseTarget {
+  pub kind: JobPauseTargetKind,
+  // NOTE: Workers exit once the queue is drained; a worker that is already gone is skipped instead of failing.
+  pub is_worker: bool,
+}
+
+impl JobPauseTarget {
+  pub fn primary(kind: JobPauseTargetKind) -> Self {
+    JobPauseTarget { kind, is_worker: false }
+  }
+
+  pub fn worker(kind: JobPauseTargetKind) -> Self {
+    JobPauseTarget { kind, is_worker: true }
+  }
+
+  fn set_paused(&self, is_paused: bool) -> Result<(), String> {
+    match &self.kind {
+      JobPauseTargetKind::Container(container) => {
+        run_docker_with_timeout(&[if is_paused { "pause" } else { "unpause" }, container], PROBE_COMMAND_TIMEOUT)
+          .map(|_| ())
+      }
+      JobPauseTargetKind::HostProcessGroup(process_group_id) => {
+        signal_process_group(*process_group_id, if is_paused { "STOP" } else { "CONT" })
+      }
+    }
+  }
+}
+
+#[cfg(unix)]
+pub fn signal_process_group(process_group_id: u32, signal_name: &str) -> Result<(), String> {
+  use std::process::Command;
+
+  // NOTE: A negative pid addresses the whole process group; `--` keeps it from being read as a signal number.
+  let status = Command::new("kill")
+    .arg("-s")
+    .arg(signal_name)
+    .arg("--")
+    .arg(format!("-{process_group_id}"))
+    .status()
+    .map_err(|error| format!("Failed to run kill. {error}"))?;
+  if !status.success() {
+    return Err(format!("kill -s {signal_name} -{process_group_id} failed ({status})"));
+  }
+  Ok(())
+}
+
+#[cfg(not(unix))]
+pub fn signal_process_group(_process_group_id: u32, _signal_name: &str) -> Result<(), String> {
+  Err("Signalling host runs is supported on Linux and macOS only.".to_string())
+}
+
+// NOTE: Returns one message per skipped worker. If the primary target fails, the targets already switched are
+// switched back, so a job is never left half paused.
+pub fn set_job_paused(targets: &[JobPauseTarget], is_paused: bool) -> Result<Vec<String>, String> {
+  let mut skipped_worker_messages: Vec<String> = vec![];
+  for (index, target) in targets.iter().enumerate() {
+    let Err(error) = target.set_paused(is_paused) else {
+      continue;
+    };
+    if target.is_worker {
+      skipped_worker_messages.push(format!("worker skipped (likely finished): {error}"));
+      continue;
+    }
+    for switched_target in &targets[..index] {
+      let _ = switched_target.set_paused(!is_paused);
+    }
+    return Err(error);
+  }
+  Ok(skipped_worker_messages)
+}
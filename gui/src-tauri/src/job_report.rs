@@ -0,0 +1,74 @@
+/*!
+Responsibility:
+- Persist a per-job report (`output/job_report.json`) that post-run passes append their results to.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  document_title::DetectedDocumentTitle, markdown_validation::MarkdownIssue, math_report::MathWarning,
+  output_destinations::OutputDestinationResult,
+};
+
+const JOB_REPORT_FILENAME: &str = "job_report.json";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JobReport {
+  #[serde(default)]
+  pub summary_markdown_path: Option<String>,
+  #[serde(default)]
+  pub spellcheck_report_path: Option<String>,
+  #[serde(default)]
+  pub package_archive_path: Option<String>,
+  #[serde(default)]
+  pub rendered_template_output_paths: Vec<String>,
+  #[serde(default)]
+  pub plugin_export_paths: Vec<String>,
+  #[serde(default)]
+  pub image_digest: Option<String>,
+  // NOTE: Copied from the job state at intake so a shared report still says whose document this was.
+  #[serde(default)]
+  pub submitter: Option<String>,
+  #[serde(default)]
+  pub provenance_manifest_path: Option<String>,
+  // NOTE: Latest delivery result per destination directory; a retry replaces that destination's entry.
+  #[serde(default)]
+  pub output_destination_results: Vec<OutputDestinationResult>,
+  #[serde(default)]
+  pub math_formula_count: Option<usize>,
+  #[serde(default)]
+  pub math_warnings: Vec<MathWarning>,
+  #[serde(default)]
+  pub mathml_export_path: Option<String>,
+  // NOTE: Broken tables, unclosed code fences and malformed links found in the engine's per-page output.
+  #[serde(default)]
+  pub markdown_issues: Vec<MarkdownIssue>,
+  // NOTE: Offered as the output's new name; applied only through `apply_detected_title`.
+  #[serde(default)]
+  pub detected_title: Option<DetectedDocumentTitle>,
+}
+
+pub fn job_report_file_path(output_directory_path: &Path) -> PathBuf {
+  output_directory_path.join(JOB_REPORT_FILENAME)
+}
+
+pub fn read_job_report_best_effort(output_directory_path: &Path) -> JobReport {
+  let Ok(raw) = fs::read_to_string(job_report_file_path(output_directory_path)) else {
+    return JobReport::default();
+  };
+  serde_json::from_str::<JobReport>(&raw).unwrap_or_default()
+}
+
+pub fn update_job_report(output_directory_path: &Path, update: impl FnOnce(&mut JobReport)) -> Result<(), String> {
+  let mut report = read_job_report_best_effort(output_directory_path);
+  update(&mut report);
+  fs::create_dir_all(output_directory_path).map_err(|error| error.to_string())?;
+  let serialized = serde_json::to_string_pretty(&report).map_err(|error| error.to_string())?;
+  fs::write(job_report_file_path(output_directory_path), serialized).map_err(|error| error.to_string())?;
+  Ok(())
+}
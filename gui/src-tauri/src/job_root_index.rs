@@ -0,0 +1,73 @@
+/*!
+Responsibility:
+- Remember, per job root, that its folders were already prepared and what its queue last looked like,
+  so status polling from every pane does not hit the disk (slow on HDD or network-backed job roots).
+- Drop a root's entry on job events (start, finish, reset) that change its folders or queue.
+*/
+
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct QueueSnapshot {
+  // NOTE: False until the CLI has enqueued inputs (`queue.sqlite3` does not exist yet).
+  pub has_queue: bool,
+  pub counts_by_status: HashMap<String, i64>,
+  pub last_error_message: Option<String>,
+}
+
+impl QueueSnapshot {
+  pub fn count(&self, status: &str) -> i64 {
+    *self.counts_by_status.get(status).unwrap_or(&0)
+  }
+}
+
+#[derive(Debug, Clone, Default)]
+struct JobRootIndexEntry {
+  is_directory_layout_prepared: bool,
+  queue_snapshot: Option<QueueSnapshot>,
+}
+
+#[derive(Debug, Default)]
+pub struct JobRootIndex {
+  entries_by_root: HashMap<PathBuf, JobRootIndexEntry>,
+}
+
+impl JobRootIndex {
+  pub fn is_directory_layout_prepared(&self, job_root_directory_path: &Path) -> bool {
+    self
+      .entries_by_root
+      .get(job_root_directory_path)
+      .map(|entry| entry.is_directory_layout_prepared)
+      .unwrap_or(false)
+  }
+
+  pub fn mark_directory_layout_prepared(&mut self, job_root_directory_path: &Path) {
+    self
+      .entries_by_root
+      .entry(job_root_directory_path.to_path_buf())
+      .or_default()
+      .is_directory_layout_prepared = true;
+  }
+
+  pub fn queue_snapshot(&self, job_root_directory_path: &Path) -> Option<QueueSnapshot> {
+    self
+      .entries_by_root
+      .get(job_root_directory_path)
+      .and_then(|entry| entry.queue_snapshot.clone())
+  }
+
+  pub fn record_queue_snapshot(&mut self, job_root_directory_path: &Path, queue_snapshot: QueueSnapshot) {
+    self
+      .entries_by_root
+      .entry(job_root_directory_path.to_path_buf())
+      .or_default()
+      .queue_snapshot = Some(queue_snapshot);
+  }
+
+  pub fn invalidate(&mut self, job_root_directory_path: &Path) {
+    self.entries_by_root.remove(job_root_directory_path);
+  }
+}
@@ -0,0 +1,118 @@
+/*!
+Responsibility:
+- Keep a history of every run of a job root in `runs.json`: the settings it ran with, start/end, output file and exit
+  status, so earlier results stay discoverable after `job.json` only remembers the latest output.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+const JOB_RUNS_FILENAME: &str = "runs.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedRunOutcome {
+  Completed,
+  Failed,
+  Cancelled,
+  // NOTE: The job root vanished mid-run; the automatic resume is recorded as the next run.
+  Suspended,
+  // NOTE: Ended by CUDA OOM and restarted with a smaller image size; the restart is recorded as the next run.
+  RetriedAfterOutOfMemory,
+  // NOTE: The Docker engine went away mid-run; the automatic resume is recorded as the next run.
+  Interrupted,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRunRecord {
+  // NOTE: 1-based and never reused, so removing an entry by hand does not renumber later runs.
+  pub run_number: u32,
+  pub run_mode: String,
+  pub started_unix_timestamp_millis: i64,
+  #[serde(default)]
+  pub finished_unix_timestamp_millis: Option<i64>,
+  pub output_markdown_path: String,
+  #[serde(default)]
+  pub image_digest: Option<String>,
+  // NOTE: `job.json` as it was when the run started.
+  pub settings_snapshot: serde_json::Value,
+  #[serde(default)]
+  pub exit_status: Option<String>,
+  #[serde(default)]
+  pub exit_code: Option<i32>,
+  #[serde(default)]
+  pub outcome: Option<RecordedRunOutcome>,
+}
+
+fn job_runs_file_path(job_root_directory_path: &Path) -> PathBuf {
+  job_root_directory_path.join(JOB_RUNS_FILENAME)
+}
+
+pub fn read_job_runs(job_root_directory_path: &Path) -> Result<Vec<JobRunRecord>, String> {
+  let runs_path = job_runs_file_path(job_root_directory_path);
+  if !runs_path.exists() {
+    return Ok(vec![]);
+  }
+  let raw = fs::read_to_string(&runs_path).map_err(|error| error.to_string())?;
+  serde_json::from_str::<Vec<JobRunRecord>>(&raw).map_err(|error| format!("Invalid run history {}: {error}", runs_path.display()))
+}
+
+fn write_job_runs(job_root_directory_path: &Path, runs: &[JobRunRecord]) -> Result<(), String> {
+  let serialized = serde_json::to_string_pretty(runs).map_err(|error| error.to_string())?;
+  fs::write(job_runs_file_path(job_root_directory_path), serialized).map_err(|error| error.to_string())
+}
+
+// NOTE: Fills in `run_number` and returns it, so the waiter can close the same entry.
+pub fn record_job_run_started(job_root_directory_path: &Path, mut run: JobRunRecord) -> Result<u32, String> {
+  let mut runs = read_job_runs(job_root_directory_path)?;
+  run.run_number = runs.iter().map(|existing| existing.run_number).max().unwrap_or(0) + 1;
+  let run_number = run.run_number;
+  runs.push(run);
+  write_job_runs(job_root_directory_path, &runs)?;
+  Ok(run_number)
+}
+
+pub fn record_job_run_finished(
+  job_root_directory_path: &Path,
+  run_number: u32,
+  finished_unix_timestamp_millis: i64,
+  exit_status: String,
+  exit_code: Option<i32>,
+  outcome: RecordedRunOutcome,
+) -> Result<(), String> {
+  let mut runs = read_job_runs(job_root_directory_path)?;
+  let run = runs
+    .iter_mut()
+    .find(|run| run.run_number == run_number)
+    .ok_or_else(|| format!("Run {run_number} is missing from the run history"))?;
+  run.finished_unix_timestamp_millis = Some(finished_unix_timestamp_millis);
+  run.exit_status = Some(exit_status);
+  run.exit_code = exit_code;
+  run.outcome = Some(outcome);
+  write_job_runs(job_root_directory_path, &runs)
+}
+
+// NOTE: Keeps the history pointing at an output file that was renamed after the run (e.g. to its detected title).
+pub fn rename_job_run_output(
+  job_root_directory_path: &Path,
+  previous_output_markdown_path: &str,
+  renamed_output_markdown_path: &str,
+) -> Result<(), String> {
+  let mut runs = read_job_runs(job_root_directory_path)?;
+  let mut is_changed = false;
+  for run in runs
+    .iter_mut()
+    .filter(|run| run.output_markdown_path == previous_output_markdown_path)
+  {
+    run.output_markdown_path = renamed_output_markdown_path.to_string();
+    is_changed = true;
+  }
+  if !is_changed {
+    return Ok(());
+  }
+  write_job_runs(job_root_directory_path, &runs)
+}
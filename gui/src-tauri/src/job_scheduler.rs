@@ -0,0 +1,199 @@
+/*!
+Responsibility:
+- Hold new job starts that found every job slot taken (`max_concurrent_jobs` in `runtime_limits.json`).
+- Hand out freed slots round-robin by source (GUI, quick job, watcher, dependency, resume) and oldest first within a
+  source, so a watcher flooded with bundles cannot starve interactive GUI starts.
+*/
+
+use std::{
+  collections::{HashSet, VecDeque},
+  path::{Path, PathBuf},
+};
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobRunMode {
+  Fresh,
+  // NOTE: Re-runs pending tasks into the previous run's markdown without enqueueing the inputs again.
+  RetryFailedTasks,
+}
+
+impl JobRunMode {
+  pub fn label(self) -> &'static str {
+    match self {
+      JobRunMode::Fresh => "fresh",
+      JobRunMode::RetryFailedTasks => "retry_failed_tasks",
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStartSource {
+  Gui,
+  WatchFolder,
+  QuickJob,
+  JobDependency,
+  // NOTE: A run resumed after its volume came back, or from its existing queue via `resume_job`.
+  Resume,
+}
+
+// NOTE: Interactive sources first, so when every source has a start waiting the GUI's goes first.
+const SOURCE_ROTATION: [JobStartSource; 5] = [
+  JobStartSource::Gui,
+  JobStartSource::QuickJob,
+  JobStartSource::WatchFolder,
+  JobStartSource::JobDependency,
+  JobStartSource::Resume,
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct QueuedJobStart {
+  pub job_root_directory_path: PathBuf,
+  pub run_mode: JobRunMode,
+  pub source: JobStartSource,
+  pub queued_unix_timestamp_millis: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum JobStartOutcome {
+  Started,
+  // NOTE: 1-based; the start runs once the jobs ahead of it have started and a slot frees up.
+  Queued { position: usize },
+}
+
+#[derive(Debug, Default)]
+pub struct JobStartQueue {
+  // NOTE: Arrival order; `dispatch_order` gives the order slots are actually handed out in.
+  queued_starts: VecDeque<QueuedJobStart>,
+  // NOTE: Roots that hold a slot but whose process is not registered as running yet; they count toward the limit.
+  starting_job_roots: HashSet<PathBuf>,
+  // NOTE: The rotation continues after this source; None starts it at the GUI.
+  last_served_source: Option<JobStartSource>,
+}
+
+impl JobStartQueue {
+  fn occupied_slot_count(&self, running_job_count: usize) -> usize {
+    running_job_count + self.starting_job_roots.len()
+  }
+
+  fn rotation_after_last_served_source(&self) -> impl Iterator<Item = JobStartSource> {
+    let start_index = self
+      .last_served_source
+      .and_then(|source| SOURCE_ROTATION.iter().position(|rotation_source| *rotation_source == source))
+      .map_or(0, |index| index + 1);
+    (0..SOURCE_ROTATION.len()).map(move |offset| SOURCE_ROTATION[(start_index + offset) % SOURCE_ROTATION.len()])
+  }
+
+  // NOTE: One start per source per round, assuming no queued root is busy when its turn comes.
+  fn dispatch_order(&self) -> Vec<&QueuedJobStart> {
+    let mut remaining_starts_by_source: Vec<VecDeque<&QueuedJobStart>> = self
+      .rotation_after_last_served_source()
+      .map(|source| {
+        self
+          .queued_starts
+          .iter()
+          .filter(|queued_start| queued_start.source == source)
+          .collect()
+      })
+      .collect();
+    let mut ordered_starts: Vec<&QueuedJobStart> = Vec::with_capacity(self.queued_starts.len());
+    loop {
+      let round_starts: Vec<&QueuedJobStart> = remaining_starts_by_source
+        .iter_mut()
+        .filter_map(|remaining_starts| remaining_starts.pop_front())
+        .collect();
+      if round_starts.is_empty() {
+        return ordered_starts;
+      }
+      ordered_starts.extend(round_starts);
+    }
+  }
+
+  pub fn is_queued(&self, job_root_directory_path: &Path) -> bool {
+    self
+      .queued_starts
+      .iter()
+      .any(|queued_start| queued_start.job_root_directory_path == job_root_directory_path)
+  }
+
+  // NOTE: `Started` means the caller now holds a slot and must call `release_slot` once the process is running.
+  // `Queued` reports the position in dispatch order; a later start from a quieter source may still go ahead of it.
+  pub fn claim_slot_or_enqueue(
+    &mut self,
+    job_root_directory_path: &Path,
+    run_mode: JobRunMode,
+    source: JobStartSource,
+    running_job_count: usize,
+    max_concurrent_jobs: usize,
+    now_unix_timestamp_millis: i64,
+  ) -> Result<JobStartOutcome, String> {
+    if self.starting_job_roots.contains(job_root_directory_path) || self.is_queued(job_root_directory_path) {
+      return Err("A job start is already queued for this output directory.".to_string());
+    }
+    // Guard: a free slot goes to the queued starts first, not to whoever asks right after a job ends.
+    if self.queued_starts.is_empty() && self.occupied_slot_count(running_job_count) < max_concurrent_jobs {
+      self.starting_job_roots.insert(job_root_directory_path.to_path_buf());
+      return Ok(JobStartOutcome::Started);
+    }
+    self.queued_starts.push_back(QueuedJobStart {
+      job_root_directory_path: job_root_directory_path.to_path_buf(),
+      run_mode,
+      source,
+      queued_unix_timestamp_millis: now_unix_timestamp_millis,
+    });
+    let position = self
+      .dispatch_order()
+      .iter()
+      .position(|queued_start| queued_start.job_root_directory_path == job_root_directory_path)
+      .map_or(self.queued_starts.len(), |index| index + 1);
+    Ok(JobStartOutcome::Queued { position })
+  }
+
+  pub fn release_slot(&mut self, job_root_directory_path: &Path) {
+    self.starting_job_roots.remove(job_root_directory_path);
+  }
+
+  // NOTE: The next source in the rotation that has a start, oldest first within it; a start whose root is busy
+  // (e.g. an OOM retry still running there) keeps its place and the turn passes to the next source.
+  pub fn take_next_start(
+    &mut self,
+    running_job_count: usize,
+    max_concurrent_jobs: usize,
+    is_job_root_running: impl Fn(&Path) -> bool,
+  ) -> Option<QueuedJobStart> {
+    if self.occupied_slot_count(running_job_count) >= max_concurrent_jobs {
+      return None;
+    }
+    let next_index = self.rotation_after_last_served_source().find_map(|source| {
+      self.queued_starts.iter().position(|queued_start| {
+        queued_start.source == source && !is_job_root_running(&queued_start.job_root_directory_path)
+      })
+    })?;
+    let next_start = self.queued_starts.remove(next_index)?;
+    self.last_served_source = Some(next_start.source);
+    self.starting_job_roots.insert(next_start.job_root_directory_path.clone());
+    Some(next_start)
+  }
+
+  pub fn remove(&mut self, job_root_directory_path: &Path) -> bool {
+    let queued_count = self.queued_starts.len();
+    self
+      .queued_starts
+      .retain(|queued_start| queued_start.job_root_directory_path != job_root_directory_path);
+    self.queued_starts.len() != queued_count
+  }
+
+  // NOTE: The watcher only takes a new bundle when it could start right away, so bundles wait in the inbox instead.
+  pub fn has_free_slot_for_new_work(&self, running_job_count: usize, max_concurrent_jobs: usize) -> bool {
+    self.queued_starts.is_empty() && self.occupied_slot_count(running_job_count) < max_concurrent_jobs
+  }
+
+  // NOTE: In dispatch order, so the list matches the positions reported when the starts were queued.
+  pub fn queued_starts(&self) -> Vec<QueuedJobStart> {
+    self.dispatch_order().into_iter().cloned().collect()
+  }
+}
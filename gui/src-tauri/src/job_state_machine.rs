@@ -0,0 +1,92 @@
+/*!
+Responsibility:
+- Define the lifecycle statuses recorded in `job_state.json` and which transitions between them are valid.
+- Record every accepted transition with its timestamp, so a late or stale writer cannot move a finished job back
+  to running.
+*/
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStateStatus {
+  Queued,
+  Running,
+  Completed,
+  Failed,
+  // NOTE: Cancelled mid-run; `output_markdown_path` holds the pages finished so far under a partial-output banner.
+  Partial,
+  // NOTE: The container engine went away mid-run (e.g. Docker Desktop restarted); the job resumes once it is back.
+  Interrupted,
+}
+
+impl JobStateStatus {
+  pub fn label(self) -> &'static str {
+    match self {
+      JobStateStatus::Queued => "queued",
+      JobStateStatus::Running => "running",
+      JobStateStatus::Completed => "completed",
+      JobStateStatus::Failed => "failed",
+      JobStateStatus::Partial => "partial",
+      JobStateStatus::Interrupted => "interrupted",
+    }
+  }
+
+  pub fn is_finished(self) -> bool {
+    matches!(self, JobStateStatus::Completed | JobStateStatus::Failed | JobStateStatus::Partial)
+  }
+
+  // NOTE: A finished job only runs again by being queued first, so a re-run shows up in the history.
+  pub fn can_transition_to(self, next: JobStateStatus) -> bool {
+    matches!(
+      (self, next),
+      (JobStateStatus::Queued, JobStateStatus::Running)
+        | (JobStateStatus::Queued, JobStateStatus::Failed)
+        | (JobStateStatus::Running, JobStateStatus::Completed)
+        | (JobStateStatus::Running, JobStateStatus::Failed)
+        | (JobStateStatus::Running, JobStateStatus::Partial)
+        | (JobStateStatus::Running, JobStateStatus::Interrupted)
+        | (JobStateStatus::Interrupted, JobStateStatus::Running)
+        | (JobStateStatus::Interrupted, JobStateStatus::Failed)
+    ) || (self.is_finished() && next == JobStateStatus::Queued)
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobStateTransition {
+  // NOTE: None for the initial transition into `queued` when the job is created.
+  pub from: Option<JobStateStatus>,
+  pub to: JobStateStatus,
+  pub unix_timestamp_millis: i64,
+}
+
+// NOTE: Ok(None) when already in `next` (restarts after an OOM retry or a volume resume re-enter `running`).
+pub fn validate_job_state_transition(
+  current: JobStateStatus,
+  next: JobStateStatus,
+  unix_timestamp_millis: i64,
+) -> Result<Option<JobStateTransition>, String> {
+  if current == next {
+    return Ok(None);
+  }
+  if !current.can_transition_to(next) {
+    return Err(format!(
+      "Invalid job state transition: {} -> {}",
+      current.label(),
+      next.label()
+    ));
+  }
+  Ok(Some(JobStateTransition {
+    from: Some(current),
+    to: next,
+    unix_timestamp_millis,
+  }))
+}
+
+pub fn initial_job_state_transition(unix_timestamp_millis: i64) -> JobStateTransition {
+  JobStateTransition {
+    from: None,
+    to: JobStateStatus::Queued,
+    unix_timestamp_millis,
+  }
+}
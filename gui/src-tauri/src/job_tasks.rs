@@ -0,0 +1,122 @@
+/*!
+Responsibility:
+- Page through the rows of a job's `queue.sqlite3` (optionally one status only) for the GUI's task table: source,
+  page, status, error and timings of each task.
+*/
+
+use std::path::Path;
+
+use rusqlite::{params, Connection};
+use serde::Serialize;
+
+pub const DEFAULT_JOB_TASK_PAGE_SIZE: usize = 100;
+pub const MAX_JOB_TASK_PAGE_SIZE: usize = 1000;
+
+const TASK_STATUSES: [&str; 4] = ["pending", "running", "completed", "failed"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobTaskRow {
+  pub task_id: i64,
+  pub task_kind: String,
+  pub source_path: String,
+  pub pdf_page_index: Option<i64>,
+  pub pdf_total_pages: Option<i64>,
+  pub status: String,
+  pub error_message: Option<String>,
+  pub created_unix_timestamp_seconds: i64,
+  // NOTE: None for tasks never claimed, and for every task of a queue written before the CLI recorded timings.
+  pub started_unix_timestamp_seconds: Option<i64>,
+  pub finished_unix_timestamp_seconds: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobTaskPage {
+  pub tasks: Vec<JobTaskRow>,
+  // NOTE: Rows matching the status filter, not just this page.
+  pub total_count: i64,
+  pub offset: usize,
+  pub limit: usize,
+}
+
+fn validate_task_status_filter(status_filter: Option<&str>) -> Result<(), String> {
+  match status_filter {
+    Some(status) if !TASK_STATUSES.contains(&status) => Err(format!(
+      "Unknown task status: {status} (expected one of {}).",
+      TASK_STATUSES.join(", ")
+    )),
+    _ => Ok(()),
+  }
+}
+
+fn has_task_timing_columns(connection: &Connection) -> Result<bool, String> {
+  let mut statement = connection
+    .prepare("SELECT name FROM pragma_table_info('tasks') WHERE name = 'started_unix_timestamp_seconds'")
+    .map_err(|error| error.to_string())?;
+  statement.exists([]).map_err(|error| error.to_string())
+}
+
+// NOTE: Tasks in task_id (enqueue) order, which is also the order of the merged Markdown.
+pub fn query_job_tasks(
+  queue_database_path: &Path,
+  status_filter: Option<&str>,
+  offset: usize,
+  limit: usize,
+) -> Result<JobTaskPage, String> {
+  validate_task_status_filter(status_filter)?;
+  let limit = limit.clamp(1, MAX_JOB_TASK_PAGE_SIZE);
+  if !queue_database_path.exists() {
+    // Guard: queue might not exist until enqueue has run.
+    return Ok(JobTaskPage {
+      tasks: vec![],
+      total_count: 0,
+      offset,
+      limit,
+    });
+  }
+
+  let connection = Connection::open(queue_database_path).map_err(|error| error.to_string())?;
+  let total_count: i64 = connection
+    .query_row(
+      "SELECT COUNT(*) FROM tasks WHERE ?1 IS NULL OR status = ?1",
+      params![status_filter],
+      |row| row.get(0),
+    )
+    .map_err(|error| error.to_string())?;
+  let timing_columns = if has_task_timing_columns(&connection)? {
+    "started_unix_timestamp_seconds, finished_unix_timestamp_seconds"
+  } else {
+    "NULL, NULL"
+  };
+  let mut statement = connection
+    .prepare(&format!(
+      "SELECT task_id, task_kind, source_path, pdf_page_index, pdf_total_pages, status, error_message, \
+       created_unix_timestamp_seconds, {timing_columns} \
+       FROM tasks WHERE ?1 IS NULL OR status = ?1 ORDER BY task_id ASC LIMIT ?2 OFFSET ?3"
+    ))
+    .map_err(|error| error.to_string())?;
+  let tasks = statement
+    .query_map(params![status_filter, limit as i64, offset as i64], |row| {
+      Ok(JobTaskRow {
+        task_id: row.get(0)?,
+        task_kind: row.get(1)?,
+        source_path: row.get(2)?,
+        pdf_page_index: row.get(3)?,
+        pdf_total_pages: row.get(4)?,
+        status: row.get(5)?,
+        error_message: row.get(6)?,
+        created_unix_timestamp_seconds: row.get(7)?,
+        started_unix_timestamp_seconds: row.get(8)?,
+        finished_unix_timestamp_seconds: row.get(9)?,
+      })
+    })
+    .map_err(|error| error.to_string())?
+    .collect::<Result<Vec<JobTaskRow>, _>>()
+    .map_err(|error| error.to_string())?;
+
+  Ok(JobTaskPage {
+    tasks,
+    total_count,
+    offset,
+    limit,
+  })
+}
@@ -0,0 +1,176 @@
+/*!
+Responsibility:
+- Read job templates from `<app config>/job_templates/<name>/` (`template.json` plus an optional `seed/` folder).
+- Name the dated job root a template creates and copy the template's seed files (e.g. a team README) into it.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::job_layout::JobDirectoryLayout;
+
+pub const JOB_TEMPLATES_DIRECTORY_NAME: &str = "job_templates";
+
+const JOB_TEMPLATE_DEFINITION_FILENAME: &str = "template.json";
+const JOB_TEMPLATE_SEED_DIRECTORY_NAME: &str = "seed";
+// NOTE: Two jobs from one template on the same day get `_2`, `_3`, ...; past this something is wrong.
+const MAX_DATED_JOB_ROOT_SUFFIX: u32 = 999;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JobTemplateDefinition {
+  // NOTE: A preset under `<app config>/presets/`; unset starts from the default job settings.
+  pub preset_name: Option<String>,
+  // NOTE: Unset uses the app-level layout (`job_layout.json`), like any new job root.
+  pub directory_layout: Option<JobDirectoryLayout>,
+  // NOTE: The folder name after the date; defaults to the template name.
+  pub job_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobTemplateSummary {
+  pub name: String,
+  pub preset_name: Option<String>,
+  pub seed_file_count: usize,
+}
+
+pub fn validate_job_template_name(template_name: &str) -> Result<String, String> {
+  let trimmed = template_name.trim();
+  let is_valid = !trimmed.is_empty()
+    && trimmed
+      .chars()
+      .all(|character| character.is_alphanumeric() || matches!(character, '-' | '_' | ' '));
+  if !is_valid {
+    // Guard: template names are folder names under the app config directory.
+    return Err(format!("Invalid job template name: {template_name}"));
+  }
+  Ok(trimmed.to_string())
+}
+
+fn job_template_directory_path(templates_directory_path: &Path, template_name: &str) -> Result<PathBuf, String> {
+  Ok(templates_directory_path.join(validate_job_template_name(template_name)?))
+}
+
+pub fn read_job_template(templates_directory_path: &Path, template_name: &str) -> Result<JobTemplateDefinition, String> {
+  let template_directory_path = job_template_directory_path(templates_directory_path, template_name)?;
+  if !template_directory_path.is_dir() {
+    return Err(format!("Job template not found: {template_name}"));
+  }
+  let definition_path = template_directory_path.join(JOB_TEMPLATE_DEFINITION_FILENAME);
+  // NOTE: A template may be only a `seed/` folder.
+  if !definition_path.exists() {
+    return Ok(JobTemplateDefinition::default());
+  }
+  let raw = fs::read_to_string(&definition_path).map_err(|error| error.to_string())?;
+  serde_json::from_str::<JobTemplateDefinition>(&raw)
+    .map_err(|error| format!("Invalid job template {}: {error}", definition_path.display()))
+}
+
+fn list_seed_files(seed_directory_path: &Path) -> Result<Vec<PathBuf>, String> {
+  if !seed_directory_path.is_dir() {
+    return Ok(vec![]);
+  }
+  let mut relative_paths: Vec<PathBuf> = vec![];
+  for entry in walkdir::WalkDir::new(seed_directory_path).min_depth(1).sort_by_file_name() {
+    let entry = entry.map_err(|error| error.to_string())?;
+    if !entry.file_type().is_file() {
+      continue;
+    }
+    let relative_path = entry
+      .path()
+      .strip_prefix(seed_directory_path)
+      .map_err(|error| error.to_string())?
+      .to_path_buf();
+    relative_paths.push(relative_path);
+  }
+  Ok(relative_paths)
+}
+
+pub fn list_job_templates(templates_directory_path: &Path) -> Result<Vec<JobTemplateSummary>, String> {
+  if !templates_directory_path.exists() {
+    return Ok(vec![]);
+  }
+  let mut summaries: Vec<JobTemplateSummary> = vec![];
+  for entry in fs::read_dir(templates_directory_path).map_err(|error| error.to_string())? {
+    let entry_path = entry.map_err(|error| error.to_string())?.path();
+    let Some(name) = entry_path.file_name().and_then(|name| name.to_str()) else {
+      continue;
+    };
+    if !entry_path.is_dir() || validate_job_template_name(name).is_err() {
+      continue;
+    }
+    let definition = read_job_template(templates_directory_path, name)?;
+    summaries.push(JobTemplateSummary {
+      name: name.to_string(),
+      preset_name: definition.preset_name,
+      seed_file_count: list_seed_files(&entry_path.join(JOB_TEMPLATE_SEED_DIRECTORY_NAME))?.len(),
+    });
+  }
+  summaries.sort_by(|left, right| left.name.cmp(&right.name));
+  Ok(summaries)
+}
+
+// NOTE: UTC calendar date (YYYY-MM-DD) without a date library; see Howard Hinnant's `civil_from_days`.
+pub fn format_utc_date(unix_timestamp_millis: i64) -> String {
+  let days_since_epoch = unix_timestamp_millis.div_euclid(86_400_000);
+  let shifted_days = days_since_epoch + 719_468;
+  let era = shifted_days.div_euclid(146_097);
+  let day_of_era = shifted_days.rem_euclid(146_097);
+  let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let month_index = (5 * day_of_year + 2) / 153;
+  let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+  let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+  let year = year_of_era + era * 400 + i64::from(month <= 2);
+  format!("{year:04}-{month:02}-{day:02}")
+}
+
+pub fn derive_dated_job_root_path(parent_directory_path: &Path, date: &str, job_name: &str) -> Result<PathBuf, String> {
+  let base_name = format!("{date}_{job_name}");
+  let base_path = parent_directory_path.join(&base_name);
+  if !base_path.exists() {
+    return Ok(base_path);
+  }
+  for suffix in 2..=MAX_DATED_JOB_ROOT_SUFFIX {
+    let candidate_path = parent_directory_path.join(format!("{base_name}_{suffix}"));
+    if !candidate_path.exists() {
+      return Ok(candidate_path);
+    }
+  }
+  Err(format!("Too many jobs named {base_name} in {}", parent_directory_path.display()))
+}
+
+// NOTE: Returns the number of files copied; `excluded_top_level_names` keeps seeds out of the job settings folder.
+pub fn copy_job_template_seed_files(
+  templates_directory_path: &Path,
+  template_name: &str,
+  job_root_directory_path: &Path,
+  excluded_top_level_names: &[&str],
+) -> Result<usize, String> {
+  let seed_directory_path =
+    job_template_directory_path(templates_directory_path, template_name)?.join(JOB_TEMPLATE_SEED_DIRECTORY_NAME);
+  let mut copied_file_count = 0;
+  for relative_path in list_seed_files(&seed_directory_path)? {
+    let is_excluded = relative_path
+      .components()
+      .next()
+      .map(|component| excluded_top_level_names.contains(&component.as_os_str().to_string_lossy().as_ref()))
+      .unwrap_or(false);
+    if is_excluded {
+      return Err(format!(
+        "Job template {template_name} seeds {}, which is reserved for job settings.",
+        relative_path.display()
+      ));
+    }
+    let destination_path = job_root_directory_path.join(&relative_path);
+    if let Some(parent_directory_path) = destination_path.parent() {
+      fs::create_dir_all(parent_directory_path).map_err(|error| error.to_string())?;
+    }
+    fs::copy(seed_directory_path.join(&relative_path), &destination_path).map_err(|error| error.to_string())?;
+    copied_file_count += 1;
+  }
+  Ok(copied_file_count)
+}
@@ -0,0 +1,150 @@
+/*!
+Responsibility:
+- Call an OpenAI-compatible chat completion endpoint (local model server or hosted API) for post-OCR passes.
+- Call the matching embeddings endpoint for semantic search.
+- Split large markdown into chunks that fit a single request.
+*/
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_LLM_BASE_URL: &str = "http://localhost:11434/v1";
+const LLM_REQUEST_TIMEOUT_SECONDS: u64 = 600;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LlmEndpointSettings {
+  // NOTE: Defaults to a local Ollama-compatible server when omitted.
+  pub base_url: Option<String>,
+  pub model: String,
+  // NOTE: The key itself is never persisted in job settings; only the variable name is.
+  pub api_key_environment_variable_name: Option<String>,
+}
+
+fn resolve_base_url(endpoint: &LlmEndpointSettings) -> String {
+  let base_url = endpoint
+    .base_url
+    .as_deref()
+    .map(|value| value.trim())
+    .filter(|value| !value.is_empty())
+    .unwrap_or(DEFAULT_LLM_BASE_URL);
+  base_url.trim_end_matches('/').to_string()
+}
+
+fn resolve_api_key(endpoint: &LlmEndpointSettings) -> Result<Option<String>, String> {
+  let Some(variable_name) = endpoint.api_key_environment_variable_name.as_deref() else {
+    return Ok(None);
+  };
+  let value = std::env::var(variable_name)
+    .map_err(|_| format!("LLM API key environment variable is not set: {variable_name}"))?;
+  Ok(Some(value))
+}
+
+pub fn complete_chat(endpoint: &LlmEndpointSettings, system_prompt: &str, user_content: &str) -> Result<String, String> {
+  if endpoint.model.trim().is_empty() {
+    // Guard: every OpenAI-compatible server requires a model name.
+    return Err("LLM endpoint model is empty".to_string());
+  }
+
+  let url = format!("{}/chat/completions", resolve_base_url(endpoint));
+  let agent = ureq::AgentBuilder::new()
+    .timeout(Duration::from_secs(LLM_REQUEST_TIMEOUT_SECONDS))
+    .build();
+  let mut request = agent.post(&url);
+  if let Some(api_key) = resolve_api_key(endpoint)? {
+    request = request.set("Authorization", &format!("Bearer {api_key}"));
+  }
+
+  let response = request
+    .send_json(serde_json::json!({
+      "model": endpoint.model,
+      "messages": [
+        { "role": "system", "content": system_prompt },
+        { "role": "user", "content": user_content },
+      ],
+    }))
+    .map_err(|error| format!("LLM request failed ({url}): {error}"))?;
+  let body: serde_json::Value = response
+    .into_json()
+    .map_err(|error| format!("LLM response is not JSON: {error}"))?;
+
+  body["choices"][0]["message"]["content"]
+    .as_str()
+    .map(|content| content.to_string())
+    .ok_or_else(|| "LLM response has no choices[0].message.content".to_string())
+}
+
+pub fn split_markdown_into_chunks(markdown: &str, max_chunk_characters: usize) -> Vec<String> {
+  let mut chunks: Vec<String> = vec![];
+  let mut current = String::new();
+  for paragraph in markdown.split("\n\n") {
+    let would_overflow = current.chars().count() + paragraph.chars().count() + 2 > max_chunk_characters;
+    if would_overflow && !current.is_empty() {
+      chunks.push(std::mem::take(&mut current));
+    }
+    if !current.is_empty() {
+      current.push_str("\n\n");
+    }
+    // NOTE: A single oversized paragraph becomes its own chunk rather than being split mid-sentence.
+    current.push_str(paragraph);
+  }
+  if !current.trim().is_empty() {
+    chunks.push(current);
+  }
+  chunks
+}
+
+pub fn embed_texts(endpoint: &LlmEndpointSettings, texts: &[String]) -> Result<Vec<Vec<f32>>, String> {
+  if endpoint.model.trim().is_empty() {
+    // Guard: every OpenAI-compatible server requires a model name.
+    return Err("Embedding endpoint model is empty".to_string());
+  }
+  if texts.is_empty() {
+    return Ok(vec![]);
+  }
+
+  let url = format!("{}/embeddings", resolve_base_url(endpoint));
+  let agent = ureq::AgentBuilder::new()
+    .timeout(Duration::from_secs(LLM_REQUEST_TIMEOUT_SECONDS))
+    .build();
+  let mut request = agent.post(&url);
+  if let Some(api_key) = resolve_api_key(endpoint)? {
+    request = request.set("Authorization", &format!("Bearer {api_key}"));
+  }
+
+  let response = request
+    .send_json(serde_json::json!({
+      "model": endpoint.model,
+      "input": texts,
+    }))
+    .map_err(|error| format!("Embedding request failed ({url}): {error}"))?;
+  let body: serde_json::Value = response
+    .into_json()
+    .map_err(|error| format!("Embedding response is not JSON: {error}"))?;
+
+  let data = body["data"]
+    .as_array()
+    .ok_or_else(|| "Embedding response has no data array".to_string())?;
+  if data.len() != texts.len() {
+    // Guard: a partial response would silently misalign chunks and vectors.
+    return Err(format!(
+      "Embedding response returned {} vector(s) for {} input(s)",
+      data.len(),
+      texts.len()
+    ));
+  }
+
+  let mut vectors: Vec<Vec<f32>> = vec![];
+  for item in data {
+    let values = item["embedding"]
+      .as_array()
+      .ok_or_else(|| "Embedding response item has no embedding array".to_string())?;
+    vectors.push(
+      values
+        .iter()
+        .map(|value| value.as_f64().unwrap_or(0.0) as f32)
+        .collect(),
+    );
+  }
+  Ok(vectors)
+}
@@ -0,0 +1,134 @@
+/*!
+Responsibility:
+- Turn the raw output of job processes into log lines the way a terminal would show them: `\r` progress updates
+  (tqdm, docker pulls) overwrite each other instead of piling up, and ANSI color/cursor codes are removed.
+*/
+
+use std::io::{ErrorKind, Read};
+
+// NOTE: A progress bar that never prints `\n` would otherwise grow one line without bound.
+const MAX_CAPTURED_LINE_BYTES: usize = 16 * 1024;
+
+const ESCAPE: char = '\u{1b}';
+const BELL: char = '\u{7}';
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CapturedLogLine {
+  // NOTE: Ended by `\n` (or `\r\n`).
+  Line(String),
+  // NOTE: Ended by a bare `\r`; whatever the same stream prints next overwrites it, as on a terminal.
+  Progress(String),
+}
+
+#[derive(Debug, Default)]
+pub struct LogLineSplitter {
+  pending_bytes: Vec<u8>,
+  // NOTE: A `\r` is held until the next byte shows whether it starts `\r\n` (Windows line end) or an overwrite.
+  has_pending_carriage_return: bool,
+}
+
+impl LogLineSplitter {
+  pub fn push(&mut self, bytes: &[u8], emit: &mut impl FnMut(CapturedLogLine)) {
+    for &byte in bytes {
+      if self.has_pending_carriage_return {
+        self.has_pending_carriage_return = false;
+        if byte == b'\n' {
+          self.flush(emit, false);
+          continue;
+        }
+        self.flush(emit, true);
+      }
+      match byte {
+        b'\n' => self.flush(emit, false),
+        b'\r' => self.has_pending_carriage_return = true,
+        _ => {
+          self.pending_bytes.push(byte);
+          if self.pending_bytes.len() >= MAX_CAPTURED_LINE_BYTES {
+            self.flush(emit, false);
+          }
+        }
+      }
+    }
+  }
+
+  // NOTE: Emits what is left at end of stream (output without a trailing newline).
+  pub fn finish(&mut self, emit: &mut impl FnMut(CapturedLogLine)) {
+    let is_progress = self.has_pending_carriage_return;
+    self.has_pending_carriage_return = false;
+    if !self.pending_bytes.is_empty() {
+      self.flush(emit, is_progress);
+    }
+  }
+
+  fn flush(&mut self, emit: &mut impl FnMut(CapturedLogLine), is_progress: bool) {
+    let text = strip_ansi_escape_sequences(&String::from_utf8_lossy(&self.pending_bytes));
+    self.pending_bytes.clear();
+    if !is_progress {
+      emit(CapturedLogLine::Line(text));
+    } else if !text.trim().is_empty() {
+      // Guard: the `\r` that starts each tqdm update leaves an empty segment in front of it.
+      emit(CapturedLogLine::Progress(text));
+    }
+  }
+}
+
+// NOTE: Reads until end of stream or a read error. Bytes are split before decoding, so a line in another encoding
+// (cp932 from a Windows tool, a binary dump) still arrives, with its undecodable bytes replaced.
+pub fn capture_stream_lines(mut stream: impl Read, emit: &mut impl FnMut(CapturedLogLine)) {
+  let mut splitter = LogLineSplitter::default();
+  let mut buffer = [0u8; 8192];
+  loop {
+    match stream.read(&mut buffer) {
+      Ok(0) => break,
+      Ok(read_byte_count) => splitter.push(&buffer[..read_byte_count], emit),
+      Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+      Err(_) => break,
+    }
+  }
+  splitter.finish(emit);
+}
+
+// NOTE: For output shown as plain lines (script hooks, plugin stderr, image builds): progress updates are kept as
+// ordinary lines.
+pub fn for_each_captured_line(stream: impl Read, mut on_line: impl FnMut(String)) {
+  capture_stream_lines(stream, &mut |captured_line| match captured_line {
+    CapturedLogLine::Line(line) | CapturedLogLine::Progress(line) => on_line(line),
+  });
+}
+
+// NOTE: Drops CSI (`ESC [ ... final`), OSC (`ESC ] ... BEL` or `ESC ] ... ESC \`) and two-character escapes; other
+// control characters except tab are dropped too, since the log pane cannot render them.
+fn strip_ansi_escape_sequences(text: &str) -> String {
+  let mut stripped = String::with_capacity(text.len());
+  let mut characters = text.chars().peekable();
+  while let Some(character) = characters.next() {
+    if character != ESCAPE {
+      if !character.is_control() || character == '\t' {
+        stripped.push(character);
+      }
+      continue;
+    }
+    match characters.next() {
+      Some('[') => {
+        for sequence_character in characters.by_ref() {
+          if ('\u{40}'..='\u{7e}').contains(&sequence_character) {
+            break;
+          }
+        }
+      }
+      Some(']') => {
+        while let Some(sequence_character) = characters.next() {
+          if sequence_character == BELL {
+            break;
+          }
+          if sequence_character == ESCAPE && characters.peek() == Some(&'\\') {
+            characters.next();
+            break;
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+  stripped
+}
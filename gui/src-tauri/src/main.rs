@@ -9,72 +9,638 @@ Responsibility:
 */
 
 use std::{
-  collections::{HashMap, VecDeque},
-  ffi::OsStr,
+  collections::{BTreeMap, HashMap, HashSet},
+  ffi::{OsStr, OsString},
   fs,
-  io::{BufRead, BufReader},
+  io::{Read, Write},
   path::{Path, PathBuf},
-  process::{Child, Command, Stdio},
-  sync::{Arc, Mutex},
-  time::{Duration, SystemTime, UNIX_EPOCH},
+  process::{Child, Command, ExitStatus, Stdio},
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+  },
+  thread,
+  time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
-use tauri::{State, Wry};
+use serde_json::Value;
+use tauri::{
+  menu::{Menu, MenuItem},
+  tray::TrayIconBuilder,
+  Emitter,
+  Manager,
+  State,
+  Wry,
+};
+use tauri_plugin_clipboard_manager::ClipboardExt;
 use tauri_plugin_dialog::DialogExt;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tauri_plugin_updater::{Updater, UpdaterExt};
 use tokio::sync::oneshot;
 use tauri_plugin_dialog::FilePath;
 
+mod api_tokens;
+mod app_config_backup;
+mod app_update;
+mod backend_capabilities;
+mod backfill;
+mod bulk_job_operations;
+mod bundle_ledger;
+mod connector_runtime;
+mod container_adoption;
+mod disk_watermarks;
+mod docker_cleanup;
+mod document_title;
+mod duplicate_detection;
+mod email_notifier;
+mod error_classification;
+mod google_drive_connector;
+mod hex_codec;
+mod host_engine;
+mod hotfolder;
+mod http_api;
+mod http_rate_limit;
+mod image_build;
+mod integrity;
+mod job_cancellation;
+mod job_dependencies;
+mod job_environment;
+mod job_events;
+mod job_heartbeat;
+mod job_history;
+mod job_layout;
+mod job_log_store;
+mod job_pause;
+mod job_report;
+mod job_root_index;
+mod job_runs;
+mod job_scheduler;
+mod job_state_machine;
+mod job_tasks;
+mod job_templates;
+mod llm_client;
+mod log_capture;
+mod maintenance_mode;
+mod markdown_validation;
+mod math_report;
+mod natural_sort;
+mod notifications;
+mod oauth_tokens;
+mod onedrive_connector;
+mod output_destinations;
+mod output_documents;
+mod packaging;
+mod page_prerender;
+mod plugins;
+mod probe_timeout;
+mod provenance;
+mod quick_jobs;
+mod removable_volumes;
+mod run_comparison;
+mod runtime_limits;
+mod script_hooks;
+mod semantic_search;
+mod settings_tuning;
+mod smoke_test;
+mod spellcheck;
+mod status_export;
+mod status_query_cache;
+mod structured_extraction;
+mod submitter_routing;
+mod summarization;
+mod task_regions;
+mod template_rendering;
+mod translation;
+mod wsl_diagnostics;
 mod watch_folder;
+mod work_cleanup;
+use api_tokens::{
+  authorize_api_token,
+  create_api_token,
+  list_api_tokens,
+  revoke_api_token,
+  ApiTokenScope,
+  ApiTokenSummary,
+  CreatedApiToken,
+  HTTP_API_TOKENS_FILENAME,
+};
+use app_config_backup::{
+  export_app_config_backup,
+  import_app_config_backup,
+  AppConfigBackupScope,
+  AppConfigExportReport,
+  AppConfigImportReport,
+};
+use app_update::{
+  append_engine_update_log_line,
+  begin_engine_update,
+  describe_engine_update_reasons,
+  get_engine_update_status as get_engine_update_status_from_state,
+  new_shared_engine_update_state,
+  previous_image_name,
+  set_engine_update_phase,
+  EngineUpdatePhase,
+  EngineUpdateRecord,
+  EngineUpdateStatus,
+  GuiUpdaterSettings,
+  SharedEngineUpdateRuntimeState,
+  UpdateCheckReport,
+  ENGINE_UPDATE_RECORD_FILENAME,
+  GUI_UPDATER_SETTINGS_FILENAME,
+};
+use backend_capabilities::{
+  is_clipboard_environment_available,
+  probe_container_runtime,
+  probe_host_gpu_name,
+  probe_scanner_backend,
+  BackendCapabilities,
+};
+use backfill::{
+  get_backfill_status as get_backfill_status_from_state,
+  BackfillRun,
+  BackfillStatus,
+  BACKFILL_POLL_INTERVAL_MILLIS,
+  BACKFILL_PROGRESS_FILENAME,
+};
+use bulk_job_operations::{
+  check_archivable_run,
+  move_job_root_to_archive,
+  BulkJobItemOutcome,
+  BulkJobItemResult,
+  BulkJobOperation,
+  BulkJobOperationProgress,
+  BulkJobOperationSummary,
+  BULK_JOB_OPERATION_PROGRESS_EVENT,
+};
+use bundle_ledger::{compute_bundle_fingerprint, BundleLedger, BundleLedgerEntry};
+use connector_runtime::{
+  new_shared_connector_state,
+  record_connector_error,
+  start_connector_polling,
+  stop_connector,
+  SharedConnectorRuntimeState,
+};
+use container_adoption::{list_running_project_containers, spawn_container_log_follower, ProjectContainer};
+use disk_watermarks::{
+  describe_queued_job_breach,
+  describe_watermark_breach,
+  remove_jobs_for_retention,
+  DiskWatermarkMonitor,
+  DiskWatermarkSettings,
+  DISK_WATERMARK_SETTINGS_FILENAME,
+};
+use document_title::{detect_document_title, title_to_filename_stem, AppliedDocumentTitle, DetectedDocumentTitle};
+use error_classification::{classify_job_error, next_oom_retry_inference_image_size, ClassifiedJobError, JobErrorCategory};
+use docker_cleanup::{
+  prune_project_docker_artifacts,
+  read_docker_disk_usage,
+  DockerDiskUsage,
+  DockerPruneOptions,
+  DockerPruneReport,
+};
+use duplicate_detection::{
+  find_duplicate_documents as find_duplicate_documents_in_sources,
+  DuplicateDocumentGroup,
+  JobFingerprintSource,
+};
+use email_notifier::store_smtp_password as store_smtp_password_in_keychain;
+use google_drive_connector::{
+  begin_sign_in as begin_google_drive_sign_in_flow,
+  complete_sign_in as complete_google_drive_sign_in_flow,
+  get_google_drive_connector_status as get_google_drive_connector_status_from_state,
+  is_signed_in as is_google_drive_signed_in,
+  poll_google_drive_changes_once,
+  DriveSession,
+  GoogleDriveConnectorSettings,
+  GoogleDriveConnectorStatus,
+  GoogleDriveDocumentHandler,
+  GoogleDriveSignInPrompt,
+  GOOGLE_DRIVE_CONNECTOR_SETTINGS_FILENAME,
+  GOOGLE_DRIVE_CONNECTOR_STATE_FILENAME,
+};
+use host_engine::{
+  build_host_job_command,
+  is_apple_silicon_host,
+  probe_host_python,
+  render_cli_script,
+  validate_host_engine_supported,
+  ExecutionEngineKind,
+  ExecutionEngineSettings,
+  EXECUTION_ENGINE_SETTINGS_FILENAME,
+};
+use hotfolder::{
+  get_hotfolder_status as get_hotfolder_status_from_state,
+  poll_hotfolders_once,
+  HotfolderDocumentHandler,
+  HotfolderObservations,
+  HotfolderPreset,
+  HotfolderStatus,
+  HOTFOLDER_POLL_INTERVAL_MILLIS,
+  HOTFOLDER_SETTINGS_FILENAME,
+};
+use image_build::{
+  compute_build_inputs_hash,
+  get_image_build_status,
+  new_shared_image_build_state,
+  start_image_build,
+  ImageBuildStatus,
+  SharedImageBuildRuntimeState,
+  BUILD_INPUTS_HASH_ENVIRONMENT_VARIABLE_NAME,
+  BUILD_INPUTS_HASH_IMAGE_LABEL,
+};
+use http_api::{
+  parse_submitter_tokens, sanitize_submitted_filename, start_http_api_server, ApiTokenAuthorizer, ControlAction,
+  ControlHandler, HttpApiConfig, InboundDocument, InboundSubmissionHandler, SubmissionError,
+  DEFAULT_HTTP_API_BIND_ADDRESS,
+};
+use job_cancellation::{
+  remove_cancel_marker,
+  requeue_running_tasks,
+  terminate_job_targets,
+  write_cancel_marker,
+  JobCancelMode,
+  JobCancellationRecord,
+  GRACEFUL_CANCEL_POLL_INTERVAL,
+  GRACEFUL_CANCEL_TIMEOUT,
+};
+use job_dependencies::{
+  read_job_dependency_graph,
+  update_job_dependency_graph,
+  JobDependencyGraph,
+  JobRunOutcome,
+  JOB_DEPENDENCIES_FILENAME,
+};
+use job_environment::validate_extra_environment;
+use job_events::{
+  parse_task_finished_line, JobLogLineEvent, JobTaskFinishedEvent, JOB_LOG_LINE_EVENT, JOB_STATUS_EVENT,
+  JOB_STATUS_EVENT_PUMP_INTERVAL, JOB_TASK_FINISHED_EVENT,
+};
+use job_heartbeat::{describe_job_heartbeat, start_job_heartbeat_writer, JobHeartbeat, JobHeartbeatProvider};
+use job_layout::{JobDirectoryLayout, JOB_DIRECTORY_LAYOUT_SETTINGS_FILENAME};
+use job_log_store::{read_persisted_job_log, write_persisted_job_log, JobLogStore};
+use job_pause::{set_job_paused, JobPauseTarget, JobPauseTargetKind};
+use integrity::{
+  compare_provenance_checksums,
+  sign_file,
+  verify_file_signature,
+  JobIntegrityReport,
+  SignedFileCheck,
+};
+use job_history::{
+  list_recent_job_roots,
+  record_job_history_finished,
+  record_job_history_started,
+  rename_job_history_output,
+  RecentJob,
+  DEFAULT_RECENT_JOB_LIMIT,
+};
+use job_report::{job_report_file_path, read_job_report_best_effort, update_job_report};
+use job_root_index::{JobRootIndex, QueueSnapshot};
+use run_comparison::{compare_run_outputs, RunComparison, RunOutput};
+use job_runs::{
+  read_job_runs, record_job_run_finished, record_job_run_started, rename_job_run_output, JobRunRecord, RecordedRunOutcome,
+};
+use job_scheduler::{JobRunMode, JobStartOutcome, JobStartQueue, JobStartSource, QueuedJobStart};
+use job_state_machine::{initial_job_state_transition, validate_job_state_transition, JobStateStatus, JobStateTransition};
+use job_tasks::{query_job_tasks, JobTaskPage, DEFAULT_JOB_TASK_PAGE_SIZE};
+use job_templates::{
+  copy_job_template_seed_files,
+  derive_dated_job_root_path,
+  format_utc_date,
+  list_job_templates as list_job_templates_in_directory,
+  read_job_template,
+  validate_job_template_name,
+  JobTemplateSummary,
+  JOB_TEMPLATES_DIRECTORY_NAME,
+};
+use llm_client::LlmEndpointSettings;
+use log_capture::{capture_stream_lines, CapturedLogLine};
+use maintenance_mode::{
+  describe_maintenance_refusal, describe_maintenance_status, MaintenanceJobPolicy, MaintenanceMode, MaintenanceStatus,
+  DEFAULT_MAINTENANCE_WAIT_TIMEOUT, MAINTENANCE_DRAIN_POLL_INTERVAL, MAX_MAINTENANCE_WAIT_TIMEOUT,
+};
+use markdown_validation::read_markdown_validation_report;
+use math_report::read_math_report;
+use notifications::{send_job_notifications, JobNotification, NotificationTarget, GLOBAL_NOTIFICATION_SETTINGS_FILENAME};
+use onedrive_connector::{
+  begin_device_code_sign_in as begin_onedrive_device_code_sign_in,
+  complete_device_code_sign_in as complete_onedrive_device_code_sign_in,
+  get_onedrive_connector_status as get_onedrive_connector_status_from_state,
+  is_signed_in as is_onedrive_signed_in,
+  poll_onedrive_folder_once,
+  DeviceCodePrompt,
+  GraphSession,
+  OneDriveConnectorSettings,
+  OneDriveConnectorStatus,
+  OneDriveDocumentHandler,
+  OneDriveWriteBackTarget,
+  ONEDRIVE_CONNECTOR_SETTINGS_FILENAME,
+  ONEDRIVE_CONNECTOR_STATE_FILENAME,
+};
+use output_documents::{load_document_markdowns, map_container_data_path_to_host};
+use page_prerender::{
+  count_pdf_pages,
+  render_pdf_page,
+  render_pdf_page_to_png,
+  spawn_pdf_page_prerender,
+  PDF_PAGE_BROWSE_DOTS_PER_INCH,
+};
+use output_destinations::{deliver_outputs_to_destination, OutputDestination, OutputDestinationResult};
+use packaging::{package_job_outputs, PACKAGE_ARCHIVE_FILENAME};
+use plugins::{
+  discover_plugins, run_plugin, select_enabled_plugins, PluginKind, PluginLogSink, PluginManifest, PluginRunResult,
+  PLUGINS_DIRECTORY_NAME,
+};
+use probe_timeout::{output_with_timeout, ProbeCommandError, PROBE_COMMAND_TIMEOUT, SLOW_PROBE_COMMAND_TIMEOUT};
+use quick_jobs::{
+  encode_clipboard_image_png,
+  find_quick_job_definition,
+  get_quick_job_status as get_quick_job_status_from_state,
+  new_shared_quick_job_state,
+  parse_clipboard_file_paths,
+  parse_quick_job_menu_item_id,
+  quick_job_menu_item_id,
+  quick_job_name_for_hotkey,
+  record_quick_job_result,
+  replace_quick_job_hotkeys,
+  validate_quick_job_definitions,
+  QuickJobContent,
+  QuickJobDefinition,
+  QuickJobStatus,
+  SharedQuickJobRuntimeState,
+  CLIPBOARD_IMAGE_FILENAME,
+  QUICK_JOB_SETTINGS_FILENAME,
+  QUICK_JOB_TRAY_ICON_ID,
+};
+use provenance::{
+  current_host,
+  hash_files,
+  list_files_recursively,
+  load_provenance_pages,
+  read_provenance_manifest,
+  rename_provenance_output,
+  write_provenance_manifest,
+  ProvenanceEngine,
+  ProvenanceManifest,
+  PROVENANCE_DIRECTORY_NAME,
+  PROVENANCE_SCHEMA_VERSION,
+};
+use removable_volumes::{describe_unavailable_volume, detect_volume_kind, VolumeKind};
+use runtime_limits::{apply_runtime_limits, current_runtime_limits, RuntimeLimits, RUNTIME_LIMITS_SETTINGS_FILENAME};
+use script_hooks::{run_script_hooks_for_stage, ScriptHookLogSink, ScriptHookSettings, ScriptHookStage};
+use settings_tuning::{
+  describe_sampled_page,
+  measure_output_markdown,
+  recommend_trial,
+  sample_input_pages,
+  settings_tuning_trials,
+  SettingsTuningReport,
+  SettingsTuningTrial,
+  SettingsTuningTrialResult,
+  SETTINGS_TUNING_DIRECTORY_NAME,
+  SETTINGS_TUNING_REPORT_FILENAME,
+  SETTINGS_TUNING_SAMPLE_DIRECTORY_NAME,
+};
+use semantic_search::{
+  index_document_embeddings,
+  semantic_search as semantic_search_in_root,
+  SemanticSearchHit,
+  EMBEDDINGS_DATABASE_FILENAME,
+};
+use smoke_test::{
+  evaluate_smoke_test_output,
+  prepare_smoke_test_root,
+  SmokeTestReport,
+  SMOKE_TEST_DIRECTORY_NAME,
+  SMOKE_TEST_OUTPUT_MARKDOWN_FILENAME,
+};
+use spellcheck::{spellcheck_markdown_file, SpellcheckReport, SpellcheckSettings, SPELLCHECK_REPORT_FILENAME};
+use status_export::{
+  derive_running_job_phase, write_status_files_best_effort, JobPhase, StatusFileWriter,
+  OCR_AGENT_STATUS_DIRECTORY_ENVIRONMENT_VARIABLE_NAME, STATUS_FILENAME,
+};
+use status_query_cache::StatusQueryCache;
+use structured_extraction::{
+  extract_structured_documents,
+  StructuredExtractionSchema,
+  STRUCTURED_OUTPUT_DIRECTORY_NAME,
+};
+use submitter_routing::{
+  find_submitter_route, read_submitter_routes, write_submitter_routes, SubmitterRoute, SUBMITTER_ROUTING_FILENAME,
+};
+use summarization::{summarize_markdown_file, SummarizationMode};
+use task_regions::{read_task_regions, TaskRegions};
+use template_rendering::{
+  list_template_names, render_templates, OutputTemplateCatalog, TemplateRenderContext, RENDERED_OUTPUT_DIRECTORY_NAME,
+  TEMPLATES_DIRECTORY_NAME,
+};
+use translation::translate_markdown_file;
+use wsl_diagnostics::{describe_failed_wsl_checks, run_wsl_diagnostics as run_wsl_diagnostic_checks, WslDiagnosticCheck};
 use watch_folder::{
   default_poll_interval as default_watch_poll_interval,
+  extract_settled_zip_drops,
+  find_or_add_watch_folder_state,
+  find_running_watch_folder_using_directory,
+  find_watch_folder_state,
   get_watch_folder_status as get_watch_folder_status_from_state,
+  is_bundle_waiting_in_higher_priority_inbox,
+  is_watch_folder_intake_paused,
+  is_watch_folder_paused_by_operator,
+  is_watch_folder_stop_requested,
   list_ready_bundle_directories,
+  list_watch_folder_statuses,
+  mark_bundle_duplicate,
   mark_bundle_failed,
   mark_bundle_processed,
+  mark_quiescent_bundles_ready,
+  new_shared_watch_folder_registry,
   new_shared_watch_folder_state,
+  record_watch_folder_duplicate_bundle,
+  record_watch_folder_error,
+  record_watch_folder_retention_cleanup,
+  release_bundle_processing_lock,
+  remove_watch_folder_state,
+  restart_watch_folder,
+  sanitize_submitter,
+  set_watch_folder_intake_pause_reason,
+  set_watch_folder_operator_pause_reason,
   start_watch_folder as start_watch_folder_with_callback,
+  submitter_of_bundle_folder,
   stop_watch_folder as stop_watch_folder_internal,
   try_lock_bundle_for_processing,
+  watch_folder_directory_paths,
+  wrap_settled_single_file_drops,
+  BundleQuiescenceObservations,
+  SharedWatchFolderRegistry,
   SharedWatchFolderRuntimeState,
+  SingleFileDropObservations,
   WatchFolderConfig,
+  WatchFolderPollCallback,
   WatchFolderStatus,
+  ZipDropObservations,
+  MIN_BUNDLE_QUIESCENCE_SECONDS,
+  PRIMARY_WATCH_ID,
+  WATCH_DUPLICATE_OF_FILENAME,
+};
+use work_cleanup::{
+  clean_work_directory,
+  retention_days_to_min_age,
+  validate_work_cleanup_policy,
+  WorkCleanupPolicy,
+  WorkCleanupSummary,
 };
 
-const DEFAULT_QUEUE_DATABASE_FILENAME: &str = "queue.sqlite3";
-const DEFAULT_INPUT_DIRECTORY_NAME: &str = "input";
-const DEFAULT_OUTPUT_DIRECTORY_NAME: &str = "output";
 const DEFAULT_OUTPUT_MARKDOWN_FILENAME_EXTENSION: &str = ".md";
 const DEFAULT_OUTPUT_MARKDOWN_FILENAME_PREFIX: &str = "ocr_output_";
+const DEFAULT_ROLLING_OUTPUT_MARKDOWN_FILENAME: &str = "ocr_log.md";
 
 const DEFAULT_JOB_SETTINGS_DIRECTORY_NAME: &str = ".ocr-agent";
 const DEFAULT_JOB_SETTINGS_FILENAME: &str = "job.json";
-
-const MAX_LOG_LINES: usize = 1500;
-const MAX_COPY_COLLISION_ATTEMPTS: u32 = 1000;
+const JOB_SETTINGS_PRESETS_DIRECTORY_NAME: &str = "presets";
+
+const DEFAULT_SEMANTIC_SEARCH_RESULT_LIMIT: usize = 20;
+const DEFAULT_DUPLICATE_TEXT_MAX_HAMMING_DISTANCE: u32 = 3;
+const MAX_JOB_WORKER_COUNT: u32 = 8;
+// NOTE: Cancellation is checked between chunks, so this bounds how long stopping the watcher can block.
+const CANCELLABLE_COPY_CHUNK_BYTES: usize = 8 * 1024 * 1024;
+// NOTE: Smaller files go through `fs::copy` (which can use OS copy offload); they finish before a cancel would matter.
+const STREAMING_COPY_MIN_FILE_BYTES: u64 = 64 * 1024 * 1024;
+// NOTE: Folder ingestion is dominated by per-file latency on NAS shares, so a few copies run at once.
+const MAX_PARALLEL_COPY_THREADS: usize = 8;
+const MAX_REPORTED_COPY_ERRORS: usize = 5;
+const SUSPENDED_JOB_RESUME_POLL_INTERVAL: Duration = Duration::from_secs(5);
 const DOCKER_COMPOSE_SERVICE_NAME: &str = "ocr-agent";
+// NOTE: The CUDA image cannot use a GPU on Apple Silicon, so Macs default to the CPU service behind this profile.
+const APPLE_SILICON_DOCKER_COMPOSE_SERVICE_NAME: &str = "ocr-agent-cpu";
+const APPLE_SILICON_DOCKER_COMPOSE_PROFILE_NAME: &str = "cpu";
+const DOCKER_COMPOSE_SETTINGS_FILENAME: &str = "docker_compose.json";
 const OCR_AGENT_REPO_ROOT_ENVIRONMENT_VARIABLE_NAME: &str = "OCR_AGENT_REPO_ROOT";
-const MAX_PREVIEW_IMAGE_BYTES: u64 = 8_000_000;
-const MAX_REPO_ROOT_SEARCH_DEPTH: usize = 8;
 
 const DEFAULT_WATCH_JOBS_DIRECTORY_NAME: &str = "jobs";
 const DEFAULT_WATCH_JOB_STATE_FILENAME: &str = "job_state.json";
 const DEFAULT_WATCH_READY_FILENAME: &str = ".ready";
+// NOTE: Optional bundle marker holding a pre-assigned job id (written by API submissions).
+const DEFAULT_WATCH_JOB_ID_FILENAME: &str = ".job_id";
+// NOTE: Optional bundle marker holding a partial `JobSettings` applied to the created job.
+const DEFAULT_WATCH_JOB_SETTINGS_FILENAME: &str = ".job_settings.json";
+// NOTE: Optional bundle marker that runs the created job even when the watcher's auto-run is off.
+const DEFAULT_WATCH_AUTO_RUN_FILENAME: &str = ".auto_run";
+// NOTE: Optional bundle marker that processes the bundle even if identical documents were already processed.
+const DEFAULT_WATCH_REPROCESS_FILENAME: &str = ".reprocess";
+// NOTE: Optional bundle marker naming who submitted it; takes precedence over an `@<submitter>` inbox folder.
+const DEFAULT_WATCH_SUBMITTER_FILENAME: &str = ".submitter";
+// NOTE: Name given to a written bundle document whose own name sanitizes to nothing.
+const DEFAULT_WATCH_BUNDLE_DOCUMENT_FILENAME: &str = "document";
+// NOTE: Markers and bundle settings are not documents: they are neither copied into the job nor fingerprinted.
+const WATCH_BUNDLE_NON_DOCUMENT_FILENAMES: &[&str] = &[
+  DEFAULT_WATCH_READY_FILENAME,
+  DEFAULT_WATCH_JOB_ID_FILENAME,
+  DEFAULT_WATCH_JOB_SETTINGS_FILENAME,
+  DEFAULT_WATCH_AUTO_RUN_FILENAME,
+  DEFAULT_WATCH_REPROCESS_FILENAME,
+  DEFAULT_WATCH_SUBMITTER_FILENAME,
+  WATCH_DUPLICATE_OF_FILENAME,
+  ".processing",
+  ".processed",
+  ".failed",
+];
 
 const OCR_AGENT_WATCH_INBOX_ENVIRONMENT_VARIABLE_NAME: &str = "OCR_AGENT_WATCH_INBOX";
 const OCR_AGENT_WATCH_JOBS_ROOT_ENVIRONMENT_VARIABLE_NAME: &str = "OCR_AGENT_WATCH_JOBS_ROOT";
+const OCR_AGENT_WATCH_ACCEPT_SINGLE_FILES_ENVIRONMENT_VARIABLE_NAME: &str = "OCR_AGENT_WATCH_ACCEPT_SINGLE_FILES";
+const OCR_AGENT_WATCH_QUIESCENCE_SECONDS_ENVIRONMENT_VARIABLE_NAME: &str = "OCR_AGENT_WATCH_QUIESCENCE_SECONDS";
+const OCR_AGENT_HTTP_API_TOKEN_ENVIRONMENT_VARIABLE_NAME: &str = "OCR_AGENT_HTTP_API_TOKEN";
+const OCR_AGENT_HTTP_API_BIND_ADDRESS_ENVIRONMENT_VARIABLE_NAME: &str = "OCR_AGENT_HTTP_API_ADDR";
+const OCR_AGENT_HTTP_API_SUBMITTER_TOKENS_ENVIRONMENT_VARIABLE_NAME: &str = "OCR_AGENT_HTTP_API_SUBMITTER_TOKENS";
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 struct JobSettings {
   output_markdown_filename_override: Option<String>,
   last_output_markdown_filename: Option<String>,
   is_math_delimiter_conversion_enabled: Option<bool>,
+  // NOTE: Also rewrites formulas already in the target style's counterpart (e.g. `$x$` when conversion is off).
+  is_math_delimiter_normalization_enabled: Option<bool>,
+  // NOTE: Writes `output/math.html` with every formula as MathML; needs `latex2mathml` in the engine image.
+  is_mathml_export_enabled: Option<bool>,
+  // NOTE: Copies each page's image to `output/assets/pages/` and links it at the top of the page's section.
+  is_page_image_export_enabled: Option<bool>,
+  is_hyphenation_repair_enabled: Option<bool>,
+  is_paragraph_reflow_enabled: Option<bool>,
+  // NOTE: One of "heading" (default), "template", or "none"; the template supports {n}, {total}, {source}.
+  page_separator_style: Option<String>,
+  page_separator_template: Option<String>,
+  is_page_source_comment_enabled: Option<bool>,
+  // NOTE: Rolling jobs append each run to one cumulative markdown file under a dated section header.
+  is_rolling_output_enabled: Option<bool>,
+  // NOTE: Records the output's detected title after each run; renaming only happens through `apply_detected_title`.
+  is_title_detection_enabled: Option<bool>,
   deepseek_ocr2_model_revision: Option<String>,
   deepseek_ocr2_markdown_prompt: Option<String>,
   deepseek_ocr2_base_image_size_pixels: Option<u32>,
   deepseek_ocr2_inference_image_size_pixels: Option<u32>,
   deepseek_ocr2_enable_crop_mode: Option<bool>,
+  // NOTE: On a CUDA out-of-memory failure, failed pages are re-run with a smaller inference image size.
+  is_oom_auto_retry_enabled: Option<bool>,
+  is_structured_extraction_enabled: Option<bool>,
+  structured_extraction_schema: Option<StructuredExtractionSchema>,
+  llm_endpoint: Option<LlmEndpointSettings>,
+  translation_target_language: Option<String>,
+  summarization_mode: Option<SummarizationMode>,
+  embedding_endpoint: Option<LlmEndpointSettings>,
+  spellcheck: Option<SpellcheckSettings>,
+  is_package_on_completion_enabled: Option<bool>,
+  // NOTE: Template filenames under `<app config>/templates/` rendered after each run.
+  output_template_names: Option<Vec<String>>,
+  script_hooks: Option<ScriptHookSettings>,
+  // NOTE: Names from `<app config>/plugins/*/plugin.json`; plugins run in manifest order, not list order.
+  enabled_plugin_names: Option<Vec<String>>,
+  // NOTE: When unset, the global targets in `<app config>/notifications.json` are used.
+  notification_targets: Option<Vec<NotificationTarget>>,
+  // NOTE: Writes `<job root>/status.json`; `OCR_AGENT_STATUS_DIR` additionally enables a global status directory.
+  is_status_file_enabled: Option<bool>,
+  // NOTE: Set by the OneDrive connector so results are uploaded next to the source document.
+  onedrive_write_back: Option<OneDriveWriteBackTarget>,
+  // NOTE: Signs `job_state.json` and each provenance manifest with this machine's key (see `verify_job_integrity`).
+  is_integrity_signing_enabled: Option<bool>,
+  // NOTE: Extra folders (e.g. a network share) that receive a copy of the final outputs after each successful run.
+  output_destinations: Option<Vec<OutputDestination>>,
+  // NOTE: Each extra worker is a separate container loading its own model copy; only useful on large-VRAM GPUs.
+  worker_count: Option<u32>,
+  // NOTE: Recorded when the job root is first prepared; jobs created before this setting existed use the defaults.
+  directory_layout: Option<JobDirectoryLayout>,
+  // NOTE: Advanced engine knobs passed to the OCR process as-is; names are limited to `OCR_AGENT_*` / `DEEPSEEK_*`.
+  extra_environment: Option<BTreeMap<String, String>>,
+  // NOTE: When `output/work/` page renders are deleted; unset keeps them, as before.
+  work_cleanup_policy: Option<WorkCleanupPolicy>,
+  work_retention_days: Option<u32>,
+}
+
+// NOTE: Variables `job_environment_variables` derives from first-class settings; `extra_environment` may not override them.
+const MANAGED_JOB_ENVIRONMENT_VARIABLE_NAMES: &[&str] = &[
+  "OCR_AGENT_MATH_DELIMITER_STYLE",
+  "OCR_AGENT_NORMALIZE_MATH_DELIMITERS",
+  "OCR_AGENT_EXPORT_MATHML",
+  "OCR_AGENT_EXPORT_PAGE_IMAGES",
+  "OCR_AGENT_REPAIR_HYPHENATION",
+  "OCR_AGENT_REFLOW_PARAGRAPHS",
+  "OCR_AGENT_PAGE_SEPARATOR_STYLE",
+  "OCR_AGENT_PAGE_SEPARATOR_TEMPLATE",
+  "OCR_AGENT_PAGE_SOURCE_COMMENTS",
+  "DEEPSEEK_OCR2_MODEL_REVISION",
+  "DEEPSEEK_OCR2_MARKDOWN_PROMPT",
+  "DEEPSEEK_OCR2_BASE_IMAGE_SIZE_PIXELS",
+  "DEEPSEEK_OCR2_INFERENCE_IMAGE_SIZE_PIXELS",
+  "DEEPSEEK_OCR2_ENABLE_CROP_MODE",
+];
+
+fn validate_job_settings(settings: &JobSettings) -> Result<(), String> {
+  if let Some(extra_environment) = settings.extra_environment.as_ref() {
+    validate_extra_environment(extra_environment, MANAGED_JOB_ENVIRONMENT_VARIABLE_NAMES)?;
+  }
+  validate_work_cleanup_policy(settings.work_cleanup_policy.unwrap_or_default(), settings.work_retention_days)?;
+  Ok(())
 }
 
 fn job_settings_directory_path(job_root_directory_path: &Path) -> PathBuf {
@@ -96,7 +662,22 @@ fn read_job_settings_best_effort(job_root_directory_path: &Path) -> JobSettings
   serde_json::from_str::<JobSettings>(&raw).unwrap_or_default()
 }
 
+fn job_directory_layout(job_root_directory_path: &Path) -> JobDirectoryLayout {
+  read_job_settings_best_effort(job_root_directory_path)
+    .directory_layout
+    .unwrap_or_default()
+}
+
+fn job_input_directory_path(job_root_directory_path: &Path) -> PathBuf {
+  job_directory_layout(job_root_directory_path).input_directory_path(job_root_directory_path)
+}
+
+fn job_output_directory_path(job_root_directory_path: &Path) -> PathBuf {
+  job_directory_layout(job_root_directory_path).output_directory_path(job_root_directory_path)
+}
+
 fn write_job_settings(job_root_directory_path: &Path, settings: &JobSettings) -> Result<(), String> {
+  validate_job_settings(settings)?;
   let settings_directory_path = job_settings_directory_path(job_root_directory_path);
   fs::create_dir_all(&settings_directory_path).map_err(|error| error.to_string())?;
   let settings_path = job_settings_file_path(job_root_directory_path);
@@ -107,7 +688,10 @@ fn write_job_settings(job_root_directory_path: &Path, settings: &JobSettings) ->
 
 #[derive(Debug, Clone, Serialize)]
 struct JobStatus {
+  // NOTE: Lossy UTF-8. The GUI sends this string back to address the job, so a root whose path is not valid UTF-8
+  // (possible on Linux, rare elsewhere) is listed but cannot be controlled from the GUI.
   job_root_directory_path: String,
+  job_id: Option<String>,
   is_running: bool,
   start_unix_timestamp_millis: Option<i64>,
   total_tasks: i64,
@@ -117,6 +701,21 @@ struct JobStatus {
   failed_tasks: i64,
   last_error_message: Option<String>,
   estimated_time_remaining_seconds: Option<i64>,
+  // NOTE: From the root's heartbeat.json; a stale heartbeat on a job this session is not running means a crashed run.
+  heartbeat_age_seconds: Option<i64>,
+  is_heartbeat_stale: bool,
+  // NOTE: Set by `pause_job`; the run keeps its place and progress until `resume_job`.
+  is_paused: bool,
+  // NOTE: A graceful cancel is pending; the run stops after its current page.
+  is_cancel_requested: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct StatusFileSnapshot {
+  #[serde(flatten)]
+  status: JobStatus,
+  phase: JobPhase,
+  updated_unix_timestamp_millis: i64,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -133,6 +732,17 @@ struct CurrentTaskPreview {
   pdf_total_pages: Option<i64>,
   preview_image_file_path: Option<String>,
   deepseek_inference_image_size_pixels: Option<u32>,
+  // NOTE: True when no task is running and this is the next task the runner will claim (lookahead only).
+  is_pending: bool,
+  next_task: Option<Box<CurrentTaskPreview>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+struct InputCopyProgress {
+  total_bytes: u64,
+  copied_bytes: u64,
+  current_file_name: Option<String>,
+  is_cancel_requested: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -141,21 +751,95 @@ struct PreviewImageBytes {
   bytes: Vec<u8>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct PdfPagePreview {
+  page_index: i64,
+  total_pages: i64,
+  // NOTE: Pixel size at `dots_per_inch`; crop rectangles picked on the image map back to PDF points via 72 / dots_per_inch.
+  width_pixels: u32,
+  height_pixels: u32,
+  dots_per_inch: f32,
+  mime_type: String,
+  bytes: Vec<u8>,
+}
+
 #[derive(Debug)]
 struct RunningJobHandle {
+  // NOTE: For containers started outside this GUI session, this is the `docker logs -f` follower; cancelling stops
+  // the container through `pause_targets`.
   child: Arc<Mutex<Child>>,
+  // NOTE: Extra `run --worker` containers sharing the queue; the primary child merges once they drain it.
+  worker_children: Vec<Arc<Mutex<Child>>>,
   start_unix_timestamp_millis: i64,
+  // NOTE: Set by `cancel_job`; the waiter then writes a partial output instead of treating the exit as a failure.
+  is_cancel_requested: bool,
+  // NOTE: How the cancel was requested; copied into the job state file once the run has stopped.
+  cancellation: Option<JobCancellationRecord>,
+  // NOTE: Detected at start; a failed run whose removable/network job root has vanished is suspended, not failed.
+  volume_kind: VolumeKind,
+  // NOTE: A Docker run whose compose client died because the engine went away is interrupted, not failed.
+  is_docker_engine: bool,
+  // NOTE: Written into the heartbeat so external tools can find the container; None for host runs.
+  container_name: Option<String>,
+  // NOTE: What `pause_job` freezes: the run's container or host process group, then one entry per worker.
+  pause_targets: Vec<JobPauseTarget>,
+  paused_unix_timestamp_millis: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum SuspensionCause {
+  VolumeUnavailable,
+  DockerEngineUnavailable,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SuspendedJob {
+  job_root_directory_path: String,
+  cause: SuspensionCause,
+  volume_kind: VolumeKind,
+  reason: String,
+  suspended_unix_timestamp_millis: i64,
 }
 
 #[derive(Default)]
 struct JobRuntimeState {
   running_job_by_root: HashMap<PathBuf, RunningJobHandle>,
-  log_lines_by_root: HashMap<PathBuf, VecDeque<String>>,
+  // NOTE: Only running and recently used roots stay in memory; the others are read back from their `job_log.txt`.
+  job_log_store: JobLogStore,
   job_state_file_path_by_root: HashMap<PathBuf, PathBuf>,
+  // NOTE: Ids of the jobs seen since startup, from their job_state.json; the dashboard, API and notifications use them.
+  job_id_by_root: HashMap<PathBuf, String>,
+  job_root_by_id: HashMap<String, PathBuf>,
+  // NOTE: Resolved once at startup because post-run passes run on threads without an AppHandle.
+  app_config_directory_path: Option<PathBuf>,
+  job_status_cache: StatusQueryCache<PathBuf, JobStatus>,
+  job_root_index: JobRootIndex,
+  // NOTE: Present while inputs are being copied into the root; polled by the UI and flagged by `cancel_input_copy`.
+  input_copy_progress_by_root: HashMap<PathBuf, InputCopyProgress>,
+  // NOTE: Jobs whose job root vanished mid-run; each has a monitor thread that resumes it once the volume is back.
+  suspended_job_by_root: HashMap<PathBuf, SuspendedJob>,
+  // NOTE: Keyed by (job root, lookahead).
+  task_preview_cache: StatusQueryCache<(PathBuf, bool), Option<CurrentTaskPreview>>,
+  // NOTE: In memory only; restarting the app (e.g. after the reboot it was entered for) leaves maintenance mode.
+  maintenance_mode: Option<MaintenanceMode>,
+  // NOTE: Set once the HTTP API server is listening; None when it is not configured or failed to bind.
+  http_api_bind_address: Option<String>,
+  // NOTE: New runs waiting for a job slot; dispatched whenever a running job ends.
+  job_start_queue: JobStartQueue,
+  // NOTE: Set in `setup`; job events (`job://...`) are pushed through it from reader and waiter threads.
+  event_app_handle: Option<tauri::AppHandle<Wry>>,
 }
 
 type SharedJobRuntimeState = Arc<Mutex<JobRuntimeState>>;
 
+struct ConnectorStates {
+  onedrive: SharedConnectorRuntimeState,
+  google_drive: SharedConnectorRuntimeState,
+  hotfolders: SharedConnectorRuntimeState,
+  backfill: SharedConnectorRuntimeState,
+}
+
 fn now_unix_timestamp_millis() -> i64 {
   let duration_since_epoch = SystemTime::now()
     .duration_since(UNIX_EPOCH)
@@ -163,7 +847,7 @@ fn now_unix_timestamp_millis() -> i64 {
   duration_since_epoch.as_millis() as i64
 }
 
-fn ensure_job_directory_layout(job_root_directory_path: &Path) -> Result<(), String> {
+fn validate_job_root_directory(job_root_directory_path: &Path) -> Result<(), String> {
   if job_root_directory_path.as_os_str().is_empty() {
     // Guard: refusing to operate on an empty path.
     return Err("job_root_directory_path is empty".to_string());
@@ -176,15 +860,79 @@ fn ensure_job_directory_layout(job_root_directory_path: &Path) -> Result<(), Str
     // Guard: output directory must be a directory.
     return Err("Selected output path is not a directory.".to_string());
   }
+  Ok(())
+}
 
-  let input_directory_path = job_root_directory_path.join(DEFAULT_INPUT_DIRECTORY_NAME);
-  let output_directory_path = job_root_directory_path.join(DEFAULT_OUTPUT_DIRECTORY_NAME);
+fn ensure_job_directory_layout(job_root_directory_path: &Path) -> Result<(), String> {
+  validate_job_root_directory(job_root_directory_path)?;
+  let directory_layout = job_directory_layout(job_root_directory_path);
+  let input_directory_path = directory_layout.input_directory_path(job_root_directory_path);
+  let output_directory_path = directory_layout.output_directory_path(job_root_directory_path);
   fs::create_dir_all(&input_directory_path).map_err(|error| error.to_string())?;
   fs::create_dir_all(&output_directory_path).map_err(|error| error.to_string())?;
   fs::create_dir_all(job_settings_directory_path(job_root_directory_path)).map_err(|error| error.to_string())?;
   Ok(())
 }
 
+fn read_app_job_directory_layout_best_effort(job_runtime_state: &SharedJobRuntimeState) -> JobDirectoryLayout {
+  read_app_config_json_file(job_runtime_state, JOB_DIRECTORY_LAYOUT_SETTINGS_FILENAME)
+    .ok()
+    .flatten()
+    .unwrap_or_default()
+}
+
+fn record_job_directory_layout_if_missing(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+) -> Result<(), String> {
+  let mut settings = read_job_settings_best_effort(job_root_directory_path);
+  if settings.directory_layout.is_some() {
+    return Ok(());
+  }
+  let default_layout = JobDirectoryLayout::default();
+  // Guard: a job that already holds data under the default names keeps them, whatever the app setting says now.
+  let directory_layout = if default_layout.has_job_data(job_root_directory_path) {
+    default_layout
+  } else {
+    let app_layout = read_app_job_directory_layout_best_effort(job_runtime_state);
+    if app_layout != default_layout {
+      // NOTE: Status polling may have created empty default folders before the layout was recorded.
+      let _ = fs::remove_dir(default_layout.input_directory_path(job_root_directory_path));
+      let _ = fs::remove_dir(default_layout.output_directory_path(job_root_directory_path));
+    }
+    app_layout
+  };
+  settings.directory_layout = Some(directory_layout);
+  write_job_settings(job_root_directory_path, &settings)
+}
+
+// NOTE: Entry points that may see a job root for the first time record its layout before creating folders.
+fn prepare_job_directory(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path) -> Result<(), String> {
+  validate_job_root_directory(job_root_directory_path)?;
+  let is_prepared = job_runtime_state
+    .lock()
+    .map(|locked_state| locked_state.job_root_index.is_directory_layout_prepared(job_root_directory_path))
+    .unwrap_or(false);
+  if is_prepared {
+    // NOTE: The recorded layout never changes and job events drop the index entry, so the folders are still there.
+    return Ok(());
+  }
+  record_job_directory_layout_if_missing(job_runtime_state, job_root_directory_path)?;
+  ensure_job_directory_layout(job_root_directory_path)?;
+  ensure_job_state(job_runtime_state, job_root_directory_path)?;
+  if let Ok(mut locked_state) = job_runtime_state.lock() {
+    locked_state.job_root_index.mark_directory_layout_prepared(job_root_directory_path);
+  }
+  Ok(())
+}
+
+// NOTE: Job start/finish and resets change a root's folders or queue; the next poll then reads them again.
+fn invalidate_job_root_index(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path) {
+  if let Ok(mut locked_state) = job_runtime_state.lock() {
+    locked_state.job_root_index.invalidate(job_root_directory_path);
+  }
+}
+
 fn normalize_windows_path_lossy(path: &Path) -> String {
   let raw = path.to_string_lossy().to_string();
   if !cfg!(target_os = "windows") {
@@ -250,7 +998,7 @@ fn repo_root_path() -> Result<PathBuf, String> {
 
 fn find_repo_root_by_walking_up(start_directory_path: &Path) -> Option<PathBuf> {
   let mut current = start_directory_path.to_path_buf();
-  for _ in 0..MAX_REPO_ROOT_SEARCH_DEPTH {
+  for _ in 0..current_runtime_limits().max_repo_root_search_depth {
     let compose_candidate = current.join("compose.yaml");
     if compose_candidate.exists() {
       let canonical = current.canonicalize().ok()?;
@@ -266,16 +1014,166 @@ fn compose_file_path(repo_root: &Path) -> PathBuf {
   repo_root.join("compose.yaml")
 }
 
-fn build_docker_compose_base_command(repo_root: &Path) -> Command {
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct DockerComposeSettings {
+  // NOTE: Any service in compose.yaml (e.g. "ocr-agent-cpu", "ocr-agent-int8"); unset means the platform default.
+  service_name: Option<String>,
+  // NOTE: Passed as `--profile` so services gated behind a compose profile resolve for build/run.
+  profile_name: Option<String>,
+  // NOTE: "sha256:..." image ID or repo digest; jobs refuse to start when the local image differs.
+  pinned_image_digest: Option<String>,
+}
+
+impl DockerComposeSettings {
+  fn configured_service_name(&self) -> Option<&str> {
+    self.service_name.as_deref().map(str::trim).filter(|name| !name.is_empty())
+  }
+
+  fn resolved_service_name(&self) -> &str {
+    match self.configured_service_name() {
+      Some(service_name) => service_name,
+      None if is_apple_silicon_host() => APPLE_SILICON_DOCKER_COMPOSE_SERVICE_NAME,
+      None => DOCKER_COMPOSE_SERVICE_NAME,
+    }
+  }
+
+  fn resolved_profile_name(&self) -> Option<&str> {
+    let configured_profile_name = self.profile_name.as_deref().map(str::trim).filter(|name| !name.is_empty());
+    // Guard: only the default Mac service needs its profile implied; an explicit service keeps the explicit profile.
+    if configured_profile_name.is_none() && self.configured_service_name().is_none() && is_apple_silicon_host() {
+      return Some(APPLE_SILICON_DOCKER_COMPOSE_PROFILE_NAME);
+    }
+    configured_profile_name
+  }
+
+  fn resolved_pinned_image_digest(&self) -> Option<&str> {
+    self.pinned_image_digest.as_deref().map(str::trim).filter(|digest| !digest.is_empty())
+  }
+}
+
+#[derive(Debug, Clone)]
+struct DockerImageDigests {
+  image_id: String,
+  repo_digests: Vec<String>,
+  // NOTE: None for images built without the label (e.g. an older compose.yaml).
+  build_inputs_hash: Option<String>,
+}
+
+impl DockerImageDigests {
+  fn matches(&self, pinned_image_digest: &str) -> bool {
+    // NOTE: Locally built images only have an ID; pulled images also carry `repo@sha256:...` digests.
+    self.image_id == pinned_image_digest
+      || self.repo_digests.iter().any(|repo_digest| {
+        repo_digest == pinned_image_digest || repo_digest.rsplit('@').next() == Some(pinned_image_digest)
+      })
+  }
+}
+
+fn is_docker_image_rebuild_recommended(repo_root: &Path, image_digests: &DockerImageDigests) -> bool {
+  let current_build_inputs_hash = compute_build_inputs_hash(repo_root);
+  image_digests
+    .build_inputs_hash
+    .as_deref()
+    .map(|built_hash| built_hash != current_build_inputs_hash)
+    .unwrap_or(false)
+}
+
+// NOTE: A missing image is `Failed`; `EngineUnresponsive` says nothing about whether the image exists.
+fn inspect_docker_image_digests(image_name: &str) -> Result<DockerImageDigests, ProbeCommandError> {
+  let output = output_with_timeout(
+    Command::new("docker").arg("image").arg("inspect").arg(image_name),
+    PROBE_COMMAND_TIMEOUT,
+  )
+  .map_err(|error| match error {
+    ProbeCommandError::Failed(message) => {
+      ProbeCommandError::Failed(format!("Failed to run docker image inspect. {message}"))
+    }
+    unresponsive => unresponsive,
+  })?;
+  if !output.status.success() {
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    return Err(ProbeCommandError::Failed(format!("docker image inspect failed for {image_name}.\n{stderr}")));
+  }
+  let inspected: Value =
+    serde_json::from_slice(&output.stdout).map_err(|error| ProbeCommandError::Failed(error.to_string()))?;
+  let image = inspected.get(0).ok_or_else(|| {
+    ProbeCommandError::Failed(format!("docker image inspect returned nothing for {image_name}"))
+  })?;
+  Ok(DockerImageDigests {
+    image_id: image.get("Id").and_then(Value::as_str).unwrap_or_default().to_string(),
+    repo_digests: image
+      .get("RepoDigests")
+      .and_then(Value::as_array)
+      .map(|values| values.iter().filter_map(Value::as_str).map(str::to_string).collect())
+      .unwrap_or_default(),
+    build_inputs_hash: image
+      .pointer("/Config/Labels")
+      .and_then(|labels| labels.get(BUILD_INPUTS_HASH_IMAGE_LABEL))
+      .and_then(Value::as_str)
+      .filter(|hash| !hash.is_empty())
+      .map(str::to_string),
+  })
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct DockerProbeReport {
+  warnings: Vec<String>,
+  is_rebuild_recommended: bool,
+}
+
+fn read_docker_compose_settings_best_effort(job_runtime_state: &SharedJobRuntimeState) -> DockerComposeSettings {
+  read_app_config_json_file(job_runtime_state, DOCKER_COMPOSE_SETTINGS_FILENAME)
+    .ok()
+    .flatten()
+    .unwrap_or_default()
+}
+
+fn validate_docker_compose_name(kind: &str, name: &str) -> Result<(), String> {
+  let is_valid = name
+    .chars()
+    .all(|character| character.is_ascii_alphanumeric() || matches!(character, '-' | '_' | '.'));
+  if !is_valid {
+    // Guard: names are passed straight to `docker compose`; reject anything that is not a compose identifier.
+    return Err(format!("Invalid compose {kind} name: {name}"));
+  }
+  Ok(())
+}
+
+fn build_docker_compose_base_command(repo_root: &Path, compose_settings: &DockerComposeSettings) -> Command {
   let mut command = Command::new("docker");
   command.arg("compose");
   command.arg("-f");
   command.arg(compose_file_path(repo_root));
   command.arg("--project-directory");
   command.arg(repo_root);
+  if let Some(profile_name) = compose_settings.resolved_profile_name() {
+    command.arg("--profile");
+    command.arg(profile_name);
+  }
   command
 }
 
+fn list_docker_compose_services(repo_root: &Path, compose_settings: &DockerComposeSettings) -> Result<Vec<String>, String> {
+  let output = output_with_timeout(
+    build_docker_compose_base_command(repo_root, compose_settings)
+      .arg("config")
+      .arg("--services"),
+    PROBE_COMMAND_TIMEOUT,
+  )
+  .map_err(|error| format!("Failed to run docker compose config. {error}"))?;
+  if !output.status.success() {
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    return Err(format!("docker compose config failed.\n{stderr}"));
+  }
+  Ok(
+    String::from_utf8_lossy(&output.stdout)
+      .lines()
+      .map(|line| line.trim().to_string())
+      .filter(|line| !line.is_empty())
+      .collect(),
+  )
+}
+
 fn derive_compose_project_name(repo_root: &Path) -> String {
   repo_root
     .file_name()
@@ -292,12 +1190,11 @@ fn derive_compose_service_image_name(repo_root: &Path, service_name: &str) -> St
 }
 
 fn validate_docker_available() -> Result<(), String> {
-  let output = Command::new("docker")
-    .arg("version")
-    .stdout(Stdio::piped())
-    .stderr(Stdio::piped())
-    .output()
-    .map_err(|error| format!("Failed to run docker. Is Docker Desktop installed? {error}"))?;
+  let output =
+    output_with_timeout(Command::new("docker").arg("version"), PROBE_COMMAND_TIMEOUT).map_err(|error| match error {
+      ProbeCommandError::Failed(message) => format!("Failed to run docker. Is Docker Desktop installed? {message}"),
+      unresponsive => unresponsive.to_string(),
+    })?;
 
   if output.status.success() {
     return Ok(());
@@ -307,9 +1204,35 @@ fn validate_docker_available() -> Result<(), String> {
   Err(format!("Docker is not available.\n{stderr}"))
 }
 
-#[tauri::command]
-fn probe_docker() -> Result<(), String> {
+// NOTE: The host engine runs the CLI with a local interpreter and never needs Docker.
+fn validate_execution_engine_available(job_runtime_state: &SharedJobRuntimeState) -> Result<(), String> {
+  let engine_settings = read_execution_engine_settings_best_effort(job_runtime_state);
+  match engine_settings.kind {
+    ExecutionEngineKind::Docker => validate_docker_available(),
+    ExecutionEngineKind::Host => {
+      validate_host_engine_supported()?;
+      let python_interpreter_path = engine_settings.resolved_python_interpreter_path()?;
+      if !Path::new(python_interpreter_path).is_file() {
+        return Err(format!("Python interpreter not found: {python_interpreter_path}"));
+      }
+      Ok(())
+    }
+  }
+}
+
+fn probe_docker_blocking(job_runtime_state: &SharedJobRuntimeState) -> Result<DockerProbeReport, String> {
+  let engine_settings = read_execution_engine_settings_best_effort(job_runtime_state);
+  if engine_settings.kind == ExecutionEngineKind::Host {
+    // NOTE: The UI probes before every run; with the host engine, "Docker" readiness means the local interpreter.
+    probe_host_python(&repo_root_path()?, engine_settings.resolved_python_interpreter_path()?)?;
+    return Ok(DockerProbeReport {
+      warnings: vec![],
+      is_rebuild_recommended: false,
+    });
+  }
   validate_docker_available()?;
+  let compose_settings = read_docker_compose_settings_best_effort(job_runtime_state);
+  let service_name = compose_settings.resolved_service_name();
 
   let repo_root = repo_root_path()?;
   let compose_path = compose_file_path(&repo_root);
@@ -321,134 +1244,745 @@ fn probe_docker() -> Result<(), String> {
     ));
   }
 
-  let output = Command::new("docker")
-    .arg("compose")
-    .arg("version")
-    .stdout(Stdio::piped())
-    .stderr(Stdio::piped())
-    .output()
+  let output = output_with_timeout(Command::new("docker").arg("compose").arg("version"), PROBE_COMMAND_TIMEOUT)
     .map_err(|error| format!("Failed to run docker compose. {error}"))?;
   if !output.status.success() {
     let stderr = String::from_utf8_lossy(&output.stderr).to_string();
     return Err(format!("docker compose is not available.\n{stderr}"));
   }
 
+  let available_service_names = list_docker_compose_services(&repo_root, &compose_settings)?;
+  if !available_service_names.iter().any(|name| name == service_name) {
+    // Guard: a service behind an unselected profile is hidden from `config --services`.
+    return Err(format!(
+      "Compose service `{service_name}` is not defined (profile: {}).\nAvailable services: {}",
+      compose_settings.resolved_profile_name().unwrap_or("none"),
+      available_service_names.join(", ")
+    ));
+  }
+
   // Guard: give a fast, actionable error if the image isn't built yet.
   // NOTE:
   // `docker compose images` can return an empty list unless containers were created, so we instead
   // check the derived image name Compose uses by default.
-  let derived_image_name = derive_compose_service_image_name(&repo_root, DOCKER_COMPOSE_SERVICE_NAME);
-  let inspect_output = Command::new("docker")
-    .arg("image")
-    .arg("inspect")
-    .arg(&derived_image_name)
-    .stdout(Stdio::null())
-    .stderr(Stdio::piped())
-    .output();
-
-  if let Ok(inspect_output) = inspect_output {
-    if !inspect_output.status.success() {
+  let derived_image_name = derive_compose_service_image_name(&repo_root, service_name);
+  let image_digests = match inspect_docker_image_digests(&derived_image_name) {
+    Ok(image_digests) => image_digests,
+    Err(unresponsive @ ProbeCommandError::EngineUnresponsive { .. }) => return Err(unresponsive.to_string()),
+    Err(ProbeCommandError::Failed(_)) => {
       return Err(format!(
-        "Docker image for `{DOCKER_COMPOSE_SERVICE_NAME}` is not built.\nExpected image: {derived_image_name}\nRun: docker compose -f \"{}\" build",
-        compose_path.display()
+        "Docker image for `{service_name}` is not built.\nExpected image: {derived_image_name}\nRun: docker compose -f \"{}\"{} build {service_name}",
+        compose_path.display(),
+        compose_settings
+          .resolved_profile_name()
+          .map(|profile_name| format!(" --profile {profile_name}"))
+          .unwrap_or_default()
+      ));
+    }
+  };
+
+  let mut warnings: Vec<String> = vec![];
+  if is_apple_silicon_host() {
+    warnings.push(format!(
+      "Apple Silicon has no NVIDIA GPU; `{service_name}` runs OCR on the CPU and is much slower. The host engine can use MPS instead."
+    ));
+  }
+  if let Some(pinned_image_digest) = compose_settings.resolved_pinned_image_digest() {
+    if !image_digests.matches(pinned_image_digest) {
+      warnings.push(format!(
+        "Local image {derived_image_name} ({}) differs from the pinned digest {pinned_image_digest}; jobs will refuse to start.",
+        image_digests.image_id
       ));
     }
   }
 
-  Ok(())
+  let is_rebuild_recommended = is_docker_image_rebuild_recommended(&repo_root, &image_digests);
+  if is_rebuild_recommended {
+    warnings.push(format!(
+      "Dockerfile/compose inputs changed since {derived_image_name} was built; rebuild recommended."
+    ));
+  }
+  Ok(DockerProbeReport {
+    warnings,
+    is_rebuild_recommended,
+  })
+}
+
+// NOTE: Several docker calls, each allowed up to `PROBE_COMMAND_TIMEOUT`; kept off the IPC thread.
+#[tauri::command]
+async fn probe_docker(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<DockerProbeReport, String> {
+  let job_runtime_state = job_runtime_state.inner().clone();
+  tauri::async_runtime::spawn_blocking(move || probe_docker_blocking(&job_runtime_state))
+    .await
+    .map_err(|error| error.to_string())?
 }
 
 #[tauri::command]
-fn probe_gpu_passthrough() -> Result<String, String> {
+fn start_docker_image_build(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+  image_build_state: State<'_, SharedImageBuildRuntimeState>,
+) -> Result<(), String> {
   validate_docker_available()?;
   let repo_root = repo_root_path()?;
+  let compose_settings = read_docker_compose_settings_best_effort(job_runtime_state.inner());
+  let mut command = build_docker_compose_base_command(&repo_root, &compose_settings);
+  command.arg("build");
+  command.arg(compose_settings.resolved_service_name());
+  command.env(BUILD_INPUTS_HASH_ENVIRONMENT_VARIABLE_NAME, compute_build_inputs_hash(&repo_root));
+  start_image_build(image_build_state.inner(), command)
+}
 
-  let output = build_docker_compose_base_command(&repo_root)
-    .arg("run")
-    .arg("--rm")
-    .arg(DOCKER_COMPOSE_SERVICE_NAME)
-    .arg("nvidia-smi")
-    .stdout(Stdio::piped())
-    .stderr(Stdio::piped())
-    .output()
-    .map_err(|error| format!("Failed to run GPU probe (nvidia-smi). {error}"))?;
-
-  if output.status.success() {
-    return Ok(String::from_utf8_lossy(&output.stdout).to_string());
-  }
-
-  let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-  Err(format!(
-    "GPU probe failed. Verify Docker Desktop GPU support and WSL2 GPU drivers.\n{stderr}"
-  ))
+fn read_gui_updater_settings(job_runtime_state: &SharedJobRuntimeState) -> Result<GuiUpdaterSettings, String> {
+  Ok(read_app_config_json_file(job_runtime_state, GUI_UPDATER_SETTINGS_FILENAME)?.unwrap_or_default())
 }
 
-#[tauri::command]
-fn get_watch_folder_status(
-  watch_folder_state: State<'_, SharedWatchFolderRuntimeState>,
-) -> Result<WatchFolderStatus, String> {
-  Ok(get_watch_folder_status_from_state(watch_folder_state.inner()))
+fn build_gui_updater(app_handle: &tauri::AppHandle<Wry>, settings: &GuiUpdaterSettings) -> Result<Updater, String> {
+  let pubkey = settings
+    .resolved_pubkey()
+    .ok_or_else(|| format!("Set `pubkey` in {GUI_UPDATER_SETTINGS_FILENAME} to enable GUI updates."))?;
+  let endpoints = settings
+    .endpoints
+    .iter()
+    .map(|endpoint| {
+      tauri::Url::parse(endpoint.trim()).map_err(|error| format!("Invalid updater endpoint {endpoint}: {error}"))
+    })
+    .collect::<Result<Vec<_>, String>>()?;
+  app_handle
+    .updater_builder()
+    .endpoints(endpoints)
+    .map_err(|error| error.to_string())?
+    .pubkey(pubkey)
+    .build()
+    .map_err(|error| error.to_string())
 }
 
-#[tauri::command]
-fn stop_watch_folder(watch_folder_state: State<'_, SharedWatchFolderRuntimeState>) -> Result<(), String> {
-  stop_watch_folder_internal(watch_folder_state.inner());
-  Ok(())
+// NOTE: None when jobs run on the host engine; there is no image to keep in step with the GUI then.
+fn inspect_engine_image(job_runtime_state: &SharedJobRuntimeState) -> Result<Option<(String, Option<DockerImageDigests>)>, String> {
+  if read_execution_engine_settings_best_effort(job_runtime_state).kind == ExecutionEngineKind::Host {
+    return Ok(None);
+  }
+  let repo_root = repo_root_path()?;
+  let compose_settings = read_docker_compose_settings_best_effort(job_runtime_state);
+  let image_name = derive_compose_service_image_name(&repo_root, compose_settings.resolved_service_name());
+  let image_digests = inspect_docker_image_digests(&image_name).ok();
+  Ok(Some((image_name, image_digests)))
 }
 
 #[tauri::command]
-fn start_watch_folder(
-  inbox_directory_path: String,
-  jobs_root_directory_path: Option<String>,
-  auto_run: Option<bool>,
+async fn check_for_updates(
+  app_handle: tauri::AppHandle<Wry>,
   job_runtime_state: State<'_, SharedJobRuntimeState>,
-  watch_folder_state: State<'_, SharedWatchFolderRuntimeState>,
-) -> Result<(), String> {
-  let inbox_directory_path = PathBuf::from(inbox_directory_path);
-  let jobs_root_directory_path = jobs_root_directory_path
-    .and_then(|raw| {
-      let trimmed = raw.trim().to_string();
-      if trimmed.is_empty() {
-        return None;
-      }
-      Some(trimmed)
-    })
-    .map(PathBuf::from)
-    .unwrap_or_else(|| inbox_directory_path.join(DEFAULT_WATCH_JOBS_DIRECTORY_NAME));
+) -> Result<UpdateCheckReport, String> {
+  let current_gui_version = app_handle.package_info().version.to_string();
+  let updater_settings = read_gui_updater_settings(job_runtime_state.inner())?;
+  let mut available_gui_version: Option<String> = None;
+  let mut gui_release_notes: Option<String> = None;
+  if updater_settings.is_configured() {
+    let update = build_gui_updater(&app_handle, &updater_settings)?
+      .check()
+      .await
+      .map_err(|error| format!("GUI update check failed. {error}"))?;
+    if let Some(update) = update {
+      available_gui_version = Some(update.version.clone());
+      gui_release_notes = update.body.clone();
+    }
+  }
 
-  let config = WatchFolderConfig {
-    inbox_directory_path,
-    jobs_root_directory_path,
-    poll_interval: default_watch_poll_interval(),
+  let job_runtime_state = job_runtime_state.inner().clone();
+  let engine_image = tauri::async_runtime::spawn_blocking({
+    let job_runtime_state = job_runtime_state.clone();
+    move || {
+      validate_docker_available()?;
+      inspect_engine_image(&job_runtime_state)
+    }
+  })
+  .await
+  .map_err(|error| error.to_string())??;
+  let record: Option<EngineUpdateRecord> = read_app_config_json_file(&job_runtime_state, ENGINE_UPDATE_RECORD_FILENAME)?;
+  let (engine_image_id, engine_update_reasons) = match engine_image {
+    None => (None, vec![]),
+    Some((_, image_digests)) => {
+      let repo_root = repo_root_path()?;
+      let is_rebuild_recommended = image_digests
+        .as_ref()
+        .map(|digests| is_docker_image_rebuild_recommended(&repo_root, digests))
+        .unwrap_or(false);
+      let engine_image_id = image_digests.map(|digests| digests.image_id);
+      let reasons = describe_engine_update_reasons(
+        &current_gui_version,
+        record.as_ref(),
+        engine_image_id.as_deref(),
+        is_rebuild_recommended,
+      );
+      (engine_image_id, reasons)
+    }
   };
-
-  let poll_callback = make_watch_folder_poll_callback(job_runtime_state.inner().clone(), auto_run.unwrap_or(false));
-
-  start_watch_folder_with_callback(watch_folder_state.inner(), config, poll_callback)?;
-  Ok(())
+  Ok(UpdateCheckReport {
+    current_gui_version,
+    is_gui_updater_configured: updater_settings.is_configured(),
+    available_gui_version,
+    gui_release_notes,
+    engine_image_id,
+    engine_verified_gui_version: record.map(|record| record.gui_version),
+    is_engine_update_recommended: !engine_update_reasons.is_empty(),
+    engine_update_reasons,
+  })
 }
 
+// NOTE: Restarts the app on success; `check_for_updates` then recommends the matching engine update.
 #[tauri::command]
-async fn pick_output_directory(app_handle: tauri::AppHandle<Wry>) -> Result<Option<String>, String> {
-  let (sender, receiver) = oneshot::channel::<Option<tauri_plugin_dialog::FilePath>>();
-  app_handle.dialog().file().pick_folder(move |path| {
-    // Guard: receiver side may be dropped if the request is cancelled.
-    let _ = sender.send(path);
-  });
-
-  let selected_directory_path = receiver
+async fn install_gui_update(
+  app_handle: tauri::AppHandle<Wry>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  if is_any_job_running(job_runtime_state.inner()) {
+    // Guard: restarting the GUI mid-run would orphan the job's container.
+    return Err("Wait for running jobs to finish (or enter maintenance mode) before updating the GUI.".to_string());
+  }
+  let updater_settings = read_gui_updater_settings(job_runtime_state.inner())?;
+  let update = build_gui_updater(&app_handle, &updater_settings)?
+    .check()
     .await
-    .map_err(|_| "Failed to receive folder picker result".to_string())?;
+    .map_err(|error| format!("GUI update check failed. {error}"))?
+    .ok_or_else(|| "No GUI update is available.".to_string())?;
+  update
+    .download_and_install(|_, _| {}, || {})
+    .await
+    .map_err(|error| format!("GUI update failed. {error}"))?;
+  app_handle.restart()
+}
 
-  let Some(directory_path) = selected_directory_path else {
-    return Ok(None);
-  };
+fn run_engine_update_command(engine_update_state: &SharedEngineUpdateRuntimeState, mut command: Command) -> Result<(), String> {
+  let output = command
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .output()
+    .map_err(|error| format!("Failed to run docker. {error}"))?;
+  for line in String::from_utf8_lossy(&output.stdout)
+    .lines()
+    .chain(String::from_utf8_lossy(&output.stderr).lines())
+  {
+    append_engine_update_log_line(engine_update_state, line.to_string());
+  }
+  if !output.status.success() {
+    return Err(format!("docker exited with {}", output.status));
+  }
+  Ok(())
+}
 
-  Ok(Some(file_path_to_string(directory_path)))
+fn tag_docker_image(engine_update_state: &SharedEngineUpdateRuntimeState, source: &str, target: &str) -> Result<(), String> {
+  let mut command = Command::new("docker");
+  command.arg("image").arg("tag").arg(source).arg(target);
+  run_engine_update_command(engine_update_state, command)
 }
 
-#[tauri::command]
-async fn pick_directory(app_handle: tauri::AppHandle<Wry>) -> Result<Option<String>, String> {
+fn verify_engine_image(
+  job_runtime_state: &SharedJobRuntimeState,
+  engine_update_state: &SharedEngineUpdateRuntimeState,
+) -> Result<(), String> {
+  let report = run_smoke_test_blocking(job_runtime_state)?;
+  append_engine_update_log_line(
+    engine_update_state,
+    format!(
+      "[backend] smoke test: {} in {} ms, {} character(s)",
+      if report.is_succeeded { "passed" } else { "failed" },
+      report.elapsed_millis,
+      report.output_character_count
+    ),
+  );
+  match report.error_message {
+    None => Ok(()),
+    Some(error_message) => Err(error_message),
+  }
+}
+
+// NOTE: Returns the final phase; the previous image stays tagged `:previous` for a manual rollback.
+fn run_staged_engine_update(
+  job_runtime_state: &SharedJobRuntimeState,
+  engine_update_state: &SharedEngineUpdateRuntimeState,
+  current_gui_version: &str,
+) -> Result<(EngineUpdatePhase, String), String> {
+  while is_any_job_running(job_runtime_state) {
+    thread::sleep(MAINTENANCE_DRAIN_POLL_INTERVAL);
+  }
+
+  set_engine_update_phase(engine_update_state, EngineUpdatePhase::Building, None);
+  let repo_root = repo_root_path()?;
+  let mut compose_settings = read_docker_compose_settings_best_effort(job_runtime_state);
+  let image_name = derive_compose_service_image_name(&repo_root, compose_settings.resolved_service_name());
+  let backup_image_name = previous_image_name(&image_name);
+  let previous_image_id = inspect_docker_image_digests(&image_name).ok().map(|digests| digests.image_id);
+  if previous_image_id.is_some() {
+    tag_docker_image(engine_update_state, &image_name, &backup_image_name)?;
+  }
+  let mut build_command = build_docker_compose_base_command(&repo_root, &compose_settings);
+  build_command.arg("build");
+  build_command.arg(compose_settings.resolved_service_name());
+  build_command.env(BUILD_INPUTS_HASH_ENVIRONMENT_VARIABLE_NAME, compute_build_inputs_hash(&repo_root));
+  // Guard: a failed build leaves the service tag on the previous image, so there is nothing to roll back.
+  run_engine_update_command(engine_update_state, build_command)?;
+  let candidate_image_id = inspect_docker_image_digests(&image_name)?.image_id;
+
+  set_engine_update_phase(engine_update_state, EngineUpdatePhase::Verifying, None);
+  if let Err(error) = verify_engine_image(job_runtime_state, engine_update_state) {
+    if previous_image_id.is_none() {
+      return Err(format!("The new image failed verification and there is no previous image to restore. {error}"));
+    }
+    tag_docker_image(engine_update_state, &backup_image_name, &image_name)?;
+    return Ok((
+      EngineUpdatePhase::RolledBack,
+      format!("The new image failed verification ({error}); {image_name} points at the previous image again."),
+    ));
+  }
+
+  // NOTE: A pinned environment only switches once the candidate has passed verification.
+  if compose_settings.resolved_pinned_image_digest().is_some() {
+    compose_settings.pinned_image_digest = Some(candidate_image_id.clone());
+    write_app_config_json_file(job_runtime_state, DOCKER_COMPOSE_SETTINGS_FILENAME, &compose_settings)?;
+  }
+  write_app_config_json_file(
+    job_runtime_state,
+    ENGINE_UPDATE_RECORD_FILENAME,
+    &EngineUpdateRecord {
+      gui_version: current_gui_version.to_string(),
+      image_id: candidate_image_id.clone(),
+      verified_unix_timestamp_millis: now_unix_timestamp_millis(),
+    },
+  )?;
+  Ok((
+    EngineUpdatePhase::Switched,
+    format!("{image_name} now runs {candidate_image_id} (previous image kept as {backup_image_name})."),
+  ))
+}
+
+// NOTE: Uses maintenance mode to stop new work while running jobs finish on the current image.
+#[tauri::command]
+fn start_engine_update(
+  app_handle: tauri::AppHandle<Wry>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+  engine_update_state: State<'_, SharedEngineUpdateRuntimeState>,
+) -> Result<EngineUpdateStatus, String> {
+  if read_execution_engine_settings_best_effort(job_runtime_state.inner()).kind == ExecutionEngineKind::Host {
+    // Guard: the host engine runs the repo's Python directly; there is no image to update.
+    return Err("Engine image updates only apply to the Docker engine.".to_string());
+  }
+  validate_docker_available()?;
+  begin_engine_update(engine_update_state.inner())?;
+  let is_maintenance_entered_here = {
+    let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    let is_maintenance_off = locked_state.maintenance_mode.is_none();
+    if is_maintenance_off {
+      locked_state.maintenance_mode = Some(MaintenanceMode {
+        reason: "engine image update".to_string(),
+        job_policy: MaintenanceJobPolicy::WaitForJobs,
+        entered_unix_timestamp_millis: now_unix_timestamp_millis(),
+      });
+    }
+    is_maintenance_off
+  };
+
+  let current_gui_version = app_handle.package_info().version.to_string();
+  let job_runtime_state = job_runtime_state.inner().clone();
+  let engine_update_state = engine_update_state.inner().clone();
+  thread::spawn(move || {
+    let result = run_staged_engine_update(&job_runtime_state, &engine_update_state, &current_gui_version);
+    // Guard: an operator's own maintenance window stays on; only the one started for this update ends here.
+    if is_maintenance_entered_here {
+      if let Err(error) = leave_maintenance_mode(&job_runtime_state) {
+        append_engine_update_log_line(&engine_update_state, format!("[backend] leaving maintenance mode failed: {error}"));
+      }
+    }
+    match result {
+      Ok((phase, message)) => set_engine_update_phase(&engine_update_state, phase, Some(message)),
+      Err(error) => set_engine_update_phase(&engine_update_state, EngineUpdatePhase::Failed, Some(error)),
+    }
+  });
+  Ok(get_engine_update_status_from_state(engine_update_state.inner()))
+}
+
+#[tauri::command]
+fn get_engine_update_status(engine_update_state: State<'_, SharedEngineUpdateRuntimeState>) -> Result<EngineUpdateStatus, String> {
+  Ok(get_engine_update_status_from_state(engine_update_state.inner()))
+}
+
+// NOTE: Runs a few short CLI probes (docker, nvidia-smi, scanimage); call it once at startup, not on every poll.
+#[tauri::command]
+async fn get_backend_capabilities(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<BackendCapabilities, String> {
+  let job_runtime_state = job_runtime_state.inner().clone();
+  tauri::async_runtime::spawn_blocking(move || {
+    let engine_settings = read_execution_engine_settings_best_effort(&job_runtime_state);
+    let gpu_name = probe_host_gpu_name();
+    let scanner_backend = probe_scanner_backend();
+    // NOTE: A broken notifications.json reports no channels here; sending reports the parse error.
+    let notification_channels = read_global_notification_targets(&job_runtime_state)
+      .unwrap_or_default()
+      .iter()
+      .map(|target| target.build_notifier().channel_name().to_string())
+      .collect();
+    let http_api_bind_address = job_runtime_state
+      .lock()
+      .map_err(|_| "State lock poisoned".to_string())?
+      .http_api_bind_address
+      .clone();
+    let is_gui_updater_configured = read_gui_updater_settings(&job_runtime_state)
+      .map(|settings| settings.is_configured())
+      .unwrap_or(false);
+    Ok(BackendCapabilities {
+      execution_engine: engine_settings.kind,
+      container_runtime: probe_container_runtime(),
+      is_host_engine_supported: validate_host_engine_supported().is_ok(),
+      is_gpu_available: gpu_name.is_some(),
+      gpu_name,
+      is_apple_silicon: is_apple_silicon_host(),
+      is_scanner_available: scanner_backend.is_some(),
+      scanner_backend,
+      is_clipboard_available: is_clipboard_environment_available(),
+      notification_channels,
+      is_http_api_enabled: http_api_bind_address.is_some(),
+      http_api_bind_address,
+      is_gui_updater_configured,
+    })
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+#[tauri::command]
+async fn get_docker_disk_usage() -> Result<DockerDiskUsage, String> {
+  tauri::async_runtime::spawn_blocking(|| {
+    validate_docker_available()?;
+    let repo_root = repo_root_path()?;
+    read_docker_disk_usage(&derive_compose_project_name(&repo_root))
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+#[tauri::command]
+async fn prune_docker_artifacts(
+  options: Option<DockerPruneOptions>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<DockerPruneReport, String> {
+  if is_any_job_running(job_runtime_state.inner()) {
+    // Guard: pruning while an OCR container runs could remove the volume or image it depends on.
+    return Err("Stop running jobs before cleaning up Docker artifacts.".to_string());
+  }
+  // NOTE: `docker builder prune` alone can run for minutes on a large cache.
+  tauri::async_runtime::spawn_blocking(move || {
+    validate_docker_available()?;
+    let repo_root = repo_root_path()?;
+    prune_project_docker_artifacts(&derive_compose_project_name(&repo_root), &options.unwrap_or_default())
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+#[tauri::command]
+fn get_docker_image_build_status(
+  image_build_state: State<'_, SharedImageBuildRuntimeState>,
+) -> Result<ImageBuildStatus, String> {
+  Ok(get_image_build_status(image_build_state.inner()))
+}
+
+fn probe_gpu_passthrough_blocking(job_runtime_state: &SharedJobRuntimeState) -> Result<String, String> {
+  let engine_settings = read_execution_engine_settings_best_effort(job_runtime_state);
+  if is_apple_silicon_host() {
+    // Guard: there is no nvidia-smi on macOS; report the accelerator the pipeline will use instead of failing.
+    if engine_settings.kind == ExecutionEngineKind::Host {
+      let inference_device =
+        probe_host_python(&repo_root_path()?, engine_settings.resolved_python_interpreter_path()?)?;
+      return Ok(format!("Apple Silicon: host Python will run OCR on `{inference_device}`."));
+    }
+    let compose_settings = read_docker_compose_settings_best_effort(job_runtime_state);
+    return Ok(format!(
+      "Apple Silicon: Docker cannot pass a GPU through; `{}` runs OCR on the CPU.",
+      compose_settings.resolved_service_name()
+    ));
+  }
+  if engine_settings.kind == ExecutionEngineKind::Host {
+    let output = output_with_timeout(&mut Command::new("nvidia-smi"), PROBE_COMMAND_TIMEOUT)
+      .map_err(|error| format!("Failed to run nvidia-smi on the host. Is the NVIDIA driver installed? {error}"))?;
+    if output.status.success() {
+      return Ok(String::from_utf8_lossy(&output.stdout).to_string());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    return Err(format!("nvidia-smi failed on the host.\n{stderr}"));
+  }
+  validate_docker_available()?;
+  let repo_root = repo_root_path()?;
+  let compose_settings = read_docker_compose_settings_best_effort(job_runtime_state);
+
+  let output = output_with_timeout(
+    build_docker_compose_base_command(&repo_root, &compose_settings)
+      .arg("run")
+      .arg("--rm")
+      .arg(compose_settings.resolved_service_name())
+      .arg("nvidia-smi"),
+    SLOW_PROBE_COMMAND_TIMEOUT,
+  )
+  .map_err(|error| format!("Failed to run GPU probe (nvidia-smi). {error}"))?;
+
+  if output.status.success() {
+    return Ok(String::from_utf8_lossy(&output.stdout).to_string());
+  }
+
+  let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+  // NOTE: On Windows, narrow the failure down to the broken layer instead of a generic hint.
+  let remediation = describe_failed_wsl_checks(&run_wsl_diagnostic_checks())
+    .map(|failed_checks| format!("\n\nDiagnostics:\n{failed_checks}"))
+    .unwrap_or_default();
+  Err(format!(
+    "GPU probe failed. Verify Docker Desktop GPU support and WSL2 GPU drivers.\n{stderr}{remediation}"
+  ))
+}
+
+// NOTE: May start a container (`SLOW_PROBE_COMMAND_TIMEOUT`); kept off the IPC thread.
+#[tauri::command]
+async fn probe_gpu_passthrough(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<String, String> {
+  let job_runtime_state = job_runtime_state.inner().clone();
+  tauri::async_runtime::spawn_blocking(move || probe_gpu_passthrough_blocking(&job_runtime_state))
+    .await
+    .map_err(|error| error.to_string())?
+}
+
+#[tauri::command]
+async fn run_wsl_diagnostics() -> Result<Vec<WslDiagnosticCheck>, String> {
+  tauri::async_runtime::spawn_blocking(run_wsl_diagnostic_checks)
+    .await
+    .map_err(|error| error.to_string())
+}
+
+// NOTE: Every registered inbox, highest priority first; the primary one (`default`) is listed even when stopped.
+#[tauri::command]
+fn get_watch_folder_status(
+  watch_folder_registry: State<'_, SharedWatchFolderRegistry>,
+) -> Result<Vec<WatchFolderStatus>, String> {
+  Ok(list_watch_folder_statuses(watch_folder_registry.inner()))
+}
+
+// NOTE: Without `watch_id`, the primary watcher.
+fn resolve_watch_folder_state(
+  watch_folder_registry: &SharedWatchFolderRegistry,
+  watch_id: Option<&str>,
+) -> Result<SharedWatchFolderRuntimeState, String> {
+  find_watch_folder_state(watch_folder_registry, watch_id.unwrap_or(PRIMARY_WATCH_ID))
+}
+
+#[tauri::command]
+fn stop_watch_folder(
+  watch_id: Option<String>,
+  watch_folder_registry: State<'_, SharedWatchFolderRegistry>,
+) -> Result<(), String> {
+  stop_watch_folder_internal(&resolve_watch_folder_state(watch_folder_registry.inner(), watch_id.as_deref())?);
+  Ok(())
+}
+
+// NOTE: Stops an additional inbox and drops it from the list; bundles and jobs on disk are left alone.
+#[tauri::command]
+fn remove_watch_folder(
+  watch_id: String,
+  watch_folder_registry: State<'_, SharedWatchFolderRegistry>,
+) -> Result<(), String> {
+  stop_watch_folder_internal(&remove_watch_folder_state(watch_folder_registry.inner(), &watch_id)?);
+  Ok(())
+}
+
+// NOTE: Returns once no job is running or after the wait timeout, whichever is first; `is_drained` tells which.
+#[tauri::command]
+async fn enter_maintenance_mode(
+  reason: Option<String>,
+  job_policy: Option<MaintenanceJobPolicy>,
+  wait_timeout_seconds: Option<u64>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<MaintenanceStatus, String> {
+  let job_runtime_state = job_runtime_state.inner().clone();
+  let job_policy = job_policy.unwrap_or(MaintenanceJobPolicy::WaitForJobs);
+  let reason = reason
+    .map(|reason| reason.trim().to_string())
+    .filter(|reason| !reason.is_empty())
+    .unwrap_or_else(|| "maintenance".to_string());
+  let wait_timeout = wait_timeout_seconds
+    .map(Duration::from_secs)
+    .unwrap_or(DEFAULT_MAINTENANCE_WAIT_TIMEOUT)
+    .min(MAX_MAINTENANCE_WAIT_TIMEOUT);
+  tauri::async_runtime::spawn_blocking(move || {
+    let running_job_roots: Vec<PathBuf> = {
+      let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+      locked_state.maintenance_mode = Some(MaintenanceMode {
+        reason,
+        job_policy,
+        entered_unix_timestamp_millis: now_unix_timestamp_millis(),
+      });
+      locked_state.running_job_by_root.keys().cloned().collect()
+    };
+    for job_root_directory_path in &running_job_roots {
+      append_log_line(
+        &job_runtime_state,
+        job_root_directory_path,
+        format!("[backend] maintenance mode entered (policy: {})", job_policy.label()),
+      );
+      if job_policy == MaintenanceJobPolicy::CancelJobs {
+        if let Err(error) = request_job_cancellation(&job_runtime_state, job_root_directory_path, JobCancelMode::Graceful) {
+          append_log_line(
+            &job_runtime_state,
+            job_root_directory_path,
+            format!("[backend] maintenance cancellation failed: {error}"),
+          );
+        }
+      }
+    }
+    let deadline = Instant::now() + wait_timeout;
+    while is_any_job_running(&job_runtime_state) && Instant::now() < deadline {
+      thread::sleep(MAINTENANCE_DRAIN_POLL_INTERVAL);
+    }
+    build_maintenance_status(&job_runtime_state)
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+// NOTE: Starts the dependents whose prerequisites finished during maintenance; watcher intake resumes on its next poll.
+#[tauri::command]
+fn exit_maintenance_mode(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<MaintenanceStatus, String> {
+  leave_maintenance_mode(job_runtime_state.inner())
+}
+
+fn leave_maintenance_mode(job_runtime_state: &SharedJobRuntimeState) -> Result<MaintenanceStatus, String> {
+  {
+    let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    locked_state.maintenance_mode = None;
+  }
+  let graph_file_path = job_dependency_graph_file_path(job_runtime_state)?;
+  let ready_job_roots = update_job_dependency_graph(&graph_file_path, |graph| Ok(graph.take_ready_jobs()))?;
+  start_ready_dependent_jobs(job_runtime_state, &graph_file_path, ready_job_roots)?;
+  dispatch_queued_job_starts_best_effort(job_runtime_state);
+  build_maintenance_status(job_runtime_state)
+}
+
+#[tauri::command]
+fn get_maintenance_status(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<MaintenanceStatus, String> {
+  build_maintenance_status(job_runtime_state.inner())
+}
+
+// NOTE: Keeps the watcher running but leaves new bundles in the inbox, e.g. during maintenance.
+#[tauri::command]
+fn pause_watch_folder_intake(
+  reason: Option<String>,
+  watch_id: Option<String>,
+  watch_folder_registry: State<'_, SharedWatchFolderRegistry>,
+) -> Result<(), String> {
+  let reason = reason
+    .map(|reason| reason.trim().to_string())
+    .filter(|reason| !reason.is_empty())
+    .unwrap_or_else(|| "Paused from the GUI".to_string());
+  set_watch_folder_operator_pause_reason(
+    &resolve_watch_folder_state(watch_folder_registry.inner(), watch_id.as_deref())?,
+    Some(reason),
+  );
+  Ok(())
+}
+
+#[tauri::command]
+fn resume_watch_folder_intake(
+  watch_id: Option<String>,
+  watch_folder_registry: State<'_, SharedWatchFolderRegistry>,
+) -> Result<(), String> {
+  set_watch_folder_operator_pause_reason(
+    &resolve_watch_folder_state(watch_folder_registry.inner(), watch_id.as_deref())?,
+    None,
+  );
+  Ok(())
+}
+
+#[tauri::command]
+fn start_watch_folder(
+  inbox_directory_path: String,
+  jobs_root_directory_path: Option<String>,
+  auto_run: Option<bool>,
+  auto_package: Option<bool>,
+  accept_single_files: Option<bool>,
+  watch_id: Option<String>,
+  priority: Option<i32>,
+  preset_name: Option<String>,
+  bundle_quiescence_seconds: Option<u64>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+  watch_folder_registry: State<'_, SharedWatchFolderRegistry>,
+) -> Result<(), String> {
+  let watch_id = watch_id.as_deref().unwrap_or(PRIMARY_WATCH_ID);
+  if let Some(seconds) = bundle_quiescence_seconds.filter(|seconds| *seconds < MIN_BUNDLE_QUIESCENCE_SECONDS) {
+    return Err(format!(
+      "Quiescence period must be at least {MIN_BUNDLE_QUIESCENCE_SECONDS} seconds (got {seconds})."
+    ));
+  }
+  let inbox_directory_path = PathBuf::from(inbox_directory_path);
+  let jobs_root_directory_path = jobs_root_directory_path
+    .and_then(|raw| {
+      let trimmed = raw.trim().to_string();
+      if trimmed.is_empty() {
+        return None;
+      }
+      Some(trimmed)
+    })
+    .map(PathBuf::from)
+    .unwrap_or_else(|| inbox_directory_path.join(DEFAULT_WATCH_JOBS_DIRECTORY_NAME));
+
+  let preset_name = preset_name.map(|name| name.trim().to_string()).filter(|name| !name.is_empty());
+  if let Some(preset_name) = preset_name.as_deref() {
+    // Guard: fail now rather than marking every bundle of this inbox `.failed` later.
+    read_job_settings_preset(job_runtime_state.inner(), preset_name)?;
+  }
+  let conflicting_watch_id = find_running_watch_folder_using_directory(
+    watch_folder_registry.inner(),
+    &watch_id,
+    &[inbox_directory_path.as_path(), jobs_root_directory_path.as_path()],
+  );
+  if let Some(conflicting_watch_id) = conflicting_watch_id {
+    // Guard: two watchers on one folder would race for the same bundles and job ids.
+    return Err(format!("Watch folder {conflicting_watch_id} already uses this inbox or jobs root."));
+  }
+
+  let config = WatchFolderConfig {
+    is_single_file_intake_enabled: accept_single_files.unwrap_or(false),
+    priority: priority.unwrap_or(0),
+    preset_name,
+    bundle_quiescence_period: bundle_quiescence_seconds.map(Duration::from_secs),
+    ..WatchFolderConfig::new(inbox_directory_path, jobs_root_directory_path, default_watch_poll_interval())
+  };
+
+  let watch_folder_state = find_or_add_watch_folder_state(watch_folder_registry.inner(), watch_id)?;
+  let poll_callback = make_watch_folder_poll_callback(
+    job_runtime_state.inner().clone(),
+    watch_folder_state.clone(),
+    watch_folder_registry.inner().clone(),
+    auto_run.unwrap_or(false),
+    auto_package.unwrap_or(false),
+  );
+
+  start_watch_folder_with_callback(&watch_folder_state, config, poll_callback)?;
+  Ok(())
+}
+
+#[tauri::command]
+async fn pick_output_directory(app_handle: tauri::AppHandle<Wry>) -> Result<Option<String>, String> {
+  let (sender, receiver) = oneshot::channel::<Option<tauri_plugin_dialog::FilePath>>();
+  app_handle.dialog().file().pick_folder(move |path| {
+    // Guard: receiver side may be dropped if the request is cancelled.
+    let _ = sender.send(path);
+  });
+
+  let selected_directory_path = receiver
+    .await
+    .map_err(|_| "Failed to receive folder picker result".to_string())?;
+
+  let Some(directory_path) = selected_directory_path else {
+    return Ok(None);
+  };
+
+  Ok(Some(file_path_to_string(directory_path)))
+}
+
+#[tauri::command]
+async fn pick_directory(app_handle: tauri::AppHandle<Wry>) -> Result<Option<String>, String> {
   let (sender, receiver) = oneshot::channel::<Option<tauri_plugin_dialog::FilePath>>();
   app_handle.dialog().file().pick_folder(move |path| {
     // Guard: receiver side may be dropped if the request is cancelled.
@@ -502,16 +2036,22 @@ async fn pick_input_folder(app_handle: tauri::AppHandle<Wry>) -> Result<Option<S
   Ok(Some(file_path_to_string(selected_path)))
 }
 
-fn sanitize_filename_for_copy(candidate_filename: &OsStr) -> String {
-  let filename_string = candidate_filename.to_string_lossy().to_string();
+// NOTE: A name that is not valid UTF-8 is kept byte for byte; decoding it lossily would rename the copy (and could
+// make two different names collide). Such a name cannot hold a path separator, being a single path component.
+fn sanitize_filename_for_copy(candidate_filename: &OsStr) -> OsString {
+  let Some(filename_string) = candidate_filename.to_str() else {
+    return candidate_filename.to_os_string();
+  };
   if filename_string.trim().is_empty() {
     // Guard: use a stable fallback name when filename is empty.
-    return "input".to_string();
+    return OsString::from("input");
   }
-  filename_string
-    .replace('\\', "_")
-    .replace('/', "_")
-    .replace(':', "_")
+  OsString::from(
+    filename_string
+      .replace('\\', "_")
+      .replace('/', "_")
+      .replace(':', "_"),
+  )
 }
 
 fn split_filename_and_extension(filename: &str) -> (String, String) {
@@ -564,18 +2104,26 @@ fn derive_default_unique_markdown_filename() -> String {
   format!("{DEFAULT_OUTPUT_MARKDOWN_FILENAME_PREFIX}{}.md", now_unix_timestamp_millis())
 }
 
+// NOTE: Works on `OsStr` so copied names that are not valid UTF-8 get their `_2` suffix without being re-encoded.
 fn derive_non_conflicting_destination_path(
   destination_directory_path: &Path,
-  desired_filename: &str,
+  desired_filename: impl AsRef<OsStr>,
 ) -> Result<PathBuf, String> {
+  let desired_filename = Path::new(desired_filename.as_ref());
   let desired_path = destination_directory_path.join(desired_filename);
   if !desired_path.exists() {
     return Ok(desired_path);
   }
 
-  let (stem, extension_with_dot) = split_filename_and_extension(desired_filename);
-  for suffix_number in 2..=MAX_COPY_COLLISION_ATTEMPTS {
-    let candidate_filename = format!("{stem}_{suffix_number}{extension_with_dot}");
+  // NOTE: Same split as `split_filename_and_extension`: the last dot, with dotfiles like ".env" having no extension.
+  let stem = desired_filename.file_stem().unwrap_or(desired_filename.as_os_str());
+  for suffix_number in 2..=current_runtime_limits().max_copy_collision_attempts {
+    let mut candidate_filename = stem.to_os_string();
+    candidate_filename.push(format!("_{suffix_number}"));
+    if let Some(extension) = desired_filename.extension() {
+      candidate_filename.push(".");
+      candidate_filename.push(extension);
+    }
     let candidate_path = destination_directory_path.join(candidate_filename);
     if !candidate_path.exists() {
       return Ok(candidate_path);
@@ -583,12 +2131,18 @@ fn derive_non_conflicting_destination_path(
   }
 
   Err(format!(
-    "Too many name collisions while copying into: {} (base name: {desired_filename})",
-    destination_directory_path.display()
+    "Too many name collisions while copying into: {} (base name: {})",
+    destination_directory_path.display(),
+    desired_filename.display()
   ))
 }
 
-fn copy_directory_recursively(source_directory_path: &Path, destination_directory_path: &Path) -> Result<u64, String> {
+fn copy_directory_recursively(
+  source_directory_path: &Path,
+  destination_directory_path: &Path,
+  is_cancel_requested: &(dyn Fn() -> bool + Sync),
+  on_copied_bytes: &(dyn Fn(&Path, u64) + Sync),
+) -> Result<u64, String> {
   if !source_directory_path.exists() {
     // Guard: do not silently ignore missing paths.
     return Err(format!("Input directory does not exist: {}", source_directory_path.display()));
@@ -600,7 +2154,7 @@ fn copy_directory_recursively(source_directory_path: &Path, destination_director
 
   fs::create_dir_all(destination_directory_path).map_err(|error| error.to_string())?;
 
-  let mut total_copied_files: u64 = 0;
+  let mut file_copies: Vec<(PathBuf, PathBuf)> = vec![];
   for entry in walkdir::WalkDir::new(source_directory_path) {
     let entry = entry.map_err(|error| error.to_string())?;
     let entry_path = entry.path();
@@ -616,20 +2170,184 @@ fn copy_directory_recursively(source_directory_path: &Path, destination_director
     if let Some(parent_directory_path) = destination_path.parent() {
       fs::create_dir_all(parent_directory_path).map_err(|error| error.to_string())?;
     }
-
-    fs::copy(entry_path, &destination_path).map_err(|error| error.to_string())?;
-    total_copied_files += 1;
+    file_copies.push((entry_path.to_path_buf(), destination_path));
   }
 
-  Ok(total_copied_files)
+  copy_files_in_parallel(&file_copies, is_cancel_requested, on_copied_bytes)
+}
+
+// NOTE: Every file is attempted even after one fails, so a single unreadable scan does not hide the rest.
+fn copy_files_in_parallel(
+  file_copies: &[(PathBuf, PathBuf)],
+  is_cancel_requested: &(dyn Fn() -> bool + Sync),
+  on_copied_bytes: &(dyn Fn(&Path, u64) + Sync),
+) -> Result<u64, String> {
+  let thread_count = thread::available_parallelism()
+    .map(|parallelism| parallelism.get())
+    .unwrap_or(1)
+    .min(MAX_PARALLEL_COPY_THREADS)
+    .min(file_copies.len())
+    .max(1);
+  let next_copy_index = AtomicUsize::new(0);
+  let copy_errors: Mutex<Vec<String>> = Mutex::new(vec![]);
+  thread::scope(|scope| {
+    for _ in 0..thread_count {
+      scope.spawn(|| {
+        while !is_cancel_requested() {
+          let Some((source_file_path, destination_file_path)) =
+            file_copies.get(next_copy_index.fetch_add(1, Ordering::Relaxed))
+          else {
+            break;
+          };
+          if let Err(error) =
+            copy_file_with_progress(source_file_path, destination_file_path, is_cancel_requested, on_copied_bytes)
+          {
+            if let Ok(mut locked_errors) = copy_errors.lock() {
+              locked_errors.push(error);
+            }
+          }
+        }
+      });
+    }
+  });
+
+  if is_cancel_requested() {
+    return Err("Copy cancelled.".to_string());
+  }
+  let copy_errors = copy_errors.into_inner().unwrap_or_default();
+  if !copy_errors.is_empty() {
+    let mut message = format!(
+      "Failed to copy {} of {} file(s): {}",
+      copy_errors.len(),
+      file_copies.len(),
+      copy_errors[..copy_errors.len().min(MAX_REPORTED_COPY_ERRORS)].join("; ")
+    );
+    if copy_errors.len() > MAX_REPORTED_COPY_ERRORS {
+      message.push_str("; …");
+    }
+    return Err(message);
+  }
+  Ok(file_copies.len() as u64)
 }
 
 #[tauri::command]
-fn job_add_inputs(job_root_directory_path: String, input_paths: Vec<String>) -> Result<(), String> {
+async fn job_add_inputs(
+  job_root_directory_path: String,
+  input_paths: Vec<String>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
   let job_root_directory_path = PathBuf::from(job_root_directory_path);
-  ensure_job_directory_layout(&job_root_directory_path)?;
+  let job_runtime_state = job_runtime_state.inner().clone();
+  // NOTE: Runs off the main thread so the UI can poll `get_input_copy_progress` while a multi-GB file copies.
+  tauri::async_runtime::spawn_blocking(move || {
+    prepare_job_directory(&job_runtime_state, &job_root_directory_path)?;
+    copy_input_paths_into_job(&job_runtime_state, &job_root_directory_path, input_paths)
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+#[tauri::command]
+fn get_input_copy_progress(
+  job_root_directory_path: String,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<Option<InputCopyProgress>, String> {
+  let locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+  Ok(
+    locked_state
+      .input_copy_progress_by_root
+      .get(&PathBuf::from(job_root_directory_path))
+      .cloned(),
+  )
+}
+
+#[tauri::command]
+fn cancel_input_copy(
+  job_root_directory_path: String,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+  let Some(progress) = locked_state
+    .input_copy_progress_by_root
+    .get_mut(&PathBuf::from(job_root_directory_path))
+  else {
+    // Guard: nothing to cancel.
+    return Err("No input copy is in progress for this output directory.".to_string());
+  };
+  progress.is_cancel_requested = true;
+  Ok(())
+}
+
+fn measure_input_paths_bytes(input_paths: &[String]) -> u64 {
+  input_paths
+    .iter()
+    .flat_map(|input_path| walkdir::WalkDir::new(input_path).into_iter().filter_map(|entry| entry.ok()))
+    .filter(|entry| entry.file_type().is_file())
+    .filter_map(|entry| entry.metadata().ok())
+    .map(|metadata| metadata.len())
+    .sum()
+}
+
+fn copy_input_paths_into_job(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  input_paths: Vec<String>,
+) -> Result<(), String> {
+  {
+    let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    if locked_state.input_copy_progress_by_root.contains_key(job_root_directory_path) {
+      // Guard: two copies into the same folder would race on collision-free names.
+      return Err("Inputs are already being copied into this output directory.".to_string());
+    }
+    locked_state.input_copy_progress_by_root.insert(
+      job_root_directory_path.to_path_buf(),
+      InputCopyProgress {
+        total_bytes: measure_input_paths_bytes(&input_paths),
+        ..InputCopyProgress::default()
+      },
+    );
+  }
+  let is_cancel_requested = || {
+    job_runtime_state
+      .lock()
+      .map(|locked_state| {
+        locked_state
+          .input_copy_progress_by_root
+          .get(job_root_directory_path)
+          .map(|progress| progress.is_cancel_requested)
+          .unwrap_or(false)
+      })
+      .unwrap_or(true)
+  };
+  let on_copied_bytes = |source_file_path: &Path, copied_bytes: u64| {
+    if let Ok(mut locked_state) = job_runtime_state.lock() {
+      if let Some(progress) = locked_state.input_copy_progress_by_root.get_mut(job_root_directory_path) {
+        progress.copied_bytes += copied_bytes;
+        progress.current_file_name = source_file_path
+          .file_name()
+          .map(|file_name| file_name.to_string_lossy().to_string());
+      }
+    }
+  };
+  let result = copy_input_paths_into_job_directory(
+    job_root_directory_path,
+    input_paths,
+    &is_cancel_requested,
+    &on_copied_bytes,
+  );
+  if let Ok(mut locked_state) = job_runtime_state.lock() {
+    locked_state.input_copy_progress_by_root.remove(job_root_directory_path);
+  }
+  result
+}
 
-  let input_directory_path = job_root_directory_path.join(DEFAULT_INPUT_DIRECTORY_NAME);
+fn copy_input_paths_into_job_directory(
+  job_root_directory_path: &Path,
+  input_paths: Vec<String>,
+  is_cancel_requested: &(dyn Fn() -> bool + Sync),
+  on_copied_bytes: &(dyn Fn(&Path, u64) + Sync),
+) -> Result<(), String> {
+  let input_directory_path = job_input_directory_path(job_root_directory_path);
   fs::create_dir_all(&input_directory_path).map_err(|error| error.to_string())?;
 
   for input_path_string in input_paths {
@@ -643,10 +2361,10 @@ fn job_add_inputs(job_root_directory_path: String, input_paths: Vec<String>) ->
       let file_name = input_path
         .file_name()
         .map(sanitize_filename_for_copy)
-        .unwrap_or_else(|| "input_file".to_string());
+        .unwrap_or_else(|| OsString::from("input_file"));
 
       let destination_path = derive_non_conflicting_destination_path(&input_directory_path, &file_name)?;
-      fs::copy(&input_path, &destination_path).map_err(|error| error.to_string())?;
+      copy_file_with_progress(&input_path, &destination_path, is_cancel_requested, on_copied_bytes)?;
       continue;
     }
 
@@ -654,11 +2372,16 @@ fn job_add_inputs(job_root_directory_path: String, input_paths: Vec<String>) ->
       let directory_name = input_path
         .file_name()
         .map(sanitize_filename_for_copy)
-        .unwrap_or_else(|| "input_directory".to_string());
+        .unwrap_or_else(|| OsString::from("input_directory"));
 
       let destination_directory_path =
         derive_non_conflicting_destination_path(&input_directory_path, &directory_name)?;
-      let _ = copy_directory_recursively(&input_path, &destination_directory_path)?;
+      let _ = copy_directory_recursively(
+        &input_path,
+        &destination_directory_path,
+        is_cancel_requested,
+        on_copied_bytes,
+      )?;
       continue;
     }
 
@@ -670,10 +2393,11 @@ fn job_add_inputs(job_root_directory_path: String, input_paths: Vec<String>) ->
 }
 
 fn get_queue_database_path(job_root_directory_path: &Path) -> PathBuf {
-  job_root_directory_path.join(DEFAULT_QUEUE_DATABASE_FILENAME)
+  job_directory_layout(job_root_directory_path).queue_database_path(job_root_directory_path)
 }
 
-fn query_current_running_task(queue_database_path: &Path) -> Result<Option<CurrentTaskPreview>, String> {
+// NOTE: The runner claims pending tasks in task_id order, so the first pending row is the next task it will run.
+fn query_first_task_with_status(queue_database_path: &Path, status: &str) -> Result<Option<CurrentTaskPreview>, String> {
   if !queue_database_path.exists() {
     // Guard: queue might not exist until enqueue has run.
     return Ok(None);
@@ -683,10 +2407,10 @@ fn query_current_running_task(queue_database_path: &Path) -> Result<Option<Curre
   let mut statement = connection
     .prepare(
       "SELECT task_id, task_kind, source_path, pdf_page_index, pdf_total_pages \
-       FROM tasks WHERE status = 'running' ORDER BY task_id ASC LIMIT 1",
+       FROM tasks WHERE status = ?1 ORDER BY task_id ASC LIMIT 1",
     )
     .map_err(|error| error.to_string())?;
-  let mut rows = statement.query([]).map_err(|error| error.to_string())?;
+  let mut rows = statement.query([status]).map_err(|error| error.to_string())?;
   let Some(row) = rows.next().map_err(|error| error.to_string())? else {
     return Ok(None);
   };
@@ -705,14 +2429,18 @@ fn query_current_running_task(queue_database_path: &Path) -> Result<Option<Curre
     pdf_total_pages,
     preview_image_file_path: None,
     deepseek_inference_image_size_pixels: None,
+    is_pending: status == "pending",
+    next_task: None,
   }))
 }
 
 fn resolve_preview_image_path_for_task(job_root_directory_path: &Path, task: &CurrentTaskPreview) -> Option<PathBuf> {
+  let directory_layout = job_directory_layout(job_root_directory_path);
   let task_kind_lower = task.task_kind.to_lowercase();
   if task_kind_lower == "image" {
-    if let Some(relative) = task.source_path.strip_prefix("/data/input/") {
-      return Some(job_root_directory_path.join(DEFAULT_INPUT_DIRECTORY_NAME).join(relative));
+    let container_input_prefix = format!("/data/{}/", directory_layout.input_directory_name);
+    if let Some(relative) = task.source_path.strip_prefix(container_input_prefix.as_str()) {
+      return Some(directory_layout.input_directory_path(job_root_directory_path).join(relative));
     }
     return Some(PathBuf::from(&task.source_path));
   }
@@ -722,17 +2450,84 @@ fn resolve_preview_image_path_for_task(job_root_directory_path: &Path, task: &Cu
   }
   let pdf_page_index = task.pdf_page_index?;
   let page_number_human = pdf_page_index + 1;
-  let work_directory_path = job_root_directory_path
-    .join(DEFAULT_OUTPUT_DIRECTORY_NAME)
-    .join("work");
+  let work_directory_path = directory_layout.output_directory_path(job_root_directory_path).join("work");
   Some(work_directory_path.join(format!(
     "pdf_{}_page_{}.png",
     task.task_id, page_number_human
   )))
 }
 
-fn infer_image_mime_type(image_file_path: &Path) -> String {
-  let extension = image_file_path
+fn lookahead_preview_image_path_for_task(job_root_directory_path: &Path, task: &CurrentTaskPreview) -> Option<PathBuf> {
+  let pdf_page_index = task.pdf_page_index?;
+  Some(
+    job_output_directory_path(job_root_directory_path)
+      .join("work")
+      .join("lookahead")
+      .join(format!("pdf_{}_page_{}.png", task.task_id, pdf_page_index + 1)),
+  )
+}
+
+// NOTE: Prefers the runner's own render; until it exists, a lookahead render keeps the pane from going blank.
+fn resolve_lookahead_preview_image_path(job_root_directory_path: &Path, task: &CurrentTaskPreview) -> Option<PathBuf> {
+  let preview_path = resolve_preview_image_path_for_task(job_root_directory_path, task)?;
+  if preview_path.exists() || task.task_kind.to_lowercase() != "pdf_page" {
+    return Some(preview_path);
+  }
+  let lookahead_path = lookahead_preview_image_path_for_task(job_root_directory_path, task)?;
+  if lookahead_path.exists() {
+    return Some(lookahead_path);
+  }
+  if task.is_pending {
+    // Guard: report no path until an image exists, so the GUI reloads once the render lands.
+    return None;
+  }
+  Some(preview_path)
+}
+
+fn prerender_lookahead_task_best_effort(job_root_directory_path: &Path, task: &CurrentTaskPreview) {
+  if task.task_kind.to_lowercase() != "pdf_page" {
+    return;
+  }
+  let Some(pdf_page_index) = task.pdf_page_index else {
+    return;
+  };
+  let Some(runner_preview_path) = resolve_preview_image_path_for_task(job_root_directory_path, task) else {
+    return;
+  };
+  if runner_preview_path.exists() {
+    return;
+  }
+  let Some(lookahead_path) = lookahead_preview_image_path_for_task(job_root_directory_path, task) else {
+    return;
+  };
+  let pdf_file_path = map_container_data_path_to_host(job_root_directory_path, &task.source_path);
+  spawn_pdf_page_prerender(pdf_file_path, pdf_page_index, lookahead_path);
+}
+
+fn query_task_preview(
+  job_root_directory_path: &Path,
+  is_lookahead_enabled: bool,
+) -> Result<Option<CurrentTaskPreview>, String> {
+  let queue_database_path = get_queue_database_path(job_root_directory_path);
+  let running_task = query_first_task_with_status(&queue_database_path, "running")?;
+  if !is_lookahead_enabled {
+    return Ok(running_task);
+  }
+  let next_task = query_first_task_with_status(&queue_database_path, "pending")?;
+  if let Some(next_task) = next_task.as_ref() {
+    prerender_lookahead_task_best_effort(job_root_directory_path, next_task);
+  }
+  match running_task {
+    Some(mut running_task) => {
+      running_task.next_task = next_task.map(Box::new);
+      Ok(Some(running_task))
+    }
+    None => Ok(next_task),
+  }
+}
+
+fn infer_image_mime_type(image_file_path: &Path) -> String {
+  let extension = image_file_path
     .extension()
     .and_then(|ext| ext.to_str())
     .unwrap_or("")
@@ -797,6 +2592,41 @@ fn query_last_error_message(queue_database_path: &Path) -> Result<Option<String>
   Ok(Some(error_message))
 }
 
+fn query_queue_snapshot(queue_database_path: &Path) -> Result<QueueSnapshot, String> {
+  if !queue_database_path.exists() {
+    return Ok(QueueSnapshot::default());
+  }
+  Ok(QueueSnapshot {
+    has_queue: true,
+    counts_by_status: query_status_counts(queue_database_path)?,
+    last_error_message: query_last_error_message(queue_database_path)?,
+  })
+}
+
+// NOTE: An idle job's queue only changes through job events, so its last snapshot is reused until one happens.
+fn load_queue_snapshot_indexed(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  is_running: bool,
+) -> Result<QueueSnapshot, String> {
+  if !is_running {
+    let cached_snapshot = job_runtime_state
+      .lock()
+      .ok()
+      .and_then(|locked_state| locked_state.job_root_index.queue_snapshot(job_root_directory_path));
+    if let Some(cached_snapshot) = cached_snapshot {
+      return Ok(cached_snapshot);
+    }
+  }
+  let queue_snapshot = query_queue_snapshot(&get_queue_database_path(job_root_directory_path))?;
+  if let Ok(mut locked_state) = job_runtime_state.lock() {
+    locked_state
+      .job_root_index
+      .record_queue_snapshot(job_root_directory_path, queue_snapshot.clone());
+  }
+  Ok(queue_snapshot)
+}
+
 fn compute_estimated_time_remaining_seconds(
   start_unix_timestamp_millis: Option<i64>,
   total_tasks: i64,
@@ -833,34 +2663,64 @@ fn get_job_status(
   job_runtime_state: State<'_, SharedJobRuntimeState>,
 ) -> Result<JobStatus, String> {
   let job_root_directory_path = PathBuf::from(job_root_directory_path);
-  ensure_job_directory_layout(&job_root_directory_path)?;
-
-  let queue_database_path = get_queue_database_path(&job_root_directory_path);
-  let counts_by_status = query_status_counts(&queue_database_path)?;
-  let pending_tasks = *counts_by_status.get("pending").unwrap_or(&0);
-  let running_tasks = *counts_by_status.get("running").unwrap_or(&0);
-  let completed_tasks = *counts_by_status.get("completed").unwrap_or(&0);
-  let failed_tasks = *counts_by_status.get("failed").unwrap_or(&0);
-  let total_tasks = pending_tasks + running_tasks + completed_tasks + failed_tasks;
-
-  let (is_running, start_unix_timestamp_millis) = {
+  {
     let locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
     let running_handle = locked_state.running_job_by_root.get(&job_root_directory_path);
+    let is_running = running_handle.is_some();
+    let is_paused = running_handle.is_some_and(|running| running.paused_unix_timestamp_millis.is_some());
+    let is_cancel_requested = running_handle.is_some_and(|running| running.is_cancel_requested);
+    // Guard: a job that started, finished, paused, resumed or was cancelled since the cached query must be reported
+    // right away.
+    if let Some(cached_status) = locked_state.job_status_cache.get_fresh(&job_root_directory_path).filter(|status| {
+      status.is_running == is_running && status.is_paused == is_paused && status.is_cancel_requested == is_cancel_requested
+    }) {
+      return Ok(cached_status);
+    }
+  }
+  prepare_job_directory(job_runtime_state.inner(), &job_root_directory_path)?;
+  let status = build_job_status(job_runtime_state.inner(), &job_root_directory_path)?;
+  if let Ok(mut locked_state) = job_runtime_state.lock() {
+    locked_state
+      .job_status_cache
+      .insert(job_root_directory_path, status.clone());
+  }
+  Ok(status)
+}
+
+fn build_job_status(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path) -> Result<JobStatus, String> {
+  let job_id = lookup_job_id(job_runtime_state, job_root_directory_path);
+  let (is_running, start_unix_timestamp_millis, is_paused, is_cancel_requested) = {
+    let locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    let running_handle = locked_state.running_job_by_root.get(job_root_directory_path);
     match running_handle {
-      None => (false, None),
-      Some(handle) => (true, Some(handle.start_unix_timestamp_millis)),
+      None => (false, None, false, false),
+      Some(handle) => (
+        true,
+        Some(handle.start_unix_timestamp_millis),
+        handle.paused_unix_timestamp_millis.is_some(),
+        handle.is_cancel_requested,
+      ),
     }
   };
 
+  let queue_snapshot = load_queue_snapshot_indexed(job_runtime_state, job_root_directory_path, is_running)?;
+  let pending_tasks = queue_snapshot.count("pending");
+  let running_tasks = queue_snapshot.count("running");
+  let completed_tasks = queue_snapshot.count("completed");
+  let failed_tasks = queue_snapshot.count("failed");
+  let total_tasks = pending_tasks + running_tasks + completed_tasks + failed_tasks;
+
   let estimated_time_remaining_seconds = compute_estimated_time_remaining_seconds(
     start_unix_timestamp_millis,
     total_tasks,
     completed_tasks,
   );
-  let last_error_message = query_last_error_message(&queue_database_path)?;
+  let last_error_message = queue_snapshot.last_error_message;
+  let heartbeat_status = describe_job_heartbeat(job_root_directory_path, now_unix_timestamp_millis());
 
   Ok(JobStatus {
     job_root_directory_path: job_root_directory_path.to_string_lossy().to_string(),
+    job_id,
     is_running,
     start_unix_timestamp_millis,
     total_tasks,
@@ -870,54 +2730,341 @@ fn get_job_status(
     failed_tasks,
     last_error_message,
     estimated_time_remaining_seconds,
+    heartbeat_age_seconds: heartbeat_status.age_seconds,
+    is_heartbeat_stale: heartbeat_status.is_stale,
+    is_paused,
+    is_cancel_requested,
+  })
+}
+
+fn resolve_status_file_paths(job_root_directory_path: &Path, settings: &JobSettings) -> Vec<PathBuf> {
+  let mut status_file_paths: Vec<PathBuf> = vec![];
+  if settings.is_status_file_enabled.unwrap_or(false) {
+    status_file_paths.push(job_root_directory_path.join(STATUS_FILENAME));
+  }
+  if let Ok(status_directory_path) = std::env::var(OCR_AGENT_STATUS_DIRECTORY_ENVIRONMENT_VARIABLE_NAME) {
+    let trimmed = status_directory_path.trim();
+    if !trimmed.is_empty() {
+      let job_name = job_root_directory_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("job");
+      status_file_paths.push(PathBuf::from(trimmed).join(format!("{job_name}.json")));
+    }
+  }
+  status_file_paths
+}
+
+fn build_status_file_snapshot(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  finished_phase: Option<JobPhase>,
+) -> Option<serde_json::Value> {
+  let status = build_job_status(job_runtime_state, job_root_directory_path).ok()?;
+  let phase = match finished_phase {
+    Some(phase) => phase,
+    None if status.is_running => derive_running_job_phase(status.total_tasks, status.pending_tasks, status.running_tasks),
+    None => JobPhase::Idle,
+  };
+  let snapshot = StatusFileSnapshot {
+    status,
+    phase,
+    updated_unix_timestamp_millis: now_unix_timestamp_millis(),
+  };
+  serde_json::to_value(snapshot).ok()
+}
+
+fn start_status_file_writer(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  status_file_paths: Vec<PathBuf>,
+) -> Option<StatusFileWriter> {
+  if status_file_paths.is_empty() {
+    return None;
+  }
+  let job_runtime_state = job_runtime_state.clone();
+  let job_root_directory_path = job_root_directory_path.to_path_buf();
+  Some(StatusFileWriter::start(
+    status_file_paths,
+    Arc::new(move || build_status_file_snapshot(&job_runtime_state, &job_root_directory_path, None)),
+  ))
+}
+
+fn finish_status_file_writer(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  status_file_writer: Option<StatusFileWriter>,
+  status_file_paths: &[PathBuf],
+  finished_phase: JobPhase,
+) {
+  let Some(status_file_writer) = status_file_writer else {
+    return;
+  };
+  // Guard: stop the periodic writer first so it cannot overwrite the final snapshot.
+  status_file_writer.stop_and_join();
+  if let Some(snapshot) = build_status_file_snapshot(job_runtime_state, job_root_directory_path, Some(finished_phase)) {
+    write_status_files_best_effort(status_file_paths, &snapshot);
+  }
+}
+
+fn make_job_heartbeat_provider(job_runtime_state: SharedJobRuntimeState) -> JobHeartbeatProvider {
+  Arc::new(move || {
+    let Ok(locked_state) = job_runtime_state.lock() else {
+      return vec![];
+    };
+    let updated_unix_timestamp_millis = now_unix_timestamp_millis();
+    locked_state
+      .running_job_by_root
+      .iter()
+      .map(|(job_root_directory_path, running)| {
+        let heartbeat = JobHeartbeat {
+          updated_unix_timestamp_millis,
+          pid: std::process::id(),
+          container_name: running.container_name.clone(),
+        };
+        (job_root_directory_path.clone(), heartbeat)
+      })
+      .collect()
   })
 }
 
 fn append_log_line(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path, line: String) {
+  append_job_log_line(job_runtime_state, job_root_directory_path, line, None, false);
+}
+
+// NOTE: `overwritable_prefix` / `is_progress` come from captured process output; see `JobLogStore::append`.
+fn append_job_log_line(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  line: String,
+  overwritable_prefix: Option<&str>,
+  is_progress: bool,
+) {
+  let max_log_lines = current_runtime_limits().max_log_lines;
+  // NOTE: A root whose log was evicted continues its persisted lines instead of starting over.
+  load_evicted_job_log_best_effort(job_runtime_state, job_root_directory_path, max_log_lines);
   let mut locked_state = match job_runtime_state.lock() {
     Ok(state) => state,
     Err(_) => return,
   };
 
-  let lines = locked_state
-    .log_lines_by_root
-    .entry(job_root_directory_path.to_path_buf())
-    .or_insert_with(VecDeque::new);
-  lines.push_back(line);
-  while lines.len() > MAX_LOG_LINES {
-    lines.pop_front();
+  let event_app_handle = locked_state.event_app_handle.clone();
+  let event_line = event_app_handle.as_ref().map(|_| line.clone());
+  let is_overwrite = locked_state.job_log_store.append(
+    job_root_directory_path,
+    line,
+    overwritable_prefix,
+    is_progress,
+    max_log_lines,
+  );
+  drop(locked_state);
+  let event = event_line.map(|line| JobLogLineEvent {
+    job_root_directory_path: job_root_directory_path.display().to_string(),
+    line,
+    replaces_previous_line: is_overwrite,
+  });
+  // NOTE: Emitted after the lock is released; a slow webview must not stall every thread that logs.
+  if let (Some(app_handle), Some(event)) = (event_app_handle, event) {
+    let _ = app_handle.emit(JOB_LOG_LINE_EVENT, event);
   }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-enum JobStateStatus {
-  Queued,
-  Running,
-  Completed,
-  Failed,
+// NOTE: The file is read outside the state lock (job roots may be on a network share).
+fn load_evicted_job_log_best_effort(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  max_log_lines: usize,
+) {
+  let is_resident = match job_runtime_state.lock() {
+    Ok(locked_state) => locked_state.job_log_store.is_resident(job_root_directory_path),
+    Err(_) => return,
+  };
+  if is_resident {
+    return;
+  }
+  let persisted_lines = read_persisted_job_log(job_root_directory_path, max_log_lines);
+  if let Ok(mut locked_state) = job_runtime_state.lock() {
+    locked_state
+      .job_log_store
+      .insert_loaded(job_root_directory_path, persisted_lines);
+  }
+  evict_idle_job_logs_best_effort(job_runtime_state);
+}
+
+// NOTE: Called whenever a root becomes resident, so the number of idle roots in memory stays bounded.
+fn evict_idle_job_logs_best_effort(job_runtime_state: &SharedJobRuntimeState) {
+  let evicted_logs = match job_runtime_state.lock() {
+    Ok(mut locked_state) => {
+      let JobRuntimeState {
+        job_log_store,
+        running_job_by_root,
+        ..
+      } = &mut *locked_state;
+      job_log_store.take_idle_logs(|job_root_directory_path| running_job_by_root.contains_key(job_root_directory_path))
+    }
+    Err(_) => return,
+  };
+  for evicted_log in evicted_logs {
+    // Guard: an unwritable root only loses its older log lines, as before logs were persisted.
+    let _ = write_persisted_job_log(&evicted_log.job_root_directory_path, &evicted_log.lines);
+  }
+}
+
+fn emit_job_event_best_effort<S: Serialize + Clone>(
+  job_runtime_state: &SharedJobRuntimeState,
+  event_name: &str,
+  payload: S,
+) {
+  let Some(app_handle) = job_runtime_state
+    .lock()
+    .ok()
+    .and_then(|locked_state| locked_state.event_app_handle.clone())
+  else {
+    return;
+  };
+  let _ = app_handle.emit(event_name, payload);
+}
+
+// NOTE: Also refreshes the status cache, so a `get_job_status` right after the event returns the same status.
+fn emit_job_status_best_effort(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path) {
+  let Ok(status) = build_job_status(job_runtime_state, job_root_directory_path) else {
+    return;
+  };
+  if let Ok(mut locked_state) = job_runtime_state.lock() {
+    locked_state
+      .job_status_cache
+      .insert(job_root_directory_path.to_path_buf(), status.clone());
+  }
+  emit_job_event_best_effort(job_runtime_state, JOB_STATUS_EVENT, status);
+}
+
+// NOTE: Jobs start, end, pause and get cancelled from many places; diffing the running set here emits one
+// `job://status` per change. The start time tells an OOM retry (same root, new run) apart from the run it replaced.
+fn start_job_status_event_pump(job_runtime_state: SharedJobRuntimeState) {
+  thread::spawn(move || {
+    let mut previous_run_flags_by_root: HashMap<PathBuf, (i64, bool, bool)> = HashMap::new();
+    loop {
+      thread::sleep(JOB_STATUS_EVENT_PUMP_INTERVAL);
+      let run_flags_by_root: HashMap<PathBuf, (i64, bool, bool)> = match job_runtime_state.lock() {
+        Ok(locked_state) => locked_state
+          .running_job_by_root
+          .iter()
+          .map(|(job_root_directory_path, running)| {
+            (
+              job_root_directory_path.clone(),
+              (
+                running.start_unix_timestamp_millis,
+                running.paused_unix_timestamp_millis.is_some(),
+                running.is_cancel_requested,
+              ),
+            )
+          })
+          .collect(),
+        Err(_) => return,
+      };
+      let changed_job_roots: Vec<PathBuf> = run_flags_by_root
+        .iter()
+        .filter(|(job_root_directory_path, run_flags)| {
+          previous_run_flags_by_root.get(*job_root_directory_path) != Some(*run_flags)
+        })
+        .map(|(job_root_directory_path, _)| job_root_directory_path.clone())
+        .chain(
+          previous_run_flags_by_root
+            .keys()
+            .filter(|job_root_directory_path| !run_flags_by_root.contains_key(*job_root_directory_path))
+            .cloned(),
+        )
+        .collect();
+      for job_root_directory_path in changed_job_roots {
+        emit_job_status_best_effort(&job_runtime_state, &job_root_directory_path);
+      }
+      previous_run_flags_by_root = run_flags_by_root;
+    }
+  });
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct JobState {
   status: JobStateStatus,
   job_id: String,
+  // NOTE: Path fields are informational and decoded lossily (non-UTF-8 bytes become U+FFFD), since JSON holds only
+  // UTF-8; the job root is always located from where job_state.json is read, never from this field.
   job_root_directory_path: String,
   source_bundle_directory_path: Option<String>,
+  // NOTE: Who submitted the bundle (`.submitter`, an `@<submitter>` inbox folder, or the HTTP API token's owner).
+  #[serde(default)]
+  submitter: Option<String>,
   accepted_unix_timestamp_millis: i64,
   started_unix_timestamp_millis: Option<i64>,
   finished_unix_timestamp_millis: Option<i64>,
   output_markdown_path: Option<String>,
   error_message: Option<String>,
+  #[serde(default)]
+  translated_output_markdown_path_by_language: BTreeMap<String, String>,
+  // NOTE: Local image ID of the OCR container that produced the output, for audit trails.
+  #[serde(default)]
+  image_digest: Option<String>,
+  #[serde(default)]
+  error_category: Option<JobErrorCategory>,
+  // NOTE: Every accepted status change, oldest first; files written before this field start empty.
+  #[serde(default)]
+  transitions: Vec<JobStateTransition>,
+  // NOTE: Set by `apply_detected_title`; the job id and folder name stay as they are.
+  #[serde(default)]
+  title: Option<String>,
+  // NOTE: Set when the last run was cancelled: how, when, and how many in-flight tasks went back to `pending`.
+  #[serde(default)]
+  cancellation: Option<JobCancellationRecord>,
+}
+
+// NOTE: The only place a job's status changes; each accepted change is timestamped and announced in the job log.
+fn transition_job_state(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  state: &mut JobState,
+  next_status: JobStateStatus,
+) -> Result<(), String> {
+  let now = now_unix_timestamp_millis();
+  let Some(transition) = validate_job_state_transition(state.status, next_status, now)? else {
+    return Ok(());
+  };
+  match next_status {
+    JobStateStatus::Queued => {
+      state.started_unix_timestamp_millis = None;
+      state.finished_unix_timestamp_millis = None;
+    }
+    JobStateStatus::Running => state.started_unix_timestamp_millis = Some(now),
+    JobStateStatus::Completed | JobStateStatus::Failed | JobStateStatus::Partial => {
+      state.finished_unix_timestamp_millis = Some(now)
+    }
+    JobStateStatus::Interrupted => {}
+  }
+  append_log_line(
+    job_runtime_state,
+    job_root_directory_path,
+    format!("[job-state] {} -> {}", state.status.label(), next_status.label()),
+  );
+  state.status = next_status;
+  state.transitions.push(transition);
+  Ok(())
 }
 
 fn job_state_file_path(job_root_directory_path: &Path) -> PathBuf {
   job_root_directory_path.join(DEFAULT_WATCH_JOB_STATE_FILENAME)
 }
 
+// NOTE: Re-signs on every write when integrity signing is on, so no app action leaves a stale signature behind (which
+// `verify_job_integrity` would report as tampering).
 fn write_job_state(job_root_directory_path: &Path, state: &JobState) -> Result<(), String> {
   let serialized = serde_json::to_string_pretty(state).map_err(|error| error.to_string())?;
-  fs::write(job_state_file_path(job_root_directory_path), serialized).map_err(|error| error.to_string())?;
+  let job_state_path = job_state_file_path(job_root_directory_path);
+  fs::write(&job_state_path, serialized).map_err(|error| error.to_string())?;
+  if read_job_settings_best_effort(job_root_directory_path)
+    .is_integrity_signing_enabled
+    .unwrap_or(false)
+  {
+    sign_file(&job_state_path).map_err(|error| format!("job state signing failed: {error}"))?;
+  }
   Ok(())
 }
 
@@ -927,6 +3074,104 @@ fn read_job_state_best_effort(job_root_directory_path: &Path) -> Option<JobState
   serde_json::from_str::<JobState>(&raw).ok()
 }
 
+fn register_job_id(job_runtime_state: &SharedJobRuntimeState, job_id: &str, job_root_directory_path: &Path) {
+  if let Ok(mut locked_state) = job_runtime_state.lock() {
+    let previous_job_id = locked_state
+      .job_id_by_root
+      .insert(job_root_directory_path.to_path_buf(), job_id.to_string());
+    // Guard: watcher intake replaces the id that preparing the root assigned; the old id must not resolve anymore.
+    if let Some(previous_job_id) = previous_job_id.filter(|previous_job_id| previous_job_id != job_id) {
+      locked_state.job_root_by_id.remove(&previous_job_id);
+    }
+    locked_state
+      .job_root_by_id
+      .insert(job_id.to_string(), job_root_directory_path.to_path_buf());
+  }
+}
+
+fn lookup_job_id(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path) -> Option<String> {
+  let known_job_id = job_runtime_state
+    .lock()
+    .ok()
+    .and_then(|locked_state| locked_state.job_id_by_root.get(job_root_directory_path).cloned());
+  if known_job_id.is_some() {
+    return known_job_id;
+  }
+  let state = read_job_state_best_effort(job_root_directory_path)?;
+  register_job_id(job_runtime_state, &state.job_id, job_root_directory_path);
+  Some(state.job_id)
+}
+
+fn derive_manual_job_id(job_root_directory_path: &Path) -> String {
+  let base = job_root_directory_path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .unwrap_or("job");
+  format!("{}_manual_{}", now_unix_timestamp_millis(), sanitize_watch_job_id_component(base))
+}
+
+// NOTE: Watcher jobs get their state at intake; any other job gets one with a fresh id the first time it is prepared
+// or run. An existing but unreadable file is reported rather than replaced, so its id is never silently lost.
+fn ensure_job_state(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path) -> Result<JobState, String> {
+  let state_path = job_state_file_path(job_root_directory_path);
+  let state = if state_path.exists() {
+    read_job_state_best_effort(job_root_directory_path)
+      .ok_or_else(|| format!("Unreadable job state: {}", state_path.display()))?
+  } else {
+    let created_at = now_unix_timestamp_millis();
+    let state = JobState {
+      status: JobStateStatus::Queued,
+      job_id: derive_manual_job_id(job_root_directory_path),
+      job_root_directory_path: job_root_directory_path.to_string_lossy().to_string(),
+      source_bundle_directory_path: None,
+      submitter: None,
+      accepted_unix_timestamp_millis: created_at,
+      started_unix_timestamp_millis: None,
+      finished_unix_timestamp_millis: None,
+      output_markdown_path: None,
+      error_message: None,
+      translated_output_markdown_path_by_language: BTreeMap::new(),
+      image_digest: None,
+      error_category: None,
+      transitions: vec![initial_job_state_transition(created_at)],
+      title: None,
+      cancellation: None,
+    };
+    write_job_state(job_root_directory_path, &state)?;
+    state
+  };
+  register_job_id(job_runtime_state, &state.job_id, job_root_directory_path);
+  Ok(state)
+}
+
+fn update_job_state_best_effort(job_root_directory_path: &Path, update: impl FnOnce(&mut JobState)) {
+  // Guard: only jobs that already have a job_state.json are tracked; never create one implicitly here.
+  let Some(mut state) = read_job_state_best_effort(job_root_directory_path) else {
+    return;
+  };
+  update(&mut state);
+  let _ = write_job_state(job_root_directory_path, &state);
+}
+
+// NOTE: Like `update_job_state_best_effort`, but through a validated transition that is logged like the waiter's.
+fn transition_job_state_best_effort(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  next_status: JobStateStatus,
+  update: impl FnOnce(&mut JobState),
+) {
+  let Some(mut state) = read_job_state_best_effort(job_root_directory_path) else {
+    return;
+  };
+  let result = transition_job_state(job_runtime_state, job_root_directory_path, &mut state, next_status).and_then(|()| {
+    update(&mut state);
+    write_job_state(job_root_directory_path, &state)
+  });
+  if let Err(error) = result {
+    append_log_line(job_runtime_state, job_root_directory_path, format!("[job-state] {error}"));
+  }
+}
+
 fn spawn_log_reader_thread(
   job_runtime_state: SharedJobRuntimeState,
   job_root_directory_path: PathBuf,
@@ -934,32 +3179,48 @@ fn spawn_log_reader_thread(
   stream_name: &'static str,
 ) {
   std::thread::spawn(move || {
-    let reader = BufReader::new(stream);
-    for line_result in reader.lines() {
-      let Ok(line) = line_result else {
-        continue;
+    let stream_prefix = format!("[{stream_name}] ");
+    let mut capture = |captured_line: CapturedLogLine| {
+      let (line, is_progress) = match captured_line {
+        CapturedLogLine::Line(line) => (line, false),
+        CapturedLogLine::Progress(line) => (line, true),
       };
-      append_log_line(
+      let finished_task = parse_task_finished_line(&line);
+      append_job_log_line(
         &job_runtime_state,
         &job_root_directory_path,
-        format!("[{stream_name}] {line}"),
+        format!("{stream_prefix}{line}"),
+        Some(stream_prefix.as_str()),
+        is_progress,
       );
-    }
+      if let Some((task_id, status)) = finished_task {
+        emit_job_event_best_effort(
+          &job_runtime_state,
+          JOB_TASK_FINISHED_EVENT,
+          JobTaskFinishedEvent {
+            job_root_directory_path: job_root_directory_path.display().to_string(),
+            task_id,
+            status,
+          },
+        );
+        emit_job_status_best_effort(&job_runtime_state, &job_root_directory_path);
+      }
+    };
+    capture_stream_lines(stream, &mut capture);
   });
 }
 
-fn spawn_job_process(job_runtime_state: SharedJobRuntimeState, job_root_directory_path: PathBuf) -> Result<(), String> {
-  let repo_root = repo_root_path()?;
-  let job_root_canonical = job_root_directory_path
-    .canonicalize()
-    .map_err(|error| format!("Failed to canonicalize job root: {error}"))?;
-  let job_root_for_docker = normalize_windows_path_lossy(&job_root_canonical);
+// NOTE: Script hooks and plugins both stream subprocess output into the job log through this sink.
+fn make_job_log_sink(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path) -> ScriptHookLogSink {
+  let job_runtime_state = job_runtime_state.clone();
+  let job_root_directory_path = job_root_directory_path.to_path_buf();
+  Arc::new(move |line: String| append_log_line(&job_runtime_state, &job_root_directory_path, line))
+}
 
-  // NOTE: We cannot rely on shell operators without invoking a shell. Use `bash -lc` inside container.
-  let mut command = build_docker_compose_base_command(&repo_root);
-  command.arg("run");
-  command.arg("--rm");
-  let settings = read_job_settings_best_effort(&job_root_directory_path);
+// NOTE: Shared by the primary job process and its extra workers so they see identical OCR settings.
+fn job_environment_variables(settings: &JobSettings) -> Vec<(String, String)> {
+  let mut environment_variables: Vec<(String, String)> = vec![];
+  let mut push = |name: &str, value: String| environment_variables.push((name.to_string(), value));
 
   let is_math_delimiter_conversion_enabled = settings.is_math_delimiter_conversion_enabled.unwrap_or(true);
   let math_delimiter_style = if is_math_delimiter_conversion_enabled {
@@ -967,126 +3228,667 @@ fn spawn_job_process(job_runtime_state: SharedJobRuntimeState, job_root_director
   } else {
     "latex"
   };
-  command.arg("-e");
-  command.arg(format!("OCR_AGENT_MATH_DELIMITER_STYLE={math_delimiter_style}"));
+  push("OCR_AGENT_MATH_DELIMITER_STYLE", math_delimiter_style.to_string());
+  if settings.is_math_delimiter_normalization_enabled.unwrap_or(false) {
+    push("OCR_AGENT_NORMALIZE_MATH_DELIMITERS", "1".to_string());
+  }
+  if settings.is_mathml_export_enabled.unwrap_or(false) {
+    push("OCR_AGENT_EXPORT_MATHML", "1".to_string());
+  }
+  if settings.is_page_image_export_enabled.unwrap_or(false) {
+    push("OCR_AGENT_EXPORT_PAGE_IMAGES", "1".to_string());
+  }
+
+  let is_hyphenation_repair_enabled = settings.is_hyphenation_repair_enabled.unwrap_or(false);
+  push(
+    "OCR_AGENT_REPAIR_HYPHENATION",
+    if is_hyphenation_repair_enabled { "1" } else { "0" }.to_string(),
+  );
+  let is_paragraph_reflow_enabled = settings.is_paragraph_reflow_enabled.unwrap_or(false);
+  push(
+    "OCR_AGENT_REFLOW_PARAGRAPHS",
+    if is_paragraph_reflow_enabled { "1" } else { "0" }.to_string(),
+  );
+
+  if let Some(page_separator_style) = settings.page_separator_style.as_deref() {
+    push("OCR_AGENT_PAGE_SEPARATOR_STYLE", page_separator_style.trim().to_string());
+  }
+  if let Some(page_separator_template) = settings.page_separator_template.as_deref() {
+    push("OCR_AGENT_PAGE_SEPARATOR_TEMPLATE", page_separator_template.to_string());
+  }
+  if settings.is_page_source_comment_enabled.unwrap_or(false) {
+    push("OCR_AGENT_PAGE_SOURCE_COMMENTS", "1".to_string());
+  }
 
   if let Some(model_revision) = settings.deepseek_ocr2_model_revision.as_deref() {
     let trimmed = model_revision.trim();
     if !trimmed.is_empty() {
-      command.arg("-e");
-      command.arg(format!("DEEPSEEK_OCR2_MODEL_REVISION={trimmed}"));
+      push("DEEPSEEK_OCR2_MODEL_REVISION", trimmed.to_string());
     }
   }
   if let Some(markdown_prompt) = settings.deepseek_ocr2_markdown_prompt.as_deref() {
     let encoded_prompt = markdown_prompt.replace("\r\n", "\n").replace('\n', "\\n");
-    command.arg("-e");
-    command.arg(format!("DEEPSEEK_OCR2_MARKDOWN_PROMPT={encoded_prompt}"));
+    push("DEEPSEEK_OCR2_MARKDOWN_PROMPT", encoded_prompt);
   }
   if let Some(base_size_pixels) = settings.deepseek_ocr2_base_image_size_pixels {
-    command.arg("-e");
-    command.arg(format!("DEEPSEEK_OCR2_BASE_IMAGE_SIZE_PIXELS={base_size_pixels}"));
+    push("DEEPSEEK_OCR2_BASE_IMAGE_SIZE_PIXELS", base_size_pixels.to_string());
   }
   if let Some(image_size_pixels) = settings.deepseek_ocr2_inference_image_size_pixels {
-    command.arg("-e");
-    command.arg(format!("DEEPSEEK_OCR2_INFERENCE_IMAGE_SIZE_PIXELS={image_size_pixels}"));
+    push("DEEPSEEK_OCR2_INFERENCE_IMAGE_SIZE_PIXELS", image_size_pixels.to_string());
   }
   if let Some(enable_crop_mode) = settings.deepseek_ocr2_enable_crop_mode {
-    command.arg("-e");
-    command.arg(format!(
-      "DEEPSEEK_OCR2_ENABLE_CROP_MODE={}",
-      if enable_crop_mode { "1" } else { "0" }
-    ));
+    push(
+      "DEEPSEEK_OCR2_ENABLE_CROP_MODE",
+      if enable_crop_mode { "1" } else { "0" }.to_string(),
+    );
   }
+  for (name, value) in settings.extra_environment.iter().flatten() {
+    push(name, value.clone());
+  }
+  environment_variables
+}
 
-  command.arg("-v");
-  command.arg(format!("{job_root_for_docker}:/data"));
-  command.arg(DOCKER_COMPOSE_SERVICE_NAME);
-  command.arg("bash");
-  command.arg("-lc");
-  let desired_output_filename = match settings.output_markdown_filename_override.as_deref() {
-    None => derive_default_unique_markdown_filename(),
-    Some(filename) => ensure_markdown_extension(&sanitize_output_markdown_filename(filename)),
-  };
-  let output_markdown_path = derive_non_conflicting_markdown_output_path(
-    &job_root_directory_path,
-    &desired_output_filename,
-  )?;
-  let output_markdown_filename = output_markdown_path
-    .file_name()
-    .and_then(|name| name.to_str())
-    .ok_or_else(|| "Failed to derive output markdown filename".to_string())?
-    .to_string();
-
-  let mut updated_settings = settings.clone();
-  updated_settings.last_output_markdown_filename = Some(output_markdown_filename.clone());
-  write_job_settings(&job_root_directory_path, &updated_settings)?;
+enum JobEngine {
+  Docker {
+    compose_settings: DockerComposeSettings,
+    job_root_for_docker: String,
+  },
+  Host {
+    python_interpreter_path: String,
+  },
+}
 
-  command.arg(format!(
-    "python3 -m ocr_agent.cli enqueue /data/input && python3 -m ocr_agent.cli run --output-md \"/data/{output_markdown_filename}\""
-  ));
-  command.stdout(Stdio::piped());
-  command.stderr(Stdio::piped());
+fn read_execution_engine_settings_best_effort(job_runtime_state: &SharedJobRuntimeState) -> ExecutionEngineSettings {
+  read_app_config_json_file(job_runtime_state, EXECUTION_ENGINE_SETTINGS_FILENAME)
+    .ok()
+    .flatten()
+    .unwrap_or_default()
+}
 
-  let mut child = command.spawn().map_err(|error| {
-    format!(
-      "Failed to start docker compose job. Is the image built and GPU enabled?\n{error}"
-    )
-  })?;
+fn resolve_job_engine(job_runtime_state: &SharedJobRuntimeState, job_root_canonical: &Path) -> Result<JobEngine, String> {
+  let engine_settings = read_execution_engine_settings_best_effort(job_runtime_state);
+  match engine_settings.kind {
+    ExecutionEngineKind::Docker => Ok(JobEngine::Docker {
+      compose_settings: read_docker_compose_settings_best_effort(job_runtime_state),
+      job_root_for_docker: normalize_windows_path_lossy(job_root_canonical),
+    }),
+    ExecutionEngineKind::Host => {
+      validate_host_engine_supported()?;
+      Ok(JobEngine::Host {
+        python_interpreter_path: engine_settings.resolved_python_interpreter_path()?.to_string(),
+      })
+    }
+  }
+}
 
-  let stdout = child.stdout.take();
-  let stderr = child.stderr.take();
+impl JobEngine {
+  // NOTE: Paths handed to the CLI; the container sees the job root at /data, the host sees it directly.
+  fn data_path(&self, job_root_directory_path: &Path, relative_path: &str) -> String {
+    match self {
+      JobEngine::Docker { .. } => format!("/data/{relative_path}"),
+      JobEngine::Host { .. } => job_root_directory_path.join(relative_path).to_string_lossy().to_string(),
+    }
+  }
 
-  let start_unix_timestamp_millis = now_unix_timestamp_millis();
-  let child_handle = Arc::new(Mutex::new(child));
+  fn queue_arguments(&self, job_root_directory_path: &Path) -> Vec<String> {
+    vec![
+      "--queue-db".to_string(),
+      self.data_path(
+        job_root_directory_path,
+        &job_directory_layout(job_root_directory_path).queue_database_filename,
+      ),
+    ]
+  }
 
-  {
-    let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
-    if locked_state.running_job_by_root.contains_key(&job_root_directory_path) {
-      // Guard: refuse to start two jobs for the same directory.
-      return Err("A job is already running for this output directory.".to_string());
+  fn build_command(
+    &self,
+    repo_root: &Path,
+    settings: &JobSettings,
+    invocations: &[Vec<String>],
+    container_name: Option<&str>,
+  ) -> Command {
+    let environment_variables = job_environment_variables(settings);
+    match self {
+      JobEngine::Docker {
+        compose_settings,
+        job_root_for_docker,
+      } => {
+        // NOTE: We cannot rely on shell operators without invoking a shell. Use `bash -lc` inside container.
+        let mut command = build_docker_compose_base_command(repo_root, compose_settings);
+        command.arg("run");
+        command.arg("--rm");
+        if let Some(container_name) = container_name {
+          command.arg("--name");
+          command.arg(container_name);
+        }
+        for (name, value) in &environment_variables {
+          command.arg("-e");
+          command.arg(format!("{name}={value}"));
+        }
+        command.arg("-v");
+        command.arg(format!("{job_root_for_docker}:/data"));
+        command.arg(compose_settings.resolved_service_name());
+        command.arg("bash");
+        command.arg("-lc");
+        command.arg(render_cli_script("python3", invocations));
+        command
+      }
+      JobEngine::Host {
+        python_interpreter_path,
+      } => build_host_job_command(repo_root, python_interpreter_path, &environment_variables, invocations),
     }
-    locked_state.running_job_by_root.insert(
-      job_root_directory_path.clone(),
-      RunningJobHandle {
-        child: child_handle.clone(),
-        start_unix_timestamp_millis,
-      },
-    );
-    locked_state
-      .log_lines_by_root
-      .entry(job_root_directory_path.clone())
-      .or_insert_with(VecDeque::new);
+  }
+}
 
-    // Guard: watcher-created jobs track their state in a separate file.
-    if locked_state
-      .job_state_file_path_by_root
-      .contains_key(&job_root_directory_path)
-    {
-      let mut state = read_job_state_best_effort(&job_root_directory_path).unwrap_or(JobState {
-        status: JobStateStatus::Queued,
-        job_id: "unknown".to_string(),
-        job_root_directory_path: job_root_directory_path.to_string_lossy().to_string(),
-        source_bundle_directory_path: None,
-        accepted_unix_timestamp_millis: now_unix_timestamp_millis(),
-        started_unix_timestamp_millis: None,
-        finished_unix_timestamp_millis: None,
-        output_markdown_path: None,
-        error_message: None,
-      });
-      state.status = JobStateStatus::Running;
-      state.started_unix_timestamp_millis = Some(start_unix_timestamp_millis);
-      let _ = write_job_state(&job_root_directory_path, &state);
+// NOTE: Workers normally exit on their own once the queue is empty; this reaps them and stops any straggler.
+fn shut_down_job_workers(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path) {
+  let worker_children = match job_runtime_state.lock() {
+    Ok(locked_state) => locked_state
+      .running_job_by_root
+      .get(job_root_directory_path)
+      .map(|running| running.worker_children.clone())
+      .unwrap_or_default(),
+    Err(_) => return,
+  };
+  for worker_child in worker_children {
+    let Ok(mut child_guard) = worker_child.lock() else {
+      continue;
+    };
+    if let Ok(None) = child_guard.try_wait() {
+      let _ = child_guard.kill();
     }
+    let _ = child_guard.wait();
   }
+}
 
-  if let Some(stream) = stdout {
-    spawn_log_reader_thread(job_runtime_state.clone(), job_root_directory_path.clone(), stream, "stdout");
-  }
-  if let Some(stream) = stderr {
-    spawn_log_reader_thread(job_runtime_state.clone(), job_root_directory_path.clone(), stream, "stderr");
-  }
+// NOTE: Where a run's start was recorded; either entry is None when recording it failed.
+#[derive(Debug, Clone, Copy)]
+struct RecordedRun {
+  run_number: Option<u32>,
+  history_id: Option<i64>,
+}
 
-  // Waiter thread: removes running state once done.
+fn record_job_run_started_best_effort(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  run_record: JobRunRecord,
+) -> RecordedRun {
+  let run_mode = run_record.run_mode.clone();
+  let started_unix_timestamp_millis = run_record.started_unix_timestamp_millis;
+  let output_markdown_path = run_record.output_markdown_path.clone();
+  let run_number = match record_job_run_started(job_root_directory_path, run_record) {
+    Ok(run_number) => Some(run_number),
+    Err(error) => {
+      // Guard: the history is informational; a broken runs.json must not stop the run.
+      append_log_line(job_runtime_state, job_root_directory_path, format!("[backend] run history update failed: {error}"));
+      None
+    }
+  };
+  let history_id = resolve_app_config_directory_path(job_runtime_state).and_then(|app_config_directory_path| {
+    record_job_history_started(
+      &app_config_directory_path,
+      job_root_directory_path,
+      &run_mode,
+      started_unix_timestamp_millis,
+      &output_markdown_path,
+    )
+  });
+  let history_id = match history_id {
+    Ok(history_id) => Some(history_id),
+    Err(error) => {
+      append_log_line(job_runtime_state, job_root_directory_path, format!("[backend] job history update failed: {error}"));
+      None
+    }
+  };
+  RecordedRun { run_number, history_id }
+}
+
+fn record_job_run_finished_best_effort(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  recorded_run: RecordedRun,
+  exit_status: String,
+  exit_code: Option<i32>,
+  outcome: RecordedRunOutcome,
+) {
+  let finished_unix_timestamp_millis = now_unix_timestamp_millis();
+  // Guard: a start that could not be recorded has no entry to close.
+  if let Some(history_id) = recorded_run.history_id {
+    let result = resolve_app_config_directory_path(job_runtime_state).and_then(|app_config_directory_path| {
+      record_job_history_finished(
+        &app_config_directory_path,
+        history_id,
+        finished_unix_timestamp_millis,
+        &exit_status,
+        exit_code,
+        outcome,
+      )
+    });
+    if let Err(error) = result {
+      append_log_line(
+        job_runtime_state,
+        job_root_directory_path,
+        format!("[backend] job history update failed: {error}"),
+      );
+    }
+  }
+  let Some(run_number) = recorded_run.run_number else {
+    return;
+  };
+  if let Err(error) = record_job_run_finished(
+    job_root_directory_path,
+    run_number,
+    finished_unix_timestamp_millis,
+    exit_status,
+    exit_code,
+    outcome,
+  ) {
+    append_log_line(
+      job_runtime_state,
+      job_root_directory_path,
+      format!("[backend] run history update failed: {error}"),
+    );
+  }
+}
+
+// NOTE: Every new run (manual, watcher, quick job, dependent) starts here; retries of a running job do not.
+fn spawn_job_process(
+  job_runtime_state: SharedJobRuntimeState,
+  job_root_directory_path: PathBuf,
+  source: JobStartSource,
+) -> Result<JobStartOutcome, String> {
+  start_or_queue_job_run(job_runtime_state, job_root_directory_path, JobRunMode::Fresh, source)
+}
+
+fn start_or_queue_job_run(
+  job_runtime_state: SharedJobRuntimeState,
+  job_root_directory_path: PathBuf,
+  run_mode: JobRunMode,
+  source: JobStartSource,
+) -> Result<JobStartOutcome, String> {
+  if let Some(maintenance_mode) = read_maintenance_mode(&job_runtime_state) {
+    // Guard: maintenance mode drains the machine; no new work may start until it is turned off.
+    return Err(describe_maintenance_refusal(&maintenance_mode));
+  }
+  let max_concurrent_jobs = current_runtime_limits().max_concurrent_jobs;
+  let outcome = {
+    let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    if locked_state.running_job_by_root.contains_key(&job_root_directory_path) {
+      return Err("A job is already running for this output directory.".to_string());
+    }
+    let running_job_count = locked_state.running_job_by_root.len();
+    locked_state.job_start_queue.claim_slot_or_enqueue(
+      &job_root_directory_path,
+      run_mode,
+      source,
+      running_job_count,
+      max_concurrent_jobs,
+      now_unix_timestamp_millis(),
+    )?
+  };
+  if let JobStartOutcome::Queued { position } = outcome {
+    append_log_line(
+      &job_runtime_state,
+      &job_root_directory_path,
+      format!("[backend] queued: #{position} waiting for a free job slot (max_concurrent_jobs = {max_concurrent_jobs})"),
+    );
+    return Ok(outcome);
+  }
+  let start_result = spawn_job_process_with_mode(job_runtime_state.clone(), job_root_directory_path.clone(), run_mode);
+  if let Ok(mut locked_state) = job_runtime_state.lock() {
+    locked_state.job_start_queue.release_slot(&job_root_directory_path);
+  }
+  if start_result.is_err() {
+    // NOTE: The slot this start held is free again.
+    dispatch_queued_job_starts_best_effort(&job_runtime_state);
+  }
+  start_result.map(|()| outcome)
+}
+
+// NOTE: Called wherever a job slot may have freed up: a run ended or was suspended, a start failed, the limit was
+// raised, or maintenance mode ended.
+fn dispatch_queued_job_starts_best_effort(job_runtime_state: &SharedJobRuntimeState) {
+  loop {
+    if read_maintenance_mode(job_runtime_state).is_some() {
+      // Guard: queued starts stay queued until maintenance mode is turned off.
+      return;
+    }
+    let max_concurrent_jobs = current_runtime_limits().max_concurrent_jobs;
+    let next_start = {
+      let Ok(mut locked_state) = job_runtime_state.lock() else {
+        return;
+      };
+      let JobRuntimeState {
+        running_job_by_root,
+        job_start_queue,
+        ..
+      } = &mut *locked_state;
+      job_start_queue.take_next_start(running_job_by_root.len(), max_concurrent_jobs, |job_root_directory_path| {
+        running_job_by_root.contains_key(job_root_directory_path)
+      })
+    };
+    let Some(next_start) = next_start else {
+      return;
+    };
+    let job_root_directory_path = next_start.job_root_directory_path;
+    let start_result =
+      spawn_job_process_with_mode(job_runtime_state.clone(), job_root_directory_path.clone(), next_start.run_mode);
+    if let Ok(mut locked_state) = job_runtime_state.lock() {
+      locked_state.job_start_queue.release_slot(&job_root_directory_path);
+    }
+    let waited_seconds = (now_unix_timestamp_millis() - next_start.queued_unix_timestamp_millis).max(0) / 1000;
+    match start_result {
+      Ok(()) => append_log_line(
+        job_runtime_state,
+        &job_root_directory_path,
+        format!("[backend] started from the job queue after waiting {waited_seconds}s"),
+      ),
+      Err(error) => {
+        append_log_line(
+          job_runtime_state,
+          &job_root_directory_path,
+          format!("[backend] queued start failed: {error}"),
+        );
+        // NOTE: A queued dependent that cannot start blocks its own dependents, as in `start_ready_dependent_jobs`.
+        advance_job_dependencies_best_effort(job_runtime_state, &job_root_directory_path, JobRunOutcome::Failed);
+      }
+    }
+  }
+}
+
+fn spawn_job_process_with_mode(
+  job_runtime_state: SharedJobRuntimeState,
+  job_root_directory_path: PathBuf,
+  run_mode: JobRunMode,
+) -> Result<(), String> {
+  let repo_root = repo_root_path()?;
+  let job_root_canonical = job_root_directory_path
+    .canonicalize()
+    .map_err(|error| format!("Failed to canonicalize job root: {error}"))?;
+
+  let settings = read_job_settings_best_effort(&job_root_directory_path);
+  // Guard: job.json may have been edited by hand; refuse to start instead of passing unchecked variables.
+  validate_job_settings(&settings)?;
+  let job_engine = resolve_job_engine(&job_runtime_state, &job_root_canonical)?;
+  let mut image_name: Option<String> = None;
+  let mut image_digest: Option<String> = None;
+  if let JobEngine::Docker { compose_settings, .. } = &job_engine {
+    let service_image_name = derive_compose_service_image_name(&repo_root, compose_settings.resolved_service_name());
+    // NOTE: Best-effort; a missing image is left to `docker compose run`, which builds it.
+    let image_digests = inspect_docker_image_digests(&service_image_name).ok();
+    if let Some(pinned_image_digest) = compose_settings.resolved_pinned_image_digest() {
+      let is_pinned_image = image_digests
+        .as_ref()
+        .map(|digests| digests.matches(pinned_image_digest))
+        .unwrap_or(false);
+      if !is_pinned_image {
+        // Guard: a pinned environment must never silently OCR with a different model/runtime image.
+        return Err(format!(
+          "Local image {service_image_name} does not match the pinned digest {pinned_image_digest}. Pull or rebuild the pinned image, or update the pin."
+        ));
+      }
+    }
+    image_digest = image_digests.map(|digests| digests.image_id);
+    image_name = Some(service_image_name);
+  }
+  let is_rolling_output_enabled = settings.is_rolling_output_enabled.unwrap_or(false);
+  let output_markdown_path = if run_mode == JobRunMode::RetryFailedTasks {
+    let previous_output_filename = settings
+      .last_output_markdown_filename
+      .as_deref()
+      .ok_or_else(|| "No previous run to retry for this job.".to_string())?;
+    job_root_directory_path.join(previous_output_filename)
+  } else if is_rolling_output_enabled {
+    // Guard: rolling jobs always write to the same file so runs accumulate.
+    let rolling_output_filename = match settings.output_markdown_filename_override.as_deref() {
+      None => DEFAULT_ROLLING_OUTPUT_MARKDOWN_FILENAME.to_string(),
+      Some(filename) => ensure_markdown_extension(&sanitize_output_markdown_filename(filename)),
+    };
+    job_root_directory_path.join(rolling_output_filename)
+  } else {
+    let desired_output_filename = match settings.output_markdown_filename_override.as_deref() {
+      None => derive_default_unique_markdown_filename(),
+      Some(filename) => ensure_markdown_extension(&sanitize_output_markdown_filename(filename)),
+    };
+    derive_non_conflicting_markdown_output_path(&job_root_directory_path, &desired_output_filename)?
+  };
+  let output_markdown_filename = output_markdown_path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .ok_or_else(|| "Failed to derive output markdown filename".to_string())?
+    .to_string();
+
+  // NOTE: A retry continues the same run, so pre-run hooks already ran for it.
+  let script_hook_settings = settings.script_hooks.as_ref().filter(|_| run_mode == JobRunMode::Fresh);
+  if let Some(script_hook_settings) = script_hook_settings {
+    // Guard: a failing pre-run hook (e.g. a validation script) aborts the run before OCR starts.
+    run_script_hooks_for_stage(
+      script_hook_settings,
+      ScriptHookStage::PreRun,
+      &job_root_directory_path,
+      Some(&output_markdown_path),
+      make_job_log_sink(&job_runtime_state, &job_root_directory_path),
+    )?;
+  }
+
+  let mut updated_settings = settings.clone();
+  updated_settings.last_output_markdown_filename = Some(output_markdown_filename.clone());
+  write_job_settings(&job_root_directory_path, &updated_settings)?;
+
+  let requested_worker_count = settings.worker_count.unwrap_or(1).clamp(1, MAX_JOB_WORKER_COUNT);
+  // Guard: rolling appends only include tasks the primary process claimed itself, so keep them single-worker.
+  // Guard: on Apple Silicon each worker loads its own model copy into shared CPU/MPS memory; extra workers only thrash.
+  let worker_count = if is_rolling_output_enabled || is_apple_silicon_host() {
+    1
+  } else {
+    requested_worker_count
+  };
+
+  let directory_layout = job_directory_layout(&job_root_directory_path);
+  let mut enqueue_arguments = vec![
+    "enqueue".to_string(),
+    job_engine.data_path(&job_root_directory_path, &directory_layout.input_directory_name),
+  ];
+  enqueue_arguments.extend(job_engine.queue_arguments(&job_root_directory_path));
+  let mut run_arguments = vec![
+    "run".to_string(),
+    "--output-dir".to_string(),
+    job_engine.data_path(&job_root_directory_path, &directory_layout.output_directory_name),
+    "--output-md".to_string(),
+    job_engine.data_path(&job_root_directory_path, &output_markdown_filename),
+  ];
+  run_arguments.extend(job_engine.queue_arguments(&job_root_directory_path));
+  if is_rolling_output_enabled {
+    run_arguments.push("--append".to_string());
+  }
+  if worker_count > 1 {
+    run_arguments.push("--await-workers".to_string());
+  }
+  let command_argument_lists = match run_mode {
+    JobRunMode::Fresh => vec![enqueue_arguments, run_arguments],
+    JobRunMode::RetryFailedTasks => vec![run_arguments],
+  };
+  // NOTE: A predictable name lets the heartbeat point external tools at the container doing the work.
+  let container_name = match &job_engine {
+    JobEngine::Docker { .. } => Some(format!(
+      "{}-job-{}",
+      derive_compose_project_name(&repo_root),
+      now_unix_timestamp_millis()
+    )),
+    JobEngine::Host { .. } => None,
+  };
+  // Guard: a marker left by a cancel whose waiter never ran (e.g. the GUI was closed) would stop this run at once.
+  remove_cancel_marker(&job_output_directory_path(&job_root_directory_path))?;
+  let mut command = job_engine.build_command(&repo_root, &settings, &command_argument_lists, container_name.as_deref());
+  command.stdout(Stdio::piped());
+  command.stderr(Stdio::piped());
+
+  let mut child = command.spawn().map_err(|error| match &job_engine {
+    JobEngine::Docker { .. } => format!("Failed to start docker compose job. Is the image built and GPU enabled?\n{error}"),
+    JobEngine::Host {
+      python_interpreter_path,
+    } => format!("Failed to start host job with {python_interpreter_path}.\n{error}"),
+  })?;
+
+  let stdout = child.stdout.take();
+  let stderr = child.stderr.take();
+
+  let start_unix_timestamp_millis = now_unix_timestamp_millis();
+  let volume_kind = detect_volume_kind(&job_root_directory_path);
+  let is_docker_engine = matches!(job_engine, JobEngine::Docker { .. });
+  let pause_target = JobPauseTarget::primary(match &container_name {
+    Some(container_name) => JobPauseTargetKind::Container(container_name.clone()),
+    None => JobPauseTargetKind::HostProcessGroup(child.id()),
+  });
+  let child_handle = Arc::new(Mutex::new(child));
+
+  {
+    let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    if locked_state.running_job_by_root.contains_key(&job_root_directory_path) {
+      // Guard: refuse to start two jobs for the same directory.
+      return Err("A job is already running for this output directory.".to_string());
+    }
+    // NOTE: Starting a suspended job by hand supersedes its automatic resume.
+    locked_state.suspended_job_by_root.remove(&job_root_directory_path);
+    locked_state.job_root_index.invalidate(&job_root_directory_path);
+    locked_state.running_job_by_root.insert(
+      job_root_directory_path.clone(),
+      RunningJobHandle {
+        child: child_handle.clone(),
+        worker_children: vec![],
+        start_unix_timestamp_millis,
+        is_cancel_requested: false,
+        cancellation: None,
+        volume_kind,
+        is_docker_engine,
+        container_name: container_name.clone(),
+        pause_targets: vec![pause_target],
+        paused_unix_timestamp_millis: None,
+      },
+    );
+  }
+
+  match ensure_job_state(&job_runtime_state, &job_root_directory_path) {
+    Ok(mut state) => {
+      if let Ok(mut locked_state) = job_runtime_state.lock() {
+        locked_state
+          .job_state_file_path_by_root
+          .insert(job_root_directory_path.clone(), job_state_file_path(&job_root_directory_path));
+      }
+      // NOTE: A new run of a finished job re-queues it first; OOM retries and volume resumes stay in `running`.
+      let requeue_result = if state.status.is_finished() {
+        transition_job_state(&job_runtime_state, &job_root_directory_path, &mut state, JobStateStatus::Queued)
+      } else {
+        Ok(())
+      };
+      let transition_result = requeue_result.and_then(|()| {
+        transition_job_state(&job_runtime_state, &job_root_directory_path, &mut state, JobStateStatus::Running)
+      });
+      match transition_result {
+        Ok(()) => {
+          state.image_digest = image_digest.clone();
+          let _ = write_job_state(&job_root_directory_path, &state);
+        }
+        Err(error) => append_log_line(&job_runtime_state, &job_root_directory_path, format!("[job-state] {error}")),
+      }
+    }
+    // Guard: a broken state file must not block the run itself.
+    Err(error) => append_log_line(&job_runtime_state, &job_root_directory_path, format!("[job-state] {error}")),
+  }
+
+  record_job_dependency_run_started_best_effort(&job_runtime_state, &job_root_directory_path);
+
+  if let Some(stream) = stdout {
+    spawn_log_reader_thread(job_runtime_state.clone(), job_root_directory_path.clone(), stream, "stdout");
+  }
+  if let Some(stream) = stderr {
+    spawn_log_reader_thread(job_runtime_state.clone(), job_root_directory_path.clone(), stream, "stderr");
+  }
+
+  if requested_worker_count > worker_count {
+    let reason = if is_rolling_output_enabled {
+      "rolling output runs"
+    } else {
+      "Apple Silicon (no NVIDIA GPU) runs"
+    };
+    append_log_line(
+      &job_runtime_state,
+      &job_root_directory_path,
+      format!("[backend] {reason} with a single worker; worker_count ignored"),
+    );
+  }
+  for worker_index in 1..worker_count {
+    let mut worker_arguments = vec![
+      "run".to_string(),
+      "--worker".to_string(),
+      "--output-dir".to_string(),
+      job_engine.data_path(&job_root_directory_path, &directory_layout.output_directory_name),
+    ];
+    worker_arguments.extend(job_engine.queue_arguments(&job_root_directory_path));
+    // NOTE: Named after the primary container so `pause_job` can address each worker container too.
+    let worker_container_name = container_name
+      .as_deref()
+      .map(|container_name| format!("{container_name}-worker-{}", worker_index + 1));
+    let mut worker_command =
+      job_engine.build_command(&repo_root, &settings, &[worker_arguments], worker_container_name.as_deref());
+    worker_command.stdout(Stdio::piped());
+    worker_command.stderr(Stdio::piped());
+    let mut worker_child = match worker_command.spawn() {
+      Ok(child) => child,
+      Err(error) => {
+        // NOTE: The primary process still drains the whole queue, so a missing worker only costs throughput.
+        append_log_line(
+          &job_runtime_state,
+          &job_root_directory_path,
+          format!("[backend] failed to start worker {}: {error}", worker_index + 1),
+        );
+        continue;
+      }
+    };
+    if let Some(stream) = worker_child.stdout.take() {
+      spawn_log_reader_thread(job_runtime_state.clone(), job_root_directory_path.clone(), stream, "worker stdout");
+    }
+    if let Some(stream) = worker_child.stderr.take() {
+      spawn_log_reader_thread(job_runtime_state.clone(), job_root_directory_path.clone(), stream, "worker stderr");
+    }
+    let worker_pause_target = JobPauseTarget::worker(match worker_container_name {
+      Some(worker_container_name) => JobPauseTargetKind::Container(worker_container_name),
+      None => JobPauseTargetKind::HostProcessGroup(worker_child.id()),
+    });
+    let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    if let Some(running) = locked_state.running_job_by_root.get_mut(&job_root_directory_path) {
+      running.worker_children.push(Arc::new(Mutex::new(worker_child)));
+      running.pause_targets.push(worker_pause_target);
+    }
+  }
+
+  if let (Some(image_name), Some(image_digest)) = (image_name.as_ref(), image_digest.as_ref()) {
+    append_log_line(&job_runtime_state, &job_root_directory_path, format!("[backend] image: {image_name} ({image_digest})"));
+    let output_directory_path = job_output_directory_path(&job_root_directory_path);
+    let image_digest = image_digest.clone();
+    if let Err(error) = update_job_report(&output_directory_path, |job_report| job_report.image_digest = Some(image_digest)) {
+      append_log_line(&job_runtime_state, &job_root_directory_path, format!("[backend] job report update failed: {error}"));
+    }
+  }
+
+  let run_record = JobRunRecord {
+    run_number: 0,
+    run_mode: run_mode.label().to_string(),
+    started_unix_timestamp_millis: start_unix_timestamp_millis,
+    finished_unix_timestamp_millis: None,
+    output_markdown_path: output_markdown_path.to_string_lossy().to_string(),
+    image_digest: image_digest.clone(),
+    settings_snapshot: serde_json::to_value(&updated_settings).unwrap_or_default(),
+    exit_status: None,
+    exit_code: None,
+    outcome: None,
+  };
+  let recorded_run = record_job_run_started_best_effort(&job_runtime_state, &job_root_directory_path, run_record);
+
+  let status_file_paths = resolve_status_file_paths(&job_root_directory_path, &settings);
+  let status_file_writer = start_status_file_writer(&job_runtime_state, &job_root_directory_path, status_file_paths.clone());
+
+  // Waiter thread: removes running state once done.
   let waiter_state = job_runtime_state.clone();
   let waiter_job_root = job_root_directory_path.clone();
   let waiter_child_handle = child_handle.clone();
@@ -1101,394 +3903,4993 @@ fn spawn_job_process(job_runtime_state: SharedJobRuntimeState, job_root_director
       child_guard.wait()
     };
 
+    shut_down_job_workers(&waiter_state, &waiter_job_root);
+
     let exit_status = match exit_status_result {
       Ok(status) => status,
       Err(error) => {
         append_log_line(&waiter_state, &waiter_job_root, format!("[backend] wait error: {error}"));
-        let mut locked_state = match waiter_state.lock() {
-          Ok(state) => state,
-          Err(_) => return,
-        };
-        locked_state.running_job_by_root.remove(&waiter_job_root);
+        if let Ok(mut locked_state) = waiter_state.lock() {
+          locked_state.running_job_by_root.remove(&waiter_job_root);
+          locked_state.job_root_index.invalidate(&waiter_job_root);
+        }
+        finish_status_file_writer(
+          &waiter_state,
+          &waiter_job_root,
+          status_file_writer,
+          &status_file_paths,
+          JobPhase::Failed,
+        );
+        record_job_run_finished_best_effort(
+          &waiter_state,
+          &waiter_job_root,
+          recorded_run,
+          format!("wait error: {error}"),
+          None,
+          RecordedRunOutcome::Failed,
+        );
+        advance_job_dependencies_best_effort(&waiter_state, &waiter_job_root, JobRunOutcome::Failed);
+        dispatch_queued_job_starts_best_effort(&waiter_state);
         return;
       }
     };
 
+    let (is_cancel_requested, mut cancellation) = waiter_state
+      .lock()
+      .ok()
+      .and_then(|locked_state| {
+        locked_state
+          .running_job_by_root
+          .get(&waiter_job_root)
+          .map(|running| (running.is_cancel_requested, running.cancellation.clone()))
+      })
+      .unwrap_or((false, None));
+    // Guard: a cancelled run is neither classified nor retried; its kill is not an OCR error.
+    let classified_error = if is_cancel_requested {
+      None
+    } else {
+      classify_finished_run_best_effort(&waiter_state, &waiter_job_root, &exit_status)
+    };
     append_log_line(
       &waiter_state,
       &waiter_job_root,
       format!("[backend] finished: {exit_status}"),
     );
+    if let Some(classified_error) = classified_error.as_ref() {
+      append_log_line(
+        &waiter_state,
+        &waiter_job_root,
+        format!("[backend] error category: {}", classified_error.category.summary()),
+      );
+      if classified_error.category == JobErrorCategory::CudaOutOfMemory
+        && retry_out_of_memory_run_best_effort(&waiter_state, &waiter_job_root)
+      {
+        // NOTE: The retried run owns the status file and the job state from here on.
+        if let Some(status_file_writer) = status_file_writer {
+          status_file_writer.stop_and_join();
+        }
+        record_job_run_finished_best_effort(
+          &waiter_state,
+          &waiter_job_root,
+          recorded_run,
+          exit_status.to_string(),
+          exit_status.code(),
+          RecordedRunOutcome::RetriedAfterOutOfMemory,
+        );
+        return;
+      }
+    }
+    if !exit_status.success() && !is_cancel_requested && suspend_job_if_volume_unavailable(&waiter_state, &waiter_job_root) {
+      finish_status_file_writer(
+        &waiter_state,
+        &waiter_job_root,
+        status_file_writer,
+        &status_file_paths,
+        JobPhase::Suspended,
+      );
+      record_job_run_finished_best_effort(
+        &waiter_state,
+        &waiter_job_root,
+        recorded_run,
+        exit_status.to_string(),
+        exit_status.code(),
+        RecordedRunOutcome::Suspended,
+      );
+      // NOTE: The suspended job gives up its slot; its resume queues like any other start if all slots are taken.
+      dispatch_queued_job_starts_best_effort(&waiter_state);
+      return;
+    }
+    if !exit_status.success()
+      && !is_cancel_requested
+      && interrupt_job_if_docker_engine_disconnected(&waiter_state, &waiter_job_root, classified_error.as_ref())
+    {
+      finish_status_file_writer(
+        &waiter_state,
+        &waiter_job_root,
+        status_file_writer,
+        &status_file_paths,
+        JobPhase::Interrupted,
+      );
+      record_job_run_finished_best_effort(
+        &waiter_state,
+        &waiter_job_root,
+        recorded_run,
+        exit_status.to_string(),
+        exit_status.code(),
+        RecordedRunOutcome::Interrupted,
+      );
+      return;
+    }
+
+    if exit_status.success() {
+      run_post_run_passes_best_effort(&waiter_state, &waiter_job_root);
+    }
+    if let Some(cancellation) = cancellation.as_mut() {
+      cancellation.requeued_task_count = requeue_cancelled_run_tasks_best_effort(&waiter_state, &waiter_job_root);
+    }
+    // NOTE: Runs while the job still counts as running, so nothing else starts in this root meanwhile.
+    let partial_output_result = is_cancel_requested.then(|| finalize_cancelled_run(&waiter_state, &waiter_job_root));
+    match partial_output_result.as_ref() {
+      Some(Ok(partial_output)) => append_log_line(
+        &waiter_state,
+        &waiter_job_root,
+        format!(
+          "[backend] partial output: {} -> {}",
+          partial_output.describe_progress(),
+          partial_output.output_markdown_path.display()
+        ),
+      ),
+      Some(Err(error)) => append_log_line(
+        &waiter_state,
+        &waiter_job_root,
+        format!("[backend] partial output failed: {error}"),
+      ),
+      None => {}
+    }
+    run_work_cleanup_policy_best_effort(
+      &waiter_state,
+      &waiter_job_root,
+      exit_status.success() && !is_cancel_requested,
+    );
+    send_job_notifications_best_effort(&waiter_state, &waiter_job_root, &exit_status);
 
     let mut locked_state = match waiter_state.lock() {
       Ok(state) => state,
       Err(_) => return,
     };
     locked_state.running_job_by_root.remove(&waiter_job_root);
+    locked_state.job_root_index.invalidate(&waiter_job_root);
 
     let job_state_path = locked_state.job_state_file_path_by_root.remove(&waiter_job_root);
     drop(locked_state);
 
-    // Guard: only watcher-created jobs register a job state path.
-    let Some(job_state_path) = job_state_path else {
+    finish_status_file_writer(
+      &waiter_state,
+      &waiter_job_root,
+      status_file_writer,
+      &status_file_paths,
+      if is_cancel_requested {
+        JobPhase::Cancelled
+      } else if exit_status.success() {
+        JobPhase::Completed
+      } else {
+        JobPhase::Failed
+      },
+    );
+    record_job_run_finished_best_effort(
+      &waiter_state,
+      &waiter_job_root,
+      recorded_run,
+      exit_status.to_string(),
+      exit_status.code(),
+      if is_cancel_requested {
+        RecordedRunOutcome::Cancelled
+      } else if exit_status.success() {
+        RecordedRunOutcome::Completed
+      } else {
+        RecordedRunOutcome::Failed
+      },
+    );
+    // NOTE: Runs after the running entry is removed so a dependent may reuse this job root's slot.
+    advance_job_dependencies_best_effort(
+      &waiter_state,
+      &waiter_job_root,
+      if exit_status.success() { JobRunOutcome::Succeeded } else { JobRunOutcome::Failed },
+    );
+    // NOTE: The freed slot goes to the next queued start in turn; dependents started above join the queue.
+    dispatch_queued_job_starts_best_effort(&waiter_state);
+
+    // Guard: a run whose job state could not be loaded at start has no path registered.
+    if job_state_path.is_none() {
       return;
-    };
+    }
 
     let mut state = read_job_state_best_effort(&waiter_job_root).unwrap_or(JobState {
       status: JobStateStatus::Running,
       job_id: "unknown".to_string(),
       job_root_directory_path: waiter_job_root.to_string_lossy().to_string(),
       source_bundle_directory_path: None,
+      submitter: None,
       accepted_unix_timestamp_millis: now_unix_timestamp_millis(),
       started_unix_timestamp_millis: None,
       finished_unix_timestamp_millis: None,
       output_markdown_path: None,
       error_message: None,
+      translated_output_markdown_path_by_language: BTreeMap::new(),
+      image_digest: None,
+      error_category: None,
+      transitions: vec![],
+      title: None,
+      cancellation: None,
     });
-    state.finished_unix_timestamp_millis = Some(now_unix_timestamp_millis());
+    let next_status = if partial_output_result.is_some() {
+      JobStateStatus::Partial
+    } else if exit_status.success() {
+      JobStateStatus::Completed
+    } else {
+      JobStateStatus::Failed
+    };
+    if let Err(error) = transition_job_state(&waiter_state, &waiter_job_root, &mut state, next_status) {
+      // Guard: never regress a state another writer already finalized.
+      append_log_line(&waiter_state, &waiter_job_root, format!("[job-state] {error}"));
+      return;
+    }
 
-    if exit_status.success() {
-      state.status = JobStateStatus::Completed;
+    state.cancellation = cancellation;
+    if let Some(partial_output_result) = partial_output_result {
+      match partial_output_result {
+        Ok(partial_output) => {
+          state.error_message = Some(format!("Cancelled: {}", partial_output.describe_progress()));
+          state.output_markdown_path = Some(partial_output.output_markdown_path.to_string_lossy().to_string());
+        }
+        Err(error) => state.error_message = Some(format!("Cancelled; partial output could not be written: {error}")),
+      }
+    } else if exit_status.success() {
       state.error_message = None;
       state.output_markdown_path = state
         .output_markdown_path
         .or_else(|| detect_last_output_markdown_path(&waiter_job_root));
     } else {
-      state.status = JobStateStatus::Failed;
       state.error_message = Some(format!("OCR process failed: {exit_status}"));
     }
+    // NOTE: A successful exit can still carry failed pages; their cause is recorded without failing the job.
+    state.error_category = classified_error.as_ref().map(|classified_error| classified_error.category);
+    if let Some(classified_error) = classified_error {
+      state.error_message = Some(format!(
+        "{}: {}",
+        classified_error.category.summary(),
+        classified_error.matched_line
+      ));
+    }
 
     // Guard: best-effort write; never panic from background thread.
-    let _ = fs::write(job_state_path, serde_json::to_string_pretty(&state).unwrap_or_default());
+    if let Err(error) = write_job_state(&waiter_job_root, &state) {
+      append_log_line(&waiter_state, &waiter_job_root, format!("[backend] job state write failed: {error}"));
+    }
   });
 
   Ok(())
 }
 
-fn is_any_job_running(job_runtime_state: &SharedJobRuntimeState) -> bool {
-  let locked = match job_runtime_state.lock() {
-    Ok(value) => value,
-    Err(_) => return true,
-  };
-  !locked.running_job_by_root.is_empty()
+// NOTE: A stopped run leaves the pages it was on `running`; a retry only picks up `pending` ones.
+fn requeue_cancelled_run_tasks_best_effort(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+) -> usize {
+  if let Err(error) = remove_cancel_marker(&job_output_directory_path(job_root_directory_path)) {
+    append_log_line(job_runtime_state, job_root_directory_path, format!("[backend] {error}"));
+  }
+  match requeue_running_tasks(&get_queue_database_path(job_root_directory_path)) {
+    Ok(requeued_task_count) => {
+      if requeued_task_count > 0 {
+        append_log_line(
+          job_runtime_state,
+          job_root_directory_path,
+          format!("[backend] {requeued_task_count} in-flight task(s) set back to pending"),
+        );
+      }
+      requeued_task_count
+    }
+    Err(error) => {
+      append_log_line(
+        job_runtime_state,
+        job_root_directory_path,
+        format!("[backend] requeueing in-flight tasks failed: {error}"),
+      );
+      0
+    }
+  }
 }
 
-fn derive_watch_job_id(source_bundle_directory_path: &Path) -> String {
-  let base = source_bundle_directory_path
-    .file_name()
-    .and_then(|name| name.to_str())
-    .unwrap_or("bundle");
-  let sanitized = base
-    .replace('\\', "_")
-    .replace('/', "_")
-    .replace(':', "_")
-    .replace(' ', "_");
-  format!("{}_{}", now_unix_timestamp_millis(), sanitized)
+struct PartialRunOutput {
+  output_markdown_path: PathBuf,
+  processed_page_count: i64,
+  total_page_count: i64,
 }
 
-fn copy_directory_recursively_with_exclusions(
-  source_directory_path: &Path,
-  destination_directory_path: &Path,
-  excluded_filenames: &[&str],
-) -> Result<u64, String> {
-  if !source_directory_path.exists() {
-    // Guard: do not silently ignore missing paths.
-    return Err(format!(
-      "Input directory does not exist: {}",
-      source_directory_path.display()
-    ));
+impl PartialRunOutput {
+  fn describe_progress(&self) -> String {
+    if self.processed_page_count == 0 {
+      return format!("no pages processed (0 of {})", self.total_page_count);
+    }
+    format!("processed pages 1–{} of {}", self.processed_page_count, self.total_page_count)
   }
-  if !source_directory_path.is_dir() {
-    // Guard: this function only handles directories.
-    return Err(format!("Not a directory: {}", source_directory_path.display()));
+}
+
+// NOTE: Mirrors the CLI's `write_partial_markdown`: only the leading run of completed tasks counts as processed.
+fn query_partial_progress(queue_database_path: &Path) -> Result<(i64, i64), String> {
+  if !queue_database_path.exists() {
+    return Ok((0, 0));
   }
-  fs::create_dir_all(destination_directory_path).map_err(|error| error.to_string())?;
+  let connection = Connection::open(queue_database_path).map_err(|error| error.to_string())?;
+  let mut statement = connection
+    .prepare("SELECT status FROM tasks ORDER BY task_id ASC")
+    .map_err(|error| error.to_string())?;
+  let statuses: Vec<String> = statement
+    .query_map([], |row| row.get::<_, String>(0))
+    .map_err(|error| error.to_string())?
+    .collect::<Result<_, _>>()
+    .map_err(|error| error.to_string())?;
+  let processed_page_count = statuses.iter().take_while(|status| status.as_str() == "completed").count();
+  Ok((processed_page_count as i64, statuses.len() as i64))
+}
 
-  let mut total_copied_files: u64 = 0;
-  for entry in walkdir::WalkDir::new(source_directory_path) {
-    let entry = entry.map_err(|error| error.to_string())?;
-    let entry_path = entry.path();
-    if entry_path.is_dir() {
+// NOTE: Uses the CLI's own merge (`finalize-partial`) so the partial file is post-processed exactly like a full run.
+fn finalize_cancelled_run(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+) -> Result<PartialRunOutput, String> {
+  let repo_root = repo_root_path()?;
+  let job_root_canonical = job_root_directory_path
+    .canonicalize()
+    .map_err(|error| format!("Failed to canonicalize job root: {error}"))?;
+  let job_engine = resolve_job_engine(job_runtime_state, &job_root_canonical)?;
+  let settings = read_job_settings_best_effort(job_root_directory_path);
+  let run_output_filename = settings
+    .last_output_markdown_filename
+    .clone()
+    .ok_or_else(|| "The cancelled run has no output filename.".to_string())?;
+  let partial_output_filename = if settings.is_rolling_output_enabled.unwrap_or(false) {
+    // Guard: never overwrite the cumulative rolling file with one cancelled run.
+    let stem = Path::new(&run_output_filename)
+      .file_stem()
+      .and_then(|stem| stem.to_str())
+      .unwrap_or("output");
+    format!("{stem}.partial.md")
+  } else {
+    run_output_filename
+  };
+
+  let mut finalize_arguments = vec![
+    "finalize-partial".to_string(),
+    "--output-md".to_string(),
+    job_engine.data_path(job_root_directory_path, &partial_output_filename),
+  ];
+  finalize_arguments.extend(job_engine.queue_arguments(job_root_directory_path));
+  let output = job_engine
+    .build_command(&repo_root, &settings, &[finalize_arguments], None)
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .output()
+    .map_err(|error| format!("Failed to start partial output finalization: {error}"))?;
+  if !output.status.success() {
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let last_line = stderr.lines().rev().find(|line| !line.trim().is_empty()).unwrap_or("").to_string();
+    return Err(format!("finalize-partial failed ({}): {last_line}", output.status));
+  }
+
+  let (processed_page_count, total_page_count) =
+    query_partial_progress(&get_queue_database_path(job_root_directory_path))?;
+  Ok(PartialRunOutput {
+    output_markdown_path: job_root_directory_path.join(partial_output_filename),
+    processed_page_count,
+    total_page_count,
+  })
+}
+
+// NOTE: Only lines logged since the previous run finished are considered, so an old failure is not reported again.
+fn classify_finished_run_best_effort(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  exit_status: &ExitStatus,
+) -> Option<ClassifiedJobError> {
+  let failed_task_count = query_status_counts(&get_queue_database_path(job_root_directory_path))
+    .ok()
+    .and_then(|counts| counts.get("failed").copied())
+    .unwrap_or(0);
+  if exit_status.success() && failed_task_count == 0 {
+    return None;
+  }
+  let run_log_lines: Vec<String> = {
+    let locked_state = job_runtime_state.lock().ok()?;
+    let log_lines = locked_state.job_log_store.peek(job_root_directory_path)?;
+    let mut run_log_lines: Vec<String> = log_lines
+      .iter()
+      .rev()
+      .take_while(|line| !line.contains("[backend] finished:"))
+      .cloned()
+      .collect();
+    run_log_lines.reverse();
+    run_log_lines
+  };
+  classify_job_error(&run_log_lines)
+}
+
+fn reset_failed_tasks_to_pending(queue_database_path: &Path) -> Result<usize, String> {
+  let connection = Connection::open(queue_database_path).map_err(|error| error.to_string())?;
+  connection
+    .execute("UPDATE tasks SET status = 'pending', error_message = NULL WHERE status = 'failed'", [])
+    .map_err(|error| error.to_string())
+}
+
+// NOTE: Also resets `running` rows: an OOM or a crash can kill the process mid-page. Only for callers that know no
+// process still owns the queue (the waiter after exit, or resume after its heartbeat check).
+fn reset_interrupted_and_failed_tasks_to_pending(queue_database_path: &Path) -> Result<usize, String> {
+  let connection = Connection::open(queue_database_path).map_err(|error| error.to_string())?;
+  connection
+    .execute(
+      "UPDATE tasks SET status = 'pending', error_message = NULL WHERE status IN ('failed', 'running')",
+      [],
+    )
+    .map_err(|error| error.to_string())
+}
+
+// NOTE: All or nothing: an id that is not a failed task rolls the whole selection back.
+fn reset_selected_failed_tasks_to_pending(queue_database_path: &Path, task_ids: &[i64]) -> Result<usize, String> {
+  let mut unique_task_ids = task_ids.to_vec();
+  unique_task_ids.sort_unstable();
+  unique_task_ids.dedup();
+  let mut connection = Connection::open(queue_database_path).map_err(|error| error.to_string())?;
+  let transaction = connection.transaction().map_err(|error| error.to_string())?;
+  let mut not_failed_task_ids: Vec<String> = vec![];
+  {
+    let mut statement = transaction
+      .prepare("UPDATE tasks SET status = 'pending', error_message = NULL WHERE task_id = ?1 AND status = 'failed'")
+      .map_err(|error| error.to_string())?;
+    for task_id in &unique_task_ids {
+      if statement.execute([task_id]).map_err(|error| error.to_string())? == 0 {
+        not_failed_task_ids.push(task_id.to_string());
+      }
+    }
+  }
+  if !not_failed_task_ids.is_empty() {
+    return Err(format!("Not failed (or unknown) task ids: {}", not_failed_task_ids.join(", ")));
+  }
+  transaction.commit().map_err(|error| error.to_string())?;
+  Ok(unique_task_ids.len())
+}
+
+// NOTE: Returns true when a retry run was started; the caller then leaves finishing the job to that run.
+fn retry_out_of_memory_run_best_effort(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path) -> bool {
+  let mut settings = read_job_settings_best_effort(job_root_directory_path);
+  if !settings.is_oom_auto_retry_enabled.unwrap_or(false) {
+    return false;
+  }
+  let Some(retry_size_pixels) = next_oom_retry_inference_image_size(settings.deepseek_ocr2_inference_image_size_pixels)
+  else {
+    append_log_line(
+      job_runtime_state,
+      job_root_directory_path,
+      "[backend] OOM auto-retry exhausted; no smaller inference image size left".to_string(),
+    );
+    return false;
+  };
+  settings.deepseek_ocr2_inference_image_size_pixels = Some(retry_size_pixels);
+  let retry_result = write_job_settings(job_root_directory_path, &settings)
+    .and_then(|_| reset_interrupted_and_failed_tasks_to_pending(&get_queue_database_path(job_root_directory_path)))
+    .and_then(|reset_task_count| {
+      if reset_task_count == 0 {
+        // Guard: nothing to re-run means the OOM happened before any page was claimed (e.g. while loading the model).
+        return Err("no failed pages to retry".to_string());
+      }
+      if let Ok(mut locked_state) = job_runtime_state.lock() {
+        locked_state.running_job_by_root.remove(job_root_directory_path);
+        locked_state.job_root_index.invalidate(job_root_directory_path);
+      }
+      append_log_line(
+        job_runtime_state,
+        job_root_directory_path,
+        format!("[backend] OOM auto-retry: re-running {reset_task_count} page(s) at inference size {retry_size_pixels}px"),
+      );
+      spawn_job_process_with_mode(
+        job_runtime_state.clone(),
+        job_root_directory_path.to_path_buf(),
+        JobRunMode::RetryFailedTasks,
+      )
+    });
+  if let Err(error) = retry_result {
+    append_log_line(job_runtime_state, job_root_directory_path, format!("[backend] OOM auto-retry skipped: {error}"));
+    return false;
+  }
+  true
+}
+
+// NOTE: Returns true when the job was suspended; the job state and dependents are left for the resumed run.
+fn suspend_job_if_volume_unavailable(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path) -> bool {
+  let volume_kind = job_runtime_state
+    .lock()
+    .ok()
+    .and_then(|locked_state| {
+      locked_state
+        .running_job_by_root
+        .get(job_root_directory_path)
+        .map(|running| running.volume_kind)
+    })
+    .unwrap_or(VolumeKind::Unknown);
+  let Some(reason) = describe_unavailable_volume(job_root_directory_path, volume_kind) else {
+    return false;
+  };
+  if let Ok(mut locked_state) = job_runtime_state.lock() {
+    locked_state.running_job_by_root.remove(job_root_directory_path);
+    locked_state.job_root_index.invalidate(job_root_directory_path);
+    locked_state.suspended_job_by_root.insert(
+      job_root_directory_path.to_path_buf(),
+      SuspendedJob {
+        job_root_directory_path: job_root_directory_path.to_string_lossy().to_string(),
+        cause: SuspensionCause::VolumeUnavailable,
+        volume_kind,
+        reason: reason.clone(),
+        suspended_unix_timestamp_millis: now_unix_timestamp_millis(),
+      },
+    );
+  }
+  append_log_line(
+    job_runtime_state,
+    job_root_directory_path,
+    format!("[backend] job suspended: {reason}; it resumes when the volume is back"),
+  );
+  spawn_suspended_job_monitor(job_runtime_state.clone(), job_root_directory_path.to_path_buf());
+  true
+}
+
+// NOTE: Returns true when the job was interrupted; like a suspension, dependents are left for the resumed run.
+fn interrupt_job_if_docker_engine_disconnected(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  classified_error: Option<&ClassifiedJobError>,
+) -> bool {
+  let is_docker_engine = job_runtime_state
+    .lock()
+    .ok()
+    .and_then(|locked_state| {
+      locked_state
+        .running_job_by_root
+        .get(job_root_directory_path)
+        .map(|running| running.is_docker_engine)
+    })
+    .unwrap_or(false);
+  if !is_docker_engine {
+    return false;
+  }
+  let is_disconnect_logged =
+    classified_error.is_some_and(|classified_error| classified_error.category == JobErrorCategory::DockerEngineDisconnected);
+  // NOTE: The client does not always print why it died; an engine that is still unreachable now tells the same story.
+  if !is_disconnect_logged && validate_docker_available().is_ok() {
+    return false;
+  }
+
+  // NOTE: Pages cut off mid-inference are left `running`; put them back so the queue does not show stale work.
+  let queue_database_path = get_queue_database_path(job_root_directory_path);
+  if queue_database_path.exists() {
+    if let Err(error) = reset_interrupted_and_failed_tasks_to_pending(&queue_database_path) {
+      append_log_line(
+        job_runtime_state,
+        job_root_directory_path,
+        format!("[backend] resetting interrupted pages failed: {error}"),
+      );
+    }
+  }
+  if let Ok(mut locked_state) = job_runtime_state.lock() {
+    locked_state.running_job_by_root.remove(job_root_directory_path);
+    locked_state.job_root_index.invalidate(job_root_directory_path);
+    locked_state.suspended_job_by_root.insert(
+      job_root_directory_path.to_path_buf(),
+      SuspendedJob {
+        job_root_directory_path: job_root_directory_path.to_string_lossy().to_string(),
+        cause: SuspensionCause::DockerEngineUnavailable,
+        volume_kind: VolumeKind::Unknown,
+        reason: JobErrorCategory::DockerEngineDisconnected.summary().to_string(),
+        suspended_unix_timestamp_millis: now_unix_timestamp_millis(),
+      },
+    );
+  }
+  transition_job_state_best_effort(job_runtime_state, job_root_directory_path, JobStateStatus::Interrupted, |state| {
+    state.error_message = Some(JobErrorCategory::DockerEngineDisconnected.summary().to_string());
+    state.error_category = Some(JobErrorCategory::DockerEngineDisconnected);
+  });
+  append_log_line(
+    job_runtime_state,
+    job_root_directory_path,
+    "[backend] job interrupted: Docker engine disconnected; it resumes when Docker is available again".to_string(),
+  );
+  spawn_suspended_job_monitor(job_runtime_state.clone(), job_root_directory_path.to_path_buf());
+  true
+}
+
+fn spawn_suspended_job_monitor(job_runtime_state: SharedJobRuntimeState, job_root_directory_path: PathBuf) {
+  thread::spawn(move || loop {
+    thread::sleep(SUSPENDED_JOB_RESUME_POLL_INTERVAL);
+    let suspension_cause = job_runtime_state.lock().ok().and_then(|locked_state| {
+      locked_state
+        .suspended_job_by_root
+        .get(&job_root_directory_path)
+        .map(|suspended_job| suspended_job.cause)
+    });
+    let Some(suspension_cause) = suspension_cause else {
+      // NOTE: Started or cancelled by hand in the meantime.
+      return;
+    };
+    // NOTE: A resume is new work for the GPU, so it waits until maintenance mode is turned off.
+    if !job_root_directory_path.is_dir() || read_maintenance_mode(&job_runtime_state).is_some() {
       continue;
     }
-    let file_name = entry_path.file_name().and_then(|name| name.to_str()).unwrap_or("");
-    if excluded_filenames.contains(&file_name) {
+    if suspension_cause == SuspensionCause::DockerEngineUnavailable && validate_docker_available().is_err() {
       continue;
     }
-    let relative_path = entry_path
-      .strip_prefix(source_directory_path)
-      .map_err(|error| error.to_string())?;
-    let destination_path = destination_directory_path.join(relative_path);
-    if let Some(parent_directory_path) = destination_path.parent() {
-      fs::create_dir_all(parent_directory_path).map_err(|error| error.to_string())?;
+    resume_suspended_job(&job_runtime_state, &job_root_directory_path, suspension_cause);
+    return;
+  });
+}
+
+fn resume_suspended_job(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  suspension_cause: SuspensionCause,
+) {
+  if let Ok(mut locked_state) = job_runtime_state.lock() {
+    locked_state.suspended_job_by_root.remove(job_root_directory_path);
+  }
+  let queue_database_path = get_queue_database_path(job_root_directory_path);
+  // NOTE: Pages cut off by the disappearance were left `running`; the retry run picks them up with the failed ones.
+  let resume_result = if queue_database_path.exists() {
+    reset_interrupted_and_failed_tasks_to_pending(&queue_database_path).and_then(|_| {
+      spawn_job_process_with_mode(
+        job_runtime_state.clone(),
+        job_root_directory_path.to_path_buf(),
+        JobRunMode::RetryFailedTasks,
+      )
+    })
+  } else {
+    spawn_job_process(job_runtime_state.clone(), job_root_directory_path.to_path_buf(), JobStartSource::Resume)
+      .map(|_| ())
+  };
+  append_log_line(
+    job_runtime_state,
+    job_root_directory_path,
+    match resume_result {
+      Ok(()) => match suspension_cause {
+        SuspensionCause::VolumeUnavailable => "[backend] volume is back; resuming suspended job".to_string(),
+        SuspensionCause::DockerEngineUnavailable => "[backend] Docker is available again; resuming interrupted job".to_string(),
+      },
+      Err(error) => format!("[backend] resuming suspended job failed: {error}"),
+    },
+  );
+}
+
+fn run_structured_extraction(job_root_directory_path: &Path, settings: &JobSettings) -> Result<Vec<PathBuf>, String> {
+  let queue_database_path = get_queue_database_path(job_root_directory_path);
+  let documents = load_document_markdowns(job_root_directory_path, &queue_database_path)?;
+  let schema = settings.structured_extraction_schema.clone().unwrap_or_default();
+  let structured_output_directory_path =
+    job_output_directory_path(job_root_directory_path).join(STRUCTURED_OUTPUT_DIRECTORY_NAME);
+  extract_structured_documents(&documents, &schema, &structured_output_directory_path)
+}
+
+fn run_translation(
+  job_root_directory_path: &Path,
+  settings: &JobSettings,
+  target_language: &str,
+) -> Result<PathBuf, String> {
+  let endpoint = settings
+    .llm_endpoint
+    .as_ref()
+    .ok_or_else(|| "Translation requires an LLM endpoint in job settings (llm_endpoint).".to_string())?;
+  let source_markdown_path = detect_last_output_markdown_path(job_root_directory_path)
+    .map(PathBuf::from)
+    .ok_or_else(|| "No output markdown found for this job yet.".to_string())?;
+  let output_directory_path = job_output_directory_path(job_root_directory_path);
+  let translated_path = translate_markdown_file(endpoint, target_language, &source_markdown_path, &output_directory_path)?;
+
+  let translated_path_string = translated_path.to_string_lossy().to_string();
+  let language_key = target_language.trim().to_string();
+  update_job_state_best_effort(job_root_directory_path, |state| {
+    state
+      .translated_output_markdown_path_by_language
+      .insert(language_key, translated_path_string);
+  });
+  Ok(translated_path)
+}
+
+fn run_summarization(
+  job_root_directory_path: &Path,
+  settings: &JobSettings,
+  mode: SummarizationMode,
+) -> Result<PathBuf, String> {
+  let endpoint = settings
+    .llm_endpoint
+    .as_ref()
+    .ok_or_else(|| "Summarization requires an LLM endpoint in job settings (llm_endpoint).".to_string())?;
+  let source_markdown_path = detect_last_output_markdown_path(job_root_directory_path)
+    .map(PathBuf::from)
+    .ok_or_else(|| "No output markdown found for this job yet.".to_string())?;
+  let output_directory_path = job_output_directory_path(job_root_directory_path);
+  let summary_path = summarize_markdown_file(endpoint, mode, &source_markdown_path, &output_directory_path)?;
+
+  let summary_path_string = summary_path.to_string_lossy().to_string();
+  update_job_report(&output_directory_path, |report| {
+    report.summary_markdown_path = Some(summary_path_string);
+  })?;
+  Ok(summary_path)
+}
+
+fn run_embedding_indexing(job_root_directory_path: &Path, endpoint: &LlmEndpointSettings) -> Result<usize, String> {
+  let queue_database_path = get_queue_database_path(job_root_directory_path);
+  let documents = load_document_markdowns(job_root_directory_path, &queue_database_path)?;
+  let embeddings_database_path = job_settings_directory_path(job_root_directory_path).join(EMBEDDINGS_DATABASE_FILENAME);
+  index_document_embeddings(endpoint, &documents, &embeddings_database_path)
+}
+
+fn run_spellcheck(job_root_directory_path: &Path, spellcheck_settings: &SpellcheckSettings) -> Result<SpellcheckReport, String> {
+  let markdown_path = detect_last_output_markdown_path(job_root_directory_path)
+    .map(PathBuf::from)
+    .ok_or_else(|| "No output markdown found for this job yet.".to_string())?;
+  let output_directory_path = job_output_directory_path(job_root_directory_path);
+  let report_path = output_directory_path.join(SPELLCHECK_REPORT_FILENAME);
+  let report = spellcheck_markdown_file(spellcheck_settings, &markdown_path, &report_path)?;
+
+  let report_path_string = report_path.to_string_lossy().to_string();
+  update_job_report(&output_directory_path, |job_report| {
+    job_report.spellcheck_report_path = Some(report_path_string);
+  })?;
+  Ok(report)
+}
+
+// NOTE: Returns (formula count, warning count), or None when the engine wrote no math report.
+fn run_math_report(job_root_directory_path: &Path) -> Result<Option<(usize, usize)>, String> {
+  let output_directory_path = job_output_directory_path(job_root_directory_path);
+  let Some(math_report) = read_math_report(&output_directory_path)? else {
+    return Ok(None);
+  };
+  let counts = (math_report.formula_count, math_report.warnings.len());
+  let mathml_export_path = math_report
+    .mathml_filename
+    .as_deref()
+    .map(|filename| output_directory_path.join(filename).to_string_lossy().to_string());
+  update_job_report(&output_directory_path, |job_report| {
+    job_report.math_formula_count = Some(math_report.formula_count);
+    job_report.math_warnings = math_report.warnings;
+    job_report.mathml_export_path = mathml_export_path;
+  })?;
+  if let Some(export_error_message) = math_report.export_error_message {
+    return Err(export_error_message);
+  }
+  Ok(Some(counts))
+}
+
+// NOTE: Returns (checked page count, issue count), or None when the engine wrote no validation report.
+fn run_markdown_validation_report(job_root_directory_path: &Path) -> Result<Option<(usize, usize)>, String> {
+  let output_directory_path = job_output_directory_path(job_root_directory_path);
+  let Some(validation_report) = read_markdown_validation_report(&output_directory_path)? else {
+    return Ok(None);
+  };
+  let counts = (validation_report.checked_page_count, validation_report.issues.len());
+  update_job_report(&output_directory_path, |job_report| {
+    job_report.markdown_issues = validation_report.issues;
+  })?;
+  Ok(Some(counts))
+}
+
+fn run_title_detection(job_root_directory_path: &Path) -> Result<Option<DetectedDocumentTitle>, String> {
+  let markdown_path = detect_last_output_markdown_path(job_root_directory_path)
+    .map(PathBuf::from)
+    .ok_or_else(|| "No output markdown found for this job yet.".to_string())?;
+  let markdown = fs::read_to_string(&markdown_path).map_err(|error| error.to_string())?;
+  let detected_title = detect_document_title(&markdown);
+  let recorded_title = detected_title.clone();
+  update_job_report(&job_output_directory_path(job_root_directory_path), |job_report| {
+    job_report.detected_title = recorded_title;
+  })?;
+  Ok(detected_title)
+}
+
+// NOTE: The run already wrote (and signed) its provenance manifest; a rename afterwards must carry it along, or
+// `verify_job_integrity` reports a normal app action as tampering. `job_state.json` is re-signed by its own write.
+fn resign_after_output_rename(
+  job_root_directory_path: &Path,
+  settings: &JobSettings,
+  previous_output_markdown_path: &Path,
+  output_markdown_path: &Path,
+) -> Result<(), String> {
+  let is_signing_enabled = settings.is_integrity_signing_enabled.unwrap_or(false);
+  let manifest_path = read_job_report_best_effort(&job_output_directory_path(job_root_directory_path))
+    .provenance_manifest_path
+    .map(PathBuf::from)
+    .filter(|manifest_path| manifest_path.is_file());
+  if let Some(manifest_path) = manifest_path {
+    let is_manifest_updated = previous_output_markdown_path != output_markdown_path
+      && rename_provenance_output(
+        job_root_directory_path,
+        &manifest_path,
+        previous_output_markdown_path,
+        output_markdown_path,
+      )?;
+    if is_manifest_updated && is_signing_enabled {
+      sign_file(&manifest_path)?;
+    }
+  }
+  Ok(())
+}
+
+fn apply_detected_title_to_output(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+) -> Result<AppliedDocumentTitle, String> {
+  let is_running = job_runtime_state
+    .lock()
+    .map(|locked_state| locked_state.running_job_by_root.contains_key(job_root_directory_path))
+    .unwrap_or(true);
+  if is_running {
+    // Guard: the running CLI still writes to the current filename.
+    return Err("Wait for the running job to finish before renaming its output.".to_string());
+  }
+  let mut settings = read_job_settings_best_effort(job_root_directory_path);
+  if settings.is_rolling_output_enabled.unwrap_or(false) {
+    // Guard: later runs append to the cumulative file by its configured name.
+    return Err("Rolling jobs keep one cumulative file; set output_markdown_filename_override to rename it.".to_string());
+  }
+  let previous_output_filename = settings
+    .last_output_markdown_filename
+    .clone()
+    .ok_or_else(|| "No output markdown found for this job yet.".to_string())?;
+  let previous_output_markdown_path = job_root_directory_path.join(&previous_output_filename);
+  let markdown = fs::read_to_string(&previous_output_markdown_path).map_err(|error| error.to_string())?;
+  let detected_title =
+    detect_document_title(&markdown).ok_or_else(|| "No title found in the output markdown.".to_string())?;
+  let filename_stem = title_to_filename_stem(&detected_title.title)
+    .ok_or_else(|| "The detected title has no characters usable in a filename.".to_string())?;
+  let desired_output_filename = ensure_markdown_extension(&filename_stem);
+
+  let is_renamed = desired_output_filename != previous_output_filename;
+  let output_markdown_path = if is_renamed {
+    let output_markdown_path = derive_non_conflicting_markdown_output_path(job_root_directory_path, &desired_output_filename)?;
+    fs::rename(&previous_output_markdown_path, &output_markdown_path).map_err(|error| error.to_string())?;
+    output_markdown_path
+  } else {
+    previous_output_markdown_path.clone()
+  };
+  let output_markdown_filename = output_markdown_path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .ok_or_else(|| "Failed to derive output markdown filename".to_string())?
+    .to_string();
+  settings.last_output_markdown_filename = Some(output_markdown_filename);
+  write_job_settings(job_root_directory_path, &settings)?;
+
+  let previous_output_markdown_path_string = previous_output_markdown_path.to_string_lossy().to_string();
+  let output_markdown_path_string = output_markdown_path.to_string_lossy().to_string();
+  rename_job_run_output(
+    job_root_directory_path,
+    &previous_output_markdown_path_string,
+    &output_markdown_path_string,
+  )?;
+  rename_job_history_output(
+    &resolve_app_config_directory_path(job_runtime_state)?,
+    job_root_directory_path,
+    &previous_output_markdown_path_string,
+    &output_markdown_path_string,
+  )?;
+  let title = detected_title.title.clone();
+  update_job_state_best_effort(job_root_directory_path, |state| {
+    if state.output_markdown_path.as_deref() == Some(previous_output_markdown_path_string.as_str()) {
+      state.output_markdown_path = Some(output_markdown_path_string.clone());
+    }
+    state.title = Some(title);
+  });
+  let recorded_title = detected_title.clone();
+  update_job_report(&job_output_directory_path(job_root_directory_path), |job_report| {
+    job_report.detected_title = Some(recorded_title);
+  })?;
+  resign_after_output_rename(job_root_directory_path, &settings, &previous_output_markdown_path, &output_markdown_path)?;
+  Ok(AppliedDocumentTitle {
+    detected_title,
+    previous_output_markdown_path: previous_output_markdown_path_string,
+    output_markdown_path: output_markdown_path_string,
+    is_renamed,
+  })
+}
+
+fn run_packaging(job_root_directory_path: &Path) -> Result<(PathBuf, usize), String> {
+  let markdown_path = detect_last_output_markdown_path(job_root_directory_path)
+    .map(PathBuf::from)
+    .ok_or_else(|| "No output markdown found for this job yet.".to_string())?;
+  let output_directory_path = job_output_directory_path(job_root_directory_path);
+  let archive_path = job_root_directory_path.join(PACKAGE_ARCHIVE_FILENAME);
+  let packaged_file_count = package_job_outputs(&markdown_path, &output_directory_path, &archive_path)?;
+
+  let archive_path_string = archive_path.to_string_lossy().to_string();
+  update_job_report(&output_directory_path, |job_report| {
+    job_report.package_archive_path = Some(archive_path_string);
+  })?;
+  Ok((archive_path, packaged_file_count))
+}
+
+// NOTE: Final outputs are the merged markdown plus every artifact a post-run pass recorded in the job report.
+fn collect_final_output_paths(job_root_directory_path: &Path) -> Result<Vec<PathBuf>, String> {
+  let markdown_path = detect_last_output_markdown_path(job_root_directory_path)
+    .map(PathBuf::from)
+    .ok_or_else(|| "No output markdown found for this job yet.".to_string())?;
+  let job_report = read_job_report_best_effort(&job_output_directory_path(job_root_directory_path));
+  let mut output_paths: Vec<PathBuf> = vec![markdown_path];
+  output_paths.extend(
+    [
+      job_report.summary_markdown_path,
+      job_report.spellcheck_report_path,
+      job_report.mathml_export_path,
+      job_report.provenance_manifest_path,
+      job_report.package_archive_path,
+    ]
+    .into_iter()
+    .flatten()
+    .chain(job_report.rendered_template_output_paths)
+    .chain(job_report.plugin_export_paths)
+    .map(PathBuf::from),
+  );
+  // Guard: an artifact removed since its pass ran is skipped rather than failing every destination.
+  output_paths.retain(|path| path.is_file());
+  output_paths.sort();
+  output_paths.dedup();
+  Ok(output_paths)
+}
+
+fn run_provenance_manifest(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  settings: &JobSettings,
+) -> Result<PathBuf, String> {
+  let output_directory_path = job_output_directory_path(job_root_directory_path);
+  let run_started_unix_timestamp_millis = job_runtime_state.lock().ok().and_then(|locked_state| {
+    locked_state
+      .running_job_by_root
+      .get(job_root_directory_path)
+      .map(|running| running.start_unix_timestamp_millis)
+  });
+  let mut settings_value = serde_json::to_value(settings).map_err(|error| error.to_string())?;
+  if let Some(settings_object) = settings_value.as_object_mut() {
+    // Guard: webhook URLs and SMTP settings are credentials, not provenance.
+    settings_object.remove("notification_targets");
+  }
+
+  let input_paths = list_files_recursively(&job_input_directory_path(job_root_directory_path), &[]);
+  let mut output_paths = list_files_recursively(&output_directory_path, &["work", PROVENANCE_DIRECTORY_NAME]);
+  output_paths.extend(collect_final_output_paths(job_root_directory_path)?);
+  output_paths.sort();
+  output_paths.dedup();
+  // NOTE: The job report keeps changing after this pass (package path, deliveries), so its hash would never match.
+  let job_report_path = job_report_file_path(&output_directory_path);
+  output_paths.retain(|path| *path != job_report_path);
+
+  let job_report = read_job_report_best_effort(&output_directory_path);
+  let manifest = ProvenanceManifest {
+    schema_version: PROVENANCE_SCHEMA_VERSION,
+    job_root_directory_path: job_root_directory_path.to_string_lossy().to_string(),
+    run_started_unix_timestamp_millis,
+    generated_unix_timestamp_millis: now_unix_timestamp_millis(),
+    engine: ProvenanceEngine {
+      kind: read_execution_engine_settings_best_effort(job_runtime_state).kind,
+      image_digest: job_report.image_digest,
+      model_revision: settings.deepseek_ocr2_model_revision.clone(),
+      app_version: env!("CARGO_PKG_VERSION").to_string(),
+    },
+    host: current_host(),
+    settings: settings_value,
+    inputs: hash_files(job_root_directory_path, &input_paths)?,
+    outputs: hash_files(job_root_directory_path, &output_paths)?,
+    pages: load_provenance_pages(job_root_directory_path, &get_queue_database_path(job_root_directory_path))?,
+  };
+  let manifest_path = write_provenance_manifest(&output_directory_path, &manifest)?;
+  if settings.is_integrity_signing_enabled.unwrap_or(false) {
+    sign_file(&manifest_path)?;
+  }
+  let manifest_path_string = manifest_path.to_string_lossy().to_string();
+  update_job_report(&output_directory_path, |job_report| {
+    job_report.provenance_manifest_path = Some(manifest_path_string);
+  })?;
+  Ok(manifest_path)
+}
+
+fn run_output_delivery(
+  job_root_directory_path: &Path,
+  destinations: &[OutputDestination],
+) -> Result<Vec<OutputDestinationResult>, String> {
+  let output_paths = collect_final_output_paths(job_root_directory_path)?;
+  let results: Vec<OutputDestinationResult> = destinations
+    .iter()
+    .map(|destination| deliver_outputs_to_destination(job_root_directory_path, &output_paths, destination))
+    .collect();
+  let recorded_results = results.clone();
+  update_job_report(&job_output_directory_path(job_root_directory_path), |job_report| {
+    for result in recorded_results {
+      job_report
+        .output_destination_results
+        .retain(|existing| existing.directory_path != result.directory_path);
+      job_report.output_destination_results.push(result);
+    }
+  })?;
+  Ok(results)
+}
+
+fn resolve_app_config_directory_path(job_runtime_state: &SharedJobRuntimeState) -> Result<PathBuf, String> {
+  let locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+  locked_state
+    .app_config_directory_path
+    .clone()
+    .ok_or_else(|| "App config directory is not available.".to_string())
+}
+
+fn resolve_templates_directory_path(job_runtime_state: &SharedJobRuntimeState) -> Result<PathBuf, String> {
+  Ok(resolve_app_config_directory_path(job_runtime_state)?.join(TEMPLATES_DIRECTORY_NAME))
+}
+
+fn resolve_plugins_directory_path(job_runtime_state: &SharedJobRuntimeState) -> Result<PathBuf, String> {
+  Ok(resolve_app_config_directory_path(job_runtime_state)?.join(PLUGINS_DIRECTORY_NAME))
+}
+
+fn run_plugins_of_kind(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  enabled_plugin_names: &[String],
+  kind: PluginKind,
+) -> Result<Vec<PluginRunResult>, String> {
+  let manifests = discover_plugins(&resolve_plugins_directory_path(job_runtime_state)?)?;
+  let selected_manifests = select_enabled_plugins(manifests, enabled_plugin_names, kind);
+  if selected_manifests.is_empty() {
+    return Ok(vec![]);
+  }
+  let markdown_path = detect_last_output_markdown_path(job_root_directory_path)
+    .map(PathBuf::from)
+    .ok_or_else(|| "No output markdown found for this job yet.".to_string())?;
+  let output_directory_path = job_output_directory_path(job_root_directory_path);
+
+  let log_sink: PluginLogSink = make_job_log_sink(job_runtime_state, job_root_directory_path);
+  let mut results: Vec<PluginRunResult> = vec![];
+  for manifest in &selected_manifests {
+    let result = run_plugin(
+      manifest,
+      job_root_directory_path,
+      &markdown_path,
+      &output_directory_path,
+      log_sink.clone(),
+    )?;
+    results.push(result);
+  }
+
+  let written_paths: Vec<String> = results.iter().flat_map(|result| result.written_paths.clone()).collect();
+  if !written_paths.is_empty() {
+    update_job_report(&output_directory_path, |job_report| {
+      for written_path in written_paths {
+        if !job_report.plugin_export_paths.contains(&written_path) {
+          job_report.plugin_export_paths.push(written_path);
+        }
+      }
+    })?;
+  }
+  Ok(results)
+}
+
+fn log_plugin_results_best_effort(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  result: Result<Vec<PluginRunResult>, String>,
+) {
+  match result {
+    Ok(results) => {
+      for result in results {
+        append_log_line(
+          job_runtime_state,
+          job_root_directory_path,
+          format!(
+            "[backend] plugin {}: markdown rewritten={}, {} file(s) written",
+            result.plugin_name,
+            result.is_markdown_rewritten,
+            result.written_paths.len()
+          ),
+        );
+      }
+    }
+    Err(error) => append_log_line(
+      job_runtime_state,
+      job_root_directory_path,
+      format!("[backend] plugin run failed: {error}"),
+    ),
+  }
+}
+
+fn run_template_rendering(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  template_names: &[String],
+) -> Result<Vec<PathBuf>, String> {
+  let templates_directory_path = resolve_templates_directory_path(job_runtime_state)?;
+  let markdown_path = detect_last_output_markdown_path(job_root_directory_path)
+    .map(PathBuf::from)
+    .ok_or_else(|| "No output markdown found for this job yet.".to_string())?;
+  let markdown = fs::read_to_string(&markdown_path).map_err(|error| error.to_string())?;
+  let queue_database_path = get_queue_database_path(job_root_directory_path);
+  let context = TemplateRenderContext {
+    job_name: job_root_directory_path
+      .file_name()
+      .and_then(|name| name.to_str())
+      .unwrap_or("job")
+      .to_string(),
+    job_root_directory_path: job_root_directory_path.to_string_lossy().to_string(),
+    generated_unix_timestamp_millis: now_unix_timestamp_millis(),
+    markdown,
+    documents: load_document_markdowns(job_root_directory_path, &queue_database_path)?,
+  };
+
+  let output_directory_path = job_output_directory_path(job_root_directory_path);
+  let written_paths = render_templates(
+    &templates_directory_path,
+    template_names,
+    &context,
+    &output_directory_path.join(RENDERED_OUTPUT_DIRECTORY_NAME),
+  )?;
+
+  let written_path_strings: Vec<String> = written_paths
+    .iter()
+    .map(|path| path.to_string_lossy().to_string())
+    .collect();
+  update_job_report(&output_directory_path, |job_report| {
+    for written_path_string in written_path_strings {
+      if !job_report.rendered_template_output_paths.contains(&written_path_string) {
+        job_report.rendered_template_output_paths.push(written_path_string);
+      }
+    }
+  })?;
+  Ok(written_paths)
+}
+
+fn global_notification_settings_file_path(job_runtime_state: &SharedJobRuntimeState) -> Result<PathBuf, String> {
+  Ok(resolve_app_config_directory_path(job_runtime_state)?.join(GLOBAL_NOTIFICATION_SETTINGS_FILENAME))
+}
+
+fn read_global_notification_targets(job_runtime_state: &SharedJobRuntimeState) -> Result<Vec<NotificationTarget>, String> {
+  let settings_path = global_notification_settings_file_path(job_runtime_state)?;
+  if !settings_path.exists() {
+    return Ok(vec![]);
+  }
+  let raw = fs::read_to_string(&settings_path).map_err(|error| error.to_string())?;
+  serde_json::from_str::<Vec<NotificationTarget>>(&raw)
+    .map_err(|error| format!("Invalid notification settings {}: {error}", settings_path.display()))
+}
+
+fn submitter_routing_file_path(job_runtime_state: &SharedJobRuntimeState) -> Result<PathBuf, String> {
+  Ok(resolve_app_config_directory_path(job_runtime_state)?.join(SUBMITTER_ROUTING_FILENAME))
+}
+
+// NOTE: Ok(None) for jobs without a recorded submitter or whose submitter has no route.
+fn resolve_job_submitter_route(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+) -> Result<Option<SubmitterRoute>, String> {
+  let Some(submitter) = read_job_state_best_effort(job_root_directory_path).and_then(|state| state.submitter) else {
+    return Ok(None);
+  };
+  let routes = read_submitter_routes(&submitter_routing_file_path(job_runtime_state)?)?;
+  Ok(find_submitter_route(routes, &submitter))
+}
+
+fn build_job_notification(job_root_directory_path: &Path, exit_status: &ExitStatus) -> JobNotification {
+  let queue_database_path = get_queue_database_path(job_root_directory_path);
+  let status_counts = query_status_counts(&queue_database_path).unwrap_or_default();
+  let completed_tasks = status_counts.get("completed").copied().unwrap_or(0);
+  let failed_tasks = status_counts.get("failed").copied().unwrap_or(0);
+  let error_message = if exit_status.success() {
+    query_last_error_message(&queue_database_path).ok().flatten()
+  } else {
+    Some(format!("OCR process failed: {exit_status}"))
+  };
+  let job_state = read_job_state_best_effort(job_root_directory_path);
+  JobNotification {
+    job_name: job_root_directory_path
+      .file_name()
+      .and_then(|name| name.to_str())
+      .unwrap_or("job")
+      .to_string(),
+    job_id: job_state.as_ref().map(|state| state.job_id.clone()),
+    submitter: job_state.and_then(|state| state.submitter),
+    job_root_directory_path: job_root_directory_path.to_string_lossy().to_string(),
+    is_success: exit_status.success(),
+    summary: format!("{completed_tasks} task(s) completed, {failed_tasks} failed"),
+    output_markdown_path: detect_last_output_markdown_path(job_root_directory_path),
+    error_message,
+  }
+}
+
+fn job_dependency_graph_file_path(job_runtime_state: &SharedJobRuntimeState) -> Result<PathBuf, String> {
+  Ok(resolve_app_config_directory_path(job_runtime_state)?.join(JOB_DEPENDENCIES_FILENAME))
+}
+
+fn job_dependency_key(job_root_directory_path: &Path) -> String {
+  job_root_directory_path.to_string_lossy().to_string()
+}
+
+fn record_job_dependency_run_started_best_effort(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path) {
+  let result = job_dependency_graph_file_path(job_runtime_state).and_then(|graph_file_path| {
+    update_job_dependency_graph(&graph_file_path, |graph| {
+      graph.record_run_started(&job_dependency_key(job_root_directory_path));
+      Ok(())
+    })
+  });
+  if let Err(error) = result {
+    append_log_line(
+      job_runtime_state,
+      job_root_directory_path,
+      format!("[backend] job dependency update failed: {error}"),
+    );
+  }
+}
+
+fn start_ready_dependent_jobs(
+  job_runtime_state: &SharedJobRuntimeState,
+  graph_file_path: &Path,
+  ready_job_roots: Vec<String>,
+) -> Result<(), String> {
+  let mut error_messages: Vec<String> = vec![];
+  for ready_job_root in ready_job_roots {
+    let ready_job_root_path = PathBuf::from(&ready_job_root);
+    let start_result = prepare_job_directory(job_runtime_state, &ready_job_root_path)
+      .and_then(|()| {
+        spawn_job_process(job_runtime_state.clone(), ready_job_root_path.clone(), JobStartSource::JobDependency)
+      });
+    match start_result {
+      Ok(JobStartOutcome::Started) => append_log_line(
+        job_runtime_state,
+        &ready_job_root_path,
+        "[backend] started: all prerequisite jobs completed".to_string(),
+      ),
+      Ok(JobStartOutcome::Queued { .. }) => append_log_line(
+        job_runtime_state,
+        &ready_job_root_path,
+        "[backend] all prerequisite jobs completed; waiting in the job queue".to_string(),
+      ),
+      Err(error) => {
+        // NOTE: A dependent that cannot start blocks its own dependents the same way a failed run does.
+        let reason = format!("Failed to start after prerequisites completed: {error}");
+        update_job_dependency_graph(graph_file_path, |graph| {
+          graph.mark_blocked(&ready_job_root, reason.clone());
+          graph.record_run_finished(&ready_job_root, JobRunOutcome::Failed);
+          Ok(())
+        })?;
+        error_messages.push(format!("{ready_job_root}: {error}"));
+      }
+    }
+  }
+  if error_messages.is_empty() {
+    return Ok(());
+  }
+  Err(error_messages.join("; "))
+}
+
+fn advance_job_dependencies_best_effort(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  outcome: JobRunOutcome,
+) {
+  let result = job_dependency_graph_file_path(job_runtime_state).and_then(|graph_file_path| {
+    let is_maintenance_mode_on = read_maintenance_mode(job_runtime_state).is_some();
+    let ready_job_roots = update_job_dependency_graph(&graph_file_path, |graph| {
+      graph.record_run_finished(&job_dependency_key(job_root_directory_path), outcome);
+      // NOTE: During maintenance, dependents stay waiting; leaving maintenance mode starts them.
+      Ok(if is_maintenance_mode_on { vec![] } else { graph.take_ready_jobs() })
+    })?;
+    start_ready_dependent_jobs(job_runtime_state, &graph_file_path, ready_job_roots)
+  });
+  if let Err(error) = result {
+    append_log_line(
+      job_runtime_state,
+      job_root_directory_path,
+      format!("[backend] dependent job start failed: {error}"),
+    );
+  }
+}
+
+fn send_job_notifications_best_effort(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  exit_status: &ExitStatus,
+) {
+  let settings = read_job_settings_best_effort(job_root_directory_path);
+  let submitter_route = match resolve_job_submitter_route(job_runtime_state, job_root_directory_path) {
+    Ok(route) => route,
+    Err(error) => {
+      // NOTE: The default targets still go out, so a broken routing file does not silence every job.
+      append_log_line(
+        job_runtime_state,
+        job_root_directory_path,
+        format!("[backend] submitter routing unavailable: {error}"),
+      );
+      None
+    }
+  };
+  let is_default_notification_skipped = submitter_route
+    .as_ref()
+    .is_some_and(|route| route.is_default_notification_skipped);
+  let mut targets = if is_default_notification_skipped {
+    vec![]
+  } else {
+    match settings.notification_targets {
+      Some(targets) => targets,
+      None => match read_global_notification_targets(job_runtime_state) {
+        Ok(targets) => targets,
+        Err(error) => {
+          append_log_line(
+            job_runtime_state,
+            job_root_directory_path,
+            format!("[backend] notification settings unavailable: {error}"),
+          );
+          vec![]
+        }
+      },
+    }
+  };
+  if let Some(route) = submitter_route {
+    targets.extend(route.notification_targets);
+  }
+  if targets.is_empty() {
+    return;
+  }
+
+  let notification = build_job_notification(job_root_directory_path, exit_status);
+  for (channel_name, result) in send_job_notifications(&targets, &notification) {
+    let message = match result {
+      Ok(()) => format!("[backend] notification sent: {channel_name}"),
+      Err(error) => format!("[backend] notification failed ({channel_name}): {error}"),
+    };
+    append_log_line(job_runtime_state, job_root_directory_path, message);
+  }
+}
+
+fn job_work_directory_path(job_root_directory_path: &Path) -> PathBuf {
+  job_output_directory_path(job_root_directory_path).join("work")
+}
+
+// NOTE: Runs while the job still counts as running, so a new run cannot be rendering into `work/` meanwhile.
+fn run_work_cleanup_policy_best_effort(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  is_successful_run: bool,
+) {
+  let settings = read_job_settings_best_effort(job_root_directory_path);
+  let min_age = match settings.work_cleanup_policy.unwrap_or_default() {
+    WorkCleanupPolicy::KeepAlways => return,
+    WorkCleanupPolicy::DeleteOnSuccess if !is_successful_run => return,
+    WorkCleanupPolicy::DeleteOnSuccess => None,
+    WorkCleanupPolicy::KeepDays => Some(retention_days_to_min_age(settings.work_retention_days.unwrap_or(0))),
+  };
+  let message = match clean_work_directory(&job_work_directory_path(job_root_directory_path), min_age) {
+    Ok(summary) if summary.deleted_file_count == 0 => return,
+    Ok(summary) => format!(
+      "[backend] work files cleaned: {} file(s), {} bytes",
+      summary.deleted_file_count, summary.freed_bytes
+    ),
+    Err(error) => format!("[backend] work file cleanup failed: {error}"),
+  };
+  append_log_line(job_runtime_state, job_root_directory_path, message);
+}
+
+fn run_post_run_passes_best_effort(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path) {
+  let settings = read_job_settings_best_effort(job_root_directory_path);
+
+  // NOTE: The math and structure reports describe the engine's own output, so they are attached before anything rewrites the text.
+  match run_math_report(job_root_directory_path) {
+    Ok(Some((formula_count, warning_count))) => append_log_line(
+      job_runtime_state,
+      job_root_directory_path,
+      format!("[backend] math: {formula_count} formula(s), {warning_count} warning(s)"),
+    ),
+    Ok(None) => {}
+    Err(error) => append_log_line(
+      job_runtime_state,
+      job_root_directory_path,
+      format!("[backend] math report failed: {error}"),
+    ),
+  }
+  match run_markdown_validation_report(job_root_directory_path) {
+    Ok(Some((checked_page_count, issue_count))) => append_log_line(
+      job_runtime_state,
+      job_root_directory_path,
+      format!("[backend] markdown validation: {issue_count} issue(s) in {checked_page_count} page(s)"),
+    ),
+    Ok(None) => {}
+    Err(error) => append_log_line(
+      job_runtime_state,
+      job_root_directory_path,
+      format!("[backend] markdown validation failed: {error}"),
+    ),
+  }
+
+  // NOTE: Text-rewriting passes run first so every later pass sees the corrected markdown.
+  if let Some(spellcheck_settings) = settings.spellcheck.as_ref() {
+    let message = match run_spellcheck(job_root_directory_path, spellcheck_settings) {
+      Ok(report) => format!(
+        "[backend] spellcheck: {} correction(s), {} flagged word(s)",
+        report.corrections.len(),
+        report.flagged_words.len()
+      ),
+      Err(error) => format!("[backend] spellcheck failed: {error}"),
+    };
+    append_log_line(job_runtime_state, job_root_directory_path, message);
+  }
+
+  let enabled_plugin_names = settings.enabled_plugin_names.clone().unwrap_or_default();
+  if !enabled_plugin_names.is_empty() {
+    let result = run_plugins_of_kind(
+      job_runtime_state,
+      job_root_directory_path,
+      &enabled_plugin_names,
+      PluginKind::Transformer,
+    );
+    log_plugin_results_best_effort(job_runtime_state, job_root_directory_path, result);
+  }
+
+  if settings.is_title_detection_enabled.unwrap_or(false) {
+    let message = match run_title_detection(job_root_directory_path) {
+      Ok(Some(detected_title)) => format!(
+        "[backend] detected title: {} (apply_detected_title renames the output)",
+        detected_title.title
+      ),
+      Ok(None) => "[backend] no title detected in the output".to_string(),
+      Err(error) => format!("[backend] title detection failed: {error}"),
+    };
+    append_log_line(job_runtime_state, job_root_directory_path, message);
+  }
+
+  if settings.is_structured_extraction_enabled.unwrap_or(false) {
+    let message = match run_structured_extraction(job_root_directory_path, &settings) {
+      Ok(written_paths) => format!("[backend] structured extraction: {} document(s)", written_paths.len()),
+      Err(error) => format!("[backend] structured extraction failed: {error}"),
+    };
+    append_log_line(job_runtime_state, job_root_directory_path, message);
+  }
+
+  if let Some(target_language) = settings.translation_target_language.as_deref() {
+    let message = match run_translation(job_root_directory_path, &settings, target_language) {
+      Ok(translated_path) => format!("[backend] translation written: {}", translated_path.display()),
+      Err(error) => format!("[backend] translation failed: {error}"),
+    };
+    append_log_line(job_runtime_state, job_root_directory_path, message);
+  }
+
+  if let Some(mode) = settings.summarization_mode {
+    let message = match run_summarization(job_root_directory_path, &settings, mode) {
+      Ok(summary_path) => format!("[backend] summary written: {}", summary_path.display()),
+      Err(error) => format!("[backend] summarization failed: {error}"),
+    };
+    append_log_line(job_runtime_state, job_root_directory_path, message);
+  }
+
+  if let Some(endpoint) = settings.embedding_endpoint.as_ref() {
+    let message = match run_embedding_indexing(job_root_directory_path, endpoint) {
+      Ok(indexed_chunks) => format!("[backend] embeddings indexed: {indexed_chunks} chunk(s)"),
+      Err(error) => format!("[backend] embedding indexing failed: {error}"),
+    };
+    append_log_line(job_runtime_state, job_root_directory_path, message);
+  }
+
+  if let Some(template_names) = settings.output_template_names.as_ref().filter(|names| !names.is_empty()) {
+    let message = match run_template_rendering(job_runtime_state, job_root_directory_path, template_names) {
+      Ok(written_paths) => format!("[backend] rendered {} template(s)", written_paths.len()),
+      Err(error) => format!("[backend] template rendering failed: {error}"),
+    };
+    append_log_line(job_runtime_state, job_root_directory_path, message);
+  }
+
+  if !enabled_plugin_names.is_empty() {
+    let result = run_plugins_of_kind(
+      job_runtime_state,
+      job_root_directory_path,
+      &enabled_plugin_names,
+      PluginKind::Exporter,
+    );
+    log_plugin_results_best_effort(job_runtime_state, job_root_directory_path, result);
+  }
+
+  // NOTE: Provenance hashes every artifact written above and is itself packaged and delivered below.
+  let message = match run_provenance_manifest(job_runtime_state, job_root_directory_path, &settings) {
+    Ok(manifest_path) => format!("[backend] provenance written: {}", manifest_path.display()),
+    Err(error) => format!("[backend] provenance failed: {error}"),
+  };
+  append_log_line(job_runtime_state, job_root_directory_path, message);
+
+  // NOTE: Packaging runs last so the archive contains every artifact written above.
+  if settings.is_package_on_completion_enabled.unwrap_or(false) {
+    let message = match run_packaging(job_root_directory_path) {
+      Ok((archive_path, packaged_file_count)) => format!(
+        "[backend] packaged {packaged_file_count} file(s): {}",
+        archive_path.display()
+      ),
+      Err(error) => format!("[backend] packaging failed: {error}"),
+    };
+    append_log_line(job_runtime_state, job_root_directory_path, message);
+  }
+
+  if let Some(target) = settings.onedrive_write_back.as_ref() {
+    let message = match run_onedrive_write_back(job_runtime_state, job_root_directory_path, target) {
+      Ok(remote_path) => format!("[backend] uploaded result to OneDrive: {remote_path}"),
+      Err(error) => format!("[backend] OneDrive write-back failed: {error}"),
+    };
+    append_log_line(job_runtime_state, job_root_directory_path, message);
+  }
+
+  // NOTE: Delivery runs after packaging so shared folders also receive the archive.
+  let mut destinations = settings.output_destinations.clone().unwrap_or_default();
+  match resolve_job_submitter_route(job_runtime_state, job_root_directory_path) {
+    Ok(Some(route)) => destinations.extend(route.output_destinations),
+    Ok(None) => {}
+    Err(error) => append_log_line(
+      job_runtime_state,
+      job_root_directory_path,
+      format!("[backend] submitter routing unavailable: {error}"),
+    ),
+  }
+  if !destinations.is_empty() {
+    match run_output_delivery(job_root_directory_path, &destinations) {
+      Ok(results) => {
+        for result in results {
+          let message = match result.error_message {
+            None => format!(
+              "[backend] delivered {} file(s) to {}",
+              result.copied_file_count, result.directory_path
+            ),
+            Some(error) => format!("[backend] delivery to {} failed: {error}", result.directory_path),
+          };
+          append_log_line(job_runtime_state, job_root_directory_path, message);
+        }
+      }
+      Err(error) => append_log_line(job_runtime_state, job_root_directory_path, format!("[backend] output delivery failed: {error}")),
+    }
+  }
+
+  // NOTE: User hooks run after every built-in pass so they see the finished artifacts.
+  if let Some(script_hook_settings) = settings.script_hooks.as_ref() {
+    let output_markdown_path = detect_last_output_markdown_path(job_root_directory_path).map(PathBuf::from);
+    let result = run_script_hooks_for_stage(
+      script_hook_settings,
+      ScriptHookStage::PostRun,
+      job_root_directory_path,
+      output_markdown_path.as_deref(),
+      make_job_log_sink(job_runtime_state, job_root_directory_path),
+    );
+    if let Err(error) = result {
+      append_log_line(job_runtime_state, job_root_directory_path, format!("[backend] post-run hook failed: {error}"));
+    }
+  }
+}
+
+fn is_any_job_running(job_runtime_state: &SharedJobRuntimeState) -> bool {
+  let locked = match job_runtime_state.lock() {
+    Ok(value) => value,
+    Err(_) => return true,
+  };
+  !locked.running_job_by_root.is_empty()
+}
+
+fn has_free_job_slot_for_new_work(job_runtime_state: &SharedJobRuntimeState) -> bool {
+  let max_concurrent_jobs = current_runtime_limits().max_concurrent_jobs;
+  job_runtime_state
+    .lock()
+    .map(|locked| {
+      locked
+        .job_start_queue
+        .has_free_slot_for_new_work(locked.running_job_by_root.len(), max_concurrent_jobs)
+    })
+    .unwrap_or(false)
+}
+
+fn read_maintenance_mode(job_runtime_state: &SharedJobRuntimeState) -> Option<MaintenanceMode> {
+  job_runtime_state
+    .lock()
+    .ok()
+    .and_then(|locked| locked.maintenance_mode.clone())
+}
+
+fn build_maintenance_status(job_runtime_state: &SharedJobRuntimeState) -> Result<MaintenanceStatus, String> {
+  let locked = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+  let mut running_job_root_directory_paths: Vec<String> = locked
+    .running_job_by_root
+    .keys()
+    .map(|path| path.to_string_lossy().to_string())
+    .collect();
+  running_job_root_directory_paths.sort();
+  Ok(describe_maintenance_status(locked.maintenance_mode.as_ref(), running_job_root_directory_paths))
+}
+
+fn sanitize_watch_job_id_component(raw: &str) -> String {
+  raw
+    .replace('\\', "_")
+    .replace('/', "_")
+    .replace(':', "_")
+    .replace(' ', "_")
+}
+
+fn derive_watch_job_id(source_bundle_directory_path: &Path) -> String {
+  let preassigned_job_id = fs::read_to_string(source_bundle_directory_path.join(DEFAULT_WATCH_JOB_ID_FILENAME))
+    .ok()
+    .map(|raw| sanitize_watch_job_id_component(raw.trim()))
+    .filter(|job_id| !job_id.is_empty() && job_id != "." && job_id != "..");
+  if let Some(job_id) = preassigned_job_id {
+    return job_id;
+  }
+
+  let base = source_bundle_directory_path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .unwrap_or("bundle");
+  format!("{}_{}", now_unix_timestamp_millis(), sanitize_watch_job_id_component(base))
+}
+
+// NOTE: `on_copied_bytes` receives the source path and the bytes written since the previous call.
+fn copy_file_with_progress(
+  source_file_path: &Path,
+  destination_file_path: &Path,
+  is_cancel_requested: &dyn Fn() -> bool,
+  on_copied_bytes: &dyn Fn(&Path, u64),
+) -> Result<(), String> {
+  if is_cancel_requested() {
+    return Err(format!("Copy cancelled: {}", source_file_path.display()));
+  }
+  let source_file_bytes = fs::metadata(source_file_path).map_err(|error| error.to_string())?.len();
+  if source_file_bytes < STREAMING_COPY_MIN_FILE_BYTES {
+    let copied_bytes = fs::copy(source_file_path, destination_file_path).map_err(|error| error.to_string())?;
+    on_copied_bytes(source_file_path, copied_bytes);
+    return Ok(());
+  }
+  let mut source = fs::File::open(source_file_path).map_err(|error| error.to_string())?;
+  let mut destination = fs::File::create(destination_file_path).map_err(|error| error.to_string())?;
+  let mut buffer = vec![0u8; CANCELLABLE_COPY_CHUNK_BYTES];
+  loop {
+    if is_cancel_requested() {
+      drop(destination);
+      // Guard: never leave a truncated file that a later run would OCR as if it were complete.
+      let _ = fs::remove_file(destination_file_path);
+      return Err(format!("Copy cancelled: {}", source_file_path.display()));
+    }
+    let read_bytes = source.read(&mut buffer).map_err(|error| error.to_string())?;
+    if read_bytes == 0 {
+      return Ok(());
+    }
+    destination.write_all(&buffer[..read_bytes]).map_err(|error| error.to_string())?;
+    on_copied_bytes(source_file_path, read_bytes as u64);
+  }
+}
+
+fn copy_directory_recursively_with_exclusions(
+  source_directory_path: &Path,
+  destination_directory_path: &Path,
+  excluded_filenames: &[&str],
+  is_cancel_requested: &dyn Fn() -> bool,
+) -> Result<u64, String> {
+  if !source_directory_path.exists() {
+    // Guard: do not silently ignore missing paths.
+    return Err(format!(
+      "Input directory does not exist: {}",
+      source_directory_path.display()
+    ));
+  }
+  if !source_directory_path.is_dir() {
+    // Guard: this function only handles directories.
+    return Err(format!("Not a directory: {}", source_directory_path.display()));
+  }
+  fs::create_dir_all(destination_directory_path).map_err(|error| error.to_string())?;
+
+  let mut total_copied_files: u64 = 0;
+  for entry in walkdir::WalkDir::new(source_directory_path) {
+    let entry = entry.map_err(|error| error.to_string())?;
+    let entry_path = entry.path();
+    if entry_path.is_dir() {
+      continue;
+    }
+    let file_name = entry_path.file_name().and_then(|name| name.to_str()).unwrap_or("");
+    if excluded_filenames.contains(&file_name) {
+      continue;
+    }
+    let relative_path = entry_path
+      .strip_prefix(source_directory_path)
+      .map_err(|error| error.to_string())?;
+    let destination_path = destination_directory_path.join(relative_path);
+    if let Some(parent_directory_path) = destination_path.parent() {
+      fs::create_dir_all(parent_directory_path).map_err(|error| error.to_string())?;
+    }
+    copy_file_with_progress(entry_path, &destination_path, is_cancel_requested, &|_, _| {})?;
+    total_copied_files += 1;
+  }
+  Ok(total_copied_files)
+}
+
+fn resolve_bundle_submitter(inbox_directory_path: &Path, bundle_directory_path: &Path) -> Option<String> {
+  fs::read_to_string(bundle_directory_path.join(DEFAULT_WATCH_SUBMITTER_FILENAME))
+    .ok()
+    .and_then(|raw| sanitize_submitter(&raw))
+    .or_else(|| submitter_of_bundle_folder(inbox_directory_path, bundle_directory_path))
+}
+
+fn create_watch_job_from_bundle(
+  job_runtime_state: SharedJobRuntimeState,
+  jobs_root_directory_path: &Path,
+  bundle_directory_path: &Path,
+  submitter: Option<String>,
+  inbox_preset_settings: Option<JobSettings>,
+  auto_run: bool,
+  auto_package: bool,
+  is_cancel_requested: &dyn Fn() -> bool,
+) -> Result<PathBuf, String> {
+  let job_id = derive_watch_job_id(bundle_directory_path);
+  let job_root_directory_path = jobs_root_directory_path.join(job_id);
+  let is_job_root_new = !job_root_directory_path.exists();
+  fs::create_dir_all(&job_root_directory_path).map_err(|error| error.to_string())?;
+  prepare_job_directory(&job_runtime_state, &job_root_directory_path)?;
+
+  let input_directory_path = job_input_directory_path(&job_root_directory_path);
+  if let Err(error) = copy_directory_recursively_with_exclusions(
+    bundle_directory_path,
+    &input_directory_path,
+    WATCH_BUNDLE_NON_DOCUMENT_FILENAMES,
+    is_cancel_requested,
+  ) {
+    if is_cancel_requested() && is_job_root_new {
+      // Guard: an aborted copy leaves a half-filled job root; drop it so the retried bundle starts clean.
+      let _ = fs::remove_dir_all(&job_root_directory_path);
+    }
+    return Err(error);
+  }
+
+  let bundle_settings_path = bundle_directory_path.join(DEFAULT_WATCH_JOB_SETTINGS_FILENAME);
+  let bundle_settings = if bundle_settings_path.exists() {
+    let raw = fs::read_to_string(&bundle_settings_path).map_err(|error| error.to_string())?;
+    Some(
+      serde_json::from_str::<JobSettings>(&raw)
+        .map_err(|error| format!("Invalid bundle settings {}: {error}", bundle_settings_path.display()))?,
+    )
+  } else {
+    None
+  };
+  let bundle_settings = match (inbox_preset_settings, bundle_settings) {
+    (Some(preset_settings), Some(bundle_settings)) => Some(overlay_job_settings(preset_settings, bundle_settings)?),
+    (preset_settings, bundle_settings) => bundle_settings.or(preset_settings),
+  };
+  if bundle_settings.is_some() || auto_package {
+    let mut settings = bundle_settings.unwrap_or_default();
+    // Guard: inputs were already copied into the recorded layout, so bundle settings cannot change it.
+    settings.directory_layout = read_job_settings_best_effort(&job_root_directory_path).directory_layout;
+    if auto_package {
+      settings.is_package_on_completion_enabled = Some(true);
+    }
+    write_job_settings(&job_root_directory_path, &settings)?;
+  }
+
+  let accepted_at = now_unix_timestamp_millis();
+  let job_id_for_state = job_root_directory_path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .unwrap_or("job")
+    .to_string();
+  let job_state = JobState {
+    status: JobStateStatus::Queued,
+    job_id: job_id_for_state,
+    job_root_directory_path: job_root_directory_path.to_string_lossy().to_string(),
+    source_bundle_directory_path: Some(bundle_directory_path.to_string_lossy().to_string()),
+    submitter: submitter.clone(),
+    accepted_unix_timestamp_millis: accepted_at,
+    started_unix_timestamp_millis: None,
+    finished_unix_timestamp_millis: None,
+    output_markdown_path: None,
+    error_message: None,
+    translated_output_markdown_path_by_language: BTreeMap::new(),
+    image_digest: None,
+    error_category: None,
+    transitions: vec![initial_job_state_transition(accepted_at)],
+    title: None,
+    cancellation: None,
+  };
+  write_job_state(&job_root_directory_path, &job_state)?;
+  register_job_id(&job_runtime_state, &job_state.job_id, &job_root_directory_path);
+  if submitter.is_some() {
+    update_job_report(&job_output_directory_path(&job_root_directory_path), |job_report| {
+      job_report.submitter = submitter
+    })?;
+  }
+
+  {
+    let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    locked_state
+      .job_state_file_path_by_root
+      .insert(job_root_directory_path.clone(), job_state_file_path(&job_root_directory_path));
+  }
+
+  if auto_run || bundle_directory_path.join(DEFAULT_WATCH_AUTO_RUN_FILENAME).exists() {
+    spawn_job_process(job_runtime_state, job_root_directory_path.clone(), JobStartSource::WatchFolder)?;
+  }
+  Ok(job_root_directory_path)
+}
+
+// NOTE: Fields set in `overrides` win; unset ones keep the base value.
+fn overlay_job_settings(base: JobSettings, overrides: JobSettings) -> Result<JobSettings, String> {
+  let mut merged = serde_json::to_value(base).map_err(|error| error.to_string())?;
+  let overrides = serde_json::to_value(overrides).map_err(|error| error.to_string())?;
+  if let (Some(merged_object), Value::Object(override_object)) = (merged.as_object_mut(), overrides) {
+    merged_object.extend(override_object.into_iter().filter(|(_, value)| !value.is_null()));
+  }
+  serde_json::from_value(merged).map_err(|error| error.to_string())
+}
+
+fn read_disk_watermark_settings_best_effort(job_runtime_state: &SharedJobRuntimeState) -> DiskWatermarkSettings {
+  read_app_config_json_file(job_runtime_state, DISK_WATERMARK_SETTINGS_FILENAME)
+    .ok()
+    .flatten()
+    .unwrap_or_default()
+}
+
+// NOTE: Only watcher jobs (with a source bundle in job_state.json) that finished before the minimum age are eligible, oldest first.
+fn list_retention_candidate_job_roots(
+  job_runtime_state: &SharedJobRuntimeState,
+  jobs_root_directory_path: &Path,
+  min_age: Duration,
+) -> Vec<PathBuf> {
+  let running_job_roots: Vec<PathBuf> = match job_runtime_state.lock() {
+    Ok(locked) => locked.running_job_by_root.keys().cloned().collect(),
+    // Guard: without the running set, deleting anything could hit a live job.
+    Err(_) => return vec![],
+  };
+  let newest_eligible_unix_timestamp_millis = now_unix_timestamp_millis() - min_age.as_millis() as i64;
+  let Ok(entries) = fs::read_dir(jobs_root_directory_path) else {
+    return vec![];
+  };
+  let mut candidates: Vec<(i64, PathBuf)> = entries
+    .filter_map(|entry| entry.ok())
+    .map(|entry| entry.path())
+    .filter(|path| path.is_dir() && !running_job_roots.contains(path))
+    .filter_map(|path| {
+      let state = read_job_state_best_effort(&path)?;
+      // Guard: manual jobs placed under the jobs root also carry a job_state.json, but are never auto-deleted.
+      state.source_bundle_directory_path.as_ref()?;
+      let finished_unix_timestamp_millis = match state.status {
+        JobStateStatus::Completed | JobStateStatus::Failed | JobStateStatus::Partial => {
+          state.finished_unix_timestamp_millis?
+        }
+        JobStateStatus::Queued | JobStateStatus::Running | JobStateStatus::Interrupted => return None,
+      };
+      (finished_unix_timestamp_millis <= newest_eligible_unix_timestamp_millis).then_some((finished_unix_timestamp_millis, path))
+    })
+    .collect();
+  candidates.sort();
+  candidates.into_iter().map(|(_, path)| path).collect()
+}
+
+// NOTE: Returns whether intake may continue; a breach is reported through the watcher status, not as an error.
+fn enforce_disk_watermarks(
+  job_runtime_state: &SharedJobRuntimeState,
+  watch_folder_state: &SharedWatchFolderRuntimeState,
+  monitor: &Mutex<DiskWatermarkMonitor>,
+  jobs_root_directory_path: &Path,
+) -> Result<bool, String> {
+  let settings = read_disk_watermark_settings_best_effort(job_runtime_state);
+  if !settings.is_enabled() {
+    set_watch_folder_intake_pause_reason(watch_folder_state, None);
+    return Ok(true);
+  }
+  let mut monitor = monitor.lock().map_err(|_| "Disk watermark monitor lock poisoned".to_string())?;
+  let is_intake_paused = is_watch_folder_intake_paused(watch_folder_state);
+  let snapshot = monitor.measure(jobs_root_directory_path, &settings)?;
+  let mut disk_breach = describe_watermark_breach(&snapshot, &settings, is_intake_paused);
+
+  if disk_breach.is_some() && settings.is_retention_cleanup_enabled {
+    let candidates =
+      list_retention_candidate_job_roots(job_runtime_state, jobs_root_directory_path, settings.retention_min_age());
+    let removed_job_roots = remove_jobs_for_retention(&mut monitor, jobs_root_directory_path, &settings, &candidates)?;
+    if !removed_job_roots.is_empty() {
+      let removed_names: Vec<String> = removed_job_roots
+        .iter()
+        .filter_map(|path| path.file_name().map(|name| name.to_string_lossy().to_string()))
+        .collect();
+      record_watch_folder_retention_cleanup(
+        watch_folder_state,
+        format!("Removed {} finished job(s): {}", removed_names.len(), removed_names.join(", ")),
+      );
+    }
+    let snapshot = monitor.measure(jobs_root_directory_path, &settings)?;
+    disk_breach = describe_watermark_breach(&snapshot, &settings, true);
+  }
+
+  let breach = disk_breach.or_else(|| {
+    let queued_job_count = monitor.count_queued_jobs(&settings, || {
+      count_queued_watch_jobs(job_runtime_state, jobs_root_directory_path)
+    });
+    describe_queued_job_breach(queued_job_count, &settings)
+  });
+  match breach {
+    None => {
+      set_watch_folder_intake_pause_reason(watch_folder_state, None);
+      Ok(true)
+    }
+    Some(breach) => {
+      set_watch_folder_intake_pause_reason(watch_folder_state, Some(format!("Intake paused: {breach}")));
+      Ok(false)
+    }
+  }
+}
+
+// NOTE: Counts watcher jobs (with a source bundle) that are still waiting to start; running ones no longer count.
+fn count_queued_watch_jobs(job_runtime_state: &SharedJobRuntimeState, jobs_root_directory_path: &Path) -> usize {
+  let running_job_roots: Vec<PathBuf> = job_runtime_state
+    .lock()
+    .map(|locked| locked.running_job_by_root.keys().cloned().collect())
+    .unwrap_or_default();
+  let Ok(entries) = fs::read_dir(jobs_root_directory_path) else {
+    return 0;
+  };
+  entries
+    .filter_map(|entry| entry.ok())
+    .map(|entry| entry.path())
+    .filter(|path| path.is_dir() && !running_job_roots.contains(path))
+    .filter_map(|path| read_job_state_best_effort(&path))
+    .filter(|state| state.status == JobStateStatus::Queued && state.source_bundle_directory_path.is_some())
+    .count()
+}
+
+fn ingest_watch_bundle(
+  job_runtime_state: &SharedJobRuntimeState,
+  watch_folder_state: &SharedWatchFolderRuntimeState,
+  config: &WatchFolderConfig,
+  bundle_directory_path: &Path,
+  auto_run: bool,
+  auto_package: bool,
+  is_cancel_requested: &dyn Fn() -> bool,
+) -> Result<(), String> {
+  let jobs_root_directory_path = config.jobs_root_directory_path.as_path();
+  let fingerprint =
+    compute_bundle_fingerprint(bundle_directory_path, WATCH_BUNDLE_NON_DOCUMENT_FILENAMES, is_cancel_requested)?;
+  let mut ledger = BundleLedger::load(jobs_root_directory_path)?;
+  let bundle_name = bundle_directory_path
+    .file_name()
+    .map(|name| name.to_string_lossy().to_string())
+    .unwrap_or_default();
+  let is_reprocess_requested = bundle_directory_path.join(DEFAULT_WATCH_REPROCESS_FILENAME).exists();
+  if !is_reprocess_requested {
+    if let Some(original) = ledger.find_live_entry(jobs_root_directory_path, &fingerprint) {
+      // Guard: identical documents were already OCR'd; point at that job instead of running them again.
+      let original_job_id = original.job_id.clone();
+      mark_bundle_duplicate(bundle_directory_path, &original_job_id)?;
+      record_watch_folder_duplicate_bundle(
+        watch_folder_state,
+        format!("Skipped bundle {bundle_name}: same documents as job {original_job_id}"),
+      );
+      return Ok(());
+    }
+  }
+
+  let job_root_directory_path = create_watch_job_from_bundle(
+    job_runtime_state.clone(),
+    jobs_root_directory_path,
+    bundle_directory_path,
+    resolve_bundle_submitter(&config.inbox_directory_path, bundle_directory_path),
+    resolve_bundle_preset_settings(job_runtime_state, config.preset_name.as_deref())?,
+    auto_run,
+    auto_package,
+    is_cancel_requested,
+  )?;
+  let _ = mark_bundle_processed(bundle_directory_path);
+  ledger.record(BundleLedgerEntry {
+    fingerprint,
+    job_id: job_root_directory_path
+      .file_name()
+      .map(|name| name.to_string_lossy().to_string())
+      .unwrap_or_default(),
+    bundle_name,
+    processed_unix_timestamp_millis: now_unix_timestamp_millis(),
+  });
+  if let Err(error) = ledger.save(jobs_root_directory_path) {
+    // Guard: the job already exists, so only report this; failing here would mark an accepted bundle `.failed`.
+    record_watch_folder_error(watch_folder_state, format!("Failed to update bundle ledger: {error}"));
+  }
+  Ok(())
+}
+
+fn make_watch_folder_poll_callback(
+  shared_job_runtime_state: SharedJobRuntimeState,
+  watch_folder_state: SharedWatchFolderRuntimeState,
+  watch_folder_registry: SharedWatchFolderRegistry,
+  auto_run: bool,
+  auto_package: bool,
+) -> WatchFolderPollCallback {
+  let disk_watermark_monitor = Mutex::new(DiskWatermarkMonitor::default());
+  let single_file_drop_observations = Mutex::new(SingleFileDropObservations::new());
+  let zip_drop_observations = Mutex::new(ZipDropObservations::default());
+  let bundle_quiescence_observations = Mutex::new(BundleQuiescenceObservations::new());
+  Arc::new(move |config: &WatchFolderConfig| {
+    if is_watch_folder_paused_by_operator(&watch_folder_state) || read_maintenance_mode(&shared_job_runtime_state).is_some() {
+      // Guard: paused for maintenance; leave every drop untouched until an operator resumes intake.
+      return Ok(());
+    }
+    let unavailable_volume_reason = [
+      ("Inbox", &config.inbox_directory_path, config.inbox_volume_kind),
+      ("Jobs root", &config.jobs_root_directory_path, config.jobs_root_volume_kind),
+    ]
+    .into_iter()
+    .find_map(|(name, path, volume_kind)| {
+      describe_unavailable_volume(path, volume_kind).map(|reason| format!("{name} {reason}"))
+    });
+    if let Some(reason) = unavailable_volume_reason {
+      // Guard: a removed drive or dropped share is not a bundle error; intake resumes once it is back.
+      set_watch_folder_intake_pause_reason(&watch_folder_state, Some(format!("Intake paused: {reason}")));
+      return Ok(());
+    }
+    // Guard: leave bundles in the inbox while the disk is short; they are picked up once space is back.
+    if !enforce_disk_watermarks(
+      &shared_job_runtime_state,
+      &watch_folder_state,
+      &disk_watermark_monitor,
+      &config.jobs_root_directory_path,
+    )? {
+      return Ok(());
+    }
+    if config.is_single_file_intake_enabled {
+      // NOTE: Wrapping only moves files within the inbox, so it keeps pace with drops even while a job runs.
+      let mut observations = single_file_drop_observations
+        .lock()
+        .map_err(|_| "Single-file drop state lock poisoned".to_string())?;
+      wrap_settled_single_file_drops(&config.inbox_directory_path, &mut observations)?;
+    }
+    {
+      // NOTE: Always on, unlike single files: a `.zip` in the inbox can only be meant as a bundle.
+      let mut observations = zip_drop_observations
+        .lock()
+        .map_err(|_| "Zip drop state lock poisoned".to_string())?;
+      extract_settled_zip_drops(&config.inbox_directory_path, &mut observations)?;
+    }
+    if let Some(quiescence_period) = config.bundle_quiescence_period {
+      let mut observations = bundle_quiescence_observations
+        .lock()
+        .map_err(|_| "Bundle quiescence state lock poisoned".to_string())?;
+      mark_quiescent_bundles_ready(
+        &config.inbox_directory_path,
+        &config.jobs_root_directory_path,
+        quiescence_period,
+        &mut observations,
+      )?;
+    }
+    if !has_free_job_slot_for_new_work(&shared_job_runtime_state) {
+      // Guard: every job slot (`max_concurrent_jobs`, 1 by default) is taken or spoken for by a queued start.
+      return Ok(());
+    }
+
+    let bundle_directories = list_ready_bundle_directories(&config.inbox_directory_path)?;
+    let is_slot_left_to_higher_priority_inbox = !bundle_directories.is_empty()
+      && is_bundle_waiting_in_higher_priority_inbox(&watch_folder_registry, config.priority);
+    if is_slot_left_to_higher_priority_inbox {
+      // Guard: leave the free slot to the higher-priority inbox; these bundles are taken on a later poll.
+      return Ok(());
+    }
+    for bundle_directory_path in bundle_directories {
+      let locked = try_lock_bundle_for_processing(&bundle_directory_path)?;
+      if !locked {
+        continue;
+      }
+
+      let is_cancel_requested = || is_watch_folder_stop_requested(&watch_folder_state);
+      let ingest_result = ingest_watch_bundle(
+        &shared_job_runtime_state,
+        &watch_folder_state,
+        config,
+        &bundle_directory_path,
+        auto_run,
+        auto_package,
+        &is_cancel_requested,
+      );
+      // NOTE: The new job counts toward the queue cap and the jobs root quota from the next poll on.
+      if let Ok(mut monitor) = disk_watermark_monitor.lock() {
+        monitor.invalidate();
+      }
+      if let Err(error_message) = ingest_result {
+        if is_cancel_requested() {
+          // Guard: a stop is not a bundle failure; leave it ready for the next watcher run.
+          release_bundle_processing_lock(&bundle_directory_path);
+          return Ok(());
+        }
+        let _ = mark_bundle_failed(&bundle_directory_path, &error_message);
+        return Err(error_message);
+      }
+      return Ok(());
+    }
+
+    Ok(())
+  })
+}
+
+fn derive_http_api_job_id(documents: &[InboundDocument]) -> String {
+  let first_filename = documents.first().map(|document| document.filename.as_str()).unwrap_or("upload");
+  let (stem, _) = split_filename_and_extension(first_filename);
+  format!("{}_api_{}", now_unix_timestamp_millis(), sanitize_watch_job_id_component(&stem))
+}
+
+fn resolve_running_watch_inbox_directory_path(watch_folder_state: &SharedWatchFolderRuntimeState) -> Result<PathBuf, String> {
+  let status = get_watch_folder_status_from_state(watch_folder_state);
+  let (inbox_directory_path, _) = watch_folder_directory_paths(watch_folder_state);
+  match (status.is_running, inbox_directory_path) {
+    (true, Some(inbox_directory_path)) => Ok(inbox_directory_path),
+    _ => Err("Watch folder is not running; start it to accept remote submissions.".to_string()),
+  }
+}
+
+fn write_inbox_bundle(
+  inbox_directory_path: &Path,
+  job_id: &str,
+  documents: Vec<InboundDocument>,
+  bundle_settings: Option<&JobSettings>,
+  submitter: Option<&str>,
+  is_auto_run_requested: bool,
+) -> Result<PathBuf, String> {
+  let bundle_directory_path = inbox_directory_path.join(job_id);
+  if bundle_directory_path.exists() {
+    // Guard: never merge two submissions into one bundle.
+    return Err(format!("Bundle already exists: {}", bundle_directory_path.display()));
+  }
+  fs::create_dir_all(&bundle_directory_path).map_err(|error| error.to_string())?;
+  for document in documents {
+    let filename = sanitize_submitted_filename(&document.filename, DEFAULT_WATCH_BUNDLE_DOCUMENT_FILENAME);
+    let destination_path = derive_non_conflicting_destination_path(&bundle_directory_path, filename)?;
+    fs::write(&destination_path, document.bytes).map_err(|error| error.to_string())?;
+  }
+  fs::write(bundle_directory_path.join(DEFAULT_WATCH_JOB_ID_FILENAME), job_id).map_err(|error| error.to_string())?;
+  if let Some(settings) = bundle_settings {
+    let serialized = serde_json::to_string_pretty(settings).map_err(|error| error.to_string())?;
+    fs::write(bundle_directory_path.join(DEFAULT_WATCH_JOB_SETTINGS_FILENAME), serialized)
+      .map_err(|error| error.to_string())?;
+  }
+  if let Some(submitter) = submitter {
+    fs::write(bundle_directory_path.join(DEFAULT_WATCH_SUBMITTER_FILENAME), submitter).map_err(|error| error.to_string())?;
+  }
+  if is_auto_run_requested {
+    fs::write(bundle_directory_path.join(DEFAULT_WATCH_AUTO_RUN_FILENAME), "").map_err(|error| error.to_string())?;
+  }
+  // NOTE: `.ready` goes last so the watcher never picks up a partially written bundle.
+  fs::write(bundle_directory_path.join(DEFAULT_WATCH_READY_FILENAME), "").map_err(|error| error.to_string())?;
+  Ok(bundle_directory_path)
+}
+
+// NOTE: Remote callers may tune OCR and output naming only; hooks, plugins, extra environment, LLM endpoints,
+// notifications and output destinations run code or reach other machines, so they stay server-side.
+const HTTP_API_SUBMISSION_SETTING_NAMES: &[&str] = &[
+  "output_markdown_filename_override",
+  "is_math_delimiter_conversion_enabled",
+  "is_math_delimiter_normalization_enabled",
+  "is_mathml_export_enabled",
+  "is_page_image_export_enabled",
+  "is_hyphenation_repair_enabled",
+  "is_paragraph_reflow_enabled",
+  "page_separator_style",
+  "page_separator_template",
+  "is_page_source_comment_enabled",
+  "is_title_detection_enabled",
+  "deepseek_ocr2_markdown_prompt",
+  "deepseek_ocr2_base_image_size_pixels",
+  "deepseek_ocr2_inference_image_size_pixels",
+  "deepseek_ocr2_enable_crop_mode",
+  "is_oom_auto_retry_enabled",
+  "is_structured_extraction_enabled",
+  "structured_extraction_schema",
+  "translation_target_language",
+  "summarization_mode",
+  "is_package_on_completion_enabled",
+  "output_template_names",
+];
+
+// NOTE: Parsed with the same `JobSettings` schema and checks as `job.json`; the result replaces the server defaults
+// for this job only, like a bundle's `.job_settings.json`.
+fn parse_http_api_submission_settings(raw_settings: Value) -> Result<JobSettings, String> {
+  let Value::Object(fields) = &raw_settings else {
+    return Err("settings must be a JSON object.".to_string());
+  };
+  if let Some(name) = fields
+    .keys()
+    .find(|name| !HTTP_API_SUBMISSION_SETTING_NAMES.contains(&name.as_str()))
+  {
+    return Err(format!("Setting {name} cannot be set per submission."));
+  }
+  let settings =
+    serde_json::from_value::<JobSettings>(raw_settings).map_err(|error| format!("Invalid settings: {error}"))?;
+  validate_job_settings(&settings)?;
+  Ok(settings)
+}
+
+fn make_http_api_submission_handler(
+  job_runtime_state: SharedJobRuntimeState,
+  watch_folder_state: SharedWatchFolderRuntimeState,
+) -> InboundSubmissionHandler {
+  Arc::new(
+    move |documents: Vec<InboundDocument>, raw_settings: Option<Value>, submitter: Option<String>| {
+      let bundle_settings = raw_settings
+        .map(parse_http_api_submission_settings)
+        .transpose()
+        .map_err(SubmissionError::InvalidRequest)?;
+      if let Some(maintenance_mode) = read_maintenance_mode(&job_runtime_state) {
+        // Guard: refuse instead of queueing, so the caller retries after maintenance rather than assuming it was
+        // accepted.
+        return Err(SubmissionError::Unavailable(describe_maintenance_refusal(&maintenance_mode)));
+      }
+      let inbox_directory_path =
+        resolve_running_watch_inbox_directory_path(&watch_folder_state).map_err(SubmissionError::Unavailable)?;
+      let job_id = derive_http_api_job_id(&documents);
+      write_inbox_bundle(
+        &inbox_directory_path,
+        &job_id,
+        documents,
+        bundle_settings.as_ref(),
+        submitter.as_deref(),
+        false,
+      )
+      .map_err(SubmissionError::Unavailable)?;
+      Ok(job_id)
+    },
+  )
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct RemoteRunningJob {
+  job_root_directory_path: String,
+  job_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct RemoteControlStatus {
+  watcher: WatchFolderStatus,
+  maintenance: Option<MaintenanceStatus>,
+  running_jobs: Vec<RemoteRunningJob>,
+  // NOTE: Watcher jobs waiting to start, and `.ready` bundles not yet turned into jobs.
+  queued_job_count: usize,
+  pending_bundle_count: usize,
+}
+
+fn build_remote_control_status(
+  job_runtime_state: &SharedJobRuntimeState,
+  watch_folder_state: &SharedWatchFolderRuntimeState,
+) -> RemoteControlStatus {
+  let watcher = get_watch_folder_status_from_state(watch_folder_state);
+  let running_job_roots: Vec<PathBuf> = job_runtime_state
+    .lock()
+    .map(|locked| locked.running_job_by_root.keys().cloned().collect())
+    .unwrap_or_default();
+  let running_jobs = running_job_roots
+    .into_iter()
+    .map(|job_root_directory_path| RemoteRunningJob {
+      job_id: lookup_job_id(job_runtime_state, &job_root_directory_path),
+      job_root_directory_path: job_root_directory_path.to_string_lossy().to_string(),
+    })
+    .collect();
+  let (inbox_directory_path, jobs_root_directory_path) = watch_folder_directory_paths(watch_folder_state);
+  let queued_job_count = jobs_root_directory_path
+    .map(|jobs_root_directory_path| count_queued_watch_jobs(job_runtime_state, &jobs_root_directory_path))
+    .unwrap_or(0);
+  let pending_bundle_count = inbox_directory_path
+    .and_then(|inbox_directory_path| list_ready_bundle_directories(&inbox_directory_path).ok())
+    .map(|bundle_directories| bundle_directories.len())
+    .unwrap_or(0);
+  RemoteControlStatus {
+    watcher,
+    maintenance: build_maintenance_status(job_runtime_state).ok(),
+    running_jobs,
+    queued_job_count,
+    pending_bundle_count,
+  }
+}
+
+fn make_http_api_control_handler(
+  job_runtime_state: SharedJobRuntimeState,
+  watch_folder_state: SharedWatchFolderRuntimeState,
+) -> ControlHandler {
+  Arc::new(move |action: ControlAction| {
+    match action {
+      ControlAction::Status => {}
+      ControlAction::StartWatcher => restart_watch_folder(&watch_folder_state)?,
+      ControlAction::StopWatcher => stop_watch_folder_internal(&watch_folder_state),
+      ControlAction::PauseIntake { reason } => set_watch_folder_operator_pause_reason(
+        &watch_folder_state,
+        Some(reason.unwrap_or_else(|| "Paused remotely".to_string())),
+      ),
+      ControlAction::ResumeIntake => set_watch_folder_operator_pause_reason(&watch_folder_state, None),
+    }
+    serde_json::to_value(build_remote_control_status(&job_runtime_state, &watch_folder_state))
+      .map_err(|error| error.to_string())
+  })
+}
+
+fn resolve_http_api_tokens_file_path(job_runtime_state: &SharedJobRuntimeState) -> Result<PathBuf, String> {
+  Ok(resolve_app_config_directory_path(job_runtime_state)?.join(HTTP_API_TOKENS_FILENAME))
+}
+
+// NOTE: The token file is read per request, so new tokens and revocations apply without restarting the HTTP API.
+fn make_http_api_token_authorizer(job_runtime_state: SharedJobRuntimeState) -> ApiTokenAuthorizer {
+  Arc::new(move |presented_token: &str| {
+    let tokens_file_path = resolve_http_api_tokens_file_path(&job_runtime_state).ok()?;
+    authorize_api_token(&tokens_file_path, presented_token)
+  })
+}
+
+#[tauri::command]
+fn list_http_api_tokens(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<Vec<ApiTokenSummary>, String> {
+  list_api_tokens(&resolve_http_api_tokens_file_path(job_runtime_state.inner())?)
+}
+
+// NOTE: The returned `token` is shown only here; the token file keeps its SHA-256 hash.
+#[tauri::command]
+fn create_http_api_token(
+  name: String,
+  scopes: Vec<ApiTokenScope>,
+  submitter: Option<String>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<CreatedApiToken, String> {
+  create_api_token(
+    &resolve_http_api_tokens_file_path(job_runtime_state.inner())?,
+    &name,
+    scopes,
+    submitter.as_deref().and_then(sanitize_submitter),
+    now_unix_timestamp_millis(),
+  )
+}
+
+#[tauri::command]
+fn revoke_http_api_token(
+  token_id: String,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<ApiTokenSummary, String> {
+  revoke_api_token(
+    &resolve_http_api_tokens_file_path(job_runtime_state.inner())?,
+    &token_id,
+    now_unix_timestamp_millis(),
+  )
+}
+
+fn read_app_config_json_file<T: serde::de::DeserializeOwned>(
+  job_runtime_state: &SharedJobRuntimeState,
+  filename: &str,
+) -> Result<Option<T>, String> {
+  let file_path = resolve_app_config_directory_path(job_runtime_state)?.join(filename);
+  if !file_path.exists() {
+    return Ok(None);
+  }
+  let raw = fs::read_to_string(&file_path).map_err(|error| error.to_string())?;
+  serde_json::from_str::<T>(&raw)
+    .map(Some)
+    .map_err(|error| format!("Invalid settings file {}: {error}", file_path.display()))
+}
+
+fn write_app_config_json_file<T: Serialize>(
+  job_runtime_state: &SharedJobRuntimeState,
+  filename: &str,
+  value: &T,
+) -> Result<(), String> {
+  let file_path = resolve_app_config_directory_path(job_runtime_state)?.join(filename);
+  if let Some(parent) = file_path.parent() {
+    fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+  }
+  let serialized = serde_json::to_string_pretty(value).map_err(|error| error.to_string())?;
+  fs::write(file_path, serialized).map_err(|error| error.to_string())
+}
+
+fn validate_job_settings_preset_name(preset_name: &str) -> Result<String, String> {
+  let trimmed = preset_name.trim();
+  let is_valid = !trimmed.is_empty()
+    && trimmed
+      .chars()
+      .all(|character| character.is_alphanumeric() || matches!(character, '-' | '_' | ' '));
+  if !is_valid {
+    // Guard: preset names become filenames under the app config directory.
+    return Err(format!("Invalid preset name: {preset_name}"));
+  }
+  Ok(trimmed.to_string())
+}
+
+fn job_settings_preset_filename(preset_name: &str) -> Result<String, String> {
+  Ok(format!(
+    "{JOB_SETTINGS_PRESETS_DIRECTORY_NAME}/{}.json",
+    validate_job_settings_preset_name(preset_name)?
+  ))
+}
+
+fn read_job_settings_preset(job_runtime_state: &SharedJobRuntimeState, preset_name: &str) -> Result<JobSettings, String> {
+  read_app_config_json_file::<JobSettings>(job_runtime_state, &job_settings_preset_filename(preset_name)?)?
+    .ok_or_else(|| format!("Preset not found: {preset_name}"))
+}
+
+fn resolve_bundle_preset_settings(
+  job_runtime_state: &SharedJobRuntimeState,
+  preset_name: Option<&str>,
+) -> Result<Option<JobSettings>, String> {
+  match preset_name.map(str::trim).filter(|name| !name.is_empty()) {
+    Some(preset_name) => read_job_settings_preset(job_runtime_state, preset_name).map(Some),
+    None => Ok(None),
+  }
+}
+
+fn require_onedrive_connector_settings(job_runtime_state: &SharedJobRuntimeState) -> Result<OneDriveConnectorSettings, String> {
+  read_app_config_json_file::<OneDriveConnectorSettings>(job_runtime_state, ONEDRIVE_CONNECTOR_SETTINGS_FILENAME)?
+    .ok_or_else(|| "OneDrive connector is not configured.".to_string())
+}
+
+fn require_google_drive_connector_settings(
+  job_runtime_state: &SharedJobRuntimeState,
+) -> Result<GoogleDriveConnectorSettings, String> {
+  read_app_config_json_file::<GoogleDriveConnectorSettings>(job_runtime_state, GOOGLE_DRIVE_CONNECTOR_SETTINGS_FILENAME)?
+    .ok_or_else(|| "Google Drive connector is not configured.".to_string())
+}
+
+fn make_onedrive_document_handler(
+  job_runtime_state: SharedJobRuntimeState,
+  watch_folder_state: SharedWatchFolderRuntimeState,
+  settings: &OneDriveConnectorSettings,
+) -> OneDriveDocumentHandler {
+  let is_write_back_enabled = settings.is_write_back_enabled;
+  let results_folder_name = settings.results_folder_name();
+  let preset_name = settings.preset_name.clone();
+  Arc::new(move |item, bytes| {
+    let inbox_directory_path = resolve_running_watch_inbox_directory_path(&watch_folder_state)?;
+    let documents = vec![InboundDocument {
+      filename: item.name.clone(),
+      bytes,
+    }];
+    let (stem, _) = split_filename_and_extension(&item.name);
+    let job_id = format!("{}_onedrive_{}", now_unix_timestamp_millis(), sanitize_watch_job_id_component(&stem));
+    let mut bundle_settings = resolve_bundle_preset_settings(&job_runtime_state, preset_name.as_deref())?;
+    if is_write_back_enabled {
+      bundle_settings.get_or_insert_with(JobSettings::default).onedrive_write_back = Some(OneDriveWriteBackTarget {
+        drive_id: item.drive_id.clone(),
+        parent_item_id: item.parent_item_id.clone(),
+        results_folder_name: results_folder_name.clone(),
+      });
+    }
+    write_inbox_bundle(&inbox_directory_path, &job_id, documents, bundle_settings.as_ref(), None, false)?;
+    Ok(())
+  })
+}
+
+fn make_google_drive_document_handler(
+  job_runtime_state: SharedJobRuntimeState,
+  watch_folder_state: SharedWatchFolderRuntimeState,
+) -> GoogleDriveDocumentHandler {
+  Arc::new(move |file, bytes| {
+    let inbox_directory_path = resolve_running_watch_inbox_directory_path(&watch_folder_state)?;
+    let bundle_settings = resolve_bundle_preset_settings(&job_runtime_state, file.folder.preset_name.as_deref())?;
+    let documents = vec![InboundDocument {
+      filename: file.name.clone(),
+      bytes,
+    }];
+    let (stem, _) = split_filename_and_extension(&file.name);
+    let job_id = format!("{}_gdrive_{}", now_unix_timestamp_millis(), sanitize_watch_job_id_component(&stem));
+    write_inbox_bundle(&inbox_directory_path, &job_id, documents, bundle_settings.as_ref(), None, false)?;
+    Ok(())
+  })
+}
+
+fn make_hotfolder_document_handler(
+  job_runtime_state: SharedJobRuntimeState,
+  watch_folder_state: SharedWatchFolderRuntimeState,
+) -> HotfolderDocumentHandler {
+  Arc::new(move |preset, pdf_path| {
+    let inbox_directory_path = resolve_running_watch_inbox_directory_path(&watch_folder_state)?;
+    let bundle_settings = read_job_settings_preset(&job_runtime_state, &preset.preset_name)?;
+    let filename = pdf_path
+      .file_name()
+      .and_then(|name| name.to_str())
+      .unwrap_or("document.pdf")
+      .to_string();
+    let bytes = fs::read(pdf_path).map_err(|error| error.to_string())?;
+    let (stem, _) = split_filename_and_extension(&filename);
+    let job_id = format!("{}_hotfolder_{}", now_unix_timestamp_millis(), sanitize_watch_job_id_component(&stem));
+    write_inbox_bundle(
+      &inbox_directory_path,
+      &job_id,
+      vec![InboundDocument { filename, bytes }],
+      Some(&bundle_settings),
+      None,
+      preset.is_auto_run_enabled,
+    )?;
+    Ok(())
+  })
+}
+
+fn submit_backfill_batch(
+  inbox_directory_path: &Path,
+  source_directory_path: &Path,
+  batch_number: usize,
+  file_paths: &[PathBuf],
+) -> Result<(), String> {
+  let mut documents: Vec<InboundDocument> = vec![];
+  for file_path in file_paths {
+    let filename = file_path
+      .file_name()
+      .and_then(|name| name.to_str())
+      .unwrap_or("document")
+      .to_string();
+    let bytes = fs::read(file_path).map_err(|error| format!("Failed to read {}: {error}", file_path.display()))?;
+    documents.push(InboundDocument { filename, bytes });
+  }
+  let source_name = source_directory_path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .unwrap_or("archive");
+  let job_id = format!(
+    "{}_backfill_{}_{batch_number:05}",
+    now_unix_timestamp_millis(),
+    sanitize_watch_job_id_component(source_name)
+  );
+  write_inbox_bundle(inbox_directory_path, &job_id, documents, None, None, false)?;
+  Ok(())
+}
+
+fn create_job_root_from_template(
+  job_runtime_state: &SharedJobRuntimeState,
+  parent_directory_path: &Path,
+  template_name: &str,
+) -> Result<PathBuf, String> {
+  validate_job_root_directory(parent_directory_path)?;
+  let templates_directory_path = resolve_app_config_directory_path(job_runtime_state)?.join(JOB_TEMPLATES_DIRECTORY_NAME);
+  let template = read_job_template(&templates_directory_path, template_name)?;
+  let mut settings = match template.preset_name.as_deref() {
+    Some(preset_name) => read_job_settings_preset(job_runtime_state, preset_name)?,
+    None => JobSettings::default(),
+  };
+  // Guard: a preset saved from another job must not carry that job's output filename or folder names.
+  settings.last_output_markdown_filename = None;
+  settings.directory_layout = template.directory_layout.clone();
+  if let Some(directory_layout) = settings.directory_layout.as_ref() {
+    directory_layout.validate(DEFAULT_JOB_SETTINGS_DIRECTORY_NAME)?;
+  }
+  validate_job_settings(&settings)?;
+
+  let job_name = validate_job_template_name(template.job_name.as_deref().unwrap_or(template_name))?;
+  let job_root_directory_path = derive_dated_job_root_path(
+    parent_directory_path,
+    &format_utc_date(now_unix_timestamp_millis()),
+    &sanitize_watch_job_id_component(&job_name),
+  )?;
+  fs::create_dir(&job_root_directory_path).map_err(|error| error.to_string())?;
+  let result = copy_job_template_seed_files(
+    &templates_directory_path,
+    template_name,
+    &job_root_directory_path,
+    &[DEFAULT_JOB_SETTINGS_DIRECTORY_NAME],
+  )
+  .and_then(|_| write_job_settings(&job_root_directory_path, &settings))
+  .and_then(|_| prepare_job_directory(job_runtime_state, &job_root_directory_path));
+  if let Err(error) = result {
+    // Guard: the folder was created above, so removing it cannot touch anything the user already had.
+    let _ = fs::remove_dir_all(&job_root_directory_path);
+    return Err(error);
+  }
+  Ok(job_root_directory_path)
+}
+
+fn read_quick_job_definitions(job_runtime_state: &SharedJobRuntimeState) -> Result<Vec<QuickJobDefinition>, String> {
+  Ok(read_app_config_json_file(job_runtime_state, QUICK_JOB_SETTINGS_FILENAME)?.unwrap_or_default())
+}
+
+fn parse_quick_job_hotkey(definition: &QuickJobDefinition) -> Result<Option<Shortcut>, String> {
+  let Some(hotkey) = definition.hotkey.as_deref().map(str::trim).filter(|hotkey| !hotkey.is_empty()) else {
+    return Ok(None);
+  };
+  hotkey
+    .parse::<Shortcut>()
+    .map(Some)
+    .map_err(|error| format!("Invalid hotkey {hotkey:?} for quick job {}: {error}", definition.name.trim()))
+}
+
+fn read_quick_job_clipboard_content(app_handle: &tauri::AppHandle<Wry>) -> Result<QuickJobContent, String> {
+  if let Ok(image) = app_handle.clipboard().read_image() {
+    return Ok(QuickJobContent::Image {
+      width: image.width(),
+      height: image.height(),
+      rgba: image.rgba().to_vec(),
+    });
+  }
+  let clipboard_text = app_handle.clipboard().read_text().unwrap_or_default();
+  parse_clipboard_file_paths(&clipboard_text)
+    .map(QuickJobContent::Files)
+    .ok_or_else(|| "Clipboard holds no image and no existing file paths.".to_string())
+}
+
+fn create_and_run_quick_job(
+  job_runtime_state: &SharedJobRuntimeState,
+  quick_job_name: &str,
+  content: QuickJobContent,
+) -> Result<PathBuf, String> {
+  let definitions = read_quick_job_definitions(job_runtime_state)?;
+  let definition = find_quick_job_definition(&definitions, quick_job_name)?;
+  let destination_root_directory_path = PathBuf::from(definition.destination_root_directory_path.trim());
+  validate_job_root_directory(&destination_root_directory_path)?;
+
+  let mut settings = read_job_settings_preset(job_runtime_state, &definition.preset_name)?;
+  // Guard: a preset saved from another job must not carry that job's output filename or folder names.
+  settings.last_output_markdown_filename = None;
+  settings.directory_layout = None;
+
+  let job_root_directory_path = destination_root_directory_path.join(format!(
+    "{}_quick_{}",
+    now_unix_timestamp_millis(),
+    sanitize_watch_job_id_component(definition.name.trim())
+  ));
+  fs::create_dir_all(&job_root_directory_path).map_err(|error| error.to_string())?;
+  write_job_settings(&job_root_directory_path, &settings)?;
+  prepare_job_directory(job_runtime_state, &job_root_directory_path)?;
+  match content {
+    QuickJobContent::Files(input_paths) => {
+      copy_input_paths_into_job(job_runtime_state, &job_root_directory_path, input_paths)?
+    }
+    QuickJobContent::Image { width, height, rgba } => {
+      let png_bytes = encode_clipboard_image_png(width, height, &rgba)?;
+      fs::write(job_input_directory_path(&job_root_directory_path).join(CLIPBOARD_IMAGE_FILENAME), png_bytes)
+        .map_err(|error| error.to_string())?;
+    }
+  }
+  spawn_job_process(job_runtime_state.clone(), job_root_directory_path.clone(), JobStartSource::QuickJob)?;
+  Ok(job_root_directory_path)
+}
+
+// NOTE: Tray and hotkey handlers run on the event loop; the clipboard read and file copies must not block it.
+fn trigger_quick_job_from_clipboard(app_handle: tauri::AppHandle<Wry>, quick_job_name: String) {
+  std::thread::spawn(move || {
+    let job_runtime_state = app_handle.state::<SharedJobRuntimeState>().inner().clone();
+    let quick_job_state = app_handle.state::<SharedQuickJobRuntimeState>().inner().clone();
+    let result = read_quick_job_clipboard_content(&app_handle)
+      .and_then(|content| create_and_run_quick_job(&job_runtime_state, &quick_job_name, content));
+    record_quick_job_result(&quick_job_state, &result);
+  });
+}
+
+fn apply_quick_job_bindings(app_handle: &tauri::AppHandle<Wry>, definitions: &[QuickJobDefinition]) -> Result<(), String> {
+  let global_shortcut = app_handle.global_shortcut();
+  global_shortcut.unregister_all().map_err(|error| error.to_string())?;
+  let mut quick_job_name_by_hotkey_id: HashMap<u32, String> = HashMap::new();
+  for definition in definitions {
+    let Some(shortcut) = parse_quick_job_hotkey(definition)? else {
+      continue;
+    };
+    global_shortcut
+      .register(shortcut)
+      .map_err(|error| format!("Failed to register hotkey for quick job {}: {error}", definition.name.trim()))?;
+    quick_job_name_by_hotkey_id.insert(shortcut.id(), definition.name.trim().to_string());
+  }
+  replace_quick_job_hotkeys(app_handle.state::<SharedQuickJobRuntimeState>().inner(), quick_job_name_by_hotkey_id);
+
+  if definitions.is_empty() {
+    let _ = app_handle.remove_tray_by_id(QUICK_JOB_TRAY_ICON_ID);
+    return Ok(());
+  }
+  let menu = Menu::<Wry>::new(app_handle).map_err(|error| error.to_string())?;
+  for definition in definitions {
+    let name = definition.name.trim();
+    let menu_item = MenuItem::with_id(
+      app_handle,
+      quick_job_menu_item_id(name),
+      format!("{name} (clipboard)"),
+      true,
+      None::<&str>,
+    )
+    .map_err(|error| error.to_string())?;
+    menu.append(&menu_item).map_err(|error| error.to_string())?;
+  }
+  if let Some(tray_icon) = app_handle.tray_by_id(QUICK_JOB_TRAY_ICON_ID) {
+    return tray_icon.set_menu(Some(menu)).map_err(|error| error.to_string());
+  }
+  let mut tray_icon_builder = TrayIconBuilder::with_id(QUICK_JOB_TRAY_ICON_ID)
+    .menu(&menu)
+    .tooltip("ocr-agent quick jobs")
+    .on_menu_event(|app_handle, event| {
+      if let Some(quick_job_name) = parse_quick_job_menu_item_id(event.id().as_ref()) {
+        trigger_quick_job_from_clipboard(app_handle.clone(), quick_job_name.to_string());
+      }
+    });
+  if let Some(icon) = app_handle.default_window_icon() {
+    tray_icon_builder = tray_icon_builder.icon(icon.clone());
+  }
+  tray_icon_builder.build(app_handle).map_err(|error| error.to_string())?;
+  Ok(())
+}
+
+fn run_onedrive_write_back(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  target: &OneDriveWriteBackTarget,
+) -> Result<String, String> {
+  let output_markdown_path = detect_last_output_markdown_path(job_root_directory_path)
+    .ok_or_else(|| "No output markdown to upload.".to_string())?;
+  let settings = require_onedrive_connector_settings(job_runtime_state)?;
+  GraphSession::new(settings).upload_result_file(target, Path::new(&output_markdown_path))
+}
+
+fn detect_last_output_markdown_path(job_root_directory_path: &Path) -> Option<String> {
+  let settings = read_job_settings_best_effort(job_root_directory_path);
+  let filename = settings.last_output_markdown_filename?;
+  Some(job_root_directory_path.join(filename).to_string_lossy().to_string())
+}
+
+#[tauri::command]
+async fn run_job(
+  job_root_directory_path: String,
+  output_markdown_filename_override: Option<String>,
+  is_math_delimiter_conversion_enabled: Option<bool>,
+  is_hyphenation_repair_enabled: Option<bool>,
+  is_paragraph_reflow_enabled: Option<bool>,
+  deepseek_ocr2_model_revision: Option<String>,
+  deepseek_ocr2_markdown_prompt: Option<String>,
+  deepseek_ocr2_base_image_size_pixels: Option<u32>,
+  deepseek_ocr2_inference_image_size_pixels: Option<u32>,
+  deepseek_ocr2_enable_crop_mode: Option<bool>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  let job_runtime_state = job_runtime_state.inner().clone();
+  // NOTE: `docker version`, the input scan and the settings write can each take seconds (slow engine, network share);
+  // off the IPC thread the webview keeps rendering meanwhile.
+  tauri::async_runtime::spawn_blocking(move || {
+    validate_execution_engine_available(&job_runtime_state)?;
+
+    let job_root_directory_path = PathBuf::from(job_root_directory_path);
+    prepare_job_directory(&job_runtime_state, &job_root_directory_path)?;
+
+    let input_directory_path = job_input_directory_path(&job_root_directory_path);
+    let has_any_input_files = walkdir::WalkDir::new(&input_directory_path)
+      .into_iter()
+      .filter_map(|entry| entry.ok())
+      .any(|entry| entry.path().is_file());
+    if !has_any_input_files {
+      // Guard: prevent a confusing no-op run.
+      return Err("No input files found under input/. Drop images or PDFs first.".to_string());
+    }
+
+    let mut settings = read_job_settings_best_effort(&job_root_directory_path);
+    let override_candidate = output_markdown_filename_override
+      .unwrap_or_default()
+      .trim()
+      .to_string();
+    if override_candidate.is_empty() {
+      settings.output_markdown_filename_override = None;
+    } else {
+      settings.output_markdown_filename_override = Some(override_candidate);
+    }
+    settings.is_math_delimiter_conversion_enabled = is_math_delimiter_conversion_enabled;
+    settings.is_hyphenation_repair_enabled = is_hyphenation_repair_enabled;
+    settings.is_paragraph_reflow_enabled = is_paragraph_reflow_enabled;
+
+    settings.deepseek_ocr2_model_revision = deepseek_ocr2_model_revision;
+    settings.deepseek_ocr2_markdown_prompt = deepseek_ocr2_markdown_prompt;
+
+    if let Some(base_image_size_pixels) = deepseek_ocr2_base_image_size_pixels {
+      if base_image_size_pixels <= 0 {
+        // Guard: reject invalid sizes early.
+        return Err("deepseek_ocr2_base_image_size_pixels must be > 0".to_string());
+      }
+      settings.deepseek_ocr2_base_image_size_pixels = Some(base_image_size_pixels);
+    }
+
+    if let Some(inference_image_size_pixels) = deepseek_ocr2_inference_image_size_pixels {
+      if inference_image_size_pixels <= 0 {
+        // Guard: reject invalid sizes early.
+        return Err("deepseek_ocr2_inference_image_size_pixels must be > 0".to_string());
+      }
+      settings.deepseek_ocr2_inference_image_size_pixels = Some(inference_image_size_pixels);
+    }
+
+    settings.deepseek_ocr2_enable_crop_mode = deepseek_ocr2_enable_crop_mode;
+    write_job_settings(&job_root_directory_path, &settings)?;
+
+    spawn_job_process(job_runtime_state, job_root_directory_path, JobStartSource::Gui)?;
+    Ok(())
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+// NOTE: Graceful by default: the run stops after its current page and is stopped outright only if it does not exit
+// within `GRACEFUL_CANCEL_TIMEOUT`; `immediate` stops it right away.
+#[tauri::command]
+fn cancel_job(
+  job_root_directory_path: String,
+  mode: Option<JobCancelMode>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  request_job_cancellation(
+    job_runtime_state.inner(),
+    &PathBuf::from(job_root_directory_path),
+    mode.unwrap_or_default(),
+  )
+}
+
+fn request_job_cancellation(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  mode: JobCancelMode,
+) -> Result<(), String> {
+  let (start_unix_timestamp_millis, paused_targets) = {
+    let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    let Some(running) = locked_state.running_job_by_root.get_mut(job_root_directory_path) else {
+      if locked_state.job_start_queue.remove(job_root_directory_path) {
+        // NOTE: A queued start has no process either; cancelling it only takes it off the job queue.
+        drop(locked_state);
+        append_log_line(
+          job_runtime_state,
+          job_root_directory_path,
+          "[backend] removed from the job queue".to_string(),
+        );
+        return Ok(());
+      }
+      // NOTE: A suspended job has no process; cancelling it only stops the automatic resume.
+      let suspended_job = locked_state.suspended_job_by_root.remove(job_root_directory_path);
+      drop(locked_state);
+      if suspended_job.is_some_and(|suspended_job| suspended_job.cause == SuspensionCause::DockerEngineUnavailable) {
+        // NOTE: Nothing will resume it anymore, so `interrupted` would otherwise stay forever.
+        transition_job_state_best_effort(job_runtime_state, job_root_directory_path, JobStateStatus::Failed, |state| {
+          state.error_message = Some("Interrupted run was cancelled before it resumed".to_string());
+        });
+      }
+      return Ok(());
+    };
+    if running.is_cancel_requested && mode == JobCancelMode::Graceful {
+      // Guard: the run is already stopping; a second graceful cancel would only start another timer.
+      return Ok(());
+    }
+    running.is_cancel_requested = true;
+    running
+      .cancellation
+      .get_or_insert_with(|| JobCancellationRecord::new(mode, now_unix_timestamp_millis()));
+    (
+      running.start_unix_timestamp_millis,
+      running
+        .paused_unix_timestamp_millis
+        .take()
+        .map(|_| running.pause_targets.clone()),
+    )
+  };
+
+  if let Some(paused_targets) = paused_targets {
+    // NOTE: A frozen run can neither see the cancel marker nor handle SIGTERM; thaw it first.
+    if let Err(error) = set_job_paused(&paused_targets, false) {
+      append_log_line(
+        job_runtime_state,
+        job_root_directory_path,
+        format!("[backend] resume before cancel failed: {error}"),
+      );
+    }
+  }
+
+  if mode == JobCancelMode::Graceful {
+    match write_cancel_marker(&job_output_directory_path(job_root_directory_path)) {
+      Ok(()) => {
+        append_log_line(
+          job_runtime_state,
+          job_root_directory_path,
+          format!(
+            "[backend] cancellation requested (graceful); stopping after the current page, at most {}s",
+            GRACEFUL_CANCEL_TIMEOUT.as_secs()
+          ),
+        );
+        spawn_graceful_cancel_escalation(
+          job_runtime_state.clone(),
+          job_root_directory_path.to_path_buf(),
+          start_unix_timestamp_millis,
+        );
+        return Ok(());
+      }
+      // NOTE: Without the marker the run would never notice the cancel, so it is stopped right away instead.
+      Err(error) => append_log_line(
+        job_runtime_state,
+        job_root_directory_path,
+        format!("[backend] {error}; stopping the run now"),
+      ),
+    }
+  }
+  stop_cancelled_job_processes(job_runtime_state, job_root_directory_path);
+  append_log_line(
+    job_runtime_state,
+    job_root_directory_path,
+    "[backend] cancellation requested (immediate)".to_string(),
+  );
+  Ok(())
+}
+
+fn spawn_graceful_cancel_escalation(
+  job_runtime_state: SharedJobRuntimeState,
+  job_root_directory_path: PathBuf,
+  start_unix_timestamp_millis: i64,
+) {
+  let is_same_run_running = move |job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path| {
+    job_runtime_state
+      .lock()
+      .map(|locked_state| {
+        locked_state
+          .running_job_by_root
+          .get(job_root_directory_path)
+          .is_some_and(|running| running.start_unix_timestamp_millis == start_unix_timestamp_millis)
+      })
+      .unwrap_or(false)
+  };
+  thread::spawn(move || {
+    let deadline = Instant::now() + GRACEFUL_CANCEL_TIMEOUT;
+    while Instant::now() < deadline {
+      thread::sleep(GRACEFUL_CANCEL_POLL_INTERVAL);
+      if !is_same_run_running(&job_runtime_state, &job_root_directory_path) {
+        return;
+      }
+    }
+    append_log_line(
+      &job_runtime_state,
+      &job_root_directory_path,
+      format!(
+        "[backend] graceful cancellation timed out after {}s; stopping the run",
+        GRACEFUL_CANCEL_TIMEOUT.as_secs()
+      ),
+    );
+    stop_cancelled_job_processes(&job_runtime_state, &job_root_directory_path);
+  });
+}
+
+// NOTE: Signals the run's container or process group instead of killing `child`: the waiter holds the child's lock
+// while waiting, and a killed compose client would leave its container running.
+fn stop_cancelled_job_processes(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path) {
+  let (child_handle, worker_children, pause_targets) = {
+    let Ok(mut locked_state) = job_runtime_state.lock() else {
+      return;
+    };
+    let Some(running) = locked_state.running_job_by_root.get_mut(job_root_directory_path) else {
+      return;
+    };
+    if let Some(cancellation) = running.cancellation.as_mut() {
+      cancellation
+        .escalated_unix_timestamp_millis
+        .get_or_insert_with(now_unix_timestamp_millis);
+    }
+    (
+      running.child.clone(),
+      running.worker_children.clone(),
+      running.pause_targets.clone(),
+    )
+  };
+
+  for error in terminate_job_targets(&pause_targets) {
+    append_log_line(
+      job_runtime_state,
+      job_root_directory_path,
+      format!("[backend] stopping the run failed: {error}"),
+    );
+  }
+  // NOTE: Workers are only reaped by the primary's waiter thread, so their locks are free to take here.
+  for worker_child in worker_children {
+    if let Ok(mut worker_guard) = worker_child.lock() {
+      let _ = worker_guard.kill();
+    }
+  }
+  if let Ok(mut child_guard) = child_handle.try_lock() {
+    let _ = child_guard.kill();
+  }
+}
+
+// NOTE: Freezes the run in place (GPU memory stays allocated); `resume_job` continues where it stopped.
+#[tauri::command]
+fn pause_job(job_root_directory_path: String, job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<(), String> {
+  set_running_job_paused(job_runtime_state.inner(), &PathBuf::from(job_root_directory_path), true)
+}
+
+// NOTE: Unpauses a paused run; a job that is not running is relaunched from its existing queue instead.
+#[tauri::command]
+fn resume_job(job_root_directory_path: String, job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<(), String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  let is_running = job_runtime_state
+    .lock()
+    .map_err(|_| "State lock poisoned".to_string())?
+    .running_job_by_root
+    .contains_key(&job_root_directory_path);
+  if is_running {
+    return set_running_job_paused(job_runtime_state.inner(), &job_root_directory_path, false);
+  }
+  resume_interrupted_job(job_runtime_state.inner(), &job_root_directory_path)
+}
+
+// NOTE: `status_filter` is one of pending/running/completed/failed; the page size is capped at 1000.
+#[tauri::command]
+fn list_job_tasks(
+  job_root_directory_path: String,
+  status_filter: Option<String>,
+  offset: Option<usize>,
+  limit: Option<usize>,
+) -> Result<JobTaskPage, String> {
+  let queue_database_path = get_queue_database_path(&PathBuf::from(job_root_directory_path));
+  query_job_tasks(
+    &queue_database_path,
+    status_filter.as_deref().map(str::trim).filter(|status| !status.is_empty()),
+    offset.unwrap_or(0),
+    limit.unwrap_or(DEFAULT_JOB_TASK_PAGE_SIZE),
+  )
+}
+
+// NOTE: Without `task_ids` every failed page is retried; with them only those pages, and the others stay failed.
+#[tauri::command]
+fn retry_failed_tasks(
+  job_root_directory_path: String,
+  task_ids: Option<Vec<i64>>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<JobStartOutcome, String> {
+  let job_runtime_state = job_runtime_state.inner();
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  let queue_database_path = get_queue_database_path(&job_root_directory_path);
+  if !queue_database_path.exists()
+    || read_job_settings_best_effort(&job_root_directory_path)
+      .last_output_markdown_filename
+      .is_none()
+  {
+    return Err("This job has not been run yet.".to_string());
+  }
+  {
+    let locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    if locked_state.running_job_by_root.contains_key(&job_root_directory_path)
+      || locked_state.job_start_queue.is_queued(&job_root_directory_path)
+    {
+      // Guard: a live run still owns its `running` rows; resetting them would hand the same page out twice.
+      return Err("This job is running or waiting for a job slot.".to_string());
+    }
+  }
+  let reset_task_count = match task_ids.as_deref() {
+    Some(task_ids) if !task_ids.is_empty() => reset_selected_failed_tasks_to_pending(&queue_database_path, task_ids)?,
+    _ => reset_failed_tasks_to_pending(&queue_database_path)?,
+  };
+  if reset_task_count == 0 {
+    return Err("This job has no failed pages to retry.".to_string());
+  }
+  append_log_line(
+    job_runtime_state,
+    &job_root_directory_path,
+    format!("[backend] retrying {reset_task_count} failed page(s)"),
+  );
+  start_or_queue_job_run(
+    job_runtime_state.clone(),
+    job_root_directory_path,
+    JobRunMode::RetryFailedTasks,
+    JobStartSource::Gui,
+  )
+}
+
+// NOTE: Recovery after a crash without `reset_job_directory`: finished pages stay done, tasks the dead run left
+// `running` (and failed ones) go back to `pending`, and only the `run` phase is launched; nothing is enqueued again.
+fn resume_interrupted_job(job_runtime_state: &SharedJobRuntimeState, job_root_directory_path: &Path) -> Result<(), String> {
+  let queue_database_path = get_queue_database_path(job_root_directory_path);
+  if !queue_database_path.exists() {
+    return Err("This job has no queue to resume from; start it instead.".to_string());
+  }
+  if read_job_settings_best_effort(job_root_directory_path)
+    .last_output_markdown_filename
+    .is_none()
+  {
+    // Guard: the run phase writes into the previous run's markdown; without one there is nothing to continue.
+    return Err("This job has no previous run to resume.".to_string());
+  }
+  let heartbeat_status = describe_job_heartbeat(job_root_directory_path, now_unix_timestamp_millis());
+  if let Some(heartbeat) = heartbeat_status.heartbeat.as_ref().filter(|_| !heartbeat_status.is_stale) {
+    if heartbeat.pid != std::process::id() {
+      // Guard: a live run elsewhere still owns these `running` rows; requeueing them would process pages twice.
+      return Err(format!(
+        "This job is still driven by another ocr-agent process (pid {}, heartbeat {}s ago).",
+        heartbeat.pid,
+        heartbeat_status.age_seconds.unwrap_or_default()
+      ));
+    }
+  }
+  let status_counts = query_status_counts(&queue_database_path)?;
+  let pending_task_count = status_counts.get("pending").copied().unwrap_or(0);
+  let reset_task_count = reset_interrupted_and_failed_tasks_to_pending(&queue_database_path)?;
+  if pending_task_count == 0 && reset_task_count == 0 {
+    return Err("Every page of this job is already done; nothing to resume.".to_string());
+  }
+  append_log_line(
+    job_runtime_state,
+    job_root_directory_path,
+    format!(
+      "[backend] resuming: {pending_task_count} pending, {reset_task_count} interrupted or failed page(s) requeued"
+    ),
+  );
+  start_or_queue_job_run(
+    job_runtime_state.clone(),
+    job_root_directory_path.to_path_buf(),
+    JobRunMode::RetryFailedTasks,
+    JobStartSource::Resume,
+  )
+  .map(|_| ())
+}
+
+fn set_running_job_paused(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  is_paused: bool,
+) -> Result<(), String> {
+  let pause_targets = {
+    let locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    let running = locked_state
+      .running_job_by_root
+      .get(job_root_directory_path)
+      .ok_or_else(|| "No job is running for this output directory.".to_string())?;
+    if running.is_cancel_requested {
+      // Guard: a cancelled run is being torn down; freezing it would stall the teardown.
+      return Err("This job is being cancelled.".to_string());
+    }
+    if running.paused_unix_timestamp_millis.is_some() == is_paused {
+      return Ok(());
+    }
+    running.pause_targets.clone()
+  };
+  // NOTE: Runs without the state lock; `docker pause` can take a moment and status polling must not block on it.
+  let skipped_worker_messages = set_job_paused(&pause_targets, is_paused)?;
+  {
+    let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    if let Some(running) = locked_state.running_job_by_root.get_mut(job_root_directory_path) {
+      running.paused_unix_timestamp_millis = is_paused.then(now_unix_timestamp_millis);
+    }
+  }
+  for message in skipped_worker_messages {
+    append_log_line(job_runtime_state, job_root_directory_path, format!("[backend] {message}"));
+  }
+  append_log_line(
+    job_runtime_state,
+    job_root_directory_path,
+    if is_paused { "[backend] paused" } else { "[backend] resumed" }.to_string(),
+  );
+  Ok(())
+}
+
+#[tauri::command]
+fn list_suspended_jobs(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<Vec<SuspendedJob>, String> {
+  let locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+  let mut suspended_jobs: Vec<SuspendedJob> = locked_state.suspended_job_by_root.values().cloned().collect();
+  suspended_jobs.sort_by_key(|suspended_job| suspended_job.suspended_unix_timestamp_millis);
+  Ok(suspended_jobs)
+}
+
+#[tauri::command]
+fn list_job_runs(job_root_directory_path: String) -> Result<Vec<JobRunRecord>, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  validate_job_root_directory(&job_root_directory_path)?;
+  read_job_runs(&job_root_directory_path)
+}
+
+fn run_output_of_record(record: &JobRunRecord) -> RunOutput<'_> {
+  RunOutput {
+    run_number: record.run_number,
+    output_markdown_path: Path::new(&record.output_markdown_path),
+    model_revision: record
+      .settings_snapshot
+      .get("deepseek_ocr2_model_revision")
+      .and_then(|value| value.as_str())
+      .map(|value| value.to_string()),
+  }
+}
+
+#[tauri::command]
+async fn compare_runs(job_root_directory_path: String, run_a: u32, run_b: u32) -> Result<RunComparison, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  validate_job_root_directory(&job_root_directory_path)?;
+  // NOTE: The character error rate is quadratic in document length, so keep it off the IPC thread.
+  tauri::async_runtime::spawn_blocking(move || {
+    let runs = read_job_runs(&job_root_directory_path)?;
+    let find_run = |run_number: u32| {
+      runs
+        .iter()
+        .find(|run| run.run_number == run_number)
+        .ok_or_else(|| format!("Run {run_number} is not in this job's run history."))
+    };
+    let (record_a, record_b) = (find_run(run_a)?, find_run(run_b)?);
+    compare_run_outputs(&job_root_directory_path, run_output_of_record(record_a), run_output_of_record(record_b))
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+// NOTE: Only jobs seen since startup (prepared, run, polled or ingested) are known by id.
+#[tauri::command]
+fn find_job_root_by_id(job_id: String, job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<Option<String>, String> {
+  let locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+  Ok(
+    locked_state
+      .job_root_by_id
+      .get(job_id.trim())
+      .map(|job_root_directory_path| job_root_directory_path.to_string_lossy().to_string()),
+  )
+}
+
+#[tauri::command]
+async fn list_running_job_containers() -> Result<Vec<ProjectContainer>, String> {
+  tauri::async_runtime::spawn_blocking(|| {
+    validate_docker_available()?;
+    let repo_root = repo_root_path()?;
+    list_running_project_containers(&derive_compose_project_name(&repo_root))
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+#[tauri::command]
+fn adopt_job_container(
+  container_id: String,
+  job_root_directory_path: String,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  validate_docker_available()?;
+  let repo_root = repo_root_path()?;
+  let Some(project_container) = list_running_project_containers(&derive_compose_project_name(&repo_root))?
+    .into_iter()
+    .find(|container| container.container_id == container_id)
+  else {
+    // Guard: only containers of this compose project may be stopped through cancel_job later.
+    return Err(format!("No running ocr-agent container with id {container_id}."));
+  };
+  let heartbeat_status = describe_job_heartbeat(&job_root_directory_path, now_unix_timestamp_millis());
+  if let Some(heartbeat) = heartbeat_status.heartbeat.as_ref().filter(|_| !heartbeat_status.is_stale) {
+    if heartbeat.pid != std::process::id() {
+      // Guard: a fresh heartbeat means another live ocr-agent process still drives this root; only abandoned runs are adopted.
+      return Err(format!(
+        "This job is still driven by another ocr-agent process (pid {}, heartbeat {}s ago).",
+        heartbeat.pid,
+        heartbeat_status.age_seconds.unwrap_or_default()
+      ));
+    }
+  }
+
+  let job_runtime_state = job_runtime_state.inner().clone();
+  let mut child = spawn_container_log_follower(&container_id)?;
+  let stdout = child.stdout.take();
+  let stderr = child.stderr.take();
+  let child_handle = Arc::new(Mutex::new(child));
+  {
+    let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    if locked_state.running_job_by_root.contains_key(&job_root_directory_path) {
+      if let Ok(mut child_guard) = child_handle.lock() {
+        let _ = child_guard.kill();
+      }
+      // Guard: the root already streams from a job this session started.
+      return Err("A job is already running for this output directory.".to_string());
+    }
+    locked_state.job_root_index.invalidate(&job_root_directory_path);
+    locked_state.running_job_by_root.insert(
+      job_root_directory_path.clone(),
+      RunningJobHandle {
+        child: child_handle.clone(),
+        worker_children: vec![],
+        // NOTE: Adoption time, not container start time; the ETA then only reflects progress seen from now on.
+        start_unix_timestamp_millis: now_unix_timestamp_millis(),
+        is_cancel_requested: false,
+        cancellation: None,
+        // NOTE: Adopted runs are never suspended; their waiter only follows the container's logs.
+        volume_kind: VolumeKind::Unknown,
+        is_docker_engine: false,
+        container_name: Some(project_container.name),
+        pause_targets: vec![JobPauseTarget::primary(JobPauseTargetKind::Container(container_id.clone()))],
+        paused_unix_timestamp_millis: None,
+      },
+    );
+  }
+  append_log_line(
+    &job_runtime_state,
+    &job_root_directory_path,
+    format!("[backend] adopted container {container_id}"),
+  );
+
+  if let Some(stream) = stdout {
+    spawn_log_reader_thread(job_runtime_state.clone(), job_root_directory_path.clone(), stream, "stdout");
+  }
+  if let Some(stream) = stderr {
+    spawn_log_reader_thread(job_runtime_state.clone(), job_root_directory_path.clone(), stream, "stderr");
+  }
+
+  std::thread::spawn(move || {
+    let wait_result = match child_handle.lock() {
+      Ok(mut child_guard) => child_guard.wait(),
+      Err(_) => return,
+    };
+    // NOTE: Post-run passes are skipped: the adopted process owns its own merge and the exit code is not observable.
+    append_log_line(
+      &job_runtime_state,
+      &job_root_directory_path,
+      match wait_result {
+        Ok(_) => format!("[backend] adopted container {container_id} exited"),
+        Err(error) => format!("[backend] wait error: {error}"),
+      },
+    );
+    let is_cancel_requested = job_runtime_state
+      .lock()
+      .ok()
+      .and_then(|locked_state| {
+        locked_state
+          .running_job_by_root
+          .get(&job_root_directory_path)
+          .map(|running| running.is_cancel_requested)
+      })
+      .unwrap_or(false);
+    if is_cancel_requested {
+      requeue_cancelled_run_tasks_best_effort(&job_runtime_state, &job_root_directory_path);
+    }
+    if let Ok(mut locked_state) = job_runtime_state.lock() {
+      locked_state.running_job_by_root.remove(&job_root_directory_path);
+      locked_state.job_root_index.invalidate(&job_root_directory_path);
+    }
+  });
+  Ok(())
+}
+
+#[tauri::command]
+fn get_job_logs(job_root_directory_path: String, job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<JobLogResponse, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  load_evicted_job_log_best_effort(
+    job_runtime_state.inner(),
+    &job_root_directory_path,
+    current_runtime_limits().max_log_lines,
+  );
+  let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+  let lines = locked_state
+    .job_log_store
+    .lines(&job_root_directory_path)
+    .unwrap_or_default();
+  Ok(JobLogResponse { lines })
+}
+
+#[tauri::command]
+fn get_current_task_preview(
+  job_root_directory_path: String,
+  lookahead: Option<bool>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<Option<CurrentTaskPreview>, String> {
+  let cache_key = (PathBuf::from(job_root_directory_path), lookahead.unwrap_or(false));
+  {
+    let locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    if let Some(cached_preview) = locked_state.task_preview_cache.get_fresh(&cache_key) {
+      return Ok(cached_preview);
+    }
+  }
+  let preview = build_current_task_preview(job_runtime_state.inner(), &cache_key.0, cache_key.1)?;
+  if let Ok(mut locked_state) = job_runtime_state.lock() {
+    locked_state.task_preview_cache.insert(cache_key, preview.clone());
+  }
+  Ok(preview)
+}
+
+fn build_current_task_preview(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  is_lookahead_enabled: bool,
+) -> Result<Option<CurrentTaskPreview>, String> {
+  prepare_job_directory(job_runtime_state, job_root_directory_path)?;
+  let is_running = job_runtime_state
+    .lock()
+    .map(|locked_state| locked_state.running_job_by_root.contains_key(job_root_directory_path))
+    .unwrap_or(false);
+  if !is_running {
+    let queue_snapshot = load_queue_snapshot_indexed(job_runtime_state, job_root_directory_path, false)?;
+    let has_previewable_task = queue_snapshot.count("running") > 0
+      || (is_lookahead_enabled && queue_snapshot.count("pending") > 0);
+    if !queue_snapshot.has_queue || !has_previewable_task {
+      // Guard: an idle job's indexed queue has nothing to preview; skip opening it again.
+      return Ok(None);
+    }
+  }
+
+  let Some(mut task) = query_task_preview(job_root_directory_path, is_lookahead_enabled)? else {
+    return Ok(None);
+  };
+
+  let settings = read_job_settings_best_effort(job_root_directory_path);
+  task.deepseek_inference_image_size_pixels = settings.deepseek_ocr2_inference_image_size_pixels;
+
+  let preview_path = if is_lookahead_enabled {
+    resolve_lookahead_preview_image_path(job_root_directory_path, &task)
+  } else {
+    resolve_preview_image_path_for_task(job_root_directory_path, &task)
+  };
+  task.preview_image_file_path = preview_path.map(|path| path.to_string_lossy().to_string());
+  if let Some(next_task) = task.next_task.as_mut() {
+    next_task.deepseek_inference_image_size_pixels = settings.deepseek_ocr2_inference_image_size_pixels;
+    next_task.preview_image_file_path = resolve_lookahead_preview_image_path(job_root_directory_path, next_task)
+      .map(|path| path.to_string_lossy().to_string());
+  }
+
+  Ok(Some(task))
+}
+
+#[tauri::command]
+fn get_current_task_preview_image_bytes(
+  job_root_directory_path: String,
+  lookahead: Option<bool>,
+) -> Result<Option<PreviewImageBytes>, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  ensure_job_directory_layout(&job_root_directory_path)?;
+
+  let is_lookahead_enabled = lookahead.unwrap_or(false);
+  let Some(task) = query_task_preview(&job_root_directory_path, is_lookahead_enabled)? else {
+    return Ok(None);
+  };
+  let image_path = if is_lookahead_enabled {
+    resolve_lookahead_preview_image_path(&job_root_directory_path, &task)
+  } else {
+    resolve_preview_image_path_for_task(&job_root_directory_path, &task)
+  };
+  let Some(image_path) = image_path else {
+    return Ok(None);
+  };
+  if !image_path.exists() {
+    // Guard: preview can lag behind rendering; treat missing as "not ready".
+    return Ok(None);
+  }
+  let metadata = fs::metadata(&image_path).map_err(|error| error.to_string())?;
+  if !metadata.is_file() {
+    // Guard: refuse non-files for preview reads.
+    return Ok(None);
+  }
+  let max_preview_image_bytes = current_runtime_limits().max_preview_image_bytes;
+  if metadata.len() > max_preview_image_bytes {
+    return Err(format!(
+      "Preview image is too large to load in GUI ({} bytes, limit {max_preview_image_bytes}; see max_preview_image_bytes).",
+      metadata.len()
+    ));
+  }
+
+  let bytes = fs::read(&image_path).map_err(|error| error.to_string())?;
+  Ok(Some(PreviewImageBytes {
+    mime_type: infer_image_mime_type(&image_path),
+    bytes,
+  }))
+}
+
+fn resolve_job_input_file_path(job_root_directory_path: &Path, input_relative_path: &str) -> Result<PathBuf, String> {
+  let relative_path = Path::new(input_relative_path.trim());
+  let is_contained = !relative_path.as_os_str().is_empty()
+    && relative_path
+      .components()
+      .all(|component| matches!(component, std::path::Component::Normal(_)));
+  if !is_contained {
+    // Guard: only files under the job's input directory may be read through this command.
+    return Err(format!("Invalid input path: {input_relative_path}"));
+  }
+  let input_file_path = job_input_directory_path(job_root_directory_path).join(relative_path);
+  if !input_file_path.is_file() {
+    return Err(format!("Input file not found: {}", input_file_path.display()));
+  }
+  Ok(input_file_path)
+}
+
+// NOTE: Async so pdfium rendering runs off the main thread; no container or queue is needed.
+#[tauri::command]
+async fn render_pdf_page_preview(
+  job_root_directory_path: String,
+  input_relative_path: String,
+  page_index: i64,
+) -> Result<PdfPagePreview, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  validate_job_root_directory(&job_root_directory_path)?;
+  let pdf_file_path = resolve_job_input_file_path(&job_root_directory_path, &input_relative_path)?;
+  let rendered_page = tauri::async_runtime::spawn_blocking(move || {
+    render_pdf_page(&pdf_file_path, page_index, PDF_PAGE_BROWSE_DOTS_PER_INCH)
+  })
+  .await
+  .map_err(|error| error.to_string())??;
+  Ok(PdfPagePreview {
+    page_index,
+    total_pages: rendered_page.total_pages,
+    width_pixels: rendered_page.width_pixels,
+    height_pixels: rendered_page.height_pixels,
+    dots_per_inch: PDF_PAGE_BROWSE_DOTS_PER_INCH,
+    mime_type: "image/png".to_string(),
+    bytes: rendered_page.png_bytes,
+  })
+}
+
+// NOTE: Trials run one after another under `<output>/work/tuning/`; the job's own queue and markdown are never touched.
+fn run_settings_tuning(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  sample_page_count: usize,
+) -> Result<SettingsTuningReport, String> {
+  {
+    let locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+    if locked_state.running_job_by_root.contains_key(job_root_directory_path) {
+      // Guard: trials would compete with the running job for the same GPU and skew the timings.
+      return Err("A job is running for this directory; tune settings before or after it.".to_string());
+    }
+  }
+  let repo_root = repo_root_path()?;
+  let job_root_canonical = job_root_directory_path
+    .canonicalize()
+    .map_err(|error| format!("Failed to canonicalize job root: {error}"))?;
+  let job_engine = resolve_job_engine(job_runtime_state, &job_root_canonical)?;
+  let settings = read_job_settings_best_effort(job_root_directory_path);
+  let directory_layout = job_directory_layout(job_root_directory_path);
+  let input_directory_path = job_input_directory_path(job_root_directory_path);
+  let sampled_pages = sample_input_pages(&input_directory_path, sample_page_count, &count_pdf_pages)?;
+
+  let tuning_relative_path = format!(
+    "{}/work/{SETTINGS_TUNING_DIRECTORY_NAME}",
+    directory_layout.output_directory_name
+  );
+  let tuning_directory_path = job_root_directory_path.join(&tuning_relative_path);
+  if tuning_directory_path.exists() {
+    // Guard: leftovers from an earlier tuning run would be enqueued with the new sample.
+    fs::remove_dir_all(&tuning_directory_path).map_err(|error| error.to_string())?;
+  }
+  let sample_directory_path = job_root_directory_path
+    .join(&tuning_relative_path)
+    .join(SETTINGS_TUNING_SAMPLE_DIRECTORY_NAME);
+  fs::create_dir_all(&sample_directory_path).map_err(|error| error.to_string())?;
+  for (index, page) in sampled_pages.iter().enumerate() {
+    match page.pdf_page_index {
+      Some(pdf_page_index) => {
+        let sample_file_path = sample_directory_path.join(format!("sample_{:03}.png", index + 1));
+        render_pdf_page_to_png(&page.source_file_path, pdf_page_index, &sample_file_path)?;
+      }
+      None => {
+        let extension = page
+          .source_file_path
+          .extension()
+          .and_then(|extension| extension.to_str())
+          .unwrap_or("png");
+        let sample_file_path = sample_directory_path.join(format!("sample_{:03}.{extension}", index + 1));
+        fs::copy(&page.source_file_path, &sample_file_path).map_err(|error| error.to_string())?;
+      }
+    }
+  }
+
+  let trials = settings_tuning_trials();
+  let mut trial_results: Vec<SettingsTuningTrialResult> = vec![];
+  for (trial_index, trial) in trials.iter().enumerate() {
+    append_log_line(
+      job_runtime_state,
+      job_root_directory_path,
+      format!("[tuning] trial {}/{}: {}", trial_index + 1, trials.len(), trial.directory_name()),
+    );
+    trial_results.push(run_settings_tuning_trial(
+      &repo_root,
+      &job_engine,
+      &settings,
+      job_root_directory_path,
+      &tuning_relative_path,
+      *trial,
+      sampled_pages.len(),
+    ));
+  }
+
+  let (recommended_trial, recommendation_reason) = recommend_trial(&trial_results);
+  append_log_line(
+    job_runtime_state,
+    job_root_directory_path,
+    match recommended_trial.as_ref() {
+      Some(trial) => format!("[tuning] recommended: {} ({recommendation_reason})", trial.directory_name()),
+      None => format!("[tuning] no recommendation: {recommendation_reason}"),
+    },
+  );
+  let report = SettingsTuningReport {
+    sampled_pages: sampled_pages
+      .iter()
+      .map(|page| describe_sampled_page(&input_directory_path, page))
+      .collect(),
+    trial_results,
+    recommended_trial,
+    recommendation_reason,
+  };
+  let serialized = serde_json::to_string_pretty(&report).map_err(|error| error.to_string())?;
+  fs::write(tuning_directory_path.join(SETTINGS_TUNING_REPORT_FILENAME), serialized).map_err(|error| error.to_string())?;
+  Ok(report)
+}
+
+// NOTE: Reports the typed cause when known (e.g. OOM at the largest size), otherwise the last output line.
+fn summarize_failed_cli_output(output: &std::process::Output) -> String {
+  let output_lines: Vec<String> = String::from_utf8_lossy(&output.stdout)
+    .lines()
+    .chain(String::from_utf8_lossy(&output.stderr).lines())
+    .map(str::to_string)
+    .collect();
+  match classify_job_error(&output_lines) {
+    Some(classified_error) => classified_error.category.summary().to_string(),
+    None => output_lines
+      .iter()
+      .rev()
+      .find(|line| !line.trim().is_empty())
+      .cloned()
+      .unwrap_or_else(|| format!("OCR process failed: {}", output.status)),
+  }
+}
+
+fn run_settings_tuning_trial(
+  repo_root: &Path,
+  job_engine: &JobEngine,
+  settings: &JobSettings,
+  job_root_directory_path: &Path,
+  tuning_relative_path: &str,
+  trial: SettingsTuningTrial,
+  sample_page_count: usize,
+) -> SettingsTuningTrialResult {
+  let trial_relative_path = format!("{tuning_relative_path}/{}", trial.directory_name());
+  let queue_arguments = vec![
+    "--queue-db".to_string(),
+    job_engine.data_path(job_root_directory_path, &format!("{trial_relative_path}/queue.sqlite3")),
+  ];
+  let mut enqueue_arguments = vec![
+    "enqueue".to_string(),
+    job_engine.data_path(
+      job_root_directory_path,
+      &format!("{tuning_relative_path}/{SETTINGS_TUNING_SAMPLE_DIRECTORY_NAME}"),
+    ),
+  ];
+  enqueue_arguments.extend(queue_arguments.clone());
+  let mut run_arguments = vec![
+    "run".to_string(),
+    "--output-dir".to_string(),
+    job_engine.data_path(job_root_directory_path, &format!("{trial_relative_path}/output")),
+    "--output-md".to_string(),
+    job_engine.data_path(job_root_directory_path, &format!("{trial_relative_path}/result.md")),
+  ];
+  run_arguments.extend(queue_arguments);
+
+  let mut trial_settings = settings.clone();
+  trial_settings.deepseek_ocr2_inference_image_size_pixels = Some(trial.inference_image_size_pixels);
+  trial_settings.deepseek_ocr2_enable_crop_mode = Some(trial.is_crop_mode_enabled);
+  let mut command = job_engine.build_command(repo_root, &trial_settings, &[enqueue_arguments, run_arguments], None);
+  command.stdout(Stdio::piped());
+  command.stderr(Stdio::piped());
+
+  let started_at = Instant::now();
+  let output_result = command.output();
+  let elapsed_millis = started_at.elapsed().as_millis() as u64;
+
+  let error_message = match &output_result {
+    Ok(output) if output.status.success() => None,
+    Ok(output) => Some(summarize_failed_cli_output(output)),
+    Err(error) => Some(format!("Failed to start OCR trial: {error}")),
+  };
+  let trial_directory_path = job_root_directory_path.join(&trial_relative_path);
+  let failed_page_count = query_status_counts(&trial_directory_path.join("queue.sqlite3"))
+    .ok()
+    .and_then(|counts| counts.get("failed").copied())
+    .unwrap_or(0);
+  let (output_character_count, repeated_line_ratio) = measure_output_markdown(&trial_directory_path.join("result.md"));
+  SettingsTuningTrialResult {
+    trial,
+    is_succeeded: error_message.is_none(),
+    error_message,
+    elapsed_millis,
+    seconds_per_page: elapsed_millis as f64 / 1000.0 / sample_page_count.max(1) as f64,
+    failed_page_count,
+    output_character_count,
+    repeated_line_ratio,
+  }
+}
+
+fn run_smoke_test_blocking(job_runtime_state: &SharedJobRuntimeState) -> Result<SmokeTestReport, String> {
+  let repo_root = repo_root_path()?;
+  let smoke_test_root_directory_path = resolve_app_config_directory_path(job_runtime_state)?.join(SMOKE_TEST_DIRECTORY_NAME);
+  let directory_layout = job_directory_layout(&smoke_test_root_directory_path);
+  prepare_smoke_test_root(&smoke_test_root_directory_path, &directory_layout.input_directory_name)?;
+  let smoke_test_root_canonical = smoke_test_root_directory_path
+    .canonicalize()
+    .map_err(|error| format!("Failed to canonicalize smoke test root: {error}"))?;
+  let job_engine = resolve_job_engine(job_runtime_state, &smoke_test_root_canonical)?;
+
+  let mut enqueue_arguments = vec![
+    "enqueue".to_string(),
+    job_engine.data_path(&smoke_test_root_directory_path, &directory_layout.input_directory_name),
+  ];
+  enqueue_arguments.extend(job_engine.queue_arguments(&smoke_test_root_directory_path));
+  let mut run_arguments = vec![
+    "run".to_string(),
+    "--output-dir".to_string(),
+    job_engine.data_path(&smoke_test_root_directory_path, &directory_layout.output_directory_name),
+    "--output-md".to_string(),
+    job_engine.data_path(&smoke_test_root_directory_path, SMOKE_TEST_OUTPUT_MARKDOWN_FILENAME),
+  ];
+  run_arguments.extend(job_engine.queue_arguments(&smoke_test_root_directory_path));
+  // NOTE: Default settings on purpose; the smoke test checks the machine and image, not a job's tuning.
+  let mut command = job_engine.build_command(&repo_root, &JobSettings::default(), &[enqueue_arguments, run_arguments], None);
+  command.stdout(Stdio::piped());
+  command.stderr(Stdio::piped());
+
+  let started_at = Instant::now();
+  let output_result = command.output();
+  let elapsed_millis = started_at.elapsed().as_millis() as u64;
+  let process_error_message = match &output_result {
+    Ok(output) if output.status.success() => None,
+    Ok(output) => Some(summarize_failed_cli_output(output)),
+    Err(error) => Some(format!("Failed to start the smoke test: {error}")),
+  };
+  Ok(evaluate_smoke_test_output(&smoke_test_root_directory_path, elapsed_millis, process_error_message))
+}
+
+// NOTE: Includes the model load, so the first run on a machine also measures the model download.
+#[tauri::command]
+async fn run_smoke_test(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<SmokeTestReport, String> {
+  if is_any_job_running(job_runtime_state.inner()) {
+    // Guard: a second model on the same GPU can run out of memory and fail the real job.
+    return Err("Wait for running jobs to finish before running the smoke test.".to_string());
+  }
+  let job_runtime_state = job_runtime_state.inner().clone();
+  tauri::async_runtime::spawn_blocking(move || run_smoke_test_blocking(&job_runtime_state))
+    .await
+    .map_err(|error| error.to_string())?
+}
+
+#[tauri::command]
+async fn tune_settings(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+  job_root_directory_path: String,
+  sample_page_count: usize,
+) -> Result<SettingsTuningReport, String> {
+  let job_runtime_state = job_runtime_state.inner().clone();
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  validate_job_root_directory(&job_root_directory_path)?;
+  // NOTE: Several full OCR runs; never block the main thread with them.
+  tauri::async_runtime::spawn_blocking(move || {
+    run_settings_tuning(&job_runtime_state, &job_root_directory_path, sample_page_count)
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+// NOTE: Deletes every page render now, whatever the job's policy; the runner re-renders pages it still needs.
+#[tauri::command]
+fn clean_work_files(
+  job_root_directory_path: String,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<WorkCleanupSummary, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  ensure_job_directory_layout(&job_root_directory_path)?;
+  let is_running = job_runtime_state
+    .lock()
+    .map_err(|_| "State lock poisoned".to_string())?
+    .running_job_by_root
+    .contains_key(&job_root_directory_path);
+  if is_running {
+    // Guard: the running job renders into `work/` and reads the renders back for OCR.
+    return Err("Wait for the running job to finish before cleaning its work files.".to_string());
+  }
+  clean_work_directory(&job_work_directory_path(&job_root_directory_path), None)
+}
+
+#[tauri::command]
+fn list_recent_jobs(
+  limit: Option<usize>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<Vec<RecentJob>, String> {
+  let app_config_directory_path = resolve_app_config_directory_path(job_runtime_state.inner())?;
+  let running_job_roots: HashSet<PathBuf> = job_runtime_state
+    .lock()
+    .map_err(|_| "State lock poisoned".to_string())?
+    .running_job_by_root
+    .keys()
+    .cloned()
+    .collect();
+  list_recent_job_roots(
+    &app_config_directory_path,
+    limit.unwrap_or(DEFAULT_RECENT_JOB_LIMIT),
+    |job_root_directory_path| running_job_roots.contains(job_root_directory_path),
+  )
+}
+
+#[tauri::command]
+fn apply_detected_title(
+  job_root_directory_path: String,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<AppliedDocumentTitle, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  ensure_job_directory_layout(&job_root_directory_path)?;
+  apply_detected_title_to_output(job_runtime_state.inner(), &job_root_directory_path)
+}
+
+#[tauri::command]
+fn extract_structured_fields(job_root_directory_path: String) -> Result<Vec<String>, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  ensure_job_directory_layout(&job_root_directory_path)?;
+
+  let settings = read_job_settings_best_effort(&job_root_directory_path);
+  let written_paths = run_structured_extraction(&job_root_directory_path, &settings)?;
+  Ok(
+    written_paths
+      .into_iter()
+      .map(|path| path.to_string_lossy().to_string())
+      .collect(),
+  )
+}
+
+#[tauri::command]
+async fn translate_job_output(job_root_directory_path: String, target_language: String) -> Result<String, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  ensure_job_directory_layout(&job_root_directory_path)?;
+
+  // NOTE: One LLM request per chunk of the document; a long job takes minutes.
+  tauri::async_runtime::spawn_blocking(move || {
+    let settings = read_job_settings_best_effort(&job_root_directory_path);
+    let translated_path = run_translation(&job_root_directory_path, &settings, &target_language)?;
+    Ok(translated_path.to_string_lossy().to_string())
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+#[tauri::command]
+async fn summarize_job_output(job_root_directory_path: String, mode: SummarizationMode) -> Result<String, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  ensure_job_directory_layout(&job_root_directory_path)?;
+
+  tauri::async_runtime::spawn_blocking(move || {
+    let settings = read_job_settings_best_effort(&job_root_directory_path);
+    let summary_path = run_summarization(&job_root_directory_path, &settings, mode)?;
+    Ok(summary_path.to_string_lossy().to_string())
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+#[tauri::command]
+async fn index_job_embeddings(job_root_directory_path: String) -> Result<usize, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  ensure_job_directory_layout(&job_root_directory_path)?;
+
+  let settings = read_job_settings_best_effort(&job_root_directory_path);
+  let endpoint = settings
+    .embedding_endpoint
+    .ok_or_else(|| "Embedding indexing requires embedding_endpoint in job settings.".to_string())?;
+  // NOTE: Embeds every chunk over HTTP; keep the IPC thread free meanwhile.
+  tauri::async_runtime::spawn_blocking(move || run_embedding_indexing(&job_root_directory_path, &endpoint))
+    .await
+    .map_err(|error| error.to_string())?
+}
+
+#[tauri::command]
+async fn semantic_search(
+  query: String,
+  search_root_directory_path: String,
+  embedding_endpoint: LlmEndpointSettings,
+  limit: Option<usize>,
+) -> Result<Vec<SemanticSearchHit>, String> {
+  let search_root_directory_path = PathBuf::from(search_root_directory_path);
+  if !search_root_directory_path.is_dir() {
+    // Guard: search root must be a job root or a jobs root directory.
+    return Err(format!("Search root is not a directory: {}", search_root_directory_path.display()));
+  }
+  // NOTE: Embeds the query over HTTP and reads every job's index under the root.
+  tauri::async_runtime::spawn_blocking(move || {
+    semantic_search_in_root(
+      &embedding_endpoint,
+      &query,
+      &search_root_directory_path,
+      DEFAULT_JOB_SETTINGS_DIRECTORY_NAME,
+      limit.unwrap_or(DEFAULT_SEMANTIC_SEARCH_RESULT_LIMIT),
+    )
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+fn list_job_root_candidates(jobs_root_directory_path: &Path) -> Result<Vec<PathBuf>, String> {
+  // Guard: allow pointing at a single job root as well as at a jobs root.
+  if job_input_directory_path(jobs_root_directory_path).is_dir() {
+    return Ok(vec![jobs_root_directory_path.to_path_buf()]);
+  }
+
+  let mut job_roots: Vec<PathBuf> = vec![];
+  let entries = fs::read_dir(jobs_root_directory_path).map_err(|error| error.to_string())?;
+  for entry_result in entries {
+    let entry = entry_result.map_err(|error| error.to_string())?;
+    let path = entry.path();
+    if job_input_directory_path(&path).is_dir() {
+      job_roots.push(path);
+    }
+  }
+  job_roots.sort();
+  Ok(job_roots)
+}
+
+fn build_job_fingerprint_source(job_root_directory_path: &Path) -> Result<JobFingerprintSource, String> {
+  let input_file_paths = walkdir::WalkDir::new(job_input_directory_path(job_root_directory_path))
+    .into_iter()
+    .filter_map(|entry| entry.ok())
+    .filter(|entry| entry.path().is_file())
+    .map(|entry| entry.path().to_path_buf())
+    .collect::<Vec<PathBuf>>();
+
+  let queue_database_path = get_queue_database_path(job_root_directory_path);
+  let mut documents = load_document_markdowns(job_root_directory_path, &queue_database_path)?;
+  for document in &mut documents {
+    document.source_path = map_container_data_path_to_host(job_root_directory_path, &document.source_path)
+      .to_string_lossy()
+      .to_string();
+  }
+
+  Ok(JobFingerprintSource {
+    job_root_directory_path: job_root_directory_path.to_path_buf(),
+    input_file_paths,
+    documents,
+  })
+}
+
+#[tauri::command]
+async fn find_duplicate_documents(
+  jobs_root_directory_path: String,
+  max_hamming_distance: Option<u32>,
+) -> Result<Vec<DuplicateDocumentGroup>, String> {
+  let jobs_root_directory_path = PathBuf::from(jobs_root_directory_path);
+  if !jobs_root_directory_path.is_dir() {
+    // Guard: fingerprinting walks a directory tree.
+    return Err(format!("Jobs root is not a directory: {}", jobs_root_directory_path.display()));
+  }
+
+  // NOTE: Hashes every input file of every job under the root, which can be gigabytes.
+  tauri::async_runtime::spawn_blocking(move || {
+    let mut sources: Vec<JobFingerprintSource> = vec![];
+    for job_root_directory_path in list_job_root_candidates(&jobs_root_directory_path)? {
+      sources.push(build_job_fingerprint_source(&job_root_directory_path)?);
+    }
+    find_duplicate_documents_in_sources(
+      &sources,
+      max_hamming_distance.unwrap_or(DEFAULT_DUPLICATE_TEXT_MAX_HAMMING_DISTANCE),
+    )
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+#[tauri::command]
+fn get_task_regions(job_root_directory_path: String, task_id: i64) -> Result<Option<TaskRegions>, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  ensure_job_directory_layout(&job_root_directory_path)?;
+  read_task_regions(&job_output_directory_path(&job_root_directory_path), task_id)
+}
+
+#[tauri::command]
+fn spellcheck_job_output(job_root_directory_path: String) -> Result<SpellcheckReport, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  ensure_job_directory_layout(&job_root_directory_path)?;
+
+  let settings = read_job_settings_best_effort(&job_root_directory_path);
+  let spellcheck_settings = settings
+    .spellcheck
+    .ok_or_else(|| "Spellcheck is not configured in job settings (spellcheck).".to_string())?;
+  run_spellcheck(&job_root_directory_path, &spellcheck_settings)
+}
+
+#[tauri::command]
+fn copy_output_to_clipboard(app_handle: tauri::AppHandle<Wry>, job_root_directory_path: String) -> Result<usize, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  let markdown_path = detect_last_output_markdown_path(&job_root_directory_path)
+    .map(PathBuf::from)
+    .ok_or_else(|| "No output markdown found for this job yet.".to_string())?;
+  let metadata = fs::metadata(&markdown_path).map_err(|error| error.to_string())?;
+  let max_clipboard_markdown_bytes = current_runtime_limits().max_clipboard_markdown_bytes;
+  if metadata.len() > max_clipboard_markdown_bytes {
+    // Guard: large documents freeze some clipboard managers; open the file instead.
+    return Err(format!(
+      "Output markdown is too large to copy to the clipboard ({} bytes, limit {max_clipboard_markdown_bytes}).",
+      metadata.len()
+    ));
+  }
+
+  let markdown = fs::read_to_string(&markdown_path).map_err(|error| error.to_string())?;
+  let copied_character_count = markdown.chars().count();
+  app_handle
+    .clipboard()
+    .write_text(markdown)
+    .map_err(|error| format!("Failed to write to clipboard: {error}"))?;
+  Ok(copied_character_count)
+}
+
+#[tauri::command]
+async fn package_outputs(job_root_directory_path: String) -> Result<String, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  ensure_job_directory_layout(&job_root_directory_path)?;
+  // NOTE: Zips the whole output directory, page images included.
+  tauri::async_runtime::spawn_blocking(move || {
+    let (archive_path, _) = run_packaging(&job_root_directory_path)?;
+    Ok(archive_path.to_string_lossy().to_string())
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+// NOTE: Emits `bulk-job-operation-progress` after each job and returns the summary once all of them were handled.
+#[tauri::command]
+async fn run_bulk_job_operation(
+  app_handle: tauri::AppHandle<Wry>,
+  operation: BulkJobOperation,
+  job_root_directory_paths: Vec<String>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<BulkJobOperationSummary, String> {
+  let target_directory_path = match &operation {
+    BulkJobOperation::RetryFailed => None,
+    BulkJobOperation::ArchiveCompleted { archive_directory_path, .. } => Some(archive_directory_path),
+    BulkJobOperation::ExportOutputs {
+      destination_directory_path,
+    } => Some(destination_directory_path),
+  };
+  if let Some(target_directory_path) = target_directory_path {
+    if target_directory_path.trim().is_empty() {
+      // Guard: an empty path would resolve against the app's working directory.
+      return Err("Choose a target folder first.".to_string());
+    }
+    fs::create_dir_all(target_directory_path).map_err(|error| error.to_string())?;
+  }
+  let job_runtime_state = job_runtime_state.inner().clone();
+  tauri::async_runtime::spawn_blocking(move || {
+    let total_count = job_root_directory_paths.len();
+    let mut summary = BulkJobOperationSummary::default();
+    for (index, job_root_directory_path) in job_root_directory_paths.into_iter().enumerate() {
+      let outcome = run_bulk_job_operation_item(&job_runtime_state, &operation, Path::new(&job_root_directory_path));
+      let item = BulkJobItemResult {
+        job_root_directory_path,
+        outcome,
+      };
+      // NOTE: Best-effort; a closed window must not stop the operation halfway.
+      let _ = app_handle.emit(
+        BULK_JOB_OPERATION_PROGRESS_EVENT,
+        BulkJobOperationProgress {
+          operation: operation.label(),
+          processed_count: index + 1,
+          total_count,
+          item: item.clone(),
+        },
+      );
+      summary.record(item);
+    }
+    Ok(summary)
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+fn describe_busy_job_root(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+) -> Option<&'static str> {
+  let locked_state = job_runtime_state.lock().ok()?;
+  if locked_state.running_job_by_root.contains_key(job_root_directory_path) {
+    Some("job is running")
+  } else if locked_state.job_start_queue.is_queued(job_root_directory_path) {
+    Some("job is waiting in the job queue")
+  } else if locked_state.suspended_job_by_root.contains_key(job_root_directory_path) {
+    Some("job is suspended and will resume")
+  } else if locked_state.input_copy_progress_by_root.contains_key(job_root_directory_path) {
+    Some("inputs are still being copied")
+  } else {
+    None
+  }
+}
+
+fn run_bulk_job_operation_item(
+  job_runtime_state: &SharedJobRuntimeState,
+  operation: &BulkJobOperation,
+  job_root_directory_path: &Path,
+) -> BulkJobItemOutcome {
+  if !job_root_directory_path.is_dir() {
+    return BulkJobItemOutcome::Failed {
+      error_message: format!("Job root not found: {}", job_root_directory_path.display()),
+    };
+  }
+  if let Some(reason) = describe_busy_job_root(job_runtime_state, job_root_directory_path) {
+    // Guard: never reset, move or copy from a job that is in use.
+    return BulkJobItemOutcome::Skipped {
+      reason: reason.to_string(),
+    };
+  }
+  let result = match operation {
+    BulkJobOperation::RetryFailed => retry_failed_tasks_for_bulk_operation(job_runtime_state, job_root_directory_path),
+    BulkJobOperation::ArchiveCompleted {
+      archive_directory_path,
+      older_than_days,
+    } => archive_completed_job_root(
+      job_runtime_state,
+      job_root_directory_path,
+      Path::new(archive_directory_path),
+      *older_than_days,
+    ),
+    BulkJobOperation::ExportOutputs {
+      destination_directory_path,
+    } => export_job_output_markdown(job_root_directory_path, Path::new(destination_directory_path)),
+  };
+  match result {
+    Ok(outcome) => outcome,
+    Err(error_message) => BulkJobItemOutcome::Failed { error_message },
+  }
+}
+
+fn retry_failed_tasks_for_bulk_operation(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+) -> Result<BulkJobItemOutcome, String> {
+  let queue_database_path = get_queue_database_path(job_root_directory_path);
+  let has_previous_run = read_job_settings_best_effort(job_root_directory_path)
+    .last_output_markdown_filename
+    .is_some();
+  if !queue_database_path.exists() || !has_previous_run {
+    return Ok(BulkJobItemOutcome::Skipped {
+      reason: "never run".to_string(),
+    });
+  }
+  let reset_task_count = reset_failed_tasks_to_pending(&queue_database_path)?;
+  if reset_task_count == 0 {
+    return Ok(BulkJobItemOutcome::Skipped {
+      reason: "no failed pages".to_string(),
+    });
+  }
+  let detail = match start_or_queue_job_run(
+    job_runtime_state.clone(),
+    job_root_directory_path.to_path_buf(),
+    JobRunMode::RetryFailedTasks,
+    JobStartSource::Gui,
+  )? {
+    JobStartOutcome::Started => format!("retrying {reset_task_count} page(s)"),
+    JobStartOutcome::Queued { position } => format!("queued (#{position}) to retry {reset_task_count} page(s)"),
+  };
+  Ok(BulkJobItemOutcome::Done { detail })
+}
+
+fn archive_completed_job_root(
+  job_runtime_state: &SharedJobRuntimeState,
+  job_root_directory_path: &Path,
+  archive_directory_path: &Path,
+  older_than_days: u32,
+) -> Result<BulkJobItemOutcome, String> {
+  let runs = read_job_runs(job_root_directory_path)?;
+  let latest_run = runs.iter().max_by_key(|run| run.run_number);
+  if let Err(reason) = check_archivable_run(latest_run, older_than_days, now_unix_timestamp_millis()) {
+    return Ok(BulkJobItemOutcome::Skipped { reason });
+  }
+  let archived_job_root_path = move_job_root_to_archive(job_root_directory_path, archive_directory_path)?;
+  // NOTE: Keep the job id resolving (dashboard, HTTP API) to where the job now lives.
+  if let Some(job_id) = lookup_job_id(job_runtime_state, job_root_directory_path) {
+    if let Ok(mut locked_state) = job_runtime_state.lock() {
+      locked_state.job_id_by_root.remove(job_root_directory_path);
+    }
+    register_job_id(job_runtime_state, &job_id, &archived_job_root_path);
+  }
+  if let Ok(mut locked_state) = job_runtime_state.lock() {
+    locked_state.job_root_index.invalidate(job_root_directory_path);
+    locked_state.job_log_store.remove(job_root_directory_path);
+  }
+  Ok(BulkJobItemOutcome::Done {
+    detail: archived_job_root_path.to_string_lossy().to_string(),
+  })
+}
+
+// NOTE: Copies only the merged markdown; `package_outputs` is the way to hand over images and other artifacts too.
+fn export_job_output_markdown(
+  job_root_directory_path: &Path,
+  destination_directory_path: &Path,
+) -> Result<BulkJobItemOutcome, String> {
+  let Some(output_markdown_path) = detect_last_output_markdown_path(job_root_directory_path).map(PathBuf::from) else {
+    return Ok(BulkJobItemOutcome::Skipped {
+      reason: "no output yet".to_string(),
+    });
+  };
+  if !output_markdown_path.is_file() {
+    return Ok(BulkJobItemOutcome::Skipped {
+      reason: format!("output file is missing: {}", output_markdown_path.display()),
+    });
+  }
+  let output_markdown_filename = output_markdown_path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .ok_or_else(|| "Failed to derive output markdown filename".to_string())?;
+  let destination_path = derive_non_conflicting_destination_path(destination_directory_path, output_markdown_filename)?;
+  fs::copy(&output_markdown_path, &destination_path).map_err(|error| error.to_string())?;
+  Ok(BulkJobItemOutcome::Done {
+    detail: destination_path.to_string_lossy().to_string(),
+  })
+}
+
+// NOTE: Re-delivers to the configured destinations (or only the failed ones), e.g. once an offline share is back.
+#[tauri::command]
+fn deliver_outputs(
+  job_root_directory_path: String,
+  only_failed: Option<bool>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<Vec<OutputDestinationResult>, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  ensure_job_directory_layout(&job_root_directory_path)?;
+  let settings = read_job_settings_best_effort(&job_root_directory_path);
+  let mut destinations = settings.output_destinations.unwrap_or_default();
+  if let Some(route) = resolve_job_submitter_route(job_runtime_state.inner(), &job_root_directory_path)? {
+    destinations.extend(route.output_destinations);
+  }
+  if only_failed.unwrap_or(false) {
+    let job_report = read_job_report_best_effort(&job_output_directory_path(&job_root_directory_path));
+    destinations.retain(|destination| {
+      !job_report
+        .output_destination_results
+        .iter()
+        .any(|result| result.directory_path == destination.directory_path && result.is_succeeded)
+    });
+  }
+  if destinations.is_empty() {
+    return Ok(vec![]);
+  }
+  run_output_delivery(&job_root_directory_path, &destinations)
+}
+
+// NOTE: Checks the final job state and the latest provenance manifest; the manifest's checksums then cover inputs and outputs.
+#[tauri::command]
+fn verify_job_integrity(job_root_directory_path: String) -> Result<JobIntegrityReport, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  validate_job_root_directory(&job_root_directory_path)?;
+  let job_report = read_job_report_best_effort(&job_output_directory_path(&job_root_directory_path));
+  let provenance_manifest_path = job_report.provenance_manifest_path.map(PathBuf::from);
+
+  let mut signed_file_paths: Vec<PathBuf> = vec![];
+  let job_state_path = job_state_file_path(&job_root_directory_path);
+  if job_state_path.exists() {
+    signed_file_paths.push(job_state_path);
+  }
+  signed_file_paths.extend(provenance_manifest_path.iter().cloned());
+  let mut signed_files: Vec<SignedFileCheck> = vec![];
+  for signed_file_path in &signed_file_paths {
+    signed_files.push(SignedFileCheck {
+      relative_path: signed_file_path
+        .strip_prefix(&job_root_directory_path)
+        .unwrap_or(signed_file_path)
+        .to_string_lossy()
+        .replace('\\', "/"),
+      status: verify_file_signature(signed_file_path)?,
+    });
+  }
+
+  let checksums = match provenance_manifest_path.as_ref() {
+    Some(manifest_path) => Some(compare_provenance_checksums(
+      &job_root_directory_path,
+      &read_provenance_manifest(manifest_path)?,
+    )),
+    None => None,
+  };
+  Ok(JobIntegrityReport::new(
+    signed_files,
+    provenance_manifest_path.map(|path| path.to_string_lossy().to_string()),
+    checksums,
+  ))
+}
+
+#[tauri::command]
+fn list_output_templates(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<OutputTemplateCatalog, String> {
+  let templates_directory_path = resolve_templates_directory_path(job_runtime_state.inner())?;
+  // Guard: create the directory so users can find where to drop templates.
+  fs::create_dir_all(&templates_directory_path).map_err(|error| error.to_string())?;
+  Ok(OutputTemplateCatalog {
+    templates_directory_path: templates_directory_path.to_string_lossy().to_string(),
+    template_names: list_template_names(&templates_directory_path)?,
+  })
+}
+
+#[tauri::command]
+fn render_output_templates(
+  job_root_directory_path: String,
+  template_names: Option<Vec<String>>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<Vec<String>, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  ensure_job_directory_layout(&job_root_directory_path)?;
+
+  let template_names = match template_names {
+    Some(names) => names,
+    None => list_template_names(&resolve_templates_directory_path(job_runtime_state.inner())?)?,
+  };
+  if template_names.is_empty() {
+    // Guard: rendering nothing is almost always a misconfiguration.
+    return Err("No output templates found. Add templates to the app config templates directory.".to_string());
+  }
+  let written_paths = run_template_rendering(job_runtime_state.inner(), &job_root_directory_path, &template_names)?;
+  Ok(
+    written_paths
+      .into_iter()
+      .map(|path| path.to_string_lossy().to_string())
+      .collect(),
+  )
+}
+
+#[tauri::command]
+fn list_plugins(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<Vec<PluginManifest>, String> {
+  let plugins_directory_path = resolve_plugins_directory_path(job_runtime_state.inner())?;
+  // Guard: create the directory so users can find where to install plugins.
+  fs::create_dir_all(&plugins_directory_path).map_err(|error| error.to_string())?;
+  discover_plugins(&plugins_directory_path)
+}
+
+#[tauri::command]
+async fn run_job_plugins(
+  job_root_directory_path: String,
+  plugin_names: Option<Vec<String>>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<Vec<PluginRunResult>, String> {
+  let job_root_directory_path = PathBuf::from(job_root_directory_path);
+  ensure_job_directory_layout(&job_root_directory_path)?;
+
+  let plugin_names = match plugin_names {
+    Some(names) => names,
+    None => read_job_settings_best_effort(&job_root_directory_path)
+      .enabled_plugin_names
+      .unwrap_or_default(),
+  };
+  if plugin_names.is_empty() {
+    return Err("No plugins selected. Enable plugins in job settings (enabled_plugin_names).".to_string());
+  }
+  let job_runtime_state = job_runtime_state.inner().clone();
+  // NOTE: Plugins are user scripts with no deadline of their own.
+  tauri::async_runtime::spawn_blocking(move || {
+    let mut results = run_plugins_of_kind(
+      &job_runtime_state,
+      &job_root_directory_path,
+      &plugin_names,
+      PluginKind::Transformer,
+    )?;
+    results.extend(run_plugins_of_kind(
+      &job_runtime_state,
+      &job_root_directory_path,
+      &plugin_names,
+      PluginKind::Exporter,
+    )?);
+    Ok(results)
+  })
+  .await
+  .map_err(|error| error.to_string())?
+}
+
+#[tauri::command]
+fn get_global_notification_targets(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<Vec<NotificationTarget>, String> {
+  read_global_notification_targets(job_runtime_state.inner())
+}
+
+#[tauri::command]
+fn set_global_notification_targets(
+  targets: Vec<NotificationTarget>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  let settings_path = global_notification_settings_file_path(job_runtime_state.inner())?;
+  if let Some(parent) = settings_path.parent() {
+    fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+  }
+  let serialized = serde_json::to_string_pretty(&targets).map_err(|error| error.to_string())?;
+  fs::write(settings_path, serialized).map_err(|error| error.to_string())?;
+  Ok(())
+}
+
+#[tauri::command]
+fn get_submitter_routes(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<Vec<SubmitterRoute>, String> {
+  read_submitter_routes(&submitter_routing_file_path(job_runtime_state.inner())?)
+}
+
+#[tauri::command]
+fn set_submitter_routes(
+  routes: Vec<SubmitterRoute>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  write_submitter_routes(&submitter_routing_file_path(job_runtime_state.inner())?, &routes)
+}
+
+#[tauri::command]
+fn send_test_notification(target: NotificationTarget) -> Result<(), String> {
+  let notification = JobNotification {
+    job_name: "test".to_string(),
+    job_id: None,
+    submitter: None,
+    job_root_directory_path: "(test notification)".to_string(),
+    is_success: true,
+    summary: "This is a test notification from ocr-agent.".to_string(),
+    output_markdown_path: None,
+    error_message: None,
+  };
+  target.build_notifier().send(&notification)
+}
+
+#[tauri::command]
+fn store_smtp_password(smtp_host: String, username: String, password: String) -> Result<(), String> {
+  if password.is_empty() {
+    // Guard: storing an empty secret would silently break every later send.
+    return Err("SMTP password must not be empty.".to_string());
+  }
+  store_smtp_password_in_keychain(smtp_host.trim(), username.trim(), &password)
+}
+
+#[tauri::command]
+fn list_job_settings_presets(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<Vec<String>, String> {
+  let presets_directory_path = resolve_app_config_directory_path(job_runtime_state.inner())?.join(JOB_SETTINGS_PRESETS_DIRECTORY_NAME);
+  if !presets_directory_path.exists() {
+    return Ok(vec![]);
+  }
+  let mut preset_names: Vec<String> = vec![];
+  for entry in fs::read_dir(&presets_directory_path).map_err(|error| error.to_string())? {
+    let entry_path = entry.map_err(|error| error.to_string())?.path();
+    if entry_path.extension().and_then(|extension| extension.to_str()) != Some("json") {
+      continue;
+    }
+    if let Some(stem) = entry_path.file_stem().and_then(|stem| stem.to_str()) {
+      preset_names.push(stem.to_string());
+    }
+  }
+  preset_names.sort();
+  Ok(preset_names)
+}
+
+#[tauri::command]
+fn list_job_templates(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<Vec<JobTemplateSummary>, String> {
+  list_job_templates_in_directory(&resolve_app_config_directory_path(job_runtime_state.inner())?.join(JOB_TEMPLATES_DIRECTORY_NAME))
+}
+
+// NOTE: Returns the new job root, e.g. `<parent>/2026-04-01_invoices/`.
+#[tauri::command]
+fn create_job_from_template(
+  parent_directory_path: String,
+  template_name: String,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<String, String> {
+  let job_root_directory_path =
+    create_job_root_from_template(job_runtime_state.inner(), Path::new(&parent_directory_path), &template_name)?;
+  Ok(job_root_directory_path.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+fn save_job_settings_preset(
+  preset_name: String,
+  settings: JobSettings,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  validate_job_settings(&settings)?;
+  write_app_config_json_file(job_runtime_state.inner(), &job_settings_preset_filename(&preset_name)?, &settings)
+}
+
+#[tauri::command]
+fn get_onedrive_connector_settings(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<Option<OneDriveConnectorSettings>, String> {
+  read_app_config_json_file(job_runtime_state.inner(), ONEDRIVE_CONNECTOR_SETTINGS_FILENAME)
+}
+
+#[tauri::command]
+fn set_onedrive_connector_settings(
+  settings: OneDriveConnectorSettings,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  write_app_config_json_file(job_runtime_state.inner(), ONEDRIVE_CONNECTOR_SETTINGS_FILENAME, &settings)
+}
+
+#[tauri::command]
+fn begin_onedrive_sign_in(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+  connector_states: State<'_, ConnectorStates>,
+) -> Result<DeviceCodePrompt, String> {
+  let settings = require_onedrive_connector_settings(job_runtime_state.inner())?;
+  let (prompt, pending) = begin_onedrive_device_code_sign_in(&settings)?;
+  // NOTE: The token poll blocks until the user finishes signing in; errors surface via the connector status.
+  let connector_state = connector_states.onedrive.clone();
+  thread::spawn(move || {
+    if let Err(error) = complete_onedrive_device_code_sign_in(&settings, pending) {
+      record_connector_error(&connector_state, error);
+    }
+  });
+  Ok(prompt)
+}
+
+#[tauri::command]
+fn start_onedrive_connector(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+  watch_folder_state: State<'_, SharedWatchFolderRuntimeState>,
+  connector_states: State<'_, ConnectorStates>,
+) -> Result<(), String> {
+  let settings = require_onedrive_connector_settings(job_runtime_state.inner())?;
+  if !is_onedrive_signed_in(&settings) {
+    // Guard: fail fast instead of recording the same token error every poll.
+    return Err("OneDrive is not signed in.".to_string());
+  }
+  // Guard: downloads are written into the watcher inbox, so it must be running first.
+  resolve_running_watch_inbox_directory_path(watch_folder_state.inner())?;
+  let state_file_path = resolve_app_config_directory_path(job_runtime_state.inner())?.join(ONEDRIVE_CONNECTOR_STATE_FILENAME);
+  let handler = make_onedrive_document_handler(
+    job_runtime_state.inner().clone(),
+    watch_folder_state.inner().clone(),
+    &settings,
+  );
+  let poll_interval = settings.poll_interval();
+  let mut session = GraphSession::new(settings);
+  start_connector_polling(
+    &connector_states.onedrive,
+    "OneDrive",
+    poll_interval,
+    Box::new(move || poll_onedrive_folder_once(&mut session, &state_file_path, &handler)),
+  )
+}
+
+#[tauri::command]
+fn stop_onedrive_connector(connector_states: State<'_, ConnectorStates>) -> Result<(), String> {
+  stop_connector(&connector_states.onedrive);
+  Ok(())
+}
+
+#[tauri::command]
+fn get_onedrive_connector_status(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+  connector_states: State<'_, ConnectorStates>,
+) -> Result<OneDriveConnectorStatus, String> {
+  let settings =
+    read_app_config_json_file::<OneDriveConnectorSettings>(job_runtime_state.inner(), ONEDRIVE_CONNECTOR_SETTINGS_FILENAME)?;
+  let state_file_path = resolve_app_config_directory_path(job_runtime_state.inner())?.join(ONEDRIVE_CONNECTOR_STATE_FILENAME);
+  Ok(get_onedrive_connector_status_from_state(
+    &connector_states.onedrive,
+    settings.as_ref(),
+    &state_file_path,
+  ))
+}
+
+#[tauri::command]
+fn get_google_drive_connector_settings(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<Option<GoogleDriveConnectorSettings>, String> {
+  read_app_config_json_file(job_runtime_state.inner(), GOOGLE_DRIVE_CONNECTOR_SETTINGS_FILENAME)
+}
+
+#[tauri::command]
+fn set_google_drive_connector_settings(
+  settings: GoogleDriveConnectorSettings,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  for folder in &settings.folders {
+    if let Some(preset_name) = folder.preset_name.as_deref().filter(|name| !name.trim().is_empty()) {
+      // Guard: surface a typo now rather than on the first imported document.
+      read_job_settings_preset(job_runtime_state.inner(), preset_name)?;
+    }
+  }
+  write_app_config_json_file(job_runtime_state.inner(), GOOGLE_DRIVE_CONNECTOR_SETTINGS_FILENAME, &settings)
+}
+
+#[tauri::command]
+fn begin_google_drive_sign_in(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+  connector_states: State<'_, ConnectorStates>,
+) -> Result<GoogleDriveSignInPrompt, String> {
+  let settings = require_google_drive_connector_settings(job_runtime_state.inner())?;
+  let (prompt, pending) = begin_google_drive_sign_in_flow(&settings)?;
+  // NOTE: The loopback listener waits for the browser redirect; errors surface via the connector status.
+  let connector_state = connector_states.google_drive.clone();
+  thread::spawn(move || {
+    if let Err(error) = complete_google_drive_sign_in_flow(&settings, pending) {
+      record_connector_error(&connector_state, error);
     }
-    fs::copy(entry_path, &destination_path).map_err(|error| error.to_string())?;
-    total_copied_files += 1;
+  });
+  Ok(prompt)
+}
+
+#[tauri::command]
+fn start_google_drive_connector(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+  watch_folder_state: State<'_, SharedWatchFolderRuntimeState>,
+  connector_states: State<'_, ConnectorStates>,
+) -> Result<(), String> {
+  let settings = require_google_drive_connector_settings(job_runtime_state.inner())?;
+  if !is_google_drive_signed_in(&settings) {
+    // Guard: fail fast instead of recording the same token error every poll.
+    return Err("Google Drive is not signed in.".to_string());
   }
-  Ok(total_copied_files)
+  if settings.folders.is_empty() {
+    return Err("Google Drive connector has no watched folders.".to_string());
+  }
+  // Guard: downloads are written into the watcher inbox, so it must be running first.
+  resolve_running_watch_inbox_directory_path(watch_folder_state.inner())?;
+  let state_file_path =
+    resolve_app_config_directory_path(job_runtime_state.inner())?.join(GOOGLE_DRIVE_CONNECTOR_STATE_FILENAME);
+  let handler = make_google_drive_document_handler(job_runtime_state.inner().clone(), watch_folder_state.inner().clone());
+  let poll_interval = settings.poll_interval();
+  let mut session = DriveSession::new(settings);
+  start_connector_polling(
+    &connector_states.google_drive,
+    "Google Drive",
+    poll_interval,
+    Box::new(move || poll_google_drive_changes_once(&mut session, &state_file_path, &handler)),
+  )
 }
 
-fn create_watch_job_from_bundle(
-  job_runtime_state: SharedJobRuntimeState,
-  jobs_root_directory_path: &Path,
-  bundle_directory_path: &Path,
-  auto_run: bool,
-) -> Result<PathBuf, String> {
-  let job_id = derive_watch_job_id(bundle_directory_path);
-  let job_root_directory_path = jobs_root_directory_path.join(job_id);
-  fs::create_dir_all(&job_root_directory_path).map_err(|error| error.to_string())?;
-  ensure_job_directory_layout(&job_root_directory_path)?;
+#[tauri::command]
+fn stop_google_drive_connector(connector_states: State<'_, ConnectorStates>) -> Result<(), String> {
+  stop_connector(&connector_states.google_drive);
+  Ok(())
+}
 
-  let input_directory_path = job_root_directory_path.join(DEFAULT_INPUT_DIRECTORY_NAME);
-  let excluded = [
-    DEFAULT_WATCH_READY_FILENAME,
-    ".processing",
-    ".processed",
-    ".failed",
-  ];
-  let _ = copy_directory_recursively_with_exclusions(bundle_directory_path, &input_directory_path, &excluded)?;
+#[tauri::command]
+fn get_google_drive_connector_status(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+  connector_states: State<'_, ConnectorStates>,
+) -> Result<GoogleDriveConnectorStatus, String> {
+  let settings = read_app_config_json_file::<GoogleDriveConnectorSettings>(
+    job_runtime_state.inner(),
+    GOOGLE_DRIVE_CONNECTOR_SETTINGS_FILENAME,
+  )?;
+  let state_file_path =
+    resolve_app_config_directory_path(job_runtime_state.inner())?.join(GOOGLE_DRIVE_CONNECTOR_STATE_FILENAME);
+  Ok(get_google_drive_connector_status_from_state(
+    &connector_states.google_drive,
+    settings.as_ref(),
+    &state_file_path,
+  ))
+}
 
-  let accepted_at = now_unix_timestamp_millis();
-  let job_id_for_state = job_root_directory_path
-    .file_name()
-    .and_then(|name| name.to_str())
-    .unwrap_or("job")
-    .to_string();
-  let job_state = JobState {
-    status: JobStateStatus::Queued,
-    job_id: job_id_for_state,
-    job_root_directory_path: job_root_directory_path.to_string_lossy().to_string(),
-    source_bundle_directory_path: Some(bundle_directory_path.to_string_lossy().to_string()),
-    accepted_unix_timestamp_millis: accepted_at,
-    started_unix_timestamp_millis: None,
-    finished_unix_timestamp_millis: None,
-    output_markdown_path: None,
-    error_message: None,
-  };
-  write_job_state(&job_root_directory_path, &job_state)?;
+#[tauri::command]
+fn get_execution_engine_settings(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<ExecutionEngineSettings, String> {
+  Ok(read_app_config_json_file(job_runtime_state.inner(), EXECUTION_ENGINE_SETTINGS_FILENAME)?.unwrap_or_default())
+}
 
-  {
-    let mut locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
-    locked_state
-      .job_state_file_path_by_root
-      .insert(job_root_directory_path.clone(), job_state_file_path(&job_root_directory_path));
+#[tauri::command]
+fn set_execution_engine_settings(
+  settings: ExecutionEngineSettings,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  if settings.kind == ExecutionEngineKind::Host {
+    validate_host_engine_supported()?;
+    let python_interpreter_path = settings.resolved_python_interpreter_path()?;
+    if !Path::new(python_interpreter_path).is_file() {
+      // Guard: surface a wrong path now rather than as a spawn error on the next run.
+      return Err(format!("Python interpreter not found: {python_interpreter_path}"));
+    }
   }
+  write_app_config_json_file(job_runtime_state.inner(), EXECUTION_ENGINE_SETTINGS_FILENAME, &settings)
+}
 
-  if auto_run {
-    spawn_job_process(job_runtime_state, job_root_directory_path.clone())?;
-  }
-  Ok(job_root_directory_path)
+// NOTE: Re-reads the file so hand edits to runtime_limits.json take effect without a restart.
+fn reload_runtime_limits(job_runtime_state: &SharedJobRuntimeState) -> Result<RuntimeLimits, String> {
+  let limits: RuntimeLimits =
+    read_app_config_json_file(job_runtime_state, RUNTIME_LIMITS_SETTINGS_FILENAME)?.unwrap_or_default();
+  apply_runtime_limits(limits)?;
+  // NOTE: A raised max_concurrent_jobs starts queued jobs right away.
+  dispatch_queued_job_starts_best_effort(job_runtime_state);
+  Ok(limits)
 }
 
-fn make_watch_folder_poll_callback(
-  shared_job_runtime_state: SharedJobRuntimeState,
-  auto_run: bool,
-) -> Arc<dyn Fn(&WatchFolderConfig) -> Result<(), String> + Send + Sync> {
-  Arc::new(move |config: &WatchFolderConfig| {
-    if is_any_job_running(&shared_job_runtime_state) {
-      // Guard: enforce single-job execution on a single Windows host.
-      return Ok(());
-    }
+#[tauri::command]
+fn get_runtime_limits(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<RuntimeLimits, String> {
+  reload_runtime_limits(job_runtime_state.inner())
+}
 
-    let bundle_directories = list_ready_bundle_directories(&config.inbox_directory_path)?;
-    for bundle_directory_path in bundle_directories {
-      let locked = try_lock_bundle_for_processing(&bundle_directory_path)?;
-      if !locked {
-        continue;
-      }
+#[tauri::command]
+fn set_runtime_limits(limits: RuntimeLimits, job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<(), String> {
+  // Guard: validate before writing so an invalid file never reaches disk.
+  limits.validate()?;
+  write_app_config_json_file(job_runtime_state.inner(), RUNTIME_LIMITS_SETTINGS_FILENAME, &limits)?;
+  apply_runtime_limits(limits)?;
+  dispatch_queued_job_starts_best_effort(job_runtime_state.inner());
+  Ok(())
+}
 
-      let create_result = create_watch_job_from_bundle(
-        shared_job_runtime_state.clone(),
-        &config.jobs_root_directory_path,
-        &bundle_directory_path,
-        auto_run,
-      );
-      if let Err(error_message) = create_result {
-        let _ = mark_bundle_failed(&bundle_directory_path, &error_message);
-        return Err(error_message);
-      }
-      let _ = mark_bundle_processed(&bundle_directory_path);
-      return Ok(());
-    }
+#[tauri::command]
+fn list_queued_job_starts(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<Vec<QueuedJobStart>, String> {
+  let locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
+  Ok(locked_state.job_start_queue.queued_starts())
+}
 
-    Ok(())
-  })
+#[tauri::command]
+fn get_job_directory_layout_settings(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<JobDirectoryLayout, String> {
+  Ok(read_app_config_json_file(job_runtime_state.inner(), JOB_DIRECTORY_LAYOUT_SETTINGS_FILENAME)?.unwrap_or_default())
 }
 
-fn detect_last_output_markdown_path(job_root_directory_path: &Path) -> Option<String> {
-  let settings = read_job_settings_best_effort(job_root_directory_path);
-  let filename = settings.last_output_markdown_filename?;
-  Some(job_root_directory_path.join(filename).to_string_lossy().to_string())
+// NOTE: Only affects job roots prepared afterwards; existing jobs keep the layout recorded in their job.json.
+#[tauri::command]
+fn set_job_directory_layout_settings(
+  layout: JobDirectoryLayout,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  layout.validate(DEFAULT_JOB_SETTINGS_DIRECTORY_NAME)?;
+  write_app_config_json_file(job_runtime_state.inner(), JOB_DIRECTORY_LAYOUT_SETTINGS_FILENAME, &layout)
+}
+
+#[tauri::command]
+fn get_disk_watermark_settings(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<DiskWatermarkSettings, String> {
+  Ok(read_app_config_json_file(job_runtime_state.inner(), DISK_WATERMARK_SETTINGS_FILENAME)?.unwrap_or_default())
+}
+
+#[tauri::command]
+fn set_disk_watermark_settings(
+  settings: DiskWatermarkSettings,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  settings.validate()?;
+  write_app_config_json_file(job_runtime_state.inner(), DISK_WATERMARK_SETTINGS_FILENAME, &settings)
+}
+
+#[tauri::command]
+fn get_job_dependencies(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<JobDependencyGraph, String> {
+  read_job_dependency_graph(&job_dependency_graph_file_path(job_runtime_state.inner())?)
 }
 
 #[tauri::command]
-fn run_job(
+fn set_job_dependencies(
   job_root_directory_path: String,
-  output_markdown_filename_override: Option<String>,
-  is_math_delimiter_conversion_enabled: Option<bool>,
-  deepseek_ocr2_model_revision: Option<String>,
-  deepseek_ocr2_markdown_prompt: Option<String>,
-  deepseek_ocr2_base_image_size_pixels: Option<u32>,
-  deepseek_ocr2_inference_image_size_pixels: Option<u32>,
-  deepseek_ocr2_enable_crop_mode: Option<bool>,
+  prerequisite_job_root_directory_paths: Vec<String>,
   job_runtime_state: State<'_, SharedJobRuntimeState>,
 ) -> Result<(), String> {
-  validate_docker_available()?;
+  let job_root_directory_path = PathBuf::from(job_root_directory_path.trim());
+  validate_job_root_directory(&job_root_directory_path)?;
+  let mut prerequisite_keys: Vec<String> = vec![];
+  for prerequisite in &prerequisite_job_root_directory_paths {
+    let prerequisite_path = PathBuf::from(prerequisite.trim());
+    validate_job_root_directory(&prerequisite_path)?;
+    if prerequisite_path == job_root_directory_path {
+      // Guard: a job can never wait for its own completion.
+      return Err("A job cannot depend on itself.".to_string());
+    }
+    let prerequisite_key = job_dependency_key(&prerequisite_path);
+    if !prerequisite_keys.contains(&prerequisite_key) {
+      prerequisite_keys.push(prerequisite_key);
+    }
+  }
+  let graph_file_path = job_dependency_graph_file_path(job_runtime_state.inner())?;
+  // NOTE: Prerequisites whose latest run already succeeded count immediately, so the job may start right away.
+  let ready_job_roots = update_job_dependency_graph(&graph_file_path, |graph| {
+    graph.declare(&job_dependency_key(&job_root_directory_path), prerequisite_keys)?;
+    Ok(graph.take_ready_jobs())
+  })?;
+  start_ready_dependent_jobs(job_runtime_state.inner(), &graph_file_path, ready_job_roots)
+}
 
-  let job_root_directory_path = PathBuf::from(job_root_directory_path);
-  ensure_job_directory_layout(&job_root_directory_path)?;
+#[tauri::command]
+fn get_docker_compose_settings(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<DockerComposeSettings, String> {
+  Ok(read_app_config_json_file(job_runtime_state.inner(), DOCKER_COMPOSE_SETTINGS_FILENAME)?.unwrap_or_default())
+}
 
-  let input_directory_path = job_root_directory_path.join(DEFAULT_INPUT_DIRECTORY_NAME);
-  let has_any_input_files = walkdir::WalkDir::new(&input_directory_path)
-    .into_iter()
-    .filter_map(|entry| entry.ok())
-    .any(|entry| entry.path().is_file());
-  if !has_any_input_files {
-    // Guard: prevent a confusing no-op run.
-    return Err("No input files found under input/. Drop images or PDFs first.".to_string());
+#[tauri::command]
+fn set_docker_compose_settings(
+  settings: DockerComposeSettings,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  validate_docker_compose_name("service", settings.resolved_service_name())?;
+  if let Some(profile_name) = settings.resolved_profile_name() {
+    validate_docker_compose_name("profile", profile_name)?;
   }
+  write_app_config_json_file(job_runtime_state.inner(), DOCKER_COMPOSE_SETTINGS_FILENAME, &settings)
+}
 
-  let mut settings = read_job_settings_best_effort(&job_root_directory_path);
-  let override_candidate = output_markdown_filename_override
-    .unwrap_or_default()
-    .trim()
-    .to_string();
-  if override_candidate.is_empty() {
-    settings.output_markdown_filename_override = None;
-  } else {
-    settings.output_markdown_filename_override = Some(override_candidate);
+fn app_config_backup_scope() -> AppConfigBackupScope {
+  // NOTE: Connector/backfill state files and job dependencies describe this machine's jobs, not its configuration.
+  AppConfigBackupScope {
+    settings_filenames: vec![
+      DOCKER_COMPOSE_SETTINGS_FILENAME,
+      EXECUTION_ENGINE_SETTINGS_FILENAME,
+      RUNTIME_LIMITS_SETTINGS_FILENAME,
+      JOB_DIRECTORY_LAYOUT_SETTINGS_FILENAME,
+      DISK_WATERMARK_SETTINGS_FILENAME,
+      QUICK_JOB_SETTINGS_FILENAME,
+      HOTFOLDER_SETTINGS_FILENAME,
+      ONEDRIVE_CONNECTOR_SETTINGS_FILENAME,
+      GOOGLE_DRIVE_CONNECTOR_SETTINGS_FILENAME,
+      GLOBAL_NOTIFICATION_SETTINGS_FILENAME,
+      SUBMITTER_ROUTING_FILENAME,
+      GUI_UPDATER_SETTINGS_FILENAME,
+    ],
+    json_directory_names: vec![JOB_SETTINGS_PRESETS_DIRECTORY_NAME],
+    text_directory_names: vec![TEMPLATES_DIRECTORY_NAME, JOB_TEMPLATES_DIRECTORY_NAME],
+    protected_fields: vec![
+      (GUI_UPDATER_SETTINGS_FILENAME, "endpoints"),
+      (GUI_UPDATER_SETTINGS_FILENAME, "pubkey"),
+      (EXECUTION_ENGINE_SETTINGS_FILENAME, "python_interpreter_path"),
+    ],
   }
-  settings.is_math_delimiter_conversion_enabled = is_math_delimiter_conversion_enabled;
+}
 
-  settings.deepseek_ocr2_model_revision = deepseek_ocr2_model_revision;
-  settings.deepseek_ocr2_markdown_prompt = deepseek_ocr2_markdown_prompt;
+#[tauri::command]
+fn export_app_config(
+  path: String,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<AppConfigExportReport, String> {
+  let app_config_directory_path = resolve_app_config_directory_path(job_runtime_state.inner())?;
+  export_app_config_backup(
+    &app_config_directory_path,
+    &app_config_backup_scope(),
+    Path::new(&path),
+    now_unix_timestamp_millis(),
+  )
+}
 
-  if let Some(base_image_size_pixels) = deepseek_ocr2_base_image_size_pixels {
-    if base_image_size_pixels <= 0 {
-      // Guard: reject invalid sizes early.
-      return Err("deepseek_ocr2_base_image_size_pixels must be > 0".to_string());
+#[tauri::command]
+fn import_app_config(
+  app_handle: tauri::AppHandle<Wry>,
+  path: String,
+  // NOTE: Keyed `<file>#<json pointer>` as listed in the export's `redacted_secrets`; missing ones are reported back.
+  secret_values: Option<BTreeMap<String, String>>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<AppConfigImportReport, String> {
+  let app_config_directory_path = resolve_app_config_directory_path(job_runtime_state.inner())?;
+  let mut report = import_app_config_backup(
+    &app_config_directory_path,
+    &app_config_backup_scope(),
+    Path::new(&path),
+    &secret_values.unwrap_or_default(),
+  )?;
+  for (filename, connector_label) in [
+    (ONEDRIVE_CONNECTOR_SETTINGS_FILENAME, "OneDrive"),
+    (GOOGLE_DRIVE_CONNECTOR_SETTINGS_FILENAME, "Google Drive"),
+  ] {
+    if report.imported_files.iter().any(|imported_file| imported_file == filename) {
+      report
+        .keychain_reminders
+        .push(format!("Sign in to {connector_label} again before starting its connector."));
     }
-    settings.deepseek_ocr2_base_image_size_pixels = Some(base_image_size_pixels);
   }
+  // NOTE: Apply what is cached in memory right away; everything else is read from disk on next use.
+  reload_runtime_limits(job_runtime_state.inner())?;
+  apply_quick_job_bindings(&app_handle, &read_quick_job_definitions(job_runtime_state.inner())?)?;
+  Ok(report)
+}
 
-  if let Some(inference_image_size_pixels) = deepseek_ocr2_inference_image_size_pixels {
-    if inference_image_size_pixels <= 0 {
-      // Guard: reject invalid sizes early.
-      return Err("deepseek_ocr2_inference_image_size_pixels must be > 0".to_string());
-    }
-    settings.deepseek_ocr2_inference_image_size_pixels = Some(inference_image_size_pixels);
+#[tauri::command]
+fn list_docker_compose_service_names(
+  profile_name: Option<String>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<Vec<String>, String> {
+  let repo_root = repo_root_path()?;
+  let mut compose_settings = read_docker_compose_settings_best_effort(job_runtime_state.inner());
+  // NOTE: Lets the settings UI preview a profile's services before saving it.
+  if profile_name.is_some() {
+    compose_settings.profile_name = profile_name;
   }
+  if let Some(profile_name) = compose_settings.resolved_profile_name() {
+    validate_docker_compose_name("profile", profile_name)?;
+  }
+  list_docker_compose_services(&repo_root, &compose_settings)
+}
 
-  settings.deepseek_ocr2_enable_crop_mode = deepseek_ocr2_enable_crop_mode;
-  write_job_settings(&job_root_directory_path, &settings)?;
+#[tauri::command]
+fn get_hotfolder_presets(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<Vec<HotfolderPreset>, String> {
+  Ok(read_app_config_json_file(job_runtime_state.inner(), HOTFOLDER_SETTINGS_FILENAME)?.unwrap_or_default())
+}
 
-  spawn_job_process(job_runtime_state.inner().clone(), job_root_directory_path)?;
-  Ok(())
+#[tauri::command]
+fn set_hotfolder_presets(
+  presets: Vec<HotfolderPreset>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  for preset in &presets {
+    if preset.folder_path.trim().is_empty() {
+      return Err("Hotfolder path is empty.".to_string());
+    }
+    // Guard: surface a typo now rather than on the first dropped PDF.
+    read_job_settings_preset(job_runtime_state.inner(), &preset.preset_name)?;
+  }
+  write_app_config_json_file(job_runtime_state.inner(), HOTFOLDER_SETTINGS_FILENAME, &presets)
 }
 
 #[tauri::command]
-fn cancel_job(job_root_directory_path: String, job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<(), String> {
-  let job_root_directory_path = PathBuf::from(job_root_directory_path);
-  let child_handle = {
-    let locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
-    let Some(running) = locked_state.running_job_by_root.get(&job_root_directory_path) else {
-      // Guard: nothing to cancel.
-      return Ok(());
-    };
-    running.child.clone()
-  };
+fn start_hotfolders(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+  watch_folder_state: State<'_, SharedWatchFolderRuntimeState>,
+  connector_states: State<'_, ConnectorStates>,
+) -> Result<(), String> {
+  let presets: Vec<HotfolderPreset> =
+    read_app_config_json_file(job_runtime_state.inner(), HOTFOLDER_SETTINGS_FILENAME)?.unwrap_or_default();
+  if presets.is_empty() {
+    return Err("No hotfolders are configured.".to_string());
+  }
+  // Guard: PDFs are wrapped into watcher inbox bundles, so it must be running first.
+  resolve_running_watch_inbox_directory_path(watch_folder_state.inner())?;
+  let handler = make_hotfolder_document_handler(job_runtime_state.inner().clone(), watch_folder_state.inner().clone());
+  let mut observations = HotfolderObservations::new();
+  start_connector_polling(
+    &connector_states.hotfolders,
+    "Hotfolder",
+    Duration::from_millis(HOTFOLDER_POLL_INTERVAL_MILLIS),
+    Box::new(move || poll_hotfolders_once(&presets, &mut observations, &handler)),
+  )
+}
 
-  let mut child_guard = child_handle.lock().map_err(|_| "Child lock poisoned".to_string())?;
-  child_guard.kill().map_err(|error| error.to_string())?;
-  append_log_line(
-    job_runtime_state.inner(),
-    &job_root_directory_path,
-    "[backend] cancellation requested".to_string(),
-  );
+#[tauri::command]
+fn stop_hotfolders(connector_states: State<'_, ConnectorStates>) -> Result<(), String> {
+  stop_connector(&connector_states.hotfolders);
   Ok(())
 }
 
 #[tauri::command]
-fn get_job_logs(job_root_directory_path: String, job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<JobLogResponse, String> {
-  let job_root_directory_path = PathBuf::from(job_root_directory_path);
-  let locked_state = job_runtime_state.lock().map_err(|_| "State lock poisoned".to_string())?;
-  let lines = locked_state
-    .log_lines_by_root
-    .get(&job_root_directory_path)
-    .cloned()
-    .unwrap_or_else(VecDeque::new)
-    .into_iter()
-    .collect::<Vec<String>>();
-  Ok(JobLogResponse { lines })
+fn get_hotfolder_status(connector_states: State<'_, ConnectorStates>) -> Result<HotfolderStatus, String> {
+  Ok(get_hotfolder_status_from_state(&connector_states.hotfolders))
 }
 
 #[tauri::command]
-fn get_current_task_preview(job_root_directory_path: String) -> Result<Option<CurrentTaskPreview>, String> {
-  let job_root_directory_path = PathBuf::from(job_root_directory_path);
-  ensure_job_directory_layout(&job_root_directory_path)?;
-
-  let queue_database_path = get_queue_database_path(&job_root_directory_path);
-  let Some(mut running_task) = query_current_running_task(&queue_database_path)? else {
-    return Ok(None);
-  };
+fn ingest_existing_folder(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+  watch_folder_state: State<'_, SharedWatchFolderRuntimeState>,
+  connector_states: State<'_, ConnectorStates>,
+  source_directory_path: String,
+  batch_size: usize,
+) -> Result<(), String> {
+  // Guard: batches are written as watcher inbox bundles, so it must be running first.
+  resolve_running_watch_inbox_directory_path(watch_folder_state.inner())?;
+  let source_directory_path = PathBuf::from(source_directory_path.trim());
+  let progress_file_path = resolve_app_config_directory_path(job_runtime_state.inner())?.join(BACKFILL_PROGRESS_FILENAME);
+  let mut backfill_run = BackfillRun::prepare(progress_file_path, &source_directory_path, batch_size)?;
+  let watch_folder_state = watch_folder_state.inner().clone();
+  start_connector_polling(
+    &connector_states.backfill,
+    "Backfill",
+    Duration::from_millis(BACKFILL_POLL_INTERVAL_MILLIS),
+    Box::new(move || {
+      let inbox_directory_path = resolve_running_watch_inbox_directory_path(&watch_folder_state)?;
+      backfill_run.poll_once(&inbox_directory_path, &|batch_number, file_paths| {
+        submit_backfill_batch(&inbox_directory_path, &source_directory_path, batch_number, file_paths)
+      })
+    }),
+  )
+}
 
-  let settings = read_job_settings_best_effort(&job_root_directory_path);
-  running_task.deepseek_inference_image_size_pixels = settings.deepseek_ocr2_inference_image_size_pixels;
+#[tauri::command]
+fn stop_backfill(connector_states: State<'_, ConnectorStates>) -> Result<(), String> {
+  stop_connector(&connector_states.backfill);
+  Ok(())
+}
 
-  let preview_path = resolve_preview_image_path_for_task(&job_root_directory_path, &running_task);
-  if let Some(preview_path) = preview_path {
-    if preview_path.exists() {
-      running_task.preview_image_file_path = Some(preview_path.to_string_lossy().to_string());
-    } else {
-      running_task.preview_image_file_path = Some(preview_path.to_string_lossy().to_string());
-    }
-  }
+#[tauri::command]
+fn get_backfill_status(
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+  connector_states: State<'_, ConnectorStates>,
+) -> Result<BackfillStatus, String> {
+  let progress_file_path = resolve_app_config_directory_path(job_runtime_state.inner())?.join(BACKFILL_PROGRESS_FILENAME);
+  get_backfill_status_from_state(&connector_states.backfill, &progress_file_path)
+}
 
-  Ok(Some(running_task))
+#[tauri::command]
+fn get_quick_jobs(job_runtime_state: State<'_, SharedJobRuntimeState>) -> Result<Vec<QuickJobDefinition>, String> {
+  read_quick_job_definitions(job_runtime_state.inner())
 }
 
 #[tauri::command]
-fn get_current_task_preview_image_bytes(job_root_directory_path: String) -> Result<Option<PreviewImageBytes>, String> {
-  let job_root_directory_path = PathBuf::from(job_root_directory_path);
-  ensure_job_directory_layout(&job_root_directory_path)?;
+fn set_quick_jobs(
+  app_handle: tauri::AppHandle<Wry>,
+  definitions: Vec<QuickJobDefinition>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
+  validate_quick_job_definitions(&definitions)?;
+  for definition in &definitions {
+    // Guard: reject a bad hotkey before it is saved and silently skipped on every startup.
+    parse_quick_job_hotkey(definition)?;
+  }
+  write_app_config_json_file(job_runtime_state.inner(), QUICK_JOB_SETTINGS_FILENAME, &definitions)?;
+  apply_quick_job_bindings(&app_handle, &definitions)
+}
 
-  let queue_database_path = get_queue_database_path(&job_root_directory_path);
-  let Some(running_task) = query_current_running_task(&queue_database_path)? else {
-    return Ok(None);
-  };
-  let Some(image_path) = resolve_preview_image_path_for_task(&job_root_directory_path, &running_task) else {
-    return Ok(None);
+#[tauri::command]
+fn run_quick_job(
+  app_handle: tauri::AppHandle<Wry>,
+  quick_job_name: String,
+  input_paths: Vec<String>,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+  quick_job_state: State<'_, SharedQuickJobRuntimeState>,
+) -> Result<String, String> {
+  // NOTE: Dropped files are passed in; with none, the clipboard is used like the tray and hotkey do.
+  let content = if input_paths.is_empty() {
+    read_quick_job_clipboard_content(&app_handle)?
+  } else {
+    QuickJobContent::Files(input_paths)
   };
-  if !image_path.exists() {
-    // Guard: preview can lag behind rendering; treat missing as "not ready".
-    return Ok(None);
-  }
-  let metadata = fs::metadata(&image_path).map_err(|error| error.to_string())?;
-  if !metadata.is_file() {
-    // Guard: refuse non-files for preview reads.
-    return Ok(None);
-  }
-  if metadata.len() > MAX_PREVIEW_IMAGE_BYTES {
-    return Err(format!(
-      "Preview image is too large to load in GUI ({} bytes).",
-      metadata.len()
-    ));
-  }
+  let result = create_and_run_quick_job(job_runtime_state.inner(), &quick_job_name, content);
+  record_quick_job_result(quick_job_state.inner(), &result);
+  result.map(|job_root_directory_path| job_root_directory_path.to_string_lossy().to_string())
+}
 
-  let bytes = fs::read(&image_path).map_err(|error| error.to_string())?;
-  Ok(Some(PreviewImageBytes {
-    mime_type: infer_image_mime_type(&image_path),
-    bytes,
-  }))
+#[tauri::command]
+fn get_quick_job_status(quick_job_state: State<'_, SharedQuickJobRuntimeState>) -> Result<QuickJobStatus, String> {
+  Ok(get_quick_job_status_from_state(quick_job_state.inner()))
 }
 
 #[tauri::command]
-fn reset_job_directory(job_root_directory_path: String) -> Result<(), String> {
+fn reset_job_directory(
+  job_root_directory_path: String,
+  job_runtime_state: State<'_, SharedJobRuntimeState>,
+) -> Result<(), String> {
   let job_root_directory_path = PathBuf::from(job_root_directory_path);
   ensure_job_directory_layout(&job_root_directory_path)?;
 
   let queue_database_path = get_queue_database_path(&job_root_directory_path);
-  let output_directory_path = job_root_directory_path.join(DEFAULT_OUTPUT_DIRECTORY_NAME);
+  let output_directory_path = job_output_directory_path(&job_root_directory_path);
   let settings = read_job_settings_best_effort(&job_root_directory_path);
   let output_markdown_path = settings
     .last_output_markdown_filename
@@ -1507,6 +8908,8 @@ fn reset_job_directory(job_root_directory_path: String) -> Result<(), String> {
     fs::remove_dir_all(output_directory_path).map_err(|error| error.to_string())?;
   }
 
+  invalidate_job_root_index(job_runtime_state.inner(), &job_root_directory_path);
+
   // Recreate expected directories after reset.
   ensure_job_directory_layout(&job_root_directory_path)?;
   Ok(())
@@ -1550,7 +8953,15 @@ fn open_in_file_manager(target_path: String) -> Result<(), String> {
 
 fn main() {
   let job_runtime_state: SharedJobRuntimeState = Arc::new(Mutex::new(JobRuntimeState::default()));
+  start_job_heartbeat_writer(make_job_heartbeat_provider(job_runtime_state.clone()));
   let watch_folder_state: SharedWatchFolderRuntimeState = new_shared_watch_folder_state();
+  let watch_folder_registry: SharedWatchFolderRegistry = new_shared_watch_folder_registry(&watch_folder_state);
+  let connector_states = ConnectorStates {
+    onedrive: new_shared_connector_state(),
+    google_drive: new_shared_connector_state(),
+    hotfolders: new_shared_connector_state(),
+    backfill: new_shared_connector_state(),
+  };
 
   // Guard: allow headless-ish automation by environment variables (useful for future Slack agent wiring).
   // If these are set, the watcher starts immediately on app startup.
@@ -1566,22 +8977,126 @@ fn main() {
         .map(PathBuf::from)
         .unwrap_or_else(|| inbox_directory_path.join(DEFAULT_WATCH_JOBS_DIRECTORY_NAME));
 
+      let is_single_file_intake_enabled = std::env::var(OCR_AGENT_WATCH_ACCEPT_SINGLE_FILES_ENVIRONMENT_VARIABLE_NAME)
+        .map(|value| value.trim() == "1")
+        .unwrap_or(false);
+      // Guard: an unparsable or too short period leaves the strict `.ready` contract in place.
+      let bundle_quiescence_period = std::env::var(OCR_AGENT_WATCH_QUIESCENCE_SECONDS_ENVIRONMENT_VARIABLE_NAME)
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .filter(|seconds| *seconds >= MIN_BUNDLE_QUIESCENCE_SECONDS)
+        .map(Duration::from_secs);
       let config = WatchFolderConfig {
-        inbox_directory_path,
-        jobs_root_directory_path,
-        poll_interval: default_watch_poll_interval(),
+        is_single_file_intake_enabled,
+        bundle_quiescence_period,
+        ..WatchFolderConfig::new(inbox_directory_path, jobs_root_directory_path, default_watch_poll_interval())
       };
-      let poll_callback = make_watch_folder_poll_callback(job_runtime_state.clone(), false);
+      let poll_callback = make_watch_folder_poll_callback(
+        job_runtime_state.clone(),
+        watch_folder_state.clone(),
+        watch_folder_registry.clone(),
+        false,
+        false,
+      );
       let _ = start_watch_folder_with_callback(&watch_folder_state, config, poll_callback);
     }
   }
 
+  // Guard: the HTTP API only starts when a token is configured; submissions are fed into the watcher inbox.
+  if let Ok(token) = std::env::var(OCR_AGENT_HTTP_API_TOKEN_ENVIRONMENT_VARIABLE_NAME) {
+    let token_trimmed = token.trim().to_string();
+    if !token_trimmed.is_empty() {
+      let bind_address = std::env::var(OCR_AGENT_HTTP_API_BIND_ADDRESS_ENVIRONMENT_VARIABLE_NAME)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| DEFAULT_HTTP_API_BIND_ADDRESS.to_string());
+      let submitter_tokens = std::env::var(OCR_AGENT_HTTP_API_SUBMITTER_TOKENS_ENVIRONMENT_VARIABLE_NAME)
+        .map(|raw| parse_submitter_tokens(&raw))
+        .unwrap_or_default();
+      let config = HttpApiConfig {
+        bind_address: bind_address.clone(),
+        token: token_trimmed,
+        submitter_tokens,
+      };
+      let token_authorizer = make_http_api_token_authorizer(job_runtime_state.clone());
+      let submission_handler = make_http_api_submission_handler(job_runtime_state.clone(), watch_folder_state.clone());
+      let control_handler = make_http_api_control_handler(job_runtime_state.clone(), watch_folder_state.clone());
+      if start_http_api_server(config, token_authorizer, submission_handler, control_handler).is_ok() {
+        if let Ok(mut locked_state) = job_runtime_state.lock() {
+          locked_state.http_api_bind_address = Some(bind_address);
+        }
+      }
+    }
+  }
+
   tauri::Builder::default()
     .plugin(tauri_plugin_dialog::init())
+    .plugin(tauri_plugin_clipboard_manager::init())
+    .plugin(tauri_plugin_updater::Builder::new().build())
+    .plugin(
+      tauri_plugin_global_shortcut::Builder::new()
+        .with_handler(|app_handle, shortcut, event| {
+          if event.state() != ShortcutState::Pressed {
+            return;
+          }
+          let quick_job_state = app_handle.state::<SharedQuickJobRuntimeState>();
+          if let Some(quick_job_name) = quick_job_name_for_hotkey(quick_job_state.inner(), shortcut.id()) {
+            trigger_quick_job_from_clipboard(app_handle.clone(), quick_job_name);
+          }
+        })
+        .build(),
+    )
+    .setup({
+      let job_runtime_state = job_runtime_state.clone();
+      move |app| {
+        if let Ok(app_config_directory_path) = app.path().app_config_dir() {
+          if let Ok(mut locked_state) = job_runtime_state.lock() {
+            locked_state.app_config_directory_path = Some(app_config_directory_path);
+          }
+        }
+        if let Ok(mut locked_state) = job_runtime_state.lock() {
+          locked_state.event_app_handle = Some(app.handle().clone());
+        }
+        start_job_status_event_pump(job_runtime_state.clone());
+        // Guard: an invalid limits file keeps the built-in defaults instead of blocking startup.
+        let _ = reload_runtime_limits(&job_runtime_state);
+        // Guard: a broken quick job file or a hotkey taken by another app must not block startup.
+        if let Ok(definitions) = read_quick_job_definitions(&job_runtime_state) {
+          let _ = apply_quick_job_bindings(app.handle(), &definitions);
+        }
+        Ok(())
+      }
+    })
     .manage(job_runtime_state)
     .manage(watch_folder_state)
+    .manage(watch_folder_registry)
+    .manage(connector_states)
+    .manage(new_shared_quick_job_state())
+    .manage(new_shared_image_build_state())
+    .manage(new_shared_engine_update_state())
     .invoke_handler(tauri::generate_handler![
       probe_docker,
+      run_wsl_diagnostics,
+      start_docker_image_build,
+      get_docker_image_build_status,
+      get_docker_disk_usage,
+      list_running_job_containers,
+      adopt_job_container,
+      prune_docker_artifacts,
+      get_execution_engine_settings,
+      set_execution_engine_settings,
+      get_docker_compose_settings,
+      set_docker_compose_settings,
+      get_runtime_limits,
+      set_runtime_limits,
+      get_job_directory_layout_settings,
+      set_job_directory_layout_settings,
+      get_disk_watermark_settings,
+      set_disk_watermark_settings,
+      get_job_dependencies,
+      set_job_dependencies,
+      list_docker_compose_service_names,
       probe_gpu_passthrough,
       pick_output_directory,
       pick_directory,
@@ -1592,13 +9107,97 @@ fn main() {
       get_job_logs,
       get_current_task_preview,
       get_current_task_preview_image_bytes,
+      render_pdf_page_preview,
+      tune_settings,
+      get_task_regions,
       run_job,
       cancel_job,
       reset_job_directory,
+      extract_structured_fields,
+      translate_job_output,
+      summarize_job_output,
+      index_job_embeddings,
+      semantic_search,
+      find_duplicate_documents,
+      spellcheck_job_output,
+      copy_output_to_clipboard,
+      package_outputs,
+      deliver_outputs,
+      verify_job_integrity,
+      get_input_copy_progress,
+      cancel_input_copy,
+      list_suspended_jobs,
+      find_job_root_by_id,
+      list_job_runs,
+      compare_runs,
+      list_output_templates,
+      render_output_templates,
+      list_plugins,
+      run_job_plugins,
+      get_global_notification_targets,
+      set_global_notification_targets,
+      get_submitter_routes,
+      set_submitter_routes,
+      send_test_notification,
+      store_smtp_password,
+      list_job_settings_presets,
+      save_job_settings_preset,
+      get_onedrive_connector_settings,
+      set_onedrive_connector_settings,
+      begin_onedrive_sign_in,
+      start_onedrive_connector,
+      stop_onedrive_connector,
+      get_onedrive_connector_status,
+      get_google_drive_connector_settings,
+      set_google_drive_connector_settings,
+      begin_google_drive_sign_in,
+      start_google_drive_connector,
+      stop_google_drive_connector,
+      get_google_drive_connector_status,
+      get_hotfolder_presets,
+      set_hotfolder_presets,
+      start_hotfolders,
+      stop_hotfolders,
+      get_hotfolder_status,
+      ingest_existing_folder,
+      stop_backfill,
+      get_backfill_status,
+      get_quick_jobs,
+      set_quick_jobs,
+      run_quick_job,
+      get_quick_job_status,
       open_in_file_manager,
       get_watch_folder_status,
       start_watch_folder,
-      stop_watch_folder
+      stop_watch_folder,
+      pause_watch_folder_intake,
+      resume_watch_folder_intake,
+      enter_maintenance_mode,
+      exit_maintenance_mode,
+      get_maintenance_status,
+      export_app_config,
+      import_app_config,
+      check_for_updates,
+      install_gui_update,
+      start_engine_update,
+      get_engine_update_status,
+      run_smoke_test,
+      get_backend_capabilities,
+      apply_detected_title,
+      list_recent_jobs,
+      clean_work_files,
+      list_job_templates,
+      create_job_from_template,
+      list_queued_job_starts,
+      run_bulk_job_operation,
+      pause_job,
+      resume_job,
+      list_http_api_tokens,
+      create_http_api_token,
+      revoke_http_api_token,
+      retry_failed_tasks,
+      list_job_tasks,
+      remove_watch_folder
     ])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
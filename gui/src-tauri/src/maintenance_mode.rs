@@ -0,0 +1,92 @@
+/*!
+Responsibility:
+- Describe maintenance mode (e.g. before a GPU driver update or a reboot of a shared OCR machine): while it is on, no
+  new work is accepted from the watcher, the HTTP API or manual runs.
+- Summarize whether the running jobs have drained yet, so an operator knows when the machine is safe to take down.
+*/
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_MAINTENANCE_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+// NOTE: Caps a caller-supplied wait; the GUI keeps polling `get_maintenance_status` for longer drains.
+pub const MAX_MAINTENANCE_WAIT_TIMEOUT: Duration = Duration::from_secs(600);
+pub const MAINTENANCE_DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MaintenanceJobPolicy {
+  // NOTE: Running jobs finish normally; their dependents wait until maintenance ends.
+  WaitForJobs,
+  // NOTE: Running jobs are cancelled the same way as `cancel_job`, keeping the pages finished so far.
+  CancelJobs,
+}
+
+impl MaintenanceJobPolicy {
+  pub fn label(self) -> &'static str {
+    match self {
+      MaintenanceJobPolicy::WaitForJobs => "wait_for_jobs",
+      MaintenanceJobPolicy::CancelJobs => "cancel_jobs",
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MaintenanceMode {
+  pub reason: String,
+  pub job_policy: MaintenanceJobPolicy,
+  pub entered_unix_timestamp_millis: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MaintenanceStatus {
+  pub is_enabled: bool,
+  pub reason: Option<String>,
+  pub job_policy: Option<MaintenanceJobPolicy>,
+  pub entered_unix_timestamp_millis: Option<i64>,
+  pub running_job_root_directory_paths: Vec<String>,
+  // NOTE: True once maintenance is on and no job is running anymore.
+  pub is_drained: bool,
+  pub summary: String,
+}
+
+pub fn describe_maintenance_status(mode: Option<&MaintenanceMode>, running_job_root_directory_paths: Vec<String>) -> MaintenanceStatus {
+  let running_job_count = running_job_root_directory_paths.len();
+  let Some(mode) = mode else {
+    return MaintenanceStatus {
+      is_enabled: false,
+      reason: None,
+      job_policy: None,
+      entered_unix_timestamp_millis: None,
+      running_job_root_directory_paths,
+      is_drained: false,
+      summary: "Not in maintenance mode".to_string(),
+    };
+  };
+  let summary = match (running_job_count, mode.job_policy) {
+    (0, _) => format!("Maintenance ({}): no jobs running; safe to update drivers or reboot", mode.reason),
+    (count, MaintenanceJobPolicy::WaitForJobs) => {
+      format!("Maintenance ({}): waiting for {count} running job(s) to finish", mode.reason)
+    }
+    (count, MaintenanceJobPolicy::CancelJobs) => {
+      format!("Maintenance ({}): cancelling {count} running job(s)", mode.reason)
+    }
+  };
+  MaintenanceStatus {
+    is_enabled: true,
+    reason: Some(mode.reason.clone()),
+    job_policy: Some(mode.job_policy),
+    entered_unix_timestamp_millis: Some(mode.entered_unix_timestamp_millis),
+    running_job_root_directory_paths,
+    is_drained: running_job_count == 0,
+    summary,
+  }
+}
+
+pub fn describe_maintenance_refusal(mode: &MaintenanceMode) -> String {
+  format!(
+    "Maintenance mode is on ({}); new runs are refused until it is turned off.",
+    mode.reason
+  )
+}
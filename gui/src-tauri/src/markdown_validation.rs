@@ -0,0 +1,50 @@
+/*!
+Responsibility:
+- Read the Markdown structure report the OCR CLI writes after merging (`output/markdown_validation.json`).
+- Carry per-page issues (broken tables, unclosed code fences, malformed links) into the job report.
+*/
+
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+pub const MARKDOWN_VALIDATION_REPORT_FILENAME: &str = "markdown_validation.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarkdownIssueKind {
+  Table,
+  CodeFence,
+  Link,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkdownIssue {
+  // NOTE: Same numbering as the merged markdown's page separators.
+  pub page_number: u32,
+  pub source_path: String,
+  pub source_page_number: Option<u32>,
+  pub kind: MarkdownIssueKind,
+  // NOTE: 1-based line within that page's own markdown (`output/markdown_items/task_<id>.md`).
+  pub line_number: u32,
+  pub message: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarkdownValidationReport {
+  pub checked_page_count: usize,
+  #[serde(default)]
+  pub issues: Vec<MarkdownIssue>,
+}
+
+pub fn read_markdown_validation_report(output_directory_path: &Path) -> Result<Option<MarkdownValidationReport>, String> {
+  let report_path = output_directory_path.join(MARKDOWN_VALIDATION_REPORT_FILENAME);
+  if !report_path.is_file() {
+    // Guard: runs by an older engine image write no validation report.
+    return Ok(None);
+  }
+  let raw = fs::read_to_string(&report_path).map_err(|error| error.to_string())?;
+  serde_json::from_str::<MarkdownValidationReport>(&raw)
+    .map(Some)
+    .map_err(|error| format!("Invalid markdown validation report {}: {error}", report_path.display()))
+}
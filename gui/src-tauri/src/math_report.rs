@@ -0,0 +1,44 @@
+/*!
+Responsibility:
+- Read the math report the OCR CLI writes next to its per-task outputs (`output/math_report.json`).
+- Carry per-page math warnings (unbalanced delimiters or braces) into the job report for reviewers.
+*/
+
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+pub const MATH_REPORT_FILENAME: &str = "math_report.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MathWarning {
+  // NOTE: Same numbering as the merged markdown's page separators.
+  pub page_number: u32,
+  pub source_path: String,
+  pub source_page_number: Option<u32>,
+  // NOTE: 1-based line within that page's own markdown (`output/markdown_items/task_<id>.md`).
+  pub line_number: u32,
+  pub message: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MathReport {
+  pub formula_count: usize,
+  // NOTE: Relative to the output directory; the CLI only knows container paths.
+  pub mathml_filename: Option<String>,
+  pub export_error_message: Option<String>,
+  #[serde(default)]
+  pub warnings: Vec<MathWarning>,
+}
+
+pub fn read_math_report(output_directory_path: &Path) -> Result<Option<MathReport>, String> {
+  let report_path = output_directory_path.join(MATH_REPORT_FILENAME);
+  if !report_path.is_file() {
+    // Guard: runs by an older engine image write no math report.
+    return Ok(None);
+  }
+  let raw = fs::read_to_string(&report_path).map_err(|error| error.to_string())?;
+  serde_json::from_str::<MathReport>(&raw)
+    .map(Some)
+    .map_err(|error| format!("Invalid math report {}: {error}", report_path.display()))
+}
@@ -0,0 +1,74 @@
+/*!
+Responsibility:
+- Order names the way scans are numbered: `page2` before `page10`, case-insensitively, with full-width digits as digits.
+- Mirror the CLI's `natural_sort_key` (`input_discovery.py`), so bundle order and page order inside a bundle agree.
+*/
+
+use std::{
+  cmp::Ordering,
+  path::{Path, PathBuf},
+};
+
+fn normalize_name_character(character: char) -> char {
+  match character {
+    '０'..='９' => char::from_u32(character as u32 - '０' as u32 + '0' as u32).unwrap_or(character),
+    _ => character,
+  }
+}
+
+// NOTE: Alternates text and digit runs, always starting with a (possibly empty) text run, like Python's `re.split`.
+fn split_name_chunks(name: &str) -> Vec<String> {
+  let mut chunks: Vec<String> = vec![String::new()];
+  for character in name.chars().map(normalize_name_character).flat_map(char::to_lowercase) {
+    let is_digit_chunk = chunks.len().is_multiple_of(2);
+    if character.is_ascii_digit() != is_digit_chunk {
+      chunks.push(String::new());
+    }
+    if let Some(chunk) = chunks.last_mut() {
+      chunk.push(character);
+    }
+  }
+  chunks
+}
+
+// NOTE: Digit runs compare by value without parsing, so arbitrarily long numbers cannot overflow.
+fn compare_digit_chunks(left: &str, right: &str) -> Ordering {
+  let left = left.trim_start_matches('0');
+  let right = right.trim_start_matches('0');
+  left.len().cmp(&right.len()).then_with(|| left.cmp(right))
+}
+
+fn compare_name_keys(left: &str, right: &str) -> Ordering {
+  let left_chunks = split_name_chunks(left);
+  let right_chunks = split_name_chunks(right);
+  left_chunks
+    .iter()
+    .zip(&right_chunks)
+    .enumerate()
+    .map(|(index, (left_chunk, right_chunk))| {
+      if index % 2 == 1 {
+        compare_digit_chunks(left_chunk, right_chunk)
+      } else {
+        left_chunk.cmp(right_chunk)
+      }
+    })
+    .find(|ordering| ordering.is_ne())
+    .unwrap_or_else(|| left_chunks.len().cmp(&right_chunks.len()))
+}
+
+pub fn compare_paths_naturally(left: &Path, right: &Path) -> Ordering {
+  let left_parts: Vec<String> = left.iter().map(|part| part.to_string_lossy().to_string()).collect();
+  let right_parts: Vec<String> = right.iter().map(|part| part.to_string_lossy().to_string()).collect();
+  left_parts
+    .iter()
+    .zip(&right_parts)
+    .map(|(left_part, right_part)| compare_name_keys(left_part, right_part))
+    .find(|ordering| ordering.is_ne())
+    .unwrap_or_else(|| left_parts.len().cmp(&right_parts.len()))
+    // Guard: names equal under the key (e.g. "a01" and "a1") still get a deterministic order.
+    .then_with(|| left.cmp(right))
+}
+
+pub fn sort_paths_naturally(paths: &mut [PathBuf]) {
+  paths.sort_by(|left, right| compare_paths_naturally(left, right));
+}
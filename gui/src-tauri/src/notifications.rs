@@ -0,0 +1,203 @@
+/*!
+Responsibility:
+- Send job completion notifications through pluggable channels sharing one `Notifier` trait.
+- Provide webhook notifiers for Slack (incoming webhook), Microsoft Teams (message card), and Discord (embed).
+*/
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::email_notifier::{EmailNotificationSettings, EmailNotifier};
+
+pub const GLOBAL_NOTIFICATION_SETTINGS_FILENAME: &str = "notifications.json";
+
+const NOTIFICATION_COLOR_SUCCESS_HEX: &str = "2EB67D";
+const NOTIFICATION_COLOR_FAILURE_HEX: &str = "E01E5A";
+const NOTIFICATION_COLOR_SUCCESS_DECIMAL: u32 = 0x2E_B6_7D;
+const NOTIFICATION_COLOR_FAILURE_DECIMAL: u32 = 0xE0_1E_5A;
+// NOTE: Sent while the job still holds its job slot; an endpoint that accepts the connection and never answers must
+// not keep the job "running".
+const WEBHOOK_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const WEBHOOK_READ_TIMEOUT: Duration = Duration::from_secs(30);
+const WEBHOOK_TOTAL_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobNotification {
+  pub job_name: String,
+  pub job_id: Option<String>,
+  pub submitter: Option<String>,
+  pub job_root_directory_path: String,
+  pub is_success: bool,
+  // NOTE: One human-readable line, e.g. "12 task(s) completed, 0 failed".
+  pub summary: String,
+  pub output_markdown_path: Option<String>,
+  pub error_message: Option<String>,
+}
+
+impl JobNotification {
+  pub(crate) fn title(&self) -> String {
+    if self.is_success {
+      format!("OCR job completed: {}", self.job_name)
+    } else {
+      format!("OCR job failed: {}", self.job_name)
+    }
+  }
+
+  pub(crate) fn detail_lines(&self) -> Vec<String> {
+    let mut lines = vec![self.summary.clone()];
+    if let Some(job_id) = self.job_id.as_deref() {
+      lines.push(format!("Job ID: {job_id}"));
+    }
+    if let Some(submitter) = self.submitter.as_deref() {
+      lines.push(format!("Submitted by: {submitter}"));
+    }
+    if let Some(output_markdown_path) = self.output_markdown_path.as_deref() {
+      lines.push(format!("Output: {output_markdown_path}"));
+    }
+    if let Some(error_message) = self.error_message.as_deref() {
+      lines.push(format!("Error: {error_message}"));
+    }
+    lines
+  }
+}
+
+pub trait Notifier {
+  fn channel_name(&self) -> &'static str;
+  fn send(&self, notification: &JobNotification) -> Result<(), String>;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "channel", rename_all = "snake_case")]
+pub enum NotificationTarget {
+  Slack { webhook_url: String },
+  Teams { webhook_url: String },
+  Discord { webhook_url: String },
+  Email(EmailNotificationSettings),
+}
+
+impl NotificationTarget {
+  pub fn build_notifier(&self) -> Box<dyn Notifier> {
+    match self {
+      NotificationTarget::Slack { webhook_url } => Box::new(SlackWebhookNotifier {
+        webhook_url: webhook_url.clone(),
+      }),
+      NotificationTarget::Teams { webhook_url } => Box::new(TeamsWebhookNotifier {
+        webhook_url: webhook_url.clone(),
+      }),
+      NotificationTarget::Discord { webhook_url } => Box::new(DiscordWebhookNotifier {
+        webhook_url: webhook_url.clone(),
+      }),
+      NotificationTarget::Email(settings) => Box::new(EmailNotifier {
+        settings: settings.clone(),
+      }),
+    }
+  }
+}
+
+fn post_webhook_json(webhook_url: &str, payload: &Value) -> Result<(), String> {
+  let agent = ureq::AgentBuilder::new()
+    .timeout_connect(WEBHOOK_CONNECT_TIMEOUT)
+    .timeout_read(WEBHOOK_READ_TIMEOUT)
+    .timeout(WEBHOOK_TOTAL_TIMEOUT)
+    .build();
+  agent
+    .post(webhook_url)
+    .send_json(payload.clone())
+    .map_err(|error| format!("Webhook request failed: {error}"))?;
+  Ok(())
+}
+
+struct SlackWebhookNotifier {
+  webhook_url: String,
+}
+
+impl Notifier for SlackWebhookNotifier {
+  fn channel_name(&self) -> &'static str {
+    "slack"
+  }
+
+  fn send(&self, notification: &JobNotification) -> Result<(), String> {
+    let payload = json!({
+      "text": notification.title(),
+      "attachments": [{
+        "color": format!(
+          "#{}",
+          if notification.is_success { NOTIFICATION_COLOR_SUCCESS_HEX } else { NOTIFICATION_COLOR_FAILURE_HEX }
+        ),
+        "text": notification.detail_lines().join("\n"),
+      }],
+    });
+    post_webhook_json(&self.webhook_url, &payload)
+  }
+}
+
+struct TeamsWebhookNotifier {
+  webhook_url: String,
+}
+
+impl Notifier for TeamsWebhookNotifier {
+  fn channel_name(&self) -> &'static str {
+    "teams"
+  }
+
+  fn send(&self, notification: &JobNotification) -> Result<(), String> {
+    // NOTE: Legacy MessageCard is still the format accepted by Teams incoming webhooks and Workflows.
+    let facts: Vec<Value> = notification
+      .detail_lines()
+      .into_iter()
+      .map(|line| match line.split_once(": ") {
+        Some((name, value)) => json!({ "name": name, "value": value }),
+        None => json!({ "name": "Summary", "value": line }),
+      })
+      .collect();
+    let payload = json!({
+      "@type": "MessageCard",
+      "@context": "https://schema.org/extensions",
+      "summary": notification.title(),
+      "themeColor": if notification.is_success { NOTIFICATION_COLOR_SUCCESS_HEX } else { NOTIFICATION_COLOR_FAILURE_HEX },
+      "sections": [{
+        "activityTitle": notification.title(),
+        "activitySubtitle": notification.job_root_directory_path,
+        "facts": facts,
+      }],
+    });
+    post_webhook_json(&self.webhook_url, &payload)
+  }
+}
+
+struct DiscordWebhookNotifier {
+  webhook_url: String,
+}
+
+impl Notifier for DiscordWebhookNotifier {
+  fn channel_name(&self) -> &'static str {
+    "discord"
+  }
+
+  fn send(&self, notification: &JobNotification) -> Result<(), String> {
+    let payload = json!({
+      "embeds": [{
+        "title": notification.title(),
+        "description": notification.detail_lines().join("\n"),
+        "color": if notification.is_success { NOTIFICATION_COLOR_SUCCESS_DECIMAL } else { NOTIFICATION_COLOR_FAILURE_DECIMAL },
+        "footer": { "text": notification.job_root_directory_path },
+      }],
+    });
+    post_webhook_json(&self.webhook_url, &payload)
+  }
+}
+
+pub fn send_job_notifications(
+  targets: &[NotificationTarget],
+  notification: &JobNotification,
+) -> Vec<(&'static str, Result<(), String>)> {
+  targets
+    .iter()
+    .map(|target| {
+      let notifier = target.build_notifier();
+      (notifier.channel_name(), notifier.send(notification))
+    })
+    .collect()
+}
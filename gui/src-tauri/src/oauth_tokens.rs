@@ -0,0 +1,38 @@
+/*!
+Responsibility:
+- Share OAuth 2.0 token endpoint plumbing between cloud connectors (form POST, error decoding).
+- Keep connector refresh tokens in the OS keychain instead of settings files.
+*/
+
+use serde_json::{json, Value};
+
+pub fn post_oauth_form(url: &str, form: &[(&str, &str)]) -> Result<Value, Value> {
+  match ureq::post(url).send_form(form) {
+    Ok(response) => response
+      .into_json::<Value>()
+      .map_err(|error| json!({ "error": "invalid_response", "error_description": error.to_string() })),
+    // NOTE: OAuth errors (e.g. authorization_pending, invalid_grant) arrive as 400 responses with a JSON body.
+    Err(ureq::Error::Status(_, response)) => Err(response.into_json::<Value>().unwrap_or(Value::Null)),
+    Err(error) => Err(json!({ "error": "transport", "error_description": error.to_string() })),
+  }
+}
+
+pub fn describe_oauth_error(error_body: &Value) -> String {
+  let code = error_body.get("error").and_then(Value::as_str).unwrap_or("unknown_error");
+  let description = error_body.get("error_description").and_then(Value::as_str).unwrap_or("");
+  format!("{code}: {description}").trim_end_matches([':', ' ']).to_string()
+}
+
+fn keychain_entry(service_name: &str, account_name: &str) -> Result<keyring::Entry, String> {
+  keyring::Entry::new(service_name, account_name).map_err(|error| format!("Keychain is unavailable: {error}"))
+}
+
+pub fn store_refresh_token(service_name: &str, account_name: &str, refresh_token: &str) -> Result<(), String> {
+  keychain_entry(service_name, account_name)?
+    .set_password(refresh_token)
+    .map_err(|error| format!("Failed to store token in keychain: {error}"))
+}
+
+pub fn read_refresh_token(service_name: &str, account_name: &str) -> Option<String> {
+  keychain_entry(service_name, account_name).ok()?.get_password().ok()
+}
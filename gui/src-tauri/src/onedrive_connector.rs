@@ -0,0 +1,422 @@
+/*!
+Responsibility:
+- Sign in to Microsoft Graph with the OAuth device code flow and keep the refresh token in the OS keychain.
+- Poll one OneDrive / SharePoint folder for new documents and hand each download to a callback (which writes an inbox bundle).
+- Upload finished markdown back to a results folder next to the source document.
+*/
+
+use std::{
+  collections::BTreeSet,
+  fs,
+  io::Read,
+  path::Path,
+  sync::Arc,
+  thread,
+  time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+  connector_runtime::{read_connector_runtime_status, SharedConnectorRuntimeState},
+  oauth_tokens::{
+    describe_oauth_error,
+    post_oauth_form,
+    read_refresh_token as read_keychain_refresh_token,
+    store_refresh_token as store_keychain_refresh_token,
+  },
+};
+
+pub const ONEDRIVE_CONNECTOR_SETTINGS_FILENAME: &str = "onedrive.json";
+pub const ONEDRIVE_CONNECTOR_STATE_FILENAME: &str = "onedrive_state.json";
+
+const ONEDRIVE_KEYCHAIN_SERVICE_NAME: &str = "ocr-agent-onedrive";
+const MICROSOFT_LOGIN_BASE_URL: &str = "https://login.microsoftonline.com";
+const GRAPH_API_BASE_URL: &str = "https://graph.microsoft.com/v1.0";
+const GRAPH_SCOPES: &str = "offline_access Files.ReadWrite.All";
+const DEVICE_CODE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+const DEFAULT_TENANT: &str = "common";
+const DEFAULT_POLL_INTERVAL_SECONDS: u64 = 60;
+const DEFAULT_RESULTS_FOLDER_NAME: &str = "ocr-results";
+const MAX_DOWNLOAD_BYTES: u64 = 200_000_000;
+// NOTE: Refresh a little early so a token never expires between listing and downloading.
+const ACCESS_TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(120);
+const SUPPORTED_DOCUMENT_EXTENSIONS: [&str; 8] = ["pdf", "png", "jpg", "jpeg", "webp", "bmp", "tif", "tiff"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OneDriveConnectorSettings {
+  // NOTE: Application (client) id of an Azure AD app registration with "Allow public client flows" enabled.
+  pub client_id: String,
+  pub tenant: Option<String>,
+  // NOTE: Unset means the signed-in user's OneDrive; set a document library drive id for SharePoint.
+  pub drive_id: Option<String>,
+  // NOTE: Folder path relative to the drive root, e.g. "Scans/Inbox"; empty means the root.
+  pub folder_path: String,
+  pub poll_interval_seconds: Option<u64>,
+  #[serde(default)]
+  pub is_write_back_enabled: bool,
+  pub results_folder_name: Option<String>,
+  // NOTE: Name of a job settings preset applied to every imported document.
+  pub preset_name: Option<String>,
+}
+
+impl OneDriveConnectorSettings {
+  fn tenant(&self) -> &str {
+    self
+      .tenant
+      .as_deref()
+      .map(str::trim)
+      .filter(|tenant| !tenant.is_empty())
+      .unwrap_or(DEFAULT_TENANT)
+  }
+
+  pub fn poll_interval(&self) -> Duration {
+    Duration::from_secs(self.poll_interval_seconds.unwrap_or(DEFAULT_POLL_INTERVAL_SECONDS).max(10))
+  }
+
+  pub fn results_folder_name(&self) -> String {
+    self
+      .results_folder_name
+      .as_deref()
+      .map(str::trim)
+      .filter(|name| !name.is_empty())
+      .unwrap_or(DEFAULT_RESULTS_FOLDER_NAME)
+      .to_string()
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OneDriveWriteBackTarget {
+  pub drive_id: String,
+  pub parent_item_id: String,
+  pub results_folder_name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoteDriveItem {
+  pub item_id: String,
+  pub name: String,
+  pub drive_id: String,
+  pub parent_item_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeviceCodePrompt {
+  pub user_code: String,
+  pub verification_uri: String,
+  pub message: String,
+  pub expires_in_seconds: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingDeviceCodeSignIn {
+  device_code: String,
+  poll_interval: Duration,
+  expires_at: Instant,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct OneDriveConnectorPersistentState {
+  #[serde(default)]
+  processed_item_ids: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OneDriveConnectorStatus {
+  pub is_running: bool,
+  pub is_signed_in: bool,
+  pub last_error_message: Option<String>,
+  pub processed_item_count: usize,
+}
+
+pub type OneDriveDocumentHandler = Arc<dyn Fn(&RemoteDriveItem, Vec<u8>) -> Result<(), String> + Send + Sync>;
+
+fn keychain_account_name(settings: &OneDriveConnectorSettings) -> String {
+  format!("{}@{}", settings.client_id.trim(), settings.tenant())
+}
+
+fn store_refresh_token(settings: &OneDriveConnectorSettings, refresh_token: &str) -> Result<(), String> {
+  store_keychain_refresh_token(ONEDRIVE_KEYCHAIN_SERVICE_NAME, &keychain_account_name(settings), refresh_token)
+}
+
+fn read_refresh_token(settings: &OneDriveConnectorSettings) -> Result<String, String> {
+  read_keychain_refresh_token(ONEDRIVE_KEYCHAIN_SERVICE_NAME, &keychain_account_name(settings))
+    .ok_or_else(|| "OneDrive is not signed in.".to_string())
+}
+
+pub fn is_signed_in(settings: &OneDriveConnectorSettings) -> bool {
+  read_refresh_token(settings).is_ok()
+}
+
+fn token_endpoint_url(settings: &OneDriveConnectorSettings) -> String {
+  format!("{MICROSOFT_LOGIN_BASE_URL}/{}/oauth2/v2.0/token", settings.tenant())
+}
+
+pub fn begin_device_code_sign_in(
+  settings: &OneDriveConnectorSettings,
+) -> Result<(DeviceCodePrompt, PendingDeviceCodeSignIn), String> {
+  if settings.client_id.trim().is_empty() {
+    return Err("OneDrive client_id is empty.".to_string());
+  }
+  let url = format!("{MICROSOFT_LOGIN_BASE_URL}/{}/oauth2/v2.0/devicecode", settings.tenant());
+  let body = post_oauth_form(&url, &[("client_id", settings.client_id.trim()), ("scope", GRAPH_SCOPES)])
+    .map_err(|error_body| format!("Device code request failed: {}", describe_oauth_error(&error_body)))?;
+
+  let read_string = |key: &str| -> Result<String, String> {
+    body
+      .get(key)
+      .and_then(Value::as_str)
+      .map(str::to_string)
+      .ok_or_else(|| format!("Device code response is missing {key}"))
+  };
+  let expires_in_seconds = body.get("expires_in").and_then(Value::as_u64).unwrap_or(900);
+  let poll_interval_seconds = body.get("interval").and_then(Value::as_u64).unwrap_or(5);
+  let prompt = DeviceCodePrompt {
+    user_code: read_string("user_code")?,
+    verification_uri: read_string("verification_uri")?,
+    message: read_string("message").unwrap_or_default(),
+    expires_in_seconds,
+  };
+  let pending = PendingDeviceCodeSignIn {
+    device_code: read_string("device_code")?,
+    poll_interval: Duration::from_secs(poll_interval_seconds),
+    expires_at: Instant::now() + Duration::from_secs(expires_in_seconds),
+  };
+  Ok((prompt, pending))
+}
+
+pub fn complete_device_code_sign_in(
+  settings: &OneDriveConnectorSettings,
+  pending: PendingDeviceCodeSignIn,
+) -> Result<(), String> {
+  let url = token_endpoint_url(settings);
+  let mut poll_interval = pending.poll_interval;
+  while Instant::now() < pending.expires_at {
+    thread::sleep(poll_interval);
+    let result = post_oauth_form(
+      &url,
+      &[
+        ("grant_type", DEVICE_CODE_GRANT_TYPE),
+        ("client_id", settings.client_id.trim()),
+        ("device_code", &pending.device_code),
+      ],
+    );
+    match result {
+      Ok(body) => {
+        let refresh_token = body
+          .get("refresh_token")
+          .and_then(Value::as_str)
+          .ok_or_else(|| "Sign-in succeeded but no refresh token was returned (is offline_access granted?)".to_string())?;
+        return store_refresh_token(settings, refresh_token);
+      }
+      Err(error_body) => match error_body.get("error").and_then(Value::as_str) {
+        Some("authorization_pending") => continue,
+        Some("slow_down") => poll_interval += Duration::from_secs(5),
+        _ => return Err(format!("OneDrive sign-in failed: {}", describe_oauth_error(&error_body))),
+      },
+    }
+  }
+  Err("OneDrive sign-in expired before it was completed.".to_string())
+}
+
+fn percent_encode_path(path: &str) -> String {
+  let mut encoded = String::new();
+  for byte in path.as_bytes() {
+    match byte {
+      b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => encoded.push(*byte as char),
+      _ => encoded.push_str(&format!("%{byte:02X}")),
+    }
+  }
+  encoded
+}
+
+fn is_supported_document_name(name: &str) -> bool {
+  let Some((_, extension)) = name.rsplit_once('.') else {
+    return false;
+  };
+  SUPPORTED_DOCUMENT_EXTENSIONS.contains(&extension.to_lowercase().as_str())
+}
+
+pub struct GraphSession {
+  settings: OneDriveConnectorSettings,
+  cached_access_token: Option<(String, Instant)>,
+}
+
+impl GraphSession {
+  pub fn new(settings: OneDriveConnectorSettings) -> GraphSession {
+    GraphSession {
+      settings,
+      cached_access_token: None,
+    }
+  }
+
+  fn access_token(&mut self) -> Result<String, String> {
+    if let Some((access_token, expires_at)) = self.cached_access_token.as_ref() {
+      if Instant::now() < *expires_at {
+        return Ok(access_token.clone());
+      }
+    }
+    let refresh_token = read_refresh_token(&self.settings)?;
+    let body = post_oauth_form(
+      &token_endpoint_url(&self.settings),
+      &[
+        ("grant_type", "refresh_token"),
+        ("client_id", self.settings.client_id.trim()),
+        ("refresh_token", &refresh_token),
+        ("scope", GRAPH_SCOPES),
+      ],
+    )
+    .map_err(|error_body| format!("OneDrive token refresh failed: {}", describe_oauth_error(&error_body)))?;
+
+    let access_token = body
+      .get("access_token")
+      .and_then(Value::as_str)
+      .ok_or_else(|| "Token response is missing access_token".to_string())?
+      .to_string();
+    // NOTE: Microsoft rotates refresh tokens; keep the newest one so the connector survives long uptimes.
+    if let Some(rotated_refresh_token) = body.get("refresh_token").and_then(Value::as_str) {
+      store_refresh_token(&self.settings, rotated_refresh_token)?;
+    }
+    let expires_in = Duration::from_secs(body.get("expires_in").and_then(Value::as_u64).unwrap_or(3600));
+    let expires_at = Instant::now() + expires_in.saturating_sub(ACCESS_TOKEN_EXPIRY_MARGIN);
+    self.cached_access_token = Some((access_token.clone(), expires_at));
+    Ok(access_token)
+  }
+
+  fn watched_folder_children_url(&self) -> String {
+    let drive_base = match self.settings.drive_id.as_deref().map(str::trim).filter(|id| !id.is_empty()) {
+      Some(drive_id) => format!("{GRAPH_API_BASE_URL}/drives/{drive_id}"),
+      None => format!("{GRAPH_API_BASE_URL}/me/drive"),
+    };
+    let folder_path = self.settings.folder_path.trim().trim_matches('/');
+    let select = "$select=id,name,file,parentReference&$top=200";
+    if folder_path.is_empty() {
+      return format!("{drive_base}/root/children?{select}");
+    }
+    format!("{drive_base}/root:/{}:/children?{select}", percent_encode_path(folder_path))
+  }
+
+  pub fn list_folder_documents(&mut self) -> Result<Vec<RemoteDriveItem>, String> {
+    let mut next_url = Some(self.watched_folder_children_url());
+    let mut items: Vec<RemoteDriveItem> = vec![];
+    while let Some(url) = next_url.take() {
+      let access_token = self.access_token()?;
+      let body = ureq::get(&url)
+        .set("Authorization", &format!("Bearer {access_token}"))
+        .call()
+        .map_err(|error| format!("Failed to list OneDrive folder: {error}"))?
+        .into_json::<Value>()
+        .map_err(|error| error.to_string())?;
+
+      for entry in body.get("value").and_then(Value::as_array).into_iter().flatten() {
+        if entry.get("file").is_none() {
+          // Guard: subfolders (including the results folder) are not documents.
+          continue;
+        }
+        let read = |pointer: &str| entry.pointer(pointer).and_then(Value::as_str).unwrap_or("").to_string();
+        let item = RemoteDriveItem {
+          item_id: read("/id"),
+          name: read("/name"),
+          drive_id: read("/parentReference/driveId"),
+          parent_item_id: read("/parentReference/id"),
+        };
+        if item.item_id.is_empty() || !is_supported_document_name(&item.name) {
+          continue;
+        }
+        items.push(item);
+      }
+      next_url = body.get("@odata.nextLink").and_then(Value::as_str).map(str::to_string);
+    }
+    Ok(items)
+  }
+
+  pub fn download_item(&mut self, item: &RemoteDriveItem) -> Result<Vec<u8>, String> {
+    let access_token = self.access_token()?;
+    let url = format!("{GRAPH_API_BASE_URL}/drives/{}/items/{}/content", item.drive_id, item.item_id);
+    let response = ureq::get(&url)
+      .set("Authorization", &format!("Bearer {access_token}"))
+      .call()
+      .map_err(|error| format!("Failed to download {}: {error}", item.name))?;
+    let mut bytes: Vec<u8> = vec![];
+    response
+      .into_reader()
+      .take(MAX_DOWNLOAD_BYTES + 1)
+      .read_to_end(&mut bytes)
+      .map_err(|error| format!("Failed to download {}: {error}", item.name))?;
+    if bytes.len() as u64 > MAX_DOWNLOAD_BYTES {
+      return Err(format!("{} exceeds {MAX_DOWNLOAD_BYTES} bytes.", item.name));
+    }
+    Ok(bytes)
+  }
+
+  pub fn upload_result_file(&mut self, target: &OneDriveWriteBackTarget, local_file_path: &Path) -> Result<String, String> {
+    let filename = local_file_path
+      .file_name()
+      .and_then(|name| name.to_str())
+      .ok_or_else(|| format!("Invalid result path: {}", local_file_path.display()))?;
+    let bytes = fs::read(local_file_path).map_err(|error| error.to_string())?;
+    let remote_relative_path = format!("{}/{filename}", target.results_folder_name);
+    let url = format!(
+      "{GRAPH_API_BASE_URL}/drives/{}/items/{}:/{}:/content",
+      target.drive_id,
+      target.parent_item_id,
+      percent_encode_path(&remote_relative_path)
+    );
+    let access_token = self.access_token()?;
+    ureq::put(&url)
+      .set("Authorization", &format!("Bearer {access_token}"))
+      .set("Content-Type", "text/markdown; charset=utf-8")
+      .send_bytes(&bytes)
+      .map_err(|error| format!("Failed to upload {filename}: {error}"))?;
+    Ok(remote_relative_path)
+  }
+}
+
+fn read_persistent_state(state_file_path: &Path) -> OneDriveConnectorPersistentState {
+  fs::read_to_string(state_file_path)
+    .ok()
+    .and_then(|raw| serde_json::from_str::<OneDriveConnectorPersistentState>(&raw).ok())
+    .unwrap_or_default()
+}
+
+fn write_persistent_state(state_file_path: &Path, state: &OneDriveConnectorPersistentState) -> Result<(), String> {
+  if let Some(parent) = state_file_path.parent() {
+    fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+  }
+  let serialized = serde_json::to_string_pretty(state).map_err(|error| error.to_string())?;
+  fs::write(state_file_path, serialized).map_err(|error| error.to_string())
+}
+
+pub fn poll_onedrive_folder_once(
+  session: &mut GraphSession,
+  state_file_path: &Path,
+  handler: &OneDriveDocumentHandler,
+) -> Result<(), String> {
+  let mut persistent_state = read_persistent_state(state_file_path);
+  for item in session.list_folder_documents()? {
+    if persistent_state.processed_item_ids.contains(&item.item_id) {
+      continue;
+    }
+    let bytes = session.download_item(&item)?;
+    handler(&item, bytes)?;
+    // NOTE: Recorded only after the bundle is written so a crash re-downloads instead of losing the document.
+    persistent_state.processed_item_ids.insert(item.item_id.clone());
+    write_persistent_state(state_file_path, &persistent_state)?;
+  }
+  Ok(())
+}
+
+pub fn get_onedrive_connector_status(
+  state: &SharedConnectorRuntimeState,
+  settings: Option<&OneDriveConnectorSettings>,
+  state_file_path: &Path,
+) -> OneDriveConnectorStatus {
+  let (is_running, last_error_message) = read_connector_runtime_status(state);
+  OneDriveConnectorStatus {
+    is_running,
+    is_signed_in: settings.map(is_signed_in).unwrap_or(false),
+    last_error_message,
+    processed_item_count: read_persistent_state(state_file_path).processed_item_ids.len(),
+  }
+}
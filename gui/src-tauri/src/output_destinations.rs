@@ -0,0 +1,82 @@
+/*!
+Responsibility:
+- Copy a finished job's final outputs to extra destination directories (e.g. a team's shared results folder on a network share).
+- Report success per destination so a failed share can be retried without re-running OCR.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::now_unix_timestamp_millis;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputDestination {
+  // NOTE: Outputs land in `<directory_path>/<job folder name>/`, keeping their paths relative to the job root.
+  pub directory_path: String,
+  pub label: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputDestinationResult {
+  pub directory_path: String,
+  pub label: Option<String>,
+  pub is_succeeded: bool,
+  pub copied_file_count: usize,
+  pub error_message: Option<String>,
+  pub finished_unix_timestamp_millis: i64,
+}
+
+fn copy_file_atomically(source_file_path: &Path, destination_file_path: &Path) -> Result<(), String> {
+  if let Some(parent) = destination_file_path.parent() {
+    fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+  }
+  // Guard: readers of a shared folder must never pick up a half-copied file.
+  let mut temporary_file_name = destination_file_path.file_name().unwrap_or_default().to_os_string();
+  temporary_file_name.push(".partial");
+  let temporary_path = destination_file_path.with_file_name(temporary_file_name);
+  fs::copy(source_file_path, &temporary_path)
+    .map_err(|error| format!("Failed to copy {}: {error}", source_file_path.display()))?;
+  fs::rename(&temporary_path, destination_file_path).map_err(|error| error.to_string())
+}
+
+fn copy_outputs(job_root_directory_path: &Path, output_file_paths: &[PathBuf], destination: &OutputDestination) -> Result<usize, String> {
+  let destination_root_path = PathBuf::from(destination.directory_path.trim());
+  if !destination_root_path.is_dir() {
+    // Guard: an unmounted share must fail visibly instead of filling a local folder with the same path.
+    return Err(format!("Destination is not a reachable directory: {}", destination_root_path.display()));
+  }
+  let job_folder_name = job_root_directory_path
+    .file_name()
+    .ok_or_else(|| format!("Job root has no folder name: {}", job_root_directory_path.display()))?;
+  let job_destination_path = destination_root_path.join(job_folder_name);
+
+  let mut copied_file_count = 0usize;
+  for output_file_path in output_file_paths {
+    let relative_path = output_file_path
+      .strip_prefix(job_root_directory_path)
+      .map_err(|_| format!("Output is outside the job root: {}", output_file_path.display()))?;
+    copy_file_atomically(output_file_path, &job_destination_path.join(relative_path))?;
+    copied_file_count += 1;
+  }
+  Ok(copied_file_count)
+}
+
+pub fn deliver_outputs_to_destination(
+  job_root_directory_path: &Path,
+  output_file_paths: &[PathBuf],
+  destination: &OutputDestination,
+) -> OutputDestinationResult {
+  let copy_result = copy_outputs(job_root_directory_path, output_file_paths, destination);
+  OutputDestinationResult {
+    directory_path: destination.directory_path.clone(),
+    label: destination.label.clone(),
+    is_succeeded: copy_result.is_ok(),
+    copied_file_count: copy_result.as_ref().copied().unwrap_or(0),
+    error_message: copy_result.err(),
+    finished_unix_timestamp_millis: now_unix_timestamp_millis(),
+  }
+}
@@ -0,0 +1,95 @@
+/*!
+Responsibility:
+- Load per-task OCR markdown from a job root's queue database.
+- Group per-task markdown into per-document markdown (one document per input file) in enqueue order.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use rusqlite::Connection;
+use serde::Serialize;
+
+const CONTAINER_DATA_DIRECTORY_PREFIX: &str = "/data/";
+
+#[derive(Debug, Clone)]
+pub struct CompletedTaskMarkdown {
+  pub source_path: String,
+  pub markdown: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DocumentMarkdown {
+  pub source_path: String,
+  pub markdown: String,
+}
+
+pub fn map_container_data_path_to_host(job_root_directory_path: &Path, container_path: &str) -> PathBuf {
+  // Guard: the container mounts the job root at /data; anything else is already a host path.
+  match container_path.strip_prefix(CONTAINER_DATA_DIRECTORY_PREFIX) {
+    Some(relative) => job_root_directory_path.join(relative),
+    None => PathBuf::from(container_path),
+  }
+}
+
+pub fn load_completed_task_markdowns(
+  job_root_directory_path: &Path,
+  queue_database_path: &Path,
+) -> Result<Vec<CompletedTaskMarkdown>, String> {
+  if !queue_database_path.exists() {
+    // Guard: queue might not exist until enqueue has run.
+    return Ok(vec![]);
+  }
+
+  let connection = Connection::open(queue_database_path).map_err(|error| error.to_string())?;
+  let mut statement = connection
+    .prepare(
+      "SELECT source_path, output_markdown_path \
+       FROM tasks WHERE status = 'completed' AND output_markdown_path IS NOT NULL ORDER BY task_id ASC",
+    )
+    .map_err(|error| error.to_string())?;
+
+  let mut tasks: Vec<CompletedTaskMarkdown> = vec![];
+  let mut rows = statement.query([]).map_err(|error| error.to_string())?;
+  while let Some(row) = rows.next().map_err(|error| error.to_string())? {
+    let source_path: String = row.get(0).map_err(|error| error.to_string())?;
+    let output_markdown_path: String = row.get(1).map_err(|error| error.to_string())?;
+
+    let host_markdown_path = map_container_data_path_to_host(job_root_directory_path, &output_markdown_path);
+    let Ok(markdown) = fs::read_to_string(&host_markdown_path) else {
+      // Guard: per-task markdown may have been cleaned up; skip instead of failing the whole load.
+      continue;
+    };
+    tasks.push(CompletedTaskMarkdown { source_path, markdown });
+  }
+  Ok(tasks)
+}
+
+pub fn group_task_markdowns_into_documents(tasks: &[CompletedTaskMarkdown]) -> Vec<DocumentMarkdown> {
+  let mut documents: Vec<DocumentMarkdown> = vec![];
+  for task in tasks {
+    // Guard: tasks are in enqueue order, so pages of one PDF are contiguous.
+    if let Some(last) = documents.last_mut() {
+      if last.source_path == task.source_path {
+        last.markdown.push_str("\n\n");
+        last.markdown.push_str(&task.markdown);
+        continue;
+      }
+    }
+    documents.push(DocumentMarkdown {
+      source_path: task.source_path.clone(),
+      markdown: task.markdown.clone(),
+    });
+  }
+  documents
+}
+
+pub fn load_document_markdowns(
+  job_root_directory_path: &Path,
+  queue_database_path: &Path,
+) -> Result<Vec<DocumentMarkdown>, String> {
+  let tasks = load_completed_task_markdowns(job_root_directory_path, queue_database_path)?;
+  Ok(group_task_markdowns_into_documents(&tasks))
+}
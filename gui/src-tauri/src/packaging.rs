@@ -0,0 +1,120 @@
+/*!
+Responsibility:
+- Zip a job's final markdown, JSON sidecars, assets, and report into one distributable archive.
+
+Archive layout (stable across versions):
+- `document.md`: the final merged markdown
+- `markdown/<relative path>`: other markdown under `output/` (per-task pages, translations, summaries)
+- `json/<relative path>`: JSON under `output/` (job report, structured fields, regions, spellcheck report)
+- `assets/<relative path>`: every other file under `output/` (e.g. images saved by the model)
+*/
+
+use std::{
+  fs::{self, File},
+  io::{self, Write},
+  path::{Path, PathBuf},
+};
+
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+pub const PACKAGE_ARCHIVE_FILENAME: &str = "ocr_outputs.zip";
+
+const PACKAGED_DOCUMENT_ENTRY_NAME: &str = "document.md";
+// NOTE: Intermediate page renders are large and reproducible from the inputs.
+const EXCLUDED_OUTPUT_DIRECTORY_NAMES: &[&str] = &["work"];
+
+fn classify_archive_directory(file_path: &Path) -> &'static str {
+  let extension = file_path
+    .extension()
+    .and_then(|value| value.to_str())
+    .unwrap_or("")
+    .to_lowercase();
+  match extension.as_str() {
+    "md" | "markdown" => "markdown",
+    "json" => "json",
+    _ => "assets",
+  }
+}
+
+fn to_archive_entry_name(relative_path: &Path) -> String {
+  // Guard: zip entry names always use forward slashes, even on Windows.
+  relative_path
+    .components()
+    .map(|component| component.as_os_str().to_string_lossy().to_string())
+    .collect::<Vec<String>>()
+    .join("/")
+}
+
+fn collect_output_files(output_directory_path: &Path) -> Result<Vec<PathBuf>, String> {
+  if !output_directory_path.exists() {
+    return Ok(vec![]);
+  }
+  let mut file_paths: Vec<PathBuf> = vec![];
+  let walker = walkdir::WalkDir::new(output_directory_path)
+    .sort_by_file_name()
+    .into_iter()
+    .filter_entry(|entry| {
+      !(entry.file_type().is_dir()
+        && entry.depth() == 1
+        && EXCLUDED_OUTPUT_DIRECTORY_NAMES.contains(&entry.file_name().to_string_lossy().as_ref()))
+    });
+  for entry in walker {
+    let entry = entry.map_err(|error| error.to_string())?;
+    if entry.file_type().is_file() {
+      file_paths.push(entry.into_path());
+    }
+  }
+  Ok(file_paths)
+}
+
+fn add_file_to_archive(
+  archive: &mut ZipWriter<File>,
+  entry_name: &str,
+  file_path: &Path,
+  options: SimpleFileOptions,
+) -> Result<(), String> {
+  archive
+    .start_file(entry_name, options)
+    .map_err(|error| format!("Failed to add {entry_name} to archive: {error}"))?;
+  let mut source = File::open(file_path).map_err(|error| format!("Failed to open {}: {error}", file_path.display()))?;
+  io::copy(&mut source, archive).map_err(|error| error.to_string())?;
+  Ok(())
+}
+
+pub fn package_job_outputs(
+  markdown_path: &Path,
+  output_directory_path: &Path,
+  archive_path: &Path,
+) -> Result<usize, String> {
+  if !markdown_path.exists() {
+    return Err(format!("Output markdown not found: {}", markdown_path.display()));
+  }
+
+  // NOTE: Write to a temporary file first so a failed run never leaves a truncated archive behind.
+  let temporary_archive_path = archive_path.with_extension("zip.partial");
+  let archive_file = File::create(&temporary_archive_path).map_err(|error| error.to_string())?;
+  let mut archive = ZipWriter::new(archive_file);
+  let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+  add_file_to_archive(&mut archive, PACKAGED_DOCUMENT_ENTRY_NAME, markdown_path, options)?;
+  let mut packaged_file_count = 1;
+
+  for file_path in collect_output_files(output_directory_path)? {
+    let relative_path = file_path
+      .strip_prefix(output_directory_path)
+      .map_err(|error| error.to_string())?;
+    let entry_name = format!(
+      "{}/{}",
+      classify_archive_directory(&file_path),
+      to_archive_entry_name(relative_path)
+    );
+    add_file_to_archive(&mut archive, &entry_name, &file_path, options)?;
+    packaged_file_count += 1;
+  }
+
+  let mut archive_file = archive.finish().map_err(|error| error.to_string())?;
+  archive_file.flush().map_err(|error| error.to_string())?;
+  drop(archive_file);
+  fs::rename(&temporary_archive_path, archive_path).map_err(|error| error.to_string())?;
+  Ok(packaged_file_count)
+}
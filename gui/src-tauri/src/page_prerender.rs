@@ -0,0 +1,140 @@
+/*!
+Responsibility:
+- Render PDF pages on the host (via the pdfium library): the next queued page for the preview pane, any input page on demand, or tuning samples.
+- Keep host renders out of the runner's work files; OCR input is always rendered by the runner itself.
+*/
+
+use std::{
+  collections::HashSet,
+  fs,
+  io::BufWriter,
+  path::{Path, PathBuf},
+  sync::{Mutex, OnceLock},
+  thread,
+};
+
+use pdfium_render::prelude::{PdfPageIndex, PdfRenderConfig, Pdfium};
+
+// NOTE: Matches the CLI's `DEFAULT_PDF_RENDER_DPI` so the lookahead image looks like the page the runner will OCR.
+const PDF_RENDER_DOTS_PER_INCH: f32 = 200.0;
+const PDF_BASE_DOTS_PER_INCH: f32 = 72.0;
+// NOTE: Browsing pages before a run only needs a readable thumbnail; full OCR resolution would be slow for large PDFs.
+pub const PDF_PAGE_BROWSE_DOTS_PER_INCH: f32 = 100.0;
+
+static PDFIUM: OnceLock<Result<Pdfium, String>> = OnceLock::new();
+static RENDERS_IN_FLIGHT: Mutex<Option<HashSet<PathBuf>>> = Mutex::new(None);
+
+// NOTE: Looks next to the app executable first (bundled `pdfium.dll` / `libpdfium.so` / `libpdfium.dylib`), then the system.
+fn pdfium() -> Result<&'static Pdfium, String> {
+  PDFIUM
+    .get_or_init(|| {
+      let bundled_library_path = std::env::current_exe()
+        .ok()
+        .and_then(|exe_path| exe_path.parent().map(Pdfium::pdfium_platform_library_name_at_path));
+      let bindings = match bundled_library_path.map(Pdfium::bind_to_library) {
+        Some(Ok(bindings)) => Ok(bindings),
+        _ => Pdfium::bind_to_system_library(),
+      };
+      bindings
+        .map(Pdfium::new)
+        .map_err(|error| format!("pdfium library not available for lookahead previews: {error}"))
+    })
+    .as_ref()
+    .map_err(Clone::clone)
+}
+
+fn encode_rgb_png(width: u32, height: u32, rgba: &[u8]) -> Result<Vec<u8>, String> {
+  // NOTE: Drop alpha like the CLI's PIL save does; rendered pages are opaque anyway.
+  let rgb: Vec<u8> = rgba
+    .chunks_exact(4)
+    .flat_map(|pixel| [pixel[0], pixel[1], pixel[2]])
+    .collect();
+  let mut png_bytes: Vec<u8> = vec![];
+  {
+    let mut encoder = png::Encoder::new(BufWriter::new(&mut png_bytes), width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(|error| error.to_string())?;
+    writer.write_image_data(&rgb).map_err(|error| error.to_string())?;
+  }
+  Ok(png_bytes)
+}
+
+#[derive(Debug, Clone)]
+pub struct RenderedPdfPage {
+  pub png_bytes: Vec<u8>,
+  pub width_pixels: u32,
+  pub height_pixels: u32,
+  pub total_pages: i64,
+}
+
+pub fn render_pdf_page(pdf_file_path: &Path, pdf_page_index: i64, dots_per_inch: f32) -> Result<RenderedPdfPage, String> {
+  let page_index = PdfPageIndex::try_from(pdf_page_index).map_err(|_| format!("Invalid PDF page index: {pdf_page_index}"))?;
+  let document = pdfium()?
+    .load_pdf_from_file(pdf_file_path, None)
+    .map_err(|error| format!("Failed to open {}: {error}", pdf_file_path.display()))?;
+  let pages = document.pages();
+  let total_pages = i64::from(pages.len());
+  if pdf_page_index < 0 || pdf_page_index >= total_pages {
+    // Guard: surface the page count so the GUI can clamp its page picker.
+    return Err(format!("Page index {pdf_page_index} is out of range (PDF has {total_pages} pages)."));
+  }
+  let page = pages.get(page_index).map_err(|error| error.to_string())?;
+  let render_config = PdfRenderConfig::new().scale_page_by_factor(dots_per_inch / PDF_BASE_DOTS_PER_INCH);
+  let bitmap = page.render_with_config(&render_config).map_err(|error| error.to_string())?;
+  let width_pixels = bitmap.width() as u32;
+  let height_pixels = bitmap.height() as u32;
+  Ok(RenderedPdfPage {
+    png_bytes: encode_rgb_png(width_pixels, height_pixels, &bitmap.as_rgba_bytes())?,
+    width_pixels,
+    height_pixels,
+    total_pages,
+  })
+}
+
+pub fn count_pdf_pages(pdf_file_path: &Path) -> Result<i64, String> {
+  let document = pdfium()?
+    .load_pdf_from_file(pdf_file_path, None)
+    .map_err(|error| format!("Failed to open {}: {error}", pdf_file_path.display()))?;
+  Ok(i64::from(document.pages().len()))
+}
+
+// NOTE: Renders at the runner's DPI, so a page written here OCRs like the same page of the PDF.
+pub fn render_pdf_page_to_png(pdf_file_path: &Path, pdf_page_index: i64, output_image_file_path: &Path) -> Result<(), String> {
+  let png_bytes = render_pdf_page(pdf_file_path, pdf_page_index, PDF_RENDER_DOTS_PER_INCH)?.png_bytes;
+
+  if let Some(parent) = output_image_file_path.parent() {
+    fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+  }
+  // Guard: write then rename so a preview poll never reads a half-written PNG.
+  let temporary_path = output_image_file_path.with_extension("png.partial");
+  fs::write(&temporary_path, png_bytes).map_err(|error| error.to_string())?;
+  fs::rename(&temporary_path, output_image_file_path).map_err(|error| error.to_string())
+}
+
+// NOTE: Returns immediately; the image appears on a later preview poll. Without pdfium there is simply no lookahead image.
+pub fn spawn_pdf_page_prerender(pdf_file_path: PathBuf, pdf_page_index: i64, output_image_file_path: PathBuf) {
+  if output_image_file_path.exists() {
+    return;
+  }
+  {
+    let Ok(mut locked) = RENDERS_IN_FLIGHT.lock() else {
+      return;
+    };
+    if !locked.get_or_insert_with(HashSet::new).insert(output_image_file_path.clone()) {
+      // Guard: the preview is polled every second; one render per page is enough.
+      return;
+    }
+  }
+  thread::spawn(move || {
+    if render_pdf_page_to_png(&pdf_file_path, pdf_page_index, &output_image_file_path).is_err() {
+      // Guard: a page that failed once stays marked so it is not re-rendered on every poll.
+      return;
+    }
+    if let Ok(mut locked) = RENDERS_IN_FLIGHT.lock() {
+      if let Some(in_flight) = locked.as_mut() {
+        in_flight.remove(&output_image_file_path);
+      }
+    }
+  });
+}
@@ -0,0 +1,242 @@
+/*!
+Responsibility:
+- Discover post-processing plugins under `<app config>/plugins/<name>/plugin.json`.
+- Run a plugin executable with a JSON stdin/stdout contract (transformers rewrite markdown, exporters write files).
+
+Contract (version 1):
+- stdin: `PluginRequest` as one JSON document, then EOF.
+- stdout: `PluginResponse` as one JSON document; stderr lines are forwarded to the job log.
+*/
+
+use std::{
+  fs,
+  io::{Read, Write},
+  path::{Path, PathBuf},
+  process::{Command, Stdio},
+  sync::Arc,
+  thread,
+  time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::log_capture::for_each_captured_line;
+
+pub const PLUGINS_DIRECTORY_NAME: &str = "plugins";
+pub const PLUGIN_EXPORTS_DIRECTORY_NAME: &str = "exports";
+
+const PLUGIN_MANIFEST_FILENAME: &str = "plugin.json";
+const PLUGIN_CONTRACT_VERSION: u32 = 1;
+const DEFAULT_PLUGIN_TIMEOUT_SECONDS: u64 = 300;
+const PLUGIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+pub type PluginLogSink = Arc<dyn Fn(String) + Send + Sync>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginKind {
+  Transformer,
+  Exporter,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginManifest {
+  pub name: String,
+  pub kind: PluginKind,
+  // NOTE: Relative paths resolve against the plugin directory.
+  pub executable: String,
+  #[serde(default)]
+  pub args: Vec<String>,
+  // NOTE: Lower runs first; ties are broken by name.
+  #[serde(default)]
+  pub order: i32,
+  pub timeout_seconds: Option<u64>,
+  #[serde(skip_deserializing)]
+  pub plugin_directory_path: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct PluginRequest<'a> {
+  contract_version: u32,
+  kind: PluginKind,
+  job_root_directory_path: String,
+  markdown_path: String,
+  markdown: &'a str,
+  // NOTE: Exporters write their files here (`output/exports/<plugin name>/`).
+  export_directory_path: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct PluginResponse {
+  markdown: Option<String>,
+  #[serde(default)]
+  written_paths: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PluginRunResult {
+  pub plugin_name: String,
+  pub kind: PluginKind,
+  pub is_markdown_rewritten: bool,
+  pub written_paths: Vec<String>,
+}
+
+pub fn discover_plugins(plugins_directory_path: &Path) -> Result<Vec<PluginManifest>, String> {
+  if !plugins_directory_path.exists() {
+    return Ok(vec![]);
+  }
+  let mut manifests: Vec<PluginManifest> = vec![];
+  for entry in fs::read_dir(plugins_directory_path).map_err(|error| error.to_string())? {
+    let entry = entry.map_err(|error| error.to_string())?;
+    let plugin_directory_path = entry.path();
+    let manifest_path = plugin_directory_path.join(PLUGIN_MANIFEST_FILENAME);
+    if !manifest_path.is_file() {
+      continue;
+    }
+    let raw = fs::read_to_string(&manifest_path).map_err(|error| error.to_string())?;
+    let mut manifest = serde_json::from_str::<PluginManifest>(&raw)
+      .map_err(|error| format!("Invalid plugin manifest {}: {error}", manifest_path.display()))?;
+    manifest.plugin_directory_path = plugin_directory_path.to_string_lossy().to_string();
+    manifests.push(manifest);
+  }
+  manifests.sort_by(|left, right| left.order.cmp(&right.order).then_with(|| left.name.cmp(&right.name)));
+  Ok(manifests)
+}
+
+fn resolve_plugin_executable_path(manifest: &PluginManifest) -> PathBuf {
+  let executable_path = PathBuf::from(&manifest.executable);
+  if executable_path.is_absolute() {
+    return executable_path;
+  }
+  let relative_to_plugin = Path::new(&manifest.plugin_directory_path).join(&executable_path);
+  if relative_to_plugin.exists() {
+    return relative_to_plugin;
+  }
+  // Guard: bare names (e.g. `python3`) are looked up on PATH.
+  executable_path
+}
+
+fn sanitize_plugin_directory_name(plugin_name: &str) -> String {
+  plugin_name
+    .chars()
+    .map(|character| {
+      if character.is_ascii_alphanumeric() || character == '-' || character == '_' {
+        character
+      } else {
+        '_'
+      }
+    })
+    .collect()
+}
+
+fn run_plugin_process(manifest: &PluginManifest, request_json: String, log_sink: PluginLogSink) -> Result<PluginResponse, String> {
+  let mut command = Command::new(resolve_plugin_executable_path(manifest));
+  command.args(&manifest.args);
+  command.current_dir(&manifest.plugin_directory_path);
+  command.stdin(Stdio::piped());
+  command.stdout(Stdio::piped());
+  command.stderr(Stdio::piped());
+  let mut child = command
+    .spawn()
+    .map_err(|error| format!("Failed to start plugin {}: {error}", manifest.name))?;
+
+  // NOTE: stdin/stdout/stderr are pumped on threads so a chatty plugin cannot deadlock on full pipes.
+  let mut stdin = child.stdin.take().ok_or_else(|| "Plugin stdin is unavailable".to_string())?;
+  let stdin_handle = thread::spawn(move || {
+    let _ = stdin.write_all(request_json.as_bytes());
+  });
+  let mut stdout = child.stdout.take().ok_or_else(|| "Plugin stdout is unavailable".to_string())?;
+  let stdout_handle = thread::spawn(move || {
+    let mut buffer = String::new();
+    let _ = stdout.read_to_string(&mut buffer);
+    buffer
+  });
+  if let Some(stderr) = child.stderr.take() {
+    let plugin_name = manifest.name.clone();
+    thread::spawn(move || {
+      for_each_captured_line(stderr, |line| log_sink(format!("[plugin {plugin_name}] {line}")));
+    });
+  }
+
+  let timeout = Duration::from_secs(manifest.timeout_seconds.unwrap_or(DEFAULT_PLUGIN_TIMEOUT_SECONDS));
+  let started_at = Instant::now();
+  let exit_status = loop {
+    if let Some(status) = child.try_wait().map_err(|error| error.to_string())? {
+      break status;
+    }
+    if started_at.elapsed() >= timeout {
+      let _ = child.kill();
+      let _ = child.wait();
+      return Err(format!("Plugin {} timed out after {} second(s)", manifest.name, timeout.as_secs()));
+    }
+    thread::sleep(PLUGIN_POLL_INTERVAL);
+  };
+  let _ = stdin_handle.join();
+  let stdout_text = stdout_handle.join().unwrap_or_default();
+
+  if !exit_status.success() {
+    return Err(format!("Plugin {} failed: {exit_status}", manifest.name));
+  }
+  if stdout_text.trim().is_empty() {
+    // Guard: an exporter with nothing to report may print nothing.
+    return Ok(PluginResponse::default());
+  }
+  serde_json::from_str::<PluginResponse>(&stdout_text)
+    .map_err(|error| format!("Plugin {} returned invalid JSON: {error}", manifest.name))
+}
+
+pub fn run_plugin(
+  manifest: &PluginManifest,
+  job_root_directory_path: &Path,
+  markdown_path: &Path,
+  output_directory_path: &Path,
+  log_sink: PluginLogSink,
+) -> Result<PluginRunResult, String> {
+  let markdown = fs::read_to_string(markdown_path)
+    .map_err(|error| format!("Failed to read {}: {error}", markdown_path.display()))?;
+  let export_directory_path = output_directory_path
+    .join(PLUGIN_EXPORTS_DIRECTORY_NAME)
+    .join(sanitize_plugin_directory_name(&manifest.name));
+  if manifest.kind == PluginKind::Exporter {
+    fs::create_dir_all(&export_directory_path).map_err(|error| error.to_string())?;
+  }
+
+  let request = PluginRequest {
+    contract_version: PLUGIN_CONTRACT_VERSION,
+    kind: manifest.kind,
+    job_root_directory_path: job_root_directory_path.to_string_lossy().to_string(),
+    markdown_path: markdown_path.to_string_lossy().to_string(),
+    markdown: &markdown,
+    export_directory_path: export_directory_path.to_string_lossy().to_string(),
+  };
+  let request_json = serde_json::to_string(&request).map_err(|error| error.to_string())?;
+  let response = run_plugin_process(manifest, request_json, log_sink)?;
+
+  let mut is_markdown_rewritten = false;
+  if manifest.kind == PluginKind::Transformer {
+    if let Some(rewritten_markdown) = response.markdown {
+      if rewritten_markdown != markdown {
+        fs::write(markdown_path, rewritten_markdown).map_err(|error| error.to_string())?;
+        is_markdown_rewritten = true;
+      }
+    }
+  }
+
+  Ok(PluginRunResult {
+    plugin_name: manifest.name.clone(),
+    kind: manifest.kind,
+    is_markdown_rewritten,
+    written_paths: response.written_paths,
+  })
+}
+
+pub fn select_enabled_plugins(
+  manifests: Vec<PluginManifest>,
+  enabled_plugin_names: &[String],
+  kind: PluginKind,
+) -> Vec<PluginManifest> {
+  manifests
+    .into_iter()
+    .filter(|manifest| manifest.kind == kind && enabled_plugin_names.contains(&manifest.name))
+    .collect()
+}
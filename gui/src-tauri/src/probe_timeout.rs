@@ -0,0 +1,98 @@
+/*!
+Responsibility:
+- Run the short commands that probe the container engine and GPU (`docker version`, `docker image inspect`,
+  `nvidia-smi`, ...) with a deadline: when Docker Desktop is wedged they never return and the GUI action waiting on
+  them freezes. A probe still running at the deadline is killed and reported as an unresponsive engine.
+*/
+
+use std::{
+  fmt,
+  io::Read,
+  process::{Command, Output, Stdio},
+  sync::mpsc::{self, Receiver},
+  thread,
+  time::{Duration, Instant},
+};
+
+// NOTE: A healthy engine answers in well under a second; Docker Desktop that is still starting up takes longer.
+pub const PROBE_COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+// NOTE: For probes that start a container or import the OCR pipeline (`docker compose run ... nvidia-smi`, host
+// Python), which are slow on a cold start.
+pub const SLOW_PROBE_COMMAND_TIMEOUT: Duration = Duration::from_secs(180);
+
+const PROBE_POLL_INTERVAL: Duration = Duration::from_millis(50);
+// NOTE: A helper process spawned by the probe can keep its output pipes open after the probe itself exited; the rest
+// of the output is given up on rather than waited for.
+const PROBE_OUTPUT_DRAIN_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Debug)]
+pub enum ProbeCommandError {
+  // NOTE: The command could not be started, or ran and failed; holds the message to show.
+  Failed(String),
+  // NOTE: Still running at the deadline and killed, so the engine (or GPU driver) is in a bad state.
+  EngineUnresponsive { program: String, timeout: Duration },
+}
+
+impl fmt::Display for ProbeCommandError {
+  fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ProbeCommandError::Failed(message) => formatter.write_str(message),
+      ProbeCommandError::EngineUnresponsive { program, timeout } => write!(
+        formatter,
+        "{program} did not respond within {} seconds and was stopped; the engine is unresponsive. \
+         Restart Docker Desktop (or the container engine) and try again.",
+        timeout.as_secs()
+      ),
+    }
+  }
+}
+
+impl From<ProbeCommandError> for String {
+  fn from(error: ProbeCommandError) -> Self {
+    error.to_string()
+  }
+}
+
+fn read_pipe_in_background(pipe: Option<impl Read + Send + 'static>) -> Receiver<Vec<u8>> {
+  let (sender, receiver) = mpsc::channel();
+  if let Some(mut pipe) = pipe {
+    thread::spawn(move || {
+      let mut bytes = vec![];
+      let _ = pipe.read_to_end(&mut bytes);
+      let _ = sender.send(bytes);
+    });
+  }
+  receiver
+}
+
+// NOTE: Like `Command::output` (stdin closed, stdout/stderr captured), but gives up at `timeout`. The pipes are read on
+// threads so a chatty probe cannot stall on a full pipe while this waits for it to exit.
+pub fn output_with_timeout(command: &mut Command, timeout: Duration) -> Result<Output, ProbeCommandError> {
+  let program = command.get_program().to_string_lossy().to_string();
+  let mut child = command
+    .stdin(Stdio::null())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .map_err(|error| ProbeCommandError::Failed(error.to_string()))?;
+  let stdout_receiver = read_pipe_in_background(child.stdout.take());
+  let stderr_receiver = read_pipe_in_background(child.stderr.take());
+
+  let started_at = Instant::now();
+  let status = loop {
+    if let Some(status) = child.try_wait().map_err(|error| ProbeCommandError::Failed(error.to_string()))? {
+      break status;
+    }
+    if started_at.elapsed() >= timeout {
+      let _ = child.kill();
+      let _ = child.wait();
+      return Err(ProbeCommandError::EngineUnresponsive { program, timeout });
+    }
+    thread::sleep(PROBE_POLL_INTERVAL);
+  };
+  Ok(Output {
+    status,
+    stdout: stdout_receiver.recv_timeout(PROBE_OUTPUT_DRAIN_TIMEOUT).unwrap_or_default(),
+    stderr: stderr_receiver.recv_timeout(PROBE_OUTPUT_DRAIN_TIMEOUT).unwrap_or_default(),
+  })
+}
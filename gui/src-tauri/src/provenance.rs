@@ -0,0 +1,197 @@
+/*!
+Responsibility:
+- Write a per-run provenance manifest (`output/provenance/run_<start millis>.json`): SHA-256 of every input and output,
+  engine/model identity, the settings used, timestamps, and the host.
+- Map each OCR'd page to the source file (and PDF page) it came from, so archived text can be traced to its scan.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+  duplicate_detection::sha256_file_hex,
+  host_engine::ExecutionEngineKind,
+  output_documents::map_container_data_path_to_host,
+};
+
+pub const PROVENANCE_DIRECTORY_NAME: &str = "provenance";
+pub const PROVENANCE_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvenanceFileEntry {
+  // NOTE: Relative to the job root, with forward slashes on every OS.
+  pub relative_path: String,
+  pub sha256: String,
+  pub size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvenancePageEntry {
+  pub source_relative_path: String,
+  // NOTE: Zero-based; None for image inputs.
+  pub pdf_page_index: Option<i64>,
+  pub output_relative_path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvenanceEngine {
+  pub kind: ExecutionEngineKind,
+  pub image_digest: Option<String>,
+  // NOTE: None means the job did not pin a revision; the runtime's `DEEPSEEK_OCR2_MODEL_REVISION` (if any) applied.
+  pub model_revision: Option<String>,
+  pub app_version: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvenanceHost {
+  pub hostname: Option<String>,
+  pub os: String,
+  pub arch: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvenanceManifest {
+  pub schema_version: u32,
+  pub job_root_directory_path: String,
+  pub run_started_unix_timestamp_millis: Option<i64>,
+  pub generated_unix_timestamp_millis: i64,
+  pub engine: ProvenanceEngine,
+  pub host: ProvenanceHost,
+  pub settings: Value,
+  pub inputs: Vec<ProvenanceFileEntry>,
+  pub outputs: Vec<ProvenanceFileEntry>,
+  pub pages: Vec<ProvenancePageEntry>,
+}
+
+fn to_relative_key(job_root_directory_path: &Path, file_path: &Path) -> String {
+  file_path
+    .strip_prefix(job_root_directory_path)
+    .unwrap_or(file_path)
+    .to_string_lossy()
+    .replace('\\', "/")
+}
+
+pub fn current_host() -> ProvenanceHost {
+  let hostname = ["COMPUTERNAME", "HOSTNAME"]
+    .iter()
+    .find_map(|name| std::env::var(name).ok())
+    .or_else(|| fs::read_to_string("/etc/hostname").ok())
+    .map(|value| value.trim().to_string())
+    .filter(|value| !value.is_empty());
+  ProvenanceHost {
+    hostname,
+    os: std::env::consts::OS.to_string(),
+    arch: std::env::consts::ARCH.to_string(),
+  }
+}
+
+// NOTE: `excluded_directory_names` applies to direct children of `directory_path` only (e.g. `work`, `provenance`).
+pub fn list_files_recursively(directory_path: &Path, excluded_directory_names: &[&str]) -> Vec<PathBuf> {
+  let mut file_paths: Vec<PathBuf> = walkdir::WalkDir::new(directory_path)
+    .sort_by_file_name()
+    .into_iter()
+    .filter_entry(|entry| {
+      !(entry.file_type().is_dir()
+        && entry.depth() == 1
+        && excluded_directory_names.contains(&entry.file_name().to_string_lossy().as_ref()))
+    })
+    .filter_map(|entry| entry.ok())
+    .filter(|entry| entry.file_type().is_file())
+    .map(|entry| entry.path().to_path_buf())
+    .collect();
+  file_paths.sort();
+  file_paths
+}
+
+pub fn hash_files(job_root_directory_path: &Path, file_paths: &[PathBuf]) -> Result<Vec<ProvenanceFileEntry>, String> {
+  file_paths
+    .iter()
+    .map(|file_path| {
+      let size_bytes = fs::metadata(file_path).map_err(|error| error.to_string())?.len();
+      Ok(ProvenanceFileEntry {
+        relative_path: to_relative_key(job_root_directory_path, file_path),
+        sha256: sha256_file_hex(file_path)?,
+        size_bytes,
+      })
+    })
+    .collect()
+}
+
+pub fn load_provenance_pages(job_root_directory_path: &Path, queue_database_path: &Path) -> Result<Vec<ProvenancePageEntry>, String> {
+  if !queue_database_path.exists() {
+    return Ok(vec![]);
+  }
+  let connection = Connection::open(queue_database_path).map_err(|error| error.to_string())?;
+  let mut statement = connection
+    .prepare(
+      "SELECT source_path, pdf_page_index, output_markdown_path \
+       FROM tasks WHERE status = 'completed' AND output_markdown_path IS NOT NULL ORDER BY task_id ASC",
+    )
+    .map_err(|error| error.to_string())?;
+  let mut pages: Vec<ProvenancePageEntry> = vec![];
+  let mut rows = statement.query([]).map_err(|error| error.to_string())?;
+  while let Some(row) = rows.next().map_err(|error| error.to_string())? {
+    let source_path: String = row.get(0).map_err(|error| error.to_string())?;
+    let pdf_page_index: Option<i64> = row.get(1).map_err(|error| error.to_string())?;
+    let output_markdown_path: String = row.get(2).map_err(|error| error.to_string())?;
+    pages.push(ProvenancePageEntry {
+      source_relative_path: to_relative_key(
+        job_root_directory_path,
+        &map_container_data_path_to_host(job_root_directory_path, &source_path),
+      ),
+      pdf_page_index,
+      output_relative_path: to_relative_key(
+        job_root_directory_path,
+        &map_container_data_path_to_host(job_root_directory_path, &output_markdown_path),
+      ),
+    });
+  }
+  Ok(pages)
+}
+
+pub fn write_provenance_manifest(output_directory_path: &Path, manifest: &ProvenanceManifest) -> Result<PathBuf, String> {
+  let provenance_directory_path = output_directory_path.join(PROVENANCE_DIRECTORY_NAME);
+  fs::create_dir_all(&provenance_directory_path).map_err(|error| error.to_string())?;
+  let run_key = manifest
+    .run_started_unix_timestamp_millis
+    .unwrap_or(manifest.generated_unix_timestamp_millis);
+  let manifest_path = provenance_directory_path.join(format!("run_{run_key}.json"));
+  let serialized = serde_json::to_string_pretty(manifest).map_err(|error| error.to_string())?;
+  fs::write(&manifest_path, serialized).map_err(|error| error.to_string())?;
+  Ok(manifest_path)
+}
+
+// NOTE: For an output renamed after the run (content unchanged): the hash stays, only the recorded path moves.
+// Returns whether the manifest listed the output and was rewritten.
+pub fn rename_provenance_output(
+  job_root_directory_path: &Path,
+  manifest_path: &Path,
+  previous_output_path: &Path,
+  output_path: &Path,
+) -> Result<bool, String> {
+  let mut manifest = read_provenance_manifest(manifest_path)?;
+  let previous_relative_path = to_relative_key(job_root_directory_path, previous_output_path);
+  let Some(entry) = manifest
+    .outputs
+    .iter_mut()
+    .find(|entry| entry.relative_path == previous_relative_path)
+  else {
+    return Ok(false);
+  };
+  entry.relative_path = to_relative_key(job_root_directory_path, output_path);
+  let serialized = serde_json::to_string_pretty(&manifest).map_err(|error| error.to_string())?;
+  fs::write(manifest_path, serialized).map_err(|error| error.to_string())?;
+  Ok(true)
+}
+
+pub fn read_provenance_manifest(manifest_path: &Path) -> Result<ProvenanceManifest, String> {
+  let raw = fs::read_to_string(manifest_path).map_err(|error| format!("Failed to read {}: {error}", manifest_path.display()))?;
+  serde_json::from_str::<ProvenanceManifest>(&raw)
+    .map_err(|error| format!("Invalid provenance manifest {}: {error}", manifest_path.display()))
+}
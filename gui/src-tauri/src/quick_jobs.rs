@@ -0,0 +1,150 @@
+/*!
+Responsibility:
+- Hold "quick job" definitions (`quick_jobs.json`): a preset plus a destination root, optionally bound to a global hotkey.
+- Turn tray/hotkey clipboard content (an image or a list of file paths) into job inputs.
+*/
+
+use std::{
+  collections::HashMap,
+  io::BufWriter,
+  path::PathBuf,
+  sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize};
+
+pub const QUICK_JOB_SETTINGS_FILENAME: &str = "quick_jobs.json";
+pub const QUICK_JOB_TRAY_ICON_ID: &str = "quick-jobs";
+const QUICK_JOB_MENU_ITEM_ID_PREFIX: &str = "quick-job:";
+pub const CLIPBOARD_IMAGE_FILENAME: &str = "clipboard.png";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuickJobDefinition {
+  pub name: String,
+  pub preset_name: String,
+  // NOTE: Each run creates `<destination root>/<timestamp>_quick_<name>/` as its job root.
+  pub destination_root_directory_path: String,
+  // NOTE: Global shortcut such as "CmdOrCtrl+Shift+O"; pressing it runs the job on the clipboard content.
+  pub hotkey: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct QuickJobStatus {
+  pub last_job_root_directory_path: Option<String>,
+  pub last_error_message: Option<String>,
+}
+
+#[derive(Default)]
+pub(crate) struct QuickJobRuntimeState {
+  quick_job_name_by_hotkey_id: HashMap<u32, String>,
+  status: QuickJobStatus,
+}
+
+pub type SharedQuickJobRuntimeState = Arc<Mutex<QuickJobRuntimeState>>;
+
+pub enum QuickJobContent {
+  Files(Vec<String>),
+  Image { width: u32, height: u32, rgba: Vec<u8> },
+}
+
+pub fn new_shared_quick_job_state() -> SharedQuickJobRuntimeState {
+  Arc::new(Mutex::new(QuickJobRuntimeState::default()))
+}
+
+pub fn validate_quick_job_definitions(definitions: &[QuickJobDefinition]) -> Result<(), String> {
+  let mut seen_names: Vec<&str> = vec![];
+  for definition in definitions {
+    let name = definition.name.trim();
+    if name.is_empty() {
+      return Err("Quick job name is empty.".to_string());
+    }
+    if seen_names.contains(&name) {
+      // Guard: tray items and hotkeys are looked up by name.
+      return Err(format!("Duplicate quick job name: {name}"));
+    }
+    seen_names.push(name);
+    if definition.preset_name.trim().is_empty() {
+      return Err(format!("Quick job {name} has no preset_name."));
+    }
+    if definition.destination_root_directory_path.trim().is_empty() {
+      return Err(format!("Quick job {name} has no destination_root_directory_path."));
+    }
+  }
+  Ok(())
+}
+
+pub fn find_quick_job_definition<'a>(definitions: &'a [QuickJobDefinition], name: &str) -> Result<&'a QuickJobDefinition, String> {
+  definitions
+    .iter()
+    .find(|definition| definition.name.trim() == name.trim())
+    .ok_or_else(|| format!("Quick job not found: {name}"))
+}
+
+pub fn quick_job_menu_item_id(name: &str) -> String {
+  format!("{QUICK_JOB_MENU_ITEM_ID_PREFIX}{}", name.trim())
+}
+
+pub fn parse_quick_job_menu_item_id(menu_item_id: &str) -> Option<&str> {
+  menu_item_id.strip_prefix(QUICK_JOB_MENU_ITEM_ID_PREFIX)
+}
+
+pub fn replace_quick_job_hotkeys(state: &SharedQuickJobRuntimeState, quick_job_name_by_hotkey_id: HashMap<u32, String>) {
+  if let Ok(mut locked) = state.lock() {
+    locked.quick_job_name_by_hotkey_id = quick_job_name_by_hotkey_id;
+  }
+}
+
+pub fn quick_job_name_for_hotkey(state: &SharedQuickJobRuntimeState, hotkey_id: u32) -> Option<String> {
+  state
+    .lock()
+    .ok()
+    .and_then(|locked| locked.quick_job_name_by_hotkey_id.get(&hotkey_id).cloned())
+}
+
+pub fn record_quick_job_result(state: &SharedQuickJobRuntimeState, result: &Result<PathBuf, String>) {
+  if let Ok(mut locked) = state.lock() {
+    match result {
+      Ok(job_root_directory_path) => {
+        locked.status.last_job_root_directory_path = Some(job_root_directory_path.to_string_lossy().to_string());
+        locked.status.last_error_message = None;
+      }
+      Err(error) => locked.status.last_error_message = Some(error.clone()),
+    }
+  }
+}
+
+pub fn get_quick_job_status(state: &SharedQuickJobRuntimeState) -> QuickJobStatus {
+  match state.lock() {
+    Ok(locked) => locked.status.clone(),
+    Err(_) => QuickJobStatus {
+      last_job_root_directory_path: None,
+      last_error_message: Some("Quick job state lock poisoned".to_string()),
+    },
+  }
+}
+
+// NOTE: Copied files in Explorer/Finder usually reach the clipboard as text with one path per line.
+pub fn parse_clipboard_file_paths(clipboard_text: &str) -> Option<Vec<String>> {
+  let file_paths: Vec<String> = clipboard_text
+    .lines()
+    .map(|line| line.trim().trim_matches('"'))
+    .filter(|line| !line.is_empty())
+    .map(|line| line.strip_prefix("file://").unwrap_or(line).to_string())
+    .collect();
+  if file_paths.is_empty() || !file_paths.iter().all(|path| PathBuf::from(path).exists()) {
+    return None;
+  }
+  Some(file_paths)
+}
+
+pub fn encode_clipboard_image_png(width: u32, height: u32, rgba: &[u8]) -> Result<Vec<u8>, String> {
+  let mut png_bytes: Vec<u8> = vec![];
+  {
+    let mut encoder = png::Encoder::new(BufWriter::new(&mut png_bytes), width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(|error| error.to_string())?;
+    writer.write_image_data(rgba).map_err(|error| error.to_string())?;
+  }
+  Ok(png_bytes)
+}
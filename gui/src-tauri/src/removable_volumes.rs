@@ -0,0 +1,216 @@
+/*!
+Responsibility:
+- Tell whether a path lives on removable or network media (USB drive, SD card, SMB/NFS share), which can vanish
+  while the app is running.
+- Describe a vanished volume so the watcher and running jobs can suspend instead of reporting a failure.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  process::{Command, Stdio},
+};
+
+use serde::Serialize;
+
+// NOTE: Linux `fstype` values (from /proc/self/mountinfo) and macOS `mount` types of network filesystems.
+const NETWORK_FILESYSTEM_TYPES: &[&str] = &[
+  "nfs",
+  "nfs4",
+  "cifs",
+  "smb3",
+  "smbfs",
+  "afpfs",
+  "webdav",
+  "9p",
+  "afs",
+  "ceph",
+  "glusterfs",
+  "davfs",
+  "fuse.sshfs",
+  "fuse.rclone",
+  "fuse.davfs2",
+];
+// NOTE: Desktop automounters put USB drives and SD cards here even when the kernel does not flag them removable.
+const REMOVABLE_MOUNT_POINT_PREFIXES: &[&str] = &["/media/", "/run/media/", "/mnt/usb"];
+const MACOS_EXTERNAL_VOLUMES_PREFIX: &str = "/Volumes/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VolumeKind {
+  Local,
+  Removable,
+  Network,
+  Unknown,
+}
+
+impl VolumeKind {
+  pub fn can_disappear(self) -> bool {
+    matches!(self, VolumeKind::Removable | VolumeKind::Network)
+  }
+
+  pub fn label(self) -> &'static str {
+    match self {
+      VolumeKind::Local => "local",
+      VolumeKind::Removable => "removable",
+      VolumeKind::Network => "network",
+      VolumeKind::Unknown => "unknown",
+    }
+  }
+}
+
+struct MountEntry {
+  mount_point: PathBuf,
+  filesystem_type: String,
+  source: String,
+}
+
+// NOTE: Call while the path is reachable; once the volume is gone, the path resolves to the parent filesystem.
+pub fn detect_volume_kind(path: &Path) -> VolumeKind {
+  if cfg!(target_os = "windows") {
+    return detect_windows_volume_kind(path);
+  }
+  let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+  let mount_entries = if cfg!(target_os = "macos") {
+    read_macos_mount_entries()
+  } else {
+    read_linux_mount_entries()
+  };
+  let Some(mount_entry) = mount_entries
+    .into_iter()
+    .filter(|entry| canonical_path.starts_with(&entry.mount_point))
+    .max_by_key(|entry| entry.mount_point.as_os_str().len())
+  else {
+    return VolumeKind::Unknown;
+  };
+  classify_mount_entry(&mount_entry)
+}
+
+// NOTE: None while reachable; otherwise a short reason for the watcher status and the job log.
+pub fn describe_unavailable_volume(path: &Path, volume_kind: VolumeKind) -> Option<String> {
+  if !volume_kind.can_disappear() || path.is_dir() {
+    return None;
+  }
+  Some(format!("{} volume is unavailable: {}", volume_kind.label(), path.display()))
+}
+
+fn classify_mount_entry(mount_entry: &MountEntry) -> VolumeKind {
+  if NETWORK_FILESYSTEM_TYPES.contains(&mount_entry.filesystem_type.as_str()) || mount_entry.source.starts_with("//") {
+    return VolumeKind::Network;
+  }
+  let mount_point = mount_entry.mount_point.to_string_lossy();
+  if cfg!(target_os = "macos") {
+    return if mount_point.starts_with(MACOS_EXTERNAL_VOLUMES_PREFIX) {
+      VolumeKind::Removable
+    } else {
+      VolumeKind::Local
+    };
+  }
+  let is_automounted = REMOVABLE_MOUNT_POINT_PREFIXES
+    .iter()
+    .any(|prefix| mount_point.starts_with(prefix));
+  if is_automounted || is_linux_block_device_removable(&mount_entry.source) {
+    return VolumeKind::Removable;
+  }
+  VolumeKind::Local
+}
+
+// NOTE: Mount points escape spaces and tabs as octal (`\040`).
+fn decode_mountinfo_path(raw: &str) -> String {
+  raw
+    .replace("\\040", " ")
+    .replace("\\011", "\t")
+    .replace("\\012", "\n")
+    .replace("\\134", "\\")
+}
+
+fn read_linux_mount_entries() -> Vec<MountEntry> {
+  let Ok(mountinfo) = fs::read_to_string("/proc/self/mountinfo") else {
+    return vec![];
+  };
+  mountinfo
+    .lines()
+    .filter_map(|line| {
+      // NOTE: `<id> <parent> <major:minor> <root> <mount point> <options> [optional...] - <fstype> <source> <super options>`
+      let (mount_fields, filesystem_fields) = line.split_once(" - ")?;
+      let mount_point = mount_fields.split(' ').nth(4)?;
+      let mut filesystem_fields = filesystem_fields.split(' ');
+      Some(MountEntry {
+        mount_point: PathBuf::from(decode_mountinfo_path(mount_point)),
+        filesystem_type: filesystem_fields.next()?.to_string(),
+        source: filesystem_fields.next().unwrap_or("").to_string(),
+      })
+    })
+    .collect()
+}
+
+fn is_linux_block_device_removable(source: &str) -> bool {
+  let Some(device_name) = source.strip_prefix("/dev/") else {
+    return false;
+  };
+  let block_device_path = Path::new("/sys/class/block").join(device_name);
+  // NOTE: Partitions (e.g. sdb1) carry no `removable` flag; it lives on the parent disk.
+  let candidate_flag_paths = [
+    Some(block_device_path.join("removable")),
+    block_device_path
+      .canonicalize()
+      .ok()
+      .and_then(|path| path.parent().map(|parent| parent.join("removable"))),
+  ];
+  candidate_flag_paths
+    .into_iter()
+    .flatten()
+    .find_map(|flag_path| fs::read_to_string(flag_path).ok())
+    .map(|flag| flag.trim() == "1")
+    .unwrap_or(false)
+}
+
+fn read_macos_mount_entries() -> Vec<MountEntry> {
+  let Ok(output) = Command::new("mount").stdin(Stdio::null()).output() else {
+    return vec![];
+  };
+  String::from_utf8_lossy(&output.stdout)
+    .lines()
+    .filter_map(|line| {
+      // NOTE: `<source> on <mount point> (<fstype>, <options...>)`
+      let (source, rest) = line.split_once(" on ")?;
+      let (mount_point, options) = rest.rsplit_once(" (")?;
+      let filesystem_type = options.split([',', ')']).next()?.trim();
+      Some(MountEntry {
+        mount_point: PathBuf::from(mount_point),
+        filesystem_type: filesystem_type.to_string(),
+        source: source.to_string(),
+      })
+    })
+    .collect()
+}
+
+fn detect_windows_volume_kind(path: &Path) -> VolumeKind {
+  let raw_path = path.to_string_lossy().to_string();
+  let raw_path = raw_path.strip_prefix(r"\\?\").unwrap_or(&raw_path);
+  if raw_path.starts_with(r"\\") || raw_path.starts_with(r"UNC\") {
+    return VolumeKind::Network;
+  }
+  let Some(drive_letter) = raw_path
+    .chars()
+    .next()
+    .filter(|character| character.is_ascii_alphabetic() && raw_path[1..].starts_with(':'))
+  else {
+    return VolumeKind::Unknown;
+  };
+  let query = format!("(Get-CimInstance Win32_LogicalDisk -Filter \"DeviceID='{drive_letter}:'\").DriveType");
+  let Ok(output) = Command::new("powershell")
+    .args(["-NoProfile", "-NonInteractive", "-Command", &query])
+    .stdin(Stdio::null())
+    .output()
+  else {
+    return VolumeKind::Unknown;
+  };
+  // NOTE: Win32_LogicalDisk.DriveType: 2 removable, 3 local disk, 4 network drive, 5 optical disc.
+  match String::from_utf8_lossy(&output.stdout).trim() {
+    "2" | "5" => VolumeKind::Removable,
+    "3" => VolumeKind::Local,
+    "4" => VolumeKind::Network,
+    _ => VolumeKind::Unknown,
+  }
+}
@@ -0,0 +1,273 @@
+/*!
+Responsibility:
+- Compare the Markdown outputs of two runs of one job: a line diff plus per-run metrics (length, tables, and the
+  character error rate against `ground_truth.md` when the job has one), so a model revision upgrade can be judged
+  on numbers rather than by eye.
+*/
+
+use std::{fs, path::Path};
+
+use serde::Serialize;
+
+pub const GROUND_TRUTH_FILENAME: &str = "ground_truth.md";
+
+const DIFF_CONTEXT_LINES: usize = 3;
+const MAX_DIFF_OUTPUT_LINES: usize = 2000;
+// NOTE: Caps the line LCS table (u32 cells) at ~16MB; larger changed regions are reported as one replaced block.
+const MAX_LINE_DIFF_CELLS: usize = 4_000_000;
+// NOTE: Character edit distance is O(n*m) time; beyond this a CER would take minutes, so it is skipped.
+const MAX_CHARACTER_ERROR_RATE_CELLS: usize = 400_000_000;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunOutputMetrics {
+  pub run_number: u32,
+  pub output_markdown_path: String,
+  pub model_revision: Option<String>,
+  pub character_count: usize,
+  pub line_count: usize,
+  pub table_count: usize,
+  // NOTE: Levenshtein distance over whitespace-collapsed text divided by the ground truth length; lower is better.
+  pub character_error_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunComparison {
+  pub run_a: RunOutputMetrics,
+  pub run_b: RunOutputMetrics,
+  pub ground_truth_path: Option<String>,
+  // NOTE: Set when a ground truth exists but the CER was not computed (e.g. the documents are too large).
+  pub character_error_rate_skipped_reason: Option<String>,
+  pub added_line_count: usize,
+  pub removed_line_count: usize,
+  // NOTE: Unified-diff style (`@@`, `-`, `+`, ` `) from run A to run B, cut off after MAX_DIFF_OUTPUT_LINES.
+  pub diff_lines: Vec<String>,
+  pub is_diff_truncated: bool,
+}
+
+pub struct RunOutput<'a> {
+  pub run_number: u32,
+  pub output_markdown_path: &'a Path,
+  pub model_revision: Option<String>,
+}
+
+pub fn compare_run_outputs(
+  job_root_directory_path: &Path,
+  run_a: RunOutput<'_>,
+  run_b: RunOutput<'_>,
+) -> Result<RunComparison, String> {
+  let text_a = read_run_output(&run_a)?;
+  let text_b = read_run_output(&run_b)?;
+
+  let ground_truth_path = job_root_directory_path.join(GROUND_TRUTH_FILENAME);
+  let ground_truth = if ground_truth_path.is_file() {
+    Some(fs::read_to_string(&ground_truth_path).map_err(|error| format!("Failed to read {}: {error}", ground_truth_path.display()))?)
+  } else {
+    None
+  };
+  let ground_truth_characters = ground_truth.as_deref().map(normalized_characters);
+  let cer_a = ground_truth_characters
+    .as_ref()
+    .and_then(|expected| compute_character_error_rate(&normalized_characters(&text_a), expected));
+  let cer_b = ground_truth_characters
+    .as_ref()
+    .and_then(|expected| compute_character_error_rate(&normalized_characters(&text_b), expected));
+  let character_error_rate_skipped_reason = match &ground_truth_characters {
+    None => None,
+    Some(expected) if expected.is_empty() => Some(format!("{GROUND_TRUTH_FILENAME} is empty")),
+    Some(_) if cer_a.is_none() || cer_b.is_none() => Some("Documents are too large for a character error rate".to_string()),
+    Some(_) => None,
+  };
+
+  let lines_a: Vec<&str> = text_a.lines().collect();
+  let lines_b: Vec<&str> = text_b.lines().collect();
+  let edits = diff_lines(&lines_a, &lines_b);
+  let added_line_count = edits.iter().filter(|edit| matches!(edit, LineEdit::Added(_))).count();
+  let removed_line_count = edits.iter().filter(|edit| matches!(edit, LineEdit::Removed(_))).count();
+  let (diff_lines, is_diff_truncated) = format_unified_diff(&edits);
+
+  Ok(RunComparison {
+    run_a: build_metrics(&run_a, &text_a, cer_a),
+    run_b: build_metrics(&run_b, &text_b, cer_b),
+    ground_truth_path: ground_truth.map(|_| ground_truth_path.to_string_lossy().to_string()),
+    character_error_rate_skipped_reason,
+    added_line_count,
+    removed_line_count,
+    diff_lines,
+    is_diff_truncated,
+  })
+}
+
+fn read_run_output(run: &RunOutput<'_>) -> Result<String, String> {
+  fs::read_to_string(run.output_markdown_path).map_err(|error| {
+    format!(
+      "Run {} output is unavailable ({}): {error}",
+      run.run_number,
+      run.output_markdown_path.display()
+    )
+  })
+}
+
+fn build_metrics(run: &RunOutput<'_>, text: &str, character_error_rate: Option<f64>) -> RunOutputMetrics {
+  RunOutputMetrics {
+    run_number: run.run_number,
+    output_markdown_path: run.output_markdown_path.to_string_lossy().to_string(),
+    model_revision: run.model_revision.clone(),
+    character_count: text.chars().filter(|character| !character.is_whitespace()).count(),
+    line_count: text.lines().count(),
+    table_count: count_tables(text),
+    character_error_rate,
+  }
+}
+
+// NOTE: DeepSeek-OCR emits tables as HTML; hand-edited or converted outputs use Markdown pipe tables.
+fn count_tables(text: &str) -> usize {
+  let html_table_count = text.to_ascii_lowercase().matches("<table").count();
+  let markdown_table_count = text.lines().filter(|line| is_markdown_table_delimiter_row(line)).count();
+  html_table_count + markdown_table_count
+}
+
+fn is_markdown_table_delimiter_row(line: &str) -> bool {
+  let trimmed = line.trim();
+  if !trimmed.contains('|') {
+    return false;
+  }
+  let cells: Vec<&str> = trimmed.trim_matches('|').split('|').map(str::trim).collect();
+  !cells.is_empty()
+    && cells.iter().all(|cell| {
+      let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+      !dashes.is_empty() && dashes.chars().all(|character| character == '-')
+    })
+}
+
+// NOTE: Whitespace differences (line wrapping, indentation) are layout, not recognition errors.
+fn normalized_characters(text: &str) -> Vec<char> {
+  text.split_whitespace().collect::<Vec<&str>>().join(" ").chars().collect()
+}
+
+fn compute_character_error_rate(actual: &[char], expected: &[char]) -> Option<f64> {
+  if expected.is_empty() || actual.len().saturating_mul(expected.len()) > MAX_CHARACTER_ERROR_RATE_CELLS {
+    return None;
+  }
+  let mut previous_row: Vec<usize> = (0..=expected.len()).collect();
+  let mut current_row: Vec<usize> = vec![0; expected.len() + 1];
+  for (actual_index, actual_character) in actual.iter().enumerate() {
+    current_row[0] = actual_index + 1;
+    for (expected_index, expected_character) in expected.iter().enumerate() {
+      let substitution_cost = usize::from(actual_character != expected_character);
+      current_row[expected_index + 1] = (previous_row[expected_index] + substitution_cost)
+        .min(previous_row[expected_index + 1] + 1)
+        .min(current_row[expected_index] + 1);
+    }
+    std::mem::swap(&mut previous_row, &mut current_row);
+  }
+  Some(previous_row[expected.len()] as f64 / expected.len() as f64)
+}
+
+enum LineEdit<'a> {
+  Equal(&'a str),
+  Removed(&'a str),
+  Added(&'a str),
+}
+
+fn diff_lines<'a>(lines_a: &[&'a str], lines_b: &[&'a str]) -> Vec<LineEdit<'a>> {
+  let common_prefix_length = lines_a
+    .iter()
+    .zip(lines_b.iter())
+    .take_while(|(line_a, line_b)| line_a == line_b)
+    .count();
+  let common_suffix_length = lines_a[common_prefix_length..]
+    .iter()
+    .rev()
+    .zip(lines_b[common_prefix_length..].iter().rev())
+    .take_while(|(line_a, line_b)| line_a == line_b)
+    .count();
+  let middle_a = &lines_a[common_prefix_length..lines_a.len() - common_suffix_length];
+  let middle_b = &lines_b[common_prefix_length..lines_b.len() - common_suffix_length];
+
+  let mut edits: Vec<LineEdit<'a>> = lines_a[..common_prefix_length].iter().map(|line| LineEdit::Equal(line)).collect();
+  if (middle_a.len() + 1).saturating_mul(middle_b.len() + 1) > MAX_LINE_DIFF_CELLS {
+    // Guard: too many changed lines for an LCS table; show the changed region as removed then added.
+    edits.extend(middle_a.iter().map(|line| LineEdit::Removed(line)));
+    edits.extend(middle_b.iter().map(|line| LineEdit::Added(line)));
+  } else {
+    edits.extend(diff_lines_by_longest_common_subsequence(middle_a, middle_b));
+  }
+  edits.extend(lines_a[lines_a.len() - common_suffix_length..].iter().map(|line| LineEdit::Equal(line)));
+  edits
+}
+
+fn diff_lines_by_longest_common_subsequence<'a>(lines_a: &[&'a str], lines_b: &[&'a str]) -> Vec<LineEdit<'a>> {
+  let width = lines_b.len() + 1;
+  // NOTE: lcs_lengths[i * width + j] is the LCS length of lines_a[i..] and lines_b[j..].
+  let mut lcs_lengths: Vec<u32> = vec![0; (lines_a.len() + 1) * width];
+  for index_a in (0..lines_a.len()).rev() {
+    for index_b in (0..lines_b.len()).rev() {
+      lcs_lengths[index_a * width + index_b] = if lines_a[index_a] == lines_b[index_b] {
+        lcs_lengths[(index_a + 1) * width + index_b + 1] + 1
+      } else {
+        lcs_lengths[(index_a + 1) * width + index_b].max(lcs_lengths[index_a * width + index_b + 1])
+      };
+    }
+  }
+
+  let mut edits: Vec<LineEdit<'a>> = vec![];
+  let (mut index_a, mut index_b) = (0, 0);
+  while index_a < lines_a.len() && index_b < lines_b.len() {
+    if lines_a[index_a] == lines_b[index_b] {
+      edits.push(LineEdit::Equal(lines_a[index_a]));
+      index_a += 1;
+      index_b += 1;
+    } else if lcs_lengths[(index_a + 1) * width + index_b] >= lcs_lengths[index_a * width + index_b + 1] {
+      edits.push(LineEdit::Removed(lines_a[index_a]));
+      index_a += 1;
+    } else {
+      edits.push(LineEdit::Added(lines_b[index_b]));
+      index_b += 1;
+    }
+  }
+  edits.extend(lines_a[index_a..].iter().map(|line| LineEdit::Removed(line)));
+  edits.extend(lines_b[index_b..].iter().map(|line| LineEdit::Added(line)));
+  edits
+}
+
+fn format_unified_diff(edits: &[LineEdit<'_>]) -> (Vec<String>, bool) {
+  let changed_indices: Vec<usize> = edits
+    .iter()
+    .enumerate()
+    .filter(|(_, edit)| !matches!(edit, LineEdit::Equal(_)))
+    .map(|(index, _)| index)
+    .collect();
+
+  // NOTE: Group changes whose context windows touch into one hunk, as `diff -u` does.
+  let mut hunk_ranges: Vec<(usize, usize)> = vec![];
+  for changed_index in changed_indices {
+    let start = changed_index.saturating_sub(DIFF_CONTEXT_LINES);
+    let end = (changed_index + DIFF_CONTEXT_LINES + 1).min(edits.len());
+    match hunk_ranges.last_mut() {
+      Some((_, last_end)) if start <= *last_end => *last_end = end,
+      _ => hunk_ranges.push((start, end)),
+    }
+  }
+
+  let mut output: Vec<String> = vec![];
+  for (start, end) in hunk_ranges {
+    let line_number_a = 1 + edits[..start].iter().filter(|edit| !matches!(edit, LineEdit::Added(_))).count();
+    let line_number_b = 1 + edits[..start].iter().filter(|edit| !matches!(edit, LineEdit::Removed(_))).count();
+    let hunk = &edits[start..end];
+    let length_a = hunk.iter().filter(|edit| !matches!(edit, LineEdit::Added(_))).count();
+    let length_b = hunk.iter().filter(|edit| !matches!(edit, LineEdit::Removed(_))).count();
+    output.push(format!("@@ -{line_number_a},{length_a} +{line_number_b},{length_b} @@"));
+    for edit in hunk {
+      output.push(match edit {
+        LineEdit::Equal(line) => format!(" {line}"),
+        LineEdit::Removed(line) => format!("-{line}"),
+        LineEdit::Added(line) => format!("+{line}"),
+      });
+    }
+    if output.len() >= MAX_DIFF_OUTPUT_LINES {
+      output.truncate(MAX_DIFF_OUTPUT_LINES);
+      return (output, true);
+    }
+  }
+  (output, false)
+}
@@ -0,0 +1,161 @@
+/*!
+Responsibility:
+- Hold the GUI's size/count limits (log buffer, preview/clipboard sizes, repo root search, copy collisions, parallel
+  jobs, HTTP API upload size and request rates, inbox zip extraction).
+- Validate user overrides from `runtime_limits.json` and apply them to the running app without a restart.
+*/
+
+use std::sync::RwLock;
+
+use serde::{Deserialize, Serialize};
+
+pub const RUNTIME_LIMITS_SETTINGS_FILENAME: &str = "runtime_limits.json";
+
+const MIN_LOG_LINES: usize = 100;
+const MAX_LOG_LINES_UPPER_BOUND: usize = 200_000;
+const MIN_PREVIEW_IMAGE_BYTES: u64 = 1_000_000;
+const MAX_PREVIEW_IMAGE_BYTES_UPPER_BOUND: u64 = 512_000_000;
+const MIN_CLIPBOARD_MARKDOWN_BYTES: u64 = 64_000;
+const MAX_CLIPBOARD_MARKDOWN_BYTES_UPPER_BOUND: u64 = 256_000_000;
+const MAX_REPO_ROOT_SEARCH_DEPTH_UPPER_BOUND: usize = 64;
+const MIN_COPY_COLLISION_ATTEMPTS: u32 = 2;
+const MAX_COPY_COLLISION_ATTEMPTS_UPPER_BOUND: u32 = 1_000_000;
+const MAX_CONCURRENT_JOBS_UPPER_BOUND: usize = 16;
+const MIN_HTTP_API_UPLOAD_BYTES: u64 = 1_000_000;
+const MAX_HTTP_API_UPLOAD_BYTES_UPPER_BOUND: u64 = 4_000_000_000;
+const MAX_HTTP_API_REQUESTS_PER_MINUTE_UPPER_BOUND: u32 = 100_000;
+const MIN_WATCH_ZIP_EXTRACTED_BYTES: u64 = 1_000_000;
+const MAX_WATCH_ZIP_EXTRACTED_BYTES_UPPER_BOUND: u64 = 1_000_000_000_000;
+const MAX_WATCH_ZIP_ENTRIES_UPPER_BOUND: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RuntimeLimits {
+  // NOTE: Per job root; older lines are dropped from the in-memory buffer (the CLI's own output files are untouched).
+  pub max_log_lines: usize,
+  pub max_preview_image_bytes: u64,
+  pub max_clipboard_markdown_bytes: u64,
+  // NOTE: How many parent directories are searched for `compose.yaml` when the GUI runs outside the repo.
+  pub max_repo_root_search_depth: usize,
+  pub max_copy_collision_attempts: u32,
+  // NOTE: Each job loads its own model; raise this only when the GPU has VRAM for that many at once.
+  pub max_concurrent_jobs: usize,
+  // NOTE: Caps one `POST /api/v1/jobs` (the whole upload, or all downloaded URLs together); larger gets `413`.
+  pub http_api_max_upload_bytes: u64,
+  // NOTE: Per client address and minute; 0 disables the limit. Over the limit gets `429` with `Retry-After`.
+  pub http_api_max_requests_per_minute: u32,
+  // NOTE: Counted separately from (and in addition to) the request limit, since each submission starts OCR work.
+  pub http_api_max_submissions_per_minute: u32,
+  // NOTE: Caps what one `.zip` dropped into a watch inbox may expand to, counting the bytes actually written (the sizes
+  // an archive declares can lie); a larger archive becomes a failed bundle instead of filling the disk.
+  pub watch_zip_max_extracted_bytes: u64,
+  pub watch_zip_max_entries: usize,
+}
+
+const DEFAULT_RUNTIME_LIMITS: RuntimeLimits = RuntimeLimits {
+  max_log_lines: 1500,
+  max_preview_image_bytes: 8_000_000,
+  max_clipboard_markdown_bytes: 2_000_000,
+  max_repo_root_search_depth: 8,
+  max_copy_collision_attempts: 1000,
+  max_concurrent_jobs: 1,
+  http_api_max_upload_bytes: 200_000_000,
+  http_api_max_requests_per_minute: 120,
+  http_api_max_submissions_per_minute: 10,
+  watch_zip_max_extracted_bytes: 2_000_000_000,
+  watch_zip_max_entries: 10_000,
+};
+
+// NOTE: Process-wide because repo root discovery and log reader threads run without access to Tauri state.
+static CURRENT_RUNTIME_LIMITS: RwLock<RuntimeLimits> = RwLock::new(DEFAULT_RUNTIME_LIMITS);
+
+impl Default for RuntimeLimits {
+  fn default() -> Self {
+    DEFAULT_RUNTIME_LIMITS
+  }
+}
+
+fn validate_range<T: PartialOrd + std::fmt::Display>(name: &str, value: T, min: T, max: T) -> Result<(), String> {
+  if value < min || value > max {
+    return Err(format!("{name} must be between {min} and {max} (got {value})."));
+  }
+  Ok(())
+}
+
+impl RuntimeLimits {
+  pub fn validate(&self) -> Result<(), String> {
+    validate_range("max_log_lines", self.max_log_lines, MIN_LOG_LINES, MAX_LOG_LINES_UPPER_BOUND)?;
+    validate_range(
+      "max_preview_image_bytes",
+      self.max_preview_image_bytes,
+      MIN_PREVIEW_IMAGE_BYTES,
+      MAX_PREVIEW_IMAGE_BYTES_UPPER_BOUND,
+    )?;
+    validate_range(
+      "max_clipboard_markdown_bytes",
+      self.max_clipboard_markdown_bytes,
+      MIN_CLIPBOARD_MARKDOWN_BYTES,
+      MAX_CLIPBOARD_MARKDOWN_BYTES_UPPER_BOUND,
+    )?;
+    validate_range(
+      "max_repo_root_search_depth",
+      self.max_repo_root_search_depth,
+      1,
+      MAX_REPO_ROOT_SEARCH_DEPTH_UPPER_BOUND,
+    )?;
+    validate_range(
+      "max_copy_collision_attempts",
+      self.max_copy_collision_attempts,
+      MIN_COPY_COLLISION_ATTEMPTS,
+      MAX_COPY_COLLISION_ATTEMPTS_UPPER_BOUND,
+    )?;
+    validate_range(
+      "max_concurrent_jobs",
+      self.max_concurrent_jobs,
+      1,
+      MAX_CONCURRENT_JOBS_UPPER_BOUND,
+    )?;
+    validate_range(
+      "http_api_max_upload_bytes",
+      self.http_api_max_upload_bytes,
+      MIN_HTTP_API_UPLOAD_BYTES,
+      MAX_HTTP_API_UPLOAD_BYTES_UPPER_BOUND,
+    )?;
+    validate_range(
+      "http_api_max_requests_per_minute",
+      self.http_api_max_requests_per_minute,
+      0,
+      MAX_HTTP_API_REQUESTS_PER_MINUTE_UPPER_BOUND,
+    )?;
+    validate_range(
+      "http_api_max_submissions_per_minute",
+      self.http_api_max_submissions_per_minute,
+      0,
+      MAX_HTTP_API_REQUESTS_PER_MINUTE_UPPER_BOUND,
+    )?;
+    validate_range(
+      "watch_zip_max_extracted_bytes",
+      self.watch_zip_max_extracted_bytes,
+      MIN_WATCH_ZIP_EXTRACTED_BYTES,
+      MAX_WATCH_ZIP_EXTRACTED_BYTES_UPPER_BOUND,
+    )?;
+    validate_range("watch_zip_max_entries", self.watch_zip_max_entries, 1, MAX_WATCH_ZIP_ENTRIES_UPPER_BOUND)
+  }
+}
+
+pub fn current_runtime_limits() -> RuntimeLimits {
+  // Guard: a poisoned lock still holds the last applied (already validated) limits.
+  match CURRENT_RUNTIME_LIMITS.read() {
+    Ok(locked) => *locked,
+    Err(poisoned) => *poisoned.into_inner(),
+  }
+}
+
+pub fn apply_runtime_limits(limits: RuntimeLimits) -> Result<(), String> {
+  limits.validate()?;
+  match CURRENT_RUNTIME_LIMITS.write() {
+    Ok(mut locked) => *locked = limits,
+    Err(poisoned) => *poisoned.into_inner() = limits,
+  }
+  Ok(())
+}
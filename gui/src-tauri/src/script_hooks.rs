@@ -0,0 +1,160 @@
+/*!
+Responsibility:
+- Run user-configured pre-run / post-run commands with the job root and output markdown path as arguments.
+- Enforce a per-hook timeout and stream captured stdout/stderr lines to the job log.
+*/
+
+use std::{
+  io::Read,
+  path::Path,
+  process::{Command, Stdio},
+  sync::Arc,
+  thread,
+  time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::log_capture::for_each_captured_line;
+
+const DEFAULT_SCRIPT_HOOK_TIMEOUT_SECONDS: u64 = 120;
+const SCRIPT_HOOK_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+const OCR_AGENT_HOOK_STAGE_ENVIRONMENT_VARIABLE_NAME: &str = "OCR_AGENT_HOOK_STAGE";
+const OCR_AGENT_JOB_ROOT_ENVIRONMENT_VARIABLE_NAME: &str = "OCR_AGENT_JOB_ROOT";
+const OCR_AGENT_OUTPUT_MARKDOWN_ENVIRONMENT_VARIABLE_NAME: &str = "OCR_AGENT_OUTPUT_MARKDOWN";
+
+pub type ScriptHookLogSink = Arc<dyn Fn(String) + Send + Sync>;
+
+#[derive(Debug, Clone, Copy)]
+pub enum ScriptHookStage {
+  PreRun,
+  PostRun,
+}
+
+impl ScriptHookStage {
+  fn as_str(self) -> &'static str {
+    match self {
+      ScriptHookStage::PreRun => "pre_run",
+      ScriptHookStage::PostRun => "post_run",
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptHookCommand {
+  pub program: String,
+  // NOTE: The job root and output markdown path are appended after these arguments.
+  #[serde(default)]
+  pub args: Vec<String>,
+  pub timeout_seconds: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScriptHookSettings {
+  #[serde(default)]
+  pub pre_run: Vec<ScriptHookCommand>,
+  #[serde(default)]
+  pub post_run: Vec<ScriptHookCommand>,
+}
+
+impl ScriptHookSettings {
+  pub fn commands_for_stage(&self, stage: ScriptHookStage) -> &[ScriptHookCommand] {
+    match stage {
+      ScriptHookStage::PreRun => &self.pre_run,
+      ScriptHookStage::PostRun => &self.post_run,
+    }
+  }
+}
+
+fn spawn_hook_output_reader(
+  stream: impl Read + Send + 'static,
+  stream_name: &'static str,
+  log_sink: ScriptHookLogSink,
+) -> thread::JoinHandle<()> {
+  thread::spawn(move || {
+    for_each_captured_line(stream, |line| log_sink(format!("[hook {stream_name}] {line}")));
+  })
+}
+
+pub fn run_script_hook(
+  hook: &ScriptHookCommand,
+  stage: ScriptHookStage,
+  job_root_directory_path: &Path,
+  output_markdown_path: Option<&Path>,
+  log_sink: ScriptHookLogSink,
+) -> Result<(), String> {
+  let program = hook.program.trim();
+  if program.is_empty() {
+    // Guard: an empty program would fail with a confusing OS error.
+    return Err("Script hook program is empty.".to_string());
+  }
+
+  let output_markdown_argument = output_markdown_path
+    .map(|path| path.to_string_lossy().to_string())
+    .unwrap_or_default();
+  let mut command = Command::new(program);
+  command.args(&hook.args);
+  command.arg(job_root_directory_path);
+  command.arg(&output_markdown_argument);
+  command.env(OCR_AGENT_HOOK_STAGE_ENVIRONMENT_VARIABLE_NAME, stage.as_str());
+  command.env(OCR_AGENT_JOB_ROOT_ENVIRONMENT_VARIABLE_NAME, job_root_directory_path);
+  command.env(OCR_AGENT_OUTPUT_MARKDOWN_ENVIRONMENT_VARIABLE_NAME, &output_markdown_argument);
+  command.current_dir(job_root_directory_path);
+  command.stdin(Stdio::null());
+  command.stdout(Stdio::piped());
+  command.stderr(Stdio::piped());
+
+  let mut child = command
+    .spawn()
+    .map_err(|error| format!("Failed to start {} hook {program}: {error}", stage.as_str()))?;
+  let mut reader_handles: Vec<thread::JoinHandle<()>> = vec![];
+  if let Some(stream) = child.stdout.take() {
+    reader_handles.push(spawn_hook_output_reader(stream, "stdout", log_sink.clone()));
+  }
+  if let Some(stream) = child.stderr.take() {
+    reader_handles.push(spawn_hook_output_reader(stream, "stderr", log_sink.clone()));
+  }
+
+  let timeout = Duration::from_secs(hook.timeout_seconds.unwrap_or(DEFAULT_SCRIPT_HOOK_TIMEOUT_SECONDS));
+  let started_at = Instant::now();
+  let exit_status = loop {
+    if let Some(status) = child.try_wait().map_err(|error| error.to_string())? {
+      break status;
+    }
+    if started_at.elapsed() >= timeout {
+      let _ = child.kill();
+      let _ = child.wait();
+      return Err(format!(
+        "{} hook {program} timed out after {} second(s)",
+        stage.as_str(),
+        timeout.as_secs()
+      ));
+    }
+    thread::sleep(SCRIPT_HOOK_POLL_INTERVAL);
+  };
+  for handle in reader_handles {
+    let _ = handle.join();
+  }
+
+  if !exit_status.success() {
+    return Err(format!("{} hook {program} failed: {exit_status}", stage.as_str()));
+  }
+  Ok(())
+}
+
+pub fn run_script_hooks_for_stage(
+  settings: &ScriptHookSettings,
+  stage: ScriptHookStage,
+  job_root_directory_path: &Path,
+  output_markdown_path: Option<&Path>,
+  log_sink: ScriptHookLogSink,
+) -> Result<usize, String> {
+  let hooks = settings.commands_for_stage(stage);
+  for hook in hooks {
+    log_sink(format!("[backend] running {} hook: {}", stage.as_str(), hook.program));
+    // Guard: stop at the first failing hook so later hooks never see a half-processed job.
+    run_script_hook(hook, stage, job_root_directory_path, output_markdown_path, log_sink.clone())?;
+  }
+  Ok(hooks.len())
+}
@@ -0,0 +1,182 @@
+/*!
+Responsibility:
+- Store embeddings of output markdown chunks in a per-job vector index (`.ocr-agent/embeddings.sqlite3`).
+- Answer semantic queries across one job root or a whole jobs root by cosine similarity.
+*/
+
+use std::path::{Path, PathBuf};
+
+use rusqlite::{params, Connection};
+use serde::Serialize;
+
+use crate::llm_client::{embed_texts, split_markdown_into_chunks, LlmEndpointSettings};
+use crate::output_documents::DocumentMarkdown;
+
+pub const EMBEDDINGS_DATABASE_FILENAME: &str = "embeddings.sqlite3";
+
+const MAX_EMBEDDING_CHUNK_CHARACTERS: usize = 1500;
+const EMBEDDING_REQUEST_BATCH_SIZE: usize = 32;
+const MAX_INDEX_SEARCH_DEPTH: usize = 4;
+const SEARCH_RESULT_SNIPPET_CHARACTERS: usize = 400;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SemanticSearchHit {
+  pub job_root_directory_path: String,
+  pub source_path: String,
+  pub chunk_index: i64,
+  pub score: f32,
+  pub snippet: String,
+}
+
+fn open_embeddings_database(embeddings_database_path: &Path) -> Result<Connection, String> {
+  let connection = Connection::open(embeddings_database_path).map_err(|error| error.to_string())?;
+  connection
+    .execute_batch(
+      "CREATE TABLE IF NOT EXISTS chunks (
+         chunk_id INTEGER PRIMARY KEY AUTOINCREMENT,
+         model TEXT NOT NULL,
+         source_path TEXT NOT NULL,
+         chunk_index INTEGER NOT NULL,
+         text TEXT NOT NULL,
+         vector BLOB NOT NULL
+       )",
+    )
+    .map_err(|error| error.to_string())?;
+  Ok(connection)
+}
+
+fn encode_vector(vector: &[f32]) -> Vec<u8> {
+  vector.iter().flat_map(|value| value.to_le_bytes()).collect()
+}
+
+fn decode_vector(bytes: &[u8]) -> Vec<f32> {
+  bytes
+    .chunks_exact(4)
+    .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+    .collect()
+}
+
+fn cosine_similarity(left: &[f32], right: &[f32]) -> f32 {
+  if left.len() != right.len() || left.is_empty() {
+    // Guard: vectors from different models are not comparable.
+    return 0.0;
+  }
+  let mut dot = 0.0f32;
+  let mut left_norm = 0.0f32;
+  let mut right_norm = 0.0f32;
+  for (left_value, right_value) in left.iter().zip(right.iter()) {
+    dot += left_value * right_value;
+    left_norm += left_value * left_value;
+    right_norm += right_value * right_value;
+  }
+  if left_norm <= 0.0 || right_norm <= 0.0 {
+    return 0.0;
+  }
+  dot / (left_norm.sqrt() * right_norm.sqrt())
+}
+
+pub fn index_document_embeddings(
+  endpoint: &LlmEndpointSettings,
+  documents: &[DocumentMarkdown],
+  embeddings_database_path: &Path,
+) -> Result<usize, String> {
+  let mut pending_chunks: Vec<(String, i64, String)> = vec![];
+  for document in documents {
+    let chunks = split_markdown_into_chunks(&document.markdown, MAX_EMBEDDING_CHUNK_CHARACTERS);
+    for (chunk_index, chunk) in chunks.into_iter().enumerate() {
+      pending_chunks.push((document.source_path.clone(), chunk_index as i64, chunk));
+    }
+  }
+
+  let mut connection = open_embeddings_database(embeddings_database_path)?;
+  let transaction = connection.transaction().map_err(|error| error.to_string())?;
+  // NOTE: Re-indexing replaces the previous run's chunks so results never mix stale outputs.
+  transaction
+    .execute("DELETE FROM chunks", [])
+    .map_err(|error| error.to_string())?;
+  for batch in pending_chunks.chunks(EMBEDDING_REQUEST_BATCH_SIZE) {
+    let texts: Vec<String> = batch.iter().map(|(_, _, text)| text.clone()).collect();
+    let vectors = embed_texts(endpoint, &texts)?;
+    for ((source_path, chunk_index, text), vector) in batch.iter().zip(vectors.iter()) {
+      transaction
+        .execute(
+          "INSERT INTO chunks (model, source_path, chunk_index, text, vector) VALUES (?1, ?2, ?3, ?4, ?5)",
+          params![endpoint.model, source_path, chunk_index, text, encode_vector(vector)],
+        )
+        .map_err(|error| error.to_string())?;
+    }
+  }
+  transaction.commit().map_err(|error| error.to_string())?;
+  Ok(pending_chunks.len())
+}
+
+fn list_embeddings_database_paths(search_root_directory_path: &Path, settings_directory_name: &str) -> Vec<PathBuf> {
+  walkdir::WalkDir::new(search_root_directory_path)
+    .max_depth(MAX_INDEX_SEARCH_DEPTH)
+    .into_iter()
+    .filter_map(|entry| entry.ok())
+    .filter(|entry| entry.file_name() == EMBEDDINGS_DATABASE_FILENAME)
+    .filter(|entry| {
+      entry
+        .path()
+        .parent()
+        .and_then(|parent| parent.file_name())
+        .map(|name| name == settings_directory_name)
+        .unwrap_or(false)
+    })
+    .map(|entry| entry.path().to_path_buf())
+    .collect()
+}
+
+fn truncate_snippet(text: &str) -> String {
+  text.chars().take(SEARCH_RESULT_SNIPPET_CHARACTERS).collect()
+}
+
+pub fn semantic_search(
+  endpoint: &LlmEndpointSettings,
+  query: &str,
+  search_root_directory_path: &Path,
+  settings_directory_name: &str,
+  limit: usize,
+) -> Result<Vec<SemanticSearchHit>, String> {
+  if query.trim().is_empty() {
+    // Guard: an empty query embeds to noise.
+    return Err("Search query is empty".to_string());
+  }
+  let query_vectors = embed_texts(endpoint, &[query.to_string()])?;
+  let Some(query_vector) = query_vectors.first() else {
+    return Err("Embedding endpoint returned no vector for the query".to_string());
+  };
+
+  let mut hits: Vec<SemanticSearchHit> = vec![];
+  for embeddings_database_path in list_embeddings_database_paths(search_root_directory_path, settings_directory_name) {
+    let job_root_directory_path = embeddings_database_path
+      .parent()
+      .and_then(|settings_directory| settings_directory.parent())
+      .map(|path| path.to_string_lossy().to_string())
+      .unwrap_or_default();
+
+    let connection = open_embeddings_database(&embeddings_database_path)?;
+    let mut statement = connection
+      .prepare("SELECT source_path, chunk_index, text, vector FROM chunks WHERE model = ?1")
+      .map_err(|error| error.to_string())?;
+    let mut rows = statement.query(params![endpoint.model]).map_err(|error| error.to_string())?;
+    while let Some(row) = rows.next().map_err(|error| error.to_string())? {
+      let source_path: String = row.get(0).map_err(|error| error.to_string())?;
+      let chunk_index: i64 = row.get(1).map_err(|error| error.to_string())?;
+      let text: String = row.get(2).map_err(|error| error.to_string())?;
+      let vector_bytes: Vec<u8> = row.get(3).map_err(|error| error.to_string())?;
+      hits.push(SemanticSearchHit {
+        job_root_directory_path: job_root_directory_path.clone(),
+        source_path,
+        chunk_index,
+        score: cosine_similarity(query_vector, &decode_vector(&vector_bytes)),
+        snippet: truncate_snippet(&text),
+      });
+    }
+  }
+
+  hits.sort_by(|left, right| right.score.total_cmp(&left.score));
+  hits.truncate(limit);
+  Ok(hits)
+}
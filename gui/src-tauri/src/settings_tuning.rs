@@ -0,0 +1,226 @@
+/*!
+Responsibility:
+- Pick a small random sample of input pages and define the settings grid (inference image size x crop mode) tried on it.
+- Score each trial's output with cheap speed/size/quality proxies and recommend a configuration for the full run.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::Serialize;
+
+pub const SETTINGS_TUNING_DIRECTORY_NAME: &str = "tuning";
+pub const SETTINGS_TUNING_SAMPLE_DIRECTORY_NAME: &str = "sample";
+pub const SETTINGS_TUNING_REPORT_FILENAME: &str = "tuning_report.json";
+// NOTE: Every trial is a separate OCR run that loads the model again; keep the sample small.
+pub const MAX_SETTINGS_TUNING_SAMPLE_PAGE_COUNT: usize = 20;
+
+const TUNING_INFERENCE_IMAGE_SIZES_PIXELS: &[u32] = &[768, 640, 512];
+const TUNING_CROP_MODES: &[bool] = &[true, false];
+// NOTE: Trials within this share of the most text are treated as equally complete, so speed decides between them.
+const MIN_RELATIVE_CHARACTER_COUNT: f64 = 0.95;
+// NOTE: Runaway repetition is the typical failure of a too-small image size; allow a little above the best trial.
+const MAX_REPEATED_LINE_RATIO_MARGIN: f64 = 0.05;
+
+// NOTE: Mirrors the CLI's supported inputs (`input_discovery.py`).
+const TUNING_IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp", "bmp", "tif", "tiff"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SampledPage {
+  pub source_file_path: PathBuf,
+  // NOTE: None for image inputs.
+  pub pdf_page_index: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct SettingsTuningTrial {
+  pub inference_image_size_pixels: u32,
+  pub is_crop_mode_enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SettingsTuningTrialResult {
+  pub trial: SettingsTuningTrial,
+  pub is_succeeded: bool,
+  pub error_message: Option<String>,
+  // NOTE: Wall-clock time including the model load, which is the same for every trial.
+  pub elapsed_millis: u64,
+  pub seconds_per_page: f64,
+  pub failed_page_count: i64,
+  pub output_character_count: usize,
+  // NOTE: Share of non-empty lines that repeat an earlier line; high values indicate a generation loop.
+  pub repeated_line_ratio: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SettingsTuningReport {
+  pub sampled_pages: Vec<String>,
+  pub trial_results: Vec<SettingsTuningTrialResult>,
+  pub recommended_trial: Option<SettingsTuningTrial>,
+  pub recommendation_reason: String,
+}
+
+pub fn settings_tuning_trials() -> Vec<SettingsTuningTrial> {
+  TUNING_INFERENCE_IMAGE_SIZES_PIXELS
+    .iter()
+    .flat_map(|size| {
+      TUNING_CROP_MODES.iter().map(|crop| SettingsTuningTrial {
+        inference_image_size_pixels: *size,
+        is_crop_mode_enabled: *crop,
+      })
+    })
+    .collect()
+}
+
+impl SettingsTuningTrial {
+  pub fn directory_name(&self) -> String {
+    let crop_label = if self.is_crop_mode_enabled { "crop" } else { "nocrop" };
+    format!("trial_{}_{crop_label}", self.inference_image_size_pixels)
+  }
+}
+
+// NOTE: A seeded xorshift is enough to spread the sample; it does not need to be reproducible across runs.
+struct SampleRandom(u64);
+
+impl SampleRandom {
+  fn from_clock() -> Self {
+    let nanos = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map(|duration| duration.as_nanos() as u64)
+      .unwrap_or(0);
+    Self(nanos | 1)
+  }
+
+  fn next_below(&mut self, bound: usize) -> usize {
+    self.0 ^= self.0 << 13;
+    self.0 ^= self.0 >> 7;
+    self.0 ^= self.0 << 17;
+    (self.0 % bound as u64) as usize
+  }
+}
+
+// NOTE: `count_pdf_pages` is injected so this module does not depend on the pdfium binding.
+pub fn sample_input_pages(
+  input_directory_path: &Path,
+  sample_page_count: usize,
+  count_pdf_pages: &dyn Fn(&Path) -> Result<i64, String>,
+) -> Result<Vec<SampledPage>, String> {
+  if sample_page_count == 0 || sample_page_count > MAX_SETTINGS_TUNING_SAMPLE_PAGE_COUNT {
+    return Err(format!(
+      "sample_page_count must be between 1 and {MAX_SETTINGS_TUNING_SAMPLE_PAGE_COUNT} (got {sample_page_count})."
+    ));
+  }
+  let mut source_file_paths: Vec<PathBuf> = walkdir::WalkDir::new(input_directory_path)
+    .into_iter()
+    .filter_map(|entry| entry.ok())
+    .filter(|entry| entry.file_type().is_file())
+    .map(|entry| entry.path().to_path_buf())
+    .collect();
+  source_file_paths.sort();
+
+  let mut all_pages: Vec<SampledPage> = vec![];
+  for source_file_path in source_file_paths {
+    let extension = source_file_path
+      .extension()
+      .and_then(|extension| extension.to_str())
+      .map(|extension| extension.to_ascii_lowercase())
+      .unwrap_or_default();
+    if extension == "pdf" {
+      let page_count = count_pdf_pages(&source_file_path)?;
+      all_pages.extend((0..page_count).map(|pdf_page_index| SampledPage {
+        source_file_path: source_file_path.clone(),
+        pdf_page_index: Some(pdf_page_index),
+      }));
+    } else if TUNING_IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+      all_pages.push(SampledPage {
+        source_file_path,
+        pdf_page_index: None,
+      });
+    }
+  }
+  if all_pages.is_empty() {
+    return Err(format!("No supported inputs found in {}", input_directory_path.display()));
+  }
+
+  let mut random = SampleRandom::from_clock();
+  let mut sampled_pages: Vec<SampledPage> = vec![];
+  while sampled_pages.len() < sample_page_count && !all_pages.is_empty() {
+    let index = random.next_below(all_pages.len());
+    sampled_pages.push(all_pages.swap_remove(index));
+  }
+  Ok(sampled_pages)
+}
+
+pub fn describe_sampled_page(input_directory_path: &Path, page: &SampledPage) -> String {
+  let relative_path = page
+    .source_file_path
+    .strip_prefix(input_directory_path)
+    .unwrap_or(&page.source_file_path)
+    .to_string_lossy()
+    .replace('\\', "/");
+  match page.pdf_page_index {
+    Some(pdf_page_index) => format!("{relative_path} (page {})", pdf_page_index + 1),
+    None => relative_path,
+  }
+}
+
+pub fn measure_output_markdown(markdown_file_path: &Path) -> (usize, f64) {
+  let Ok(markdown) = fs::read_to_string(markdown_file_path) else {
+    return (0, 0.0);
+  };
+  let content_lines: Vec<&str> = markdown
+    .lines()
+    .map(str::trim)
+    .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with("<!--"))
+    .collect();
+  let mut seen_lines: Vec<&str> = vec![];
+  let mut repeated_line_count = 0usize;
+  for line in &content_lines {
+    if seen_lines.contains(line) {
+      repeated_line_count += 1;
+    } else {
+      seen_lines.push(line);
+    }
+  }
+  let character_count = content_lines.iter().map(|line| line.chars().count()).sum();
+  let repeated_line_ratio = if content_lines.is_empty() {
+    0.0
+  } else {
+    repeated_line_count as f64 / content_lines.len() as f64
+  };
+  (character_count, repeated_line_ratio)
+}
+
+// NOTE: Among trials without failures, keep those close to the most text and least repetition, then take the fastest.
+pub fn recommend_trial(trial_results: &[SettingsTuningTrialResult]) -> (Option<SettingsTuningTrial>, String) {
+  let clean_results: Vec<&SettingsTuningTrialResult> = trial_results
+    .iter()
+    .filter(|result| result.is_succeeded && result.failed_page_count == 0)
+    .collect();
+  let Some(max_character_count) = clean_results.iter().map(|result| result.output_character_count).max() else {
+    return (None, "No trial finished without failed pages.".to_string());
+  };
+  let min_repeated_line_ratio = clean_results
+    .iter()
+    .map(|result| result.repeated_line_ratio)
+    .fold(f64::INFINITY, f64::min);
+  let recommended = clean_results
+    .iter()
+    .filter(|result| result.output_character_count as f64 >= max_character_count as f64 * MIN_RELATIVE_CHARACTER_COUNT)
+    .filter(|result| result.repeated_line_ratio <= min_repeated_line_ratio + MAX_REPEATED_LINE_RATIO_MARGIN)
+    .min_by(|left, right| left.seconds_per_page.total_cmp(&right.seconds_per_page));
+  match recommended {
+    Some(result) => (
+      Some(result.trial),
+      format!(
+        "Fastest trial ({:.1}s/page) within {:.0}% of the most extracted text without extra repetition.",
+        result.seconds_per_page,
+        MIN_RELATIVE_CHARACTER_COUNT * 100.0
+      ),
+    ),
+    None => (None, "No trial matched both the text and repetition thresholds.".to_string()),
+  }
+}
@@ -0,0 +1,65 @@
+/*!
+Responsibility:
+- Ship a tiny built-in test page and lay it out as a throwaway job root for an end-to-end OCR check.
+- Judge the result (non-empty markdown, expected words) and report timing for new machines and engine updates.
+*/
+
+use std::{fs, path::Path};
+
+use serde::Serialize;
+
+pub const SMOKE_TEST_DIRECTORY_NAME: &str = "smoke_test";
+pub const SMOKE_TEST_OUTPUT_MARKDOWN_FILENAME: &str = "result.md";
+
+const SMOKE_TEST_IMAGE_FILENAME: &str = "smoke_test.png";
+const SMOKE_TEST_IMAGE_BYTES: &[u8] = include_bytes!("../assets/smoke_test.png");
+// NOTE: The page reads "OCR SMOKE TEST" in a blocky bitmap font; models may vary in spacing or case.
+const SMOKE_TEST_EXPECTED_WORDS: &[&str] = &["smoke", "test"];
+const SMOKE_TEST_OUTPUT_EXCERPT_CHARACTERS: usize = 200;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SmokeTestReport {
+  pub is_succeeded: bool,
+  pub error_message: Option<String>,
+  pub elapsed_millis: u64,
+  pub output_character_count: usize,
+  // NOTE: Informational; a run that produced text but misread the page still proves the pipeline works.
+  pub is_expected_text_found: bool,
+  pub output_excerpt: String,
+  pub smoke_test_root_directory_path: String,
+}
+
+pub fn prepare_smoke_test_root(smoke_test_root_directory_path: &Path, input_directory_name: &str) -> Result<(), String> {
+  // Guard: a previous run's queue would make `enqueue` skip the page and `run` finish without OCR.
+  if smoke_test_root_directory_path.exists() {
+    fs::remove_dir_all(smoke_test_root_directory_path).map_err(|error| error.to_string())?;
+  }
+  let input_directory_path = smoke_test_root_directory_path.join(input_directory_name);
+  fs::create_dir_all(&input_directory_path).map_err(|error| error.to_string())?;
+  fs::write(input_directory_path.join(SMOKE_TEST_IMAGE_FILENAME), SMOKE_TEST_IMAGE_BYTES).map_err(|error| error.to_string())
+}
+
+pub fn evaluate_smoke_test_output(
+  smoke_test_root_directory_path: &Path,
+  elapsed_millis: u64,
+  process_error_message: Option<String>,
+) -> SmokeTestReport {
+  let markdown = fs::read_to_string(smoke_test_root_directory_path.join(SMOKE_TEST_OUTPUT_MARKDOWN_FILENAME)).unwrap_or_default();
+  let output_character_count = markdown.chars().filter(|character| !character.is_whitespace()).count();
+  let normalized_markdown = markdown.to_lowercase();
+  let is_expected_text_found = SMOKE_TEST_EXPECTED_WORDS
+    .iter()
+    .all(|word| normalized_markdown.contains(word));
+  let error_message = process_error_message.or_else(|| {
+    (output_character_count == 0).then(|| "The OCR run finished but produced no text.".to_string())
+  });
+  SmokeTestReport {
+    is_succeeded: error_message.is_none(),
+    error_message,
+    elapsed_millis,
+    output_character_count,
+    is_expected_text_found,
+    output_excerpt: markdown.trim().chars().take(SMOKE_TEST_OUTPUT_EXCERPT_CHARACTERS).collect(),
+    smoke_test_root_directory_path: smoke_test_root_directory_path.to_string_lossy().to_string(),
+  }
+}
@@ -0,0 +1,277 @@
+/*!
+Responsibility:
+- Flag or auto-correct common OCR misrecognitions in output markdown using hunspell-style dictionaries.
+- Write a changes report so reviewers can audit every correction.
+*/
+
+use std::{
+  collections::HashSet,
+  fs,
+  path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+pub const SPELLCHECK_REPORT_FILENAME: &str = "spellcheck_report.json";
+
+const DEFAULT_HUNSPELL_DICTIONARY_DIRECTORY: &str = "/usr/share/hunspell";
+const OCR_AGENT_DICTIONARY_DIRECTORY_ENVIRONMENT_VARIABLE_NAME: &str = "OCR_AGENT_DICTIONARY_DIR";
+const MIN_CHECKED_WORD_CHARACTERS: usize = 3;
+
+// NOTE: Typical OCR glyph confusions; each pair is tried in both directions.
+const OCR_CONFUSION_PAIRS: &[(&str, &str)] = &[
+  ("rn", "m"),
+  ("cl", "d"),
+  ("vv", "w"),
+  ("li", "h"),
+  ("ii", "u"),
+  ("I", "l"),
+  ("c", "e"),
+  ("fi", "f"),
+];
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpellcheckMode {
+  #[default]
+  Flag,
+  AutoCorrect,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpellcheckSettings {
+  // NOTE: Language codes resolve to `<dictionary_directory_path>/<lang>.dic` (e.g. `en_US`).
+  #[serde(default)]
+  pub languages: Vec<String>,
+  pub dictionary_directory_path: Option<String>,
+  #[serde(default)]
+  pub custom_word_list_paths: Vec<String>,
+  #[serde(default)]
+  pub mode: SpellcheckMode,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SpellcheckCorrection {
+  pub line_number: usize,
+  pub original: String,
+  pub corrected: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SpellcheckFlaggedWord {
+  pub line_number: usize,
+  pub word: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SpellcheckReport {
+  pub markdown_path: String,
+  pub mode: SpellcheckMode,
+  pub corrections: Vec<SpellcheckCorrection>,
+  pub flagged_words: Vec<SpellcheckFlaggedWord>,
+}
+
+fn resolve_dictionary_directory(settings: &SpellcheckSettings) -> PathBuf {
+  if let Some(configured) = settings.dictionary_directory_path.as_deref() {
+    return PathBuf::from(configured);
+  }
+  if let Ok(from_environment) = std::env::var(OCR_AGENT_DICTIONARY_DIRECTORY_ENVIRONMENT_VARIABLE_NAME) {
+    return PathBuf::from(from_environment);
+  }
+  PathBuf::from(DEFAULT_HUNSPELL_DICTIONARY_DIRECTORY)
+}
+
+fn load_word_list_into(word_list_path: &Path, words: &mut HashSet<String>) -> Result<(), String> {
+  let raw = fs::read_to_string(word_list_path)
+    .map_err(|error| format!("Failed to read dictionary {}: {error}", word_list_path.display()))?;
+  for (line_index, line) in raw.lines().enumerate() {
+    let trimmed = line.trim();
+    // Guard: hunspell `.dic` files start with a word count line.
+    if line_index == 0 && trimmed.chars().all(|character| character.is_ascii_digit()) {
+      continue;
+    }
+    // NOTE: Affix flags (`word/FLAGS`) are stripped; affix expansion is not supported.
+    let word = trimmed.split('/').next().unwrap_or("").trim();
+    if word.is_empty() || word.starts_with('#') {
+      continue;
+    }
+    words.insert(word.to_lowercase());
+  }
+  Ok(())
+}
+
+pub fn load_dictionary_words(settings: &SpellcheckSettings) -> Result<HashSet<String>, String> {
+  let dictionary_directory_path = resolve_dictionary_directory(settings);
+  let mut words: HashSet<String> = HashSet::new();
+  for language in &settings.languages {
+    load_word_list_into(&dictionary_directory_path.join(format!("{language}.dic")), &mut words)?;
+  }
+  for custom_word_list_path in &settings.custom_word_list_paths {
+    load_word_list_into(Path::new(custom_word_list_path), &mut words)?;
+  }
+  if words.is_empty() {
+    // Guard: with no dictionary every word would be flagged.
+    return Err("Spellcheck has no dictionary words. Configure languages or custom word lists.".to_string());
+  }
+  Ok(words)
+}
+
+// NOTE: Hiragana, Katakana (incl. half-width) and CJK ideographs; `.dic` word lists do not cover these scripts, and a
+// Japanese text run has no spaces, so it would be flagged as one long unknown word.
+fn is_cjk_character(character: char) -> bool {
+  matches!(
+    character,
+    '\u{3040}'..='\u{30ff}'
+      | '\u{31f0}'..='\u{31ff}'
+      | '\u{3400}'..='\u{4dbf}'
+      | '\u{4e00}'..='\u{9fff}'
+      | '\u{f900}'..='\u{faff}'
+      | '\u{ff66}'..='\u{ff9f}'
+      | '\u{20000}'..='\u{2fa1f}'
+  )
+}
+
+fn is_checkable_word(word: &str) -> bool {
+  word.chars().count() >= MIN_CHECKED_WORD_CHARACTERS
+    && word
+      .chars()
+      .all(|character| character.is_alphabetic() && !is_cjk_character(character))
+}
+
+fn is_known_word(words: &HashSet<String>, word: &str) -> bool {
+  words.contains(&word.to_lowercase())
+}
+
+fn find_unique_confusion_correction(words: &HashSet<String>, word: &str) -> Option<String> {
+  let mut candidates: Vec<String> = vec![];
+  for (left, right) in OCR_CONFUSION_PAIRS {
+    for (from, to) in [(left, right), (right, left)] {
+      let mut search_start = 0;
+      while let Some(relative_index) = word[search_start..].find(from) {
+        let index = search_start + relative_index;
+        let candidate = format!("{}{}{}", &word[..index], to, &word[index + from.len()..]);
+        if is_known_word(words, &candidate) && !candidates.contains(&candidate) {
+          candidates.push(candidate);
+        }
+        search_start = index + from.len();
+      }
+    }
+  }
+  // Guard: ambiguous fixes are flagged for a human instead of guessed.
+  if candidates.len() == 1 {
+    return candidates.pop();
+  }
+  None
+}
+
+fn split_words_with_offsets(line: &str) -> Vec<(usize, &str)> {
+  let mut words: Vec<(usize, &str)> = vec![];
+  let mut word_start: Option<usize> = None;
+  for (index, character) in line.char_indices() {
+    if character.is_alphanumeric() {
+      if word_start.is_none() {
+        word_start = Some(index);
+      }
+      continue;
+    }
+    if let Some(start) = word_start.take() {
+      words.push((start, &line[start..index]));
+    }
+  }
+  if let Some(start) = word_start {
+    words.push((start, &line[start..]));
+  }
+  words
+}
+
+fn is_fence_line(line: &str) -> bool {
+  let trimmed = line.trim_start();
+  trimmed.starts_with("```") || trimmed.starts_with("~~~")
+}
+
+pub fn spellcheck_markdown(
+  words: &HashSet<String>,
+  markdown: &str,
+  mode: SpellcheckMode,
+) -> (String, Vec<SpellcheckCorrection>, Vec<SpellcheckFlaggedWord>) {
+  let mut corrections: Vec<SpellcheckCorrection> = vec![];
+  let mut flagged_words: Vec<SpellcheckFlaggedWord> = vec![];
+  let mut output_lines: Vec<String> = vec![];
+  let mut is_inside_fenced_code_block = false;
+
+  for (line_index, line) in markdown.lines().enumerate() {
+    if is_fence_line(line) {
+      is_inside_fenced_code_block = !is_inside_fenced_code_block;
+      output_lines.push(line.to_string());
+      continue;
+    }
+    // Guard: never touch code, URLs, or math.
+    if is_inside_fenced_code_block || line.contains("://") || line.contains('$') {
+      output_lines.push(line.to_string());
+      continue;
+    }
+
+    let mut corrected_line = String::with_capacity(line.len());
+    let mut copied_until = 0;
+    for (offset, word) in split_words_with_offsets(line) {
+      if !is_checkable_word(word) || is_known_word(words, word) {
+        continue;
+      }
+      let line_number = line_index + 1;
+      match find_unique_confusion_correction(words, word) {
+        Some(corrected) => {
+          corrections.push(SpellcheckCorrection {
+            line_number,
+            original: word.to_string(),
+            corrected: corrected.clone(),
+          });
+          if matches!(mode, SpellcheckMode::AutoCorrect) {
+            corrected_line.push_str(&line[copied_until..offset]);
+            corrected_line.push_str(&corrected);
+            copied_until = offset + word.len();
+          }
+        }
+        None => flagged_words.push(SpellcheckFlaggedWord {
+          line_number,
+          word: word.to_string(),
+        }),
+      }
+    }
+    corrected_line.push_str(&line[copied_until..]);
+    output_lines.push(corrected_line);
+  }
+
+  let mut output = output_lines.join("\n");
+  if markdown.ends_with('\n') {
+    output.push('\n');
+  }
+  (output, corrections, flagged_words)
+}
+
+pub fn spellcheck_markdown_file(
+  settings: &SpellcheckSettings,
+  markdown_path: &Path,
+  report_path: &Path,
+) -> Result<SpellcheckReport, String> {
+  let words = load_dictionary_words(settings)?;
+  let markdown = fs::read_to_string(markdown_path)
+    .map_err(|error| format!("Failed to read {}: {error}", markdown_path.display()))?;
+
+  let (corrected_markdown, corrections, flagged_words) = spellcheck_markdown(&words, &markdown, settings.mode);
+  if matches!(settings.mode, SpellcheckMode::AutoCorrect) && !corrections.is_empty() {
+    fs::write(markdown_path, corrected_markdown).map_err(|error| error.to_string())?;
+  }
+
+  let report = SpellcheckReport {
+    markdown_path: markdown_path.to_string_lossy().to_string(),
+    mode: settings.mode,
+    corrections,
+    flagged_words,
+  };
+  if let Some(parent) = report_path.parent() {
+    fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+  }
+  let serialized = serde_json::to_string_pretty(&report).map_err(|error| error.to_string())?;
+  fs::write(report_path, serialized).map_err(|error| error.to_string())?;
+  Ok(report)
+}
@@ -0,0 +1,97 @@
+/*!
+Responsibility:
+- Periodically write a machine-readable job status snapshot (`status.json`) for external polling.
+- Write atomically (temp file + rename) so readers never observe a half-written file.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+  thread,
+  time::Duration,
+};
+
+use serde::Serialize;
+use serde_json::Value;
+
+pub const STATUS_FILENAME: &str = "status.json";
+pub const OCR_AGENT_STATUS_DIRECTORY_ENVIRONMENT_VARIABLE_NAME: &str = "OCR_AGENT_STATUS_DIR";
+
+const STATUS_FILE_WRITE_INTERVAL: Duration = Duration::from_secs(2);
+
+pub type StatusSnapshotProvider = Arc<dyn Fn() -> Option<Value> + Send + Sync>;
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobPhase {
+  Idle,
+  Enqueuing,
+  Processing,
+  PostProcessing,
+  Completed,
+  Failed,
+  Cancelled,
+  // NOTE: The job root's removable/network volume vanished mid-run; the job resumes when it is back.
+  Suspended,
+  // NOTE: The Docker engine went away mid-run (e.g. Docker Desktop restarted); the job resumes when it is back.
+  Interrupted,
+}
+
+pub fn derive_running_job_phase(total_tasks: i64, pending_tasks: i64, running_tasks: i64) -> JobPhase {
+  if total_tasks == 0 {
+    return JobPhase::Enqueuing;
+  }
+  if pending_tasks + running_tasks > 0 {
+    return JobPhase::Processing;
+  }
+  // NOTE: All tasks are done but the process is still alive: merging and post-run passes.
+  JobPhase::PostProcessing
+}
+
+pub fn write_status_file_atomically(status_file_path: &Path, snapshot: &Value) -> Result<(), String> {
+  if let Some(parent) = status_file_path.parent() {
+    fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+  }
+  let temporary_path = status_file_path.with_extension("json.tmp");
+  let serialized = serde_json::to_string_pretty(snapshot).map_err(|error| error.to_string())?;
+  fs::write(&temporary_path, serialized).map_err(|error| error.to_string())?;
+  fs::rename(&temporary_path, status_file_path).map_err(|error| error.to_string())?;
+  Ok(())
+}
+
+pub fn write_status_files_best_effort(status_file_paths: &[PathBuf], snapshot: &Value) {
+  for status_file_path in status_file_paths {
+    // Guard: a slow or missing network share must not affect the OCR job.
+    let _ = write_status_file_atomically(status_file_path, snapshot);
+  }
+}
+
+pub struct StatusFileWriter {
+  stop_requested: Arc<AtomicBool>,
+  thread: thread::JoinHandle<()>,
+}
+
+impl StatusFileWriter {
+  pub fn start(status_file_paths: Vec<PathBuf>, snapshot_provider: StatusSnapshotProvider) -> StatusFileWriter {
+    let stop_requested = Arc::new(AtomicBool::new(false));
+    let thread_stop_requested = stop_requested.clone();
+    let thread = thread::spawn(move || {
+      while !thread_stop_requested.load(Ordering::SeqCst) {
+        if let Some(snapshot) = snapshot_provider() {
+          write_status_files_best_effort(&status_file_paths, &snapshot);
+        }
+        thread::sleep(STATUS_FILE_WRITE_INTERVAL);
+      }
+    });
+    StatusFileWriter { stop_requested, thread }
+  }
+
+  pub fn stop_and_join(self) {
+    self.stop_requested.store(true, Ordering::SeqCst);
+    let _ = self.thread.join();
+  }
+}
@@ -0,0 +1,44 @@
+/*!
+Responsibility:
+- Keep the last result of frequently polled status queries for a short time, so bursts of UI polling reuse it
+  instead of reopening SQLite and re-walking directories (slow on network-mounted job roots).
+*/
+
+use std::{
+  collections::HashMap,
+  hash::Hash,
+  time::{Duration, Instant},
+};
+
+// NOTE: Shorter than the UI's polling interval, so a single poller always sees fresh data; only bursts are absorbed.
+pub const STATUS_QUERY_CACHE_TTL: Duration = Duration::from_millis(250);
+
+pub struct StatusQueryCache<K, V> {
+  entries_by_key: HashMap<K, (Instant, V)>,
+}
+
+impl<K, V> Default for StatusQueryCache<K, V> {
+  fn default() -> Self {
+    Self {
+      entries_by_key: HashMap::new(),
+    }
+  }
+}
+
+impl<K: Eq + Hash, V: Clone> StatusQueryCache<K, V> {
+  pub fn get_fresh(&self, key: &K) -> Option<V> {
+    self
+      .entries_by_key
+      .get(key)
+      .filter(|(cached_at, _)| cached_at.elapsed() < STATUS_QUERY_CACHE_TTL)
+      .map(|(_, value)| value.clone())
+  }
+
+  pub fn insert(&mut self, key: K, value: V) {
+    // Guard: drop expired entries so roots that are no longer polled do not accumulate.
+    self
+      .entries_by_key
+      .retain(|_, (cached_at, _)| cached_at.elapsed() < STATUS_QUERY_CACHE_TTL);
+    self.entries_by_key.insert(key, (Instant::now(), value));
+  }
+}
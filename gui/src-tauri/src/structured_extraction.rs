@@ -0,0 +1,212 @@
+/*!
+Responsibility:
+- Extract structured fields (vendor, date, total, line items) from OCR'd invoice/receipt markdown.
+- Write one JSON file per input document under `output/structured/`.
+*/
+
+use std::{
+  collections::BTreeMap,
+  fs,
+  path::{Path, PathBuf},
+};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::output_documents::DocumentMarkdown;
+
+pub const STRUCTURED_OUTPUT_DIRECTORY_NAME: &str = "structured";
+
+const DEFAULT_VENDOR_PATTERN: &str = r"(?m)^\s*#*\s*([^\s#|<].{1,80}?)\s*$";
+const DEFAULT_DATE_PATTERN: &str =
+  r"(\d{4}[-/.年]\d{1,2}[-/.月]\d{1,2}日?|\d{1,2}[-/.]\d{1,2}[-/.]\d{2,4})";
+const DEFAULT_TOTAL_PATTERN: &str =
+  r"(?i)(?:grand\s+total|total\s+due|amount\s+due|total|合計|総額)[^\d\n]{0,20}([\d,]+(?:\.\d{1,2})?)";
+const DEFAULT_LINE_ITEM_PATTERN: &str = r"(?m)^\s*(?:[-*]\s+)?\|?\s*(?P<description>[^\d|\n][^|\n]*?)\s*\|?\s*(?:(?P<quantity>\d+)\s*(?:[xX×]|\|)\s*)?(?P<amount>[\d,]+\.\d{2})\s*\|?\s*$";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructuredFieldRule {
+  pub name: String,
+  // NOTE: The first capture group is used as the field value; the whole match is used when there is none.
+  pub pattern: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructuredExtractionSchema {
+  pub fields: Vec<StructuredFieldRule>,
+  // NOTE: Named groups `description`, `quantity`, and `amount` are recognized.
+  pub line_item_pattern: Option<String>,
+}
+
+impl Default for StructuredExtractionSchema {
+  fn default() -> Self {
+    StructuredExtractionSchema {
+      fields: vec![
+        StructuredFieldRule {
+          name: "vendor".to_string(),
+          pattern: DEFAULT_VENDOR_PATTERN.to_string(),
+        },
+        StructuredFieldRule {
+          name: "date".to_string(),
+          pattern: DEFAULT_DATE_PATTERN.to_string(),
+        },
+        StructuredFieldRule {
+          name: "total".to_string(),
+          pattern: DEFAULT_TOTAL_PATTERN.to_string(),
+        },
+      ],
+      line_item_pattern: Some(DEFAULT_LINE_ITEM_PATTERN.to_string()),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StructuredLineItem {
+  pub description: Option<String>,
+  pub quantity: Option<String>,
+  pub amount: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StructuredDocument {
+  pub source_path: String,
+  pub fields: BTreeMap<String, Option<String>>,
+  pub line_items: Vec<StructuredLineItem>,
+}
+
+struct CompiledSchema {
+  field_patterns: Vec<(String, Regex)>,
+  line_item_pattern: Option<Regex>,
+}
+
+fn compile_schema(schema: &StructuredExtractionSchema) -> Result<CompiledSchema, String> {
+  let mut field_patterns: Vec<(String, Regex)> = vec![];
+  for rule in &schema.fields {
+    if rule.name.trim().is_empty() {
+      // Guard: unnamed fields cannot be represented in the JSON output.
+      return Err("Structured extraction field name must not be empty".to_string());
+    }
+    let pattern = Regex::new(&rule.pattern)
+      .map_err(|error| format!("Invalid pattern for field `{}`: {error}", rule.name))?;
+    field_patterns.push((rule.name.clone(), pattern));
+  }
+
+  let line_item_pattern = match schema.line_item_pattern.as_deref() {
+    None => None,
+    Some(raw) => Some(Regex::new(raw).map_err(|error| format!("Invalid line item pattern: {error}"))?),
+  };
+
+  Ok(CompiledSchema {
+    field_patterns,
+    line_item_pattern,
+  })
+}
+
+fn strip_html_table_markup(markdown: &str) -> String {
+  // Guard: the engine often emits tables as HTML; flatten rows into pipe-separated lines for the patterns.
+  markdown
+    .replace("</tr>", "\n")
+    .replace("</td>", " | ")
+    .replace("</th>", " | ")
+    .lines()
+    .map(|line| {
+      let mut plain = String::with_capacity(line.len());
+      let mut is_inside_tag = false;
+      for character in line.chars() {
+        match character {
+          '<' => is_inside_tag = true,
+          '>' if is_inside_tag => is_inside_tag = false,
+          _ if !is_inside_tag => plain.push(character),
+          _ => {}
+        }
+      }
+      plain
+    })
+    .collect::<Vec<String>>()
+    .join("\n")
+}
+
+fn extract_first_match(pattern: &Regex, text: &str) -> Option<String> {
+  let captures = pattern.captures(text)?;
+  let matched = captures.get(1).or_else(|| captures.get(0))?;
+  let value = matched.as_str().trim().to_string();
+  if value.is_empty() {
+    return None;
+  }
+  Some(value)
+}
+
+fn extract_line_items(pattern: &Regex, text: &str) -> Vec<StructuredLineItem> {
+  let named_value = |captures: &regex::Captures<'_>, name: &str| {
+    captures
+      .name(name)
+      .map(|value| value.as_str().trim().to_string())
+      .filter(|value| !value.is_empty())
+  };
+
+  pattern
+    .captures_iter(text)
+    .map(|captures| StructuredLineItem {
+      description: named_value(&captures, "description"),
+      quantity: named_value(&captures, "quantity"),
+      amount: named_value(&captures, "amount"),
+    })
+    .filter(|item| item.description.is_some() || item.amount.is_some())
+    .collect()
+}
+
+fn extract_document(compiled: &CompiledSchema, document: &DocumentMarkdown) -> StructuredDocument {
+  let plain_text = strip_html_table_markup(&document.markdown);
+
+  let mut fields: BTreeMap<String, Option<String>> = BTreeMap::new();
+  for (name, pattern) in &compiled.field_patterns {
+    fields.insert(name.clone(), extract_first_match(pattern, &plain_text));
+  }
+
+  let line_items = match &compiled.line_item_pattern {
+    None => vec![],
+    Some(pattern) => extract_line_items(pattern, &plain_text),
+  };
+
+  StructuredDocument {
+    source_path: document.source_path.clone(),
+    fields,
+    line_items,
+  }
+}
+
+fn derive_structured_output_filename(source_path: &str, document_index: usize) -> String {
+  let stem = Path::new(source_path)
+    .file_stem()
+    .and_then(|stem| stem.to_str())
+    .unwrap_or("document");
+  let sanitized: String = stem
+    .chars()
+    .map(|character| match character {
+      '\\' | '/' | ':' | ' ' => '_',
+      other => other,
+    })
+    .collect();
+  // Guard: prefix with the document index so identical stems from different folders never collide.
+  format!("{:03}_{sanitized}.json", document_index + 1)
+}
+
+pub fn extract_structured_documents(
+  documents: &[DocumentMarkdown],
+  schema: &StructuredExtractionSchema,
+  structured_output_directory_path: &Path,
+) -> Result<Vec<PathBuf>, String> {
+  let compiled = compile_schema(schema)?;
+  fs::create_dir_all(structured_output_directory_path).map_err(|error| error.to_string())?;
+
+  let mut written_paths: Vec<PathBuf> = vec![];
+  for (document_index, document) in documents.iter().enumerate() {
+    let structured_document = extract_document(&compiled, document);
+    let output_path = structured_output_directory_path
+      .join(derive_structured_output_filename(&document.source_path, document_index));
+    let serialized = serde_json::to_string_pretty(&structured_document).map_err(|error| error.to_string())?;
+    fs::write(&output_path, serialized).map_err(|error| error.to_string())?;
+    written_paths.push(output_path);
+  }
+  Ok(written_paths)
+}
@@ -0,0 +1,58 @@
+/*!
+Responsibility:
+- Hold the per-submitter routing table in `<app config>/submitter_routing.json`: extra notification targets (e.g. a
+  Slack webhook that posts to the submitter's DM) and output destinations (e.g. the submitter's share) for jobs
+  whose `job_state.json` names that submitter.
+*/
+
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::notifications::NotificationTarget;
+use crate::output_destinations::OutputDestination;
+
+pub const SUBMITTER_ROUTING_FILENAME: &str = "submitter_routing.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitterRoute {
+  // NOTE: Matched case-insensitively against the recorded submitter, ignoring surrounding whitespace.
+  pub submitter: String,
+  #[serde(default)]
+  pub notification_targets: Vec<NotificationTarget>,
+  #[serde(default)]
+  pub output_destinations: Vec<OutputDestination>,
+  // NOTE: When true, only this route's targets are notified (e.g. keep a submitter's results out of the team channel).
+  #[serde(default)]
+  pub is_default_notification_skipped: bool,
+}
+
+pub fn read_submitter_routes(routing_file_path: &Path) -> Result<Vec<SubmitterRoute>, String> {
+  if !routing_file_path.exists() {
+    return Ok(vec![]);
+  }
+  let raw = fs::read_to_string(routing_file_path).map_err(|error| error.to_string())?;
+  serde_json::from_str::<Vec<SubmitterRoute>>(&raw)
+    .map_err(|error| format!("Invalid submitter routing {}: {error}", routing_file_path.display()))
+}
+
+pub fn write_submitter_routes(routing_file_path: &Path, routes: &[SubmitterRoute]) -> Result<(), String> {
+  for route in routes {
+    // Guard: a blank name would never match and usually means the row was left half-filled.
+    if route.submitter.trim().is_empty() {
+      return Err("Submitter routes need a submitter name".to_string());
+    }
+  }
+  if let Some(parent) = routing_file_path.parent() {
+    fs::create_dir_all(parent).map_err(|error| error.to_string())?;
+  }
+  let serialized = serde_json::to_string_pretty(routes).map_err(|error| error.to_string())?;
+  fs::write(routing_file_path, serialized).map_err(|error| error.to_string())
+}
+
+pub fn find_submitter_route(routes: Vec<SubmitterRoute>, submitter: &str) -> Option<SubmitterRoute> {
+  let submitter = submitter.trim();
+  routes
+    .into_iter()
+    .find(|route| route.submitter.trim().eq_ignore_ascii_case(submitter))
+}
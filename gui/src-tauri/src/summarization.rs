@@ -0,0 +1,91 @@
+/*!
+Responsibility:
+- Generate an executive summary of the merged OCR markdown via the configured LLM endpoint.
+- Either write a separate `output/<name>.summary.md` or insert a summary section into the output itself.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::llm_client::{complete_chat, split_markdown_into_chunks, LlmEndpointSettings};
+
+const MAX_SUMMARIZATION_CHUNK_CHARACTERS: usize = 12000;
+const SUMMARY_SECTION_HEADING: &str = "## Executive Summary";
+const MERGED_MARKDOWN_TITLE_LINE: &str = "# OCR Output";
+
+const CHUNK_SUMMARY_SYSTEM_PROMPT: &str = "Summarize the following part of an OCR'd document in concise bullet points. \
+   Keep names, dates, amounts, and decisions. Output Markdown only.";
+const FINAL_SUMMARY_SYSTEM_PROMPT: &str = "Write an executive summary (at most 10 bullet points) of the document \
+   described by the following notes. Output Markdown bullet points only.";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SummarizationMode {
+  SeparateFile,
+  InsertSection,
+}
+
+fn summarize_markdown(endpoint: &LlmEndpointSettings, markdown: &str) -> Result<String, String> {
+  let chunks = split_markdown_into_chunks(markdown, MAX_SUMMARIZATION_CHUNK_CHARACTERS);
+  if chunks.is_empty() {
+    // Guard: nothing to summarize.
+    return Err("Output markdown is empty; nothing to summarize.".to_string());
+  }
+
+  // NOTE: Map-reduce keeps long documents within the model context window.
+  let mut chunk_summaries: Vec<String> = vec![];
+  for chunk in &chunks {
+    chunk_summaries.push(complete_chat(endpoint, CHUNK_SUMMARY_SYSTEM_PROMPT, chunk)?);
+  }
+  if chunk_summaries.len() == 1 {
+    return Ok(chunk_summaries.remove(0));
+  }
+  complete_chat(endpoint, FINAL_SUMMARY_SYSTEM_PROMPT, &chunk_summaries.join("\n\n"))
+}
+
+fn insert_summary_section(markdown: &str, summary: &str) -> String {
+  let section = format!("{SUMMARY_SECTION_HEADING}\n\n{}\n", summary.trim());
+  // Guard: keep the merged title first when present; otherwise the summary leads the document.
+  match markdown.strip_prefix(MERGED_MARKDOWN_TITLE_LINE) {
+    Some(rest) => format!("{MERGED_MARKDOWN_TITLE_LINE}\n\n{section}{rest}"),
+    None => format!("{section}\n{markdown}"),
+  }
+}
+
+pub fn summarize_markdown_file(
+  endpoint: &LlmEndpointSettings,
+  mode: SummarizationMode,
+  source_markdown_path: &Path,
+  output_directory_path: &Path,
+) -> Result<PathBuf, String> {
+  let source_markdown = fs::read_to_string(source_markdown_path)
+    .map_err(|error| format!("Failed to read {}: {error}", source_markdown_path.display()))?;
+  if source_markdown.contains(SUMMARY_SECTION_HEADING) {
+    // Guard: never summarize a summary (e.g. when the pass is re-run on the same output).
+    return Err("Output markdown already contains a summary section.".to_string());
+  }
+  let summary = summarize_markdown(endpoint, &source_markdown)?;
+
+  match mode {
+    SummarizationMode::SeparateFile => {
+      let stem = source_markdown_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("output");
+      fs::create_dir_all(output_directory_path).map_err(|error| error.to_string())?;
+      let summary_path = output_directory_path.join(format!("{stem}.summary.md"));
+      fs::write(&summary_path, format!("{SUMMARY_SECTION_HEADING}\n\n{}\n", summary.trim()))
+        .map_err(|error| error.to_string())?;
+      Ok(summary_path)
+    }
+    SummarizationMode::InsertSection => {
+      fs::write(source_markdown_path, insert_summary_section(&source_markdown, &summary))
+        .map_err(|error| error.to_string())?;
+      Ok(source_markdown_path.to_path_buf())
+    }
+  }
+}
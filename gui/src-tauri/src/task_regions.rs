@@ -0,0 +1,41 @@
+/*!
+Responsibility:
+- Read per-task region bounding boxes written by the engine (`output/regions/task_<id>.json`).
+*/
+
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+pub const REGIONS_DIRECTORY_NAME: &str = "regions";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskRegion {
+  pub label: String,
+  pub x1: i64,
+  pub y1: i64,
+  pub x2: i64,
+  pub y2: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskRegions {
+  pub task_id: i64,
+  // NOTE: Coordinates are normalized to 0..coordinate_scale; the frontend scales them to the preview image.
+  pub coordinate_scale: i64,
+  pub regions: Vec<TaskRegion>,
+}
+
+pub fn read_task_regions(output_directory_path: &Path, task_id: i64) -> Result<Option<TaskRegions>, String> {
+  let regions_file_path = output_directory_path
+    .join(REGIONS_DIRECTORY_NAME)
+    .join(format!("task_{task_id}.json"));
+  if !regions_file_path.exists() {
+    // Guard: tasks that have not run yet (or older engine versions) have no sidecar.
+    return Ok(None);
+  }
+  let raw = fs::read_to_string(&regions_file_path).map_err(|error| error.to_string())?;
+  let regions = serde_json::from_str::<TaskRegions>(&raw)
+    .map_err(|error| format!("Invalid regions file {}: {error}", regions_file_path.display()))?;
+  Ok(Some(regions))
+}
@@ -0,0 +1,112 @@
+/*!
+Responsibility:
+- Render user-provided minijinja templates (stored in the app config directory) against a job's OCR results.
+- Write each rendered artifact under `output/rendered/`, named after the template without its `.j2`/`.jinja` suffix.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use minijinja::{path_loader, Environment};
+use serde::Serialize;
+
+use crate::output_documents::DocumentMarkdown;
+
+pub const TEMPLATES_DIRECTORY_NAME: &str = "templates";
+pub const RENDERED_OUTPUT_DIRECTORY_NAME: &str = "rendered";
+
+const TEMPLATE_FILENAME_SUFFIXES: &[&str] = &[".j2", ".jinja", ".jinja2"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OutputTemplateCatalog {
+  pub templates_directory_path: String,
+  pub template_names: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateRenderContext {
+  pub job_name: String,
+  pub job_root_directory_path: String,
+  pub generated_unix_timestamp_millis: i64,
+  // NOTE: The final merged markdown; `documents` holds the same text split per input file.
+  pub markdown: String,
+  pub documents: Vec<DocumentMarkdown>,
+}
+
+pub fn list_template_names(templates_directory_path: &Path) -> Result<Vec<String>, String> {
+  if !templates_directory_path.exists() {
+    return Ok(vec![]);
+  }
+  let mut template_names: Vec<String> = vec![];
+  for entry in fs::read_dir(templates_directory_path).map_err(|error| error.to_string())? {
+    let entry = entry.map_err(|error| error.to_string())?;
+    if !entry.path().is_file() {
+      continue;
+    }
+    let file_name = entry.file_name().to_string_lossy().to_string();
+    // Guard: skip editor backups and dotfiles that are not meant to be rendered.
+    if file_name.starts_with('.') || file_name.ends_with('~') {
+      continue;
+    }
+    template_names.push(file_name);
+  }
+  template_names.sort();
+  Ok(template_names)
+}
+
+fn derive_rendered_output_filename(template_name: &str) -> String {
+  for suffix in TEMPLATE_FILENAME_SUFFIXES {
+    if let Some(stripped) = template_name.strip_suffix(suffix) {
+      if !stripped.is_empty() {
+        return stripped.to_string();
+      }
+    }
+  }
+  template_name.to_string()
+}
+
+fn escape_latex(value: String) -> String {
+  let mut escaped = String::with_capacity(value.len());
+  for character in value.chars() {
+    match character {
+      '\\' => escaped.push_str("\\textbackslash{}"),
+      '~' => escaped.push_str("\\textasciitilde{}"),
+      '^' => escaped.push_str("\\textasciicircum{}"),
+      '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+        escaped.push('\\');
+        escaped.push(character);
+      }
+      _ => escaped.push(character),
+    }
+  }
+  escaped
+}
+
+pub fn render_templates(
+  templates_directory_path: &Path,
+  template_names: &[String],
+  context: &TemplateRenderContext,
+  rendered_output_directory_path: &Path,
+) -> Result<Vec<PathBuf>, String> {
+  let mut environment = Environment::new();
+  environment.set_loader(path_loader(templates_directory_path));
+  // NOTE: HTML templates are auto-escaped by extension; LaTeX templates opt in with `| latex_escape`.
+  environment.add_filter("latex_escape", escape_latex);
+
+  fs::create_dir_all(rendered_output_directory_path).map_err(|error| error.to_string())?;
+  let mut written_paths: Vec<PathBuf> = vec![];
+  for template_name in template_names {
+    let template = environment
+      .get_template(template_name)
+      .map_err(|error| format!("Failed to load template {template_name}: {error}"))?;
+    let rendered = template
+      .render(context)
+      .map_err(|error| format!("Failed to render template {template_name}: {error}"))?;
+    let output_path = rendered_output_directory_path.join(derive_rendered_output_filename(template_name));
+    fs::write(&output_path, rendered).map_err(|error| error.to_string())?;
+    written_paths.push(output_path);
+  }
+  Ok(written_paths)
+}
@@ -0,0 +1,71 @@
+/*!
+Responsibility:
+- Translate the merged OCR markdown into a target language via the configured LLM endpoint.
+- Write the result as `output/<name>.<lang>.md` next to the other job outputs.
+*/
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use crate::llm_client::{complete_chat, split_markdown_into_chunks, LlmEndpointSettings};
+
+const MAX_TRANSLATION_CHUNK_CHARACTERS: usize = 6000;
+
+fn build_translation_system_prompt(target_language: &str) -> String {
+  format!(
+    "Translate the user's Markdown document into {target_language}. \
+     Preserve Markdown structure, tables, math, and code blocks exactly. \
+     Output only the translated Markdown."
+  )
+}
+
+pub fn normalize_language_code(raw: &str) -> Result<String, String> {
+  let trimmed = raw.trim();
+  if trimmed.is_empty() {
+    // Guard: the language code becomes part of the output filename.
+    return Err("Translation target language is empty".to_string());
+  }
+  let is_safe = trimmed
+    .chars()
+    .all(|character| character.is_ascii_alphanumeric() || character == '-' || character == '_');
+  if !is_safe {
+    return Err(format!("Translation target language must be a language code like `en` or `pt-BR`: {trimmed}"));
+  }
+  Ok(trimmed.to_string())
+}
+
+pub fn derive_translated_output_path(
+  output_directory_path: &Path,
+  source_markdown_path: &Path,
+  language_code: &str,
+) -> PathBuf {
+  let stem = source_markdown_path
+    .file_stem()
+    .and_then(|stem| stem.to_str())
+    .unwrap_or("output");
+  output_directory_path.join(format!("{stem}.{language_code}.md"))
+}
+
+pub fn translate_markdown_file(
+  endpoint: &LlmEndpointSettings,
+  target_language: &str,
+  source_markdown_path: &Path,
+  output_directory_path: &Path,
+) -> Result<PathBuf, String> {
+  let language_code = normalize_language_code(target_language)?;
+  let source_markdown = fs::read_to_string(source_markdown_path)
+    .map_err(|error| format!("Failed to read {}: {error}", source_markdown_path.display()))?;
+
+  let system_prompt = build_translation_system_prompt(&language_code);
+  let mut translated_chunks: Vec<String> = vec![];
+  for chunk in split_markdown_into_chunks(&source_markdown, MAX_TRANSLATION_CHUNK_CHARACTERS) {
+    translated_chunks.push(complete_chat(endpoint, &system_prompt, &chunk)?);
+  }
+
+  fs::create_dir_all(output_directory_path).map_err(|error| error.to_string())?;
+  let output_path = derive_translated_output_path(output_directory_path, source_markdown_path, &language_code);
+  fs::write(&output_path, translated_chunks.join("\n\n")).map_err(|error| error.to_string())?;
+  Ok(output_path)
+}
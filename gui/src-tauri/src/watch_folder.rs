@@ -2,34 +2,96 @@
 Responsibility:
 - Provide a simple watch-folder based ingestion loop for the Tauri GUI.
 - Detect completed inbox bundles (via a `.ready` marker), then create job roots and trigger OCR runs.
+- Wake on filesystem notifications for the inbox, keeping a slow directory scan as a fallback for shares and volumes
+  that do not deliver them.
+- Accept bundles inside per-submitter folders (`inbox/@<submitter>/<bundle>`) on shared inboxes.
+- Optionally wrap standalone files dropped into the inbox (e.g. a lone PDF) into `.ready` bundles once they settle.
+- Extract `.zip` archives dropped into the inbox into `.ready` bundles once their size stops changing, on a background
+  thread and within the `watch_zip_max_*` runtime limits.
+- Optionally mark bundle folders `.ready` once their files stop changing (quiescence mode), for uploaders that cannot
+  create marker files.
+- Track why intake is paused (e.g. a disk watermark or a removed USB drive) so the GUI can show it instead of a write error.
+- Let an operator pause intake for maintenance and restart a stopped watcher with its last settings (e.g. over the HTTP API).
+- Run several inboxes side by side, each with its own jobs root, priority and preset; a free job slot goes to the
+  highest-priority inbox with a bundle waiting.
 */
 
 use std::{
+  collections::{BTreeMap, HashMap, HashSet},
   fs,
-  fs::OpenOptions,
+  fs::{File, OpenOptions},
+  io::{self, Read},
   path::{Path, PathBuf},
   sync::{
     atomic::{AtomicBool, Ordering},
+    mpsc::{self, Receiver, RecvTimeoutError, Sender},
     Arc, Mutex,
   },
   thread,
-  time::Duration,
+  time::{Duration, Instant, SystemTime},
 };
 
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use serde::Serialize;
+use zip::ZipArchive;
+
+use crate::{
+  natural_sort::sort_paths_naturally,
+  removable_volumes::{detect_volume_kind, VolumeKind},
+  runtime_limits::{current_runtime_limits, RuntimeLimits},
+};
 
 const DEFAULT_WATCH_POLL_INTERVAL_MILLIS: u64 = 1000;
+// NOTE: With notifications active, the inbox is still scanned this often: bundles left waiting for a job slot or a
+// paused intake produce no new event, and some network shares drop events.
+const NOTIFIED_WATCH_FALLBACK_POLL_INTERVAL: Duration = Duration::from_secs(10);
 const WATCH_READY_FILENAME: &str = ".ready";
 const WATCH_PROCESSING_FILENAME: &str = ".processing";
 const WATCH_PROCESSED_FILENAME: &str = ".processed";
 const WATCH_FAILED_FILENAME: &str = ".failed";
+// NOTE: A dropped file must keep its size and mtime this long before it is wrapped; copies over SMB stall mid-write.
+const SINGLE_FILE_DROP_STABILITY_DELAY: Duration = Duration::from_secs(5);
+// NOTE: Mirrors SUPPORTED_IMAGE_EXTENSIONS and the PDF handling in input_discovery.py.
+const SINGLE_FILE_DROP_EXTENSIONS: &[&str] = &["pdf", "png", "jpg", "jpeg", "webp", "bmp", "tif", "tiff"];
+const ZIP_DROP_EXTENSION: &str = "zip";
+// NOTE: A settled `.zip` is moved into `inbox/.extracting_<name>/` and expanded there on a background thread; hidden
+// folders are never bundles, so neither intake nor quiescence mode sees a half-extracted archive.
+const ZIP_EXTRACTION_STAGING_PREFIX: &str = ".extracting_";
+const ZIP_EXTRACTION_ARCHIVE_FILENAME: &str = "archive.zip";
+const ZIP_EXTRACTION_CONTENTS_DIRECTORY_NAME: &str = "extracted";
+// NOTE: Shorter waits mark folders ready between two files of an SMB copy that stalls briefly.
+pub const MIN_BUNDLE_QUIESCENCE_SECONDS: u64 = 5;
+// NOTE: Top-level inbox folders named `@<submitter>` hold that person's bundles instead of being bundles themselves.
+const SUBMITTER_FOLDER_PREFIX: &str = "@";
+const MAX_SUBMITTER_CHARACTERS: usize = 120;
+// NOTE: Written next to `.processed` when the bundle matched an already processed one; holds that job's id.
+pub const WATCH_DUPLICATE_OF_FILENAME: &str = ".duplicate_of";
+// NOTE: The watcher started by `start_watch_folder` without an id; the HTTP API, connectors and backfill write into
+// its inbox.
+pub const PRIMARY_WATCH_ID: &str = "default";
+const MAX_WATCH_ID_CHARACTERS: usize = 64;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct WatchFolderStatus {
+  pub watch_id: String,
+  pub priority: i32,
+  pub preset_name: Option<String>,
+  // NOTE: Set when quiescence mode is on; bundle folders then need no `.ready`.
+  pub bundle_quiescence_seconds: Option<u64>,
   pub is_running: bool,
+  // NOTE: Display strings (lossy UTF-8: undecodable bytes become U+FFFD); see `watch_folder_directory_paths`.
   pub inbox_directory_path: Option<String>,
   pub jobs_root_directory_path: Option<String>,
   pub last_error_message: Option<String>,
+  pub intake_pause_reason: Option<String>,
+  // NOTE: Set by an operator (GUI or HTTP API); unlike `intake_pause_reason` it is only cleared by resuming.
+  pub operator_pause_reason: Option<String>,
+  pub last_retention_cleanup_message: Option<String>,
+  pub last_duplicate_bundle_message: Option<String>,
+  pub inbox_volume_kind: Option<VolumeKind>,
+  pub jobs_root_volume_kind: Option<VolumeKind>,
+  // NOTE: False when the inbox could not be watched (e.g. the inotify watch limit); intake then scans every second.
+  pub is_inbox_notification_active: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -37,21 +99,224 @@ pub struct WatchFolderConfig {
   pub inbox_directory_path: PathBuf,
   pub jobs_root_directory_path: PathBuf,
   pub poll_interval: Duration,
+  // NOTE: Detected at start, while the paths are reachable; a vanished volume then pauses intake instead of failing.
+  pub inbox_volume_kind: VolumeKind,
+  pub jobs_root_volume_kind: VolumeKind,
+  // NOTE: Off by default so an inbox shared with other tools keeps the strict `.ready` contract.
+  pub is_single_file_intake_enabled: bool,
+  // NOTE: Higher goes first when several inboxes have bundles waiting for a job slot.
+  pub priority: i32,
+  // NOTE: Base settings for this inbox's jobs; a bundle's `.job_settings.json` overrides them field by field.
+  pub preset_name: Option<String>,
+  // NOTE: Quiescence mode when set: a bundle folder whose files have not changed for this long is treated as if it had
+  // `.ready`. Off by default, since a folder that is only slow to fill would otherwise be taken half-copied.
+  pub bundle_quiescence_period: Option<Duration>,
+}
+
+impl WatchFolderConfig {
+  pub fn new(inbox_directory_path: PathBuf, jobs_root_directory_path: PathBuf, poll_interval: Duration) -> Self {
+    Self {
+      inbox_volume_kind: detect_volume_kind(&inbox_directory_path),
+      jobs_root_volume_kind: detect_volume_kind(&jobs_root_directory_path),
+      inbox_directory_path,
+      jobs_root_directory_path,
+      poll_interval,
+      is_single_file_intake_enabled: false,
+      priority: 0,
+      preset_name: None,
+      bundle_quiescence_period: None,
+    }
+  }
+}
+
+// NOTE: (size, modified, first seen with that size/modified) per standalone inbox file.
+pub type SingleFileDropObservations = HashMap<PathBuf, (u64, Option<SystemTime>, Instant)>;
+
+#[derive(Default)]
+pub struct ZipDropObservations {
+  // NOTE: Size at the previous poll per `.zip` in the inbox.
+  size_by_path: HashMap<PathBuf, u64>,
+  // NOTE: Staging folders a background thread is extracting into right now.
+  extracting_staging_directories: Arc<Mutex<HashSet<PathBuf>>>,
 }
 
+// NOTE: ((file count, total bytes, newest modified), first seen with those contents) per bundle folder not yet ready.
+pub type BundleQuiescenceObservations = HashMap<PathBuf, ((usize, u64, Option<SystemTime>), Instant)>;
+
+pub type WatchFolderPollCallback = Arc<dyn Fn(&WatchFolderConfig) -> Result<(), String> + Send + Sync>;
+
 #[derive(Default)]
 pub(crate) struct WatchFolderRuntimeState {
+  watch_id: String,
   running_thread: Option<thread::JoinHandle<()>>,
   stop_requested: Arc<AtomicBool>,
   inbox_directory_path: Option<PathBuf>,
   jobs_root_directory_path: Option<PathBuf>,
   last_error_message: Option<String>,
+  intake_pause_reason: Option<String>,
+  operator_pause_reason: Option<String>,
+  last_retention_cleanup_message: Option<String>,
+  last_duplicate_bundle_message: Option<String>,
+  inbox_volume_kind: Option<VolumeKind>,
+  jobs_root_volume_kind: Option<VolumeKind>,
+  is_inbox_notification_active: bool,
+  // NOTE: Kept after a stop so the watcher can be restarted remotely without re-entering its settings.
+  last_start: Option<(WatchFolderConfig, WatchFolderPollCallback)>,
 }
 
 pub type SharedWatchFolderRuntimeState = Arc<Mutex<WatchFolderRuntimeState>>;
 
 pub fn new_shared_watch_folder_state() -> SharedWatchFolderRuntimeState {
-  Arc::new(Mutex::new(WatchFolderRuntimeState::default()))
+  new_shared_watch_folder_state_with_id(PRIMARY_WATCH_ID)
+}
+
+fn new_shared_watch_folder_state_with_id(watch_id: &str) -> SharedWatchFolderRuntimeState {
+  Arc::new(Mutex::new(WatchFolderRuntimeState {
+    watch_id: watch_id.to_string(),
+    ..WatchFolderRuntimeState::default()
+  }))
+}
+
+// NOTE: Every watcher by id, the primary one included; each keeps its own thread, state and last settings.
+pub struct WatchFolderRegistry {
+  states_by_watch_id: BTreeMap<String, SharedWatchFolderRuntimeState>,
+}
+
+pub type SharedWatchFolderRegistry = Arc<Mutex<WatchFolderRegistry>>;
+
+pub fn new_shared_watch_folder_registry(primary_state: &SharedWatchFolderRuntimeState) -> SharedWatchFolderRegistry {
+  Arc::new(Mutex::new(WatchFolderRegistry {
+    states_by_watch_id: BTreeMap::from([(PRIMARY_WATCH_ID.to_string(), primary_state.clone())]),
+  }))
+}
+
+pub fn validate_watch_id(raw: &str) -> Result<String, String> {
+  let trimmed = raw.trim();
+  let is_valid = !trimmed.is_empty()
+    && trimmed.chars().count() <= MAX_WATCH_ID_CHARACTERS
+    && trimmed
+      .chars()
+      .all(|character| character.is_ascii_alphanumeric() || character == '-' || character == '_');
+  if !is_valid {
+    return Err(format!(
+      "Invalid watch id: {raw} (use up to {MAX_WATCH_ID_CHARACTERS} letters, digits, '-' or '_')."
+    ));
+  }
+  Ok(trimmed.to_string())
+}
+
+pub fn find_watch_folder_state(
+  registry: &SharedWatchFolderRegistry,
+  watch_id: &str,
+) -> Result<SharedWatchFolderRuntimeState, String> {
+  registry
+    .lock()
+    .map_err(|_| "Watch folder registry lock poisoned".to_string())?
+    .states_by_watch_id
+    .get(watch_id)
+    .cloned()
+    .ok_or_else(|| format!("Watch folder not found: {watch_id}"))
+}
+
+pub fn find_or_add_watch_folder_state(
+  registry: &SharedWatchFolderRegistry,
+  watch_id: &str,
+) -> Result<SharedWatchFolderRuntimeState, String> {
+  let watch_id = validate_watch_id(watch_id)?;
+  let mut locked = registry
+    .lock()
+    .map_err(|_| "Watch folder registry lock poisoned".to_string())?;
+  Ok(
+    locked
+      .states_by_watch_id
+      .entry(watch_id.clone())
+      .or_insert_with(|| new_shared_watch_folder_state_with_id(&watch_id))
+      .clone(),
+  )
+}
+
+// NOTE: The primary watcher cannot be removed (only stopped); the caller stops the returned watcher.
+pub fn remove_watch_folder_state(
+  registry: &SharedWatchFolderRegistry,
+  watch_id: &str,
+) -> Result<SharedWatchFolderRuntimeState, String> {
+  if watch_id == PRIMARY_WATCH_ID {
+    return Err("The default watch folder cannot be removed; stop it instead.".to_string());
+  }
+  registry
+    .lock()
+    .map_err(|_| "Watch folder registry lock poisoned".to_string())?
+    .states_by_watch_id
+    .remove(watch_id)
+    .ok_or_else(|| format!("Watch folder not found: {watch_id}"))
+}
+
+fn registered_watch_folder_states(registry: &SharedWatchFolderRegistry) -> Vec<SharedWatchFolderRuntimeState> {
+  registry
+    .lock()
+    .map(|locked| locked.states_by_watch_id.values().cloned().collect())
+    .unwrap_or_default()
+}
+
+// NOTE: Highest priority first, then by id.
+pub fn list_watch_folder_statuses(registry: &SharedWatchFolderRegistry) -> Vec<WatchFolderStatus> {
+  let mut statuses: Vec<WatchFolderStatus> = registered_watch_folder_states(registry)
+    .iter()
+    .map(get_watch_folder_status)
+    .collect();
+  statuses.sort_by(|left, right| {
+    right
+      .priority
+      .cmp(&left.priority)
+      .then_with(|| left.watch_id.cmp(&right.watch_id))
+  });
+  statuses
+}
+
+// NOTE: Lets a lower-priority inbox leave a free job slot to a higher-priority one that has a bundle ready. Inboxes
+// that are stopped or paused do not hold others back.
+pub fn is_bundle_waiting_in_higher_priority_inbox(registry: &SharedWatchFolderRegistry, priority: i32) -> bool {
+  registered_watch_folder_states(registry).iter().any(|state| {
+    let status = get_watch_folder_status(state);
+    let (inbox_directory_path, _) = watch_folder_directory_paths(state);
+    let Some(inbox_directory_path) = inbox_directory_path.filter(|_| {
+      status.is_running
+        && status.priority > priority
+        && status.intake_pause_reason.is_none()
+        && status.operator_pause_reason.is_none()
+    }) else {
+      return false;
+    };
+    list_ready_bundle_directories(&inbox_directory_path).is_ok_and(|bundles| !bundles.is_empty())
+  })
+}
+
+// NOTE: Compares the configured paths themselves, so two non-UTF-8 paths that render alike are still told apart.
+pub fn find_running_watch_folder_using_directory(
+  registry: &SharedWatchFolderRegistry,
+  excluded_watch_id: &str,
+  directory_paths: &[&Path],
+) -> Option<String> {
+  registered_watch_folder_states(registry).iter().find_map(|state| {
+    let locked = state.lock().ok()?;
+    if locked.running_thread.is_none() || locked.watch_id == excluded_watch_id {
+      return None;
+    }
+    let is_using_directory = [&locked.inbox_directory_path, &locked.jobs_root_directory_path]
+      .into_iter()
+      .flatten()
+      .any(|path| directory_paths.contains(&path.as_path()));
+    is_using_directory.then(|| locked.watch_id.clone())
+  })
+}
+
+// NOTE: (inbox, jobs root) as last started. Code that goes back to the filesystem uses these rather than the strings
+// in `WatchFolderStatus`, which are lossy for non-UTF-8 paths.
+pub fn watch_folder_directory_paths(state: &SharedWatchFolderRuntimeState) -> (Option<PathBuf>, Option<PathBuf>) {
+  state
+    .lock()
+    .map(|locked| (locked.inbox_directory_path.clone(), locked.jobs_root_directory_path.clone()))
+    .unwrap_or((None, None))
 }
 
 pub fn get_watch_folder_status(state: &SharedWatchFolderRuntimeState) -> WatchFolderStatus {
@@ -60,15 +325,33 @@ pub fn get_watch_folder_status(state: &SharedWatchFolderRuntimeState) -> WatchFo
     Err(_) => {
       // Guard: state lock poisoned.
       return WatchFolderStatus {
+        watch_id: String::new(),
+        priority: 0,
+        preset_name: None,
+        bundle_quiescence_seconds: None,
         is_running: false,
         inbox_directory_path: None,
         jobs_root_directory_path: None,
         last_error_message: Some("Watch folder state lock poisoned".to_string()),
+        intake_pause_reason: None,
+        operator_pause_reason: None,
+        last_retention_cleanup_message: None,
+        last_duplicate_bundle_message: None,
+        inbox_volume_kind: None,
+        jobs_root_volume_kind: None,
+        is_inbox_notification_active: false,
       };
     }
   };
 
+  let last_config = locked.last_start.as_ref().map(|(config, _)| config);
   WatchFolderStatus {
+    watch_id: locked.watch_id.clone(),
+    priority: last_config.map(|config| config.priority).unwrap_or(0),
+    preset_name: last_config.and_then(|config| config.preset_name.clone()),
+    bundle_quiescence_seconds: last_config
+      .and_then(|config| config.bundle_quiescence_period)
+      .map(|period| period.as_secs()),
     is_running: locked.running_thread.is_some(),
     inbox_directory_path: locked
       .inbox_directory_path
@@ -79,6 +362,67 @@ pub fn get_watch_folder_status(state: &SharedWatchFolderRuntimeState) -> WatchFo
       .as_ref()
       .map(|p| p.to_string_lossy().to_string()),
     last_error_message: locked.last_error_message.clone(),
+    intake_pause_reason: locked.intake_pause_reason.clone(),
+    operator_pause_reason: locked.operator_pause_reason.clone(),
+    last_retention_cleanup_message: locked.last_retention_cleanup_message.clone(),
+    last_duplicate_bundle_message: locked.last_duplicate_bundle_message.clone(),
+    inbox_volume_kind: locked.inbox_volume_kind,
+    jobs_root_volume_kind: locked.jobs_root_volume_kind,
+    is_inbox_notification_active: locked.running_thread.is_some() && locked.is_inbox_notification_active,
+  }
+}
+
+// NOTE: Long-running ingestion polls this so stopping the watcher does not wait for a large bundle copy.
+pub fn is_watch_folder_stop_requested(state: &SharedWatchFolderRuntimeState) -> bool {
+  state
+    .lock()
+    .map(|locked| locked.stop_requested.load(Ordering::SeqCst))
+    .unwrap_or(true)
+}
+
+pub fn is_watch_folder_intake_paused(state: &SharedWatchFolderRuntimeState) -> bool {
+  state
+    .lock()
+    .map(|locked| locked.intake_pause_reason.is_some())
+    .unwrap_or(false)
+}
+
+pub fn set_watch_folder_intake_pause_reason(state: &SharedWatchFolderRuntimeState, reason: Option<String>) {
+  if let Ok(mut locked) = state.lock() {
+    locked.intake_pause_reason = reason;
+  }
+}
+
+pub fn is_watch_folder_paused_by_operator(state: &SharedWatchFolderRuntimeState) -> bool {
+  state
+    .lock()
+    .map(|locked| locked.operator_pause_reason.is_some())
+    .unwrap_or(false)
+}
+
+// NOTE: None resumes intake. Running jobs are not affected; only new bundles stay in the inbox.
+pub fn set_watch_folder_operator_pause_reason(state: &SharedWatchFolderRuntimeState, reason: Option<String>) {
+  if let Ok(mut locked) = state.lock() {
+    locked.operator_pause_reason = reason;
+  }
+}
+
+// NOTE: For failures after a bundle was already accepted, which must not mark the bundle `.failed`.
+pub fn record_watch_folder_error(state: &SharedWatchFolderRuntimeState, message: String) {
+  if let Ok(mut locked) = state.lock() {
+    locked.last_error_message = Some(message);
+  }
+}
+
+pub fn record_watch_folder_duplicate_bundle(state: &SharedWatchFolderRuntimeState, message: String) {
+  if let Ok(mut locked) = state.lock() {
+    locked.last_duplicate_bundle_message = Some(message);
+  }
+}
+
+pub fn record_watch_folder_retention_cleanup(state: &SharedWatchFolderRuntimeState, message: String) {
+  if let Ok(mut locked) = state.lock() {
+    locked.last_retention_cleanup_message = Some(message);
   }
 }
 
@@ -103,7 +447,7 @@ pub fn stop_watch_folder(state: &SharedWatchFolderRuntimeState) {
 pub fn start_watch_folder(
   state: &SharedWatchFolderRuntimeState,
   config: WatchFolderConfig,
-  poll_once_callback: Arc<dyn Fn(&WatchFolderConfig) -> Result<(), String> + Send + Sync>,
+  poll_once_callback: WatchFolderPollCallback,
 ) -> Result<(), String> {
   if config.inbox_directory_path.as_os_str().is_empty() {
     // Guard: empty inbox path is meaningless.
@@ -113,6 +457,8 @@ pub fn start_watch_folder(
     // Guard: empty jobs root is meaningless.
     return Err("jobs_root_directory_path is empty".to_string());
   }
+  // Guard: a jobs root that was never created would look like a vanished volume and pause intake for good.
+  let _ = fs::create_dir_all(&config.jobs_root_directory_path);
 
   {
     let mut locked = state.lock().map_err(|_| "Watch folder state lock poisoned".to_string())?;
@@ -123,7 +469,12 @@ pub fn start_watch_folder(
     locked.stop_requested = Arc::new(AtomicBool::new(false));
     locked.inbox_directory_path = Some(config.inbox_directory_path.clone());
     locked.jobs_root_directory_path = Some(config.jobs_root_directory_path.clone());
+    locked.inbox_volume_kind = Some(config.inbox_volume_kind);
+    locked.jobs_root_volume_kind = Some(config.jobs_root_volume_kind);
     locked.last_error_message = None;
+    locked.intake_pause_reason = None;
+    locked.is_inbox_notification_active = false;
+    locked.last_start = Some((config.clone(), poll_once_callback.clone()));
   }
 
   let shared_state_for_thread = state.clone();
@@ -132,22 +483,38 @@ pub fn start_watch_folder(
     locked.stop_requested.clone()
   };
 
-  let thread_handle = thread::spawn(move || loop {
-    if stop_flag.load(Ordering::SeqCst) {
-      return;
+  let thread_handle = thread::spawn(move || {
+    let (change_sender, change_receiver) = mpsc::channel::<()>();
+    // Guard: without notifications (unsupported filesystem, watch limit reached) intake polls every interval.
+    let inbox_watcher = watch_inbox_for_changes(&config, change_sender).ok();
+    if let Ok(mut locked) = shared_state_for_thread.lock() {
+      locked.is_inbox_notification_active = inbox_watcher.is_some();
     }
 
-    let poll_result = poll_once_callback.as_ref()(&config);
-    if let Err(message) = poll_result {
-      // Guard: store last error but keep the watcher alive.
-      let mut locked = match shared_state_for_thread.lock() {
-        Ok(value) => value,
-        Err(_) => return,
-      };
-      locked.last_error_message = Some(message);
-    }
+    loop {
+      if stop_flag.load(Ordering::SeqCst) {
+        return;
+      }
+
+      // NOTE: Changes seen so far are covered by this poll; ones arriving during it trigger the next.
+      while change_receiver.try_recv().is_ok() {}
+      let poll_started_at = Instant::now();
+      let poll_result = poll_once_callback.as_ref()(&config);
+      if let Err(message) = poll_result {
+        // Guard: store last error but keep the watcher alive.
+        let mut locked = match shared_state_for_thread.lock() {
+          Ok(value) => value,
+          Err(_) => return,
+        };
+        locked.last_error_message = Some(message);
+      }
 
-    thread::sleep(config.poll_interval);
+      if inbox_watcher.is_some() {
+        wait_for_inbox_change(&change_receiver, &stop_flag, config.poll_interval);
+      }
+      // Guard: a burst of events (a large copy) still polls at most once per interval.
+      thread::sleep(config.poll_interval.saturating_sub(poll_started_at.elapsed()));
+    }
   });
 
   let mut locked = state.lock().map_err(|_| "Watch folder state lock poisoned".to_string())?;
@@ -159,6 +526,75 @@ pub fn default_poll_interval() -> Duration {
   Duration::from_millis(DEFAULT_WATCH_POLL_INTERVAL_MILLIS)
 }
 
+// NOTE: Recursive, because `.ready` is created inside the bundle (or submitter) folder, not in the inbox itself.
+fn watch_inbox_for_changes(
+  config: &WatchFolderConfig,
+  change_sender: Sender<()>,
+) -> Result<RecommendedWatcher, String> {
+  let inbox_directory_path = config.inbox_directory_path.clone();
+  let is_single_file_intake_enabled = config.is_single_file_intake_enabled;
+  let mut watcher = notify::recommended_watcher(move |event_result: notify::Result<notify::Event>| {
+    let Ok(event) = event_result else {
+      return;
+    };
+    if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+      return;
+    }
+    let is_relevant = event
+      .paths
+      .iter()
+      .any(|path| is_inbox_change_relevant(&inbox_directory_path, is_single_file_intake_enabled, path));
+    if is_relevant {
+      let _ = change_sender.send(());
+    }
+  })
+  .map_err(|error| error.to_string())?;
+  watcher
+    .watch(&config.inbox_directory_path, RecursiveMode::Recursive)
+    .map_err(|error| error.to_string())?;
+  Ok(watcher)
+}
+
+// NOTE: Only changes that can make something ready are forwarded; job roots under the inbox (the default `jobs/`)
+// are written to on every page and must not wake the loop.
+fn is_inbox_change_relevant(inbox_directory_path: &Path, is_single_file_intake_enabled: bool, path: &Path) -> bool {
+  if path.file_name().is_some_and(|name| name == WATCH_READY_FILENAME) {
+    return true;
+  }
+  let Some(parent_directory_path) = path.parent() else {
+    return false;
+  };
+  let is_bundle_location = parent_directory_path == inbox_directory_path
+    || (is_submitter_folder(parent_directory_path) && parent_directory_path.parent() == Some(inbox_directory_path));
+  if !is_bundle_location {
+    return false;
+  }
+  // NOTE: A bundle moved in as a whole (with `.ready` already inside) only reports the folder itself.
+  if path.join(WATCH_READY_FILENAME).exists() {
+    return true;
+  }
+  if parent_directory_path != inbox_directory_path {
+    return false;
+  }
+  is_zip_drop_candidate(path) || (is_single_file_intake_enabled && is_single_file_drop_candidate(path))
+}
+
+// NOTE: Returns on the first change or after the fallback interval; the stop flag is checked every `poll_interval`
+// so stopping the watcher is not delayed by the longer wait.
+fn wait_for_inbox_change(change_receiver: &Receiver<()>, stop_flag: &AtomicBool, poll_interval: Duration) {
+  let deadline = Instant::now() + NOTIFIED_WATCH_FALLBACK_POLL_INTERVAL;
+  while !stop_flag.load(Ordering::SeqCst) {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    if remaining.is_zero() {
+      return;
+    }
+    match change_receiver.recv_timeout(poll_interval.min(remaining)) {
+      Ok(()) | Err(RecvTimeoutError::Disconnected) => return,
+      Err(RecvTimeoutError::Timeout) => {}
+    }
+  }
+}
+
 pub fn list_ready_bundle_directories(inbox_directory_path: &Path) -> Result<Vec<PathBuf>, String> {
   if !inbox_directory_path.exists() {
     // Guard: inbox must exist to be watchable.
@@ -176,13 +612,7 @@ pub fn list_ready_bundle_directories(inbox_directory_path: &Path) -> Result<Vec<
   }
 
   let mut candidates: Vec<PathBuf> = vec![];
-  let entries = fs::read_dir(inbox_directory_path).map_err(|error| error.to_string())?;
-  for entry_result in entries {
-    let entry = entry_result.map_err(|error| error.to_string())?;
-    let path = entry.path();
-    if !path.is_dir() {
-      continue;
-    }
+  for path in list_bundle_directories(inbox_directory_path)? {
     if !path.join(WATCH_READY_FILENAME).exists() {
       continue;
     }
@@ -195,10 +625,39 @@ pub fn list_ready_bundle_directories(inbox_directory_path: &Path) -> Result<Vec<
     candidates.push(path);
   }
 
-  candidates.sort();
+  // NOTE: Natural order, so "scan_2" is processed before "scan_10".
+  sort_paths_naturally(&mut candidates);
   Ok(candidates)
 }
 
+// NOTE: Folders directly in the inbox, and those inside `@<submitter>` folders instead of the submitter folders.
+fn list_bundle_directories(inbox_directory_path: &Path) -> Result<Vec<PathBuf>, String> {
+  let mut bundle_directories: Vec<PathBuf> = vec![];
+  let entries = fs::read_dir(inbox_directory_path).map_err(|error| error.to_string())?;
+  for entry_result in entries {
+    let entry = entry_result.map_err(|error| error.to_string())?;
+    let path = entry.path();
+    if !path.is_dir() {
+      continue;
+    }
+    if !is_submitter_folder(&path) {
+      bundle_directories.push(path);
+      continue;
+    }
+    // Guard: one unreadable submitter folder must not stop intake for everyone else.
+    let Ok(submitter_entries) = fs::read_dir(&path) else {
+      continue;
+    };
+    bundle_directories.extend(
+      submitter_entries
+        .filter_map(|submitter_entry| submitter_entry.ok())
+        .map(|submitter_entry| submitter_entry.path())
+        .filter(|bundle_directory_path| bundle_directory_path.is_dir()),
+    );
+  }
+  Ok(bundle_directories)
+}
+
 pub fn try_lock_bundle_for_processing(bundle_directory_path: &Path) -> Result<bool, String> {
   let processing_marker_path = bundle_directory_path.join(WATCH_PROCESSING_FILENAME);
   let create_result = OpenOptions::new()
@@ -229,6 +688,16 @@ pub fn mark_bundle_processed(bundle_directory_path: &Path) -> Result<(), String>
   Ok(())
 }
 
+pub fn mark_bundle_duplicate(bundle_directory_path: &Path, original_job_id: &str) -> Result<(), String> {
+  fs::write(bundle_directory_path.join(WATCH_DUPLICATE_OF_FILENAME), original_job_id).map_err(|error| error.to_string())?;
+  mark_bundle_processed(bundle_directory_path)
+}
+
+// NOTE: Hands an interrupted bundle back to the inbox so the next watcher run picks it up again.
+pub fn release_bundle_processing_lock(bundle_directory_path: &Path) {
+  let _ = fs::remove_file(bundle_directory_path.join(WATCH_PROCESSING_FILENAME));
+}
+
 pub fn mark_bundle_failed(bundle_directory_path: &Path, error_message: &str) -> Result<(), String> {
   let failed_path = bundle_directory_path.join(WATCH_FAILED_FILENAME);
   fs::write(failed_path, error_message).map_err(|error| error.to_string())?;
@@ -240,3 +709,410 @@ pub fn mark_bundle_failed(bundle_directory_path: &Path, error_message: &str) ->
   Ok(())
 }
 
+fn is_single_file_drop_candidate(path: &Path) -> bool {
+  let is_hidden = path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .map(|name| name.starts_with('.') || name.starts_with("~$"))
+    .unwrap_or(true);
+  if is_hidden || !path.is_file() {
+    return false;
+  }
+  path
+    .extension()
+    .and_then(|extension| extension.to_str())
+    .map(|extension| {
+      SINGLE_FILE_DROP_EXTENSIONS
+        .iter()
+        .any(|supported| extension.eq_ignore_ascii_case(supported))
+    })
+    .unwrap_or(false)
+}
+
+fn list_settled_single_file_drops(
+  inbox_directory_path: &Path,
+  observations: &mut SingleFileDropObservations,
+) -> Result<Vec<PathBuf>, String> {
+  let mut settled: Vec<PathBuf> = vec![];
+  let mut seen_paths: Vec<PathBuf> = vec![];
+  let entries = fs::read_dir(inbox_directory_path).map_err(|error| error.to_string())?;
+  for entry_result in entries {
+    let path = entry_result.map_err(|error| error.to_string())?.path();
+    if !is_single_file_drop_candidate(&path) {
+      continue;
+    }
+    let Ok(metadata) = fs::metadata(&path) else {
+      continue;
+    };
+    seen_paths.push(path.clone());
+    let size_and_modified = (metadata.len(), metadata.modified().ok());
+    let unchanged_since = match observations.get(&path) {
+      Some((size, modified, since)) if (*size, *modified) == size_and_modified => *since,
+      _ => Instant::now(),
+    };
+    observations.insert(path.clone(), (size_and_modified.0, size_and_modified.1, unchanged_since));
+    // Guard: an empty or still-growing file is a copy in progress.
+    if size_and_modified.0 > 0 && unchanged_since.elapsed() >= SINGLE_FILE_DROP_STABILITY_DELAY {
+      settled.push(path);
+    }
+  }
+  observations.retain(|path, _| seen_paths.contains(path));
+  sort_paths_naturally(&mut settled);
+  Ok(settled)
+}
+
+fn create_single_file_bundle_directory(inbox_directory_path: &Path, file_path: &Path) -> Result<PathBuf, String> {
+  let stem = file_path
+    .file_stem()
+    .and_then(|stem| stem.to_str())
+    .filter(|stem| !stem.trim().is_empty())
+    .unwrap_or("document")
+    .trim()
+    .to_string();
+  // NOTE: Scanners reuse names like "scan.pdf"; suffix instead of merging into an earlier bundle.
+  for attempt in 0..1000 {
+    let directory_name = if attempt == 0 {
+      stem.clone()
+    } else {
+      format!("{stem}_{attempt}")
+    };
+    let bundle_directory_path = inbox_directory_path.join(directory_name);
+    match fs::create_dir(&bundle_directory_path) {
+      Ok(()) => return Ok(bundle_directory_path),
+      Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => continue,
+      Err(error) => return Err(error.to_string()),
+    }
+  }
+  Err(format!("No free bundle directory name for {}", file_path.display()))
+}
+
+// NOTE: Moves each settled standalone file into its own bundle directory and marks it `.ready`, so the regular
+// bundle intake picks it up on this or the next poll. Returns the created bundle directories.
+pub fn wrap_settled_single_file_drops(
+  inbox_directory_path: &Path,
+  observations: &mut SingleFileDropObservations,
+) -> Result<Vec<PathBuf>, String> {
+  let mut wrapped_bundle_directories: Vec<PathBuf> = vec![];
+  for file_path in list_settled_single_file_drops(inbox_directory_path, observations)? {
+    let Some(file_name) = file_path.file_name() else {
+      continue;
+    };
+    let bundle_directory_path = create_single_file_bundle_directory(inbox_directory_path, &file_path)?;
+    if fs::rename(&file_path, bundle_directory_path.join(file_name)).is_err() {
+      // Guard: the writer may still hold the file open (Windows); retry on a later poll.
+      let _ = fs::remove_dir(&bundle_directory_path);
+      continue;
+    }
+    observations.remove(&file_path);
+    // NOTE: `.ready` goes last, so the bundle intake never sees a directory before its file has moved in.
+    fs::write(bundle_directory_path.join(WATCH_READY_FILENAME), "").map_err(|error| error.to_string())?;
+    wrapped_bundle_directories.push(bundle_directory_path);
+  }
+  Ok(wrapped_bundle_directories)
+}
+
+fn is_zip_drop_candidate(path: &Path) -> bool {
+  let is_hidden = path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .map(|name| name.starts_with('.') || name.starts_with("~$"))
+    .unwrap_or(true);
+  !is_hidden
+    && path.is_file()
+    && path
+      .extension()
+      .and_then(|extension| extension.to_str())
+      .is_some_and(|extension| extension.eq_ignore_ascii_case(ZIP_DROP_EXTENSION))
+}
+
+// NOTE: A zip is settled once its size is unchanged since the previous poll; its central directory is written last,
+// so a zip still being copied usually cannot be opened at all.
+fn list_settled_zip_drops(
+  inbox_directory_path: &Path,
+  observations: &mut HashMap<PathBuf, u64>,
+) -> Result<Vec<PathBuf>, String> {
+  let mut settled: Vec<PathBuf> = vec![];
+  let mut seen_paths: Vec<PathBuf> = vec![];
+  let entries = fs::read_dir(inbox_directory_path).map_err(|error| error.to_string())?;
+  for entry_result in entries {
+    let path = entry_result.map_err(|error| error.to_string())?.path();
+    if !is_zip_drop_candidate(&path) {
+      continue;
+    }
+    let Ok(metadata) = fs::metadata(&path) else {
+      continue;
+    };
+    seen_paths.push(path.clone());
+    let previous_size = observations.insert(path.clone(), metadata.len());
+    if metadata.len() > 0 && previous_size == Some(metadata.len()) {
+      settled.push(path);
+    }
+  }
+  observations.retain(|path, _| seen_paths.contains(path));
+  sort_paths_naturally(&mut settled);
+  Ok(settled)
+}
+
+// NOTE: Entries that would land outside the bundle (`../`, absolute paths) and hidden entries (`__MACOSX/._*`,
+// `.DS_Store`, or a `.ready` packed by mistake) are skipped. Stops at the `watch_zip_max_*` limits, so a zip bomb
+// fails instead of filling the disk.
+fn extract_zip_into_directory(
+  zip_file: File,
+  destination_directory_path: &Path,
+  limits: &RuntimeLimits,
+) -> Result<(), String> {
+  let mut archive = ZipArchive::new(zip_file).map_err(|error| error.to_string())?;
+  if archive.len() > limits.watch_zip_max_entries {
+    return Err(format!(
+      "{} entries exceed the limit of {} (watch_zip_max_entries)",
+      archive.len(),
+      limits.watch_zip_max_entries
+    ));
+  }
+  let max_extracted_bytes = limits.watch_zip_max_extracted_bytes;
+  let size_limit_error =
+    || format!("Extracted contents exceed the limit of {max_extracted_bytes} bytes (watch_zip_max_extracted_bytes)");
+  let mut extracted_bytes: u64 = 0;
+  for index in 0..archive.len() {
+    let mut entry = archive.by_index(index).map_err(|error| error.to_string())?;
+    let Some(relative_path) = entry.enclosed_name() else {
+      continue;
+    };
+    let is_hidden = relative_path.components().any(|component| {
+      let name = component.as_os_str().to_string_lossy();
+      name.starts_with('.') || name == "__MACOSX"
+    });
+    if is_hidden {
+      continue;
+    }
+    let destination_path = destination_directory_path.join(&relative_path);
+    if entry.is_dir() {
+      fs::create_dir_all(&destination_path).map_err(|error| error.to_string())?;
+      continue;
+    }
+    let remaining_bytes = max_extracted_bytes.saturating_sub(extracted_bytes);
+    if entry.size() > remaining_bytes {
+      // Guard: refuse from the declared size before writing anything.
+      return Err(size_limit_error());
+    }
+    if let Some(parent_directory_path) = destination_path.parent() {
+      fs::create_dir_all(parent_directory_path).map_err(|error| error.to_string())?;
+    }
+    let mut destination_file = File::create(&destination_path).map_err(|error| error.to_string())?;
+    extracted_bytes += io::copy(&mut entry.by_ref().take(remaining_bytes + 1), &mut destination_file)
+      .map_err(|error| format!("{}: {error}", relative_path.display()))?;
+    if extracted_bytes > max_extracted_bytes {
+      return Err(size_limit_error());
+    }
+  }
+  Ok(())
+}
+
+fn list_zip_extraction_staging_directories(inbox_directory_path: &Path) -> Result<Vec<PathBuf>, String> {
+  let entries = fs::read_dir(inbox_directory_path).map_err(|error| error.to_string())?;
+  let mut staging_directories: Vec<PathBuf> = vec![];
+  for entry_result in entries {
+    let path = entry_result.map_err(|error| error.to_string())?.path();
+    let is_staging_directory = path
+      .file_name()
+      .is_some_and(|name| name.to_string_lossy().starts_with(ZIP_EXTRACTION_STAGING_PREFIX));
+    if is_staging_directory && path.is_dir() {
+      staging_directories.push(path);
+    }
+  }
+  Ok(staging_directories)
+}
+
+// NOTE: Runs on its own thread. The extracted files are moved into the bundle folder and `.ready` goes last, so the
+// bundle intake never sees a half-extracted directory. An archive that cannot be read, or that exceeds the limits, is
+// moved into its bundle folder and marked `.failed` instead, and whatever was extracted is removed.
+fn extract_staged_zip_drop(inbox_directory_path: &Path, staging_directory_path: &Path) -> Result<(), String> {
+  let zip_file_name = staging_directory_path
+    .file_name()
+    .map(|name| name.to_string_lossy().to_string())
+    .and_then(|name| name.strip_prefix(ZIP_EXTRACTION_STAGING_PREFIX).map(str::to_string))
+    .ok_or_else(|| format!("Not a zip staging folder: {}", staging_directory_path.display()))?;
+  let archive_path = staging_directory_path.join(ZIP_EXTRACTION_ARCHIVE_FILENAME);
+  let contents_directory_path = staging_directory_path.join(ZIP_EXTRACTION_CONTENTS_DIRECTORY_NAME);
+  // NOTE: Leftovers of an extraction cut off by closing the app; it starts over.
+  let _ = fs::remove_dir_all(&contents_directory_path);
+  fs::create_dir_all(&contents_directory_path).map_err(|error| error.to_string())?;
+
+  let limits = current_runtime_limits();
+  let extraction_result = File::open(&archive_path)
+    .map_err(|error| error.to_string())
+    .and_then(|zip_file| extract_zip_into_directory(zip_file, &contents_directory_path, &limits));
+  if extraction_result.is_ok() {
+    // NOTE: Removed before anything moves, so a later retry of this staging folder can never ingest it twice.
+    fs::remove_file(&archive_path).map_err(|error| error.to_string())?;
+  }
+  let bundle_directory_path = create_single_file_bundle_directory(inbox_directory_path, Path::new(&zip_file_name))?;
+  match extraction_result {
+    Ok(()) => {
+      for entry_result in fs::read_dir(&contents_directory_path).map_err(|error| error.to_string())? {
+        let entry = entry_result.map_err(|error| error.to_string())?;
+        fs::rename(entry.path(), bundle_directory_path.join(entry.file_name())).map_err(|error| error.to_string())?;
+      }
+      fs::write(bundle_directory_path.join(WATCH_READY_FILENAME), "").map_err(|error| error.to_string())?;
+    }
+    Err(error) => {
+      let _ = fs::rename(&archive_path, bundle_directory_path.join(&zip_file_name));
+      mark_bundle_failed(&bundle_directory_path, &format!("Failed to extract {zip_file_name}: {error}"))?;
+    }
+  }
+  fs::remove_dir_all(staging_directory_path).map_err(|error| error.to_string())
+}
+
+// NOTE: Moves each settled `.zip` into a staging folder and extracts it on a background thread into its own bundle
+// folder (named after the archive), so a large archive does not hold up the other bundles. Staging folders left by an
+// extraction the app did not finish are picked up again. Returns the staging folders whose extraction started.
+pub fn extract_settled_zip_drops(
+  inbox_directory_path: &Path,
+  observations: &mut ZipDropObservations,
+) -> Result<Vec<PathBuf>, String> {
+  for zip_file_path in list_settled_zip_drops(inbox_directory_path, &mut observations.size_by_path)? {
+    let Some(file_name) = zip_file_path.file_name() else {
+      continue;
+    };
+    let staging_directory_path =
+      inbox_directory_path.join(format!("{ZIP_EXTRACTION_STAGING_PREFIX}{}", file_name.to_string_lossy()));
+    if fs::create_dir(&staging_directory_path).is_err() {
+      // Guard: an archive of the same name is still being extracted; take this one once it is done.
+      continue;
+    }
+    if fs::rename(&zip_file_path, staging_directory_path.join(ZIP_EXTRACTION_ARCHIVE_FILENAME)).is_err() {
+      // Guard: the writer may still hold the file open (Windows); retry on a later poll.
+      let _ = fs::remove_dir(&staging_directory_path);
+      continue;
+    }
+    observations.size_by_path.remove(&zip_file_path);
+  }
+
+  let mut started_staging_directories: Vec<PathBuf> = vec![];
+  let mut extracting_staging_directories = observations
+    .extracting_staging_directories
+    .lock()
+    .map_err(|_| "Zip extraction state lock poisoned".to_string())?;
+  for staging_directory_path in list_zip_extraction_staging_directories(inbox_directory_path)? {
+    if !staging_directory_path.join(ZIP_EXTRACTION_ARCHIVE_FILENAME).is_file()
+      || !extracting_staging_directories.insert(staging_directory_path.clone())
+    {
+      continue;
+    }
+    let inbox_directory_path = inbox_directory_path.to_path_buf();
+    let thread_staging_directory_path = staging_directory_path.clone();
+    let extracting_registry = Arc::clone(&observations.extracting_staging_directories);
+    thread::spawn(move || {
+      // NOTE: On an error before the archive is used up, the staging folder stays and is retried on a later poll.
+      let _ = extract_staged_zip_drop(&inbox_directory_path, &thread_staging_directory_path);
+      if let Ok(mut locked) = extracting_registry.lock() {
+        locked.remove(&thread_staging_directory_path);
+      }
+    });
+    started_staging_directories.push(staging_directory_path);
+  }
+  Ok(started_staging_directories)
+}
+
+// NOTE: Hidden entries (markers, `.DS_Store`) are left out so writing a marker does not count as a change.
+fn snapshot_bundle_contents(bundle_directory_path: &Path) -> Option<(usize, u64, Option<SystemTime>)> {
+  let (mut file_count, mut total_bytes, mut newest_modified) = (0usize, 0u64, None);
+  let mut pending_directory_paths = vec![bundle_directory_path.to_path_buf()];
+  while let Some(directory_path) = pending_directory_paths.pop() {
+    for entry in fs::read_dir(&directory_path).ok()?.flatten() {
+      if entry.file_name().to_string_lossy().starts_with('.') {
+        continue;
+      }
+      let Ok(metadata) = entry.metadata() else {
+        continue;
+      };
+      if metadata.is_dir() {
+        pending_directory_paths.push(entry.path());
+        continue;
+      }
+      file_count += 1;
+      total_bytes += metadata.len();
+      newest_modified = newest_modified.max(metadata.modified().ok());
+    }
+  }
+  Some((file_count, total_bytes, newest_modified))
+}
+
+// NOTE: Quiescence mode. Compares what each poll sees rather than modification times alone: copies (SMB, Explorer)
+// keep the source files' old times, so only an unchanged file count and size over the whole period means the upload
+// is done. Returns the folders marked `.ready`.
+pub fn mark_quiescent_bundles_ready(
+  inbox_directory_path: &Path,
+  jobs_root_directory_path: &Path,
+  quiescence_period: Duration,
+  observations: &mut BundleQuiescenceObservations,
+) -> Result<Vec<PathBuf>, String> {
+  let mut marked_bundle_directories: Vec<PathBuf> = vec![];
+  let mut seen_paths: Vec<PathBuf> = vec![];
+  for bundle_directory_path in list_bundle_directories(inbox_directory_path)? {
+    let is_hidden = bundle_directory_path
+      .file_name()
+      .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+    let has_marker = [WATCH_READY_FILENAME, WATCH_PROCESSING_FILENAME, WATCH_PROCESSED_FILENAME, WATCH_FAILED_FILENAME]
+      .iter()
+      .any(|marker_filename| bundle_directory_path.join(marker_filename).exists());
+    // Guard: the default jobs root (`inbox/jobs`) sits among the bundles; its job roots change on every page.
+    if is_hidden || has_marker || jobs_root_directory_path.starts_with(&bundle_directory_path) {
+      continue;
+    }
+    let Some(contents) = snapshot_bundle_contents(&bundle_directory_path) else {
+      continue;
+    };
+    seen_paths.push(bundle_directory_path.clone());
+    let unchanged_since = match observations.get(&bundle_directory_path) {
+      Some((previous_contents, since)) if *previous_contents == contents => *since,
+      _ => Instant::now(),
+    };
+    observations.insert(bundle_directory_path.clone(), (contents, unchanged_since));
+    // Guard: an empty folder was usually just created and is about to be filled.
+    if contents.0 > 0 && unchanged_since.elapsed() >= quiescence_period {
+      fs::write(bundle_directory_path.join(WATCH_READY_FILENAME), "").map_err(|error| error.to_string())?;
+      observations.remove(&bundle_directory_path);
+      marked_bundle_directories.push(bundle_directory_path);
+    }
+  }
+  observations.retain(|path, _| seen_paths.contains(path));
+  Ok(marked_bundle_directories)
+}
+
+fn is_submitter_folder(path: &Path) -> bool {
+  path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .map(|name| name.len() > SUBMITTER_FOLDER_PREFIX.len() && name.starts_with(SUBMITTER_FOLDER_PREFIX))
+    .unwrap_or(false)
+}
+
+// NOTE: Submitters end up in job state, reports and notifications; keep them to one short line.
+pub fn sanitize_submitter(raw: &str) -> Option<String> {
+  let first_line = raw.lines().next().unwrap_or("").trim();
+  if first_line.is_empty() {
+    return None;
+  }
+  Some(first_line.chars().take(MAX_SUBMITTER_CHARACTERS).collect())
+}
+
+// NOTE: `inbox/@alice/scan_01` belongs to "alice"; bundles directly in the inbox have no folder submitter.
+pub fn submitter_of_bundle_folder(inbox_directory_path: &Path, bundle_directory_path: &Path) -> Option<String> {
+  let parent_directory_path = bundle_directory_path.parent()?;
+  if parent_directory_path == inbox_directory_path || !is_submitter_folder(parent_directory_path) {
+    return None;
+  }
+  let folder_name = parent_directory_path.file_name()?.to_str()?;
+  sanitize_submitter(folder_name.strip_prefix(SUBMITTER_FOLDER_PREFIX)?)
+}
+
+pub fn restart_watch_folder(state: &SharedWatchFolderRuntimeState) -> Result<(), String> {
+  let (config, poll_once_callback) = state
+    .lock()
+    .map_err(|_| "Watch folder state lock poisoned".to_string())?
+    .last_start
+    .clone()
+    .ok_or_else(|| "Watch folder has not been started since launch; start it once from the GUI.".to_string())?;
+  start_watch_folder(state, config, poll_once_callback)
+}
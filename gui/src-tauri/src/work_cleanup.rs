@@ -0,0 +1,84 @@
+/*!
+Responsibility:
+- Decide when a job's `output/work/` page renders may be deleted (on success, after N days, or never).
+- Delete them, keeping settings-tuning trials, and report what was freed.
+*/
+
+use std::{
+  fs,
+  path::Path,
+  time::{Duration, SystemTime},
+};
+
+use serde::{Deserialize, Serialize};
+
+// NOTE: Tuning trials keep their own report and sample pages; `tune_settings` owns that directory.
+const PRESERVED_WORK_DIRECTORY_NAMES: &[&str] = &["tuning"];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkCleanupPolicy {
+  #[default]
+  KeepAlways,
+  // NOTE: Only after a successful, uncancelled run; failed pages are re-rendered by a retry anyway.
+  DeleteOnSuccess,
+  // NOTE: Renders older than `work_retention_days` are removed whenever a run of the job finishes.
+  KeepDays,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct WorkCleanupSummary {
+  pub deleted_file_count: usize,
+  pub freed_bytes: u64,
+}
+
+pub fn validate_work_cleanup_policy(policy: WorkCleanupPolicy, retention_days: Option<u32>) -> Result<(), String> {
+  match (policy, retention_days) {
+    (WorkCleanupPolicy::KeepDays, None | Some(0)) => {
+      Err("work_cleanup_policy \"keep_days\" requires work_retention_days > 0.".to_string())
+    }
+    _ => Ok(()),
+  }
+}
+
+pub fn retention_days_to_min_age(retention_days: u32) -> Duration {
+  Duration::from_secs(u64::from(retention_days).saturating_mul(24 * 3600))
+}
+
+// NOTE: With a minimum age, only files last modified at least that long ago are removed.
+pub fn clean_work_directory(work_directory_path: &Path, min_age: Option<Duration>) -> Result<WorkCleanupSummary, String> {
+  let mut summary = WorkCleanupSummary::default();
+  if !work_directory_path.exists() {
+    return Ok(summary);
+  }
+  let now = SystemTime::now();
+  let walker = walkdir::WalkDir::new(work_directory_path)
+    .min_depth(1)
+    .into_iter()
+    .filter_entry(|entry| {
+      !(entry.file_type().is_dir()
+        && entry.depth() == 1
+        && PRESERVED_WORK_DIRECTORY_NAMES.contains(&entry.file_name().to_string_lossy().as_ref()))
+    });
+  for entry in walker {
+    let entry = entry.map_err(|error| error.to_string())?;
+    if !entry.file_type().is_file() {
+      continue;
+    }
+    let metadata = entry.metadata().map_err(|error| error.to_string())?;
+    if let Some(min_age) = min_age {
+      let age = metadata
+        .modified()
+        .ok()
+        .and_then(|modified| now.duration_since(modified).ok())
+        .unwrap_or_default();
+      if age < min_age {
+        continue;
+      }
+    }
+    fs::remove_file(entry.path()).map_err(|error| format!("Failed to delete {}: {error}", entry.path().display()))?;
+    summary.deleted_file_count += 1;
+    summary.freed_bytes += metadata.len();
+  }
+  Ok(summary)
+}
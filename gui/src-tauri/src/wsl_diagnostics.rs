@@ -0,0 +1,224 @@
+/*!
+Responsibility:
+- Diagnose the Windows GPU path (WSL2 → distro → NVIDIA driver → Docker Desktop integration) step by step.
+- Attach a concrete remediation to every failed check so "GPU probe failed" becomes actionable.
+*/
+
+use std::process::Command;
+
+use serde::Serialize;
+
+use crate::probe_timeout::{output_with_timeout, PROBE_COMMAND_TIMEOUT};
+
+const DOCKER_DESKTOP_WSL_DISTRO_NAME: &str = "docker-desktop";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WslDiagnosticStatus {
+  Ok,
+  Warning,
+  Error,
+  Skipped,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WslDiagnosticCheck {
+  pub name: String,
+  pub status: WslDiagnosticStatus,
+  pub detail: String,
+  pub remediation: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct WslDistro {
+  name: String,
+  is_default: bool,
+  // NOTE: Only an explicit English "Stopped" counts; other (possibly localized) states are not flagged.
+  is_stopped: bool,
+  version: u32,
+}
+
+fn make_check(name: &str, status: WslDiagnosticStatus, detail: String, remediation: Option<&str>) -> WslDiagnosticCheck {
+  WslDiagnosticCheck {
+    name: name.to_string(),
+    status,
+    detail,
+    remediation: remediation.map(str::to_string),
+  }
+}
+
+// NOTE: wsl.exe writes UTF-16LE to pipes, while Linux commands run through it write UTF-8.
+fn decode_wsl_output(bytes: &[u8]) -> String {
+  // Guard: ASCII text in UTF-16LE has a zero high byte in (almost) every code unit.
+  let high_byte_zero_count = bytes.iter().skip(1).step_by(2).filter(|byte| **byte == 0).count();
+  let is_utf16 = bytes.len() >= 2 && high_byte_zero_count * 4 >= bytes.len();
+  if !is_utf16 {
+    return String::from_utf8_lossy(bytes).to_string();
+  }
+  let units: Vec<u16> = bytes
+    .chunks_exact(2)
+    .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+    .collect();
+  String::from_utf16_lossy(&units).replace('\u{feff}', "")
+}
+
+fn run_command(program: &str, arguments: &[&str]) -> Result<(bool, String), String> {
+  let output = output_with_timeout(Command::new(program).args(arguments), PROBE_COMMAND_TIMEOUT)?;
+  let mut text = decode_wsl_output(&output.stdout);
+  text.push_str(&decode_wsl_output(&output.stderr));
+  Ok((output.status.success(), text.trim().to_string()))
+}
+
+// NOTE: `wsl -l -v` headers are localized, so only the data rows (`[*] NAME STATE VERSION`) are parsed.
+fn parse_wsl_distros(listing: &str) -> Vec<WslDistro> {
+  listing
+    .lines()
+    .filter_map(|line| {
+      let is_default = line.trim_start().starts_with('*');
+      let tokens: Vec<&str> = line.trim_start_matches([' ', '*']).split_whitespace().collect();
+      let (name, state, version) = match tokens.as_slice() {
+        [name, state, version] => (*name, *state, *version),
+        _ => return None,
+      };
+      let version = version.parse::<u32>().ok()?;
+      Some(WslDistro {
+        name: name.to_string(),
+        is_default,
+        is_stopped: state.eq_ignore_ascii_case("stopped"),
+        version,
+      })
+    })
+    .collect()
+}
+
+fn check_host_nvidia_driver() -> WslDiagnosticCheck {
+  let name = "NVIDIA driver (Windows)";
+  match run_command("nvidia-smi", &["-L"]) {
+    Ok((true, output)) => make_check(name, WslDiagnosticStatus::Ok, output, None),
+    Ok((false, output)) => make_check(
+      name,
+      WslDiagnosticStatus::Error,
+      output,
+      Some("nvidia-smi failed on Windows. Reinstall the latest NVIDIA Game Ready/Studio driver and reboot."),
+    ),
+    Err(error) => make_check(
+      name,
+      WslDiagnosticStatus::Error,
+      error,
+      Some("nvidia-smi was not found. Install the NVIDIA driver for Windows (it also provides GPU support inside WSL2)."),
+    ),
+  }
+}
+
+fn check_gpu_inside_wsl(default_distro: &WslDistro) -> WslDiagnosticCheck {
+  let name = "GPU inside WSL";
+  match run_command("wsl", &["-d", &default_distro.name, "-e", "nvidia-smi", "-L"]) {
+    Ok((true, output)) => make_check(name, WslDiagnosticStatus::Ok, output, None),
+    Ok((false, output)) | Err(output) => make_check(
+      name,
+      WslDiagnosticStatus::Error,
+      output,
+      Some(
+        "The GPU is not visible inside WSL. Update the Windows NVIDIA driver, run `wsl --update`, then `wsl --shutdown`. Do not install a Linux NVIDIA driver inside the distro.",
+      ),
+    ),
+  }
+}
+
+fn check_docker_desktop_integration(distros: &[WslDistro]) -> WslDiagnosticCheck {
+  let name = "Docker Desktop WSL integration";
+  match distros.iter().find(|distro| distro.name == DOCKER_DESKTOP_WSL_DISTRO_NAME) {
+    None => make_check(
+      name,
+      WslDiagnosticStatus::Error,
+      format!("WSL distro `{DOCKER_DESKTOP_WSL_DISTRO_NAME}` not found."),
+      Some("Enable Docker Desktop > Settings > General > \"Use the WSL 2 based engine\" and restart Docker Desktop."),
+    ),
+    Some(distro) if distro.is_stopped => make_check(
+      name,
+      WslDiagnosticStatus::Warning,
+      format!("`{DOCKER_DESKTOP_WSL_DISTRO_NAME}` is installed but stopped."),
+      Some("Start Docker Desktop and wait until the engine reports \"running\"."),
+    ),
+    Some(distro) if distro.version != 2 => make_check(
+      name,
+      WslDiagnosticStatus::Error,
+      format!("`{DOCKER_DESKTOP_WSL_DISTRO_NAME}` runs on WSL {}.", distro.version),
+      Some("Docker Desktop must use WSL 2. Run `wsl --set-default-version 2` and reinstall Docker Desktop."),
+    ),
+    Some(_) => make_check(name, WslDiagnosticStatus::Ok, "docker-desktop distro is running on WSL 2.".to_string(), None),
+  }
+}
+
+pub fn run_wsl_diagnostics() -> Vec<WslDiagnosticCheck> {
+  if !cfg!(windows) {
+    return vec![make_check(
+      "WSL2",
+      WslDiagnosticStatus::Skipped,
+      "WSL diagnostics only apply to Windows hosts.".to_string(),
+      None,
+    )];
+  }
+
+  let mut checks: Vec<WslDiagnosticCheck> = vec![check_host_nvidia_driver()];
+  let listing = match run_command("wsl", &["--list", "--verbose"]) {
+    Ok((true, listing)) => listing,
+    Ok((false, output)) | Err(output) => {
+      checks.push(make_check(
+        "WSL2",
+        WslDiagnosticStatus::Error,
+        output,
+        Some("WSL is not installed or disabled. Run `wsl --install` in an elevated PowerShell, then reboot."),
+      ));
+      return checks;
+    }
+  };
+
+  let distros = parse_wsl_distros(&listing);
+  let user_distros: Vec<&WslDistro> = distros
+    .iter()
+    .filter(|distro| !distro.name.starts_with(DOCKER_DESKTOP_WSL_DISTRO_NAME))
+    .collect();
+  let default_distro = user_distros
+    .iter()
+    .find(|distro| distro.is_default)
+    .or_else(|| user_distros.first())
+    .copied();
+  match default_distro {
+    None => checks.push(make_check(
+      "WSL distro",
+      WslDiagnosticStatus::Warning,
+      "No Linux distro is installed (only Docker Desktop's internal distros).".to_string(),
+      Some("Install one with `wsl --install -d Ubuntu` so GPU support can be verified inside WSL."),
+    )),
+    Some(distro) if distro.version != 2 => checks.push(make_check(
+      "WSL distro",
+      WslDiagnosticStatus::Error,
+      format!("Distro `{}` runs on WSL {}.", distro.name, distro.version),
+      Some("Convert it with `wsl --set-version <distro> 2` and set `wsl --set-default-version 2`."),
+    )),
+    Some(distro) => {
+      checks.push(make_check(
+        "WSL distro",
+        WslDiagnosticStatus::Ok,
+        format!("Distro `{}` runs on WSL 2.", distro.name),
+        None,
+      ));
+      checks.push(check_gpu_inside_wsl(distro));
+    }
+  }
+  checks.push(check_docker_desktop_integration(&distros));
+  checks
+}
+
+pub fn describe_failed_wsl_checks(checks: &[WslDiagnosticCheck]) -> Option<String> {
+  let lines: Vec<String> = checks
+    .iter()
+    .filter(|check| matches!(check.status, WslDiagnosticStatus::Error | WslDiagnosticStatus::Warning))
+    .map(|check| format!("- {}: {}", check.name, check.remediation.as_deref().unwrap_or(&check.detail)))
+    .collect();
+  if lines.is_empty() {
+    return None;
+  }
+  Some(lines.join("\n"))
+}
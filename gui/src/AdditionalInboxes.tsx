@@ -0,0 +1,205 @@
+/**
+ * Responsibility:
+ * - Show the watch folders beyond the default one (each with its own jobs root, priority and preset).
+ * - Start, stop and remove them; the default watcher keeps its own controls in the automation card.
+ */
+import { useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+
+export type WatchFolderStatus = {
+  watch_id: string;
+  priority: number;
+  preset_name: string | null;
+  bundle_quiescence_seconds: number | null;
+  is_running: boolean;
+  inbox_directory_path: string | null;
+  jobs_root_directory_path: string | null;
+  last_error_message: string | null;
+  intake_pause_reason: string | null;
+  operator_pause_reason: string | null;
+  last_retention_cleanup_message: string | null;
+  last_duplicate_bundle_message: string | null;
+  is_inbox_notification_active: boolean;
+};
+
+export const PRIMARY_WATCH_ID = "default";
+
+type AdditionalInboxesProps = {
+  statuses: WatchFolderStatus[];
+  isDisabled: boolean;
+  onLogLine: (line: string) => void;
+  onError: (message: string | null) => void;
+};
+
+export function AdditionalInboxes(props: AdditionalInboxesProps) {
+  const { statuses, isDisabled, onLogLine, onError } = props;
+
+  const [watchId, setWatchId] = useState<string>("");
+  const [inboxDirectoryPath, setInboxDirectoryPath] = useState<string>("");
+  const [jobsRootDirectoryPath, setJobsRootDirectoryPath] = useState<string>("");
+  const [priority, setPriority] = useState<number>(0);
+  const [presetName, setPresetName] = useState<string>("");
+  // NOTE: Empty keeps the `.ready` contract for this inbox.
+  const [bundleQuiescenceSeconds, setBundleQuiescenceSeconds] = useState<string>("");
+  const [isAutoRunEnabled, setIsAutoRunEnabled] = useState<boolean>(false);
+
+  async function runWatchFolderCommand(command: string, args: Record<string, unknown>, doneMessage: string) {
+    try {
+      onError(null);
+      await invoke(command, args);
+      onLogLine(`[watch-folder] ${doneMessage}`);
+    } catch (error) {
+      const errorMessage = String(error);
+      onError(errorMessage);
+      onLogLine(`[watch-folder] ERROR: ${errorMessage}`);
+    }
+  }
+
+  async function handlePickInboxDirectory(): Promise<void> {
+    try {
+      const selectedDirectoryPath = await invoke<string | null>("pick_directory");
+      if (selectedDirectoryPath !== null) {
+        setInboxDirectoryPath(selectedDirectoryPath);
+      }
+    } catch (error) {
+      onError(String(error));
+    }
+  }
+
+  async function handleStart(): Promise<void> {
+    if (watchId.trim() === "" || inboxDirectoryPath.trim() === "") {
+      onError("Enter an id and select an inbox directory first.");
+      return;
+    }
+    await runWatchFolderCommand(
+      "start_watch_folder",
+      {
+        watchId: watchId.trim(),
+        inboxDirectoryPath: inboxDirectoryPath.trim(),
+        jobsRootDirectoryPath: jobsRootDirectoryPath.trim() === "" ? null : jobsRootDirectoryPath.trim(),
+        autoRun: isAutoRunEnabled,
+        autoPackage: false,
+        acceptSingleFiles: false,
+        priority,
+        presetName: presetName.trim() === "" ? null : presetName.trim(),
+        bundleQuiescenceSeconds: bundleQuiescenceSeconds.trim() === "" ? null : Math.floor(Number(bundleQuiescenceSeconds))
+      },
+      `started ${watchId.trim()}`
+    );
+  }
+
+  const additionalStatuses = statuses.filter((status) => status.watch_id !== PRIMARY_WATCH_ID);
+
+  return (
+    <div>
+      {additionalStatuses.map((status) => (
+        <div className="row" key={status.watch_id}>
+          <div>
+            <div>
+              <b>{status.watch_id}</b> · priority {status.priority}
+              {status.preset_name ? ` · preset ${status.preset_name}` : ""}
+              {status.bundle_quiescence_seconds != null ? ` · ready after ${status.bundle_quiescence_seconds}s idle` : ""} ·{" "}
+              {status.is_running ? "running" : "stopped"}
+            </div>
+            <div className="mono">{status.inbox_directory_path ?? ""}</div>
+            {status.last_error_message || status.intake_pause_reason || status.operator_pause_reason ? (
+              <div className="label" style={{ color: "var(--danger)" }}>
+                {status.operator_pause_reason ?? status.intake_pause_reason ?? status.last_error_message}
+              </div>
+            ) : null}
+          </div>
+          <button
+            className="button"
+            onClick={() =>
+              runWatchFolderCommand(
+                status.operator_pause_reason != null ? "resume_watch_folder_intake" : "pause_watch_folder_intake",
+                { watchId: status.watch_id, reason: null },
+                `${status.operator_pause_reason != null ? "resumed" : "paused"} intake of ${status.watch_id}`
+              )
+            }
+            disabled={isDisabled || !status.is_running}
+          >
+            {status.operator_pause_reason != null ? "Resume intake" : "Pause intake"}
+          </button>
+          <button
+            className="button"
+            onClick={() => runWatchFolderCommand("stop_watch_folder", { watchId: status.watch_id }, `stopped ${status.watch_id}`)}
+            disabled={isDisabled || !status.is_running}
+          >
+            Stop
+          </button>
+          <button
+            className="button"
+            onClick={() => runWatchFolderCommand("remove_watch_folder", { watchId: status.watch_id }, `removed ${status.watch_id}`)}
+            disabled={isDisabled}
+          >
+            Remove
+          </button>
+        </div>
+      ))}
+      <div style={{ height: 8 }} />
+      <div className="row">
+        <input
+          className="input"
+          value={watchId}
+          onChange={(event) => setWatchId(event.target.value)}
+          placeholder="id (e.g. accounting)"
+          aria-label="Watch folder id"
+          disabled={isDisabled}
+        />
+        <button className="button" onClick={handlePickInboxDirectory} disabled={isDisabled}>
+          Select inbox…
+        </button>
+      </div>
+      <div className="mono">{inboxDirectoryPath.trim() === "" ? "(inbox not selected)" : inboxDirectoryPath}</div>
+      <div className="row">
+        <input
+          className="input"
+          value={jobsRootDirectoryPath}
+          onChange={(event) => setJobsRootDirectoryPath(event.target.value)}
+          placeholder="jobs root (default: inbox/jobs)"
+          aria-label="Jobs root directory"
+          disabled={isDisabled}
+        />
+        <input
+          className="input"
+          value={presetName}
+          onChange={(event) => setPresetName(event.target.value)}
+          placeholder="preset (optional)"
+          aria-label="Preset name"
+          disabled={isDisabled}
+        />
+        <label className="label">
+          priority{" "}
+          <input
+            type="number"
+            value={priority}
+            onChange={(event) => setPriority(Math.floor(Number(event.target.value) || 0))}
+            disabled={isDisabled}
+            style={{ width: 56 }}
+          />
+        </label>
+        <input
+          className="input"
+          value={bundleQuiescenceSeconds}
+          onChange={(event) => setBundleQuiescenceSeconds(event.target.value)}
+          placeholder="ready after N s idle (optional)"
+          aria-label="Quiescence period in seconds"
+          disabled={isDisabled}
+        />
+        <label className="label">
+          <input
+            type="checkbox"
+            checked={isAutoRunEnabled}
+            onChange={(event) => setIsAutoRunEnabled(event.target.checked)}
+            disabled={isDisabled}
+          />{" "}
+          auto-run
+        </label>
+        <button className="button" onClick={handleStart} disabled={isDisabled}>
+          Start inbox
+        </button>
+      </div>
+    </div>
+  );
+}
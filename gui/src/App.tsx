@@ -8,12 +8,16 @@
  */
 import { useEffect, useMemo, useRef, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { isTauriWebview } from "./tauri_env";
+import { AdditionalInboxes, PRIMARY_WATCH_ID, WatchFolderStatus } from "./AdditionalInboxes";
 import { LogEntry, LogViewer, LogSource } from "./LogViewer";
 import { CurrentTaskPreview, PreviewPanel } from "./PreviewPanel";
+import { TaskTable } from "./TaskTable";
 
 type JobStatus = {
   job_root_directory_path: string;
+  job_id: string | null;
   is_running: boolean;
   start_unix_timestamp_millis: number | null;
   total_tasks: number;
@@ -23,28 +27,135 @@ type JobStatus = {
   failed_tasks: number;
   last_error_message: string | null;
   estimated_time_remaining_seconds: number | null;
+  heartbeat_age_seconds: number | null;
+  is_heartbeat_stale: boolean;
+  is_paused: boolean;
+  is_cancel_requested: boolean;
 };
 
 type JobLogResponse = {
   lines: string[];
 };
 
-type WatchFolderStatus = {
+type JobStartOutcome = { kind: "started" } | { kind: "queued"; position: number };
+
+type JobLogLineEvent = {
+  job_root_directory_path: string;
+  line: string;
+  replaces_previous_line: boolean;
+};
+
+type MaintenanceStatus = {
+  is_enabled: boolean;
+  reason: string | null;
+  job_policy: "wait_for_jobs" | "cancel_jobs" | null;
+  running_job_root_directory_paths: string[];
+  is_drained: boolean;
+  summary: string;
+};
+
+type DockerProbeReport = {
+  warnings: string[];
+  is_rebuild_recommended: boolean;
+};
+
+type BackendCapabilities = {
+  execution_engine: "docker" | "host";
+  container_runtime: { kind: "docker" | "podman" | "none"; is_engine_running: boolean; version: string | null; note: string | null };
+  is_gpu_available: boolean;
+  gpu_name: string | null;
+  is_apple_silicon: boolean;
+  is_clipboard_available: boolean;
+  is_http_api_enabled: boolean;
+};
+
+type RecentJob = {
+  job_root_directory_path: string;
+  is_job_root_present: boolean;
+  run_count: number;
+  last_run_mode: string;
+  last_started_unix_timestamp_millis: number;
+  last_finished_unix_timestamp_millis: number | null;
+  last_exit_status: string | null;
+  last_exit_code: number | null;
+  last_outcome: string | null;
+  last_output_markdown_path: string;
   is_running: boolean;
-  inbox_directory_path: string | null;
-  jobs_root_directory_path: string | null;
-  last_error_message: string | null;
 };
 
+type JobTemplateSummary = {
+  name: string;
+  preset_name: string | null;
+  seed_file_count: number;
+};
+
+type BulkJobOperation =
+  | { kind: "retry_failed" }
+  | { kind: "archive_completed"; archive_directory_path: string; older_than_days: number }
+  | { kind: "export_outputs"; destination_directory_path: string };
+
+type BulkJobItemResult = {
+  job_root_directory_path: string;
+  status: "done" | "skipped" | "failed";
+  detail?: string;
+  reason?: string;
+  error_message?: string;
+};
+
+type BulkJobOperationProgress = {
+  operation: string;
+  processed_count: number;
+  total_count: number;
+  item: BulkJobItemResult;
+};
+
+type BulkJobOperationSummary = {
+  done_count: number;
+  skipped_count: number;
+  failed_count: number;
+  items: BulkJobItemResult[];
+};
+
+type WorkCleanupSummary = {
+  deleted_file_count: number;
+  freed_bytes: number;
+};
+
+type AppliedDocumentTitle = {
+  detected_title: { title: string; source: "heading1" | "heading2" | "first_line" };
+  previous_output_markdown_path: string;
+  output_markdown_path: string;
+  is_renamed: boolean;
+};
+
+type ImageBuildStatus = {
+  is_running: boolean;
+  log_lines: string[];
+  is_last_build_successful: boolean | null;
+};
+
+const BULK_JOB_OPERATION_PROGRESS_EVENT = "bulk-job-operation-progress";
+const DEFAULT_ARCHIVE_OLDER_THAN_DAYS = 30;
 const PROGRESS_POLL_INTERVAL_MILLIS = 900;
-const LOG_POLL_INTERVAL_MILLIS = 700;
+const JOB_STATUS_EVENT = "job://status";
+const JOB_LOG_LINE_EVENT = "job://log-line";
+// NOTE: Status and logs are pushed as events; this slow poll only catches what no event covers (heartbeat age, runs
+// started outside this app) and resyncs log lines the backend has trimmed.
+const JOB_EVENT_FALLBACK_POLL_INTERVAL_MILLIS = 5000;
+const MAX_STREAMED_BACKEND_LOG_LINES = 5000;
 const MAX_UI_LOG_LINES = 400;
 const DEFAULT_LOG_VIEW_START_INDEX = 0;
 const WATCH_STATUS_POLL_INTERVAL_MILLIS = 1100;
+const IMAGE_BUILD_STATUS_POLL_INTERVAL_MILLIS = 1500;
 
 const LOCAL_STORAGE_WATCH_INBOX_DIRECTORY_PATH_KEY = "ocr-agent.watchInboxDirectoryPath";
 const LOCAL_STORAGE_WATCH_JOBS_ROOT_DIRECTORY_PATH_KEY = "ocr-agent.watchJobsRootDirectoryPath";
 const LOCAL_STORAGE_WATCH_AUTO_RUN_ENABLED_KEY = "ocr-agent.watchAutoRunEnabled";
+const LOCAL_STORAGE_WATCH_AUTO_PACKAGE_ENABLED_KEY = "ocr-agent.watchAutoPackageEnabled";
+const LOCAL_STORAGE_WATCH_ACCEPT_SINGLE_FILES_KEY = "ocr-agent.watchAcceptSingleFiles";
+// NOTE: "0" (or missing) keeps the `.ready` contract; any other value is the quiescence period in seconds.
+const LOCAL_STORAGE_WATCH_BUNDLE_QUIESCENCE_SECONDS_KEY = "ocr-agent.watchBundleQuiescenceSeconds";
+const DEFAULT_WATCH_BUNDLE_QUIESCENCE_SECONDS = 30;
 
 const DEFAULT_DEEPSEEK_OCR2_BASE_IMAGE_SIZE_PIXELS = 1024;
 const DEFAULT_DEEPSEEK_OCR2_INFERENCE_IMAGE_SIZE_PIXELS = 768;
@@ -107,6 +218,8 @@ export function App() {
   const [isStartingRun, setIsStartingRun] = useState<boolean>(false);
   const [outputMarkdownFilenameOverride, setOutputMarkdownFilenameOverride] = useState<string>("");
   const [isMathDelimiterConversionEnabled, setIsMathDelimiterConversionEnabled] = useState<boolean>(true);
+  const [isHyphenationRepairEnabled, setIsHyphenationRepairEnabled] = useState<boolean>(false);
+  const [isParagraphReflowEnabled, setIsParagraphReflowEnabled] = useState<boolean>(false);
   const [deepseekOcr2ModelRevision, setDeepseekOcr2ModelRevision] = useState<string>("");
   const [deepseekOcr2MarkdownPrompt, setDeepseekOcr2MarkdownPrompt] = useState<string>(
     DEFAULT_DEEPSEEK_OCR2_MARKDOWN_PROMPT
@@ -129,7 +242,25 @@ export function App() {
   const [watchInboxDirectoryPath, setWatchInboxDirectoryPath] = useState<string>("");
   const [watchJobsRootDirectoryPath, setWatchJobsRootDirectoryPath] = useState<string>("");
   const [watchFolderStatus, setWatchFolderStatus] = useState<WatchFolderStatus | null>(null);
+  const [watchFolderStatuses, setWatchFolderStatuses] = useState<WatchFolderStatus[]>([]);
+  const [maintenanceStatus, setMaintenanceStatus] = useState<MaintenanceStatus | null>(null);
+  const [isMaintenanceRequestPending, setIsMaintenanceRequestPending] = useState<boolean>(false);
   const [isWatchAutoRunEnabled, setIsWatchAutoRunEnabled] = useState<boolean>(false);
+  const [isWatchAutoPackageEnabled, setIsWatchAutoPackageEnabled] = useState<boolean>(false);
+  const [isWatchSingleFileIntakeEnabled, setIsWatchSingleFileIntakeEnabled] = useState<boolean>(false);
+  const [isWatchBundleQuiescenceEnabled, setIsWatchBundleQuiescenceEnabled] = useState<boolean>(false);
+  const [watchBundleQuiescenceSeconds, setWatchBundleQuiescenceSeconds] = useState<number>(
+    DEFAULT_WATCH_BUNDLE_QUIESCENCE_SECONDS
+  );
+  const [isDockerRebuildRecommended, setIsDockerRebuildRecommended] = useState<boolean>(false);
+  const [imageBuildStatus, setImageBuildStatus] = useState<ImageBuildStatus | null>(null);
+  const [backendCapabilities, setBackendCapabilities] = useState<BackendCapabilities | null>(null);
+  const [recentJobs, setRecentJobs] = useState<RecentJob[]>([]);
+  const [jobTemplates, setJobTemplates] = useState<JobTemplateSummary[]>([]);
+  const [selectedJobTemplateName, setSelectedJobTemplateName] = useState<string>("");
+  const [bulkSelectedJobRootPaths, setBulkSelectedJobRootPaths] = useState<string[]>([]);
+  const [archiveOlderThanDays, setArchiveOlderThanDays] = useState<number>(DEFAULT_ARCHIVE_OLDER_THAN_DAYS);
+  const [isBulkJobOperationRunning, setIsBulkJobOperationRunning] = useState<boolean>(false);
 
   const jobRootDirectoryPathRef = useRef<string | null>(null);
   jobRootDirectoryPathRef.current = jobRootDirectoryPath;
@@ -171,6 +302,12 @@ export function App() {
     return selectedInputPathCount > 0;
   }, [jobRootDirectoryPath, jobStatus?.is_running, selectedInputPathCount]);
 
+  // NOTE: Unfinished tasks with no live run means the previous run was interrupted (crash, killed app).
+  const canResumeInterruptedJob =
+    jobStatus !== null &&
+    !jobStatus.is_running &&
+    jobStatus.pending_tasks + jobStatus.running_tasks + jobStatus.failed_tasks > 0;
+
   useEffect(() => {
     if (!isRunningInsideTauri) {
       // Guard: When running as a normal browser tab (not in Tauri), native file dialogs are unavailable.
@@ -188,9 +325,20 @@ export function App() {
       const inbox = window.localStorage.getItem(LOCAL_STORAGE_WATCH_INBOX_DIRECTORY_PATH_KEY) ?? "";
       const jobsRoot = window.localStorage.getItem(LOCAL_STORAGE_WATCH_JOBS_ROOT_DIRECTORY_PATH_KEY) ?? "";
       const autoRunRaw = window.localStorage.getItem(LOCAL_STORAGE_WATCH_AUTO_RUN_ENABLED_KEY) ?? "0";
+      const autoPackageRaw = window.localStorage.getItem(LOCAL_STORAGE_WATCH_AUTO_PACKAGE_ENABLED_KEY) ?? "0";
+      const acceptSingleFilesRaw = window.localStorage.getItem(LOCAL_STORAGE_WATCH_ACCEPT_SINGLE_FILES_KEY) ?? "0";
       setWatchInboxDirectoryPath(inbox);
       setWatchJobsRootDirectoryPath(jobsRoot);
       setIsWatchAutoRunEnabled(autoRunRaw === "1");
+      setIsWatchAutoPackageEnabled(autoPackageRaw === "1");
+      setIsWatchSingleFileIntakeEnabled(acceptSingleFilesRaw === "1");
+      const bundleQuiescenceSeconds = Number(
+        window.localStorage.getItem(LOCAL_STORAGE_WATCH_BUNDLE_QUIESCENCE_SECONDS_KEY) ?? "0"
+      );
+      if (Number.isFinite(bundleQuiescenceSeconds) && bundleQuiescenceSeconds > 0) {
+        setIsWatchBundleQuiescenceEnabled(true);
+        setWatchBundleQuiescenceSeconds(bundleQuiescenceSeconds);
+      }
     } catch {
       // Guard: localStorage access may fail in some environments.
     }
@@ -203,6 +351,52 @@ export function App() {
     }
   }, [isRunningInsideTauri]);
 
+  useEffect(() => {
+    if (!isRunningInsideTauri) {
+      return;
+    }
+    // NOTE: Probed once; controls stay enabled (null) until the report arrives or if it fails.
+    invoke<BackendCapabilities>("get_backend_capabilities", {})
+      .then(setBackendCapabilities)
+      .catch(() => setBackendCapabilities(null));
+  }, [isRunningInsideTauri]);
+
+  useEffect(() => {
+    if (!isRunningInsideTauri) {
+      return;
+    }
+    invoke<JobTemplateSummary[]>("list_job_templates", {})
+      .then((templates) => {
+        setJobTemplates(templates);
+        setSelectedJobTemplateName((previous) => previous || (templates[0]?.name ?? ""));
+      })
+      .catch(() => setJobTemplates([]));
+  }, [isRunningInsideTauri]);
+
+  useEffect(() => {
+    if (!isRunningInsideTauri) {
+      return;
+    }
+    const unlistenPromise = listen<BulkJobOperationProgress>(BULK_JOB_OPERATION_PROGRESS_EVENT, (event) => {
+      const { processed_count, total_count, item } = event.payload;
+      const detail = item.detail ?? item.reason ?? item.error_message ?? "";
+      appendUiLogLine(`[bulk] ${processed_count}/${total_count} ${item.status}: ${item.job_root_directory_path} ${detail}`);
+    });
+    return () => {
+      unlistenPromise.then((unlisten) => unlisten()).catch(() => undefined);
+    };
+  }, [isRunningInsideTauri]);
+
+  useEffect(() => {
+    if (!isRunningInsideTauri) {
+      return;
+    }
+    // NOTE: Reloaded when a run starts or finishes, so the latest outcome shows without polling.
+    invoke<RecentJob[]>("list_recent_jobs", {})
+      .then(setRecentJobs)
+      .catch(() => setRecentJobs([]));
+  }, [isRunningInsideTauri, jobStatus?.is_running]);
+
   useEffect(() => {
     if (!isRunningInsideTauri) {
       // Guard: do not call invoke() outside of Tauri.
@@ -213,26 +407,66 @@ export function App() {
     }
 
     let cancelled = false;
-    const intervalId = window.setInterval(async () => {
-      if (cancelled) {
-        return;
-      }
+    async function refreshJobStatus(): Promise<void> {
       try {
         const status = await invoke<JobStatus>("get_job_status", {
           jobRootDirectoryPath
         });
-        setJobStatus(status);
+        if (!cancelled) {
+          setJobStatus(status);
+        }
       } catch (error) {
         setUiErrorMessage(String(error));
       }
-    }, PROGRESS_POLL_INTERVAL_MILLIS);
+    }
+
+    refreshJobStatus();
+    const unlistenPromise = listen<JobStatus>(JOB_STATUS_EVENT, (event) => {
+      if (!cancelled && event.payload.job_root_directory_path === jobRootDirectoryPath) {
+        setJobStatus(event.payload);
+      }
+    });
+    const intervalId = window.setInterval(refreshJobStatus, JOB_EVENT_FALLBACK_POLL_INTERVAL_MILLIS);
 
     return () => {
       cancelled = true;
       window.clearInterval(intervalId);
+      unlistenPromise.then((unlisten) => unlisten()).catch(() => undefined);
     };
   }, [jobRootDirectoryPath]);
 
+  const isImageBuildRunning = imageBuildStatus?.is_running === true;
+  useEffect(() => {
+    if (!isRunningInsideTauri || !isImageBuildRunning) {
+      // Guard: only poll while a build started from this window is in progress.
+      return;
+    }
+
+    let cancelled = false;
+    const intervalId = window.setInterval(async () => {
+      if (cancelled) {
+        return;
+      }
+      try {
+        const status = await invoke<ImageBuildStatus>("get_docker_image_build_status", {});
+        setImageBuildStatus(status);
+        if (!status.is_running) {
+          appendUiLogLine(status.is_last_build_successful === true ? "[docker] image build finished" : "[docker] image build failed");
+          if (status.is_last_build_successful === true) {
+            setIsDockerRebuildRecommended(false);
+          }
+        }
+      } catch {
+        // Guard: build status polling should never break the main UI.
+      }
+    }, IMAGE_BUILD_STATUS_POLL_INTERVAL_MILLIS);
+
+    return () => {
+      cancelled = true;
+      window.clearInterval(intervalId);
+    };
+  }, [isRunningInsideTauri, isImageBuildRunning]);
+
   useEffect(() => {
     if (!isRunningInsideTauri) {
       return;
@@ -244,8 +478,10 @@ export function App() {
         return;
       }
       try {
-        const status = await invoke<WatchFolderStatus>("get_watch_folder_status", {});
-        setWatchFolderStatus(status);
+        const statuses = await invoke<WatchFolderStatus[]>("get_watch_folder_status", {});
+        setWatchFolderStatuses(statuses);
+        setWatchFolderStatus(statuses.find((status) => status.watch_id === PRIMARY_WATCH_ID) ?? null);
+        setMaintenanceStatus(await invoke<MaintenanceStatus>("get_maintenance_status", {}));
       } catch {
         // Guard: watcher status polling should never break the main UI.
       }
@@ -267,23 +503,37 @@ export function App() {
     }
 
     let cancelled = false;
-    const intervalId = window.setInterval(async () => {
-      if (cancelled) {
-        return;
-      }
+    async function refreshJobLogs(): Promise<void> {
       try {
         const response = await invoke<JobLogResponse>("get_job_logs", {
           jobRootDirectoryPath
         });
-        setBackendLogLines(response.lines);
+        if (!cancelled) {
+          setBackendLogLines(response.lines);
+        }
       } catch {
         // Guard: log polling should not spam errors when job isn't running yet.
       }
-    }, LOG_POLL_INTERVAL_MILLIS);
+    }
+
+    refreshJobLogs();
+    const unlistenPromise = listen<JobLogLineEvent>(JOB_LOG_LINE_EVENT, (event) => {
+      if (cancelled || event.payload.job_root_directory_path !== jobRootDirectoryPath) {
+        return;
+      }
+      setBackendLogLines((previous) => {
+        // NOTE: Progress bars (`\r` updates) overwrite their previous line, as in a terminal.
+        const kept = event.payload.replaces_previous_line ? previous.slice(0, -1) : previous;
+        const next = [...kept, event.payload.line];
+        return next.length > MAX_STREAMED_BACKEND_LOG_LINES ? next.slice(next.length - MAX_STREAMED_BACKEND_LOG_LINES) : next;
+      });
+    });
+    const intervalId = window.setInterval(refreshJobLogs, JOB_EVENT_FALLBACK_POLL_INTERVAL_MILLIS);
 
     return () => {
       cancelled = true;
       window.clearInterval(intervalId);
+      unlistenPromise.then((unlisten) => unlisten()).catch(() => undefined);
     };
   }, [jobRootDirectoryPath]);
 
@@ -304,7 +554,8 @@ export function App() {
       }
       try {
         const preview = await invoke<CurrentTaskPreview | null>("get_current_task_preview", {
-          jobRootDirectoryPath
+          jobRootDirectoryPath,
+          lookahead: true
         });
         setCurrentTaskPreview(preview);
       } catch {
@@ -340,7 +591,7 @@ export function App() {
       try {
         const response = await invoke<{ mime_type: string; bytes: number[] } | null>(
           "get_current_task_preview_image_bytes",
-          { jobRootDirectoryPath }
+          { jobRootDirectoryPath, lookahead: true }
         );
         if (cancelled) {
           return;
@@ -381,19 +632,100 @@ export function App() {
       if (selectedDirectoryPath === null) {
         return;
       }
-      setJobRootDirectoryPath(selectedDirectoryPath);
-      setSelectedInputPathCount(0);
-      setOutputMarkdownFilenameOverride("");
-      setUiLogLines([]);
-      setBackendLogLines([]);
-      setCurrentTaskPreview(null);
-      setCurrentTaskPreviewImageUrl(null);
-      setLogViewStartIndex(DEFAULT_LOG_VIEW_START_INDEX);
+      selectJobRootDirectory(selectedDirectoryPath);
+    } catch (error) {
+      setUiErrorMessage(String(error));
+    }
+  }
+
+  async function handleCreateJobFromTemplate(): Promise<void> {
+    if (!isRunningInsideTauri) {
+      setUiErrorMessage("Job templates are only available in the Tauri desktop app.");
+      return;
+    }
+    if (selectedJobTemplateName === "") {
+      return;
+    }
+    try {
+      setUiErrorMessage(null);
+      const parentDirectoryPath = await invoke<string | null>("pick_directory");
+      if (parentDirectoryPath === null) {
+        return;
+      }
+      const createdJobRootDirectoryPath = await invoke<string>("create_job_from_template", {
+        parentDirectoryPath,
+        templateName: selectedJobTemplateName,
+      });
+      selectJobRootDirectory(createdJobRootDirectoryPath);
+      appendUiLogLine(`[template] created ${createdJobRootDirectoryPath} from ${selectedJobTemplateName}`);
     } catch (error) {
       setUiErrorMessage(String(error));
     }
   }
 
+  function selectJobRootDirectory(selectedDirectoryPath: string): void {
+    setJobRootDirectoryPath(selectedDirectoryPath);
+    setSelectedInputPathCount(0);
+    setOutputMarkdownFilenameOverride("");
+    setUiLogLines([]);
+    setBackendLogLines([]);
+    setCurrentTaskPreview(null);
+    setCurrentTaskPreviewImageUrl(null);
+    setLogViewStartIndex(DEFAULT_LOG_VIEW_START_INDEX);
+  }
+
+  function toggleBulkSelectedJobRoot(jobRootPath: string, isSelected: boolean): void {
+    setBulkSelectedJobRootPaths((previous) =>
+      isSelected ? [...previous.filter((path) => path !== jobRootPath), jobRootPath] : previous.filter((path) => path !== jobRootPath),
+    );
+  }
+
+  async function handleRunBulkJobOperation(kind: BulkJobOperation["kind"]): Promise<void> {
+    if (!isRunningInsideTauri || bulkSelectedJobRootPaths.length === 0) {
+      return;
+    }
+    try {
+      setUiErrorMessage(null);
+      let operation: BulkJobOperation;
+      if (kind === "retry_failed") {
+        operation = { kind };
+      } else {
+        const targetDirectoryPath = await invoke<string | null>("pick_directory");
+        if (targetDirectoryPath === null) {
+          return;
+        }
+        operation =
+          kind === "archive_completed"
+            ? { kind, archive_directory_path: targetDirectoryPath, older_than_days: archiveOlderThanDays }
+            : { kind, destination_directory_path: targetDirectoryPath };
+      }
+      setIsBulkJobOperationRunning(true);
+      const summary = await invoke<BulkJobOperationSummary>("run_bulk_job_operation", {
+        operation,
+        jobRootDirectoryPaths: bulkSelectedJobRootPaths,
+      });
+      appendUiLogLine(
+        `[bulk] ${kind}: ${summary.done_count} done, ${summary.skipped_count} skipped, ${summary.failed_count} failed`,
+      );
+      setBulkSelectedJobRootPaths([]);
+      const refreshedRecentJobs = await invoke<RecentJob[]>("list_recent_jobs", {});
+      setRecentJobs(refreshedRecentJobs);
+    } catch (error) {
+      setUiErrorMessage(String(error));
+    } finally {
+      setIsBulkJobOperationRunning(false);
+    }
+  }
+
+  function describeRecentJob(recentJob: RecentJob): string {
+    const startedAt = new Date(recentJob.last_started_unix_timestamp_millis).toLocaleString();
+    const outcome = recentJob.is_running
+      ? "running"
+      : (recentJob.last_outcome ?? (recentJob.last_finished_unix_timestamp_millis === null ? "did not finish" : "unknown"));
+    const runCount = recentJob.run_count === 1 ? "1 run" : `${recentJob.run_count} runs`;
+    return `${startedAt} · ${outcome} · ${runCount}`;
+  }
+
   function appendUiLogLine(line: string): void {
     setUiLogLines((previous) => {
       const next = [...previous, line];
@@ -508,12 +840,18 @@ export function App() {
       setUiErrorMessage(null);
       appendUiLogLine("[run] starting…");
       setIsStartingRun(true);
-      await invoke("probe_docker", {});
+      const dockerProbeReport = await invoke<DockerProbeReport>("probe_docker", {});
+      setIsDockerRebuildRecommended(dockerProbeReport.is_rebuild_recommended);
+      for (const dockerWarning of dockerProbeReport.warnings) {
+        appendUiLogLine(`[docker] WARNING: ${dockerWarning}`);
+      }
       await invoke("run_job", {
         jobRootDirectoryPath: currentJobRootDirectoryPath,
         outputMarkdownFilenameOverride:
           outputMarkdownFilenameOverride.trim() === "" ? null : outputMarkdownFilenameOverride.trim(),
         isMathDelimiterConversionEnabled,
+        isHyphenationRepairEnabled,
+        isParagraphReflowEnabled,
         deepseekOcr2ModelRevision: deepseekOcr2ModelRevision.trim() === "" ? null : deepseekOcr2ModelRevision.trim(),
         deepseekOcr2MarkdownPrompt: promptTrimmed,
         deepseekOcr2BaseImageSizePixels: baseImageSizePixels,
@@ -548,6 +886,22 @@ export function App() {
     }
   }
 
+  async function handleStartImageBuild(): Promise<void> {
+    if (!isRunningInsideTauri) {
+      return;
+    }
+    try {
+      setUiErrorMessage(null);
+      await invoke("start_docker_image_build", {});
+      setImageBuildStatus({ is_running: true, log_lines: [], is_last_build_successful: null });
+      appendUiLogLine("[docker] image build started");
+    } catch (error) {
+      const errorMessage = String(error);
+      setUiErrorMessage(errorMessage);
+      appendUiLogLine(`[docker] ERROR: ${errorMessage}`);
+    }
+  }
+
   async function handleCancelJob(): Promise<void> {
     if (!isRunningInsideTauri) {
       return;
@@ -556,10 +910,12 @@ export function App() {
     if (currentJobRootDirectoryPath === null) {
       return;
     }
+    // NOTE: The first click stops the run after its current page; a second click while it winds down stops it now.
+    const mode = jobStatus?.is_cancel_requested === true ? "immediate" : "graceful";
     try {
       setUiErrorMessage(null);
-      appendUiLogLine("[cancel] requested");
-      await invoke("cancel_job", { jobRootDirectoryPath: currentJobRootDirectoryPath });
+      appendUiLogLine(`[cancel] requested (${mode})`);
+      await invoke("cancel_job", { jobRootDirectoryPath: currentJobRootDirectoryPath, mode });
     } catch (error) {
       const errorMessage = String(error);
       setUiErrorMessage(errorMessage);
@@ -567,6 +923,36 @@ export function App() {
     }
   }
 
+  async function handleTogglePauseJob(): Promise<void> {
+    if (!isRunningInsideTauri || jobRootDirectoryPath === null || jobStatus === null) {
+      return;
+    }
+    // NOTE: A job that is not running resumes from its existing queue (e.g. after a crash) instead of unpausing.
+    const command = jobStatus.is_paused || !jobStatus.is_running ? "resume_job" : "pause_job";
+    try {
+      setUiErrorMessage(null);
+      await invoke(command, { jobRootDirectoryPath });
+      appendUiLogLine(`[${command === "pause_job" ? "pause" : "resume"}] done`);
+    } catch (error) {
+      setUiErrorMessage(String(error));
+    }
+  }
+
+  async function handleRetryFailedTasks(): Promise<void> {
+    if (!isRunningInsideTauri || jobRootDirectoryPath === null) {
+      return;
+    }
+    try {
+      setUiErrorMessage(null);
+      const outcome = await invoke<JobStartOutcome>("retry_failed_tasks", { jobRootDirectoryPath });
+      appendUiLogLine(outcome.kind === "queued" ? `[retry] queued (#${outcome.position})` : "[retry] started");
+    } catch (error) {
+      const errorMessage = String(error);
+      setUiErrorMessage(errorMessage);
+      appendUiLogLine(`[retry] ERROR: ${errorMessage}`);
+    }
+  }
+
   async function handleOpenOutputDirectory(): Promise<void> {
     if (!isRunningInsideTauri) {
       return;
@@ -581,6 +967,73 @@ export function App() {
     }
   }
 
+  async function handleCopyOutputToClipboard(): Promise<void> {
+    if (!isRunningInsideTauri) {
+      return;
+    }
+    if (jobRootDirectoryPath === null) {
+      return;
+    }
+    try {
+      const copiedCharacterCount = await invoke<number>("copy_output_to_clipboard", {
+        jobRootDirectoryPath
+      });
+      appendUiLogLine(`[clipboard] copied ${copiedCharacterCount} characters`);
+    } catch (error) {
+      setUiErrorMessage(String(error));
+    }
+  }
+
+  async function handlePackageOutputs(): Promise<void> {
+    if (!isRunningInsideTauri) {
+      return;
+    }
+    if (jobRootDirectoryPath === null) {
+      return;
+    }
+    try {
+      const archivePath = await invoke<string>("package_outputs", { jobRootDirectoryPath });
+      appendUiLogLine(`[package] written: ${archivePath}`);
+    } catch (error) {
+      setUiErrorMessage(String(error));
+    }
+  }
+
+  async function handleApplyDetectedTitle(): Promise<void> {
+    if (!isRunningInsideTauri) {
+      return;
+    }
+    if (jobRootDirectoryPath === null) {
+      return;
+    }
+    try {
+      const appliedTitle = await invoke<AppliedDocumentTitle>("apply_detected_title", { jobRootDirectoryPath });
+      appendUiLogLine(
+        appliedTitle.is_renamed
+          ? `[title] "${appliedTitle.detected_title.title}": renamed to ${appliedTitle.output_markdown_path}`
+          : `[title] "${appliedTitle.detected_title.title}": output already uses this name`,
+      );
+    } catch (error) {
+      setUiErrorMessage(String(error));
+    }
+  }
+
+  async function handleCleanWorkFiles(): Promise<void> {
+    if (!isRunningInsideTauri) {
+      return;
+    }
+    if (jobRootDirectoryPath === null) {
+      return;
+    }
+    try {
+      const summary = await invoke<WorkCleanupSummary>("clean_work_files", { jobRootDirectoryPath });
+      const freedMegabytes = (summary.freed_bytes / (1024 * 1024)).toFixed(1);
+      appendUiLogLine(`[work] deleted ${summary.deleted_file_count} file(s), freed ${freedMegabytes} MiB`);
+    } catch (error) {
+      setUiErrorMessage(String(error));
+    }
+  }
+
   async function handleResetJobDirectory(): Promise<void> {
     if (!isRunningInsideTauri) {
       return;
@@ -655,7 +1108,10 @@ export function App() {
       await invoke("start_watch_folder", {
         inboxDirectoryPath: inbox,
         jobsRootDirectoryPath: watchJobsRootDirectoryPath.trim() === "" ? null : watchJobsRootDirectoryPath.trim(),
-        autoRun: isWatchAutoRunEnabled
+        autoRun: isWatchAutoRunEnabled,
+        autoPackage: isWatchAutoPackageEnabled,
+        acceptSingleFiles: isWatchSingleFileIntakeEnabled,
+        bundleQuiescenceSeconds: isWatchBundleQuiescenceEnabled ? watchBundleQuiescenceSeconds : null
       });
       appendUiLogLine("[watch-folder] started");
     } catch (error) {
@@ -681,6 +1137,71 @@ export function App() {
     }
   }
 
+  async function handleToggleWatchFolderIntakePause(): Promise<void> {
+    if (!isRunningInsideTauri) {
+      return;
+    }
+    const isPaused = watchFolderStatus?.operator_pause_reason != null;
+    try {
+      setUiErrorMessage(null);
+      if (isPaused) {
+        await invoke("resume_watch_folder_intake", {});
+        appendUiLogLine("[watch-folder] intake resumed");
+      } else {
+        await invoke("pause_watch_folder_intake", { reason: null });
+        appendUiLogLine("[watch-folder] intake paused");
+      }
+    } catch (error) {
+      const errorMessage = String(error);
+      setUiErrorMessage(errorMessage);
+      appendUiLogLine(`[watch-folder] ERROR: ${errorMessage}`);
+    }
+  }
+
+  async function enterMaintenanceMode(jobPolicy: "wait_for_jobs" | "cancel_jobs"): Promise<void> {
+    if (!isRunningInsideTauri) {
+      return;
+    }
+    try {
+      setUiErrorMessage(null);
+      setIsMaintenanceRequestPending(true);
+      appendUiLogLine(`[maintenance] entering (${jobPolicy})…`);
+      const status = await invoke<MaintenanceStatus>("enter_maintenance_mode", { reason: null, jobPolicy });
+      setMaintenanceStatus(status);
+      appendUiLogLine(`[maintenance] ${status.summary}`);
+    } catch (error) {
+      const errorMessage = String(error);
+      setUiErrorMessage(errorMessage);
+      appendUiLogLine(`[maintenance] ERROR: ${errorMessage}`);
+    } finally {
+      setIsMaintenanceRequestPending(false);
+    }
+  }
+
+  async function handleEnterMaintenanceModeLettingJobsFinish(): Promise<void> {
+    await enterMaintenanceMode("wait_for_jobs");
+  }
+
+  async function handleEnterMaintenanceModeCancellingJobs(): Promise<void> {
+    await enterMaintenanceMode("cancel_jobs");
+  }
+
+  async function handleExitMaintenanceMode(): Promise<void> {
+    if (!isRunningInsideTauri) {
+      return;
+    }
+    try {
+      setUiErrorMessage(null);
+      const status = await invoke<MaintenanceStatus>("exit_maintenance_mode", {});
+      setMaintenanceStatus(status);
+      appendUiLogLine("[maintenance] exited");
+    } catch (error) {
+      const errorMessage = String(error);
+      setUiErrorMessage(errorMessage);
+      appendUiLogLine(`[maintenance] ERROR: ${errorMessage}`);
+    }
+  }
+
   async function handleOpenWatchInboxDirectory(): Promise<void> {
     if (!isRunningInsideTauri) {
       return;
@@ -778,6 +1299,38 @@ export function App() {
                   >
                     Open folder
                   </button>
+                  <button
+                    className="button"
+                    onClick={handleCopyOutputToClipboard}
+                    disabled={
+                      !isRunningInsideTauri ||
+                      jobRootDirectoryPath === null ||
+                      backendCapabilities?.is_clipboard_available === false
+                    }
+                  >
+                    Copy output
+                  </button>
+                  <button
+                    className="button"
+                    onClick={handlePackageOutputs}
+                    disabled={!isRunningInsideTauri || jobRootDirectoryPath === null || jobStatus?.is_running === true}
+                  >
+                    Package (zip)
+                  </button>
+                  <button
+                    className="button"
+                    onClick={handleApplyDetectedTitle}
+                    disabled={!isRunningInsideTauri || jobRootDirectoryPath === null || jobStatus?.is_running === true}
+                  >
+                    Rename to title
+                  </button>
+                  <button
+                    className="button"
+                    onClick={handleCleanWorkFiles}
+                    disabled={!isRunningInsideTauri || jobRootDirectoryPath === null || jobStatus?.is_running === true}
+                  >
+                    Clean work files
+                  </button>
                   <button
                     className="button"
                     onClick={handleResetJobDirectory}
@@ -786,11 +1339,109 @@ export function App() {
                     Reset job (delete queue/output)
                   </button>
                 </div>
+                {jobTemplates.length > 0 ? (
+                  <>
+                    <div style={{ height: 10 }} />
+                    <div className="row">
+                      <select
+                        className="select"
+                        value={selectedJobTemplateName}
+                        onChange={(event) => setSelectedJobTemplateName(event.target.value)}
+                      >
+                        {jobTemplates.map((jobTemplate) => (
+                          <option key={jobTemplate.name} value={jobTemplate.name}>
+                            {jobTemplate.name}
+                            {jobTemplate.preset_name ? ` (preset: ${jobTemplate.preset_name})` : ""}
+                          </option>
+                        ))}
+                      </select>
+                      <button className="button" onClick={handleCreateJobFromTemplate} disabled={!isRunningInsideTauri}>
+                        New job from template…
+                      </button>
+                    </div>
+                  </>
+                ) : null}
                 <div style={{ height: 10 }} />
                 <div className="label">Job root</div>
                 <div className="mono">{jobRootDirectoryPath ?? "(not selected)"}</div>
+                {jobStatus?.job_id ? (
+                  <>
+                    <div style={{ height: 6 }} />
+                    <div className="label">Job ID</div>
+                    <div className="mono">{jobStatus.job_id}</div>
+                  </>
+                ) : null}
               </div>
 
+              {recentJobs.length > 0 ? (
+                <>
+                  <div style={{ height: 14 }} />
+                  <div className="card">
+                    <div className="label">Recent jobs</div>
+                    <div style={{ height: 6 }} />
+                    {recentJobs.map((recentJob) => (
+                      <div className="row" key={recentJob.job_root_directory_path}>
+                        <input
+                          type="checkbox"
+                          checked={bulkSelectedJobRootPaths.includes(recentJob.job_root_directory_path)}
+                          onChange={(event) => toggleBulkSelectedJobRoot(recentJob.job_root_directory_path, event.target.checked)}
+                          disabled={!recentJob.is_job_root_present || isBulkJobOperationRunning}
+                        />
+                        <button
+                          className="button"
+                          onClick={() => selectJobRootDirectory(recentJob.job_root_directory_path)}
+                          disabled={!recentJob.is_job_root_present || recentJob.job_root_directory_path === jobRootDirectoryPath}
+                        >
+                          Open
+                        </button>
+                        <div>
+                          <div className="mono">{recentJob.job_root_directory_path}</div>
+                          <div className="label">
+                            {recentJob.is_job_root_present ? describeRecentJob(recentJob) : "folder no longer exists"}
+                          </div>
+                        </div>
+                      </div>
+                    ))}
+                    <div style={{ height: 6 }} />
+                    <div className="row">
+                      <button
+                        className="button"
+                        onClick={() => handleRunBulkJobOperation("retry_failed")}
+                        disabled={bulkSelectedJobRootPaths.length === 0 || isBulkJobOperationRunning}
+                      >
+                        Retry failed
+                      </button>
+                      <button
+                        className="button"
+                        onClick={() => handleRunBulkJobOperation("archive_completed")}
+                        disabled={bulkSelectedJobRootPaths.length === 0 || isBulkJobOperationRunning}
+                      >
+                        Archive completed…
+                      </button>
+                      <label className="label">
+                        older than{" "}
+                        <input
+                          type="number"
+                          min={0}
+                          value={archiveOlderThanDays}
+                          onChange={(event) => setArchiveOlderThanDays(Math.max(0, Math.floor(Number(event.target.value) || 0)))}
+                          disabled={isBulkJobOperationRunning}
+                          style={{ width: 56 }}
+                        />{" "}
+                        days
+                      </label>
+                      <button
+                        className="button"
+                        onClick={() => handleRunBulkJobOperation("export_outputs")}
+                        disabled={bulkSelectedJobRootPaths.length === 0 || isBulkJobOperationRunning}
+                      >
+                        Export outputs…
+                      </button>
+                    </div>
+                  </div>
+                </>
+              ) : null}
+
               <div style={{ height: 14 }} />
 
               <div className="card">
@@ -815,7 +1466,7 @@ export function App() {
                   Added items this session: <b>{selectedInputPathCount}</b>
                 </div>
                 <div className="label">
-                  Inputs are copied into <span className="mono">input/</span> under the selected output directory.
+                  Inputs are copied into the job's input folder (<span className="mono">input/</span> by default) under the selected output directory.
                 </div>
               </div>
 
@@ -877,6 +1528,13 @@ export function App() {
                   >
                     Stop
                   </button>
+                  <button
+                    className="button"
+                    onClick={handleToggleWatchFolderIntakePause}
+                    disabled={!isRunningInsideTauri}
+                  >
+                    {watchFolderStatus?.operator_pause_reason != null ? "Resume intake" : "Pause intake"}
+                  </button>
                 </div>
 
                 <div style={{ height: 10 }} />
@@ -899,6 +1557,85 @@ export function App() {
                     Auto-run OCR after ingest (can be heavy; recommended OFF)
                   </span>
                 </label>
+                <div style={{ height: 10 }} />
+                <label className="toggle">
+                  <input
+                    type="checkbox"
+                    checked={isWatchAutoPackageEnabled}
+                    onChange={(event) => {
+                      const next = event.target.checked;
+                      setIsWatchAutoPackageEnabled(next);
+                      try {
+                        window.localStorage.setItem(LOCAL_STORAGE_WATCH_AUTO_PACKAGE_ENABLED_KEY, next ? "1" : "0");
+                      } catch {
+                        // Guard: localStorage failures should not break UX.
+                      }
+                    }}
+                    disabled={!isRunningInsideTauri || watchFolderStatus?.is_running === true}
+                  />
+                  <span className="toggleLabel">Package outputs into a zip when each job finishes</span>
+                </label>
+                <div style={{ height: 10 }} />
+                <label className="toggle">
+                  <input
+                    type="checkbox"
+                    checked={isWatchSingleFileIntakeEnabled}
+                    onChange={(event) => {
+                      const next = event.target.checked;
+                      setIsWatchSingleFileIntakeEnabled(next);
+                      try {
+                        window.localStorage.setItem(LOCAL_STORAGE_WATCH_ACCEPT_SINGLE_FILES_KEY, next ? "1" : "0");
+                      } catch {
+                        // Guard: localStorage failures should not break UX.
+                      }
+                    }}
+                    disabled={!isRunningInsideTauri || watchFolderStatus?.is_running === true}
+                  />
+                  <span className="toggleLabel">Accept single PDFs/images dropped directly into the inbox (no .ready needed)</span>
+                </label>
+                <div style={{ height: 10 }} />
+                <label className="toggle">
+                  <input
+                    type="checkbox"
+                    checked={isWatchBundleQuiescenceEnabled}
+                    onChange={(event) => {
+                      const next = event.target.checked;
+                      setIsWatchBundleQuiescenceEnabled(next);
+                      try {
+                        window.localStorage.setItem(
+                          LOCAL_STORAGE_WATCH_BUNDLE_QUIESCENCE_SECONDS_KEY,
+                          next ? String(watchBundleQuiescenceSeconds) : "0"
+                        );
+                      } catch {
+                        // Guard: localStorage failures should not break UX.
+                      }
+                    }}
+                    disabled={!isRunningInsideTauri || watchFolderStatus?.is_running === true}
+                  />
+                  <span className="toggleLabel">
+                    Treat a bundle folder as ready once its files stop changing for{" "}
+                    <input
+                      type="number"
+                      min={5}
+                      value={watchBundleQuiescenceSeconds}
+                      onChange={(event) => {
+                        const next = Math.max(5, Math.floor(Number(event.target.value) || 0));
+                        setWatchBundleQuiescenceSeconds(next);
+                        try {
+                          if (isWatchBundleQuiescenceEnabled) {
+                            window.localStorage.setItem(LOCAL_STORAGE_WATCH_BUNDLE_QUIESCENCE_SECONDS_KEY, String(next));
+                          }
+                        } catch {
+                          // Guard: localStorage failures should not break UX.
+                        }
+                      }}
+                      disabled={!isRunningInsideTauri || watchFolderStatus?.is_running === true}
+                      style={{ width: 56 }}
+                      aria-label="Quiescence period in seconds"
+                    />{" "}
+                    seconds (no .ready needed)
+                  </span>
+                </label>
 
                 <div style={{ height: 10 }} />
                 <div className="label">
@@ -909,6 +1646,68 @@ export function App() {
                     Watch error: {watchFolderStatus.last_error_message}
                   </div>
                 ) : null}
+                {watchFolderStatus?.operator_pause_reason ? (
+                  <div className="label" style={{ color: "var(--danger)" }}>
+                    Intake paused by operator: {watchFolderStatus.operator_pause_reason}
+                  </div>
+                ) : null}
+                {watchFolderStatus?.intake_pause_reason ? (
+                  <div className="label" style={{ color: "var(--danger)" }}>
+                    {watchFolderStatus.intake_pause_reason}
+                  </div>
+                ) : null}
+                {watchFolderStatus?.last_retention_cleanup_message ? (
+                  <div className="label">Retention cleanup: {watchFolderStatus.last_retention_cleanup_message}</div>
+                ) : null}
+                {watchFolderStatus?.last_duplicate_bundle_message ? (
+                  <div className="label">{watchFolderStatus.last_duplicate_bundle_message}</div>
+                ) : null}
+                {watchFolderStatus?.is_running === true && !watchFolderStatus.is_inbox_notification_active ? (
+                  <div className="label">Inbox change notifications unavailable; scanning every second.</div>
+                ) : null}
+
+                <div style={{ height: 10 }} />
+                <details>
+                  <summary className="label">Additional inboxes</summary>
+                  <div style={{ height: 8 }} />
+                  <AdditionalInboxes
+                    statuses={watchFolderStatuses}
+                    isDisabled={!isRunningInsideTauri}
+                    onLogLine={appendUiLogLine}
+                    onError={setUiErrorMessage}
+                  />
+                </details>
+
+                <div style={{ height: 10 }} />
+                <div className="row">
+                  {maintenanceStatus?.is_enabled === true ? (
+                    <button className="button" onClick={handleExitMaintenanceMode} disabled={!isRunningInsideTauri}>
+                      Exit maintenance mode
+                    </button>
+                  ) : (
+                    <>
+                      <button
+                        className="button"
+                        onClick={handleEnterMaintenanceModeLettingJobsFinish}
+                        disabled={!isRunningInsideTauri || isMaintenanceRequestPending}
+                      >
+                        Maintenance (let jobs finish)
+                      </button>
+                      <button
+                        className="button"
+                        onClick={handleEnterMaintenanceModeCancellingJobs}
+                        disabled={!isRunningInsideTauri || isMaintenanceRequestPending}
+                      >
+                        Maintenance (cancel jobs)
+                      </button>
+                    </>
+                  )}
+                </div>
+                {maintenanceStatus?.is_enabled === true ? (
+                  <div className="label" style={{ color: maintenanceStatus.is_drained ? undefined : "var(--danger)" }}>
+                    {maintenanceStatus.summary}
+                  </div>
+                ) : null}
               </div>
 
               <div style={{ height: 14 }} />
@@ -925,18 +1724,54 @@ export function App() {
                   <button
                     className="button"
                     onClick={handleProbeGpu}
-                    disabled={!isRunningInsideTauri || jobStatus?.is_running === true}
+                    disabled={
+                      !isRunningInsideTauri ||
+                      jobStatus?.is_running === true ||
+                      (backendCapabilities?.execution_engine === "docker" &&
+                        backendCapabilities.container_runtime.kind === "none")
+                    }
                   >
                     Check GPU
                   </button>
+                  <button
+                    className={isDockerRebuildRecommended ? "button buttonPrimary" : "button"}
+                    onClick={handleStartImageBuild}
+                    disabled={!isRunningInsideTauri || isImageBuildRunning || jobStatus?.is_running === true}
+                  >
+                    {isImageBuildRunning ? "Building image…" : "Rebuild image"}
+                  </button>
+                  <button
+                    className="button"
+                    onClick={handleTogglePauseJob}
+                    disabled={!isRunningInsideTauri || (!jobStatus?.is_running && !canResumeInterruptedJob)}
+                  >
+                    {jobStatus?.is_paused === true || canResumeInterruptedJob ? "Resume" : "Pause"}
+                  </button>
+                  <button
+                    className="button"
+                    onClick={handleRetryFailedTasks}
+                    disabled={!isRunningInsideTauri || jobStatus?.is_running !== false || (jobStatus?.failed_tasks ?? 0) === 0}
+                  >
+                    Retry failed pages
+                  </button>
                   <button
                     className="button buttonDanger"
                     onClick={handleCancelJob}
                     disabled={!isRunningInsideTauri || !jobStatus?.is_running}
                   >
-                    Cancel
+                    {jobStatus?.is_cancel_requested === true ? "Stop now" : "Cancel"}
                   </button>
                 </div>
+                {isDockerRebuildRecommended ? (
+                  <div className="label" style={{ color: "var(--danger)" }}>
+                    Rebuild recommended: the Dockerfile or compose inputs changed since the image was built.
+                  </div>
+                ) : null}
+                {imageBuildStatus !== null && imageBuildStatus.log_lines.length > 0 ? (
+                  <div className="label">
+                    Build: {imageBuildStatus.log_lines[imageBuildStatus.log_lines.length - 1]}
+                  </div>
+                ) : null}
 
                 <div style={{ height: 12 }} />
 
@@ -963,6 +1798,28 @@ export function App() {
                 </label>
                 <div style={{ height: 12 }} />
 
+                <label className="toggle">
+                  <input
+                    type="checkbox"
+                    checked={isHyphenationRepairEnabled}
+                    onChange={(event) => setIsHyphenationRepairEnabled(event.target.checked)}
+                    disabled={!isRunningInsideTauri || jobStatus?.is_running === true}
+                  />
+                  <span className="toggleLabel">Join hyphenated words split across lines</span>
+                </label>
+                <div style={{ height: 12 }} />
+
+                <label className="toggle">
+                  <input
+                    type="checkbox"
+                    checked={isParagraphReflowEnabled}
+                    onChange={(event) => setIsParagraphReflowEnabled(event.target.checked)}
+                    disabled={!isRunningInsideTauri || jobStatus?.is_running === true}
+                  />
+                  <span className="toggleLabel">Reflow paragraph line breaks (keeps lists, tables, code)</span>
+                </label>
+                <div style={{ height: 12 }} />
+
                 <details className="details" open>
                   <summary className="detailsSummary">DeepSeek-OCR-2 settings</summary>
                   <div style={{ height: 12 }} />
@@ -1080,6 +1937,17 @@ export function App() {
                   </div>
                 </div>
 
+                {jobStatus && !jobStatus.is_running && jobStatus.heartbeat_age_seconds !== null ? (
+                  <>
+                    <div style={{ height: 10 }} />
+                    <div className="label" style={{ color: jobStatus.is_heartbeat_stale ? "var(--danger)" : undefined }}>
+                      {jobStatus.is_heartbeat_stale
+                        ? `Abandoned run: last heartbeat ${formatSecondsHuman(jobStatus.heartbeat_age_seconds)} ago`
+                        : `Running in another ocr-agent process (heartbeat ${formatSecondsHuman(jobStatus.heartbeat_age_seconds)} ago)`}
+                    </div>
+                  </>
+                ) : null}
+
                 {jobStatus?.last_error_message ? (
                   <>
                     <div style={{ height: 10 }} />
@@ -1089,6 +1957,20 @@ export function App() {
                   </>
                 ) : null}
 
+                {isRunningInsideTauri && jobRootDirectoryPath !== null && (jobStatus?.total_tasks ?? 0) > 0 ? (
+                  <>
+                    <div style={{ height: 10 }} />
+                    <details>
+                      <summary className="label">Tasks</summary>
+                      <div style={{ height: 8 }} />
+                      <TaskTable
+                        jobRootDirectoryPath={jobRootDirectoryPath}
+                        refreshKey={`${jobStatus?.completed_tasks}/${jobStatus?.failed_tasks}/${jobStatus?.running_tasks}`}
+                      />
+                    </details>
+                  </>
+                ) : null}
+
                 {uiErrorMessage ? (
                   <>
                     <div style={{ height: 10 }} />
@@ -22,6 +22,9 @@ export type CurrentTaskPreview = {
   pdf_total_pages: number | null;
   preview_image_file_path: string | null;
   deepseek_inference_image_size_pixels: number | null;
+  // NOTE: Set when nothing is running yet and this is the next queued task (pre-rendered host-side).
+  is_pending: boolean;
+  next_task: CurrentTaskPreview | null;
 };
 
 const MAX_DETECTION_RECTANGLES_TO_SHOW = 24;
@@ -104,7 +107,11 @@ export function PreviewPanel(props: PreviewPanelProps) {
     return () => observer.disconnect();
   }, []);
 
-  const detections = useMemo(() => extractDetectionsFromLogLines(backendLogLines), [backendLogLines]);
+  const isPending = preview?.is_pending ?? false;
+  const detections = useMemo(
+    () => (isPending ? [] : extractDetectionsFromLogLines(backendLogLines)),
+    [backendLogLines, isPending]
+  );
 
   const inferenceSizePixels =
     preview?.deepseek_inference_image_size_pixels ?? fallbackInferenceImageSizePixels ?? DEFAULT_INFERENCE_IMAGE_SIZE_PIXELS_FALLBACK;
@@ -123,7 +130,14 @@ export function PreviewPanel(props: PreviewPanelProps) {
     <div>
       <div className="row" style={{ justifyContent: "space-between", width: "100%" }}>
         <div className="label">
-          Now processing: <b>{formatTaskLabel(preview)}</b> · task_id: <span className="mono">{preview.task_id}</span>
+          {isPending ? "Up next" : "Now processing"}: <b>{formatTaskLabel(preview)}</b> · task_id:{" "}
+          <span className="mono">{preview.task_id}</span>
+          {preview.next_task !== null ? (
+            <>
+              {" "}
+              · next: <b>{formatTaskLabel(preview.next_task)}</b>
+            </>
+          ) : null}
         </div>
         <div className="label">det: <b>{detections.length}</b></div>
       </div>
@@ -0,0 +1,155 @@
+/**
+ * Responsibility:
+ * - List the tasks (pages) of the selected job from its queue, one page of rows at a time.
+ * - Filter by status so failed pages and their errors can be found without reading the logs.
+ */
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+
+type JobTaskRow = {
+  task_id: number;
+  task_kind: string;
+  source_path: string;
+  pdf_page_index: number | null;
+  pdf_total_pages: number | null;
+  status: string;
+  error_message: string | null;
+  created_unix_timestamp_seconds: number;
+  started_unix_timestamp_seconds: number | null;
+  finished_unix_timestamp_seconds: number | null;
+};
+
+type JobTaskPage = {
+  tasks: JobTaskRow[];
+  total_count: number;
+  offset: number;
+  limit: number;
+};
+
+type TaskStatusFilter = "" | "pending" | "running" | "completed" | "failed";
+
+const TASK_TABLE_PAGE_SIZE = 50;
+
+type TaskTableProps = {
+  jobRootDirectoryPath: string;
+  // NOTE: Any change (e.g. the completed/failed counts) reloads the current page.
+  refreshKey: string;
+};
+
+function formatTaskSource(task: JobTaskRow): string {
+  const filename = task.source_path.split(/[\\/]/).pop() ?? task.source_path;
+  if (task.pdf_page_index === null) {
+    return filename;
+  }
+  return `${filename} p.${task.pdf_page_index + 1}/${task.pdf_total_pages ?? "?"}`;
+}
+
+function formatTaskDuration(task: JobTaskRow): string {
+  if (task.started_unix_timestamp_seconds === null || task.finished_unix_timestamp_seconds === null) {
+    return "—";
+  }
+  return `${Math.max(0, task.finished_unix_timestamp_seconds - task.started_unix_timestamp_seconds)}s`;
+}
+
+export function TaskTable(props: TaskTableProps) {
+  const { jobRootDirectoryPath, refreshKey } = props;
+
+  const [statusFilter, setStatusFilter] = useState<TaskStatusFilter>("");
+  const [offset, setOffset] = useState<number>(0);
+  const [taskPage, setTaskPage] = useState<JobTaskPage | null>(null);
+  const [errorMessage, setErrorMessage] = useState<string | null>(null);
+
+  useEffect(() => {
+    setOffset(0);
+  }, [jobRootDirectoryPath, statusFilter]);
+
+  useEffect(() => {
+    let cancelled = false;
+    invoke<JobTaskPage>("list_job_tasks", {
+      jobRootDirectoryPath,
+      statusFilter: statusFilter === "" ? null : statusFilter,
+      offset,
+      limit: TASK_TABLE_PAGE_SIZE
+    })
+      .then((page) => {
+        if (!cancelled) {
+          setTaskPage(page);
+          setErrorMessage(null);
+        }
+      })
+      .catch((error) => {
+        if (!cancelled) {
+          setErrorMessage(String(error));
+        }
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [jobRootDirectoryPath, statusFilter, offset, refreshKey]);
+
+  const totalCount = taskPage?.total_count ?? 0;
+  const lastShownIndex = Math.min(offset + TASK_TABLE_PAGE_SIZE, totalCount);
+
+  return (
+    <div>
+      <div className="row">
+        <select
+          className="select"
+          value={statusFilter}
+          onChange={(event) => setStatusFilter(event.target.value as TaskStatusFilter)}
+          aria-label="Task status filter"
+        >
+          <option value="">All statuses</option>
+          <option value="pending">Pending</option>
+          <option value="running">Running</option>
+          <option value="completed">Completed</option>
+          <option value="failed">Failed</option>
+        </select>
+        <button
+          className="button"
+          onClick={() => setOffset(Math.max(0, offset - TASK_TABLE_PAGE_SIZE))}
+          disabled={offset === 0}
+        >
+          Prev
+        </button>
+        <button
+          className="button"
+          onClick={() => setOffset(offset + TASK_TABLE_PAGE_SIZE)}
+          disabled={lastShownIndex >= totalCount}
+        >
+          Next
+        </button>
+        <div className="label">{totalCount === 0 ? "No tasks" : `${offset + 1}–${lastShownIndex} of ${totalCount}`}</div>
+      </div>
+      {errorMessage ? (
+        <div className="label" style={{ color: "var(--danger)" }}>
+          {errorMessage}
+        </div>
+      ) : null}
+      {taskPage !== null && taskPage.tasks.length > 0 ? (
+        <table className="label" style={{ width: "100%", marginTop: 8 }}>
+          <thead>
+            <tr>
+              <th style={{ textAlign: "left" }}>#</th>
+              <th style={{ textAlign: "left" }}>Source</th>
+              <th style={{ textAlign: "left" }}>Status</th>
+              <th style={{ textAlign: "left" }}>Time</th>
+              <th style={{ textAlign: "left" }}>Error</th>
+            </tr>
+          </thead>
+          <tbody>
+            {taskPage.tasks.map((task) => (
+              <tr key={task.task_id}>
+                <td className="mono">{task.task_id}</td>
+                <td title={task.source_path}>{formatTaskSource(task)}</td>
+                <td style={{ color: task.status === "failed" ? "var(--danger)" : undefined }}>{task.status}</td>
+                <td>{formatTaskDuration(task)}</td>
+                <td title={task.error_message ?? undefined}>{task.error_message ?? ""}</td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      ) : null}
+    </div>
+  );
+}
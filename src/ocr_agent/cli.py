@@ -1,11 +1,13 @@
 """
 Responsibility:
 - Provide a simple CLI to enqueue inputs, process the queue, and merge Markdown.
+- Finalize a cancelled run into a clearly marked partial Markdown file.
 """
 
 from __future__ import annotations
 
 import argparse
+from datetime import datetime
 from pathlib import Path
 import shutil
 import time
@@ -20,9 +22,16 @@ from ocr_agent.input_discovery import (
     discover_input_paths_in_enqueue_order,
     split_image_and_pdf_paths,
 )
-from ocr_agent.markdown_merge import merge_tasks_into_single_markdown
+from ocr_agent.markdown_merge import (
+    append_tasks_to_rolling_markdown,
+    merge_tasks_into_single_markdown,
+    write_page_reports_for_tasks,
+    write_partial_markdown,
+)
+from ocr_agent.page_assets import copy_page_images_to_assets, rendered_pdf_page_image_path
 from ocr_agent.pdf_render import get_pdf_total_pages, render_pdf_page_to_image_file
-from ocr_agent.queue_store import QueueStore, TASK_KIND_PDF_PAGE
+from ocr_agent.queue_store import QueueStore, QueueTask, TASK_KIND_PDF_PAGE, TASK_STATUS_PENDING, TASK_STATUS_RUNNING
+from ocr_agent.regions import write_regions_sidecar
 
 
 DEFAULT_QUEUE_DATABASE_PATH = Path("/data/queue.sqlite3")
@@ -30,9 +39,18 @@ DEFAULT_OUTPUT_DIRECTORY_PATH = Path("/data/output")
 DEFAULT_MERGED_MARKDOWN_PATH = Path("/data/output.md")
 
 EXIT_CODE_NOTHING_ENQUEUED = 2
+EXIT_CODE_CANCELLED = 3
+
+ROLLING_SECTION_TIMESTAMP_FORMAT = "%Y-%m-%d %H:%M"
 
 UNSAFE_DELETION_PATH_STRINGS = {"", "/", ".", ".."}
 
+# NOTE: Extra workers start alongside the primary process, so they wait for its enqueue step first.
+WORKER_FIRST_TASK_WAIT_SECONDS = 600.0
+WORKER_IDLE_EXIT_SECONDS = 10.0
+WORKER_POLL_INTERVAL_SECONDS = 1.0
+AWAIT_WORKERS_STALL_TIMEOUT_SECONDS = 1800.0
+
 
 def main() -> None:
     argument_parser = argparse.ArgumentParser(prog="ocr-agent")
@@ -83,6 +101,41 @@ def main() -> None:
         action="store_true",
         help="Stop immediately when a task fails",
     )
+    run_parser.add_argument(
+        "--append",
+        dest="append",
+        action="store_true",
+        help="Append this run's tasks to output-md under a dated section instead of rewriting it",
+    )
+    run_parser.add_argument(
+        "--worker",
+        dest="worker",
+        action="store_true",
+        help="Only process tasks (no merge); for extra processes sharing the same queue-db",
+    )
+    run_parser.add_argument(
+        "--await-workers",
+        dest="await_workers",
+        action="store_true",
+        help="Before merging, wait until tasks claimed by other workers are no longer running",
+    )
+
+    finalize_partial_parser = subparsers.add_parser(
+        "finalize-partial",
+        help="Write the pages finished so far to output-md under a partial-output banner (after a cancel)",
+    )
+    finalize_partial_parser.add_argument(
+        "--queue-db",
+        dest="queue_database_path",
+        default=str(DEFAULT_QUEUE_DATABASE_PATH),
+        help="SQLite queue database path",
+    )
+    finalize_partial_parser.add_argument(
+        "--output-md",
+        dest="merged_markdown_path",
+        default=str(DEFAULT_MERGED_MARKDOWN_PATH),
+        help="Partial Markdown output file path",
+    )
 
     status_parser = subparsers.add_parser("status", help="Show queue status counts")
     status_parser.add_argument(
@@ -140,6 +193,16 @@ def main() -> None:
             merged_markdown_path=Path(args.merged_markdown_path),
             save_model_results=bool(args.save_model_results),
             fail_fast=bool(args.fail_fast),
+            append=bool(args.append),
+            worker=bool(args.worker),
+            await_workers=bool(args.await_workers),
+        )
+        return
+
+    if args.command == "finalize-partial":
+        _run_finalize_partial_command(
+            queue_database_path=Path(args.queue_database_path),
+            merged_markdown_path=Path(args.merged_markdown_path),
         )
         return
 
@@ -203,6 +266,9 @@ def _run_run_command(
     *,
     save_model_results: bool,
     fail_fast: bool,
+    append: bool = False,
+    worker: bool = False,
+    await_workers: bool = False,
 ) -> None:
     queue_store = QueueStore(queue_database_path)
     queue_store.initialize()
@@ -221,12 +287,20 @@ def _run_run_command(
 
     processed_tasks_count = 0
     failed_tasks_count = 0
+    processed_task_ids: set[int] = set()
+    is_cancelled = False
     while True:
-        next_task = queue_store.fetch_next_pending_task()
+        if runtime_paths.cancel_marker_path.exists():
+            # NOTE: Checked between tasks only, so the page in progress is finished and recorded before stopping.
+            is_cancelled = True
+            break
+        next_task = queue_store.claim_next_pending_task()
         if next_task is None:
+            wait_seconds = WORKER_IDLE_EXIT_SECONDS if processed_task_ids else WORKER_FIRST_TASK_WAIT_SECONDS
+            if worker and _wait_for_pending_tasks(queue_store, wait_seconds, runtime_paths.cancel_marker_path):
+                continue
             break
 
-        queue_store.mark_task_running(next_task.task_id)
         try:
             task_markdown_path = _process_task_to_markdown(
                 deepseek_runner=deepseek_runner,
@@ -236,18 +310,70 @@ def _run_run_command(
             )
             queue_store.mark_task_completed(next_task.task_id, task_markdown_path)
             processed_tasks_count += 1
+            processed_task_ids.add(next_task.task_id)
+            # NOTE: The GUI turns these result lines into `job://task-finished` events; flush so they arrive per page.
+            print(f"Task completed (task_id={next_task.task_id})", flush=True)
         except Exception as exception:
             queue_store.mark_task_failed(next_task.task_id, repr(exception))
             failed_tasks_count += 1
-            print(f"Task failed (task_id={next_task.task_id}): {repr(exception)}")
+            print(f"Task failed (task_id={next_task.task_id}): {repr(exception)}", flush=True)
             if fail_fast:
                 raise
 
+    if worker:
+        # Guard: the primary process owns the merged Markdown; workers only drain the queue.
+        print(f"Worker processed {processed_tasks_count} task(s), failed {failed_tasks_count} task(s).")
+        return
+
+    if is_cancelled:
+        if await_workers:
+            # NOTE: Workers see the marker too; let them finish their current page instead of being killed mid-page.
+            _wait_for_running_tasks_to_finish(queue_store)
+        # Guard: the GUI writes the partial output (`finalize-partial`); a merge here would look like a full run.
+        print(
+            f"Cancelled: processed {processed_tasks_count} task(s), failed {failed_tasks_count} task(s). "
+            "Remaining tasks stay pending."
+        )
+        raise SystemExit(EXIT_CODE_CANCELLED)
+
+    if await_workers:
+        _wait_for_running_tasks_to_finish(queue_store)
+
     tasks_in_enqueue_order = queue_store.fetch_tasks_in_enqueue_order()
+    if append:
+        # NOTE: Only tasks completed by this run are appended; earlier runs are already in the file.
+        tasks_processed_in_this_run = [
+            task for task in tasks_in_enqueue_order if task.task_id in processed_task_ids
+        ]
+        section_title = datetime.now().astimezone().strftime(ROLLING_SECTION_TIMESTAMP_FORMAT)
+        is_appended = append_tasks_to_rolling_markdown(
+            tasks_processed_in_this_run,
+            runtime_paths.merged_markdown_path,
+            post_processing_settings,
+            section_title,
+            _export_page_images_if_enabled(tasks_processed_in_this_run, runtime_paths, post_processing_settings),
+        )
+        write_page_reports_for_tasks(
+            tasks_processed_in_this_run, runtime_paths.output_directory_path, post_processing_settings
+        )
+        print(
+            f"Processed {processed_tasks_count} task(s), failed {failed_tasks_count} task(s). "
+            + (
+                f"Appended to {runtime_paths.merged_markdown_path}"
+                if is_appended
+                else "Nothing new to append."
+            )
+        )
+        return
+
     merge_tasks_into_single_markdown(
         tasks_in_enqueue_order,
         runtime_paths.merged_markdown_path,
         post_processing_settings,
+        _export_page_images_if_enabled(tasks_in_enqueue_order, runtime_paths, post_processing_settings),
+    )
+    write_page_reports_for_tasks(
+        tasks_in_enqueue_order, runtime_paths.output_directory_path, post_processing_settings
     )
 
     print(
@@ -255,6 +381,60 @@ def _run_run_command(
         f"Merged into {runtime_paths.merged_markdown_path}"
     )
 
+
+def _export_page_images_if_enabled(
+    tasks: list[QueueTask],
+    runtime_paths: RuntimePaths,
+    post_processing_settings: MarkdownPostProcessingSettings,
+) -> dict[int, str] | None:
+    if not post_processing_settings.export_page_images:
+        return None
+    return copy_page_images_to_assets(
+        tasks, runtime_paths.output_directory_path, runtime_paths.merged_markdown_path.parent
+    )
+
+
+def _run_finalize_partial_command(queue_database_path: Path, merged_markdown_path: Path) -> None:
+    queue_store = QueueStore(queue_database_path)
+    queue_store.initialize()
+
+    processed_page_count, total_page_count = write_partial_markdown(
+        queue_store.fetch_tasks_in_enqueue_order(),
+        merged_markdown_path,
+        MarkdownPostProcessingSettings.from_environment(),
+    )
+    print(f"Partial output: processed {processed_page_count} of {total_page_count} page(s). Wrote {merged_markdown_path}")
+
+
+def _wait_for_pending_tasks(queue_store: QueueStore, wait_seconds: float, cancel_marker_path: Path) -> bool:
+    deadline = time.monotonic() + wait_seconds
+    while time.monotonic() < deadline:
+        if cancel_marker_path.exists():
+            return False
+        if queue_store.fetch_status_counts().get(TASK_STATUS_PENDING, 0) > 0:
+            return True
+        time.sleep(WORKER_POLL_INTERVAL_SECONDS)
+    return False
+
+
+def _wait_for_running_tasks_to_finish(queue_store: QueueStore) -> None:
+    last_running_count = -1
+    last_change_monotonic = time.monotonic()
+    while True:
+        running_count = queue_store.fetch_status_counts().get(TASK_STATUS_RUNNING, 0)
+        if running_count == 0:
+            return
+        if running_count != last_running_count:
+            print(f"Waiting for {running_count} task(s) running in other workers...")
+            last_running_count = running_count
+            last_change_monotonic = time.monotonic()
+        # Guard: a crashed worker leaves its task "running" forever; merge what we have instead of hanging.
+        if time.monotonic() - last_change_monotonic > AWAIT_WORKERS_STALL_TIMEOUT_SECONDS:
+            print(f"Gave up waiting for {running_count} running task(s); merging without them.")
+            return
+        time.sleep(WORKER_POLL_INTERVAL_SECONDS)
+
+
 def _run_status_command(queue_database_path: Path) -> None:
     queue_store = QueueStore(queue_database_path)
     queue_store.initialize()
@@ -386,14 +566,20 @@ def _process_task_to_markdown(
     image_file_path = _resolve_task_image_path(runtime_paths=runtime_paths, task=task)
 
     task_output_directory_path = runtime_paths.output_directory_path / f"task_{task.task_id}"
-    inferred_markdown = deepseek_runner.infer_markdown_from_image(
+    inference_result = deepseek_runner.infer_markdown_and_regions_from_image(
         image_file_path=image_file_path,
         output_directory_path=task_output_directory_path,
         save_results=save_model_results,
     )
 
     task_markdown_path = runtime_paths.per_task_markdown_directory_path / f"task_{task.task_id}.md"
-    task_markdown_path.write_text(inferred_markdown, encoding="utf-8")
+    task_markdown_path.write_text(inference_result.markdown, encoding="utf-8")
+
+    write_regions_sidecar(
+        runtime_paths.per_task_regions_directory_path / f"task_{task.task_id}.json",
+        task_id=task.task_id,
+        regions=inference_result.regions,
+    )
     return task_markdown_path
 
 
@@ -413,9 +599,8 @@ def _resolve_task_image_path(runtime_paths: RuntimePaths, task: object) -> Path:
         raise ValueError("pdf_page_index is required for pdf_page task")
 
     pdf_file_path = Path(task.source_path)
-    rendered_image_file_path = (
-        runtime_paths.work_directory_path
-        / f"pdf_{task.task_id}_page_{task.pdf_page_index + 1}.png"
+    rendered_image_file_path = rendered_pdf_page_image_path(
+        runtime_paths.work_directory_path, task.task_id, task.pdf_page_index
     )
 
     if rendered_image_file_path.exists():
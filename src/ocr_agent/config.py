@@ -17,16 +17,45 @@ DEFAULT_BASE_IMAGE_SIZE_PIXELS = 1024
 DEFAULT_INFERENCE_IMAGE_SIZE_PIXELS = 768
 DEFAULT_ENABLE_CROP_MODE = True
 
+# Inference device. "auto" prefers CUDA, then Apple MPS; CPU is only used when requested explicitly.
+INFERENCE_DEVICE_AUTO = "auto"
+INFERENCE_DEVICE_CUDA = "cuda"
+INFERENCE_DEVICE_MPS = "mps"
+INFERENCE_DEVICE_CPU = "cpu"
+SUPPORTED_INFERENCE_DEVICES = {
+    INFERENCE_DEVICE_AUTO,
+    INFERENCE_DEVICE_CUDA,
+    INFERENCE_DEVICE_MPS,
+    INFERENCE_DEVICE_CPU,
+}
+DEFAULT_INFERENCE_DEVICE = INFERENCE_DEVICE_AUTO
+
 # Prompt variants from model card.
 DEFAULT_MARKDOWN_CONVERSION_PROMPT = "<image>\n<|grounding|>Convert the document to markdown. "
 
 # PDF rendering defaults (named to avoid magic numbers).
 DEFAULT_PDF_RENDER_DPI = 200
 
+# Written into output-dir by the GUI's graceful cancel (`JOB_CANCEL_MARKER_FILENAME` in the Tauri backend).
+CANCEL_MARKER_FILENAME = "cancel_requested"
+
 # Markdown post-processing defaults.
 MATH_DELIMITER_STYLE_LATEX = "latex"  # Use \( \) and \[ \]
 MATH_DELIMITER_STYLE_DOLLAR = "dollar"  # Use $ and $$
 DEFAULT_MATH_DELIMITER_STYLE = MATH_DELIMITER_STYLE_DOLLAR
+DEFAULT_NORMALIZE_MATH_DELIMITERS = False  # Also rewrite formulas already in the other style
+DEFAULT_EXPORT_MATHML = False
+DEFAULT_EXPORT_PAGE_IMAGES = False  # Copy page images to output-dir/assets/pages and link them per page
+DEFAULT_REPAIR_HYPHENATION = False
+DEFAULT_REFLOW_PARAGRAPHS = False
+
+# Page separator styles for the combined Markdown.
+PAGE_SEPARATOR_STYLE_HEADING = "heading"  # "## source (page n/total)" header + "---" rule
+PAGE_SEPARATOR_STYLE_TEMPLATE = "template"  # One rendered template line before each page
+PAGE_SEPARATOR_STYLE_NONE = "none"  # Pages are concatenated with blank lines only
+DEFAULT_PAGE_SEPARATOR_STYLE = PAGE_SEPARATOR_STYLE_HEADING
+DEFAULT_PAGE_SEPARATOR_TEMPLATE = "--- page {n} / {total} ---"
+DEFAULT_PAGE_SOURCE_COMMENTS = False
 
 
 @dataclass(frozen=True)
@@ -37,6 +66,7 @@ class DeepSeekOcr2Settings:
     base_image_size_pixels: int
     inference_image_size_pixels: int
     enable_crop_mode: bool
+    inference_device: str = DEFAULT_INFERENCE_DEVICE
 
     @staticmethod
     def from_environment() -> "DeepSeekOcr2Settings":
@@ -71,6 +101,13 @@ class DeepSeekOcr2Settings:
             "1" if DEFAULT_ENABLE_CROP_MODE else "0",
         )
         enable_crop_mode = enable_crop_mode_raw.strip() not in {"0", "false", "False"}
+        inference_device = os.getenv("DEEPSEEK_OCR2_DEVICE", DEFAULT_INFERENCE_DEVICE).strip().lower()
+        if inference_device not in SUPPORTED_INFERENCE_DEVICES:
+            # Guard: a typo would otherwise silently fall back to "auto".
+            raise ValueError(
+                f"Unsupported DEEPSEEK_OCR2_DEVICE: {inference_device} "
+                f"(expected one of {sorted(SUPPORTED_INFERENCE_DEVICES)})"
+            )
 
         return DeepSeekOcr2Settings(
             model_name=model_name,
@@ -79,6 +116,7 @@ class DeepSeekOcr2Settings:
             base_image_size_pixels=base_image_size_pixels,
             inference_image_size_pixels=inference_image_size_pixels,
             enable_crop_mode=enable_crop_mode,
+            inference_device=inference_device,
         )
 
 
@@ -89,6 +127,9 @@ class RuntimePaths:
     merged_markdown_path: Path
     work_directory_path: Path
     per_task_markdown_directory_path: Path
+    per_task_regions_directory_path: Path
+    # NOTE: The GUI creates this file to cancel a run gracefully; the runner stops claiming tasks once it exists.
+    cancel_marker_path: Path
 
     @staticmethod
     def from_arguments(
@@ -98,26 +139,84 @@ class RuntimePaths:
     ) -> "RuntimePaths":
         work_directory_path = output_directory_path / "work"
         per_task_markdown_directory_path = output_directory_path / "markdown_items"
+        per_task_regions_directory_path = output_directory_path / "regions"
+        cancel_marker_path = output_directory_path / CANCEL_MARKER_FILENAME
         return RuntimePaths(
             queue_database_path=queue_database_path,
             output_directory_path=output_directory_path,
             merged_markdown_path=merged_markdown_path,
             work_directory_path=work_directory_path,
             per_task_markdown_directory_path=per_task_markdown_directory_path,
+            per_task_regions_directory_path=per_task_regions_directory_path,
+            cancel_marker_path=cancel_marker_path,
         )
 
 
+def _read_boolean_environment_variable(name: str, default_value: bool) -> bool:
+    raw = os.getenv(name, "1" if default_value else "0").strip()
+    return raw not in {"0", "false", "False", ""}
+
+
 @dataclass(frozen=True)
 class MarkdownPostProcessingSettings:
     math_delimiter_style: str
+    repair_hyphenation: bool = DEFAULT_REPAIR_HYPHENATION
+    reflow_paragraphs: bool = DEFAULT_REFLOW_PARAGRAPHS
+    page_separator_style: str = DEFAULT_PAGE_SEPARATOR_STYLE
+    page_separator_template: str = DEFAULT_PAGE_SEPARATOR_TEMPLATE
+    include_page_source_comments: bool = DEFAULT_PAGE_SOURCE_COMMENTS
+    normalize_math_delimiters: bool = DEFAULT_NORMALIZE_MATH_DELIMITERS
+    export_mathml: bool = DEFAULT_EXPORT_MATHML
+    export_page_images: bool = DEFAULT_EXPORT_PAGE_IMAGES
 
     @staticmethod
     def from_environment() -> "MarkdownPostProcessingSettings":
+        repair_hyphenation = _read_boolean_environment_variable(
+            "OCR_AGENT_REPAIR_HYPHENATION", DEFAULT_REPAIR_HYPHENATION
+        )
+        reflow_paragraphs = _read_boolean_environment_variable(
+            "OCR_AGENT_REFLOW_PARAGRAPHS", DEFAULT_REFLOW_PARAGRAPHS
+        )
+
         raw = os.getenv("OCR_AGENT_MATH_DELIMITER_STYLE", DEFAULT_MATH_DELIMITER_STYLE).strip()
         normalized = raw.lower()
-        if normalized in {MATH_DELIMITER_STYLE_LATEX, MATH_DELIMITER_STYLE_DOLLAR}:
-            return MarkdownPostProcessingSettings(math_delimiter_style=normalized)
+        if normalized not in {MATH_DELIMITER_STYLE_LATEX, MATH_DELIMITER_STYLE_DOLLAR}:
+            # Guard: Unknown value should fall back to a safe default.
+            normalized = DEFAULT_MATH_DELIMITER_STYLE
+        normalize_math_delimiters = _read_boolean_environment_variable(
+            "OCR_AGENT_NORMALIZE_MATH_DELIMITERS", DEFAULT_NORMALIZE_MATH_DELIMITERS
+        )
+        export_mathml = _read_boolean_environment_variable("OCR_AGENT_EXPORT_MATHML", DEFAULT_EXPORT_MATHML)
+        export_page_images = _read_boolean_environment_variable(
+            "OCR_AGENT_EXPORT_PAGE_IMAGES", DEFAULT_EXPORT_PAGE_IMAGES
+        )
 
-        # Guard: Unknown value should fall back to a safe default.
-        return MarkdownPostProcessingSettings(math_delimiter_style=DEFAULT_MATH_DELIMITER_STYLE)
+        page_separator_style = (
+            os.getenv("OCR_AGENT_PAGE_SEPARATOR_STYLE", DEFAULT_PAGE_SEPARATOR_STYLE).strip().lower()
+        )
+        if page_separator_style not in {
+            PAGE_SEPARATOR_STYLE_HEADING,
+            PAGE_SEPARATOR_STYLE_TEMPLATE,
+            PAGE_SEPARATOR_STYLE_NONE,
+        }:
+            # Guard: Unknown value should fall back to a safe default.
+            page_separator_style = DEFAULT_PAGE_SEPARATOR_STYLE
+        page_separator_template = os.getenv("OCR_AGENT_PAGE_SEPARATOR_TEMPLATE", "").strip()
+        if page_separator_template == "":
+            page_separator_template = DEFAULT_PAGE_SEPARATOR_TEMPLATE
+        include_page_source_comments = _read_boolean_environment_variable(
+            "OCR_AGENT_PAGE_SOURCE_COMMENTS", DEFAULT_PAGE_SOURCE_COMMENTS
+        )
+
+        return MarkdownPostProcessingSettings(
+            math_delimiter_style=normalized,
+            repair_hyphenation=repair_hyphenation,
+            reflow_paragraphs=reflow_paragraphs,
+            page_separator_style=page_separator_style,
+            page_separator_template=page_separator_template,
+            include_page_source_comments=include_page_source_comments,
+            normalize_math_delimiters=normalize_math_delimiters,
+            export_mathml=export_mathml,
+            export_page_images=export_page_images,
+        )
 
@@ -2,19 +2,30 @@
 Responsibility:
 - Provide a small, explicit wrapper around DeepSeek-OCR-2 inference.
 - Hide model loading details from the rest of the application.
+- Capture grounding output (region boxes) printed by the model during inference.
 """
 
 from __future__ import annotations
 
+import contextlib
 from dataclasses import dataclass
+import io
 from pathlib import Path
 import shutil
-from typing import Any
+import sys
+from typing import Any, TextIO
 
 import torch
 from transformers import AutoModel, AutoTokenizer
 
-from ocr_agent.config import DeepSeekOcr2Settings
+from ocr_agent.config import (
+    INFERENCE_DEVICE_AUTO,
+    INFERENCE_DEVICE_CPU,
+    INFERENCE_DEVICE_CUDA,
+    INFERENCE_DEVICE_MPS,
+    DeepSeekOcr2Settings,
+)
+from ocr_agent.regions import DetectedRegion, parse_detected_regions
 
 
 DEFAULT_SAVED_MARKDOWN_FILENAME = "result.mmd"
@@ -23,13 +34,63 @@ CUDA_NOT_AVAILABLE_ERROR_MESSAGE = (
     "CUDA GPU is not available inside the container.\n"
     "Verify Docker GPU passthrough first:\n"
     "  docker compose run --rm ocr-agent nvidia-smi\n"
-    "If that fails, fix Docker Desktop GPU support / WSL2 GPU drivers."
+    "If that fails, fix Docker Desktop GPU support / WSL2 GPU drivers.\n"
+    "Without an NVIDIA GPU, set DEEPSEEK_OCR2_DEVICE=cpu (slow) or use the `ocr-agent-cpu` compose service."
 )
+MPS_NOT_AVAILABLE_ERROR_MESSAGE = "DEEPSEEK_OCR2_DEVICE=mps was requested but Apple MPS is not available."
+
+
+class _TeeTextStream(io.TextIOBase):
+    """Forward writes to the original stream while keeping a copy for parsing."""
+
+    def __init__(self, passthrough_stream: TextIO) -> None:
+        self._passthrough_stream = passthrough_stream
+        self._captured_text = io.StringIO()
+
+    def write(self, text: str) -> int:
+        self._passthrough_stream.write(text)
+        self._captured_text.write(text)
+        return len(text)
+
+    def flush(self) -> None:
+        self._passthrough_stream.flush()
+
+    def getvalue(self) -> str:
+        return self._captured_text.getvalue()
+
+
+@dataclass(frozen=True)
+class OcrInferenceResult:
+    markdown: str
+    regions: list[DetectedRegion]
+
+
+def _is_mps_available() -> bool:
+    mps_backend = getattr(torch.backends, "mps", None)
+    return mps_backend is not None and mps_backend.is_available()
+
 
+def _resolve_inference_device(requested_device: str) -> str:
+    if requested_device == INFERENCE_DEVICE_CPU:
+        return INFERENCE_DEVICE_CPU
+    if requested_device == INFERENCE_DEVICE_MPS:
+        if not _is_mps_available():
+            raise RuntimeError(MPS_NOT_AVAILABLE_ERROR_MESSAGE)
+        return INFERENCE_DEVICE_MPS
+    if torch.cuda.is_available():
+        return INFERENCE_DEVICE_CUDA
+    if requested_device == INFERENCE_DEVICE_AUTO and _is_mps_available():
+        return INFERENCE_DEVICE_MPS
+    # Guard: This project targets GPU execution; CPU must be opted into explicitly.
+    raise RuntimeError(CUDA_NOT_AVAILABLE_ERROR_MESSAGE)
 
-def _select_inference_dtype() -> torch.dtype:
+
+def _select_inference_dtype(inference_device: str) -> torch.dtype:
+    if inference_device == INFERENCE_DEVICE_CPU:
+        # Guard: half precision matmuls are missing or very slow on most CPUs.
+        return torch.float32
     # Guard: The model card example uses BF16; fall back when unsupported.
-    if torch.cuda.is_available() and torch.cuda.is_bf16_supported():
+    if inference_device == INFERENCE_DEVICE_CUDA and torch.cuda.is_bf16_supported():
         return torch.bfloat16
     return torch.float16
 
@@ -89,15 +150,15 @@ class DeepSeekOcr2Runner:
         if self._model is not None:
             return self._model
 
-        if not torch.cuda.is_available():
-            # Guard: This project targets GPU execution.
-            raise RuntimeError(CUDA_NOT_AVAILABLE_ERROR_MESSAGE)
-
-        inference_dtype = _select_inference_dtype()
+        inference_device = _resolve_inference_device(self.settings.inference_device)
+        inference_dtype = _select_inference_dtype(inference_device)
 
         # Prefer flash-attn when available, but do not hard-fail if unavailable.
         # Guard: Not all builds accept this argument; fallback if necessary.
+        # NOTE: flash-attn is CUDA-only, so MPS/CPU go straight to the default attention.
         try:
+            if inference_device != INFERENCE_DEVICE_CUDA:
+                raise TypeError("flash_attention_2 requires CUDA")
             model = AutoModel.from_pretrained(
                 self.settings.model_name,
                 revision=self.settings.model_revision,
@@ -115,7 +176,7 @@ class DeepSeekOcr2Runner:
                 torch_dtype=inference_dtype,
             )
 
-        model = model.eval().cuda()
+        model = model.eval().to(inference_device)
         self._model = model
         return self._model
 
@@ -126,6 +187,20 @@ class DeepSeekOcr2Runner:
         *,
         save_results: bool,
     ) -> str:
+        inference_result = self.infer_markdown_and_regions_from_image(
+            image_file_path=image_file_path,
+            output_directory_path=output_directory_path,
+            save_results=save_results,
+        )
+        return inference_result.markdown
+
+    def infer_markdown_and_regions_from_image(
+        self,
+        image_file_path: Path,
+        output_directory_path: Path,
+        *,
+        save_results: bool,
+    ) -> OcrInferenceResult:
         if not image_file_path.exists():
             # Guard: Explicitly surface missing input.
             raise FileNotFoundError(str(image_file_path))
@@ -146,29 +221,34 @@ class DeepSeekOcr2Runner:
         tokenizer = self._get_tokenizer()
         model = self._get_model()
 
-        markdown_result = ""
+        # Guard: grounding tags (<|ref|>/<|det|>) are only printed to stdout, not saved in `result.mmd`.
+        tee_stdout = _TeeTextStream(sys.stdout)
         try:
             # Guard: DeepSeek-OCR-2 may print OCR results to stdout but return an empty value.
             # To reliably obtain Markdown, always enable saving and read `result.mmd` when present.
-            infer_result = model.infer(
-                tokenizer,
-                prompt=self.settings.markdown_prompt,
-                image_file=str(image_file_path),
-                output_path=str(model_output_directory_path),
-                base_size=self.settings.base_image_size_pixels,
-                image_size=self.settings.inference_image_size_pixels,
-                crop_mode=self.settings.enable_crop_mode,
-                save_results=True,
-            )
+            with contextlib.redirect_stdout(tee_stdout):
+                infer_result = model.infer(
+                    tokenizer,
+                    prompt=self.settings.markdown_prompt,
+                    image_file=str(image_file_path),
+                    output_path=str(model_output_directory_path),
+                    base_size=self.settings.base_image_size_pixels,
+                    image_size=self.settings.inference_image_size_pixels,
+                    crop_mode=self.settings.enable_crop_mode,
+                    save_results=True,
+                )
 
             saved_markdown = _read_saved_markdown_if_present(model_output_directory_path)
             if saved_markdown is not None and saved_markdown.strip() != "":
                 markdown_result = saved_markdown
-                return markdown_result
-
-            # Fallback: Some versions might still return the Markdown directly.
-            markdown_result = _normalize_infer_result_to_markdown(infer_result)
-            return markdown_result
+            else:
+                # Fallback: Some versions might still return the Markdown directly.
+                markdown_result = _normalize_infer_result_to_markdown(infer_result)
+
+            regions = parse_detected_regions(tee_stdout.getvalue())
+            if not regions:
+                regions = parse_detected_regions(markdown_result)
+            return OcrInferenceResult(markdown=markdown_result, regions=regions)
         finally:
             if save_results:
                 pass
@@ -2,12 +2,14 @@
 Responsibility:
 - Expand user-provided input paths (files/directories) deterministically.
 - Preserve the user's input order, and provide stable ordering within directories.
+- Order files within a directory naturally (`page2` before `page10`) so multi-part scans merge in sequence.
 """
 
 from __future__ import annotations
 
 from dataclasses import dataclass
-from pathlib import Path
+from pathlib import Path, PurePath
+import re
 from typing import Iterable
 
 
@@ -23,6 +25,10 @@ SUPPORTED_IMAGE_FILE_EXTENSIONS = {
 
 SUPPORTED_PDF_FILE_EXTENSIONS = {".pdf"}
 
+_DIGIT_RUN_PATTERN = re.compile(r"(\d+)")
+# Scanner software on Japanese systems often writes full-width digits (e.g. "ページ１０").
+_FULL_WIDTH_DIGIT_TRANSLATION = str.maketrans("０１２３４５６７８９", "0123456789")
+
 
 @dataclass(frozen=True)
 class InputDiscoveryReport:
@@ -100,7 +106,11 @@ def discover_input_paths_in_enqueue_order(input_paths: Iterable[Path]) -> InputD
 def _list_supported_files_in_directory(directory_path: Path) -> list[Path]:
     # Deterministic order for reproducible queue ordering.
     file_paths: list[Path] = []
-    for candidate_path in sorted(directory_path.rglob("*")):
+    candidate_paths = sorted(
+        directory_path.rglob("*"),
+        key=lambda candidate_path: natural_sort_key(candidate_path.relative_to(directory_path)),
+    )
+    for candidate_path in candidate_paths:
         if not candidate_path.is_file():
             continue
         if not _is_supported_file(candidate_path):
@@ -109,6 +119,23 @@ def _list_supported_files_in_directory(directory_path: Path) -> list[Path]:
     return file_paths
 
 
+def natural_sort_key(path: PurePath) -> tuple[tuple[tuple[object, ...], ...], str]:
+    """
+    Sort key comparing each path component by its text case-insensitively and by its digit runs numerically.
+
+    Mirrors the GUI's `natural_sort.rs`, so bundles and the pages inside them follow the same order.
+    """
+    part_keys = tuple(_natural_name_key(part) for part in path.parts)
+    # Tie-breaker: names equal under the key (e.g. "a01" and "a1") keep a deterministic order.
+    return part_keys, str(path)
+
+
+def _natural_name_key(name: str) -> tuple[object, ...]:
+    chunks = _DIGIT_RUN_PATTERN.split(name.translate(_FULL_WIDTH_DIGIT_TRANSLATION).lower())
+    # re.split with a capture group alternates text and digit runs, so equal positions always hold equal types.
+    return tuple(int(chunk) if index % 2 == 1 else chunk for index, chunk in enumerate(chunks))
+
+
 def _is_supported_file(file_path: Path) -> bool:
     return _is_supported_image_file(file_path) or _is_supported_pdf_file(file_path)
 
@@ -0,0 +1,117 @@
+"""
+Responsibility:
+- Repair OCR line breaks: join soft-hyphenated words and reflow wrapped paragraph lines.
+- Leave structural Markdown (headings, lists, tables, quotes, math, HTML, code) untouched.
+"""
+
+from __future__ import annotations
+
+import re
+
+
+_FENCE_START_PATTERN = re.compile(r"^(`{3,}|~{3,})")
+_STRUCTURAL_LINE_PATTERN = re.compile(
+    r"^(#{1,6}\s|[-*+]\s|\d+[.)]\s|\||>|<|\$\$|!\[|-{3,}\s*$|\*{3,}\s*$|_{3,}\s*$)"
+)
+_SOFT_HYPHEN_END_PATTERN = re.compile(r"[^\W\d_]-$")
+
+
+def repair_line_breaks(markdown_text: str, *, repair_hyphenation: bool, reflow_paragraphs: bool) -> str:
+    if not repair_hyphenation and not reflow_paragraphs:
+        return markdown_text
+
+    output_lines: list[str] = []
+    paragraph_lines: list[str] = []
+    is_inside_fenced_code_block = False
+    fence_marker: str | None = None
+
+    def flush_paragraph() -> None:
+        if paragraph_lines:
+            output_lines.extend(
+                _repair_paragraph(
+                    paragraph_lines,
+                    repair_hyphenation=repair_hyphenation,
+                    reflow_paragraphs=reflow_paragraphs,
+                )
+            )
+            paragraph_lines.clear()
+
+    for line in markdown_text.split("\n"):
+        fence_match = _FENCE_START_PATTERN.match(line)
+        if is_inside_fenced_code_block:
+            output_lines.append(line)
+            if fence_match is not None and fence_marker is not None and fence_match.group(1).startswith(fence_marker[0]):
+                is_inside_fenced_code_block = False
+                fence_marker = None
+            continue
+        if fence_match is not None:
+            flush_paragraph()
+            is_inside_fenced_code_block = True
+            fence_marker = fence_match.group(1)
+            output_lines.append(line)
+            continue
+
+        if _is_paragraph_text_line(line):
+            paragraph_lines.append(line)
+            continue
+
+        # Guard: blank lines and structural lines end the current paragraph and pass through as-is.
+        flush_paragraph()
+        output_lines.append(line)
+
+    flush_paragraph()
+    return "\n".join(output_lines)
+
+
+def _is_paragraph_text_line(line: str) -> bool:
+    if line.strip() == "":
+        return False
+    # Guard: indented lines are list continuations or indented code.
+    if line.startswith(("    ", "\t")):
+        return False
+    return _STRUCTURAL_LINE_PATTERN.match(line.lstrip()) is None
+
+
+def _repair_paragraph(lines: list[str], *, repair_hyphenation: bool, reflow_paragraphs: bool) -> list[str]:
+    repaired_lines: list[str] = [lines[0]]
+    for line in lines[1:]:
+        previous = repaired_lines[-1].rstrip()
+        current = line.strip()
+
+        # NOTE: Markdown hard breaks (two trailing spaces or a backslash) are intentional.
+        is_hard_break = repaired_lines[-1].endswith("  ") or previous.endswith("\\")
+
+        if (
+            repair_hyphenation
+            and not is_hard_break
+            and _SOFT_HYPHEN_END_PATTERN.search(previous) is not None
+            and current[:1].islower()
+        ):
+            repaired_lines[-1] = previous[:-1] + current
+            continue
+
+        if reflow_paragraphs and not is_hard_break:
+            repaired_lines[-1] = previous + _join_separator(previous, current) + current
+            continue
+
+        repaired_lines.append(line)
+    return repaired_lines
+
+
+def _join_separator(previous: str, current: str) -> str:
+    # Guard: CJK text is written without spaces between wrapped lines.
+    if _is_cjk_character(previous[-1]) and _is_cjk_character(current[0]):
+        return ""
+    return " "
+
+
+def _is_cjk_character(character: str) -> bool:
+    code_point = ord(character)
+    return (
+        0x3000 <= code_point <= 0x30FF
+        or 0x3400 <= code_point <= 0x4DBF
+        or 0x4E00 <= code_point <= 0x9FFF
+        or 0xF900 <= code_point <= 0xFAFF
+        or 0xFF00 <= code_point <= 0xFFEF
+        or 0xAC00 <= code_point <= 0xD7AF
+    )
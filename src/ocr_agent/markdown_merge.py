@@ -1,139 +1,217 @@
 """
 Responsibility:
 - Merge per-task Markdown into one Markdown file in enqueue order.
+- Optionally append a run's tasks to a cumulative (rolling) Markdown file.
+- Write a clearly marked partial Markdown file when a run is cancelled.
+- Check the merged pages (math, Markdown structure) and write the reports next to the per-task outputs.
+- Link each page section to its page image when the images were exported to output-dir/assets/pages.
 """
 
 from __future__ import annotations
 
+import itertools
 from pathlib import Path
-import re
 
 from ocr_agent.config import (
-    MATH_DELIMITER_STYLE_DOLLAR,
+    DEFAULT_PAGE_SEPARATOR_TEMPLATE,
+    PAGE_SEPARATOR_STYLE_HEADING,
+    PAGE_SEPARATOR_STYLE_TEMPLATE,
     MarkdownPostProcessingSettings,
 )
-from ocr_agent.queue_store import QueueTask, TASK_KIND_IMAGE, TASK_KIND_PDF_PAGE
+from ocr_agent.line_repair import repair_line_breaks
+from ocr_agent.markdown_validation import write_markdown_validation_report
+from ocr_agent.math_postprocess import post_process_math, write_math_report
+from ocr_agent.page_reports import PageMarkdown
+from ocr_agent.queue_store import QueueTask, TASK_KIND_IMAGE, TASK_KIND_PDF_PAGE, TASK_STATUS_COMPLETED
+
+
+MERGED_MARKDOWN_TITLE_LINE = "# OCR Output"
+PARTIAL_OUTPUT_BANNER_PREFIX = "> **Partial output (run cancelled)**"
 
 
 def merge_tasks_into_single_markdown(
     tasks_in_enqueue_order: list[QueueTask],
     merged_markdown_path: Path,
     post_processing_settings: MarkdownPostProcessingSettings,
+    page_image_links: dict[int, str] | None = None,
 ) -> None:
     merged_markdown_path.parent.mkdir(parents=True, exist_ok=True)
 
     merged_lines: list[str] = []
-    merged_lines.append("# OCR Output")
+    merged_lines.append(MERGED_MARKDOWN_TITLE_LINE)
     merged_lines.append("")
+    merged_lines.extend(
+        _render_task_section_lines(tasks_in_enqueue_order, post_processing_settings, page_image_links)
+    )
 
-    for task in tasks_in_enqueue_order:
-        if task.output_markdown_path is None:
-            continue
-        task_markdown_path = Path(task.output_markdown_path)
-        if not task_markdown_path.exists():
-            continue
-
-        task_markdown = task_markdown_path.read_text(encoding="utf-8")
-        if task_markdown.strip() == "":
-            continue
+    merged_markdown_path.write_text("\n".join(merged_lines).rstrip() + "\n", encoding="utf-8")
 
-        merged_lines.extend(_render_task_header_lines(task))
-        merged_lines.append("")
-        merged_lines.append(_post_process_task_markdown(task_markdown, post_processing_settings))
-        merged_lines.append("")
-        merged_lines.append("---")
-        merged_lines.append("")
 
-    merged_markdown_path.write_text("\n".join(merged_lines).rstrip() + "\n", encoding="utf-8")
+def append_tasks_to_rolling_markdown(
+    tasks_in_enqueue_order: list[QueueTask],
+    rolling_markdown_path: Path,
+    post_processing_settings: MarkdownPostProcessingSettings,
+    section_title: str,
+    page_image_links: dict[int, str] | None = None,
+) -> bool:
+    """
+    Append one dated section to a cumulative Markdown file.
 
+    Returns False (and leaves the file untouched) when no task produced Markdown.
+    """
 
-_INLINE_LATEX_MATH_PATTERN = re.compile(r"\\\((.+?)\\\)", flags=re.DOTALL)
-_BLOCK_LATEX_MATH_PATTERN = re.compile(r"\\\[(.+?)\\\]", flags=re.DOTALL)
-_FENCE_START_PATTERN = re.compile(r"^(\s*)(`{3,}|~{3,})")
+    task_section_lines = _render_task_section_lines(
+        tasks_in_enqueue_order, post_processing_settings, page_image_links
+    )
+    if not task_section_lines:
+        return False
 
+    rolling_markdown_path.parent.mkdir(parents=True, exist_ok=True)
+    existing_markdown = ""
+    if rolling_markdown_path.exists():
+        existing_markdown = rolling_markdown_path.read_text(encoding="utf-8").rstrip()
+    if existing_markdown == "":
+        existing_markdown = MERGED_MARKDOWN_TITLE_LINE
 
-def _post_process_task_markdown(
-    task_markdown: str, post_processing_settings: MarkdownPostProcessingSettings
-) -> str:
-    if post_processing_settings.math_delimiter_style != MATH_DELIMITER_STYLE_DOLLAR:
-        return task_markdown
-    return _convert_latex_math_delimiters_to_dollar(task_markdown)
+    appended_lines: list[str] = [existing_markdown, "", f"## {section_title}", ""]
+    appended_lines.extend(task_section_lines)
+    rolling_markdown_path.write_text("\n".join(appended_lines).rstrip() + "\n", encoding="utf-8")
+    return True
 
 
-def _convert_latex_math_delimiters_to_dollar(markdown_text: str) -> str:
+def write_partial_markdown(
+    tasks_in_enqueue_order: list[QueueTask],
+    merged_markdown_path: Path,
+    post_processing_settings: MarkdownPostProcessingSettings,
+) -> tuple[int, int]:
     """
-    Convert LaTeX-style delimiters to dollar-style delimiters:
-    - \\( ... \\)  -> $...$
-    - \\[ ... \\]  -> $$\\n...\\n$$
+    Write the leading completed tasks under a banner that marks the file as partial.
+
+    Returns (processed page count, total page count).
 
     Guard:
-    - Do not rewrite fenced code blocks (``` / ~~~).
+    - Pages finished after the first unfinished one (by other workers) are left out, so "pages 1-N" stays exact.
     """
 
-    lines = markdown_text.splitlines(keepends=True)
-    converted_chunks: list[str] = []
-    is_inside_fenced_code_block = False
-    fence_marker: str | None = None
-
-    current_non_code_chunk: list[str] = []
-
-    def flush_non_code_chunk() -> None:
-        if not current_non_code_chunk:
-            return
-        raw_chunk = "".join(current_non_code_chunk)
-        converted_chunks.append(_convert_latex_math_delimiters_in_plain_markdown(raw_chunk))
-        current_non_code_chunk.clear()
-
-    for line in lines:
-        fence_match = _FENCE_START_PATTERN.match(line)
-        if fence_match is None:
-            if is_inside_fenced_code_block:
-                converted_chunks.append(line)
-            else:
-                current_non_code_chunk.append(line)
-            continue
+    processed_tasks = list(
+        itertools.takewhile(lambda task: task.status == TASK_STATUS_COMPLETED, tasks_in_enqueue_order)
+    )
+    processed_page_count = len(processed_tasks)
+    total_page_count = len(tasks_in_enqueue_order)
+    if processed_page_count == 0:
+        banner_line = f"{PARTIAL_OUTPUT_BANNER_PREFIX}: no pages were processed (0 of {total_page_count})."
+    else:
+        banner_line = (
+            f"{PARTIAL_OUTPUT_BANNER_PREFIX}: processed pages 1\u2013{processed_page_count} of {total_page_count}."
+        )
 
-        indent, marker = fence_match.group(1), fence_match.group(2)
-        if indent.strip() != "":
-            # Guard: indented fences are treated as plain text.
-            if is_inside_fenced_code_block:
-                converted_chunks.append(line)
-            else:
-                current_non_code_chunk.append(line)
-            continue
+    merged_markdown_path.parent.mkdir(parents=True, exist_ok=True)
+    merged_lines: list[str] = [MERGED_MARKDOWN_TITLE_LINE, "", banner_line, ""]
+    merged_lines.extend(_render_task_section_lines(processed_tasks, post_processing_settings))
+    merged_markdown_path.write_text("\n".join(merged_lines).rstrip() + "\n", encoding="utf-8")
+    return processed_page_count, total_page_count
 
-        if not is_inside_fenced_code_block:
-            flush_non_code_chunk()
-            is_inside_fenced_code_block = True
-            fence_marker = marker
-            converted_chunks.append(line)
-            continue
 
-        if fence_marker is not None and marker.startswith(fence_marker[0]):
-            is_inside_fenced_code_block = False
-            fence_marker = None
-            converted_chunks.append(line)
+def write_page_reports_for_tasks(
+    tasks_in_enqueue_order: list[QueueTask],
+    output_directory_path: Path,
+    post_processing_settings: MarkdownPostProcessingSettings,
+) -> None:
+    # NOTE: Page numbers follow the same numbering as the merged file's separators ({n} / page=n/total).
+    pages = [
+        PageMarkdown(
+            page_number=page_index + 1,
+            source_path=task.source_path,
+            source_page_number=None if task.pdf_page_index is None else task.pdf_page_index + 1,
+            markdown=task_markdown,
+        )
+        for page_index, (task, task_markdown) in enumerate(_read_task_markdowns(tasks_in_enqueue_order))
+    ]
+    write_math_report(pages, output_directory_path, post_processing_settings)
+    write_markdown_validation_report(pages, output_directory_path)
+
+
+def _read_task_markdowns(tasks_in_enqueue_order: list[QueueTask]) -> list[tuple[QueueTask, str]]:
+    task_markdowns: list[tuple[QueueTask, str]] = []
+    for task in tasks_in_enqueue_order:
+        if task.output_markdown_path is None:
+            continue
+        task_markdown_path = Path(task.output_markdown_path)
+        if not task_markdown_path.exists():
             continue
 
-        converted_chunks.append(line)
-
-    flush_non_code_chunk()
-    return "".join(converted_chunks)
+        task_markdown = task_markdown_path.read_text(encoding="utf-8")
+        if task_markdown.strip() == "":
+            continue
+        task_markdowns.append((task, task_markdown))
+    return task_markdowns
 
 
-def _convert_latex_math_delimiters_in_plain_markdown(markdown_text: str) -> str:
-    def replace_block(match: re.Match[str]) -> str:
-        content = match.group(1)
-        content = content.strip("\n")
-        return f"$$\n{content}\n$$"
+def _render_task_section_lines(
+    tasks_in_enqueue_order: list[QueueTask],
+    post_processing_settings: MarkdownPostProcessingSettings,
+    page_image_links: dict[int, str] | None = None,
+) -> list[str]:
+    task_markdowns = _read_task_markdowns(tasks_in_enqueue_order)
+    section_lines: list[str] = []
+    total_pages = len(task_markdowns)
+    for page_index, (task, task_markdown) in enumerate(task_markdowns):
+        page_number_human = page_index + 1
+        if post_processing_settings.include_page_source_comments:
+            section_lines.append(_render_source_comment_line(task, page_number_human, total_pages))
+        if post_processing_settings.page_separator_style == PAGE_SEPARATOR_STYLE_HEADING:
+            section_lines.extend(_render_task_header_lines(task))
+            section_lines.append("")
+        elif post_processing_settings.page_separator_style == PAGE_SEPARATOR_STYLE_TEMPLATE:
+            section_lines.append(
+                _render_page_separator_template(
+                    post_processing_settings.page_separator_template, task, page_number_human, total_pages
+                )
+            )
+            section_lines.append("")
+        if page_image_links is not None and task.task_id in page_image_links:
+            section_lines.append(f"![Page {page_number_human}]({page_image_links[task.task_id]})")
+            section_lines.append("")
+        section_lines.append(_post_process_task_markdown(task_markdown, post_processing_settings))
+        section_lines.append("")
+        if post_processing_settings.page_separator_style == PAGE_SEPARATOR_STYLE_HEADING:
+            section_lines.append("---")
+            section_lines.append("")
+    return section_lines
 
-    def replace_inline(match: re.Match[str]) -> str:
-        content = match.group(1)
-        content = content.strip()
-        return f"${content}$"
 
-    after_block = _BLOCK_LATEX_MATH_PATTERN.sub(replace_block, markdown_text)
-    return _INLINE_LATEX_MATH_PATTERN.sub(replace_inline, after_block)
+def _post_process_task_markdown(
+    task_markdown: str, post_processing_settings: MarkdownPostProcessingSettings
+) -> str:
+    return repair_line_breaks(
+        post_process_math(task_markdown, post_processing_settings),
+        repair_hyphenation=post_processing_settings.repair_hyphenation,
+        reflow_paragraphs=post_processing_settings.reflow_paragraphs,
+    )
+
+
+def _render_page_separator_template(template: str, task: QueueTask, page_number: int, total_pages: int) -> str:
+    placeholders = {
+        "n": page_number,
+        "total": total_pages,
+        "source": task.source_path,
+        "source_page": "" if task.pdf_page_index is None else task.pdf_page_index + 1,
+        "source_total": "" if task.pdf_total_pages is None else task.pdf_total_pages,
+    }
+    try:
+        return template.format_map(placeholders)
+    except (KeyError, IndexError, ValueError):
+        # Guard: a malformed user template should not fail the whole merge.
+        return DEFAULT_PAGE_SEPARATOR_TEMPLATE.format_map(placeholders)
+
+
+def _render_source_comment_line(task: QueueTask, page_number: int, total_pages: int) -> str:
+    source_page = ""
+    if task.pdf_page_index is not None and task.pdf_total_pages is not None:
+        source_page = f" source_page={task.pdf_page_index + 1}/{task.pdf_total_pages}"
+    # Guard: "--" is not allowed inside HTML comments.
+    source_path = task.source_path.replace("--", "%2D%2D")
+    return f"<!-- page={page_number}/{total_pages} source={source_path}{source_page} -->"
 
 
 def _render_task_header_lines(task: QueueTask) -> list[str]:
@@ -0,0 +1,228 @@
+"""
+Responsibility:
+- Check each page's OCR Markdown for broken structure: tables, unclosed code fences, malformed links.
+- Write the issues per page (with line numbers) so reviewers can jump straight to the problem sections.
+"""
+
+from __future__ import annotations
+
+from dataclasses import asdict, dataclass
+from pathlib import Path
+import re
+
+from ocr_agent.page_reports import PageMarkdown, write_json_report
+
+
+MARKDOWN_VALIDATION_REPORT_FILENAME = "markdown_validation.json"
+
+ISSUE_KIND_TABLE = "table"
+ISSUE_KIND_CODE_FENCE = "code_fence"
+ISSUE_KIND_LINK = "link"
+
+_FENCE_PATTERN = re.compile(r"^ {0,3}(`{3,}|~{3,})")
+_TABLE_DELIMITER_CELL_PATTERN = re.compile(r"^:?-{1,}:?$")
+_LINK_OPENER_PATTERN = re.compile(r"!?\[([^\]\n]*)\]\(")
+_INLINE_CODE_PATTERN = re.compile(r"(`+)(?:(?!\1).)+?\1")
+_LINK_TITLE_PATTERN = re.compile(r"^\S+\s+(\"[^\"]*\"|'[^']*'|\([^)]*\))$")
+_HTML_TABLE_TAG_PATTERN = re.compile(r"<(/?)table\b[^>]*>", flags=re.IGNORECASE)
+
+
+@dataclass(frozen=True)
+class MarkdownIssue:
+    kind: str
+    # NOTE: 1-based, relative to the page's own Markdown (`markdown_items/task_<id>.md`).
+    line_number: int
+    message: str
+
+
+@dataclass(frozen=True)
+class PageMarkdownIssue:
+    page_number: int
+    source_path: str
+    source_page_number: int | None
+    kind: str
+    line_number: int
+    message: str
+
+
+def find_markdown_issues(markdown_text: str) -> list[MarkdownIssue]:
+    issues: list[MarkdownIssue] = []
+    table_lines: list[tuple[int, str]] = []
+    html_table_opener_line_numbers: list[int] = []
+    fence_marker: str | None = None
+    fence_line_number = 0
+
+    def flush_table() -> None:
+        if table_lines:
+            issues.extend(_find_table_issues(table_lines))
+            table_lines.clear()
+
+    for line_index, line in enumerate(markdown_text.split("\n")):
+        line_number = line_index + 1
+        fence_match = _FENCE_PATTERN.match(line)
+        if fence_marker is not None:
+            # NOTE: A closing fence uses the same character and is at least as long as the opener.
+            is_closing_fence = (
+                fence_match is not None
+                and fence_match.group(1)[0] == fence_marker[0]
+                and len(fence_match.group(1)) >= len(fence_marker)
+                and line.strip() == fence_match.group(1)
+            )
+            if is_closing_fence:
+                fence_marker = None
+            continue
+        if fence_match is not None:
+            flush_table()
+            fence_marker = fence_match.group(1)
+            fence_line_number = line_number
+            continue
+
+        if line.lstrip().startswith("|"):
+            table_lines.append((line_number, line))
+            continue
+        flush_table()
+        issues.extend(_find_link_issues(line, line_number))
+        # NOTE: The model often emits tables as HTML; only <table> itself is checked since </tr> and </td> are optional.
+        for tag_match in _HTML_TABLE_TAG_PATTERN.finditer(line):
+            if tag_match.group(1) == "":
+                html_table_opener_line_numbers.append(line_number)
+            elif html_table_opener_line_numbers:
+                html_table_opener_line_numbers.pop()
+            else:
+                issues.append(MarkdownIssue(ISSUE_KIND_TABLE, line_number, "</table> without a matching <table>."))
+    flush_table()
+
+    for opener_line_number in html_table_opener_line_numbers:
+        issues.append(MarkdownIssue(ISSUE_KIND_TABLE, opener_line_number, "<table> is never closed with </table>."))
+
+    if fence_marker is not None:
+        issues.append(
+            MarkdownIssue(
+                ISSUE_KIND_CODE_FENCE,
+                fence_line_number,
+                f"Code block opened with {fence_marker} is never closed; the rest of the page renders as code.",
+            )
+        )
+    issues.sort(key=lambda issue: issue.line_number)
+    return issues
+
+
+def _split_table_cells(line: str) -> list[str]:
+    stripped = line.strip()
+    if stripped.startswith("|"):
+        stripped = stripped[1:]
+    if stripped.endswith("|") and not stripped.endswith("\\|"):
+        stripped = stripped[:-1]
+    # NOTE: `\|` is a literal pipe inside a cell.
+    return [cell.strip() for cell in re.split(r"(?<!\\)\|", stripped)]
+
+
+def _find_table_issues(table_lines: list[tuple[int, str]]) -> list[MarkdownIssue]:
+    header_line_number, header_line = table_lines[0]
+    header_cells = _split_table_cells(header_line)
+    if len(table_lines) < 2 or not _is_table_delimiter_row(table_lines[1][1]):
+        return [
+            MarkdownIssue(
+                ISSUE_KIND_TABLE,
+                header_line_number,
+                "Table has no delimiter row (| --- |) under its header, so it renders as plain text.",
+            )
+        ]
+
+    issues: list[MarkdownIssue] = []
+    delimiter_line_number, delimiter_line = table_lines[1]
+    delimiter_cell_count = len(_split_table_cells(delimiter_line))
+    if delimiter_cell_count != len(header_cells):
+        issues.append(
+            MarkdownIssue(
+                ISSUE_KIND_TABLE,
+                delimiter_line_number,
+                f"Table delimiter row has {delimiter_cell_count} column(s) but the header has {len(header_cells)}.",
+            )
+        )
+    for line_number, line in table_lines[2:]:
+        cell_count = len(_split_table_cells(line))
+        if cell_count != len(header_cells):
+            issues.append(
+                MarkdownIssue(
+                    ISSUE_KIND_TABLE,
+                    line_number,
+                    f"Table row has {cell_count} cell(s) but the header has {len(header_cells)}.",
+                )
+            )
+    return issues
+
+
+def _is_table_delimiter_row(line: str) -> bool:
+    cells = _split_table_cells(line)
+    return len(cells) > 0 and all(_TABLE_DELIMITER_CELL_PATTERN.match(cell) for cell in cells)
+
+
+def _find_link_issues(line: str, line_number: int) -> list[MarkdownIssue]:
+    # Guard: brackets inside inline code (`a[i](x)`) are not links.
+    text = _INLINE_CODE_PATTERN.sub(lambda match: " " * len(match.group(0)), line)
+    issues: list[MarkdownIssue] = []
+    for link_match in _LINK_OPENER_PATTERN.finditer(text):
+        label = "Image" if link_match.group(0).startswith("!") else "Link"
+        destination_start = link_match.end()
+        destination_end = _find_link_destination_end(text, destination_start)
+        if destination_end is None:
+            issues.append(
+                MarkdownIssue(ISSUE_KIND_LINK, line_number, f"{label} [{link_match.group(1)}] is missing its closing ).")
+            )
+            continue
+        destination = text[destination_start:destination_end].strip()
+        if destination == "":
+            issues.append(MarkdownIssue(ISSUE_KIND_LINK, line_number, f"{label} [{link_match.group(1)}] has an empty URL."))
+            continue
+        has_unquoted_space = re.search(r"\s", destination) is not None and not (
+            destination.startswith("<") or _LINK_TITLE_PATTERN.match(destination)
+        )
+        if has_unquoted_space:
+            issues.append(
+                MarkdownIssue(
+                    ISSUE_KIND_LINK,
+                    line_number,
+                    f"{label} [{link_match.group(1)}] has spaces in its URL ({destination}); it will not render as a link.",
+                )
+            )
+    return issues
+
+
+def _find_link_destination_end(text: str, destination_start: int) -> int | None:
+    depth = 0
+    index = destination_start
+    while index < len(text):
+        character = text[index]
+        if character == "\\":
+            index += 2
+            continue
+        if character == "(":
+            depth += 1
+        elif character == ")":
+            if depth == 0:
+                return index
+            depth -= 1
+        index += 1
+    return None
+
+
+def write_markdown_validation_report(pages: list[PageMarkdown], output_directory_path: Path) -> Path:
+    page_issues: list[PageMarkdownIssue] = []
+    for page in pages:
+        page_issues.extend(
+            PageMarkdownIssue(
+                page_number=page.page_number,
+                source_path=page.source_path,
+                source_page_number=page.source_page_number,
+                kind=issue.kind,
+                line_number=issue.line_number,
+                message=issue.message,
+            )
+            for issue in find_markdown_issues(page.markdown)
+        )
+    report = {
+        "checked_page_count": len(pages),
+        "issues": [asdict(issue) for issue in page_issues],
+    }
+    return write_json_report(output_directory_path, MARKDOWN_VALIDATION_REPORT_FILENAME, report)
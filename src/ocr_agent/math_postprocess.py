@@ -0,0 +1,374 @@
+"""
+Responsibility:
+- Find math spans (\\( \\), \\[ \\], $, $$) outside fenced code blocks and rewrite them to one delimiter style.
+- Report unbalanced delimiters and braces per page so reviewers can find broken formulas.
+- Optionally export every formula as MathML next to the combined Markdown.
+"""
+
+from __future__ import annotations
+
+from dataclasses import asdict, dataclass
+import html
+from pathlib import Path
+import re
+
+from ocr_agent.config import (
+    MATH_DELIMITER_STYLE_DOLLAR,
+    MATH_DELIMITER_STYLE_LATEX,
+    MarkdownPostProcessingSettings,
+)
+from ocr_agent.page_reports import PageMarkdown, write_json_report
+
+
+MATH_REPORT_FILENAME = "math_report.json"
+MATHML_EXPORT_FILENAME = "math.html"
+
+_FENCE_START_PATTERN = re.compile(r"^(\s*)(`{3,}|~{3,})")
+_LEFT_COMMAND_PATTERN = re.compile(r"\\left(?![A-Za-z])")
+_RIGHT_COMMAND_PATTERN = re.compile(r"\\right(?![A-Za-z])")
+
+
+@dataclass(frozen=True)
+class MathSpan:
+    is_display: bool
+    tex: str
+    # NOTE: "latex" for \( \) / \[ \], "dollar" for $ / $$.
+    delimiter_style: str
+    start: int
+    end: int
+
+
+@dataclass(frozen=True)
+class MathWarning:
+    # NOTE: 1-based, relative to the page's own Markdown (before the page separator is added).
+    line_number: int
+    message: str
+
+
+@dataclass(frozen=True)
+class PageMathWarning:
+    page_number: int
+    source_path: str
+    source_page_number: int | None
+    line_number: int
+    message: str
+
+
+def split_fenced_code_segments(markdown_text: str) -> list[tuple[bool, int, str]]:
+    """
+    Split Markdown into (is_code, start_offset, text) segments.
+
+    Guard:
+    - Indented fences are treated as plain text; an unclosed fence runs to the end of the text.
+    """
+
+    segments: list[tuple[bool, int, str]] = []
+    current_lines: list[str] = []
+    current_start = 0
+    current_is_code = False
+    fence_marker: str | None = None
+    offset = 0
+
+    def flush(next_start: int, next_is_code: bool) -> None:
+        nonlocal current_start, current_is_code
+        if current_lines:
+            segments.append((current_is_code, current_start, "".join(current_lines)))
+            current_lines.clear()
+        current_start = next_start
+        current_is_code = next_is_code
+
+    for line in markdown_text.splitlines(keepends=True):
+        fence_match = _FENCE_START_PATTERN.match(line)
+        is_fence_line = fence_match is not None and fence_match.group(1).strip() == ""
+        if is_fence_line and not current_is_code:
+            flush(offset, True)
+            fence_marker = fence_match.group(2)
+            current_lines.append(line)
+        elif is_fence_line and fence_marker is not None and fence_match.group(2).startswith(fence_marker[0]):
+            current_lines.append(line)
+            fence_marker = None
+            flush(offset + len(line), False)
+        else:
+            current_lines.append(line)
+        offset += len(line)
+    flush(offset, False)
+    return segments
+
+
+def scan_math_spans(plain_markdown: str) -> tuple[list[MathSpan], list[tuple[int, str]]]:
+    """
+    Find math spans in Markdown that contains no fenced code.
+
+    Returns (spans, problems) where each problem is (offset, message).
+    Inline `$` follows the Pandoc rule (no space inside the delimiters, no digit after the closer),
+    plus a currency guard, so amounts like "$5 and $10" are not mistaken for math.
+    """
+
+    spans: list[MathSpan] = []
+    problems: list[tuple[int, str]] = []
+    index = 0
+    length = len(plain_markdown)
+    while index < length:
+        character = plain_markdown[index]
+        if character == "\\" and index + 1 < length:
+            next_character = plain_markdown[index + 1]
+            if next_character in "([":
+                opener = "\\" + next_character
+                closer = "\\)" if next_character == "(" else "\\]"
+                end = plain_markdown.find(closer, index + 2)
+                next_opener = plain_markdown.find(opener, index + 2)
+                # Guard: another opener before the closer means this one was never closed.
+                if end == -1 or (next_opener != -1 and next_opener < end):
+                    problems.append((index, f"Unclosed {opener} (no matching {closer})."))
+                    index += 2
+                    continue
+                spans.append(
+                    MathSpan(
+                        is_display=next_character == "[",
+                        tex=plain_markdown[index + 2 : end],
+                        delimiter_style=MATH_DELIMITER_STYLE_LATEX,
+                        start=index,
+                        end=end + 2,
+                    )
+                )
+                index = end + 2
+                continue
+            if next_character in ")]":
+                problems.append((index, f"Stray \\{next_character} without a matching opener."))
+            # NOTE: Any other escape (\$, \\, \{) is skipped as a unit so it never opens math.
+            index += 2
+            continue
+        if character == "$" and plain_markdown.startswith("$$", index):
+            end = plain_markdown.find("$$", index + 2)
+            if end == -1:
+                problems.append((index, "Unclosed $$ (no matching $$)."))
+                index += 2
+                continue
+            spans.append(
+                MathSpan(
+                    is_display=True,
+                    tex=plain_markdown[index + 2 : end],
+                    delimiter_style=MATH_DELIMITER_STYLE_DOLLAR,
+                    start=index,
+                    end=end + 2,
+                )
+            )
+            index = end + 2
+            continue
+        if character == "$":
+            end = _find_inline_dollar_closer(plain_markdown, index)
+            if end is not None:
+                spans.append(
+                    MathSpan(
+                        is_display=False,
+                        tex=plain_markdown[index + 1 : end],
+                        delimiter_style=MATH_DELIMITER_STYLE_DOLLAR,
+                        start=index,
+                        end=end + 1,
+                    )
+                )
+                index = end + 1
+                continue
+            next_character = plain_markdown[index + 1] if index + 1 < length else ""
+            # Guard: "$5" or a lone "$ " is currency or prose, not a broken formula.
+            if next_character != "" and not next_character.isspace() and not next_character.isdigit():
+                problems.append((index, "Unclosed $ (no matching $ in the same paragraph)."))
+        index += 1
+    return spans, problems
+
+
+def _find_inline_dollar_closer(plain_markdown: str, opener_index: int) -> int | None:
+    length = len(plain_markdown)
+    first_content_index = opener_index + 1
+    if first_content_index >= length or plain_markdown[first_content_index].isspace():
+        return None
+    paragraph_end = plain_markdown.find("\n\n", first_content_index)
+    search_end = length if paragraph_end == -1 else paragraph_end
+    # NOTE: "$5 ... $" is far more often two prices than a formula, so a digit-led span may not contain spaces.
+    is_digit_led = plain_markdown[first_content_index].isdigit()
+    index = first_content_index
+    while index < search_end:
+        character = plain_markdown[index]
+        if is_digit_led and character.isspace():
+            return None
+        if character == "\\":
+            index += 2
+            continue
+        if character == "$":
+            is_after_content = index > first_content_index and not plain_markdown[index - 1].isspace()
+            is_followed_by_digit = index + 1 < length and plain_markdown[index + 1].isdigit()
+            if is_after_content and not is_followed_by_digit:
+                return index
+            if plain_markdown.startswith("$$", index):
+                # Guard: a display opener ends any inline candidate.
+                return None
+        index += 1
+    return None
+
+
+def find_formula_problems(tex: str) -> list[str]:
+    problems: list[str] = []
+    depth = 0
+    index = 0
+    while index < len(tex):
+        character = tex[index]
+        if character == "\\":
+            index += 2
+            continue
+        if character == "{":
+            depth += 1
+        elif character == "}":
+            depth -= 1
+            if depth < 0:
+                problems.append("Formula has a closing } without a matching {.")
+                depth = 0
+        index += 1
+    if depth > 0:
+        problems.append(f"Formula has {depth} unclosed {{.")
+    left_count = len(_LEFT_COMMAND_PATTERN.findall(tex))
+    right_count = len(_RIGHT_COMMAND_PATTERN.findall(tex))
+    if left_count != right_count:
+        problems.append(f"Formula has {left_count} \\left but {right_count} \\right.")
+    return problems
+
+
+def render_math_span(span: MathSpan, delimiter_style: str) -> str:
+    if span.is_display:
+        display_tex = span.tex.strip("\n")
+        if delimiter_style == MATH_DELIMITER_STYLE_DOLLAR:
+            return f"$$\n{display_tex}\n$$"
+        return f"\\[\n{display_tex}\n\\]"
+    inline_tex = span.tex.strip()
+    if delimiter_style == MATH_DELIMITER_STYLE_DOLLAR:
+        return f"${inline_tex}$"
+    return f"\\({inline_tex}\\)"
+
+
+def post_process_math(markdown_text: str, post_processing_settings: MarkdownPostProcessingSettings) -> str:
+    """
+    Rewrite math delimiters outside fenced code blocks.
+
+    - Without normalization, only LaTeX delimiters are converted to dollars (the "dollar" style); "latex" keeps the text.
+    - With normalization, every formula is rewritten to the selected style, and display math gets its own lines.
+    """
+
+    delimiter_style = post_processing_settings.math_delimiter_style
+    is_normalization_enabled = post_processing_settings.normalize_math_delimiters
+    if delimiter_style != MATH_DELIMITER_STYLE_DOLLAR and not is_normalization_enabled:
+        return markdown_text
+
+    converted_chunks: list[str] = []
+    for is_code, _, segment_text in split_fenced_code_segments(markdown_text):
+        if is_code:
+            converted_chunks.append(segment_text)
+            continue
+        spans, _ = scan_math_spans(segment_text)
+        cursor = 0
+        for span in spans:
+            should_rewrite = is_normalization_enabled or span.delimiter_style != delimiter_style
+            if not should_rewrite:
+                continue
+            converted_chunks.append(segment_text[cursor : span.start])
+            converted_chunks.append(render_math_span(span, delimiter_style))
+            cursor = span.end
+        converted_chunks.append(segment_text[cursor:])
+    return "".join(converted_chunks)
+
+
+def find_math_warnings(markdown_text: str) -> tuple[list[MathSpan], list[MathWarning]]:
+    formulas: list[MathSpan] = []
+    warnings: list[MathWarning] = []
+    for is_code, segment_start, segment_text in split_fenced_code_segments(markdown_text):
+        if is_code:
+            continue
+        spans, problems = scan_math_spans(segment_text)
+        for problem_offset, message in problems:
+            warnings.append(MathWarning(_line_number_at(markdown_text, segment_start + problem_offset), message))
+        for span in spans:
+            formulas.append(span)
+            for message in find_formula_problems(span.tex):
+                warnings.append(MathWarning(_line_number_at(markdown_text, segment_start + span.start), message))
+    warnings.sort(key=lambda warning: warning.line_number)
+    return formulas, warnings
+
+
+def _line_number_at(text: str, offset: int) -> int:
+    return text.count("\n", 0, offset) + 1
+
+
+def write_math_report(
+    pages: list[PageMarkdown],
+    output_directory_path: Path,
+    post_processing_settings: MarkdownPostProcessingSettings,
+) -> Path:
+    """
+    Write `math_report.json` (and `math.html` when MathML export is on) into output-dir.
+
+    NOTE:
+    - Pages are checked as OCR produced them, so line numbers match `markdown_items/task_<id>.md`.
+    - The MathML file is referenced by filename; the report is read on the host, where /data paths do not exist.
+    """
+
+    page_warnings: list[PageMathWarning] = []
+    page_formulas: list[tuple[PageMarkdown, list[MathSpan]]] = []
+    for page in pages:
+        formulas, warnings = find_math_warnings(page.markdown)
+        page_formulas.append((page, formulas))
+        page_warnings.extend(
+            PageMathWarning(
+                page_number=page.page_number,
+                source_path=page.source_path,
+                source_page_number=page.source_page_number,
+                line_number=warning.line_number,
+                message=warning.message,
+            )
+            for warning in warnings
+        )
+
+    mathml_filename: str | None = None
+    export_error_message: str | None = None
+    mathml_export_path = output_directory_path / MATHML_EXPORT_FILENAME
+    if post_processing_settings.export_mathml:
+        try:
+            _write_mathml_export(page_formulas, mathml_export_path)
+            mathml_filename = MATHML_EXPORT_FILENAME
+        except ImportError:
+            export_error_message = "MathML export needs the latex2mathml package in the OCR environment."
+    elif mathml_export_path.exists():
+        # Guard: a file left from an earlier run would not match this run's formulas.
+        mathml_export_path.unlink()
+
+    report = {
+        "formula_count": sum(len(formulas) for _, formulas in page_formulas),
+        "mathml_filename": mathml_filename,
+        "export_error_message": export_error_message,
+        "warnings": [asdict(warning) for warning in page_warnings],
+    }
+    return write_json_report(output_directory_path, MATH_REPORT_FILENAME, report)
+
+
+def _write_mathml_export(page_formulas: list[tuple[PageMarkdown, list[MathSpan]]], export_path: Path) -> None:
+    from latex2mathml.converter import convert as convert_latex_to_mathml
+
+    body_lines: list[str] = []
+    for page, formulas in page_formulas:
+        if not formulas:
+            continue
+        source_page = "" if page.source_page_number is None else f" (page {page.source_page_number})"
+        body_lines.append(f"<h2>Page {page.page_number}: {html.escape(page.source_path)}{source_page}</h2>")
+        for formula in formulas:
+            tex = formula.tex.strip()
+            try:
+                mathml = convert_latex_to_mathml(tex, display="block" if formula.is_display else "inline")
+            except Exception:
+                # Guard: one formula the converter cannot parse should not drop the rest of the export.
+                mathml = "<p><em>Could not convert this formula.</em></p>"
+            body_lines.append(f"<figure>{mathml}<figcaption><code>{html.escape(tex)}</code></figcaption></figure>")
+
+    export_path.parent.mkdir(parents=True, exist_ok=True)
+    export_path.write_text(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>OCR formulas</title>\n</head>\n<body>\n"
+        + "\n".join(body_lines)
+        + "\n</body>\n</html>\n",
+        encoding="utf-8",
+    )
@@ -0,0 +1,67 @@
+"""
+Responsibility:
+- Name the rasterized page images the runner writes under output-dir/work.
+- Copy each merged page's image into a stable output-dir/assets/pages layout and build the Markdown links to it.
+"""
+
+from __future__ import annotations
+
+import os
+from pathlib import Path
+import re
+import shutil
+
+from ocr_agent.queue_store import QueueTask, TASK_KIND_IMAGE, TASK_KIND_PDF_PAGE
+
+
+PAGE_ASSETS_RELATIVE_DIRECTORY_PATH = Path("assets") / "pages"
+
+
+def rendered_pdf_page_image_path(work_directory_path: Path, task_id: int, pdf_page_index: int) -> Path:
+    return work_directory_path / f"pdf_{task_id}_page_{pdf_page_index + 1}.png"
+
+
+def copy_page_images_to_assets(
+    tasks_in_enqueue_order: list[QueueTask],
+    output_directory_path: Path,
+    markdown_directory_path: Path,
+) -> dict[int, str]:
+    """
+    Copy one image per task into output-dir/assets/pages/task_<id>.<ext>.
+
+    Returns task_id -> link target relative to the directory holding the merged Markdown.
+
+    Guard:
+    - Tasks whose image is gone (e.g. a cleaned work directory) get no link instead of failing the merge.
+    """
+
+    assets_directory_path = output_directory_path / PAGE_ASSETS_RELATIVE_DIRECTORY_PATH
+    work_directory_path = output_directory_path / "work"
+    page_image_links: dict[int, str] = {}
+    for task in tasks_in_enqueue_order:
+        source_image_path = _resolve_page_image_path(task, work_directory_path)
+        if source_image_path is None or not source_image_path.is_file():
+            continue
+        assets_directory_path.mkdir(parents=True, exist_ok=True)
+        # NOTE: Named like markdown_items/task_<id>.md, so a page's text and image stay easy to pair up.
+        asset_path = assets_directory_path / f"task_{task.task_id}{source_image_path.suffix.lower()}"
+        shutil.copyfile(source_image_path, asset_path)
+        page_image_links[task.task_id] = _render_link_target(asset_path, markdown_directory_path)
+    return page_image_links
+
+
+def _resolve_page_image_path(task: QueueTask, work_directory_path: Path) -> Path | None:
+    if task.task_kind == TASK_KIND_IMAGE:
+        # NOTE: Image inputs are never rasterized; the input itself is the page image.
+        return Path(task.source_path)
+    if task.task_kind == TASK_KIND_PDF_PAGE and task.pdf_page_index is not None:
+        return rendered_pdf_page_image_path(work_directory_path, task.task_id, task.pdf_page_index)
+    return None
+
+
+def _render_link_target(asset_path: Path, markdown_directory_path: Path) -> str:
+    link_target = Path(os.path.relpath(asset_path, markdown_directory_path)).as_posix()
+    # Guard: a bare destination ends at the first space (e.g. a job root named "My Scans").
+    if re.search(r"\s", link_target):
+        return f"<{link_target}>"
+    return link_target
@@ -0,0 +1,27 @@
+"""
+Responsibility:
+- Describe one merged page (numbering, source, OCR Markdown) for the post-merge checks.
+- Write the checks' JSON reports into output-dir, where the GUI backend attaches them to the job report.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+import json
+from pathlib import Path
+
+
+@dataclass(frozen=True)
+class PageMarkdown:
+    # NOTE: Same numbering as the merged file's separators ({n} / page=n/total).
+    page_number: int
+    source_path: str
+    source_page_number: int | None
+    markdown: str
+
+
+def write_json_report(output_directory_path: Path, report_filename: str, report: dict[str, object]) -> Path:
+    output_directory_path.mkdir(parents=True, exist_ok=True)
+    report_path = output_directory_path / report_filename
+    report_path.write_text(json.dumps(report, ensure_ascii=False, indent=2) + "\n", encoding="utf-8")
+    return report_path
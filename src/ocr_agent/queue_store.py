@@ -2,11 +2,12 @@
 Responsibility:
 - Persist and manage the task queue in SQLite.
 - Preserve enqueue order deterministically for merged Markdown ordering.
+- Record when each task was claimed and finished so the GUI can show per-task timings.
 """
 
 from __future__ import annotations
 
-from dataclasses import dataclass
+from dataclasses import dataclass, replace
 from pathlib import Path
 import sqlite3
 import time
@@ -25,6 +26,9 @@ DEFAULT_SQLITE_CONNECT_TIMEOUT_SECONDS = 30.0
 DEFAULT_SQLITE_CONNECT_MAX_RETRIES = 5
 DEFAULT_SQLITE_CONNECT_RETRY_SLEEP_SECONDS = 0.4
 
+# NOTE: Added after the first release; queues created before then get them in `initialize`.
+TASK_TIMING_COLUMN_NAMES = ("started_unix_timestamp_seconds", "finished_unix_timestamp_seconds")
+
 
 @dataclass(frozen=True)
 class QueueTask:
@@ -57,12 +61,30 @@ class QueueStore:
                   created_unix_timestamp_seconds INTEGER NOT NULL,
                   status TEXT NOT NULL,
                   output_markdown_path TEXT NULL,
-                  error_message TEXT NULL
+                  error_message TEXT NULL,
+                  started_unix_timestamp_seconds INTEGER NULL,
+                  finished_unix_timestamp_seconds INTEGER NULL
                 )
                 """
             )
+            self._add_missing_timing_columns(connection)
             connection.commit()
 
+    @staticmethod
+    def _add_missing_timing_columns(connection: sqlite3.Connection) -> None:
+        existing_column_names = {
+            str(row["name"]) for row in connection.execute("PRAGMA table_info(tasks)").fetchall()
+        }
+        for column_name in TASK_TIMING_COLUMN_NAMES:
+            if column_name in existing_column_names:
+                continue
+            try:
+                connection.execute(f"ALTER TABLE tasks ADD COLUMN {column_name} INTEGER NULL")
+            except sqlite3.OperationalError as exception:
+                # Guard: another worker sharing this queue may have added the column first.
+                if "duplicate column" not in str(exception):
+                    raise
+
     def enqueue_image_tasks(
         self, image_file_paths: Iterable[Path], created_unix_timestamp_seconds: int
     ) -> int:
@@ -137,11 +159,50 @@ class QueueStore:
             ).fetchone()
         return self._row_to_task(row)
 
+    def claim_next_pending_task(self) -> QueueTask | None:
+        # NOTE: Several workers may share one queue file; select-and-mark must be a single write transaction.
+        with self._connect() as connection:
+            connection.isolation_level = None
+            connection.execute("BEGIN IMMEDIATE")
+            try:
+                row = connection.execute(
+                    """
+                    SELECT task_id, task_kind, source_path, pdf_page_index, pdf_total_pages,
+                           created_unix_timestamp_seconds, status, output_markdown_path, error_message
+                    FROM tasks
+                    WHERE status = ?
+                    ORDER BY task_id ASC
+                    LIMIT 1
+                    """,
+                    (TASK_STATUS_PENDING,),
+                ).fetchone()
+                if row is not None:
+                    connection.execute(
+                        """
+                        UPDATE tasks
+                        SET status = ?, started_unix_timestamp_seconds = ?, finished_unix_timestamp_seconds = NULL
+                        WHERE task_id = ?
+                        """,
+                        (TASK_STATUS_RUNNING, int(time.time()), int(row["task_id"])),
+                    )
+                connection.execute("COMMIT")
+            except Exception:
+                connection.execute("ROLLBACK")
+                raise
+        task = self._row_to_task(row)
+        if task is None:
+            return None
+        return replace(task, status=TASK_STATUS_RUNNING)
+
     def mark_task_running(self, task_id: int) -> None:
         with self._connect() as connection:
             connection.execute(
-                "UPDATE tasks SET status = ? WHERE task_id = ?",
-                (TASK_STATUS_RUNNING, task_id),
+                """
+                UPDATE tasks
+                SET status = ?, started_unix_timestamp_seconds = ?, finished_unix_timestamp_seconds = NULL
+                WHERE task_id = ?
+                """,
+                (TASK_STATUS_RUNNING, int(time.time()), task_id),
             )
             connection.commit()
 
@@ -150,10 +211,10 @@ class QueueStore:
             connection.execute(
                 """
                 UPDATE tasks
-                SET status = ?, output_markdown_path = ?, error_message = NULL
+                SET status = ?, output_markdown_path = ?, error_message = NULL, finished_unix_timestamp_seconds = ?
                 WHERE task_id = ?
                 """,
-                (TASK_STATUS_COMPLETED, str(output_markdown_path), task_id),
+                (TASK_STATUS_COMPLETED, str(output_markdown_path), int(time.time()), task_id),
             )
             connection.commit()
 
@@ -162,10 +223,10 @@ class QueueStore:
             connection.execute(
                 """
                 UPDATE tasks
-                SET status = ?, error_message = ?
+                SET status = ?, error_message = ?, finished_unix_timestamp_seconds = ?
                 WHERE task_id = ?
                 """,
-                (TASK_STATUS_FAILED, error_message, task_id),
+                (TASK_STATUS_FAILED, error_message, int(time.time()), task_id),
             )
             connection.commit()
 
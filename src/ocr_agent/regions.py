@@ -0,0 +1,57 @@
+"""
+Responsibility:
+- Parse DeepSeek-OCR-2 grounding output (<|ref|>/<|det|> tags) into region bounding boxes.
+- Persist per-task regions as JSON sidecars for overlay viewing in the GUI.
+"""
+
+from __future__ import annotations
+
+from dataclasses import asdict, dataclass
+import json
+from pathlib import Path
+import re
+
+
+# Model-card grounding output uses coordinates normalized to 0..999.
+REGION_COORDINATE_SCALE = 999
+
+_REGION_TAG_PATTERN = re.compile(
+    r"<\|ref\|>(?P<label>.*?)<\|/ref\|><\|det\|>(?P<boxes>\[\[.*?\]\])<\|/det\|>",
+    flags=re.DOTALL,
+)
+_BOX_PATTERN = re.compile(r"\[\s*(\d+)\s*,\s*(\d+)\s*,\s*(\d+)\s*,\s*(\d+)\s*\]")
+
+
+@dataclass(frozen=True)
+class DetectedRegion:
+    label: str
+    x1: int
+    y1: int
+    x2: int
+    y2: int
+
+
+def parse_detected_regions(raw_model_output: str) -> list[DetectedRegion]:
+    regions: list[DetectedRegion] = []
+    for tag_match in _REGION_TAG_PATTERN.finditer(raw_model_output):
+        label = tag_match.group("label").strip()
+        # Guard: one <|det|> tag may carry several boxes for the same label.
+        for box_match in _BOX_PATTERN.finditer(tag_match.group("boxes")):
+            x1, y1, x2, y2 = (int(value) for value in box_match.groups())
+            regions.append(DetectedRegion(label=label, x1=x1, y1=y1, x2=x2, y2=y2))
+    return regions
+
+
+def write_regions_sidecar(
+    regions_file_path: Path,
+    *,
+    task_id: int,
+    regions: list[DetectedRegion],
+) -> None:
+    regions_file_path.parent.mkdir(parents=True, exist_ok=True)
+    payload = {
+        "task_id": task_id,
+        "coordinate_scale": REGION_COORDINATE_SCALE,
+        "regions": [asdict(region) for region in regions],
+    }
+    regions_file_path.write_text(json.dumps(payload, ensure_ascii=False, indent=2), encoding="utf-8")
@@ -0,0 +1,41 @@
+"""
+Responsibility:
+- Unit test: files inside an input directory are enqueued in natural (numeric-aware) order.
+"""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from ocr_agent.input_discovery import expand_input_paths_in_enqueue_order
+
+
+def _touch_files(directory_path: Path, relative_paths: list[str]) -> None:
+    for relative_path in relative_paths:
+        file_path = directory_path / relative_path
+        file_path.parent.mkdir(parents=True, exist_ok=True)
+        file_path.write_bytes(b"")
+
+
+def _relative_enqueue_order(directory_path: Path) -> list[str]:
+    return [
+        file_path.relative_to(directory_path).as_posix()
+        for file_path in expand_input_paths_in_enqueue_order([directory_path])
+    ]
+
+
+def test_directory_files_are_ordered_numerically(tmp_path: Path) -> None:
+    _touch_files(tmp_path, ["page10.png", "page2.png", "Page1.png", "page_notes.txt"])
+
+    assert _relative_enqueue_order(tmp_path) == ["Page1.png", "page2.png", "page10.png"]
+
+
+def test_subdirectories_and_full_width_digits_are_ordered_numerically(tmp_path: Path) -> None:
+    _touch_files(tmp_path, ["part10/scan.pdf", "part2/scan.pdf", "part2/ページ１０.png", "part2/ページ２.png"])
+
+    assert _relative_enqueue_order(tmp_path) == [
+        "part2/scan.pdf",
+        "part2/ページ２.png",
+        "part2/ページ１０.png",
+        "part10/scan.pdf",
+    ]
@@ -0,0 +1,32 @@
+"""
+Responsibility:
+- Unit test: join soft hyphens and reflow paragraphs without touching structural Markdown.
+"""
+
+from __future__ import annotations
+
+from ocr_agent.line_repair import repair_line_breaks
+
+
+def test_repair_line_breaks_joins_hyphens_and_reflows_paragraphs() -> None:
+    markdown_text = "The docu-\nment was wrapped\nacross lines.\n\n- item one\n- item two\n\n| a | b |\n|---|---|"
+
+    repaired = repair_line_breaks(markdown_text, repair_hyphenation=True, reflow_paragraphs=True)
+
+    assert repaired == "The document was wrapped across lines.\n\n- item one\n- item two\n\n| a | b |\n|---|---|"
+
+
+def test_repair_line_breaks_leaves_code_blocks_and_cjk_spacing() -> None:
+    markdown_text = "```\nfoo-\nbar\n```\n日本語の\n文章です。"
+
+    repaired = repair_line_breaks(markdown_text, repair_hyphenation=True, reflow_paragraphs=True)
+
+    assert repaired == "```\nfoo-\nbar\n```\n日本語の文章です。"
+
+
+def test_repair_line_breaks_hyphenation_only_keeps_other_breaks() -> None:
+    markdown_text = "infor-\nmation here\nnext line"
+
+    repaired = repair_line_breaks(markdown_text, repair_hyphenation=True, reflow_paragraphs=False)
+
+    assert repaired == "information here\nnext line"
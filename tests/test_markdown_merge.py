@@ -0,0 +1,150 @@
+"""
+Responsibility:
+- Unit test: page separator styles, source comments, rolling appends, partial output, and page image links in the combined Markdown.
+"""
+
+from __future__ import annotations
+
+from dataclasses import replace
+from pathlib import Path
+
+from ocr_agent.config import (
+    MATH_DELIMITER_STYLE_LATEX,
+    PAGE_SEPARATOR_STYLE_NONE,
+    PAGE_SEPARATOR_STYLE_TEMPLATE,
+    MarkdownPostProcessingSettings,
+)
+from ocr_agent.markdown_merge import (
+    append_tasks_to_rolling_markdown,
+    merge_tasks_into_single_markdown,
+    write_partial_markdown,
+)
+from ocr_agent.page_assets import copy_page_images_to_assets, rendered_pdf_page_image_path
+from ocr_agent.queue_store import TASK_KIND_PDF_PAGE, QueueTask
+
+
+def _write_pdf_page_tasks(tmp_path: Path, page_markdowns: list[str]) -> list[QueueTask]:
+    tmp_path.mkdir(parents=True, exist_ok=True)
+    tasks: list[QueueTask] = []
+    for page_index, page_markdown in enumerate(page_markdowns):
+        task_markdown_path = tmp_path / f"task_{page_index}.md"
+        task_markdown_path.write_text(page_markdown, encoding="utf-8")
+        tasks.append(
+            QueueTask(
+                task_id=page_index + 1,
+                task_kind=TASK_KIND_PDF_PAGE,
+                source_path="/data/input/report.pdf",
+                pdf_page_index=page_index,
+                pdf_total_pages=len(page_markdowns),
+                created_unix_timestamp_seconds=0,
+                status="completed",
+                output_markdown_path=str(task_markdown_path),
+                error_message=None,
+            )
+        )
+    return tasks
+
+
+def test_merge_renders_page_separator_template_with_source_comments(tmp_path: Path) -> None:
+    tasks = _write_pdf_page_tasks(tmp_path, ["First page", "Second page"])
+    merged_markdown_path = tmp_path / "merged.md"
+    settings = MarkdownPostProcessingSettings(
+        math_delimiter_style=MATH_DELIMITER_STYLE_LATEX,
+        page_separator_style=PAGE_SEPARATOR_STYLE_TEMPLATE,
+        page_separator_template="--- page {n} / {total} ---",
+        include_page_source_comments=True,
+    )
+
+    merge_tasks_into_single_markdown(tasks, merged_markdown_path, settings)
+
+    merged = merged_markdown_path.read_text(encoding="utf-8")
+    assert "--- page 1 / 2 ---" in merged
+    assert "--- page 2 / 2 ---" in merged
+    assert "<!-- page=2/2 source=/data/input/report.pdf source_page=2/2 -->" in merged
+    assert "## /data/input/report.pdf" not in merged
+
+
+def test_merge_without_separators_only_keeps_page_text(tmp_path: Path) -> None:
+    tasks = _write_pdf_page_tasks(tmp_path, ["First page", "Second page"])
+    merged_markdown_path = tmp_path / "merged.md"
+    settings = MarkdownPostProcessingSettings(
+        math_delimiter_style=MATH_DELIMITER_STYLE_LATEX,
+        page_separator_style=PAGE_SEPARATOR_STYLE_NONE,
+    )
+
+    merge_tasks_into_single_markdown(tasks, merged_markdown_path, settings)
+
+    assert merged_markdown_path.read_text(encoding="utf-8") == "# OCR Output\n\nFirst page\n\nSecond page\n"
+
+
+def test_append_to_rolling_markdown_adds_dated_sections(tmp_path: Path) -> None:
+    rolling_markdown_path = tmp_path / "ocr_log.md"
+    settings = MarkdownPostProcessingSettings(
+        math_delimiter_style=MATH_DELIMITER_STYLE_LATEX,
+        page_separator_style=PAGE_SEPARATOR_STYLE_NONE,
+    )
+
+    first_run_tasks = _write_pdf_page_tasks(tmp_path / "first", ["Monday notes"])
+    second_run_tasks = _write_pdf_page_tasks(tmp_path / "second", ["Tuesday notes"])
+    assert append_tasks_to_rolling_markdown(first_run_tasks, rolling_markdown_path, settings, "2026-01-05 09:00")
+    assert append_tasks_to_rolling_markdown(second_run_tasks, rolling_markdown_path, settings, "2026-01-06 09:00")
+    assert not append_tasks_to_rolling_markdown([], rolling_markdown_path, settings, "2026-01-07 09:00")
+
+    assert rolling_markdown_path.read_text(encoding="utf-8") == (
+        "# OCR Output\n\n## 2026-01-05 09:00\n\nMonday notes\n\n## 2026-01-06 09:00\n\nTuesday notes\n"
+    )
+
+
+def test_partial_markdown_keeps_leading_completed_pages_under_banner(tmp_path: Path) -> None:
+    tasks = _write_pdf_page_tasks(tmp_path, ["First page", "Second page", "Third page", "Fourth page"])
+    tasks[2] = replace(tasks[2], status="running")
+    partial_markdown_path = tmp_path / "partial.md"
+    settings = MarkdownPostProcessingSettings(
+        math_delimiter_style=MATH_DELIMITER_STYLE_LATEX,
+        page_separator_style=PAGE_SEPARATOR_STYLE_NONE,
+    )
+
+    assert write_partial_markdown(tasks, partial_markdown_path, settings) == (2, 4)
+
+    assert partial_markdown_path.read_text(encoding="utf-8") == (
+        "# OCR Output\n\n"
+        "> **Partial output (run cancelled)**: processed pages 1\u20132 of 4.\n\n"
+        "First page\n\nSecond page\n"
+    )
+
+
+def test_merge_links_each_page_to_its_exported_image(tmp_path: Path) -> None:
+    job_root_path = tmp_path / "My Scans"
+    output_directory_path = job_root_path / "output"
+    tasks = _write_pdf_page_tasks(tmp_path / "markdown_items", ["First page", "Second page"])
+    work_directory_path = output_directory_path / "work"
+    work_directory_path.mkdir(parents=True)
+    rendered_pdf_page_image_path(work_directory_path, task_id=1, pdf_page_index=0).write_bytes(b"png-1")
+    merged_markdown_path = job_root_path / "report.md"
+    settings = MarkdownPostProcessingSettings(
+        math_delimiter_style=MATH_DELIMITER_STYLE_LATEX,
+        page_separator_style=PAGE_SEPARATOR_STYLE_NONE,
+        export_page_images=True,
+    )
+
+    page_image_links = copy_page_images_to_assets(tasks, output_directory_path, merged_markdown_path.parent)
+    merge_tasks_into_single_markdown(tasks, merged_markdown_path, settings, page_image_links)
+
+    # NOTE: The second page's render is missing, so only the first page gets an image.
+    assert (output_directory_path / "assets" / "pages" / "task_1.png").read_bytes() == b"png-1"
+    assert page_image_links == {1: "output/assets/pages/task_1.png"}
+    assert merged_markdown_path.read_text(encoding="utf-8") == (
+        "# OCR Output\n\n![Page 1](output/assets/pages/task_1.png)\n\nFirst page\n\nSecond page\n"
+    )
+
+
+def test_page_image_links_are_bracketed_when_the_path_has_spaces(tmp_path: Path) -> None:
+    output_directory_path = tmp_path / "job" / "ocr output"
+    tasks = _write_pdf_page_tasks(tmp_path / "markdown_items", ["Only page"])
+    work_directory_path = output_directory_path / "work"
+    work_directory_path.mkdir(parents=True)
+    rendered_pdf_page_image_path(work_directory_path, task_id=1, pdf_page_index=0).write_bytes(b"png-1")
+
+    page_image_links = copy_page_images_to_assets(tasks, output_directory_path, tmp_path / "job")
+
+    assert page_image_links == {1: "<ocr output/assets/pages/task_1.png>"}
@@ -0,0 +1,78 @@
+"""
+Responsibility:
+- Unit test: per-page Markdown structure issues (tables, code fences, links) and the validation report.
+"""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+from ocr_agent.markdown_validation import (
+    ISSUE_KIND_CODE_FENCE,
+    ISSUE_KIND_LINK,
+    ISSUE_KIND_TABLE,
+    find_markdown_issues,
+    write_markdown_validation_report,
+)
+from ocr_agent.page_reports import PageMarkdown
+
+
+def test_find_markdown_issues_reports_ragged_and_headerless_tables() -> None:
+    markdown_text = "| a | b |\n|---|---|\n| 1 | 2 |\n| 3 |\n\n| x | y |\n| 1 | 2 |\n\n<table><tr><td>open"
+
+    issues = find_markdown_issues(markdown_text)
+
+    assert [(issue.kind, issue.line_number) for issue in issues] == [
+        (ISSUE_KIND_TABLE, 4),
+        (ISSUE_KIND_TABLE, 6),
+        (ISSUE_KIND_TABLE, 9),
+    ]
+    assert issues[0].message == "Table row has 1 cell(s) but the header has 2."
+
+
+def test_find_markdown_issues_reports_unclosed_fence_and_ignores_its_content() -> None:
+    markdown_text = "Intro\n```python\n| not | a table\n[not](a link"
+
+    issues = find_markdown_issues(markdown_text)
+
+    assert [(issue.kind, issue.line_number) for issue in issues] == [(ISSUE_KIND_CODE_FENCE, 2)]
+
+
+def test_find_markdown_issues_reports_malformed_links_but_not_inline_code() -> None:
+    markdown_text = (
+        "See [docs](https://example.com/a b) and [empty]() and ![fig](img.png\n"
+        'Fine [ok](https://example.com "Title") and `a[i](x` and [nested](https://e.com/(x))'
+    )
+
+    issues = find_markdown_issues(markdown_text)
+
+    assert [(issue.kind, issue.line_number, issue.message) for issue in issues] == [
+        (ISSUE_KIND_LINK, 1, "Link [docs] has spaces in its URL (https://example.com/a b); it will not render as a link."),
+        (ISSUE_KIND_LINK, 1, "Link [empty] has an empty URL."),
+        (ISSUE_KIND_LINK, 1, "Image [fig] is missing its closing )."),
+    ]
+
+
+def test_write_markdown_validation_report_lists_issues_per_page(tmp_path: Path) -> None:
+    pages = [
+        PageMarkdown(page_number=1, source_path="/data/input/a.png", source_page_number=None, markdown="Clean page"),
+        PageMarkdown(page_number=2, source_path="/data/input/b.pdf", source_page_number=4, markdown="x\n~~~\ncode"),
+    ]
+
+    report_path = write_markdown_validation_report(pages, tmp_path)
+
+    report = json.loads(report_path.read_text(encoding="utf-8"))
+    assert report == {
+        "checked_page_count": 2,
+        "issues": [
+            {
+                "page_number": 2,
+                "source_path": "/data/input/b.pdf",
+                "source_page_number": 4,
+                "kind": ISSUE_KIND_CODE_FENCE,
+                "line_number": 2,
+                "message": "Code block opened with ~~~ is never closed; the rest of the page renders as code.",
+            }
+        ],
+    }
@@ -0,0 +1,87 @@
+"""
+Responsibility:
+- Unit test: math delimiter conversion/normalization, unbalanced math warnings, and the math report.
+"""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+from ocr_agent.config import (
+    MATH_DELIMITER_STYLE_DOLLAR,
+    MATH_DELIMITER_STYLE_LATEX,
+    MarkdownPostProcessingSettings,
+)
+from ocr_agent.math_postprocess import (
+    MATH_REPORT_FILENAME,
+    find_math_warnings,
+    post_process_math,
+    write_math_report,
+)
+from ocr_agent.page_reports import PageMarkdown
+
+
+def test_dollar_style_converts_latex_delimiters_outside_code_blocks() -> None:
+    markdown_text = "Inline \\( a+b \\) and\n\\[\nx^2\n\\]\n```\n\\(kept\\)\n```\nPrice $x$ stays."
+    settings = MarkdownPostProcessingSettings(math_delimiter_style=MATH_DELIMITER_STYLE_DOLLAR)
+
+    assert post_process_math(markdown_text, settings) == (
+        "Inline $a+b$ and\n$$\nx^2\n$$\n```\n\\(kept\\)\n```\nPrice $x$ stays."
+    )
+
+
+def test_latex_style_normalization_rewrites_dollar_math_but_not_currency() -> None:
+    markdown_text = "Costs $5 and $10. Area $\\pi r^2$ and $$E=mc^2$$ here."
+    settings = MarkdownPostProcessingSettings(
+        math_delimiter_style=MATH_DELIMITER_STYLE_LATEX,
+        normalize_math_delimiters=True,
+    )
+
+    assert post_process_math(markdown_text, settings) == (
+        "Costs $5 and $10. Area \\(\\pi r^2\\) and \\[\nE=mc^2\n\\] here."
+    )
+
+
+def test_latex_style_without_normalization_keeps_text() -> None:
+    markdown_text = "Area $\\pi r^2$ and \\(x\\)."
+    settings = MarkdownPostProcessingSettings(math_delimiter_style=MATH_DELIMITER_STYLE_LATEX)
+
+    assert post_process_math(markdown_text, settings) == markdown_text
+
+
+def test_find_math_warnings_reports_unclosed_delimiters_and_braces() -> None:
+    markdown_text = "Fine $a$ here.\nBroken \\(x + y\nThen $$\\frac{1}{2$$\nStray \\]\n```\n$$ in code\n```"
+
+    formulas, warnings = find_math_warnings(markdown_text)
+
+    assert [formula.tex for formula in formulas] == ["a", "\\frac{1}{2"]
+    assert [(warning.line_number, warning.message) for warning in warnings] == [
+        (2, "Unclosed \\( (no matching \\))."),
+        (3, "Formula has 1 unclosed {."),
+        (4, "Stray \\] without a matching opener."),
+    ]
+
+
+def test_write_math_report_lists_warnings_per_page(tmp_path: Path) -> None:
+    pages = [
+        PageMarkdown(page_number=1, source_path="/data/input/a.png", source_page_number=None, markdown="$x$"),
+        PageMarkdown(page_number=2, source_path="/data/input/b.pdf", source_page_number=3, markdown="\n$$\\left( x$$"),
+    ]
+    settings = MarkdownPostProcessingSettings(math_delimiter_style=MATH_DELIMITER_STYLE_DOLLAR)
+
+    report_path = write_math_report(pages, tmp_path, settings)
+
+    assert report_path == tmp_path / MATH_REPORT_FILENAME
+    report = json.loads(report_path.read_text(encoding="utf-8"))
+    assert report["formula_count"] == 2
+    assert report["mathml_filename"] is None
+    assert report["warnings"] == [
+        {
+            "page_number": 2,
+            "source_path": "/data/input/b.pdf",
+            "source_page_number": 3,
+            "line_number": 2,
+            "message": "Formula has 1 \\left but 0 \\right.",
+        }
+    ]
@@ -0,0 +1,123 @@
+"""
+Responsibility:
+- Unit test: several workers sharing one queue database never claim the same task twice.
+- Unit test: claimed and finished tasks record their timings, also in queues created before the timing columns.
+"""
+
+from __future__ import annotations
+
+from pathlib import Path
+import sqlite3
+import threading
+
+from ocr_agent.queue_store import TASK_STATUS_PENDING, TASK_STATUS_RUNNING, QueueStore
+
+
+def _make_queue_store(tmp_path: Path, task_count: int) -> QueueStore:
+    queue_store = QueueStore(tmp_path / "queue.sqlite3")
+    queue_store.initialize()
+    queue_store.enqueue_image_tasks(
+        image_file_paths=[tmp_path / f"page_{index:03d}.png" for index in range(task_count)],
+        created_unix_timestamp_seconds=0,
+    )
+    return queue_store
+
+
+def test_claim_next_pending_task_marks_task_running(tmp_path: Path) -> None:
+    queue_store = _make_queue_store(tmp_path, task_count=2)
+
+    first_task = queue_store.claim_next_pending_task()
+    assert first_task is not None
+    assert first_task.status == TASK_STATUS_RUNNING
+    assert queue_store.fetch_status_counts() == {TASK_STATUS_PENDING: 1, TASK_STATUS_RUNNING: 1}
+
+    second_task = queue_store.claim_next_pending_task()
+    assert second_task is not None
+    assert second_task.task_id > first_task.task_id
+    assert queue_store.claim_next_pending_task() is None
+
+
+def test_concurrent_workers_claim_each_task_once(tmp_path: Path) -> None:
+    task_count = 40
+    _make_queue_store(tmp_path, task_count=task_count)
+
+    claimed_task_ids: list[int] = []
+    claimed_task_ids_lock = threading.Lock()
+
+    def drain_queue() -> None:
+        # NOTE: Each worker opens its own store, like separate containers sharing the bind-mounted file.
+        worker_queue_store = QueueStore(tmp_path / "queue.sqlite3")
+        while True:
+            task = worker_queue_store.claim_next_pending_task()
+            if task is None:
+                return
+            with claimed_task_ids_lock:
+                claimed_task_ids.append(task.task_id)
+
+    workers = [threading.Thread(target=drain_queue) for _ in range(4)]
+    for worker in workers:
+        worker.start()
+    for worker in workers:
+        worker.join()
+
+    assert sorted(claimed_task_ids) == list(range(1, task_count + 1))
+
+
+def _read_task_timings(queue_database_path: Path, task_id: int) -> tuple[int | None, int | None]:
+    with sqlite3.connect(str(queue_database_path)) as connection:
+        row = connection.execute(
+            "SELECT started_unix_timestamp_seconds, finished_unix_timestamp_seconds FROM tasks WHERE task_id = ?",
+            (task_id,),
+        ).fetchone()
+    return row[0], row[1]
+
+
+def test_claimed_and_finished_tasks_record_timings(tmp_path: Path) -> None:
+    queue_store = _make_queue_store(tmp_path, task_count=2)
+    queue_database_path = tmp_path / "queue.sqlite3"
+
+    completed_task = queue_store.claim_next_pending_task()
+    assert completed_task is not None
+    started, finished = _read_task_timings(queue_database_path, completed_task.task_id)
+    assert started is not None and finished is None
+    queue_store.mark_task_completed(completed_task.task_id, tmp_path / "page.md")
+    started, finished = _read_task_timings(queue_database_path, completed_task.task_id)
+    assert started is not None and finished is not None and finished >= started
+
+    failed_task = queue_store.claim_next_pending_task()
+    assert failed_task is not None
+    queue_store.mark_task_failed(failed_task.task_id, "boom")
+    assert _read_task_timings(queue_database_path, failed_task.task_id)[1] is not None
+
+
+def test_initialize_adds_timing_columns_to_old_queue(tmp_path: Path) -> None:
+    queue_database_path = tmp_path / "queue.sqlite3"
+    with sqlite3.connect(str(queue_database_path)) as connection:
+        connection.execute(
+            """
+            CREATE TABLE tasks (
+              task_id INTEGER PRIMARY KEY AUTOINCREMENT,
+              task_kind TEXT NOT NULL,
+              source_path TEXT NOT NULL,
+              pdf_page_index INTEGER NULL,
+              pdf_total_pages INTEGER NULL,
+              created_unix_timestamp_seconds INTEGER NOT NULL,
+              status TEXT NOT NULL,
+              output_markdown_path TEXT NULL,
+              error_message TEXT NULL
+            )
+            """
+        )
+        connection.execute(
+            "INSERT INTO tasks (task_kind, source_path, created_unix_timestamp_seconds, status) VALUES (?, ?, 0, ?)",
+            ("image", str(tmp_path / "old.png"), TASK_STATUS_PENDING),
+        )
+
+    queue_store = QueueStore(queue_database_path)
+    queue_store.initialize()
+    # NOTE: A second worker initializing the same queue must not fail on the columns the first one added.
+    QueueStore(queue_database_path).initialize()
+
+    task = queue_store.claim_next_pending_task()
+    assert task is not None
+    assert _read_task_timings(queue_database_path, task.task_id)[0] is not None
@@ -0,0 +1,27 @@
+"""
+Responsibility:
+- Unit test: parse DeepSeek-OCR-2 grounding tags into region boxes.
+"""
+
+from __future__ import annotations
+
+from ocr_agent.regions import DetectedRegion, parse_detected_regions
+
+
+def test_parse_detected_regions_reads_single_and_multiple_boxes() -> None:
+    raw_output = (
+        "<|ref|>title<|/ref|><|det|>[[10, 20, 300, 60]]<|/det|>\n# Heading\n"
+        "<|ref|>text<|/ref|><|det|>[[10, 80, 500, 120], [10, 130, 500, 170]]<|/det|>\nBody"
+    )
+
+    regions = parse_detected_regions(raw_output)
+
+    assert regions == [
+        DetectedRegion(label="title", x1=10, y1=20, x2=300, y2=60),
+        DetectedRegion(label="text", x1=10, y1=80, x2=500, y2=120),
+        DetectedRegion(label="text", x1=10, y1=130, x2=500, y2=170),
+    ]
+
+
+def test_parse_detected_regions_ignores_plain_markdown() -> None:
+    assert parse_detected_regions("# Heading\n\nNo grounding tags here.") == []